@@ -0,0 +1,180 @@
+#![cfg(test)]
+
+use crate::assert_balance;
+use crate::polkadot_test_net::*;
+
+use frame_support::assert_ok;
+use frame_support::traits::{tokens::nonfungibles::Inspect, Contains, Get};
+use hydradx_runtime::{
+	ConcentratedLiquidity, DustRemovalWhitelist, EmaOracle, Router, RouterWeightInfo, Runtime, RuntimeOrigin, Uniques,
+};
+use hydradx_traits::{
+	router::{PoolType, Trade, TradeExecution},
+	AggregatedPriceOracle,
+	OraclePeriod::LastBlock,
+};
+use orml_traits::MultiCurrency;
+use pallet_route_executor::AmmTradeWeights;
+use primitives::constants::chain::CONCENTRATED_SOURCE;
+use sp_runtime::{traits::Zero, FixedU128, Permill};
+use xcm_emulator::TestExt;
+
+const FEE: Permill = Permill::from_parts(3_000);
+const TICK_SPACING: u32 = 60;
+
+fn init_pool() {
+	assert_ok!(ConcentratedLiquidity::set_fee_tier(
+		RuntimeOrigin::root(),
+		FEE,
+		TICK_SPACING
+	));
+	assert_ok!(ConcentratedLiquidity::create_pool(
+		RuntimeOrigin::root(),
+		HDX,
+		DOT,
+		FEE,
+		FixedU128::from(1)
+	));
+	assert_ok!(ConcentratedLiquidity::add_liquidity(
+		RuntimeOrigin::signed(ALICE.into()),
+		0,
+		-600,
+		600,
+		100 * UNITS,
+		100 * UNITS,
+		0
+	));
+}
+
+fn route() -> Vec<Trade<AssetId>> {
+	vec![Trade {
+		pool: PoolType::Concentrated(0),
+		asset_in: HDX,
+		asset_out: DOT,
+	}]
+}
+
+#[test]
+fn pool_account_should_be_added_into_whitelist_when_pool_is_created() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		//Act
+		init_pool();
+
+		//Assert
+		assert!(DustRemovalWhitelist::contains(&ConcentratedLiquidity::pool_account(0)));
+	});
+}
+
+#[test]
+fn add_liquidity_should_mint_position_nft() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		//Act
+		init_pool();
+
+		//Assert
+		let collection_id = <Runtime as pallet_concentrated_liquidity::Config>::NFTCollectionId::get();
+		assert_eq!(Uniques::owner(&collection_id, &0), Some(ALICE.into()));
+		assert!(ConcentratedLiquidity::positions(0).is_some());
+	});
+}
+
+#[test]
+fn sell_should_work_when_route_contains_concentrated_pool() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		//Arrange
+		init_pool();
+
+		let amount_to_sell = 10 * UNITS;
+		let expected_amount_out =
+			ConcentratedLiquidity::calculate_sell(PoolType::Concentrated(0), HDX, DOT, amount_to_sell).unwrap();
+
+		//Act
+		assert_ok!(Router::sell(
+			RuntimeOrigin::signed(BOB.into()),
+			HDX,
+			DOT,
+			amount_to_sell,
+			expected_amount_out,
+			route()
+		));
+
+		//Assert
+		assert_balance!(BOB.into(), HDX, BOB_INITIAL_NATIVE_BALANCE - amount_to_sell);
+		assert_balance!(BOB.into(), DOT, expected_amount_out);
+
+		expect_hydra_last_events(vec![pallet_route_executor::Event::Executed {
+			asset_in: HDX,
+			asset_out: DOT,
+			amount_in: amount_to_sell,
+			amount_out: expected_amount_out,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn buy_should_work_when_route_contains_concentrated_pool() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		//Arrange
+		init_pool();
+
+		let amount_to_buy = 10 * UNITS;
+		let expected_amount_in =
+			ConcentratedLiquidity::calculate_buy(PoolType::Concentrated(0), HDX, DOT, amount_to_buy).unwrap();
+
+		//Act
+		assert_ok!(Router::buy(
+			RuntimeOrigin::signed(BOB.into()),
+			HDX,
+			DOT,
+			amount_to_buy,
+			expected_amount_in,
+			route()
+		));
+
+		//Assert
+		assert_balance!(BOB.into(), HDX, BOB_INITIAL_NATIVE_BALANCE - expected_amount_in);
+		assert_balance!(BOB.into(), DOT, amount_to_buy);
+	});
+}
+
+#[test]
+fn trades_should_be_ingested_into_oracle() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		//Arrange
+		hydradx_run_to_next_block();
+		init_pool();
+
+		assert_ok!(ConcentratedLiquidity::sell(
+			RuntimeOrigin::signed(BOB.into()),
+			0,
+			HDX,
+			DOT,
+			10 * UNITS,
+			0
+		));
+
+		//Act
+		hydradx_run_to_next_block();
+
+		//Assert
+		assert!(EmaOracle::get_price(HDX, DOT, LastBlock, CONCENTRATED_SOURCE).is_ok());
+	});
+}
+
+#[test]
+fn router_weights_should_include_concentrated_pool() {
+	assert!(!RouterWeightInfo::sell_weight(&route()).is_zero());
+	assert!(!RouterWeightInfo::buy_weight(&route()).is_zero());
+	assert!(!RouterWeightInfo::calculate_spot_price_with_fee_weight(&route()).is_zero());
+}
@@ -266,6 +266,7 @@ mod omnipool {
 						asset_out: DAI,
 					}]),
 				},
+				trigger: None,
 			};
 			create_schedule(ALICE, schedule1);
 
@@ -1230,6 +1231,7 @@ mod omnipool {
 						asset_out: DAI,
 					}]),
 				},
+				trigger: None,
 			};
 			create_schedule(ALICE, schedule1);
 
@@ -2143,6 +2145,7 @@ mod stableswap {
 						min_amount_out: Balance::MIN,
 						route: create_bounded_vec(trades),
 					},
+					trigger: None,
 				};
 
 				create_schedule(ALICE, schedule);
@@ -2423,6 +2426,7 @@ mod stableswap {
 						min_amount_out: Balance::MIN,
 						route: create_bounded_vec(trades),
 					},
+					trigger: None,
 				};
 
 				create_schedule(ALICE, schedule);
@@ -2731,6 +2735,7 @@ mod stableswap {
 						max_amount_in: Balance::MAX,
 						route: create_bounded_vec(trades),
 					},
+					trigger: None,
 				};
 
 				create_schedule(ALICE, schedule);
@@ -2912,6 +2917,7 @@ mod stableswap {
 						max_amount_in: Balance::MAX,
 						route: create_bounded_vec(trades),
 					},
+					trigger: None,
 				};
 
 				create_schedule(ALICE, schedule);
@@ -3165,6 +3171,7 @@ mod all_pools {
 						min_amount_out: Balance::MIN,
 						route: create_bounded_vec(trades),
 					},
+					trigger: None,
 				};
 
 				create_schedule(ALICE, schedule);
@@ -3313,6 +3320,7 @@ mod with_onchain_route {
 						max_amount_in: Balance::MAX,
 						route: create_bounded_vec(vec![]),
 					},
+					trigger: None,
 				};
 
 				create_schedule(ALICE, schedule);
@@ -3427,6 +3435,7 @@ mod with_onchain_route {
 						min_amount_out: Balance::MIN,
 						route: create_bounded_vec(vec![]),
 					},
+					trigger: None,
 				};
 
 				create_schedule(ALICE, schedule);
@@ -3511,6 +3520,7 @@ mod with_onchain_route {
 					min_amount_out: Balance::MIN,
 					route: create_bounded_vec(vec![]),
 				},
+				trigger: None,
 			};
 
 			//We verify the price diff between hdx and stable asset.
@@ -3658,6 +3668,7 @@ mod with_onchain_route {
 						min_amount_out: Balance::MIN,
 						route: create_bounded_vec(vec![]),
 					},
+					trigger: None,
 				};
 
 				//We verify the price diff between hdx and stable asset.
@@ -3778,6 +3789,7 @@ mod with_onchain_route {
 					min_amount_out: Balance::MIN,
 					route: create_bounded_vec(vec![]),
 				},
+				trigger: None,
 			};
 
 			//Just to verify the price difference between HDX and DOT
@@ -3883,6 +3895,7 @@ fn schedule_fake_with_buy_order_with_route(
 			max_amount_in: Balance::MAX,
 			route: create_bounded_vec(route),
 		},
+		trigger: None,
 	}
 }
 
@@ -3930,6 +3943,7 @@ fn schedule_fake_with_sell_order_with_route(
 			min_amount_out: Balance::MIN,
 			route: create_bounded_vec(route),
 		},
+		trigger: None,
 	}
 }
 
@@ -69,7 +69,8 @@ fn fees_should_initialize_lazily_to_min_value_when_first_trade_happens() {
 			FeeEntry {
 				asset_fee: asset_fee_params.min_fee,
 				protocol_fee: Permill::from_float(0.000788_f64),
-				timestamp: 12_u32
+				timestamp: 12_u32,
+				fixed: false
 			}
 		);
 	});
@@ -105,7 +106,8 @@ fn fees_should_initialize_lazily_to_min_value_when_first_buy_happens() {
 			FeeEntry {
 				asset_fee: asset_fee_params.min_fee,
 				protocol_fee: Permill::from_float(0.000788_f64),
-				timestamp: 12_u32
+				timestamp: 12_u32,
+				fixed: false
 			}
 		);
 	});
@@ -151,7 +153,8 @@ fn fees_should_change_when_buys_happen_in_different_blocks() {
 			FeeEntry {
 				asset_fee: Permill::from_float(0.0025_f64),
 				protocol_fee: Permill::from_float(0.001_f64),
-				timestamp: 11_u32
+				timestamp: 11_u32,
+				fixed: false
 			}
 		);
 	});
@@ -195,7 +198,8 @@ fn fees_should_change_when_sells_happen_in_different_blocks() {
 			FeeEntry {
 				asset_fee: Permill::from_float(0.0025_f64),
 				protocol_fee: Permill::from_float(0.000926_f64),
-				timestamp: 13_u32
+				timestamp: 13_u32,
+				fixed: false
 			}
 		);
 	});
@@ -239,7 +243,8 @@ fn fees_should_change_when_trades_happen_in_different_blocks() {
 			FeeEntry {
 				asset_fee: Permill::from_float(0.0025_f64),
 				protocol_fee: Permill::from_float(0.000926_f64),
-				timestamp: 13_u32
+				timestamp: 13_u32,
+				fixed: false
 			}
 		);
 	});
@@ -283,7 +288,8 @@ fn fees_should_change_only_one_when_trades_happen_in_the_same_block() {
 			FeeEntry {
 				asset_fee: Permill::from_float(0.0025_f64),
 				protocol_fee: Permill::from_float(0.000926_f64),
-				timestamp: 13_u32
+				timestamp: 13_u32,
+				fixed: false
 			}
 		);
 
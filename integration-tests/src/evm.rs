@@ -605,9 +605,12 @@ mod standard_precompiles {
 
 mod currency_precompile {
 	use super::*;
+	use crate::utils::accounts::{alith_evm_address, alith_secret_key};
 	use fp_evm::ExitRevert::Reverted;
 	use fp_evm::PrecompileFailure;
 	use frame_support::assert_noop;
+	use hydradx_runtime::evm::precompiles::Output;
+	use libsecp256k1::{sign, Message, SecretKey};
 	use pretty_assertions::assert_eq;
 
 	type AllHydraDXPrecompile = HydraDXPrecompiles<hydradx_runtime::Runtime>;
@@ -986,271 +989,1659 @@ mod currency_precompile {
 	}
 
 	#[test]
-	fn precompile_for_currency_approve_allowance_should_fail_as_not_supported() {
+	fn precompile_for_currency_approve_should_set_allowance() {
 		TestNet::reset();
 
 		Hydra::execute_with(|| {
 			//Arrange
+			let data = EvmDataWriter::new_with_selector(Function::Approve)
+				.write(Address::from(evm_address2()))
+				.write(U256::from(50u128 * UNITS))
+				.build();
+
+			let mut handle = MockHandle {
+				input: data,
+				context: Context {
+					address: native_asset_ethereum_address(),
+					caller: evm_address(),
+					apparent_value: U256::from(0),
+				},
+				code_address: native_asset_ethereum_address(),
+				is_static: false,
+			};
+
+			//Act
+			let result = CurrencyPrecompile::execute(&mut handle);
+
+			//Assert
+			assert_eq!(
+				result,
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: hex!["0000000000000000000000000000000000000000000000000000000000000001"].to_vec(),
+				})
+			);
+			assert_eq!(
+				CurrencyPrecompile::allowance_of(HDX, evm_address(), evm_address2()),
+				50u128 * UNITS
+			);
+		});
+	}
+
+	#[test]
+	fn precompile_for_currency_approve_should_saturate_allowance_when_amount_exceeds_balance_type() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			let data = EvmDataWriter::new_with_selector(Function::Approve)
+				.write(Address::from(evm_address2()))
+				.write(U256::MAX)
+				.build();
+
+			let mut handle = MockHandle {
+				input: data,
+				context: Context {
+					address: native_asset_ethereum_address(),
+					caller: evm_address(),
+					apparent_value: U256::from(0),
+				},
+				code_address: native_asset_ethereum_address(),
+				is_static: false,
+			};
+
+			//Act
+			assert_ok!(CurrencyPrecompile::execute(&mut handle));
+
+			//Assert
+			assert_eq!(
+				CurrencyPrecompile::allowance_of(HDX, evm_address(), evm_address2()),
+				Balance::MAX
+			);
+		});
+	}
+
+	#[test]
+	fn precompile_for_currency_approve_should_fail_in_static_context() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			let data = EvmDataWriter::new_with_selector(Function::Approve)
+				.write(Address::from(evm_address2()))
+				.write(U256::from(50u128 * UNITS))
+				.build();
+
+			let mut handle = MockHandle {
+				input: data,
+				context: Context {
+					address: native_asset_ethereum_address(),
+					caller: evm_address(),
+					apparent_value: U256::from(0),
+				},
+				code_address: native_asset_ethereum_address(),
+				is_static: true,
+			};
+
+			//Act & Assert
+			assert_noop!(
+				CurrencyPrecompile::execute(&mut handle),
+				PrecompileFailure::Revert {
+					exit_status: Reverted,
+					output: "can't call non-static function in static context".as_bytes().to_vec()
+				}
+			);
+		});
+	}
+
+	#[test]
+	fn precompile_for_currency_increase_and_decrease_allowance_should_work() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			let increase = EvmDataWriter::new_with_selector(Function::IncreaseAllowance)
+				.write(Address::from(evm_address2()))
+				.write(U256::from(50u128 * UNITS))
+				.build();
+			let decrease = EvmDataWriter::new_with_selector(Function::DecreaseAllowance)
+				.write(Address::from(evm_address2()))
+				.write(U256::from(20u128 * UNITS))
+				.build();
+
+			let mut handle = MockHandle {
+				input: increase,
+				context: Context {
+					address: native_asset_ethereum_address(),
+					caller: evm_address(),
+					apparent_value: U256::from(0),
+				},
+				code_address: native_asset_ethereum_address(),
+				is_static: false,
+			};
+
+			//Act
+			assert_ok!(CurrencyPrecompile::execute(&mut handle));
+			handle.input = decrease;
+			assert_ok!(CurrencyPrecompile::execute(&mut handle));
+
+			//Assert
+			assert_eq!(
+				CurrencyPrecompile::allowance_of(HDX, evm_address(), evm_address2()),
+				30u128 * UNITS
+			);
+		});
+	}
+
+	#[test]
+	fn precompile_for_currency_increase_allowance_should_saturate_allowance_when_amount_exceeds_balance_type() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			let increase = |value: U256| {
+				EvmDataWriter::new_with_selector(Function::IncreaseAllowance)
+					.write(Address::from(evm_address2()))
+					.write(value)
+					.build()
+			};
+
+			let mut handle = MockHandle {
+				input: increase(U256::from(50u128 * UNITS)),
+				context: Context {
+					address: native_asset_ethereum_address(),
+					caller: evm_address(),
+					apparent_value: U256::from(0),
+				},
+				code_address: native_asset_ethereum_address(),
+				is_static: false,
+			};
+
+			//Act
+			assert_ok!(CurrencyPrecompile::execute(&mut handle));
+			handle.input = increase(U256::MAX);
+			assert_ok!(CurrencyPrecompile::execute(&mut handle));
+
+			//Assert
+			assert_eq!(
+				CurrencyPrecompile::allowance_of(HDX, evm_address(), evm_address2()),
+				Balance::MAX
+			);
+		});
+	}
+
+	#[test]
+	fn precompile_for_currency_decrease_allowance_should_fail_when_below_zero() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			let data = EvmDataWriter::new_with_selector(Function::DecreaseAllowance)
+				.write(Address::from(evm_address2()))
+				.write(U256::from(UNITS))
+				.build();
+
+			let mut handle = MockHandle {
+				input: data,
+				context: Context {
+					address: native_asset_ethereum_address(),
+					caller: evm_address(),
+					apparent_value: U256::from(0),
+				},
+				code_address: native_asset_ethereum_address(),
+				is_static: false,
+			};
+
+			//Act & Assert
+			assert_noop!(
+				CurrencyPrecompile::execute(&mut handle),
+				PrecompileFailure::Revert {
+					exit_status: Reverted,
+					output: "ERC20: decreased allowance below zero".as_bytes().to_vec()
+				}
+			);
+		});
+	}
+
+	#[test]
+	fn precompile_for_currency_allowance_should_return_approved_amount() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			let approve = EvmDataWriter::new_with_selector(Function::Approve)
+				.write(Address::from(evm_address()))
+				.write(U256::from(50u128 * UNITS))
+				.build();
+
+			let mut handle = MockHandle {
+				input: approve,
+				context: Context {
+					address: native_asset_ethereum_address(),
+					caller: evm_address2(),
+					apparent_value: U256::from(0),
+				},
+				code_address: native_asset_ethereum_address(),
+				is_static: false,
+			};
+			assert_ok!(CurrencyPrecompile::execute(&mut handle));
+
+			handle.input = EvmDataWriter::new_with_selector(Function::Allowance)
+				.write(Address::from(evm_address2()))
+				.write(Address::from(evm_address()))
+				.build();
+			handle.is_static = true;
+
+			//Act
+			let result = CurrencyPrecompile::execute(&mut handle);
+
+			//Assert
+			assert_eq!(
+				result,
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint(50u128 * UNITS),
+				})
+			);
+		});
+	}
+
+	#[test]
+	fn precompile_for_currency_allowance_should_return_zero_for_not_approved_contract() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			let data = EvmDataWriter::new_with_selector(Function::Allowance)
+				.write(Address::from(evm_address2()))
+				.write(Address::from(evm_address()))
+				.build();
+
+			let mut handle = MockHandle {
+				input: data,
+				context: Context {
+					address: native_asset_ethereum_address(),
+					caller: evm_address(),
+					apparent_value: U256::from(0),
+				},
+				code_address: native_asset_ethereum_address(),
+				is_static: true,
+			};
+
+			//Act
+			let result = CurrencyPrecompile::execute(&mut handle);
+
+			//Assert
+			assert_eq!(
+				result,
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: hex!["0000000000000000000000000000000000000000000000000000000000000000"].to_vec()
+				})
+			);
+		});
+	}
+
+	#[test]
+	fn precompile_for_currency_allowance_should_return_max_for_approved_contract() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			assert_ok!(EVMAccounts::approve_contract(
+				hydradx_runtime::RuntimeOrigin::root(),
+				evm_address(),
+			));
+
+			let data = EvmDataWriter::new_with_selector(Function::Allowance)
+				.write(Address::from(evm_address2()))
+				.write(Address::from(evm_address()))
+				.build();
+
+			let mut handle = MockHandle {
+				input: data,
+				context: Context {
+					address: native_asset_ethereum_address(),
+					caller: evm_address(),
+					apparent_value: U256::from(0),
+				},
+				code_address: native_asset_ethereum_address(),
+				is_static: true,
+			};
+
+			//Act
+			let result = CurrencyPrecompile::execute(&mut handle);
+
+			//Assert
+			assert_eq!(
+				result,
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: hex!["00000000000000000000000000000000FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF"].to_vec()
+				})
+			);
+		});
+	}
+
+	#[test]
+	fn precompile_for_currency_allowance_should_return_zero_for_disapproved_contract() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			assert_ok!(EVMAccounts::approve_contract(
+				hydradx_runtime::RuntimeOrigin::root(),
+				evm_address(),
+			));
+			let data = EvmDataWriter::new_with_selector(Function::Allowance)
+				.write(Address::from(evm_address2()))
+				.write(Address::from(evm_address()))
+				.build();
+
+			let mut handle = MockHandle {
+				input: data,
+				context: Context {
+					address: native_asset_ethereum_address(),
+					caller: evm_address(),
+					apparent_value: U256::from(0),
+				},
+				code_address: native_asset_ethereum_address(),
+				is_static: true,
+			};
+
+			//Act
+			assert_ok!(EVMAccounts::disapprove_contract(
+				hydradx_runtime::RuntimeOrigin::root(),
+				evm_address(),
+			));
+			let result = CurrencyPrecompile::execute(&mut handle);
+
+			//Assert
+			assert_eq!(
+				result,
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: hex!["0000000000000000000000000000000000000000000000000000000000000000"].to_vec()
+				})
+			);
+		});
+	}
+
+	#[test]
+	fn precompile_for_transfer_from_should_fail_for_not_approved_contract() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			assert_ok!(hydradx_runtime::Currencies::update_balance(
+				hydradx_runtime::RuntimeOrigin::root(),
+				evm_account(),
+				HDX,
+				100 * UNITS as i128,
+			));
+
+			let data = EvmDataWriter::new_with_selector(Function::TransferFrom)
+				.write(Address::from(evm_address()))
+				.write(Address::from(evm_address2()))
+				.write(U256::from(50u128 * UNITS))
+				.build();
+
+			let mut handle = MockHandle {
+				input: data,
+				context: Context {
+					address: native_asset_ethereum_address(),
+					caller: native_asset_ethereum_address(),
+					apparent_value: U256::from(0),
+				},
+				code_address: native_asset_ethereum_address(),
+				is_static: false,
+			};
+
+			//Act & Assert
+			assert_noop!(
+				CurrencyPrecompile::execute(&mut handle),
+				PrecompileFailure::Revert {
+					exit_status: Reverted,
+					output: "ERC20: insufficient allowance".as_bytes().to_vec()
+				}
+			);
+			assert_balance!(evm_account2(), HDX, 0);
+		});
+	}
+
+	#[test]
+	fn precompile_for_transfer_from_should_spend_allowance() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			assert_ok!(hydradx_runtime::Currencies::update_balance(
+				hydradx_runtime::RuntimeOrigin::root(),
+				evm_account(),
+				HDX,
+				100 * UNITS as i128,
+			));
+
+			let approve = EvmDataWriter::new_with_selector(Function::Approve)
+				.write(Address::from(evm_address2()))
+				.write(U256::from(80u128 * UNITS))
+				.build();
+
+			let mut handle = MockHandle {
+				input: approve,
+				context: Context {
+					address: native_asset_ethereum_address(),
+					caller: evm_address(),
+					apparent_value: U256::from(0),
+				},
+				code_address: native_asset_ethereum_address(),
+				is_static: false,
+			};
+			assert_ok!(CurrencyPrecompile::execute(&mut handle));
+
+			handle.input = EvmDataWriter::new_with_selector(Function::TransferFrom)
+				.write(Address::from(evm_address()))
+				.write(Address::from(evm_address2()))
+				.write(U256::from(50u128 * UNITS))
+				.build();
+			handle.context.caller = evm_address2();
+
+			//Act
+			let result = CurrencyPrecompile::execute(&mut handle);
+
+			//Assert
+			assert_eq!(
+				result,
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: hex!["0000000000000000000000000000000000000000000000000000000000000001"].to_vec(),
+				})
+			);
+			assert_balance!(evm_account2(), HDX, 50u128 * UNITS);
+			assert_eq!(
+				CurrencyPrecompile::allowance_of(HDX, evm_address(), evm_address2()),
+				30u128 * UNITS
+			);
+
+			// Remaining allowance is not enough
+			assert_noop!(
+				CurrencyPrecompile::execute(&mut handle),
+				PrecompileFailure::Revert {
+					exit_status: Reverted,
+					output: "ERC20: insufficient allowance".as_bytes().to_vec()
+				}
+			);
+		});
+	}
+
+	#[test]
+	fn precompile_for_permit_should_set_allowance_and_increase_nonce() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			let owner = alith_evm_address();
+			let value = U256::from(50u128 * UNITS);
+			let deadline = U256::from(1000000000000u128);
+
+			let digest = CurrencyPrecompile::permit_digest(
+				HDX,
+				native_asset_ethereum_address(),
+				owner,
+				evm_address2(),
+				value,
+				U256::zero(),
+				deadline,
+			);
+			let secret_key = SecretKey::parse(&alith_secret_key()).unwrap();
+			let (rs, v) = sign(&Message::parse(&digest), &secret_key);
+
+			let data = EvmDataWriter::new_with_selector(Function::Permit)
+				.write(Address::from(owner))
+				.write(Address::from(evm_address2()))
+				.write(value)
+				.write(deadline)
+				.write(v.serialize())
+				.write(H256::from(rs.r.b32()))
+				.write(H256::from(rs.s.b32()))
+				.build();
+
+			let mut handle = MockHandle {
+				input: data.clone(),
+				context: Context {
+					address: native_asset_ethereum_address(),
+					caller: evm_address(),
+					apparent_value: U256::from(0),
+				},
+				code_address: native_asset_ethereum_address(),
+				is_static: false,
+			};
+
+			//Act
+			assert_ok!(CurrencyPrecompile::execute(&mut handle));
+
+			//Assert
+			assert_eq!(
+				CurrencyPrecompile::allowance_of(HDX, owner, evm_address2()),
+				50u128 * UNITS
+			);
+			handle.input = EvmDataWriter::new_with_selector(Function::Nonces)
+				.write(Address::from(owner))
+				.build();
+			assert_eq!(
+				CurrencyPrecompile::execute(&mut handle).unwrap().output,
+				Output::encode_uint(1u128)
+			);
+
+			// Permit can't be replayed
+			handle.input = data;
+			assert_noop!(
+				CurrencyPrecompile::execute(&mut handle),
+				PrecompileFailure::Revert {
+					exit_status: Reverted,
+					output: "ERC20Permit: invalid signature".as_bytes().to_vec()
+				}
+			);
+		});
+	}
+
+	#[test]
+	fn precompile_for_permit_should_fail_when_deadline_expired() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			pallet_timestamp::Now::<hydradx_runtime::Runtime>::put(10_000_000);
+
+			let data = EvmDataWriter::new_with_selector(Function::Permit)
+				.write(Address::from(alith_evm_address()))
+				.write(Address::from(evm_address2()))
+				.write(U256::from(UNITS))
+				.write(U256::from(1u128))
+				.write(27u8)
+				.write(H256::zero())
+				.write(H256::zero())
+				.build();
+
+			let mut handle = MockHandle {
+				input: data,
+				context: Context {
+					address: native_asset_ethereum_address(),
+					caller: evm_address(),
+					apparent_value: U256::from(0),
+				},
+				code_address: native_asset_ethereum_address(),
+				is_static: false,
+			};
+
+			//Act & Assert
+			assert_noop!(
+				CurrencyPrecompile::execute(&mut handle),
+				PrecompileFailure::Revert {
+					exit_status: Reverted,
+					output: "ERC20Permit: expired deadline".as_bytes().to_vec()
+				}
+			);
+		});
+	}
+
+	#[test]
+	fn precompile_for_permit_should_fail_when_signature_s_is_high() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			let data = EvmDataWriter::new_with_selector(Function::Permit)
+				.write(Address::from(alith_evm_address()))
+				.write(Address::from(evm_address2()))
+				.write(U256::from(UNITS))
+				.write(U256::from(1000000000000u128))
+				.write(27u8)
+				.write(H256::zero())
+				.write(H256::repeat_byte(0xff))
+				.build();
+
+			let mut handle = MockHandle {
+				input: data,
+				context: Context {
+					address: native_asset_ethereum_address(),
+					caller: evm_address(),
+					apparent_value: U256::from(0),
+				},
+				code_address: native_asset_ethereum_address(),
+				is_static: false,
+			};
+
+			//Act & Assert
+			assert_noop!(
+				CurrencyPrecompile::execute(&mut handle),
+				PrecompileFailure::Revert {
+					exit_status: Reverted,
+					output: "ERC20Permit: invalid signature 's' value".as_bytes().to_vec()
+				}
+			);
+		});
+	}
+
+	#[test]
+	fn precompile_for_transfer_from_is_allowed_for_approved_contract() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			assert_ok!(EVMAccounts::approve_contract(
+				hydradx_runtime::RuntimeOrigin::root(),
+				native_asset_ethereum_address(),
+			));
+
 			assert_ok!(hydradx_runtime::Currencies::update_balance(
 				hydradx_runtime::RuntimeOrigin::root(),
 				evm_account(),
-				HDX,
-				100 * UNITS as i128,
+				HDX,
+				100 * UNITS as i128,
+			));
+
+			let data = EvmDataWriter::new_with_selector(Function::TransferFrom)
+				.write(Address::from(evm_address()))
+				.write(Address::from(evm_address2()))
+				.write(U256::from(50u128 * UNITS))
+				.build();
+
+			let mut handle = MockHandle {
+				input: data,
+				context: Context {
+					address: native_asset_ethereum_address(),
+					caller: native_asset_ethereum_address(),
+					apparent_value: U256::from(0),
+				},
+				code_address: native_asset_ethereum_address(),
+				is_static: false,
+			};
+
+			//Act
+			let result = CurrencyPrecompile::execute(&mut handle);
+			// Assert
+			assert_ok!(result.clone());
+			assert_eq!(
+				result,
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: hex!["0000000000000000000000000000000000000000000000000000000000000001"].to_vec(),
+				})
+			);
+			assert_balance!(evm_account2(), HDX, 50u128 * UNITS);
+		});
+	}
+
+	fn account_to_default_evm_address(account_id: &impl Encode) -> EvmAddress {
+		let payload = (b"evm:", account_id);
+		EvmAddress::from_slice(&payload.using_encoded(blake2_256)[0..20])
+	}
+
+	pub fn alice_evm_addr() -> H160 {
+		account_to_default_evm_address(&ALICE)
+	}
+
+	pub fn alice_substrate_evm_addr() -> AccountId {
+		ExtendedAddressMapping::into_account_id(alice_evm_addr())
+	}
+}
+
+mod dex_precompile {
+	use super::*;
+	use fp_evm::ExitRevert::Reverted;
+	use fp_evm::PrecompileFailure;
+	use hydradx_runtime::evm::precompiles::{
+		dex::Function as DexFunction, erc20_mapping::HydraErc20Mapping, Output, DEX_ADDR,
+	};
+	use hydradx_traits::evm::Erc20Mapping;
+	use hydradx_traits::router::{AssetPair, RouteProvider, RouteSpotPriceProvider};
+	use pretty_assertions::assert_eq;
+
+	type AllHydraDXPrecompile = HydraDXPrecompiles<hydradx_runtime::Runtime>;
+
+	fn asset_address(asset_id: AssetId) -> Address {
+		Address(HydraErc20Mapping::encode_evm_address(asset_id))
+	}
+
+	fn dex_handle(data: Vec<u8>, is_static: bool) -> MockHandle {
+		MockHandle {
+			input: data,
+			context: Context {
+				address: DEX_ADDR,
+				caller: evm_address(),
+				apparent_value: U256::zero(),
+			},
+			code_address: DEX_ADDR,
+			is_static,
+		}
+	}
+
+	fn revert_with(reason: &str) -> Option<PrecompileResult> {
+		Some(Err(PrecompileFailure::Revert {
+			exit_status: Reverted,
+			output: reason.as_bytes().to_vec(),
+		}))
+	}
+
+	fn reverted_with_error(result: &Option<PrecompileResult>, error: &str) -> bool {
+		match result {
+			Some(Err(PrecompileFailure::Revert {
+				exit_status: Reverted,
+				output,
+			})) => String::from_utf8_lossy(output).contains(error),
+			_ => false,
+		}
+	}
+
+	fn succeed_with(amount: Balance) -> Option<PrecompileResult> {
+		Some(Ok(PrecompileOutput {
+			exit_status: ExitSucceed::Returned,
+			output: Output::encode_uint(amount),
+		}))
+	}
+
+	#[test]
+	fn sell_should_work() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			init_omnipool_with_oracle_for_block_10();
+			let amount_in = 10 * UNITS;
+			assert_ok!(Tokens::set_balance(
+				RawOrigin::Root.into(),
+				evm_account(),
+				DOT,
+				amount_in,
+				0,
+			));
+			let hdx_balance = Currencies::free_balance(HDX, &evm_account());
+
+			let data = EvmDataWriter::new_with_selector(DexFunction::Sell)
+				.write(asset_address(DOT))
+				.write(asset_address(HDX))
+				.write(amount_in)
+				.write(0u128)
+				.build();
+
+			//Act
+			let result = AllHydraDXPrecompile::new().execute(&mut dex_handle(data, false));
+
+			//Assert
+			let received = Currencies::free_balance(HDX, &evm_account()) - hdx_balance;
+			assert!(received > 0);
+			assert_eq!(result, succeed_with(received));
+			assert_eq!(Currencies::free_balance(DOT, &evm_account()), 0);
+		});
+	}
+
+	#[test]
+	fn sell_should_return_amount_calculated_by_get_amount_out() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			init_omnipool_with_oracle_for_block_10();
+			let amount_in = 10 * UNITS;
+			assert_ok!(Tokens::set_balance(
+				RawOrigin::Root.into(),
+				evm_account(),
+				DOT,
+				amount_in,
+				0,
+			));
+
+			let quote = EvmDataWriter::new_with_selector(DexFunction::GetAmountOut)
+				.write(asset_address(DOT))
+				.write(asset_address(HDX))
+				.write(amount_in)
+				.build();
+			let expected = AllHydraDXPrecompile::new().execute(&mut dex_handle(quote, true));
+
+			let data = EvmDataWriter::new_with_selector(DexFunction::Sell)
+				.write(asset_address(DOT))
+				.write(asset_address(HDX))
+				.write(amount_in)
+				.write(0u128)
+				.build();
+
+			//Act
+			let result = AllHydraDXPrecompile::new().execute(&mut dex_handle(data, false));
+
+			//Assert
+			assert!(matches!(expected, Some(Ok(_))));
+			assert_eq!(result, expected);
+		});
+	}
+
+	#[test]
+	fn sell_should_fail_when_min_amount_out_is_not_reached() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			init_omnipool_with_oracle_for_block_10();
+			let amount_in = 10 * UNITS;
+			assert_ok!(Tokens::set_balance(
+				RawOrigin::Root.into(),
+				evm_account(),
+				DOT,
+				amount_in,
+				0,
+			));
+
+			let data = EvmDataWriter::new_with_selector(DexFunction::Sell)
+				.write(asset_address(DOT))
+				.write(asset_address(HDX))
+				.write(amount_in)
+				.write(Balance::MAX)
+				.build();
+
+			//Act
+			let result = AllHydraDXPrecompile::new().execute(&mut dex_handle(data, false));
+
+			//Assert
+			assert!(reverted_with_error(&result, "TradingLimitReached"));
+			assert_eq!(Currencies::free_balance(DOT, &evm_account()), amount_in);
+		});
+	}
+
+	#[test]
+	fn sell_should_fail_when_router_sell_is_paused() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			init_omnipool_with_oracle_for_block_10();
+			let amount_in = 10 * UNITS;
+			assert_ok!(Tokens::set_balance(
+				RawOrigin::Root.into(),
+				evm_account(),
+				DOT,
+				amount_in,
+				0,
+			));
+			assert_ok!(TransactionPause::pause_transaction(
+				RuntimeOrigin::root(),
+				b"Router".to_vec(),
+				b"sell".to_vec()
+			));
+
+			let data = EvmDataWriter::new_with_selector(DexFunction::Sell)
+				.write(asset_address(DOT))
+				.write(asset_address(HDX))
+				.write(amount_in)
+				.write(0u128)
+				.build();
+
+			//Act
+			let result = AllHydraDXPrecompile::new().execute(&mut dex_handle(data, false));
+
+			//Assert
+			assert!(reverted_with_error(&result, "CallFiltered"));
+			assert_eq!(Currencies::free_balance(DOT, &evm_account()), amount_in);
+		});
+	}
+
+	#[test]
+	fn buy_should_fail_when_router_buy_is_paused() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			init_omnipool_with_oracle_for_block_10();
+			let amount_in = 100 * UNITS;
+			assert_ok!(Tokens::set_balance(
+				RawOrigin::Root.into(),
+				evm_account(),
+				DOT,
+				amount_in,
+				0,
+			));
+			assert_ok!(TransactionPause::pause_transaction(
+				RuntimeOrigin::root(),
+				b"Router".to_vec(),
+				b"buy".to_vec()
+			));
+
+			let data = EvmDataWriter::new_with_selector(DexFunction::Buy)
+				.write(asset_address(DOT))
+				.write(asset_address(HDX))
+				.write(UNITS)
+				.write(amount_in)
+				.build();
+
+			//Act
+			let result = AllHydraDXPrecompile::new().execute(&mut dex_handle(data, false));
+
+			//Assert
+			assert!(reverted_with_error(&result, "CallFiltered"));
+			assert_eq!(Currencies::free_balance(DOT, &evm_account()), amount_in);
+		});
+	}
+
+	#[test]
+	fn sell_should_fail_in_static_context() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			init_omnipool_with_oracle_for_block_10();
+
+			let data = EvmDataWriter::new_with_selector(DexFunction::Sell)
+				.write(asset_address(DOT))
+				.write(asset_address(HDX))
+				.write(10 * UNITS)
+				.write(0u128)
+				.build();
+
+			//Act
+			let result = AllHydraDXPrecompile::new().execute(&mut dex_handle(data, true));
+
+			//Assert
+			assert_eq!(result, revert_with("can't call non-static function in static context"));
+		});
+	}
+
+	#[test]
+	fn sell_should_fail_when_asset_address_is_invalid() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			init_omnipool_with_oracle_for_block_10();
+
+			let data = EvmDataWriter::new_with_selector(DexFunction::Sell)
+				.write(Address(evm_address()))
+				.write(asset_address(HDX))
+				.write(10 * UNITS)
+				.write(0u128)
+				.build();
+
+			//Act
+			let result = AllHydraDXPrecompile::new().execute(&mut dex_handle(data, false));
+
+			//Assert
+			assert_eq!(result, revert_with("invalid asset address"));
+		});
+	}
+
+	#[test]
+	fn sell_should_fail_when_assets_are_same() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			init_omnipool_with_oracle_for_block_10();
+
+			let data = EvmDataWriter::new_with_selector(DexFunction::Sell)
+				.write(asset_address(DOT))
+				.write(asset_address(DOT))
+				.write(10 * UNITS)
+				.write(0u128)
+				.build();
+
+			//Act
+			let result = AllHydraDXPrecompile::new().execute(&mut dex_handle(data, false));
+
+			//Assert
+			assert_eq!(result, revert_with("assets must be different"));
+		});
+	}
+
+	#[test]
+	fn buy_should_work() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			init_omnipool_with_oracle_for_block_10();
+			let dot_balance = 100 * UNITS;
+			let amount_out = 1_000 * UNITS;
+			assert_ok!(Tokens::set_balance(
+				RawOrigin::Root.into(),
+				evm_account(),
+				DOT,
+				dot_balance,
+				0,
 			));
+			let hdx_balance = Currencies::free_balance(HDX, &evm_account());
+
+			let quote = EvmDataWriter::new_with_selector(DexFunction::GetAmountIn)
+				.write(asset_address(DOT))
+				.write(asset_address(HDX))
+				.write(amount_out)
+				.build();
+			let expected = AllHydraDXPrecompile::new().execute(&mut dex_handle(quote, true));
+
+			let data = EvmDataWriter::new_with_selector(DexFunction::Buy)
+				.write(asset_address(DOT))
+				.write(asset_address(HDX))
+				.write(amount_out)
+				.write(dot_balance)
+				.build();
+
+			//Act
+			let result = AllHydraDXPrecompile::new().execute(&mut dex_handle(data, false));
+
+			//Assert
+			let spent = dot_balance - Currencies::free_balance(DOT, &evm_account());
+			assert_eq!(result, succeed_with(spent));
+			assert_eq!(result, expected);
+			assert_eq!(Currencies::free_balance(HDX, &evm_account()), hdx_balance + amount_out);
+		});
+	}
+
+	#[test]
+	fn spot_price_should_return_price_of_route() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			init_omnipool_with_oracle_for_block_10();
+			let route = hydradx_runtime::Router::get_route(AssetPair::new(DOT, HDX));
+			let expected = hydradx_runtime::Router::spot_price_with_fee(&route).unwrap();
+
+			let data = EvmDataWriter::new_with_selector(DexFunction::SpotPrice)
+				.write(asset_address(DOT))
+				.write(asset_address(HDX))
+				.build();
+
+			//Act
+			let result = AllHydraDXPrecompile::new().execute(&mut dex_handle(data, true));
+
+			//Assert
+			assert_eq!(result, succeed_with(expected.into_inner()));
+		});
+	}
+}
+
+mod oracle_precompile {
+	use super::*;
+	use fp_evm::ExitRevert::Reverted;
+	use fp_evm::PrecompileFailure;
+	use frame_support::traits::Time;
+	use hydradx_adapters::OraclePriceProvider;
+	use hydradx_runtime::evm::precompiles::chainlink_adapter::{
+		encode_oracle_address, Function as OracleFunction, DECIMALS,
+	};
+	use hydradx_runtime::evm::precompiles::Output;
+	use hydradx_runtime::EmaOracle;
+	use hydradx_traits::{OraclePeriod, PriceOracle};
+	use pallet_ema_oracle::ordered_pair;
+	use pretty_assertions::assert_eq;
+	use primitives::constants::{chain::OMNIPOOL_SOURCE, time::SECS_PER_BLOCK};
+
+	type AllHydraDXPrecompile = HydraDXPrecompiles<hydradx_runtime::Runtime>;
+
+	fn set_decimals(asset_id: AssetId, decimals: u8) {
+		assert_ok!(AssetRegistry::update(
+			hydradx_runtime::RuntimeOrigin::root(),
+			asset_id,
+			None,
+			None,
+			None,
+			None,
+			None,
+			None,
+			Some(decimals),
+			None,
+		));
+	}
+
+	fn last_oracle_update(asset_id: AssetId) -> BlockNumber {
+		EmaOracle::oracle((OMNIPOOL_SOURCE, ordered_pair(asset_id, LRNA), OraclePeriod::LastBlock))
+			.unwrap()
+			.0
+			.updated_at
+	}
+
+	fn oracle_handle(address: H160, data: Vec<u8>) -> MockHandle {
+		MockHandle {
+			input: data,
+			context: Context {
+				address,
+				caller: evm_address(),
+				apparent_value: U256::zero(),
+			},
+			code_address: address,
+			is_static: true,
+		}
+	}
+
+	#[test]
+	fn decimals_should_work() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			let address = encode_oracle_address(DOT, LRNA, OraclePeriod::TenMinutes);
+			let data = EvmDataWriter::new_with_selector(OracleFunction::Decimals).build();
+
+			//Act
+			let result = AllHydraDXPrecompile::new().execute(&mut oracle_handle(address, data));
+
+			//Assert
+			assert_eq!(
+				result,
+				Some(Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint(DECIMALS),
+				}))
+			);
+		});
+	}
+
+	#[test]
+	fn latest_round_data_should_return_price_composed_through_lrna() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			init_omnipool_with_oracle_for_block_10();
+			set_decimals(DOT, 10);
+			set_decimals(WETH, 18);
+			hydradx_runtime::Timestamp::set_timestamp(1_700_000_000_000);
+			let address = encode_oracle_address(DOT, WETH, OraclePeriod::TenMinutes);
+			let data = EvmDataWriter::new_with_selector(OracleFunction::LatestRoundData).build();
+
+			let route = [Trade {
+				pool: PoolType::Omnipool,
+				asset_in: DOT,
+				asset_out: WETH,
+			}];
+			let price = OraclePriceProvider::<AssetId, EmaOracle, hydradx_runtime::LRNA>::price(
+				&route,
+				OraclePeriod::TenMinutes,
+			)
+			.unwrap();
+			let answer = U256::from(price.d) * U256::from(10).pow(U256::from(10 + DECIMALS))
+				/ (U256::from(price.n) * U256::from(10).pow(U256::from(18)));
+			let updated_in = last_oracle_update(DOT).min(last_oracle_update(WETH));
+			let round_id = U256::from(updated_in);
+			let blocks_since_update = (hydradx_runtime::System::block_number() - updated_in) as u64;
+			let updated_at =
+				U256::from(<hydradx_runtime::Timestamp as Time>::now() / 1000 - blocks_since_update * SECS_PER_BLOCK);
+
+			//Act
+			let result = AllHydraDXPrecompile::new().execute(&mut oracle_handle(address, data));
+
+			//Assert
+			let expected = EvmDataWriter::new()
+				.write(round_id)
+				.write(answer)
+				.write(updated_at)
+				.write(updated_at)
+				.write(round_id)
+				.build();
+			assert_eq!(
+				result,
+				Some(Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: expected,
+				}))
+			);
+		});
+	}
+
+	#[test]
+	fn latest_round_data_should_report_last_oracle_update_when_pair_is_not_traded() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			init_omnipool_with_oracle_for_block_10();
+			set_decimals(DOT, 10);
+			set_decimals(WETH, 18);
+			hydradx_runtime::Timestamp::set_timestamp(1_700_000_000_000);
+			let address = encode_oracle_address(DOT, WETH, OraclePeriod::TenMinutes);
+			let data = EvmDataWriter::new_with_selector(OracleFunction::LatestRoundData).build();
+
+			hydradx_run_to_next_block();
+			let updated_in = last_oracle_update(DOT).min(last_oracle_update(WETH));
+			for _ in 0..5 {
+				hydradx_run_to_next_block();
+			}
+
+			//Act
+			let result = AllHydraDXPrecompile::new().execute(&mut oracle_handle(address, data));
+
+			//Assert
+			let output = result.unwrap().unwrap().output;
+			let round_id = U256::from_big_endian(&output[0..32]);
+			let updated_at = U256::from_big_endian(&output[96..128]);
+			let now = U256::from(<hydradx_runtime::Timestamp as Time>::now() / 1000);
+			assert_eq!(round_id, U256::from(updated_in));
+			assert_eq!(
+				updated_at,
+				now - U256::from((hydradx_runtime::System::block_number() - updated_in) as u64 * SECS_PER_BLOCK)
+			);
+			assert!(updated_at < now);
+		});
+	}
+
+	#[test]
+	fn get_round_data_should_fail_when_round_is_not_latest() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			init_omnipool_with_oracle_for_block_10();
+			set_decimals(DOT, 10);
+			set_decimals(LRNA, 12);
+			let address = encode_oracle_address(DOT, LRNA, OraclePeriod::TenMinutes);
+			let data = EvmDataWriter::new_with_selector(OracleFunction::GetRoundData)
+				.write(U256::from(1))
+				.build();
+
+			//Act
+			let result = AllHydraDXPrecompile::new().execute(&mut oracle_handle(address, data));
+
+			//Assert
+			assert_eq!(
+				result,
+				Some(Err(PrecompileFailure::Revert {
+					exit_status: Reverted,
+					output: "No data present".as_bytes().to_vec(),
+				}))
+			);
+		});
+	}
+
+	#[test]
+	fn latest_round_data_should_fail_when_oracle_does_not_exist() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			let address = encode_oracle_address(DOT, WETH, OraclePeriod::TenMinutes);
+			let data = EvmDataWriter::new_with_selector(OracleFunction::LatestRoundData).build();
+
+			//Act
+			let result = AllHydraDXPrecompile::new().execute(&mut oracle_handle(address, data));
+
+			//Assert
+			assert_eq!(
+				result,
+				Some(Err(PrecompileFailure::Revert {
+					exit_status: Reverted,
+					output: "No data present".as_bytes().to_vec(),
+				}))
+			);
+		});
+	}
+}
+
+mod staking_precompile {
+	use super::*;
+	use fp_evm::ExitRevert::Reverted;
+	use fp_evm::PrecompileFailure;
+	use hydradx_runtime::evm::precompiles::{staking::Function as StakingFunction, Output, STAKING_ADDR};
+	use hydradx_runtime::Staking;
+	use pretty_assertions::assert_eq;
+
+	type AllHydraDXPrecompile = HydraDXPrecompiles<hydradx_runtime::Runtime>;
+
+	fn staking_handle(data: Vec<u8>, is_static: bool) -> MockHandle {
+		MockHandle {
+			input: data,
+			context: Context {
+				address: STAKING_ADDR,
+				caller: evm_address(),
+				apparent_value: U256::zero(),
+			},
+			code_address: STAKING_ADDR,
+			is_static,
+		}
+	}
+
+	fn init_staking() {
+		assert_ok!(Staking::initialize_staking(RawOrigin::Root.into()));
+		assert_ok!(Currencies::update_balance(
+			RawOrigin::Root.into(),
+			Staking::pot_account_id(),
+			HDX,
+			(10_000 * UNITS) as i128,
+		));
+		assert_ok!(Balances::force_set_balance(
+			RawOrigin::Root.into(),
+			evm_account(),
+			1_000_000 * UNITS,
+		));
+	}
+
+	fn stake(amount: Balance) -> u128 {
+		let data = EvmDataWriter::new_with_selector(StakingFunction::Stake)
+			.write(amount)
+			.build();
+		assert!(matches!(
+			AllHydraDXPrecompile::new().execute(&mut staking_handle(data, false)),
+			Some(Ok(_))
+		));
+
+		Staking::get_user_position_id(&evm_account()).unwrap().unwrap()
+	}
 
-			let data = EvmDataWriter::new_with_selector(Function::Approve)
-				.write(Address::from(evm_address2()))
-				.write(U256::from(50u128 * UNITS))
-				.build();
+	#[test]
+	fn stake_should_create_position_of_caller() {
+		TestNet::reset();
 
-			let mut handle = MockHandle {
-				input: data,
-				context: Context {
-					address: evm_address(),
-					caller: native_asset_ethereum_address(),
-					apparent_value: U256::from(0),
-				},
-				code_address: native_asset_ethereum_address(),
-				is_static: true,
-			};
+		Hydra::execute_with(|| {
+			//Arrange
+			init_staking();
+			let data = EvmDataWriter::new_with_selector(StakingFunction::Stake)
+				.write(1_000 * UNITS)
+				.build();
 
 			//Act
-			let result = CurrencyPrecompile::execute(&mut handle);
+			let result = AllHydraDXPrecompile::new().execute(&mut staking_handle(data, false));
 
 			//Assert
 			assert_eq!(
 				result,
-				Err(PrecompileFailure::Error {
-					exit_status: pallet_evm::ExitError::Other("not supported".into())
-				})
+				Some(Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: vec![],
+				}))
 			);
+			let position_id = Staking::get_user_position_id(&evm_account()).unwrap().unwrap();
+			assert_eq!(Staking::get_position(position_id).unwrap().get_stake(), 1_000 * UNITS);
 		});
 	}
 
 	#[test]
-	fn precompile_for_currency_allowance_should_return_zero_for_not_approved_contract() {
+	fn stake_should_fail_when_amount_is_below_min_stake() {
 		TestNet::reset();
 
 		Hydra::execute_with(|| {
 			//Arrange
-			let data = EvmDataWriter::new_with_selector(Function::Allowance)
-				.write(Address::from(evm_address2()))
-				.write(Address::from(evm_address()))
+			init_staking();
+			let data = EvmDataWriter::new_with_selector(StakingFunction::Stake)
+				.write(UNITS)
 				.build();
 
-			let mut handle = MockHandle {
-				input: data,
-				context: Context {
-					address: native_asset_ethereum_address(),
-					caller: evm_address(),
-					apparent_value: U256::from(0),
-				},
-				code_address: native_asset_ethereum_address(),
-				is_static: true,
-			};
+			//Act
+			let result = AllHydraDXPrecompile::new().execute(&mut staking_handle(data, false));
+
+			//Assert
+			assert!(matches!(
+				result,
+				Some(Err(PrecompileFailure::Revert {
+					exit_status: Reverted,
+					..
+				}))
+			));
+			assert_eq!(Staking::get_user_position_id(&evm_account()).unwrap(), None);
+		});
+	}
+
+	#[test]
+	fn stake_should_fail_in_static_context() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			init_staking();
+			let data = EvmDataWriter::new_with_selector(StakingFunction::Stake)
+				.write(1_000 * UNITS)
+				.build();
 
 			//Act
-			let result = CurrencyPrecompile::execute(&mut handle);
+			let result = AllHydraDXPrecompile::new().execute(&mut staking_handle(data, true));
 
 			//Assert
 			assert_eq!(
 				result,
-				Ok(PrecompileOutput {
-					exit_status: ExitSucceed::Returned,
-					output: hex!["0000000000000000000000000000000000000000000000000000000000000000"].to_vec()
-				})
+				Some(Err(PrecompileFailure::Revert {
+					exit_status: Reverted,
+					output: "can't call non-static function in static context".as_bytes().to_vec(),
+				}))
 			);
 		});
 	}
 
 	#[test]
-	fn precompile_for_currency_allowance_should_return_max_for_approved_contract() {
+	fn increase_stake_should_work() {
 		TestNet::reset();
 
 		Hydra::execute_with(|| {
 			//Arrange
-			assert_ok!(EVMAccounts::approve_contract(
-				hydradx_runtime::RuntimeOrigin::root(),
-				evm_address(),
-			));
+			init_staking();
+			let position_id = stake(1_000 * UNITS);
+			let data = EvmDataWriter::new_with_selector(StakingFunction::IncreaseStake)
+				.write(position_id)
+				.write(500 * UNITS)
+				.build();
 
-			let data = EvmDataWriter::new_with_selector(Function::Allowance)
-				.write(Address::from(evm_address2()))
-				.write(Address::from(evm_address()))
+			//Act
+			let result = AllHydraDXPrecompile::new().execute(&mut staking_handle(data, false));
+
+			//Assert
+			assert!(matches!(result, Some(Ok(_))));
+			assert_eq!(Staking::get_position(position_id).unwrap().get_stake(), 1_500 * UNITS);
+		});
+	}
+
+	#[test]
+	fn unstake_should_remove_position() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			init_staking();
+			let position_id = stake(1_000 * UNITS);
+			let data = EvmDataWriter::new_with_selector(StakingFunction::Unstake)
+				.write(position_id)
 				.build();
 
-			let mut handle = MockHandle {
-				input: data,
-				context: Context {
-					address: native_asset_ethereum_address(),
-					caller: evm_address(),
-					apparent_value: U256::from(0),
-				},
-				code_address: native_asset_ethereum_address(),
-				is_static: true,
-			};
+			//Act
+			let result = AllHydraDXPrecompile::new().execute(&mut staking_handle(data, false));
+
+			//Assert
+			assert!(matches!(result, Some(Ok(_))));
+			assert_eq!(Staking::get_user_position_id(&evm_account()).unwrap(), None);
+		});
+	}
+
+	#[test]
+	fn get_position_id_should_return_position_of_owner() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			init_staking();
+			let position_id = stake(1_000 * UNITS);
+			let data = EvmDataWriter::new_with_selector(StakingFunction::GetPositionId)
+				.write(Address(evm_address()))
+				.build();
 
 			//Act
-			let result = CurrencyPrecompile::execute(&mut handle);
+			let result = AllHydraDXPrecompile::new().execute(&mut staking_handle(data, true));
 
 			//Assert
 			assert_eq!(
 				result,
-				Ok(PrecompileOutput {
+				Some(Ok(PrecompileOutput {
 					exit_status: ExitSucceed::Returned,
-					output: hex!["00000000000000000000000000000000FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF"].to_vec()
-				})
+					output: Output::encode_uint(position_id),
+				}))
 			);
 		});
 	}
 
 	#[test]
-	fn precompile_for_currency_allowance_should_return_zero_for_disapproved_contract() {
+	fn get_position_should_return_stake_points_and_rewards() {
 		TestNet::reset();
 
 		Hydra::execute_with(|| {
 			//Arrange
-			assert_ok!(EVMAccounts::approve_contract(
-				hydradx_runtime::RuntimeOrigin::root(),
-				evm_address(),
-			));
-			let data = EvmDataWriter::new_with_selector(Function::Allowance)
-				.write(Address::from(evm_address2()))
-				.write(Address::from(evm_address()))
+			init_staking();
+			let position_id = stake(1_000 * UNITS);
+			let (points, claimable, unclaimable) = Staking::get_position_points_and_rewards(position_id).unwrap();
+			let data = EvmDataWriter::new_with_selector(StakingFunction::GetPosition)
+				.write(position_id)
 				.build();
 
-			let mut handle = MockHandle {
-				input: data,
-				context: Context {
-					address: native_asset_ethereum_address(),
-					caller: evm_address(),
-					apparent_value: U256::from(0),
-				},
-				code_address: native_asset_ethereum_address(),
-				is_static: true,
-			};
-
 			//Act
-			assert_ok!(EVMAccounts::disapprove_contract(
-				hydradx_runtime::RuntimeOrigin::root(),
-				evm_address(),
-			));
-			let result = CurrencyPrecompile::execute(&mut handle);
+			let result = AllHydraDXPrecompile::new().execute(&mut staking_handle(data, true));
 
 			//Assert
 			assert_eq!(
 				result,
-				Ok(PrecompileOutput {
+				Some(Ok(PrecompileOutput {
 					exit_status: ExitSucceed::Returned,
-					output: hex!["0000000000000000000000000000000000000000000000000000000000000000"].to_vec()
-				})
+					output: EvmDataWriter::new()
+						.write(U256::from(1_000 * UNITS))
+						.write(U256::from(1_000 * UNITS))
+						.write(U256::from(points))
+						.write(U256::from(claimable))
+						.write(U256::from(unclaimable))
+						.build(),
+				}))
 			);
 		});
 	}
+}
+
+mod democracy_precompile {
+	use super::*;
+	use fp_evm::ExitRevert::Reverted;
+	use fp_evm::PrecompileFailure;
+	use frame_support::traits::StorePreimage;
+	use hydradx_runtime::evm::precompiles::{democracy::Function as DemocracyFunction, DEMOCRACY_ADDR};
+	use hydradx_runtime::{Democracy, Preimage};
+	use pallet_democracy::{AccountVote, Conviction, ReferendumIndex, Vote, VoteThreshold, Voting};
+	use pretty_assertions::assert_eq;
+
+	type AllHydraDXPrecompile = HydraDXPrecompiles<hydradx_runtime::Runtime>;
+
+	fn democracy_handle(data: Vec<u8>) -> MockHandle {
+		MockHandle {
+			input: data,
+			context: Context {
+				address: DEMOCRACY_ADDR,
+				caller: evm_address(),
+				apparent_value: U256::zero(),
+			},
+			code_address: DEMOCRACY_ADDR,
+			is_static: false,
+		}
+	}
+
+	fn start_referendum() -> ReferendumIndex {
+		let call = RuntimeCall::Balances(pallet_balances::Call::force_set_balance {
+			who: CHARLIE.into(),
+			new_free: 2,
+		});
+		Democracy::internal_start_referendum(Preimage::bound(call).unwrap(), VoteThreshold::SuperMajorityApprove, 0)
+	}
+
+	fn vote_data(ref_index: ReferendumIndex, conviction: u8) -> Vec<u8> {
+		EvmDataWriter::new_with_selector(DemocracyFunction::Vote)
+			.write(ref_index)
+			.write(true)
+			.write(10 * UNITS)
+			.write(conviction)
+			.build()
+	}
+
+	fn votes_of_caller() -> Vec<(ReferendumIndex, AccountVote<Balance>)> {
+		match pallet_democracy::VotingOf::<hydradx_runtime::Runtime>::get(evm_account()) {
+			Voting::Direct { votes, .. } => votes.into_inner(),
+			Voting::Delegating { .. } => vec![],
+		}
+	}
 
 	#[test]
-	fn precompile_for_transfer_from_should_fail_for_not_approved_contract() {
+	fn vote_should_work() {
 		TestNet::reset();
 
 		Hydra::execute_with(|| {
 			//Arrange
-			assert_ok!(hydradx_runtime::Currencies::update_balance(
-				hydradx_runtime::RuntimeOrigin::root(),
+			assert_ok!(Balances::force_set_balance(
+				RawOrigin::Root.into(),
 				evm_account(),
-				HDX,
-				100 * UNITS as i128,
+				1_000 * UNITS,
 			));
+			let ref_index = start_referendum();
 
-			let data = EvmDataWriter::new_with_selector(Function::TransferFrom)
-				.write(Address::from(evm_address()))
-				.write(Address::from(evm_address2()))
-				.write(U256::from(50u128 * UNITS))
-				.build();
-
-			let mut handle = MockHandle {
-				input: data,
-				context: Context {
-					address: native_asset_ethereum_address(),
-					caller: native_asset_ethereum_address(),
-					apparent_value: U256::from(0),
-				},
-				code_address: native_asset_ethereum_address(),
-				is_static: false,
-			};
+			//Act
+			let result = AllHydraDXPrecompile::new().execute(&mut democracy_handle(vote_data(ref_index, 2)));
 
-			//Act & Assert
-			assert_noop!(
-				CurrencyPrecompile::execute(&mut handle),
-				PrecompileFailure::Revert {
-					exit_status: Reverted,
-					output: "Not approved contract".as_bytes().to_vec()
-				}
+			//Assert
+			assert!(matches!(result, Some(Ok(_))));
+			assert_eq!(
+				votes_of_caller(),
+				vec![(
+					ref_index,
+					AccountVote::Standard {
+						vote: Vote {
+							aye: true,
+							conviction: Conviction::Locked2x,
+						},
+						balance: 10 * UNITS,
+					}
+				)]
 			);
-			assert_balance!(evm_account2(), HDX, 0);
 		});
 	}
 
 	#[test]
-	fn precompile_for_transfer_from_is_allowed_for_approved_contract() {
+	fn vote_should_fail_when_conviction_is_invalid() {
 		TestNet::reset();
 
 		Hydra::execute_with(|| {
 			//Arrange
-			assert_ok!(EVMAccounts::approve_contract(
-				hydradx_runtime::RuntimeOrigin::root(),
-				native_asset_ethereum_address(),
-			));
-
-			assert_ok!(hydradx_runtime::Currencies::update_balance(
-				hydradx_runtime::RuntimeOrigin::root(),
+			assert_ok!(Balances::force_set_balance(
+				RawOrigin::Root.into(),
 				evm_account(),
-				HDX,
-				100 * UNITS as i128,
+				1_000 * UNITS,
 			));
-
-			let data = EvmDataWriter::new_with_selector(Function::TransferFrom)
-				.write(Address::from(evm_address()))
-				.write(Address::from(evm_address2()))
-				.write(U256::from(50u128 * UNITS))
-				.build();
-
-			let mut handle = MockHandle {
-				input: data,
-				context: Context {
-					address: native_asset_ethereum_address(),
-					caller: native_asset_ethereum_address(),
-					apparent_value: U256::from(0),
-				},
-				code_address: native_asset_ethereum_address(),
-				is_static: false,
-			};
+			let ref_index = start_referendum();
 
 			//Act
-			let result = CurrencyPrecompile::execute(&mut handle);
-			// Assert
-			assert_ok!(result.clone());
+			let result = AllHydraDXPrecompile::new().execute(&mut democracy_handle(vote_data(ref_index, 7)));
+
+			//Assert
 			assert_eq!(
 				result,
-				Ok(PrecompileOutput {
-					exit_status: ExitSucceed::Returned,
-					output: hex!["0000000000000000000000000000000000000000000000000000000000000001"].to_vec(),
-				})
+				Some(Err(PrecompileFailure::Revert {
+					exit_status: Reverted,
+					output: "invalid conviction".as_bytes().to_vec(),
+				}))
 			);
-			assert_balance!(evm_account2(), HDX, 50u128 * UNITS);
+			assert!(votes_of_caller().is_empty());
 		});
 	}
 
-	fn account_to_default_evm_address(account_id: &impl Encode) -> EvmAddress {
-		let payload = (b"evm:", account_id);
-		EvmAddress::from_slice(&payload.using_encoded(blake2_256)[0..20])
-	}
+	#[test]
+	fn remove_vote_should_work() {
+		TestNet::reset();
 
-	pub fn alice_evm_addr() -> H160 {
-		account_to_default_evm_address(&ALICE)
-	}
+		Hydra::execute_with(|| {
+			//Arrange
+			assert_ok!(Balances::force_set_balance(
+				RawOrigin::Root.into(),
+				evm_account(),
+				1_000 * UNITS,
+			));
+			let ref_index = start_referendum();
+			assert!(matches!(
+				AllHydraDXPrecompile::new().execute(&mut democracy_handle(vote_data(ref_index, 0))),
+				Some(Ok(_))
+			));
+			let data = EvmDataWriter::new_with_selector(DemocracyFunction::RemoveVote)
+				.write(ref_index)
+				.build();
 
-	pub fn alice_substrate_evm_addr() -> AccountId {
-		ExtendedAddressMapping::into_account_id(alice_evm_addr())
+			//Act
+			let result = AllHydraDXPrecompile::new().execute(&mut democracy_handle(data));
+
+			//Assert
+			assert!(matches!(result, Some(Ok(_))));
+			assert!(votes_of_caller().is_empty());
+		});
 	}
 }
 
@@ -1950,11 +3341,11 @@ impl PrecompileHandle for MockHandle {
 	fn refund_external_cost(&mut self, _ref_time: Option<u64>, _proof_size: Option<u64>) {}
 
 	fn remaining_gas(&self) -> u64 {
-		unimplemented!()
+		u64::MAX
 	}
 
 	fn log(&mut self, _: H160, _: Vec<H256>, _: Vec<u8>) -> Result<(), ExitError> {
-		unimplemented!()
+		Ok(())
 	}
 
 	fn code_address(&self) -> H160 {
@@ -5,6 +5,7 @@ mod asset_registry;
 mod bonds;
 mod call_filter;
 mod circuit_breaker;
+mod concentrated_liquidity;
 mod contracts;
 mod cross_chain_transfer;
 mod dca;
@@ -30,6 +31,7 @@ mod referrals;
 mod router;
 mod staking;
 mod transact_call_filter;
+mod treasury;
 pub mod utils;
 mod vesting;
 mod xyk;
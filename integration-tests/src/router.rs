@@ -5453,6 +5453,9 @@ fn create_lbp_pool(accumulated_asset: u32, distributed_asset: u32) {
 		(2, 1_000),
 		CHARLIE.into(),
 		0,
+		None,
+		false,
+		None,
 	));
 
 	let account_id = get_lbp_pair_account_id(accumulated_asset, distributed_asset);
@@ -5468,6 +5471,10 @@ fn create_lbp_pool(accumulated_asset: u32, distributed_asset: u32) {
 		None,
 		None,
 		None,
+		None,
+		None,
+		None,
+		None,
 	));
 }
 
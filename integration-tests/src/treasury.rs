@@ -0,0 +1,211 @@
+#![cfg(test)]
+
+use crate::evm::init_omnipool_with_oracle_for_block_10;
+use crate::polkadot_test_net::*;
+use frame_support::traits::Hooks;
+use frame_support::weights::Weight;
+use frame_support::{assert_noop, assert_ok};
+use frame_system::RawOrigin;
+use hydradx_runtime::{
+	Balances, CouncilCollective, Currencies, Runtime, RuntimeOrigin, Tokens, Treasury, TreasuryAccount,
+	TreasuryConversion,
+};
+use orml_traits::MultiCurrency;
+use primitives::AccountId;
+use xcm_emulator::TestExt;
+
+fn council_origin(approvals: u32, members: u32) -> RuntimeOrigin {
+	pallet_collective::RawOrigin::<AccountId, CouncilCollective>::Members(approvals, members).into()
+}
+
+fn fund_treasury(asset_id: AssetId, amount: Balance) {
+	if asset_id == HDX {
+		assert_ok!(Balances::force_set_balance(
+			RawOrigin::Root.into(),
+			TreasuryAccount::get(),
+			amount,
+		));
+	} else {
+		assert_ok!(Tokens::set_balance(
+			RawOrigin::Root.into(),
+			TreasuryAccount::get(),
+			asset_id,
+			amount,
+			0,
+		));
+	}
+}
+
+#[test]
+fn spend_should_pay_out_native_asset() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		//Arrange
+		fund_treasury(HDX, 1_000_000 * UNITS);
+		let bob_balance = Currencies::free_balance(HDX, &AccountId::from(BOB));
+
+		//Act
+		assert_ok!(Treasury::spend(
+			RawOrigin::Root.into(),
+			Box::new(HDX),
+			100_000 * UNITS,
+			Box::new(BOB.into()),
+			None,
+		));
+		assert_ok!(Treasury::payout(RuntimeOrigin::signed(BOB.into()), 0));
+
+		//Assert
+		assert_eq!(
+			Currencies::free_balance(HDX, &AccountId::from(BOB)),
+			bob_balance + 100_000 * UNITS
+		);
+	});
+}
+
+#[test]
+fn spend_should_pay_out_registry_asset() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		//Arrange
+		init_omnipool_with_oracle_for_block_10();
+		fund_treasury(DAI, 1_000 * UNITS);
+		let bob_balance = Currencies::free_balance(DAI, &AccountId::from(BOB));
+
+		//Act
+		assert_ok!(Treasury::spend(
+			council_origin(3, 5),
+			Box::new(DAI),
+			100 * UNITS,
+			Box::new(BOB.into()),
+			None,
+		));
+		assert_ok!(Treasury::payout(RuntimeOrigin::signed(BOB.into()), 0));
+
+		//Assert
+		assert_eq!(
+			Currencies::free_balance(DAI, &AccountId::from(BOB)),
+			bob_balance + 100 * UNITS
+		);
+		assert_eq!(Currencies::free_balance(DAI, &TreasuryAccount::get()), 900 * UNITS);
+	});
+}
+
+#[test]
+fn spend_should_fail_when_value_exceeds_limit_of_origin() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		//Arrange
+		fund_treasury(HDX, 10_000_000 * UNITS);
+
+		//Act & Assert
+		assert_noop!(
+			Treasury::spend(
+				council_origin(3, 5),
+				Box::new(HDX),
+				2_000_000 * UNITS,
+				Box::new(BOB.into()),
+				None,
+			),
+			pallet_treasury::Error::<Runtime>::InsufficientPermission
+		);
+	});
+}
+
+#[test]
+fn spend_should_be_allowed_up_to_limit_of_higher_tier() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		//Arrange
+		fund_treasury(HDX, 10_000_000 * UNITS);
+
+		//Act & Assert
+		assert_ok!(Treasury::spend(
+			council_origin(4, 5),
+			Box::new(HDX),
+			2_000_000 * UNITS,
+			Box::new(BOB.into()),
+			None,
+		));
+	});
+}
+
+#[test]
+fn spend_should_fail_when_price_of_asset_is_not_available() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		//Arrange
+		fund_treasury(DAI, 1_000 * UNITS);
+
+		//Act & Assert
+		assert_noop!(
+			Treasury::spend(
+				council_origin(3, 5),
+				Box::new(DAI),
+				100 * UNITS,
+				Box::new(BOB.into()),
+				None,
+			),
+			pallet_treasury::Error::<Runtime>::FailedToConvertBalance
+		);
+	});
+}
+
+#[test]
+fn on_idle_should_convert_treasury_asset_to_target_asset_when_threshold_is_reached() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		//Arrange
+		init_omnipool_with_oracle_for_block_10();
+		fund_treasury(DAI, 1_000 * UNITS);
+		let treasury_hdx_balance = Currencies::free_balance(HDX, &TreasuryAccount::get());
+
+		assert_ok!(TreasuryConversion::set_target_asset(RawOrigin::Root.into(), HDX));
+		assert_ok!(TreasuryConversion::set_threshold(
+			RawOrigin::Root.into(),
+			DAI,
+			100 * UNITS
+		));
+
+		//Act
+		TreasuryConversion::on_idle(hydradx_runtime::System::block_number(), Weight::MAX);
+
+		//Assert
+		assert!(Currencies::free_balance(DAI, &TreasuryAccount::get()) < 100 * UNITS);
+		assert!(Currencies::free_balance(HDX, &TreasuryAccount::get()) > treasury_hdx_balance);
+	});
+}
+
+#[test]
+fn on_idle_should_not_convert_treasury_asset_when_threshold_is_not_reached() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		//Arrange
+		init_omnipool_with_oracle_for_block_10();
+		fund_treasury(DAI, 1_000 * UNITS);
+		let treasury_hdx_balance = Currencies::free_balance(HDX, &TreasuryAccount::get());
+
+		assert_ok!(TreasuryConversion::set_target_asset(RawOrigin::Root.into(), HDX));
+		assert_ok!(TreasuryConversion::set_threshold(
+			RawOrigin::Root.into(),
+			DAI,
+			10_000 * UNITS
+		));
+
+		//Act
+		TreasuryConversion::on_idle(hydradx_runtime::System::block_number(), Weight::MAX);
+
+		//Assert
+		assert_eq!(Currencies::free_balance(DAI, &TreasuryAccount::get()), 1_000 * UNITS);
+		assert_eq!(
+			Currencies::free_balance(HDX, &TreasuryAccount::get()),
+			treasury_hdx_balance
+		);
+	});
+}
@@ -196,6 +196,9 @@ fn creating_xyk_pool_should_fail_when_lbp_pool_is_running() {
 			(2, 1_000),
 			ALICE.into(),
 			0,
+			None,
+			false,
+			None,
 		));
 
 		let pool_id = LBP::get_pair_id(pallet_lbp::AssetPair {
@@ -214,6 +217,10 @@ fn creating_xyk_pool_should_fail_when_lbp_pool_is_running() {
 			None,
 			None,
 			None,
+			None,
+			None,
+			None,
+			None,
 		));
 
 		// running LBP
@@ -0,0 +1,516 @@
+use crate::{
+	ensure, to_balance, MathError,
+	MathError::{DivisionByZero, InsufficientOutReserve, Overflow, ZeroReserve},
+};
+use core::convert::TryFrom;
+use primitive_types::{U256, U512};
+use sp_arithmetic::{FixedPointNumber, FixedU128, Permill};
+
+type Balance = u128;
+
+/// Liquidity of a concentrated liquidity position or pool.
+pub type Liquidity = u128;
+
+/// Number of fractional bits of a sqrt price. Sqrt prices are Q64.96 fixed point numbers.
+pub const RESOLUTION: usize = 96;
+
+/// The minimum tick that can be used, corresponds to the price of 1.0001^MIN_TICK.
+pub const MIN_TICK: i32 = -887272;
+
+/// The maximum tick that can be used, corresponds to the price of 1.0001^MAX_TICK.
+pub const MAX_TICK: i32 = 887272;
+
+/// Sqrt price at `MIN_TICK`.
+pub const MIN_SQRT_PRICE: U256 = U256([4295128739, 0, 0, 0]);
+
+/// Sqrt price at `MAX_TICK`.
+pub const MAX_SQRT_PRICE: U256 = U256([0x5d951d5263988d26, 0xefd1fc6a50648849, 0xfffd8963, 0]);
+
+const FEE_DENOMINATOR: u32 = 1_000_000;
+
+/// Multipliers of 1/sqrt(1.0001)^(2^i) in Q128.128 format for bits 1..=19 of the absolute tick.
+const TICK_MULTIPLIERS: [(u32, u128); 19] = [
+	(0x2, 0xfff97272373d413259a46990580e213a),
+	(0x4, 0xfff2e50f5f656932ef12357cf3c7fdcc),
+	(0x8, 0xffe5caca7e10e4e61c3624eaa0941cd0),
+	(0x10, 0xffcb9843d60f6159c9db58835c926644),
+	(0x20, 0xff973b41fa98c081472e6896dfb254c0),
+	(0x40, 0xff2ea16466c96a3843ec78b326b52861),
+	(0x80, 0xfe5dee046a99a2a811c461f1969c3053),
+	(0x100, 0xfcbe86c7900a88aedcffc83b479aa3a4),
+	(0x200, 0xf987a7253ac413176f2b074cf7815e54),
+	(0x400, 0xf3392b0822b70005940c7a398e4b70f3),
+	(0x800, 0xe7159475a2c29b7443b29c7fa6e889d9),
+	(0x1000, 0xd097f3bdfd2022b8845ad8f792aa5825),
+	(0x2000, 0xa9f746462d870fdf8a65dc1f90e061e5),
+	(0x4000, 0x70d869a156d2a1b890bb3df62baf32f7),
+	(0x8000, 0x31be135f97d08fd981231505542fcfa6),
+	(0x10000, 0x9aa508b5b7a84e1c677de54f3e99bc9),
+	(0x20000, 0x5d6af8dedb81196699c329225ee604),
+	(0x40000, 0x2216e584f5fa1ea926041bedfe98),
+	(0x80000, 0x48a170391f7dc42444e8fa2),
+];
+
+/// Result of a single step of a swap within one initialized tick range.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SwapStep {
+	/// Sqrt price after the step
+	pub sqrt_price_next: U256,
+	/// Amount of asset in consumed by the step, excluding fee
+	pub amount_in: Balance,
+	/// Amount of asset out received from the step
+	pub amount_out: Balance,
+	/// Fee paid in asset in
+	pub fee_amount: Balance,
+}
+
+/// Calculate sqrt(1.0001^tick) as Q64.96 fixed point number.
+///
+/// Returns `Overflow` if the tick is outside of `MIN_TICK..=MAX_TICK`.
+pub fn calculate_sqrt_price_at_tick(tick: i32) -> Result<U256, MathError> {
+	let abs_tick = tick.unsigned_abs();
+	ensure!(abs_tick <= MAX_TICK as u32, Overflow);
+
+	let mut ratio = if abs_tick & 0x1 != 0 {
+		U256::from(0xfffcb933bd6fad37aa2d162d1a594001u128)
+	} else {
+		U256::one() << 128
+	};
+
+	for (bit, multiplier) in TICK_MULTIPLIERS.iter() {
+		if abs_tick & bit != 0 {
+			ratio = (ratio * U256::from(*multiplier)) >> 128;
+		}
+	}
+
+	if tick > 0 {
+		ratio = U256::MAX / ratio;
+	}
+
+	// Q128.128 -> Q64.96, rounding up so that tick_at_sqrt_price(sqrt_price_at_tick(tick)) == tick
+	let round_up = if (ratio & U256::from(u32::MAX)).is_zero() {
+		U256::zero()
+	} else {
+		U256::one()
+	};
+
+	Ok((ratio >> 32) + round_up)
+}
+
+/// Calculate the greatest tick for which `calculate_sqrt_price_at_tick(tick) <= sqrt_price`.
+///
+/// Returns `Overflow` if the sqrt price is outside of `MIN_SQRT_PRICE..MAX_SQRT_PRICE`.
+pub fn calculate_tick_at_sqrt_price(sqrt_price: U256) -> Result<i32, MathError> {
+	ensure!(sqrt_price >= MIN_SQRT_PRICE && sqrt_price < MAX_SQRT_PRICE, Overflow);
+
+	let (mut low, mut high) = (MIN_TICK, MAX_TICK);
+	while low < high {
+		let mid = low + (high - low + 1) / 2;
+		if calculate_sqrt_price_at_tick(mid)? <= sqrt_price {
+			low = mid;
+		} else {
+			high = mid - 1;
+		}
+	}
+
+	Ok(low)
+}
+
+/// Convert a price of asset 0 denominated in asset 1 to a sqrt price.
+pub fn calculate_sqrt_price_from_price(price: FixedU128) -> Result<U256, MathError> {
+	let price_x192 = (U512::from(price.into_inner()) << (2 * RESOLUTION)) / U512::from(FixedU128::DIV);
+	let sqrt_price = U256::try_from(price_x192.integer_sqrt()).map_err(|_| Overflow)?;
+
+	ensure!(sqrt_price >= MIN_SQRT_PRICE && sqrt_price < MAX_SQRT_PRICE, Overflow);
+
+	Ok(sqrt_price)
+}
+
+/// Convert a sqrt price to a price of asset 0 denominated in asset 1, represented as (n, d).
+///
+/// Both parts are scaled down to fit in a Balance, precision is lost for very large or very small prices.
+pub fn calculate_price_from_sqrt_price(sqrt_price: U256) -> (Balance, Balance) {
+	let n = sqrt_price.full_mul(sqrt_price);
+	let d = U512::one() << (2 * RESOLUTION);
+
+	// n has at most 320 bits so the shift never exceeds 192 and d stays non-zero
+	let shift = n.bits().max(2 * RESOLUTION + 1).saturating_sub(128);
+
+	((n >> shift).low_u128(), (d >> shift).low_u128())
+}
+
+/// Calculate amount of asset 0 between two sqrt prices for given liquidity.
+/// Formula: L * (sqrt(Pb) - sqrt(Pa)) / (sqrt(Pa) * sqrt(Pb))
+///
+/// Result is rounded up if `round_up` is true, otherwise it is rounded down.
+pub fn calculate_amount_0_delta(
+	sqrt_price_a: U256,
+	sqrt_price_b: U256,
+	liquidity: Liquidity,
+	round_up: bool,
+) -> Result<Balance, MathError> {
+	to_balance!(amount_0_delta(sqrt_price_a, sqrt_price_b, liquidity, round_up)?)
+}
+
+/// Calculate amount of asset 1 between two sqrt prices for given liquidity.
+/// Formula: L * (sqrt(Pb) - sqrt(Pa))
+///
+/// Result is rounded up if `round_up` is true, otherwise it is rounded down.
+pub fn calculate_amount_1_delta(
+	sqrt_price_a: U256,
+	sqrt_price_b: U256,
+	liquidity: Liquidity,
+	round_up: bool,
+) -> Result<Balance, MathError> {
+	to_balance!(amount_1_delta(sqrt_price_a, sqrt_price_b, liquidity, round_up)?)
+}
+
+/// Calculate amounts of asset 0 and asset 1 represented by `liquidity` in the range between `sqrt_price_lower`
+/// and `sqrt_price_upper` at the current sqrt price.
+///
+/// Amounts are rounded up when liquidity is added (`round_up` is true) and rounded down when it is removed.
+pub fn calculate_amounts_for_liquidity(
+	sqrt_price: U256,
+	sqrt_price_lower: U256,
+	sqrt_price_upper: U256,
+	liquidity: Liquidity,
+	round_up: bool,
+) -> Result<(Balance, Balance), MathError> {
+	if sqrt_price <= sqrt_price_lower {
+		Ok((
+			calculate_amount_0_delta(sqrt_price_lower, sqrt_price_upper, liquidity, round_up)?,
+			0,
+		))
+	} else if sqrt_price < sqrt_price_upper {
+		Ok((
+			calculate_amount_0_delta(sqrt_price, sqrt_price_upper, liquidity, round_up)?,
+			calculate_amount_1_delta(sqrt_price_lower, sqrt_price, liquidity, round_up)?,
+		))
+	} else {
+		Ok((
+			0,
+			calculate_amount_1_delta(sqrt_price_lower, sqrt_price_upper, liquidity, round_up)?,
+		))
+	}
+}
+
+/// Calculate the maximum liquidity that can be provided with `amount_0` and `amount_1` in the range between
+/// `sqrt_price_lower` and `sqrt_price_upper` at the current sqrt price.
+pub fn calculate_liquidity_for_amounts(
+	sqrt_price: U256,
+	sqrt_price_lower: U256,
+	sqrt_price_upper: U256,
+	amount_0: Balance,
+	amount_1: Balance,
+) -> Result<Liquidity, MathError> {
+	ensure!(sqrt_price_lower < sqrt_price_upper, Overflow);
+
+	let liquidity = if sqrt_price <= sqrt_price_lower {
+		liquidity_for_amount_0(sqrt_price_lower, sqrt_price_upper, amount_0)?
+	} else if sqrt_price < sqrt_price_upper {
+		liquidity_for_amount_0(sqrt_price, sqrt_price_upper, amount_0)?.min(liquidity_for_amount_1(
+			sqrt_price_lower,
+			sqrt_price,
+			amount_1,
+		)?)
+	} else {
+		liquidity_for_amount_1(sqrt_price_lower, sqrt_price_upper, amount_1)?
+	};
+
+	Liquidity::try_from(liquidity).map_err(|_| Overflow)
+}
+
+/// Calculate next sqrt price after `amount_in` is swapped in.
+///
+/// Asset 0 is swapped in if `zero_for_one` is true, otherwise asset 1 is swapped in.
+pub fn calculate_next_sqrt_price_from_input(
+	sqrt_price: U256,
+	liquidity: Liquidity,
+	amount_in: Balance,
+	zero_for_one: bool,
+) -> Result<U256, MathError> {
+	ensure!(!sqrt_price.is_zero(), ZeroReserve);
+	ensure!(liquidity > 0, ZeroReserve);
+
+	if zero_for_one {
+		next_sqrt_price_from_amount_0(sqrt_price, liquidity, amount_in, true)
+	} else {
+		next_sqrt_price_from_amount_1(sqrt_price, liquidity, amount_in, true)
+	}
+}
+
+/// Calculate next sqrt price after `amount_out` is swapped out.
+///
+/// Asset 1 is swapped out if `zero_for_one` is true, otherwise asset 0 is swapped out.
+pub fn calculate_next_sqrt_price_from_output(
+	sqrt_price: U256,
+	liquidity: Liquidity,
+	amount_out: Balance,
+	zero_for_one: bool,
+) -> Result<U256, MathError> {
+	ensure!(!sqrt_price.is_zero(), ZeroReserve);
+	ensure!(liquidity > 0, ZeroReserve);
+
+	if zero_for_one {
+		next_sqrt_price_from_amount_1(sqrt_price, liquidity, amount_out, false)
+	} else {
+		next_sqrt_price_from_amount_0(sqrt_price, liquidity, amount_out, false)
+	}
+}
+
+/// Calculate a single swap step from the current sqrt price towards the target sqrt price.
+///
+/// - `sqrt_price_current` - current sqrt price of the pool
+/// - `sqrt_price_target` - sqrt price which cannot be exceeded, usually the price of the next initialized tick
+/// - `liquidity` - active liquidity
+/// - `amount_remaining` - remaining amount in (if `exact_in`) or out to be swapped
+/// - `fee` - fee taken from amount in
+/// - `exact_in` - true for sell, false for buy
+pub fn calculate_swap_step(
+	sqrt_price_current: U256,
+	sqrt_price_target: U256,
+	liquidity: Liquidity,
+	amount_remaining: Balance,
+	fee: Permill,
+	exact_in: bool,
+) -> Result<SwapStep, MathError> {
+	let fee_parts = fee.deconstruct();
+	ensure!(fee_parts < FEE_DENOMINATOR, Overflow);
+
+	let zero_for_one = sqrt_price_current >= sqrt_price_target;
+	let fee_complement = U256::from(FEE_DENOMINATOR - fee_parts);
+
+	let mut sqrt_price_next = sqrt_price_target;
+	let mut amount_in = U256::zero();
+	let mut amount_out = U256::zero();
+
+	if exact_in {
+		let amount_remaining_less_fee = mul_div(
+			U256::from(amount_remaining),
+			fee_complement,
+			U256::from(FEE_DENOMINATOR),
+		)?;
+		amount_in = if zero_for_one {
+			amount_0_delta(sqrt_price_target, sqrt_price_current, liquidity, true)?
+		} else {
+			amount_1_delta(sqrt_price_current, sqrt_price_target, liquidity, true)?
+		};
+		if amount_remaining_less_fee < amount_in {
+			sqrt_price_next = calculate_next_sqrt_price_from_input(
+				sqrt_price_current,
+				liquidity,
+				to_balance!(amount_remaining_less_fee)?,
+				zero_for_one,
+			)?;
+		}
+	} else {
+		amount_out = if zero_for_one {
+			amount_1_delta(sqrt_price_target, sqrt_price_current, liquidity, false)?
+		} else {
+			amount_0_delta(sqrt_price_current, sqrt_price_target, liquidity, false)?
+		};
+		if U256::from(amount_remaining) < amount_out {
+			sqrt_price_next =
+				calculate_next_sqrt_price_from_output(sqrt_price_current, liquidity, amount_remaining, zero_for_one)?;
+		}
+	}
+
+	let target_reached = sqrt_price_next == sqrt_price_target;
+
+	if zero_for_one {
+		if !(target_reached && exact_in) {
+			amount_in = amount_0_delta(sqrt_price_next, sqrt_price_current, liquidity, true)?;
+		}
+		if !(target_reached && !exact_in) {
+			amount_out = amount_1_delta(sqrt_price_next, sqrt_price_current, liquidity, false)?;
+		}
+	} else {
+		if !(target_reached && exact_in) {
+			amount_in = amount_1_delta(sqrt_price_current, sqrt_price_next, liquidity, true)?;
+		}
+		if !(target_reached && !exact_in) {
+			amount_out = amount_0_delta(sqrt_price_current, sqrt_price_next, liquidity, false)?;
+		}
+	}
+
+	// cap the output amount to not exceed the remaining output amount
+	if !exact_in && amount_out > U256::from(amount_remaining) {
+		amount_out = U256::from(amount_remaining);
+	}
+
+	let amount_in = to_balance!(amount_in)?;
+	let amount_out = to_balance!(amount_out)?;
+
+	let fee_amount = if exact_in && !target_reached {
+		// the rest of the remaining amount is taken as fee
+		amount_remaining.checked_sub(amount_in).ok_or(Overflow)?
+	} else {
+		to_balance!(mul_div_rounding_up(
+			U256::from(amount_in),
+			U256::from(fee_parts),
+			fee_complement
+		)?)?
+	};
+
+	Ok(SwapStep {
+		sqrt_price_next,
+		amount_in,
+		amount_out,
+		fee_amount,
+	})
+}
+
+/// Calculate fee growth per unit of liquidity as Q128.128 fixed point number.
+pub fn calculate_fee_growth(fee_amount: Balance, liquidity: Liquidity) -> Result<U256, MathError> {
+	ensure!(liquidity > 0, DivisionByZero);
+	Ok((U256::from(fee_amount) << 128) / U256::from(liquidity))
+}
+
+/// Calculate fees earned by `liquidity` for given fee growth (Q128.128) since the last collection.
+pub fn calculate_fees_owed(fee_growth_delta: U256, liquidity: Liquidity) -> Result<Balance, MathError> {
+	to_balance!(mul_div(fee_growth_delta, U256::from(liquidity), U256::one() << 128)?)
+}
+
+/// Calculate the virtual reserves of asset 0 and asset 1 for active liquidity at the current sqrt price.
+pub fn calculate_virtual_reserves(sqrt_price: U256, liquidity: Liquidity) -> Result<(Balance, Balance), MathError> {
+	ensure!(!sqrt_price.is_zero(), ZeroReserve);
+
+	let reserve_0 = mul_div(U256::from(liquidity) << RESOLUTION, U256::one(), sqrt_price)?;
+	let reserve_1 = mul_div(U256::from(liquidity), sqrt_price, U256::one() << RESOLUTION)?;
+
+	Ok((to_balance!(reserve_0)?, to_balance!(reserve_1)?))
+}
+
+fn sorted(sqrt_price_a: U256, sqrt_price_b: U256) -> (U256, U256) {
+	if sqrt_price_a > sqrt_price_b {
+		(sqrt_price_b, sqrt_price_a)
+	} else {
+		(sqrt_price_a, sqrt_price_b)
+	}
+}
+
+fn amount_0_delta(
+	sqrt_price_a: U256,
+	sqrt_price_b: U256,
+	liquidity: Liquidity,
+	round_up: bool,
+) -> Result<U256, MathError> {
+	let (lower, upper) = sorted(sqrt_price_a, sqrt_price_b);
+	ensure!(!lower.is_zero(), ZeroReserve);
+
+	let numerator_1 = U256::from(liquidity) << RESOLUTION;
+	let numerator_2 = upper - lower;
+
+	if round_up {
+		Ok(div_rounding_up(
+			mul_div_rounding_up(numerator_1, numerator_2, upper)?,
+			lower,
+		))
+	} else {
+		Ok(mul_div(numerator_1, numerator_2, upper)? / lower)
+	}
+}
+
+fn amount_1_delta(
+	sqrt_price_a: U256,
+	sqrt_price_b: U256,
+	liquidity: Liquidity,
+	round_up: bool,
+) -> Result<U256, MathError> {
+	let (lower, upper) = sorted(sqrt_price_a, sqrt_price_b);
+
+	if round_up {
+		mul_div_rounding_up(U256::from(liquidity), upper - lower, U256::one() << RESOLUTION)
+	} else {
+		mul_div(U256::from(liquidity), upper - lower, U256::one() << RESOLUTION)
+	}
+}
+
+fn liquidity_for_amount_0(sqrt_price_a: U256, sqrt_price_b: U256, amount: Balance) -> Result<U256, MathError> {
+	let (lower, upper) = sorted(sqrt_price_a, sqrt_price_b);
+	ensure!(lower != upper, DivisionByZero);
+
+	let intermediate = mul_div(lower, upper, U256::one() << RESOLUTION)?;
+	mul_div(U256::from(amount), intermediate, upper - lower)
+}
+
+fn liquidity_for_amount_1(sqrt_price_a: U256, sqrt_price_b: U256, amount: Balance) -> Result<U256, MathError> {
+	let (lower, upper) = sorted(sqrt_price_a, sqrt_price_b);
+	ensure!(lower != upper, DivisionByZero);
+
+	mul_div(U256::from(amount), U256::one() << RESOLUTION, upper - lower)
+}
+
+/// Price moves down when asset 0 is added and up when it is removed. Always rounds up.
+fn next_sqrt_price_from_amount_0(
+	sqrt_price: U256,
+	liquidity: Liquidity,
+	amount: Balance,
+	add: bool,
+) -> Result<U256, MathError> {
+	if amount == 0 {
+		return Ok(sqrt_price);
+	}
+
+	let numerator = U256::from(liquidity) << RESOLUTION;
+	let product = U256::from(amount).checked_mul(sqrt_price);
+
+	if add {
+		if let Some(denominator) = product.and_then(|p| numerator.checked_add(p)) {
+			return mul_div_rounding_up(numerator, sqrt_price, denominator);
+		}
+		// fallback formula: L / (L / sqrt(P) + amount)
+		let denominator = (numerator / sqrt_price)
+			.checked_add(U256::from(amount))
+			.ok_or(Overflow)?;
+		Ok(div_rounding_up(numerator, denominator))
+	} else {
+		let product = product.ok_or(InsufficientOutReserve)?;
+		ensure!(numerator > product, InsufficientOutReserve);
+		mul_div_rounding_up(numerator, sqrt_price, numerator - product)
+	}
+}
+
+/// Price moves up when asset 1 is added and down when it is removed. Always rounds down.
+fn next_sqrt_price_from_amount_1(
+	sqrt_price: U256,
+	liquidity: Liquidity,
+	amount: Balance,
+	add: bool,
+) -> Result<U256, MathError> {
+	let amount_shifted = U256::from(amount) << RESOLUTION;
+	let liquidity = U256::from(liquidity);
+
+	if add {
+		sqrt_price.checked_add(amount_shifted / liquidity).ok_or(Overflow)
+	} else {
+		let quotient = div_rounding_up(amount_shifted, liquidity);
+		ensure!(sqrt_price > quotient, InsufficientOutReserve);
+		Ok(sqrt_price - quotient)
+	}
+}
+
+fn mul_div(a: U256, b: U256, denominator: U256) -> Result<U256, MathError> {
+	ensure!(!denominator.is_zero(), DivisionByZero);
+	U256::try_from(a.full_mul(b) / U512::from(denominator)).map_err(|_| Overflow)
+}
+
+fn mul_div_rounding_up(a: U256, b: U256, denominator: U256) -> Result<U256, MathError> {
+	ensure!(!denominator.is_zero(), DivisionByZero);
+	let (quotient, remainder) = a.full_mul(b).div_mod(U512::from(denominator));
+	let quotient = if remainder.is_zero() {
+		quotient
+	} else {
+		quotient + U512::one()
+	};
+	U256::try_from(quotient).map_err(|_| Overflow)
+}
+
+fn div_rounding_up(a: U256, b: U256) -> U256 {
+	let (quotient, remainder) = a.div_mod(b);
+	if remainder.is_zero() {
+		quotient
+	} else {
+		quotient + U256::one()
+	}
+}
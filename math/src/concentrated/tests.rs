@@ -0,0 +1,267 @@
+use crate::concentrated::*;
+use crate::MathError::{InsufficientOutReserve, Overflow};
+use primitive_types::U256;
+use sp_arithmetic::{FixedU128, Permill};
+
+const ONE: u128 = 1_000_000_000_000_000_000;
+
+fn q96() -> U256 {
+	U256::one() << RESOLUTION
+}
+
+#[test]
+fn sqrt_price_at_tick_zero_should_be_one() {
+	assert_eq!(calculate_sqrt_price_at_tick(0), Ok(q96()));
+}
+
+#[test]
+fn sqrt_price_at_tick_bounds_should_match_min_and_max_sqrt_price() {
+	assert_eq!(calculate_sqrt_price_at_tick(MIN_TICK), Ok(MIN_SQRT_PRICE));
+	assert_eq!(calculate_sqrt_price_at_tick(MAX_TICK), Ok(MAX_SQRT_PRICE));
+}
+
+#[test]
+fn sqrt_price_at_tick_should_fail_when_tick_is_out_of_range() {
+	assert_eq!(calculate_sqrt_price_at_tick(MIN_TICK - 1), Err(Overflow));
+	assert_eq!(calculate_sqrt_price_at_tick(MAX_TICK + 1), Err(Overflow));
+}
+
+#[test]
+fn sqrt_price_at_tick_should_match_float_approximation() {
+	for tick in [-500_000, -100_000, -60, -1, 1, 10, 60, 1_000, 100_000, 500_000] {
+		let expected = (tick as f64 * 1.0001f64.ln() / 2.0).exp() * 2f64.powi(96);
+		let sqrt_price = calculate_sqrt_price_at_tick(tick).unwrap();
+		let actual = sqrt_price.to_string().parse::<f64>().unwrap();
+		assert!(
+			((actual - expected) / expected).abs() < 1e-10,
+			"tick {tick}: {actual} != {expected}"
+		);
+	}
+}
+
+#[test]
+fn sqrt_price_at_tick_should_increase_with_tick() {
+	let mut previous = calculate_sqrt_price_at_tick(-1_000).unwrap();
+	for tick in -999..1_000 {
+		let sqrt_price = calculate_sqrt_price_at_tick(tick).unwrap();
+		assert!(sqrt_price > previous);
+		previous = sqrt_price;
+	}
+}
+
+#[test]
+fn tick_at_sqrt_price_should_be_inverse_of_sqrt_price_at_tick() {
+	for tick in [MIN_TICK, -500_000, -887, -60, -1, 0, 1, 60, 887, 500_000, MAX_TICK - 1] {
+		let sqrt_price = calculate_sqrt_price_at_tick(tick).unwrap();
+		assert_eq!(calculate_tick_at_sqrt_price(sqrt_price), Ok(tick));
+		assert_eq!(calculate_tick_at_sqrt_price(sqrt_price + 1), Ok(tick));
+		if tick > MIN_TICK {
+			assert_eq!(calculate_tick_at_sqrt_price(sqrt_price - 1), Ok(tick - 1));
+		}
+	}
+}
+
+#[test]
+fn tick_at_sqrt_price_should_fail_when_sqrt_price_is_out_of_range() {
+	assert_eq!(calculate_tick_at_sqrt_price(MIN_SQRT_PRICE - 1), Err(Overflow));
+	assert_eq!(calculate_tick_at_sqrt_price(MAX_SQRT_PRICE), Err(Overflow));
+}
+
+#[test]
+fn sqrt_price_from_price_should_work() {
+	assert_eq!(calculate_sqrt_price_from_price(FixedU128::from(1)), Ok(q96()));
+	assert_eq!(calculate_sqrt_price_from_price(FixedU128::from(4)), Ok(q96() << 1));
+	assert_eq!(
+		calculate_sqrt_price_from_price(FixedU128::from_rational(1, 4)),
+		Ok(q96() >> 1)
+	);
+	assert_eq!(calculate_sqrt_price_from_price(FixedU128::from(0)), Err(Overflow));
+}
+
+#[test]
+fn price_from_sqrt_price_should_work() {
+	let (n, d) = calculate_price_from_sqrt_price(q96());
+	assert_eq!(n, d);
+
+	let (n, d) = calculate_price_from_sqrt_price(q96() << 1);
+	assert_eq!(n, 4 * d);
+
+	let (n, d) = calculate_price_from_sqrt_price(MAX_SQRT_PRICE);
+	assert!(n > 0 && d > 0);
+}
+
+#[test]
+fn amount_deltas_should_work() {
+	let sqrt_price_1 = q96();
+	let sqrt_price_4 = q96() << 1;
+
+	assert_eq!(
+		calculate_amount_0_delta(sqrt_price_1, sqrt_price_4, ONE, false),
+		Ok(ONE / 2)
+	);
+	assert_eq!(
+		calculate_amount_0_delta(sqrt_price_4, sqrt_price_1, ONE, true),
+		Ok(ONE / 2)
+	);
+	assert_eq!(
+		calculate_amount_1_delta(sqrt_price_1, sqrt_price_4, ONE, false),
+		Ok(ONE)
+	);
+	assert_eq!(calculate_amount_1_delta(sqrt_price_4, sqrt_price_1, ONE, true), Ok(ONE));
+}
+
+#[test]
+fn amount_deltas_should_round_in_requested_direction() {
+	let lower = calculate_sqrt_price_at_tick(-60).unwrap();
+	let upper = calculate_sqrt_price_at_tick(60).unwrap();
+
+	let down = calculate_amount_0_delta(lower, upper, ONE, false).unwrap();
+	let up = calculate_amount_0_delta(lower, upper, ONE, true).unwrap();
+	assert_eq!(up, down + 1);
+
+	let down = calculate_amount_1_delta(lower, upper, ONE, false).unwrap();
+	let up = calculate_amount_1_delta(lower, upper, ONE, true).unwrap();
+	assert_eq!(up, down + 1);
+}
+
+#[test]
+fn amounts_for_liquidity_should_depend_on_current_price() {
+	let lower = calculate_sqrt_price_at_tick(-600).unwrap();
+	let upper = calculate_sqrt_price_at_tick(600).unwrap();
+
+	// price below range - only asset 0
+	let (amount_0, amount_1) =
+		calculate_amounts_for_liquidity(calculate_sqrt_price_at_tick(-1_000).unwrap(), lower, upper, ONE, true)
+			.unwrap();
+	assert!(amount_0 > 0);
+	assert_eq!(amount_1, 0);
+
+	// price in range - both assets
+	let (amount_0, amount_1) = calculate_amounts_for_liquidity(q96(), lower, upper, ONE, true).unwrap();
+	assert!(amount_0 > 0);
+	assert!(amount_1 > 0);
+
+	// price above range - only asset 1
+	let (amount_0, amount_1) =
+		calculate_amounts_for_liquidity(calculate_sqrt_price_at_tick(1_000).unwrap(), lower, upper, ONE, true).unwrap();
+	assert_eq!(amount_0, 0);
+	assert!(amount_1 > 0);
+}
+
+#[test]
+fn liquidity_for_amounts_should_not_exceed_provided_amounts() {
+	let lower = calculate_sqrt_price_at_tick(-600).unwrap();
+	let upper = calculate_sqrt_price_at_tick(600).unwrap();
+	let sqrt_price = calculate_sqrt_price_at_tick(100).unwrap();
+
+	let liquidity = calculate_liquidity_for_amounts(sqrt_price, lower, upper, 100 * ONE, 50 * ONE).unwrap();
+	let (amount_0, amount_1) = calculate_amounts_for_liquidity(sqrt_price, lower, upper, liquidity, true).unwrap();
+
+	assert!(amount_0 <= 100 * ONE);
+	assert!(amount_1 <= 50 * ONE);
+	// one of the amounts is fully used
+	assert!(100 * ONE - amount_0 <= 1 || 50 * ONE - amount_1 <= 1);
+}
+
+#[test]
+fn next_sqrt_price_from_input_should_move_price_in_correct_direction() {
+	let sqrt_price = q96();
+
+	let down = calculate_next_sqrt_price_from_input(sqrt_price, ONE, ONE / 10, true).unwrap();
+	let up = calculate_next_sqrt_price_from_input(sqrt_price, ONE, ONE / 10, false).unwrap();
+
+	assert!(down < sqrt_price);
+	assert!(up > sqrt_price);
+	assert_eq!(
+		calculate_next_sqrt_price_from_input(sqrt_price, ONE, 0, true),
+		Ok(sqrt_price)
+	);
+}
+
+#[test]
+fn next_sqrt_price_from_output_should_fail_when_output_exceeds_liquidity() {
+	let sqrt_price = q96();
+
+	assert_eq!(
+		calculate_next_sqrt_price_from_output(sqrt_price, ONE, ONE, true),
+		Err(InsufficientOutReserve)
+	);
+	assert_eq!(
+		calculate_next_sqrt_price_from_output(sqrt_price, ONE, ONE, false),
+		Err(InsufficientOutReserve)
+	);
+}
+
+#[test]
+fn swap_step_exact_in_should_stop_before_target_when_amount_is_small() {
+	let current = q96();
+	let target = calculate_sqrt_price_at_tick(-600).unwrap();
+
+	let step = calculate_swap_step(current, target, 1_000 * ONE, ONE, Permill::from_percent(1), true).unwrap();
+
+	assert!(step.sqrt_price_next < current && step.sqrt_price_next > target);
+	assert_eq!(step.amount_in + step.fee_amount, ONE);
+	assert_eq!(step.fee_amount, ONE / 100);
+	assert!(step.amount_out < step.amount_in);
+}
+
+#[test]
+fn swap_step_exact_in_should_stop_at_target_when_amount_is_large() {
+	let current = q96();
+	let target = calculate_sqrt_price_at_tick(600).unwrap();
+
+	let step = calculate_swap_step(current, target, ONE, 1_000 * ONE, Permill::from_parts(3_000), true).unwrap();
+
+	assert_eq!(step.sqrt_price_next, target);
+	assert_eq!(
+		step.amount_in,
+		calculate_amount_1_delta(current, target, ONE, true).unwrap()
+	);
+	assert_eq!(
+		step.amount_out,
+		calculate_amount_0_delta(current, target, ONE, false).unwrap()
+	);
+	assert!(step.amount_in + step.fee_amount < 1_000 * ONE);
+}
+
+#[test]
+fn swap_step_exact_out_should_return_requested_amount() {
+	let current = q96();
+	let target = calculate_sqrt_price_at_tick(-600).unwrap();
+
+	let step = calculate_swap_step(current, target, 1_000 * ONE, ONE, Permill::from_parts(3_000), false).unwrap();
+
+	assert!(step.sqrt_price_next < current && step.sqrt_price_next > target);
+	assert_eq!(step.amount_out, ONE);
+	assert!(step.amount_in > ONE);
+	assert!(step.fee_amount > 0);
+}
+
+#[test]
+fn swap_step_exact_in_and_exact_out_should_be_consistent() {
+	let current = q96();
+	let target = calculate_sqrt_price_at_tick(-6_000).unwrap();
+	let fee = Permill::from_parts(3_000);
+
+	let sell = calculate_swap_step(current, target, 1_000 * ONE, ONE, fee, true).unwrap();
+	let buy = calculate_swap_step(current, target, 1_000 * ONE, sell.amount_out, fee, false).unwrap();
+
+	assert!(buy.amount_in + buy.fee_amount <= ONE);
+	assert!(ONE - (buy.amount_in + buy.fee_amount) <= 2);
+}
+
+#[test]
+fn fee_growth_and_fees_owed_should_be_consistent() {
+	let fee_growth = calculate_fee_growth(3 * ONE, 1_000 * ONE).unwrap();
+
+	assert_eq!(calculate_fees_owed(fee_growth, 1_000 * ONE), Ok(3 * ONE - 1));
+	assert_eq!(calculate_fees_owed(fee_growth, 500 * ONE), Ok(3 * ONE / 2 - 1));
+}
+
+#[test]
+fn virtual_reserves_should_reflect_price() {
+	let (reserve_0, reserve_1) = calculate_virtual_reserves(q96() << 1, ONE).unwrap();
+
+	assert_eq!(reserve_0, ONE / 2);
+	assert_eq!(reserve_1, 2 * ONE);
+}
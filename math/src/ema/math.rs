@@ -186,6 +186,62 @@ pub fn liquidity_weighted_average(
 	)
 }
 
+/// Cumulative price as a 128.128 bit fixed point number.
+///
+/// Accumulation wraps around on overflow, so only the difference between two cumulative prices is
+/// meaningful.
+pub type CumulativePrice = U256;
+
+/// Convert `price` into a 128.128 bit fixed point number.
+pub fn price_to_fixed(price: EmaPrice) -> U256 {
+	(U256::from(price.n) << 128) / U256::from(price.d.max(1))
+}
+
+/// Advance `cumulative` by `price` held constant for `blocks` blocks.
+///
+/// Note: Wraps around on overflow.
+pub fn accumulate_price(cumulative: CumulativePrice, price: EmaPrice, blocks: u64) -> CumulativePrice {
+	let (increment, _) = price_to_fixed(price).overflowing_mul(U256::from(blocks));
+	let (new_cumulative, _) = cumulative.overflowing_add(increment);
+	new_cumulative
+}
+
+/// Calculate the time weighted average price between the cumulative prices `start` and `end`
+/// which are `blocks` apart.
+///
+/// Returns `None` if `blocks` is zero.
+pub fn calculate_twap(start: CumulativePrice, end: CumulativePrice, blocks: u64) -> Option<EmaPrice> {
+	if blocks == 0 {
+		return None;
+	}
+	let (sum, _) = end.overflowing_sub(start);
+	// twap = sum / (blocks * 2^128)
+	let d = U512::from(blocks) << 128;
+	Some(round_to_rational((U512::from(sum), d), Rounding::Nearest))
+}
+
+/// Calculate the median of `prices`. For an even number of prices the mean of the two middle
+/// values is returned.
+///
+/// Note: Sorts `prices` in place. Returns `None` if `prices` is empty.
+pub fn median_price(prices: &mut [EmaPrice]) -> Option<EmaPrice> {
+	if prices.is_empty() {
+		return None;
+	}
+	prices.sort_unstable();
+	let mid = prices.len() / 2;
+	if prices.len() % 2 == 1 {
+		return Some(prices[mid]);
+	}
+	let (l, r) = (prices[mid - 1], prices[mid]);
+	let (l_n, l_d, r_n, r_d) = to_u128_wrapper!(l.n, l.d, r.n, r.d);
+	// n = l.n * r.d + r.n * l.d
+	let n = U512::from(l_n.full_mul(r_d)) + U512::from(r_n.full_mul(l_d));
+	// d = 2 * l.d * r.d
+	let d = U512::from(l_d.full_mul(r_d)) << 1;
+	Some(round_to_rational((n, d), Rounding::Nearest))
+}
+
 // Utility functions for working with rational numbers.
 
 /// Subtract `r` from `l` and return a tuple of `U256` for full precision.
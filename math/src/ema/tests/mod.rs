@@ -10,6 +10,7 @@ use crate::types::Fraction;
 mod high_precision;
 mod invariants;
 mod test_data;
+mod twap;
 use test_data::*;
 
 use num_traits::One;
@@ -0,0 +1,75 @@
+use super::*;
+
+use sp_std::cmp::Ordering;
+
+fn assert_price_eq(left: EmaPrice, right: EmaPrice) {
+	assert_eq!(left.cmp(&right), Ordering::Equal, "{:?} != {:?}", left, right);
+}
+
+#[test]
+fn price_to_fixed_works() {
+	assert_eq!(price_to_fixed(EmaPrice::one()), U256::one() << 128);
+	assert_eq!(price_to_fixed(EmaPrice::new(1, 2)), U256::one() << 127);
+	assert_eq!(price_to_fixed(EmaPrice::zero()), U256::zero());
+}
+
+#[test]
+fn accumulate_price_works() {
+	let cumulative = accumulate_price(CumulativePrice::zero(), EmaPrice::from(2), 10);
+	assert_eq!(cumulative, U256::from(20) << 128);
+	let cumulative = accumulate_price(cumulative, EmaPrice::new(1, 2), 4);
+	assert_eq!(cumulative, U256::from(22) << 128);
+}
+
+#[test]
+fn accumulate_price_should_wrap_around_on_overflow() {
+	let start = U256::MAX - (U256::from(5) << 128);
+	let end = accumulate_price(start, EmaPrice::from(3), 4);
+	assert!(end < start);
+	assert_price_eq(calculate_twap(start, end, 4).unwrap(), EmaPrice::from(3));
+}
+
+#[test]
+fn calculate_twap_works() {
+	let start = accumulate_price(CumulativePrice::zero(), EmaPrice::from(7), 3);
+	let mid = accumulate_price(start, EmaPrice::from(2), 10);
+	let end = accumulate_price(mid, EmaPrice::from(4), 10);
+	assert_price_eq(calculate_twap(start, mid, 10).unwrap(), EmaPrice::from(2));
+	assert_price_eq(calculate_twap(start, end, 20).unwrap(), EmaPrice::from(3));
+}
+
+#[test]
+fn calculate_twap_should_return_none_when_blocks_is_zero() {
+	assert_eq!(
+		calculate_twap(CumulativePrice::zero(), CumulativePrice::zero(), 0),
+		None
+	);
+}
+
+#[test]
+fn median_price_works_for_odd_number_of_prices() {
+	let mut prices = [EmaPrice::new(3, 1), EmaPrice::new(1, 2), EmaPrice::new(10, 4)];
+	assert_price_eq(median_price(&mut prices).unwrap(), EmaPrice::new(5, 2));
+}
+
+#[test]
+fn median_price_works_for_even_number_of_prices() {
+	let mut prices = [
+		EmaPrice::new(100, 1),
+		EmaPrice::new(1, 2),
+		EmaPrice::new(3, 2),
+		EmaPrice::new(1, 100),
+	];
+	assert_price_eq(median_price(&mut prices).unwrap(), EmaPrice::one());
+}
+
+#[test]
+fn median_price_should_ignore_outlier() {
+	let mut prices = [EmaPrice::from(2), EmaPrice::from(2_000_000), EmaPrice::new(21, 10)];
+	assert_price_eq(median_price(&mut prices).unwrap(), EmaPrice::new(21, 10));
+}
+
+#[test]
+fn median_price_should_return_none_when_empty() {
+	assert_eq!(median_price(&mut []), None);
+}
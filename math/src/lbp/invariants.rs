@@ -3,6 +3,7 @@ use crate::lbp::lbp;
 use primitive_types::U256;
 use proptest::prelude::*;
 use rand::Rng;
+use sp_arithmetic::Permill;
 
 const MIN_START_BLOCK: u32 = 0;
 const MAX_START_BLOCK: u32 = 10_000_000;
@@ -25,6 +26,18 @@ fn final_weight() -> impl Strategy<Value = u32> {
 	10_000_001u32..100_000_000u32
 }
 
+fn decay_rate() -> impl Strategy<Value = (u32, u32)> {
+	(1u32..10_000u32).prop_map(|n| (n, 1_000))
+}
+
+fn steps() -> impl Strategy<Value = u32> {
+	1u32..100u32
+}
+
+fn breakpoint() -> impl Strategy<Value = (Permill, u32)> {
+	(1u32..1_000_000u32, 1_000_000..100_000_000u32).prop_map(|(p, w)| (Permill::from_parts(p), w))
+}
+
 //Spec: https://www.notion.so/Property-Tests-7b506add39ea48fc8f68ecd18391e30a#9bbed73541c84e45a9855360aeee1f9b
 proptest! {
 	#![proptest_config(ProptestConfig::with_cases(10000))]
@@ -52,3 +65,90 @@ proptest! {
 		assert_approx_eq!(a1*a2, b1*b2, max_delta, "The invariant does not hold")
 	}
 }
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(10000))]
+	#[test]
+	fn exponential_weights_should_be_monotonic_and_bounded(
+		start_x_block in start_blocks(),
+		lbp_length in lbp_length(),
+		start_y_weight in final_weight(),
+		end_y_weight in initial_weight(),
+		rate in decay_rate(),
+		at_offset in 0u32..ONE_WEEK_IN_BLOCKS,
+	) {
+		//Arrange
+		let end_x_block = start_x_block.checked_add(lbp_length).unwrap();
+		let at_block = start_x_block + at_offset % lbp_length;
+
+		//Act
+		let weight = lbp::calculate_exponential_weights(start_x_block, end_x_block, start_y_weight, end_y_weight, rate, at_block).unwrap();
+		let next_weight = lbp::calculate_exponential_weights(start_x_block, end_x_block, start_y_weight, end_y_weight, rate, at_block + 1).unwrap();
+		let linear_weight = lbp::calculate_linear_weights(start_x_block, end_x_block, start_y_weight, end_y_weight, at_block).unwrap();
+
+		//Assert
+		assert!(weight <= start_y_weight && weight >= end_y_weight);
+		assert!(next_weight <= weight);
+		// decay is front-loaded, so the weight is never above the linear one
+		assert!(weight <= linear_weight + 1);
+	}
+
+	#[test]
+	fn exponential_weights_should_match_boundaries(
+		start_x_block in start_blocks(),
+		lbp_length in lbp_length(),
+		start_y_weight in initial_weight(),
+		end_y_weight in final_weight(),
+		rate in decay_rate(),
+	) {
+		let end_x_block = start_x_block.checked_add(lbp_length).unwrap();
+
+		assert_eq!(lbp::calculate_exponential_weights(start_x_block, end_x_block, start_y_weight, end_y_weight, rate, start_x_block), Ok(start_y_weight));
+		assert_eq!(lbp::calculate_exponential_weights(start_x_block, end_x_block, start_y_weight, end_y_weight, rate, end_x_block), Ok(end_y_weight));
+	}
+
+	#[test]
+	fn step_weights_should_follow_linear_weights_at_steps(
+		start_x_block in start_blocks(),
+		lbp_length in lbp_length(),
+		start_y_weight in initial_weight(),
+		end_y_weight in final_weight(),
+		steps in steps(),
+		at_offset in 0u32..ONE_WEEK_IN_BLOCKS,
+	) {
+		let end_x_block = start_x_block.checked_add(lbp_length).unwrap();
+		let at_block = start_x_block + at_offset % lbp_length;
+
+		let weight = lbp::calculate_step_weights(start_x_block, end_x_block, start_y_weight, end_y_weight, steps, at_block).unwrap();
+		let linear_weight = lbp::calculate_linear_weights(start_x_block, end_x_block, start_y_weight, end_y_weight, at_block).unwrap();
+
+		// weight increases in steps, so it lags behind the linear weight by less than one step
+		let step_size = (end_y_weight - start_y_weight) / steps + 1;
+		assert!(weight <= linear_weight);
+		assert!(linear_weight - weight <= step_size);
+	}
+
+	#[test]
+	fn piecewise_linear_weights_should_pass_through_breakpoint(
+		start_x_block in start_blocks(),
+		lbp_length in lbp_length(),
+		start_y_weight in initial_weight(),
+		end_y_weight in final_weight(),
+		breakpoint in breakpoint(),
+		at_offset in 0u32..ONE_WEEK_IN_BLOCKS,
+	) {
+		let end_x_block = start_x_block.checked_add(lbp_length).unwrap();
+		let at_block = start_x_block + at_offset % lbp_length;
+		let breakpoint_block = start_x_block + breakpoint.0.mul_floor(lbp_length);
+
+		let weight = lbp::calculate_piecewise_linear_weights(start_x_block, end_x_block, start_y_weight, end_y_weight, &[breakpoint], at_block).unwrap();
+
+		let min_weight = start_y_weight.min(end_y_weight).min(breakpoint.1);
+		let max_weight = start_y_weight.max(end_y_weight).max(breakpoint.1);
+		assert!(weight >= min_weight && weight <= max_weight);
+		assert_eq!(
+			lbp::calculate_piecewise_linear_weights(start_x_block, end_x_block, start_y_weight, end_y_weight, &[breakpoint], breakpoint_block),
+			Ok(breakpoint.1)
+		);
+	}
+}
@@ -1,10 +1,12 @@
 use core::convert::{TryFrom, TryInto};
 use primitive_types::U256;
 
+use crate::ratio::Ratio;
+use crate::support::rational::{round_to_rational, Rounding};
 use crate::types::{AssetId, Balance, LBPWeight};
 use crate::{
 	ensure, to_balance, to_lbp_weight, to_u256, MathError,
-	MathError::{Overflow, ZeroDuration, ZeroReserve},
+	MathError::{Overflow, ZeroDuration, ZeroReserve, ZeroWeight},
 };
 
 use core::convert::From;
@@ -12,7 +14,31 @@ use fixed::types::U32F96;
 use num_traits::{CheckedMul, CheckedSub, Zero};
 use sp_arithmetic;
 use sp_arithmetic::helpers_128bit::multiply_by_rational_with_rounding;
-use sp_arithmetic::{FixedPointNumber, FixedU128, Rounding};
+use sp_arithmetic::{FixedPointNumber, FixedU128, Permill, Rounding};
+
+/// Calculating spot price of asset b denominated in asset a as a rational number.
+/// Formula : (RESERVE_A / WEIGHT_A) / (RESERVE_B / WEIGHT_B)
+///
+/// - `reserve_a` - reserve amount of asset a
+/// - `reserve_b` - reserve amount of asset b
+/// - `weight_a` - pool weight of asset a
+/// - `weight_b` - pool weight of asset b
+///
+/// Returns `MathError::ZeroWeight` if any of the weights is zero.
+pub fn calculate_spot_price_ratio(
+	reserve_a: Balance,
+	reserve_b: Balance,
+	weight_a: LBPWeight,
+	weight_b: LBPWeight,
+) -> Result<Ratio, MathError> {
+	ensure!(weight_a != 0 && weight_b != 0, ZeroWeight);
+
+	let n = U256::from(reserve_a).saturating_mul(U256::from(weight_b));
+	let d = U256::from(reserve_b).saturating_mul(U256::from(weight_a));
+	let (n, d) = round_to_rational((n, d), Rounding::Nearest);
+
+	Ok(Ratio::new(n, d))
+}
 
 /// Calculating spot price given reserve of selling asset and reserve of buying asset.
 /// Formula : BUY_RESERVE * AMOUNT / SELL_RESERVE
@@ -211,6 +237,177 @@ pub fn calculate_linear_weights<BlockNumber: num_traits::CheckedSub + TryInto<u3
 	to_lbp_weight!(result)
 }
 
+/// Calculating weight at any given block in an interval using exponential decay of the weight difference.
+/// Formula : END_Y + (START_Y - END_Y) * (e^(RATE * REMAINING / DURATION) - 1) / (e^RATE - 1)
+///
+/// The weight moves quickly away from the initial weight at the beginning of the interval
+/// and slowly approaches the final weight at the end. Higher `rate` makes the curve steeper.
+/// Zero `rate` degenerates to linear interpolation.
+///
+/// - `start_x` - beginning of an interval
+/// - `end_x` - end of an interval
+/// - `start_y` - initial weight
+/// - `end_y` - final weight
+/// - `rate` - decay rate as a rational number
+/// - `at` - block number at which to calculate the weight
+pub fn calculate_exponential_weights<BlockNumber: num_traits::CheckedSub + TryInto<u32> + TryInto<u128>>(
+	start_x: BlockNumber,
+	end_x: BlockNumber,
+	start_y: LBPWeight,
+	end_y: LBPWeight,
+	rate: (u32, u32),
+	at: BlockNumber,
+) -> Result<LBPWeight, MathError> {
+	ensure!(rate.1 != 0, DivisionByZero);
+
+	let (elapsed, duration) = interval_progress(start_x, end_x, at)?;
+
+	if rate.0 == 0 {
+		// elapsed is not greater than duration
+		let elapsed: u32 = elapsed.try_into().map_err(|_| Overflow)?;
+		return calculate_linear_weights(0, duration, start_y, end_y, elapsed);
+	}
+	let remaining = u128::from(duration).checked_sub(elapsed).ok_or(Overflow)?;
+
+	// both exponents are scaled by the duration so that they are equal at the beginning of the interval
+	let denom = u128::from(rate.1).checked_mul(duration.into()).ok_or(Overflow)?;
+	let total_exp = div_to_fixed(
+		u128::from(rate.0).checked_mul(duration.into()).ok_or(Overflow)?,
+		denom,
+		Rounding::Down,
+	)
+	.ok_or(Overflow)?;
+	let remaining_exp = div_to_fixed(
+		u128::from(rate.0).checked_mul(remaining).ok_or(Overflow)?,
+		denom,
+		Rounding::Down,
+	)
+	.ok_or(Overflow)?;
+
+	let total: U32F96 = crate::transcendental::exp(total_exp, false).map_err(|_| Overflow)?;
+	let remaining: U32F96 = crate::transcendental::exp(remaining_exp, false).map_err(|_| Overflow)?;
+
+	let ratio = remaining
+		.checked_sub(U32F96::one())
+		.ok_or(Overflow)?
+		.checked_div(total.checked_sub(U32F96::one()).ok_or(Overflow)?)
+		.ok_or(DivisionByZero)?;
+
+	let delta = mul_to_balance(start_y.abs_diff(end_y).into(), ratio, Rounding::Down).ok_or(Overflow)?;
+
+	let result = if start_y >= end_y {
+		Balance::from(end_y).checked_add(delta)
+	} else {
+		Balance::from(end_y).checked_sub(delta)
+	}
+	.ok_or(Overflow)?;
+
+	to_lbp_weight!(result)
+}
+
+/// Calculating weight at any given block in an interval using a step function.
+///
+/// The interval is divided into `steps` equally long periods. The weight is constant within a period
+/// and changes by the same amount at the beginning of every period, reaching the final weight at the end of the interval.
+///
+/// - `start_x` - beginning of an interval
+/// - `end_x` - end of an interval
+/// - `start_y` - initial weight
+/// - `end_y` - final weight
+/// - `steps` - number of weight changes
+/// - `at` - block number at which to calculate the weight
+pub fn calculate_step_weights<BlockNumber: num_traits::CheckedSub + TryInto<u32> + TryInto<u128>>(
+	start_x: BlockNumber,
+	end_x: BlockNumber,
+	start_y: LBPWeight,
+	end_y: LBPWeight,
+	steps: u32,
+	at: BlockNumber,
+) -> Result<LBPWeight, MathError> {
+	ensure!(steps != 0, DivisionByZero);
+
+	let (elapsed, duration) = interval_progress(start_x, end_x, at)?;
+
+	let (start_y, end_y, steps, elapsed, duration) = to_u256!(start_y, end_y, steps, elapsed, duration);
+
+	let step = elapsed
+		.checked_mul(steps)
+		.ok_or(Overflow)?
+		.checked_div(duration)
+		.ok_or(Overflow)?;
+
+	let left_part = start_y
+		.checked_mul(steps.checked_sub(step).ok_or(Overflow)?)
+		.ok_or(Overflow)?;
+	let right_part = end_y.checked_mul(step).ok_or(Overflow)?;
+	let result = (left_part.checked_add(right_part).ok_or(Overflow)?)
+		.checked_div(steps)
+		.ok_or(Overflow)?;
+
+	to_lbp_weight!(result)
+}
+
+/// Calculating weight at any given block in an interval using piecewise linear interpolation.
+///
+/// The curve is a sequence of linear segments connecting the initial weight, `breakpoints` and the final weight.
+/// Breakpoint position is a fraction of the interval and the positions have to be non-decreasing.
+///
+/// - `start_x` - beginning of an interval
+/// - `end_x` - end of an interval
+/// - `start_y` - initial weight
+/// - `end_y` - final weight
+/// - `breakpoints` - positions and weights of the points between the segments
+/// - `at` - block number at which to calculate the weight
+pub fn calculate_piecewise_linear_weights<BlockNumber: num_traits::CheckedSub + TryInto<u32> + TryInto<u128>>(
+	start_x: BlockNumber,
+	end_x: BlockNumber,
+	start_y: LBPWeight,
+	end_y: LBPWeight,
+	breakpoints: &[(Permill, LBPWeight)],
+	at: BlockNumber,
+) -> Result<LBPWeight, MathError> {
+	let (elapsed, duration) = interval_progress(start_x, end_x, at)?;
+	// elapsed is not greater than duration
+	let elapsed: u32 = elapsed.try_into().map_err(|_| Overflow)?;
+
+	let points = breakpoints
+		.iter()
+		.map(|(position, weight)| (position.mul_floor(duration), *weight))
+		.chain(core::iter::once((duration, end_y)));
+
+	let mut previous = (0u32, start_y);
+	for point in points {
+		ensure!(point.0 >= previous.0, Overflow);
+
+		if elapsed < point.0 {
+			return calculate_linear_weights(previous.0, point.0, previous.1, point.1, elapsed);
+		}
+
+		previous = point;
+	}
+
+	Ok(end_y)
+}
+
+/// Elapsed part and total length of an interval.
+fn interval_progress<BlockNumber: num_traits::CheckedSub + TryInto<u32> + TryInto<u128>>(
+	start_x: BlockNumber,
+	end_x: BlockNumber,
+	at: BlockNumber,
+) -> Result<(u128, u32), MathError> {
+	let elapsed = at.checked_sub(&start_x).ok_or(Overflow)?;
+	let duration = end_x.checked_sub(&start_x).ok_or(Overflow)?;
+	ensure!(end_x.checked_sub(&at).is_some(), Overflow);
+
+	let duration: u32 = duration.try_into().map_err(|_| Overflow)?;
+	// if duration fits into u32, elapsed fits into u128
+	let elapsed: u128 = elapsed.try_into().map_err(|_| Overflow)?;
+
+	ensure!(duration != 0, ZeroDuration);
+
+	Ok((elapsed, duration))
+}
+
 /// Create a fixed point number based on two `u128` values. Divides the values and rounds according to `r`.
 pub(crate) fn div_to_fixed(num: u128, denom: u128, r: Rounding) -> Option<U32F96> {
 	let bits = multiply_by_rational_with_rounding(num, U32F96::one().to_bits(), denom, r)?;
@@ -1,8 +1,10 @@
 #![allow(clippy::type_complexity)]
 use crate::lbp::lbp;
 
+use crate::ratio::Ratio;
 use crate::types::{Balance, LBPWeight, HYDRA_ONE};
-use crate::MathError::{Overflow, ZeroDuration, ZeroReserve};
+use crate::MathError::{DivisionByZero, Overflow, ZeroDuration, ZeroReserve, ZeroWeight};
+use sp_arithmetic::Permill;
 
 use std::vec;
 
@@ -219,3 +221,249 @@ fn linear_weights_should_work() {
 		);
 	}
 }
+
+#[test]
+fn exponential_weights_should_work() {
+	let cases = vec![
+		(
+			100u32,
+			200u32,
+			2_000u32,
+			1_000u32,
+			(1, 1),
+			100u32,
+			Ok(2_000),
+			"Initial weight",
+		),
+		(
+			100u32,
+			200u32,
+			2_000u32,
+			1_000u32,
+			(1, 1),
+			200u32,
+			Ok(1_000),
+			"Final weight",
+		),
+		(
+			100u32,
+			200u32,
+			2_000u32,
+			1_000u32,
+			(1, 1),
+			150u32,
+			Ok(1_377),
+			"Easy decreasing case",
+		),
+		(
+			100u32,
+			200u32,
+			1_000u32,
+			2_000u32,
+			(1, 1),
+			150u32,
+			Ok(1_623),
+			"Easy case",
+		),
+		(
+			100u32,
+			200u32,
+			2_000u32,
+			1_000u32,
+			(0, 1),
+			170u32,
+			Ok(1_300),
+			"Zero rate is linear",
+		),
+		(
+			100u32,
+			200u32,
+			2_000u32,
+			1_000u32,
+			(1, 0),
+			170u32,
+			Err(DivisionByZero),
+			"Invalid rate",
+		),
+		(
+			100u32,
+			100u32,
+			2_000u32,
+			1_000u32,
+			(1, 1),
+			100u32,
+			Err(ZeroDuration),
+			"Invalid interval",
+		),
+		(
+			100u32,
+			200u32,
+			2_000u32,
+			1_000u32,
+			(1, 1),
+			210u32,
+			Err(Overflow),
+			"Out of bound",
+		),
+	];
+
+	for case in cases {
+		assert_eq!(
+			lbp::calculate_exponential_weights(case.0, case.1, case.2, case.3, case.4, case.5),
+			case.6,
+			"{}",
+			case.7
+		);
+	}
+}
+
+#[test]
+fn step_weights_should_work() {
+	let cases = vec![
+		(
+			100u32,
+			200u32,
+			1_000u32,
+			2_000u32,
+			4,
+			100u32,
+			Ok(1_000),
+			"Initial weight",
+		),
+		(100u32, 200u32, 1_000u32, 2_000u32, 4, 124u32, Ok(1_000), "First step"),
+		(100u32, 200u32, 1_000u32, 2_000u32, 4, 125u32, Ok(1_250), "Second step"),
+		(100u32, 200u32, 1_000u32, 2_000u32, 4, 199u32, Ok(1_750), "Last step"),
+		(100u32, 200u32, 1_000u32, 2_000u32, 4, 200u32, Ok(2_000), "Final weight"),
+		(
+			100u32,
+			200u32,
+			2_000u32,
+			1_000u32,
+			4,
+			150u32,
+			Ok(1_500),
+			"Easy decreasing case",
+		),
+		(
+			100u32,
+			200u32,
+			1_000u32,
+			2_000u32,
+			0,
+			150u32,
+			Err(DivisionByZero),
+			"Zero steps",
+		),
+		(
+			100u32,
+			200u32,
+			1_000u32,
+			2_000u32,
+			4,
+			10u32,
+			Err(Overflow),
+			"Out of bound",
+		),
+	];
+
+	for case in cases {
+		assert_eq!(
+			lbp::calculate_step_weights(case.0, case.1, case.2, case.3, case.4, case.5),
+			case.6,
+			"{}",
+			case.7
+		);
+	}
+}
+
+#[test]
+fn piecewise_linear_weights_should_work() {
+	let breakpoint = vec![(Permill::from_percent(50), 1_800u32)];
+	let cases = vec![
+		(100u32, &breakpoint, 1_000),
+		(125u32, &breakpoint, 1_400),
+		(150u32, &breakpoint, 1_800),
+		(175u32, &breakpoint, 1_900),
+		(200u32, &breakpoint, 2_000),
+	];
+
+	for case in cases {
+		assert_eq!(
+			lbp::calculate_piecewise_linear_weights(100u32, 200u32, 1_000u32, 2_000u32, case.1, case.0),
+			Ok(case.2),
+			"at {}",
+			case.0
+		);
+	}
+
+	// no breakpoints is linear
+	assert_eq!(
+		lbp::calculate_piecewise_linear_weights(100u32, 200u32, 1_000u32, 2_000u32, &[], 170u32),
+		Ok(1_700)
+	);
+
+	// later of the breakpoints at the same position is used
+	assert_eq!(
+		lbp::calculate_piecewise_linear_weights(
+			100u32,
+			200u32,
+			1_000u32,
+			2_000u32,
+			&[
+				(Permill::from_percent(50), 1_800u32),
+				(Permill::from_percent(50), 1_200u32)
+			],
+			150u32
+		),
+		Ok(1_200)
+	);
+
+	// breakpoints have to be ordered
+	assert_eq!(
+		lbp::calculate_piecewise_linear_weights(
+			100u32,
+			200u32,
+			1_000u32,
+			2_000u32,
+			&[
+				(Permill::from_percent(60), 1_800u32),
+				(Permill::from_percent(40), 1_200u32)
+			],
+			190u32
+		),
+		Err(Overflow)
+	);
+}
+
+#[test]
+fn spot_price_ratio_should_work() {
+	let cases: Vec<(u128, u128, u32, u32, Result<Ratio, crate::MathError>, &str)> = vec![
+		(
+			1000,
+			2000,
+			500,
+			500,
+			Ok(Ratio::new(500_000, 1_000_000)),
+			"Equal weights",
+		),
+		(
+			1000,
+			2000,
+			250,
+			750,
+			Ok(Ratio::new(750_000, 500_000)),
+			"Different weights",
+		),
+		(1000, 2000, 0, 750, Err(ZeroWeight), "Zero weight"),
+		(0, 2000, 250, 750, Ok(Ratio::new(0, 500_000)), "Zero reserve"),
+	];
+
+	for case in cases {
+		assert_eq!(
+			lbp::calculate_spot_price_ratio(case.0, case.1, case.2, case.3),
+			case.4,
+			"{}",
+			case.5
+		);
+	}
+}
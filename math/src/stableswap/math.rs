@@ -34,21 +34,11 @@ pub fn calculate_out_given_in<const D: u8, const Y: u8>(
 	if idx_in >= initial_reserves.len() || idx_out >= initial_reserves.len() {
 		return None;
 	}
-	let reserves = normalize_reserves(initial_reserves);
-	let amount_in = normalize_value(
-		amount_in,
-		initial_reserves[idx_in].decimals,
-		TARGET_PRECISION,
-		Rounding::Up,
-	);
+	let reserves = normalize_reserves(initial_reserves)?;
+	let amount_in = normalize_amount(amount_in, &initial_reserves[idx_in], Rounding::Up)?;
 	let new_reserve_out = calculate_y_given_in::<D, Y>(amount_in, idx_in, idx_out, &reserves, amplification)?;
 	let amount_out = reserves[idx_out].checked_sub(new_reserve_out)?;
-	let amount_out = normalize_value(
-		amount_out,
-		TARGET_PRECISION,
-		initial_reserves[idx_out].decimals,
-		Rounding::Down,
-	);
+	let amount_out = denormalize_amount(amount_out, &initial_reserves[idx_out], Rounding::Down)?;
 	Some(amount_out.saturating_sub(1u128))
 }
 
@@ -68,21 +58,11 @@ pub fn calculate_in_given_out<const D: u8, const Y: u8>(
 	if idx_in >= initial_reserves.len() || idx_out >= initial_reserves.len() {
 		return None;
 	}
-	let reserves = normalize_reserves(initial_reserves);
-	let amount_out = normalize_value(
-		amount_out,
-		initial_reserves[idx_out].decimals,
-		TARGET_PRECISION,
-		Rounding::Down,
-	);
+	let reserves = normalize_reserves(initial_reserves)?;
+	let amount_out = normalize_amount(amount_out, &initial_reserves[idx_out], Rounding::Down)?;
 	let new_reserve_in = calculate_y_given_out::<D, Y>(amount_out, idx_in, idx_out, &reserves, amplification)?;
 	let amount_in = new_reserve_in.checked_sub(reserves[idx_in])?;
-	let amount_in = normalize_value(
-		amount_in,
-		TARGET_PRECISION,
-		initial_reserves[idx_in].decimals,
-		Rounding::Up,
-	);
+	let amount_in = denormalize_amount(amount_in, &initial_reserves[idx_in], Rounding::Up)?;
 	Some(amount_in.saturating_add(1u128))
 }
 
@@ -161,10 +141,10 @@ pub fn calculate_shares<const D: u8>(
 				let ideal_balance = d1.checked_mul(initial_reserve)?.checked_div(d0)?;
 				let diff = Balance::try_from(updated_reserve.abs_diff(ideal_balance)).ok()?;
 				let fee_amount = fee.checked_mul_int(diff)?;
-				Some(AssetReserve::new(
-					asset_reserve.amount.saturating_sub(fee_amount),
-					asset_reserve.decimals,
-				))
+				Some(AssetReserve {
+					amount: asset_reserve.amount.saturating_sub(fee_amount),
+					..*asset_reserve
+				})
 			})
 			.collect::<Option<Vec<AssetReserve>>>()?
 	} else {
@@ -208,7 +188,10 @@ pub fn calculate_shares_for_amount<const D: u8>(
 		.enumerate()
 		.map(|(idx, v)| -> Option<AssetReserve> {
 			if idx == asset_idx {
-				Some(AssetReserve::new(v.amount.checked_sub(amount)?, v.decimals))
+				Some(AssetReserve {
+					amount: v.amount.checked_sub(amount)?,
+					..*v
+				})
 			} else {
 				Some(*v)
 			}
@@ -226,10 +209,10 @@ pub fn calculate_shares_for_amount<const D: u8>(
 			let ideal_balance = d1.checked_mul(initial_reserve)?.checked_div(d0)?;
 			let diff = Balance::try_from(updated_reserve.abs_diff(ideal_balance)).ok()?;
 			let fee_amount = fee.checked_mul_int(diff)?;
-			Some(AssetReserve::new(
-				asset_reserve.amount.saturating_sub(fee_amount),
-				asset_reserve.decimals,
-			))
+			Some(AssetReserve {
+				amount: asset_reserve.amount.saturating_sub(fee_amount),
+				..*asset_reserve
+			})
 		})
 		.collect::<Option<Vec<AssetReserve>>>()?;
 
@@ -279,8 +262,8 @@ pub fn calculate_withdraw_one_asset<const D: u8, const Y: u8>(
 	if n_coins <= 1 {
 		return None;
 	}
-	let asset_out_decimals = reserves[asset_index].decimals;
-	let reserves = normalize_reserves(reserves);
+	let asset_out_reserve = reserves[asset_index];
+	let reserves = normalize_reserves(reserves)?;
 
 	let fixed_fee = FixedU128::from(fee);
 	let fee = fixed_fee
@@ -332,8 +315,8 @@ pub fn calculate_withdraw_one_asset<const D: u8, const Y: u8>(
 	let dy_0 = reserves[asset_index].checked_sub(y)?;
 	let fee = dy_0.checked_sub(dy)?;
 
-	let amount_out = normalize_value(dy, TARGET_PRECISION, asset_out_decimals, Rounding::Down);
-	let fee = normalize_value(fee, TARGET_PRECISION, asset_out_decimals, Rounding::Down);
+	let amount_out = denormalize_amount(dy, &asset_out_reserve, Rounding::Down)?;
+	let fee = denormalize_amount(fee, &asset_out_reserve, Rounding::Down)?;
 	Some((amount_out, fee))
 }
 
@@ -360,8 +343,8 @@ pub fn calculate_add_one_asset<const D: u8, const Y: u8>(
 		return None;
 	}
 
-	let asset_in_decimals = reserves[asset_index].decimals;
-	let reserves = normalize_reserves(reserves);
+	let asset_in_reserve = reserves[asset_index];
+	let reserves = normalize_reserves(reserves)?;
 
 	let initial_d = calculate_d_internal::<D>(&reserves, amplification)?;
 	let (shares_hp, issuance_hp, d_hp) = to_u256!(shares, share_asset_issuance, initial_d);
@@ -411,12 +394,12 @@ pub fn calculate_add_one_asset<const D: u8, const Y: u8>(
 	let dy = y1.checked_sub(asset_reserve)?;
 	let dy_0 = y.checked_sub(asset_reserve)?;
 	let fee = dy.checked_sub(dy_0)?;
-	let amount_in = normalize_value(dy, TARGET_PRECISION, asset_in_decimals, Rounding::Up);
-	let fee = normalize_value(fee, TARGET_PRECISION, asset_in_decimals, Rounding::Down);
+	let amount_in = denormalize_amount(dy, &asset_in_reserve, Rounding::Up)?;
+	let fee = denormalize_amount(fee, &asset_in_reserve, Rounding::Down)?;
 	Some((amount_in, fee))
 }
 pub fn calculate_d<const D: u8>(reserves: &[AssetReserve], amplification: Balance) -> Option<Balance> {
-	let n_reserves = normalize_reserves(reserves);
+	let n_reserves = normalize_reserves(reserves)?;
 	calculate_d_internal::<D>(&n_reserves, amplification)
 }
 
@@ -614,6 +597,27 @@ pub fn calculate_amplification(
 	}
 }
 
+/// Calculate current fee of a pool with fee ramping from initial to final fee.
+///
+/// Fee changes linearly between `initial_block` and `final_block`, same as amplification.
+pub fn calculate_ramped_fee(
+	initial_fee: Permill,
+	final_fee: Permill,
+	initial_block: u128,
+	final_block: u128,
+	current_block: u128,
+) -> Permill {
+	let fee = calculate_amplification(
+		initial_fee.deconstruct().into(),
+		final_fee.deconstruct().into(),
+		initial_block,
+		final_block,
+		current_block,
+	);
+	// result is always between initial and final fee, so it fits into u32
+	Permill::from_parts(fee as u32)
+}
+
 #[inline]
 fn has_converged(v0: U256, v1: U256, precision: U256) -> bool {
 	let diff = abs_diff(v0, v1);
@@ -631,6 +635,7 @@ fn abs_diff(d0: U256, d1: U256) -> U256 {
 	}
 }
 
+#[derive(Clone, Copy)]
 pub(crate) enum Rounding {
 	Down,
 	Up,
@@ -643,13 +648,44 @@ fn calculate_fee_amount(amount: Balance, fee: Permill, rounding: Rounding) -> Ba
 	}
 }
 
-pub(crate) fn normalize_reserves(reserves: &[AssetReserve]) -> Vec<Balance> {
+/// Convert reserves to target precision and scale them by asset rates.
+pub(crate) fn normalize_reserves(reserves: &[AssetReserve]) -> Option<Vec<Balance>> {
 	reserves
 		.iter()
-		.map(|v| normalize_value(v.amount, v.decimals, TARGET_PRECISION, Rounding::Down))
+		.map(|v| normalize_amount(v.amount, v, Rounding::Down))
 		.collect()
 }
 
+/// Convert amount of an asset to target precision and scale it by the asset's rate.
+pub(crate) fn normalize_amount(amount: Balance, reserve: &AssetReserve, rounding: Rounding) -> Option<Balance> {
+	let amount = normalize_value(amount, reserve.decimals, TARGET_PRECISION, rounding);
+	scale_by_rate(amount, reserve.rate, rounding)
+}
+
+/// Convert rate scaled amount in target precision back to amount of an asset.
+pub(crate) fn denormalize_amount(amount: Balance, reserve: &AssetReserve, rounding: Rounding) -> Option<Balance> {
+	let amount = scale_by_rate(amount, (reserve.rate.1, reserve.rate.0), rounding)?;
+	Some(normalize_value(amount, TARGET_PRECISION, reserve.decimals, rounding))
+}
+
+fn scale_by_rate(amount: Balance, rate: (Balance, Balance), rounding: Rounding) -> Option<Balance> {
+	if rate.0.is_zero() || rate.1.is_zero() {
+		return None;
+	}
+	if rate.0 == rate.1 {
+		return Some(amount);
+	}
+	let (amount, num, denom) = to_u256!(amount, rate.0, rate.1);
+	let scaled = amount.checked_mul(num)?;
+	let scaled = match rounding {
+		Rounding::Down => scaled.checked_div(denom)?,
+		Rounding::Up => scaled
+			.checked_add(denom.checked_sub(U256::one())?)?
+			.checked_div(denom)?,
+	};
+	Balance::try_from(scaled).ok()
+}
+
 pub(crate) fn normalize_value(amount: Balance, decimals: u8, target_decimals: u8, rounding: Rounding) -> Balance {
 	if target_decimals == decimals {
 		return amount;
@@ -704,7 +740,7 @@ pub fn calculate_share_price<const D: u8>(
 	} else {
 		calculate_d::<D>(reserves, amplification)?
 	};
-	let n_reserves = normalize_reserves(reserves);
+	let n_reserves = normalize_reserves(reserves)?;
 
 	let c = n_reserves
 		.iter()
@@ -725,6 +761,11 @@ pub fn calculate_share_price<const D: u8>(
 	let num = p1.checked_add(p2)?.checked_sub(p3)?;
 	let denom = issuance.checked_mul(xann.checked_add(c)?)?;
 
+	// Price is calculated for rate scaled reserve, convert it back to the asset
+	let (rate_num, rate_denom) = to_u256!(reserves[asset_idx].rate.0, reserves[asset_idx].rate.1);
+	let num = num.checked_mul(rate_denom)?;
+	let denom = denom.checked_mul(rate_num)?;
+
 	let p_diff = U256::from(10u128.saturating_pow(18u8.saturating_sub(reserves[asset_idx].decimals) as u32));
 	let (num, denom) = if let Some(v) = denom.checked_mul(p_diff) {
 		(num, v)
@@ -854,7 +895,7 @@ pub fn calculate_spot_price_between_two_stable_assets(
 	}
 	let ann = calculate_ann(n, amplification)?;
 
-	let mut n_reserves = normalize_reserves(reserves);
+	let mut n_reserves = normalize_reserves(reserves)?;
 
 	let x0 = n_reserves[asset_in_idx];
 	let xi = n_reserves[asset_out_idx];
@@ -870,6 +911,16 @@ pub fn calculate_spot_price_between_two_stable_assets(
 	let num = x0.checked_mul(ann.checked_mul(xi)?.checked_add(c)?)?;
 	let denom = xi.checked_mul(ann.checked_mul(x0)?.checked_add(c)?)?;
 
+	// Price is calculated for rate scaled reserves, convert it back to the assets
+	let (in_rate_num, in_rate_denom, out_rate_num, out_rate_denom) = to_u256!(
+		reserves[asset_in_idx].rate.0,
+		reserves[asset_in_idx].rate.1,
+		reserves[asset_out_idx].rate.0,
+		reserves[asset_out_idx].rate.1
+	);
+	let num = num.checked_mul(out_rate_num)?.checked_mul(in_rate_denom)?;
+	let denom = denom.checked_mul(out_rate_denom)?.checked_mul(in_rate_num)?;
+
 	let mut spot_price = round_to_rational((num, denom), crate::support::rational::Rounding::Down);
 
 	if let Some(fee) = fee {
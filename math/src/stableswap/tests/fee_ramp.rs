@@ -0,0 +1,28 @@
+use crate::stableswap::calculate_ramped_fee;
+use sp_arithmetic::Permill;
+
+#[test]
+fn calculate_ramped_fee_should_return_initial_fee_when_ramp_has_not_started() {
+	let result = calculate_ramped_fee(Permill::from_percent(1), Permill::from_percent(3), 100, 200, 50);
+	assert_eq!(result, Permill::from_percent(1));
+}
+
+#[test]
+fn calculate_ramped_fee_should_return_final_fee_when_ramp_has_finished() {
+	let result = calculate_ramped_fee(Permill::from_percent(1), Permill::from_percent(3), 100, 200, 250);
+	assert_eq!(result, Permill::from_percent(3));
+}
+
+#[test]
+fn calculate_ramped_fee_should_increase_fee_linearly() {
+	let result = calculate_ramped_fee(Permill::from_percent(1), Permill::from_percent(3), 100, 200, 150);
+	assert_eq!(result, Permill::from_percent(2));
+}
+
+#[test]
+fn calculate_ramped_fee_should_decrease_fee_linearly() {
+	for idx in 0..1000u128 {
+		let result = calculate_ramped_fee(Permill::from_parts(5000), Permill::from_parts(2000), 0, 1000, idx);
+		assert_eq!(result, Permill::from_parts(5000 - idx as u32 * 3));
+	}
+}
@@ -0,0 +1,138 @@
+const D_ITERATIONS: u8 = 128;
+const Y_ITERATIONS: u8 = 64;
+
+use crate::stableswap::types::AssetReserve;
+use crate::stableswap::*;
+use crate::types::Balance;
+use sp_arithmetic::{FixedU128, Permill};
+
+const ONE: Balance = 1_000_000_000_000_000_000;
+
+fn balanced_reserves() -> [AssetReserve; 2] {
+	[AssetReserve::new(1_000 * ONE, 18), AssetReserve::new(1_000 * ONE, 18)]
+}
+
+fn rate_reserves() -> [AssetReserve; 2] {
+	[
+		AssetReserve::new(1_000 * ONE, 18),
+		AssetReserve::new(2_000 * ONE, 18).with_rate((1, 2)),
+	]
+}
+
+#[test]
+fn calculate_d_should_use_rate_scaled_reserves() {
+	let amp = 100u128;
+
+	let expected = calculate_d::<D_ITERATIONS>(&balanced_reserves(), amp);
+	let result = calculate_d::<D_ITERATIONS>(&rate_reserves(), amp);
+
+	assert!(expected.is_some());
+	assert_eq!(result, expected);
+}
+
+#[test]
+fn calculate_out_given_in_should_convert_amount_out_with_rate() {
+	let amp = 100u128;
+	let amount_in = 10 * ONE;
+
+	let expected =
+		calculate_out_given_in::<D_ITERATIONS, Y_ITERATIONS>(&balanced_reserves(), 0, 1, amount_in, amp).unwrap();
+	let result = calculate_out_given_in::<D_ITERATIONS, Y_ITERATIONS>(&rate_reserves(), 0, 1, amount_in, amp).unwrap();
+
+	assert_eq!(result, expected * 2);
+}
+
+#[test]
+fn calculate_out_given_in_should_convert_amount_in_with_rate() {
+	let amp = 100u128;
+	let amount_in = 10 * ONE;
+
+	let expected =
+		calculate_out_given_in::<D_ITERATIONS, Y_ITERATIONS>(&balanced_reserves(), 1, 0, amount_in, amp).unwrap();
+	let result =
+		calculate_out_given_in::<D_ITERATIONS, Y_ITERATIONS>(&rate_reserves(), 1, 0, 2 * amount_in, amp).unwrap();
+
+	assert_eq!(result, expected);
+}
+
+#[test]
+fn calculate_in_given_out_should_convert_amounts_with_rate() {
+	let amp = 100u128;
+	let amount_out = 10 * ONE;
+
+	let expected =
+		calculate_in_given_out::<D_ITERATIONS, Y_ITERATIONS>(&balanced_reserves(), 0, 1, amount_out, amp).unwrap();
+	let result =
+		calculate_in_given_out::<D_ITERATIONS, Y_ITERATIONS>(&rate_reserves(), 0, 1, 2 * amount_out, amp).unwrap();
+
+	assert_eq!(result, expected);
+}
+
+#[test]
+fn calculate_shares_should_be_same_as_balanced_pool_when_amount_is_scaled_by_rate() {
+	let amp = 100u128;
+	let issuance = 2_000 * ONE;
+
+	let expected =
+		calculate_shares_for_amount::<D_ITERATIONS>(&balanced_reserves(), 1, 10 * ONE, amp, issuance, Permill::zero())
+			.unwrap();
+	let result =
+		calculate_shares_for_amount::<D_ITERATIONS>(&rate_reserves(), 1, 20 * ONE, amp, issuance, Permill::zero())
+			.unwrap();
+
+	assert_eq!(result, expected);
+}
+
+#[test]
+fn calculate_withdraw_one_asset_should_convert_amount_out_with_rate() {
+	let amp = 100u128;
+	let issuance = 2_000 * ONE;
+	let shares = 10 * ONE;
+
+	let (expected, _) = calculate_withdraw_one_asset::<D_ITERATIONS, Y_ITERATIONS>(
+		&balanced_reserves(),
+		shares,
+		1,
+		issuance,
+		amp,
+		Permill::zero(),
+	)
+	.unwrap();
+	let (result, _) = calculate_withdraw_one_asset::<D_ITERATIONS, Y_ITERATIONS>(
+		&rate_reserves(),
+		shares,
+		1,
+		issuance,
+		amp,
+		Permill::zero(),
+	)
+	.unwrap();
+
+	assert_eq!(result, expected * 2);
+}
+
+#[test]
+fn spot_price_should_include_rates() {
+	let amp = 100u128;
+
+	let reserves = rate_reserves();
+	let d = calculate_d::<D_ITERATIONS>(&reserves, amp).unwrap();
+	let result = calculate_spot_price_between_two_stable_assets(&reserves, amp, d, 0, 1, None);
+
+	assert_eq!(result, Some(FixedU128::from_rational(1, 2)));
+}
+
+#[test]
+fn calculations_should_fail_when_rate_is_zero() {
+	let amp = 100u128;
+	let reserves = [
+		AssetReserve::new(1_000 * ONE, 18),
+		AssetReserve::new(1_000 * ONE, 18).with_rate((0, 1)),
+	];
+
+	assert_eq!(calculate_d::<D_ITERATIONS>(&reserves, amp), None);
+	assert_eq!(
+		calculate_out_given_in::<D_ITERATIONS, Y_ITERATIONS>(&reserves, 0, 1, ONE, amp),
+		None
+	);
+}
@@ -1,15 +1,26 @@
 use crate::types::Balance;
-use num_traits::Zero;
+use num_traits::{One, Zero};
 
 #[derive(Debug, Clone, Copy)]
 pub struct AssetReserve {
 	pub amount: Balance,
 	pub decimals: u8,
+	/// Target rate of the asset - (numerator, denominator).
+	/// Reserve is scaled by the rate before it is used in calculations.
+	pub rate: (Balance, Balance),
 }
 
 impl AssetReserve {
 	pub fn new(amount: Balance, decimals: u8) -> Self {
-		Self { amount, decimals }
+		Self {
+			amount,
+			decimals,
+			rate: (Balance::one(), Balance::one()),
+		}
+	}
+
+	pub fn with_rate(self, rate: (Balance, Balance)) -> Self {
+		Self { rate, ..self }
 	}
 
 	pub fn is_zero(&self) -> bool {
@@ -216,3 +216,59 @@ pub fn calculate_shares(asset_reserve: Balance, asset_amount: Balance, share_iss
 
 	to_balance!(result).ok()
 }
+
+/// Calculating square root of the pool invariant.
+/// Formula : sqrt(ASSET_A_RESERVE * ASSET_B_RESERVE)
+///
+/// - `asset_a_reserve` - reserve amount of asset a
+/// - `asset_b_reserve` - reserve amount of asset b
+///
+/// Returns MathError in case of error
+pub fn calculate_root_k(asset_a_reserve: Balance, asset_b_reserve: Balance) -> Result<Balance, MathError> {
+	let (a_reserve_hp, b_reserve_hp) = to_u256!(asset_a_reserve, asset_b_reserve);
+
+	let root_k_hp = a_reserve_hp.checked_mul(b_reserve_hp).ok_or(Overflow)?.integer_sqrt();
+
+	to_balance!(root_k_hp)
+}
+
+/// Calculating amount of shares minted as protocol fee for the growth of the pool invariant
+/// since last liquidity change.
+/// Formula : ISSUANCE * (ROOT_K - ROOT_K_LAST) * P / (ROOT_K * (Q - P) + ROOT_K_LAST * P)
+///
+/// where P / Q is a protocol share of the trade fees.
+///
+/// - `root_k` - current square root of the pool invariant
+/// - `root_k_last` - square root of the pool invariant after last liquidity change
+/// - `share_issuance` - total issuance of share asset
+/// - `protocol_fee` - protocol share of the trade fees
+///
+/// Returns MathError in case of error
+pub fn calculate_protocol_fee_shares(
+	root_k: Balance,
+	root_k_last: Balance,
+	share_issuance: Balance,
+	protocol_fee: (u32, u32),
+) -> Result<Balance, MathError> {
+	let (numerator, denominator) = protocol_fee;
+	ensure!(denominator != 0 && numerator <= denominator, Overflow);
+
+	if root_k <= root_k_last || root_k_last.is_zero() || numerator.is_zero() {
+		return Ok(Balance::zero());
+	}
+
+	let (root_k_hp, root_k_last_hp, issuance_hp, p_hp, q_hp) =
+		to_u256!(root_k, root_k_last, share_issuance, numerator, denominator);
+
+	let shares_numerator = issuance_hp
+		.checked_mul(root_k_hp - root_k_last_hp)
+		.and_then(|v| v.checked_mul(p_hp))
+		.ok_or(Overflow)?;
+
+	let shares_denominator = root_k_hp
+		.checked_mul(q_hp - p_hp)
+		.and_then(|v| v.checked_add(root_k_last_hp.checked_mul(p_hp)?))
+		.ok_or(Overflow)?;
+
+	to_balance!(shares_numerator.checked_div(shares_denominator).ok_or(Overflow)?)
+}
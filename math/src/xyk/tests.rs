@@ -138,3 +138,40 @@ fn calculate_shares() {
 		);
 	}
 }
+
+#[test]
+fn calculate_root_k_should_work() {
+	let cases = vec![
+		(100, 10_000, Ok(1_000), "Easy case"),
+		(2, 3, Ok(2), "Rounded down"),
+		(0, 1_000, Ok(0), "Zero reserve"),
+		(u128::MAX, u128::MAX, Ok(u128::MAX), "Max reserves"),
+	];
+
+	for case in cases {
+		assert_eq!(crate::xyk::calculate_root_k(case.0, case.1), case.2, "{}", case.3);
+	}
+}
+
+#[test]
+fn calculate_protocol_fee_shares_should_work() {
+	let cases = vec![
+		(110, 100, 1_000, (1, 6), Ok(15), "Easy case"),
+		(110, 100, 1_000, (1, 1), Ok(100), "Whole fee growth"),
+		(100, 100, 1_000, (1, 6), Ok(0), "No growth"),
+		(90, 100, 1_000, (1, 6), Ok(0), "Invariant decreased"),
+		(110, 0, 1_000, (1, 6), Ok(0), "Root k last not set"),
+		(110, 100, 1_000, (0, 6), Ok(0), "Protocol fee is zero"),
+		(110, 100, 1_000, (1, 0), Err(Overflow), "Invalid protocol fee"),
+		(110, 100, 1_000, (7, 6), Err(Overflow), "Protocol fee greater than one"),
+	];
+
+	for case in cases {
+		assert_eq!(
+			crate::xyk::calculate_protocol_fee_shares(case.0, case.1, case.2, case.3),
+			case.4,
+			"{}",
+			case.5
+		);
+	}
+}
@@ -189,5 +189,36 @@ benchmarks! {
 		assert!(before_out != after_out);
 	}
 
+	set_pool_price_barrier {
+		let asset_a = T::AssetId::from(2u32);
+		let asset_b = T::AssetId::from(3u32);
+		let max_deviation = Some(Permill::from_percent(5));
+	}: _(RawOrigin::Root, *b"omnipool", asset_a, asset_b, max_deviation)
+	verify {
+		assert_eq!(PoolPriceBarrier::<T>::get(*b"omnipool", (asset_a, asset_b)), max_deviation);
+	}
+
+	set_asset_price_barrier {
+		let asset_id = T::AssetId::from(2u32);
+		let max_deviation = Some(Permill::from_percent(5));
+	}: _(RawOrigin::Root, asset_id, max_deviation)
+	verify {
+		assert_eq!(AssetPriceBarrier::<T>::get(asset_id), max_deviation);
+	}
+
+	ensure_price {
+		let user: T::AccountId = account("user", 0, 1);
+		let asset_a = T::AssetId::from(2u32);
+		let asset_b = T::AssetId::from(3u32);
+		// pool price barrier is not set so both asset price barriers are read
+		crate::Pallet::<T>::set_asset_price_barrier(RawOrigin::Root.into(), asset_a, Some(Permill::from_percent(5)))?;
+		crate::Pallet::<T>::set_asset_price_barrier(RawOrigin::Root.into(), asset_b, Some(Permill::from_percent(10)))?;
+	}: {
+		crate::Pallet::<T>::ensure_price(&user, *b"omnipool", asset_a, asset_b, EmaPrice::new(1, 1))?
+	}
+	verify {
+		assert_eq!(crate::Pallet::<T>::max_price_deviation(*b"omnipool", asset_a, asset_b), Some(Permill::from_percent(5)));
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::tests::mock::ExtBuilder::default().build(), crate::tests::mock::Test);
 }
@@ -23,10 +23,14 @@ use frame_support::weights::Weight;
 use frame_support::{ensure, pallet_prelude::DispatchResult, traits::Get};
 use frame_system::ensure_signed_or_root;
 use frame_system::pallet_prelude::{BlockNumberFor, OriginFor};
+use hydra_dx_math::ema::EmaPrice;
+use hydradx_traits::price::PriceBarrier;
+use hydradx_traits::{AggregatedPriceOracle, OraclePeriod, Source};
 use scale_info::TypeInfo;
-use sp_core::MaxEncodedLen;
+use sp_core::{MaxEncodedLen, U256};
 use sp_runtime::traits::{AtLeast32BitUnsigned, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Zero};
-use sp_runtime::{ArithmeticError, DispatchError, RuntimeDebug};
+use sp_runtime::{ArithmeticError, DispatchError, PerThing, Permill, RuntimeDebug};
+use sp_std::marker::PhantomData;
 
 pub mod weights;
 
@@ -162,6 +166,13 @@ pub mod pallet {
 					"Circuit Breaker: Max remove liquidity limit per block is set to invalid value."
 				);
 			}
+
+			if let Some(max_deviation) = T::DefaultMaxPriceDeviation::get() {
+				assert!(
+					Self::validate_max_deviation(max_deviation).is_ok(),
+					"Circuit Breaker: Max price deviation is set to invalid value."
+				);
+			}
 		}
 	}
 
@@ -220,6 +231,16 @@ pub mod pallet {
 		/// Omnipool's hub asset id. The limits are not tracked for this asset.
 		type OmnipoolHubAsset: Get<Self::AssetId>;
 
+		/// Oracle providing the reference price for the price barrier of liquidity operations.
+		type PriceBarrierOracle: AggregatedPriceOracle<Self::AssetId, BlockNumberFor<Self>, EmaPrice>;
+
+		/// The maximum allowed deviation of a pool's spot price from the oracle price for adding
+		/// or removing liquidity. Applies to pools and assets without a price barrier set by
+		/// `set_pool_price_barrier` or `set_asset_price_barrier`.
+		/// If set to None, the price is not checked by default.
+		#[pallet::constant]
+		type DefaultMaxPriceDeviation: Get<Option<Permill>>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -284,6 +305,18 @@ pub mod pallet {
 	pub type AllowedRemoveLiquidityAmountPerAsset<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::AssetId, LiquidityLimit<T>>;
 
+	#[pallet::storage]
+	/// Maximum allowed deviation of the spot price from the oracle price per pool.
+	/// Pools are identified by the oracle source and the ordered asset pair.
+	#[pallet::getter(fn pool_price_barrier)]
+	pub type PoolPriceBarrier<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, Source, Blake2_128Concat, (T::AssetId, T::AssetId), Permill>;
+
+	#[pallet::storage]
+	/// Maximum allowed deviation of the spot price from the oracle price for pools containing an asset.
+	#[pallet::getter(fn asset_price_barrier)]
+	pub type AssetPriceBarrier<T: Config> = StorageMap<_, Blake2_128Concat, T::AssetId, Permill>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -302,6 +335,17 @@ pub mod pallet {
 			asset_id: T::AssetId,
 			liquidity_limit: Option<(u32, u32)>,
 		},
+		/// Price barrier of a pool was changed.
+		PoolPriceBarrierChanged {
+			source: Source,
+			assets: (T::AssetId, T::AssetId),
+			max_deviation: Option<Permill>,
+		},
+		/// Price barrier of an asset was changed.
+		AssetPriceBarrierChanged {
+			asset_id: T::AssetId,
+			max_deviation: Option<Permill>,
+		},
 	}
 
 	#[pallet::error]
@@ -319,6 +363,10 @@ pub mod pallet {
 		MaxLiquidityLimitPerBlockReached,
 		/// Asset is not allowed to have a limit
 		NotAllowed,
+		/// Spot price deviates from the oracle price more than allowed.
+		/// `deviation` is the observed deviation in basis points (1 bps = 0.01%), rounded down
+		/// and saturating at 10_000 bps.
+		PriceDeviationTooHigh { deviation: u16 },
 	}
 
 	#[pallet::call]
@@ -422,7 +470,115 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Set price barrier for a pool.
+		///
+		/// Adding or removing liquidity is rejected if the spot price of the pool deviates from
+		/// the oracle price by more than `max_deviation`. The pool setting takes precedence over
+		/// the asset settings.
+		///
+		/// Rejected operations fail with `PriceDeviationTooHigh` carrying the observed deviation
+		/// in basis points, while `max_deviation` is set as `Permill`.
+		///
+		/// Parameters:
+		/// - `origin`: The dispatch origin for this call. Must be `TechnicalOrigin`
+		/// - `source`: Oracle source of the pool
+		/// - `asset_a`: The identifier of the first asset of the pool
+		/// - `asset_b`: The identifier of the second asset of the pool
+		/// - `max_deviation`: Optional maximum allowed deviation. None removes the barrier.
+		///
+		/// Emits `PoolPriceBarrierChanged` event when successful.
+		///
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_pool_price_barrier())]
+		pub fn set_pool_price_barrier(
+			origin: OriginFor<T>,
+			source: Source,
+			asset_a: T::AssetId,
+			asset_b: T::AssetId,
+			max_deviation: Option<Permill>,
+		) -> DispatchResult {
+			T::TechnicalOrigin::ensure_origin(origin)?;
+
+			ensure!(asset_a != asset_b, Error::<T>::NotAllowed);
+
+			if let Some(deviation) = max_deviation {
+				Self::validate_max_deviation(deviation)?;
+			}
+
+			let assets = ordered_pair(asset_a, asset_b);
+			<PoolPriceBarrier<T>>::set(source, assets, max_deviation);
+
+			Self::deposit_event(Event::PoolPriceBarrierChanged {
+				source,
+				assets,
+				max_deviation,
+			});
+
+			Ok(())
+		}
+
+		/// Set price barrier for all pools containing an asset.
+		///
+		/// If both assets of a pool have a price barrier, the stricter one applies.
+		///
+		/// Parameters:
+		/// - `origin`: The dispatch origin for this call. Must be `TechnicalOrigin`
+		/// - `asset_id`: The identifier of an asset
+		/// - `max_deviation`: Optional maximum allowed deviation. None removes the barrier.
+		///
+		/// Emits `AssetPriceBarrierChanged` event when successful.
+		///
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_asset_price_barrier())]
+		pub fn set_asset_price_barrier(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			max_deviation: Option<Permill>,
+		) -> DispatchResult {
+			T::TechnicalOrigin::ensure_origin(origin)?;
+
+			if let Some(deviation) = max_deviation {
+				Self::validate_max_deviation(deviation)?;
+			}
+
+			<AssetPriceBarrier<T>>::set(asset_id, max_deviation);
+
+			Self::deposit_event(Event::AssetPriceBarrierChanged {
+				asset_id,
+				max_deviation,
+			});
+
+			Ok(())
+		}
+	}
+}
+
+fn ordered_pair<AssetId: PartialOrd>(asset_a: AssetId, asset_b: AssetId) -> (AssetId, AssetId) {
+	if asset_a < asset_b {
+		(asset_a, asset_b)
+	} else {
+		(asset_b, asset_a)
+	}
+}
+
+/// Relative deviation of the spot price from the oracle price, saturating at 100%.
+///
+/// Returns None if the oracle price is zero.
+pub fn price_deviation(spot_price: EmaPrice, oracle_price: EmaPrice) -> Option<Permill> {
+	let spot = U256::from(spot_price.n) * U256::from(oracle_price.d);
+	let oracle = U256::from(oracle_price.n) * U256::from(spot_price.d);
+	if oracle.is_zero() {
+		return None;
 	}
+	let diff = if spot > oracle { spot - oracle } else { oracle - spot };
+	let parts = diff.full_mul(U256::from(Permill::ACCURACY)) / oracle.full_mul(U256::one());
+	Some(Permill::from_parts(parts.min(Permill::ACCURACY.into()).low_u32()))
+}
+
+/// Price deviation in basis points, as carried by the `PriceDeviationTooHigh` error.
+pub fn deviation_in_bps(deviation: Permill) -> u16 {
+	(deviation.deconstruct() / 100).min(u16::MAX.into()) as u16
 }
 
 impl<T: Config> Pallet<T> {
@@ -617,6 +773,75 @@ impl<T: Config> Pallet<T> {
 		Ok(T::WeightInfo::ensure_remove_liquidity_limit())
 	}
 
+	/// Ensure that the spot price of a pool does not deviate from the `Short` oracle price by more
+	/// than the price barrier configured for the pool.
+	///
+	/// Operations of whitelisted accounts and pools without an oracle entry are not checked.
+	/// Rejected operations fail with `PriceDeviationTooHigh` carrying the observed deviation
+	/// in basis points.
+	pub fn ensure_price(
+		who: &T::AccountId,
+		source: Source,
+		asset_a: T::AssetId,
+		asset_b: T::AssetId,
+		spot_price: EmaPrice,
+	) -> DispatchResult {
+		if T::WhitelistedAccounts::contains(who) {
+			return Ok(());
+		}
+
+		let Some(max_deviation) = Self::max_price_deviation(source, asset_a, asset_b) else {
+			return Ok(());
+		};
+
+		let Ok((oracle_price, _)) = T::PriceBarrierOracle::get_price(asset_a, asset_b, OraclePeriod::Short, source)
+		else {
+			return Ok(());
+		};
+
+		let Some(deviation) = price_deviation(spot_price, oracle_price) else {
+			return Ok(());
+		};
+
+		ensure!(
+			deviation <= max_deviation,
+			Error::<T>::PriceDeviationTooHigh {
+				deviation: deviation_in_bps(deviation),
+			}
+		);
+
+		Ok(())
+	}
+
+	pub fn ensure_price_weight() -> Weight {
+		T::WeightInfo::ensure_price().saturating_add(T::PriceBarrierOracle::get_price_weight())
+	}
+
+	/// Maximum allowed price deviation of a pool.
+	///
+	/// The pool setting takes precedence. Otherwise the stricter of the asset settings applies,
+	/// falling back to the default.
+	pub fn max_price_deviation(source: Source, asset_a: T::AssetId, asset_b: T::AssetId) -> Option<Permill> {
+		if let Some(deviation) = Pallet::<T>::pool_price_barrier(source, ordered_pair(asset_a, asset_b)) {
+			return Some(deviation);
+		}
+
+		match (
+			Pallet::<T>::asset_price_barrier(asset_a),
+			Pallet::<T>::asset_price_barrier(asset_b),
+		) {
+			(Some(a), Some(b)) => Some(a.min(b)),
+			(Some(a), None) => Some(a),
+			(None, Some(b)) => Some(b),
+			(None, None) => T::DefaultMaxPriceDeviation::get(),
+		}
+	}
+
+	pub fn validate_max_deviation(max_deviation: Permill) -> DispatchResult {
+		ensure!(!max_deviation.is_zero(), Error::<T>::InvalidLimitValue);
+		Ok(())
+	}
+
 	pub(crate) fn is_origin_whitelisted_or_root(origin: OriginFor<T>) -> Result<bool, DispatchError> {
 		let who = ensure_signed_or_root(origin)?;
 		match who {
@@ -635,3 +860,21 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 }
+
+/// Price barrier for liquidity operations of pools of the given oracle `Source`.
+pub struct OraclePriceBarrier<T, S>(PhantomData<(T, S)>);
+
+impl<T: Config, S: Get<Source>> PriceBarrier<T::AccountId, T::AssetId, EmaPrice> for OraclePriceBarrier<T, S> {
+	fn ensure_price(
+		who: &T::AccountId,
+		asset_a: T::AssetId,
+		asset_b: T::AssetId,
+		spot_price: EmaPrice,
+	) -> DispatchResult {
+		Pallet::<T>::ensure_price(who, S::get(), asset_a, asset_b, spot_price)
+	}
+
+	fn ensure_price_weight() -> Weight {
+		Pallet::<T>::ensure_price_weight()
+	}
+}
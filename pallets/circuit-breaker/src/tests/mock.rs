@@ -76,6 +76,8 @@ thread_local! {
 	pub static MAX_NET_TRADE_VOLUME_LIMIT_PER_BLOCK: RefCell<(u32, u32)> = const { RefCell::new((2_000, 10_000)) }; // 20%
 	pub static MAX_ADD_LIQUIDITY_LIMIT_PER_BLOCK: RefCell<Option<(u32, u32)>> = const { RefCell::new(Some((4_000, 10_000))) }; // 40%
 	pub static MAX_REMOVE_LIQUIDITY_LIMIT_PER_BLOCK: RefCell<Option<(u32, u32)>> = const { RefCell::new(Some((2_000, 10_000))) }; // 20%
+	pub static MAX_PRICE_DEVIATION: RefCell<Option<Permill>> = const { RefCell::new(None) };
+	pub static ORACLE_PRICES: RefCell<HashMap<(AssetId, AssetId), EmaPrice>> = RefCell::new(HashMap::default());
 }
 
 frame_support::construct_runtime!(
@@ -131,6 +133,7 @@ parameter_types! {
 	pub DefaultMaxAddLiquidityLimitPerBlock: Option<(u32, u32)> = MAX_ADD_LIQUIDITY_LIMIT_PER_BLOCK.with(|v| *v.borrow());
 	pub DefaultMaxRemoveLiquidityLimitPerBlock: Option<(u32, u32)> = MAX_REMOVE_LIQUIDITY_LIMIT_PER_BLOCK.with(|v| *v.borrow());
 	pub const OmnipoolHubAsset: AssetId = LRNA;
+	pub DefaultMaxPriceDeviation: Option<Permill> = MAX_PRICE_DEVIATION.with(|v| *v.borrow());
 }
 
 impl pallet_circuit_breaker::Config for Test {
@@ -143,6 +146,8 @@ impl pallet_circuit_breaker::Config for Test {
 	type DefaultMaxAddLiquidityLimitPerBlock = DefaultMaxAddLiquidityLimitPerBlock;
 	type DefaultMaxRemoveLiquidityLimitPerBlock = DefaultMaxRemoveLiquidityLimitPerBlock;
 	type OmnipoolHubAsset = OmnipoolHubAsset;
+	type PriceBarrierOracle = PriceBarrierOracle;
+	type DefaultMaxPriceDeviation = DefaultMaxPriceDeviation;
 	type WeightInfo = ();
 }
 
@@ -369,7 +374,9 @@ impl<AccountId: From<u64> + Into<u64> + Copy> Mutate<AccountId> for DummyNFT {
 }
 
 use crate::Config;
+use frame_system::pallet_prelude::BlockNumberFor;
 use hydradx_traits::registry::{AssetKind, Inspect as InspectRegistry};
+use hydradx_traits::{AggregatedPriceOracle, OraclePeriod, Source};
 use pallet_omnipool::traits::{AssetInfo, ExternalPriceProvider, OmnipoolHooks};
 
 pub struct DummyRegistry<T>(sp_std::marker::PhantomData<T>);
@@ -431,6 +438,8 @@ pub struct ExtBuilder {
 	max_net_trade_volume_limit_per_block: (u32, u32),
 	max_add_liquidity_limit_per_block: Option<(u32, u32)>,
 	max_remove_liquidity_limit_per_block: Option<(u32, u32)>,
+	max_price_deviation: Option<Permill>,
+	oracle_prices: Vec<((AssetId, AssetId), EmaPrice)>,
 }
 
 impl Default for ExtBuilder {
@@ -465,6 +474,9 @@ impl Default for ExtBuilder {
 		MAX_OUT_RATIO.with(|v| {
 			*v.borrow_mut() = 1u128;
 		});
+		ORACLE_PRICES.with(|v| {
+			v.borrow_mut().clear();
+		});
 
 		Self {
 			endowed_accounts: vec![
@@ -485,6 +497,8 @@ impl Default for ExtBuilder {
 			max_net_trade_volume_limit_per_block: (2_000, 10_000),
 			max_add_liquidity_limit_per_block: Some((4_000, 10_000)),
 			max_remove_liquidity_limit_per_block: Some((2_000, 10_000)),
+			max_price_deviation: None,
+			oracle_prices: vec![],
 		}
 	}
 }
@@ -523,6 +537,16 @@ impl ExtBuilder {
 		self
 	}
 
+	pub fn with_max_price_deviation(mut self, value: Option<Permill>) -> Self {
+		self.max_price_deviation = value;
+		self
+	}
+
+	pub fn with_oracle_price(mut self, assets: (AssetId, AssetId), price: EmaPrice) -> Self {
+		self.oracle_prices.push((assets, price));
+		self
+	}
+
 	pub fn with_token(
 		mut self,
 		asset_id: AssetId,
@@ -582,6 +606,14 @@ impl ExtBuilder {
 		MAX_REMOVE_LIQUIDITY_LIMIT_PER_BLOCK.with(|v| {
 			*v.borrow_mut() = self.max_remove_liquidity_limit_per_block;
 		});
+		MAX_PRICE_DEVIATION.with(|v| {
+			*v.borrow_mut() = self.max_price_deviation;
+		});
+		ORACLE_PRICES.with(|v| {
+			for (assets, price) in self.oracle_prices.iter() {
+				v.borrow_mut().insert(*assets, *price);
+			}
+		});
 
 		orml_tokens::GenesisConfig::<Test> {
 			balances: self
@@ -664,3 +696,33 @@ impl GetByKey<AssetId, (Permill, Permill)> for FeeProvider {
 		(ASSET_FEE.with(|v| *v.borrow()), PROTOCOL_FEE.with(|v| *v.borrow()))
 	}
 }
+
+pub struct PriceBarrierOracle;
+
+impl AggregatedPriceOracle<AssetId, BlockNumberFor<Test>, EmaPrice> for PriceBarrierOracle {
+	type Error = ();
+
+	fn get_price(
+		asset_a: AssetId,
+		asset_b: AssetId,
+		period: OraclePeriod,
+		_source: Source,
+	) -> Result<(EmaPrice, BlockNumberFor<Test>), Self::Error> {
+		assert_eq!(period, OraclePeriod::Short);
+		ORACLE_PRICES.with(|v| {
+			let prices = v.borrow();
+			if let Some(price) = prices.get(&(asset_a, asset_b)) {
+				Ok((*price, 0))
+			} else {
+				prices
+					.get(&(asset_b, asset_a))
+					.map(|price| (price.inverted(), 0))
+					.ok_or(())
+			}
+		})
+	}
+
+	fn get_price_weight() -> Weight {
+		Weight::zero()
+	}
+}
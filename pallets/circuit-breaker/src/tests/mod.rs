@@ -3,5 +3,6 @@ mod ensure_add_liquidity_limit;
 mod ensure_remove_liquidity_limit;
 pub(crate) mod mock;
 mod omnipool;
+mod price_barrier;
 mod remove_liquidity_limit;
 mod trade_volume;
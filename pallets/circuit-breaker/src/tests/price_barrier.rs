@@ -0,0 +1,336 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::*;
+use frame_support::traits::Get;
+pub use pretty_assertions::assert_eq;
+use test_case::test_case;
+
+const SOURCE: Source = *b"testsrc_";
+
+parameter_types! {
+	pub const TestSource: Source = SOURCE;
+}
+
+type TestPriceBarrier = OraclePriceBarrier<Test, TestSource>;
+
+#[test]
+fn set_pool_price_barrier_should_store_barrier_for_ordered_pair() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		assert_ok!(CircuitBreaker::set_pool_price_barrier(
+			RuntimeOrigin::root(),
+			SOURCE,
+			DOT,
+			HDX,
+			Some(Permill::from_percent(5))
+		));
+
+		// Assert
+		assert_eq!(
+			CircuitBreaker::pool_price_barrier(SOURCE, (HDX, DOT)),
+			Some(Permill::from_percent(5))
+		);
+		expect_events(vec![Event::PoolPriceBarrierChanged {
+			source: SOURCE,
+			assets: (HDX, DOT),
+			max_deviation: Some(Permill::from_percent(5)),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn set_pool_price_barrier_should_remove_barrier_when_set_to_none() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(CircuitBreaker::set_pool_price_barrier(
+			RuntimeOrigin::root(),
+			SOURCE,
+			HDX,
+			DOT,
+			Some(Permill::from_percent(5))
+		));
+
+		// Act
+		assert_ok!(CircuitBreaker::set_pool_price_barrier(
+			RuntimeOrigin::root(),
+			SOURCE,
+			HDX,
+			DOT,
+			None
+		));
+
+		// Assert
+		assert_eq!(CircuitBreaker::pool_price_barrier(SOURCE, (HDX, DOT)), None);
+	});
+}
+
+#[test]
+fn set_pool_price_barrier_should_fail_when_called_by_non_technical_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			CircuitBreaker::set_pool_price_barrier(
+				RuntimeOrigin::signed(ALICE),
+				SOURCE,
+				HDX,
+				DOT,
+				Some(Permill::from_percent(5))
+			),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_pool_price_barrier_should_fail_when_deviation_is_zero() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			CircuitBreaker::set_pool_price_barrier(RuntimeOrigin::root(), SOURCE, HDX, DOT, Some(Permill::zero())),
+			Error::<Test>::InvalidLimitValue
+		);
+	});
+}
+
+#[test]
+fn set_pool_price_barrier_should_fail_when_assets_are_same() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			CircuitBreaker::set_pool_price_barrier(
+				RuntimeOrigin::root(),
+				SOURCE,
+				HDX,
+				HDX,
+				Some(Permill::from_percent(5))
+			),
+			Error::<Test>::NotAllowed
+		);
+	});
+}
+
+#[test]
+fn set_asset_price_barrier_should_store_barrier() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		assert_ok!(CircuitBreaker::set_asset_price_barrier(
+			RuntimeOrigin::root(),
+			DOT,
+			Some(Permill::from_percent(10))
+		));
+
+		// Assert
+		assert_eq!(
+			CircuitBreaker::asset_price_barrier(DOT),
+			Some(Permill::from_percent(10))
+		);
+		expect_events(vec![Event::AssetPriceBarrierChanged {
+			asset_id: DOT,
+			max_deviation: Some(Permill::from_percent(10)),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn max_price_deviation_should_prefer_pool_barrier() {
+	ExtBuilder::default()
+		.with_max_price_deviation(Some(Permill::from_percent(20)))
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(CircuitBreaker::set_asset_price_barrier(
+				RuntimeOrigin::root(),
+				DOT,
+				Some(Permill::from_percent(1))
+			));
+			assert_ok!(CircuitBreaker::set_pool_price_barrier(
+				RuntimeOrigin::root(),
+				SOURCE,
+				HDX,
+				DOT,
+				Some(Permill::from_percent(5))
+			));
+
+			// Act & Assert
+			assert_eq!(
+				CircuitBreaker::max_price_deviation(SOURCE, DOT, HDX),
+				Some(Permill::from_percent(5))
+			);
+		});
+}
+
+#[test]
+fn max_price_deviation_should_use_stricter_asset_barrier_when_pool_barrier_is_not_set() {
+	ExtBuilder::default()
+		.with_max_price_deviation(Some(Permill::from_percent(20)))
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(CircuitBreaker::set_asset_price_barrier(
+				RuntimeOrigin::root(),
+				DOT,
+				Some(Permill::from_percent(3))
+			));
+			assert_ok!(CircuitBreaker::set_asset_price_barrier(
+				RuntimeOrigin::root(),
+				HDX,
+				Some(Permill::from_percent(7))
+			));
+
+			// Act & Assert
+			assert_eq!(
+				CircuitBreaker::max_price_deviation(SOURCE, HDX, DOT),
+				Some(Permill::from_percent(3))
+			);
+			assert_eq!(
+				CircuitBreaker::max_price_deviation(SOURCE, HDX, DAI),
+				Some(Permill::from_percent(7))
+			);
+			assert_eq!(
+				CircuitBreaker::max_price_deviation(SOURCE, DAI, ACA),
+				Some(Permill::from_percent(20))
+			);
+		});
+}
+
+#[test_case(EmaPrice::new(100, 100), EmaPrice::new(100, 100), Permill::zero())]
+#[test_case(EmaPrice::new(105, 100), EmaPrice::new(1, 1), Permill::from_percent(5))]
+#[test_case(EmaPrice::new(95, 100), EmaPrice::new(1, 1), Permill::from_percent(5))]
+#[test_case(EmaPrice::new(1, 2), EmaPrice::new(1, 4), Permill::from_percent(100))]
+#[test_case(EmaPrice::new(u128::MAX, 1), EmaPrice::new(1, u128::MAX), Permill::from_percent(100))]
+#[test_case(EmaPrice::new(0, 1), EmaPrice::new(1, 1), Permill::from_percent(100))]
+fn price_deviation_should_be_relative_to_oracle_price(spot: EmaPrice, oracle: EmaPrice, expected: Permill) {
+	assert_eq!(price_deviation(spot, oracle), Some(expected));
+}
+
+#[test]
+fn price_deviation_should_be_none_when_oracle_price_is_zero() {
+	assert_eq!(price_deviation(EmaPrice::new(1, 1), EmaPrice::new(0, 1)), None);
+}
+
+#[test]
+fn ensure_price_should_pass_when_deviation_is_within_barrier() {
+	ExtBuilder::default()
+		.with_max_price_deviation(Some(Permill::from_percent(5)))
+		.with_oracle_price((HDX, DOT), EmaPrice::new(100, 100))
+		.build()
+		.execute_with(|| {
+			assert_ok!(TestPriceBarrier::ensure_price(
+				&ALICE,
+				HDX,
+				DOT,
+				EmaPrice::new(104, 100)
+			));
+			assert_ok!(TestPriceBarrier::ensure_price(
+				&ALICE,
+				DOT,
+				HDX,
+				EmaPrice::new(100, 104)
+			));
+		});
+}
+
+#[test]
+fn ensure_price_should_fail_with_deviation_when_deviation_is_above_barrier() {
+	ExtBuilder::default()
+		.with_max_price_deviation(Some(Permill::from_percent(5)))
+		.with_oracle_price((HDX, DOT), EmaPrice::new(100, 100))
+		.build()
+		.execute_with(|| {
+			// Act
+			let result = TestPriceBarrier::ensure_price(&ALICE, HDX, DOT, EmaPrice::new(110, 100));
+
+			// Assert
+			assert_eq!(
+				result,
+				Err(Error::<Test>::PriceDeviationTooHigh { deviation: 1_000 }.into())
+			);
+		});
+}
+
+#[test]
+fn ensure_price_should_use_pool_barrier_when_set() {
+	ExtBuilder::default()
+		.with_max_price_deviation(Some(Permill::from_percent(5)))
+		.with_oracle_price((HDX, DOT), EmaPrice::new(100, 100))
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(CircuitBreaker::set_pool_price_barrier(
+				RuntimeOrigin::root(),
+				SOURCE,
+				HDX,
+				DOT,
+				Some(Permill::from_percent(20))
+			));
+
+			// Act & Assert
+			assert_ok!(TestPriceBarrier::ensure_price(
+				&ALICE,
+				HDX,
+				DOT,
+				EmaPrice::new(110, 100)
+			));
+		});
+}
+
+#[test]
+fn ensure_price_should_pass_when_barrier_is_not_set() {
+	ExtBuilder::default()
+		.with_oracle_price((HDX, DOT), EmaPrice::new(100, 100))
+		.build()
+		.execute_with(|| {
+			assert_ok!(TestPriceBarrier::ensure_price(
+				&ALICE,
+				HDX,
+				DOT,
+				EmaPrice::new(200, 100)
+			));
+		});
+}
+
+#[test]
+fn ensure_price_should_pass_when_oracle_is_not_available() {
+	ExtBuilder::default()
+		.with_max_price_deviation(Some(Permill::from_percent(5)))
+		.build()
+		.execute_with(|| {
+			assert_ok!(TestPriceBarrier::ensure_price(
+				&ALICE,
+				HDX,
+				DOT,
+				EmaPrice::new(200, 100)
+			));
+		});
+}
+
+#[test]
+fn ensure_price_should_pass_when_account_is_whitelisted() {
+	ExtBuilder::default()
+		.with_max_price_deviation(Some(Permill::from_percent(5)))
+		.with_oracle_price((HDX, DOT), EmaPrice::new(100, 100))
+		.build()
+		.execute_with(|| {
+			assert_ok!(TestPriceBarrier::ensure_price(
+				&WHITELISTED_ACCCOUNT,
+				HDX,
+				DOT,
+				EmaPrice::new(200, 100)
+			));
+		});
+}
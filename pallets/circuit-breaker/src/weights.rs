@@ -60,6 +60,9 @@ pub trait WeightInfo {
 	fn ensure_pool_state_change_limit() -> Weight;
 	fn ensure_add_liquidity_limit() -> Weight;
 	fn ensure_remove_liquidity_limit() -> Weight;
+	fn set_pool_price_barrier() -> Weight;
+	fn set_asset_price_barrier() -> Weight;
+	fn ensure_price() -> Weight;
 }
 
 // For backwards compatibility and tests
@@ -175,4 +178,36 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Storage: `CircuitBreaker::PoolPriceBarrier` (r:0 w:1)
+	/// Proof: `CircuitBreaker::PoolPriceBarrier` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn set_pool_price_barrier() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_214_000 picoseconds.
+		Weight::from_parts(9_532_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CircuitBreaker::AssetPriceBarrier` (r:0 w:1)
+	/// Proof: `CircuitBreaker::AssetPriceBarrier` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn set_asset_price_barrier() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 8_871_000 picoseconds.
+		Weight::from_parts(9_104_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CircuitBreaker::PoolPriceBarrier` (r:1 w:0)
+	/// Proof: `CircuitBreaker::PoolPriceBarrier` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `CircuitBreaker::AssetPriceBarrier` (r:2 w:0)
+	/// Proof: `CircuitBreaker::AssetPriceBarrier` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn ensure_price() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `261`
+		//  Estimated: `6488`
+		// Minimum execution time: 16_482_000 picoseconds.
+		Weight::from_parts(16_935_000, 6488)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+	}
 }
@@ -0,0 +1,255 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::account;
+use frame_benchmarking::benchmarks;
+use frame_support::traits::EnsureOrigin;
+use frame_system::RawOrigin;
+use hydra_dx_math::concentrated::calculate_amount_1_delta;
+use hydradx_traits::router::{PoolType, TradeExecution};
+use orml_traits::MultiCurrencyExtended;
+
+const ASSET_0: u32 = 2_000;
+const ASSET_1: u32 = 2_001;
+const TICK_SPACING: u32 = 60;
+const ONE: Balance = 1_000_000_000_000;
+const FEE: Permill = Permill::from_parts(3_000);
+
+/// Create a pool with `MaxTickCrossings + 1` nested positions around the current price.
+///
+/// Trades returned by `worst_case_amount_out` cross `MaxTickCrossings` initialized ticks.
+fn setup_pool<T: Config>() -> Result<PoolId, DispatchError>
+where
+	T::AssetId: From<u32>,
+	T::Currency: MultiCurrencyExtended<T::AccountId, Amount = i128>,
+{
+	T::BenchmarkHelper::register_asset(ASSET_0.into(), 12)?;
+	T::BenchmarkHelper::register_asset(ASSET_1.into(), 12)?;
+
+	let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+	Pallet::<T>::set_fee_tier(successful_origin.clone(), FEE, TICK_SPACING)?;
+	Pallet::<T>::create_pool(successful_origin, ASSET_0.into(), ASSET_1.into(), FEE, FixedU128::one())?;
+	let pool_id = NextPoolId::<T>::get() - 1;
+
+	let lp: T::AccountId = account("provider", 0, 1);
+	T::Currency::update_balance(ASSET_0.into(), &lp, 1_000_000 * ONE as i128)?;
+	T::Currency::update_balance(ASSET_1.into(), &lp, 1_000_000 * ONE as i128)?;
+
+	for i in 1..=T::MaxTickCrossings::get() + 1 {
+		let tick = (i * TICK_SPACING) as Tick;
+		Pallet::<T>::add_liquidity(
+			RawOrigin::Signed(lp.clone()).into(),
+			pool_id,
+			-tick,
+			tick,
+			1_000 * ONE,
+			1_000 * ONE,
+			0,
+		)?;
+	}
+
+	Ok(pool_id)
+}
+
+/// Amount of asset 1 which can be bought from the pool created by `setup_pool` while crossing
+/// all but the last initialized tick below the current price.
+fn worst_case_amount_out<T: Config>(pool_id: PoolId) -> Result<Balance, DispatchError>
+where
+	T::AssetId: From<u32>,
+{
+	let n = (T::MaxTickCrossings::get() + 1) as Tick;
+	let last = Pallet::<T>::ticks(pool_id, -n * TICK_SPACING as Tick).ok_or(Error::<T>::InsufficientLiquidity)?;
+	let last_segment = calculate_amount_1_delta(
+		calculate_sqrt_price_at_tick(-n * TICK_SPACING as Tick).map_err(|_| ArithmeticError::Overflow)?,
+		calculate_sqrt_price_at_tick(-(n - 1) * TICK_SPACING as Tick).map_err(|_| ArithmeticError::Overflow)?,
+		last.liquidity_gross,
+		false,
+	)
+	.map_err(|_| ArithmeticError::Overflow)?;
+
+	let reserve = T::Currency::free_balance(ASSET_1.into(), &Pallet::<T>::pool_account(pool_id));
+
+	Ok(reserve.saturating_sub(last_segment))
+}
+
+benchmarks! {
+	where_clause { where
+		T::AssetId: From<u32>,
+		T::Currency: MultiCurrencyExtended<T::AccountId, Amount=i128>,
+	}
+
+	set_fee_tier {
+		let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+	}: _<T::RuntimeOrigin>(successful_origin, FEE, TICK_SPACING)
+	verify {
+		assert_eq!(FeeTiers::<T>::get(FEE), Some(TICK_SPACING));
+	}
+
+	remove_fee_tier {
+		let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+		Pallet::<T>::set_fee_tier(successful_origin.clone(), FEE, TICK_SPACING)?;
+	}: _<T::RuntimeOrigin>(successful_origin, FEE)
+	verify {
+		assert!(FeeTiers::<T>::get(FEE).is_none());
+	}
+
+	create_pool {
+		T::BenchmarkHelper::register_asset(ASSET_0.into(), 12)?;
+		T::BenchmarkHelper::register_asset(ASSET_1.into(), 12)?;
+		let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+		Pallet::<T>::set_fee_tier(successful_origin.clone(), FEE, TICK_SPACING)?;
+	}: _<T::RuntimeOrigin>(successful_origin, ASSET_1.into(), ASSET_0.into(), FEE, FixedU128::from(2))
+	verify {
+		assert!(PoolIds::<T>::get((T::AssetId::from(ASSET_0), T::AssetId::from(ASSET_1), FEE)).is_some());
+	}
+
+	add_liquidity {
+		let pool_id = setup_pool::<T>()?;
+		let caller: T::AccountId = account("caller", 0, 1);
+		T::Currency::update_balance(ASSET_0.into(), &caller, 1_000 * ONE as i128)?;
+		T::Currency::update_balance(ASSET_1.into(), &caller, 1_000 * ONE as i128)?;
+		// new ticks outside of the initialized ones, lower tick is inserted at the beginning of initialized ticks
+		let tick = ((T::MaxTickCrossings::get() + 3) * TICK_SPACING) as Tick;
+		let position_id = NextPositionId::<T>::get();
+	}: _(RawOrigin::Signed(caller.clone()), pool_id, -tick, tick, 100 * ONE, 100 * ONE, 0)
+	verify {
+		assert!(Positions::<T>::get(position_id).is_some());
+	}
+
+	remove_liquidity {
+		let pool_id = setup_pool::<T>()?;
+		let caller: T::AccountId = account("caller", 0, 1);
+		T::Currency::update_balance(ASSET_0.into(), &caller, 1_000 * ONE as i128)?;
+		T::Currency::update_balance(ASSET_1.into(), &caller, 1_000 * ONE as i128)?;
+		let position_id = NextPositionId::<T>::get();
+		Pallet::<T>::add_liquidity(RawOrigin::Signed(caller.clone()).into(), pool_id, -7 * TICK_SPACING as Tick, 11 * TICK_SPACING as Tick, 100 * ONE, 100 * ONE, 0)?;
+
+		// earn some fees so they are collected as well
+		let trader: T::AccountId = account("trader", 0, 1);
+		T::Currency::update_balance(ASSET_0.into(), &trader, 10 * ONE as i128)?;
+		Pallet::<T>::sell(RawOrigin::Signed(trader).into(), pool_id, ASSET_0.into(), ASSET_1.into(), 10 * ONE, 0)?;
+
+		let liquidity = Positions::<T>::get(position_id).unwrap().liquidity;
+	}: _(RawOrigin::Signed(caller.clone()), position_id, liquidity)
+	verify {
+		assert!(Positions::<T>::get(position_id).is_none());
+	}
+
+	collect_fees {
+		let pool_id = setup_pool::<T>()?;
+		let caller: T::AccountId = account("caller", 0, 1);
+		T::Currency::update_balance(ASSET_0.into(), &caller, 1_000 * ONE as i128)?;
+		T::Currency::update_balance(ASSET_1.into(), &caller, 1_000 * ONE as i128)?;
+		let position_id = NextPositionId::<T>::get();
+		Pallet::<T>::add_liquidity(RawOrigin::Signed(caller.clone()).into(), pool_id, -7 * TICK_SPACING as Tick, 11 * TICK_SPACING as Tick, 100 * ONE, 100 * ONE, 0)?;
+
+		let trader: T::AccountId = account("trader", 0, 1);
+		T::Currency::update_balance(ASSET_0.into(), &trader, 10 * ONE as i128)?;
+		T::Currency::update_balance(ASSET_1.into(), &trader, 10 * ONE as i128)?;
+		Pallet::<T>::sell(RawOrigin::Signed(trader.clone()).into(), pool_id, ASSET_0.into(), ASSET_1.into(), 10 * ONE, 0)?;
+		Pallet::<T>::sell(RawOrigin::Signed(trader).into(), pool_id, ASSET_1.into(), ASSET_0.into(), 10 * ONE, 0)?;
+	}: _(RawOrigin::Signed(caller.clone()), position_id)
+	verify {
+		let position = Positions::<T>::get(position_id).unwrap();
+		assert_eq!(position.fees_owed_0, 0);
+		assert_eq!(position.fees_owed_1, 0);
+	}
+
+	sell {
+		let pool_id = setup_pool::<T>()?;
+		let amount_out = worst_case_amount_out::<T>(pool_id)?;
+		let amount_in = <Pallet::<T> as TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balance>>::calculate_buy(PoolType::Concentrated(pool_id), ASSET_0.into(), ASSET_1.into(), amount_out).map_err(|_| Error::<T>::InsufficientLiquidity)?;
+
+		let seller: T::AccountId = account("seller", 0, 1);
+		T::Currency::update_balance(ASSET_0.into(), &seller, amount_in as i128)?;
+	}: _(RawOrigin::Signed(seller.clone()), pool_id, ASSET_0.into(), ASSET_1.into(), amount_in, 0)
+	verify {
+		assert_eq!(T::Currency::free_balance(ASSET_0.into(), &seller), 0);
+		assert!(T::Currency::free_balance(ASSET_1.into(), &seller) >= amount_out);
+	}
+
+	buy {
+		let pool_id = setup_pool::<T>()?;
+		let amount_out = worst_case_amount_out::<T>(pool_id)?;
+
+		let buyer: T::AccountId = account("buyer", 0, 1);
+		T::Currency::update_balance(ASSET_0.into(), &buyer, 1_000_000 * ONE as i128)?;
+	}: _(RawOrigin::Signed(buyer.clone()), pool_id, ASSET_1.into(), ASSET_0.into(), amount_out, Balance::MAX)
+	verify {
+		assert_eq!(T::Currency::free_balance(ASSET_1.into(), &buyer), amount_out);
+	}
+
+	router_execution_sell {
+		let c in 1..2;	// number of times calculate_sell is executed
+		let e in 0..1;	// if e == 1, execute_sell is executed
+
+		let pool_id = setup_pool::<T>()?;
+		let amount_out = worst_case_amount_out::<T>(pool_id)?;
+		let amount_in = <Pallet::<T> as TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balance>>::calculate_buy(PoolType::Concentrated(pool_id), ASSET_0.into(), ASSET_1.into(), amount_out).map_err(|_| Error::<T>::InsufficientLiquidity)?;
+
+		let seller: T::AccountId = account("seller", 0, 1);
+		T::Currency::update_balance(ASSET_0.into(), &seller, amount_in as i128)?;
+	}: {
+		for _ in 1..c {
+			assert!(<Pallet::<T> as TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balance>>::calculate_sell(PoolType::Concentrated(pool_id), ASSET_0.into(), ASSET_1.into(), amount_in).is_ok());
+		}
+		if e != 0 {
+			assert!(<Pallet::<T> as TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balance>>::execute_sell(RawOrigin::Signed(seller.clone()).into(), PoolType::Concentrated(pool_id), ASSET_0.into(), ASSET_1.into(), amount_in, 0).is_ok());
+		}
+	}
+	verify {
+		if e != 0 {
+			assert_eq!(T::Currency::free_balance(ASSET_0.into(), &seller), 0);
+			assert!(T::Currency::free_balance(ASSET_1.into(), &seller) >= amount_out);
+		}
+	}
+
+	router_execution_buy {
+		let c in 1..2;	// number of times calculate_buy is executed
+		let e in 0..1;	// if e == 1, execute_buy is executed
+
+		let pool_id = setup_pool::<T>()?;
+		let amount_out = worst_case_amount_out::<T>(pool_id)?;
+
+		let buyer: T::AccountId = account("buyer", 0, 1);
+		T::Currency::update_balance(ASSET_0.into(), &buyer, 1_000_000 * ONE as i128)?;
+	}: {
+		for _ in 1..c {
+			assert!(<Pallet::<T> as TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balance>>::calculate_buy(PoolType::Concentrated(pool_id), ASSET_0.into(), ASSET_1.into(), amount_out).is_ok());
+		}
+		if e != 0 {
+			assert!(<Pallet::<T> as TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balance>>::execute_buy(RawOrigin::Signed(buyer.clone()).into(), PoolType::Concentrated(pool_id), ASSET_0.into(), ASSET_1.into(), amount_out, Balance::MAX).is_ok());
+		}
+	}
+	verify {
+		if e != 0 {
+			assert_eq!(T::Currency::free_balance(ASSET_1.into(), &buyer), amount_out);
+		}
+	}
+
+	calculate_spot_price_with_fee {
+		let pool_id = setup_pool::<T>()?;
+	}: {
+		assert!(<Pallet::<T> as TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balance>>::calculate_spot_price_with_fee(PoolType::Concentrated(pool_id), ASSET_0.into(), ASSET_1.into()).is_ok());
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::tests::mock::ExtBuilder::default().build(), crate::tests::mock::Test);
+}
@@ -0,0 +1,1283 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Concentrated liquidity pallet
+//!
+//! ## Overview
+//!
+//! Concentrated liquidity AMM for pairs of assets. Unlike XYK pools, where liquidity is spread over the whole price
+//! range, liquidity providers choose a price range in which their liquidity is used. This makes the liquidity
+//! much more capital efficient, especially for volatile pairs which are not in the Omnipool.
+//!
+//! The price range is split into ticks. Each tick is a 0.01% price change, price at tick `i` is `1.0001^i`.
+//! Positions are bounded by two ticks which must be divisible by the tick spacing of the pool.
+//! The tick spacing is given by the fee tier of the pool. Fee tiers are managed by the authority origin.
+//!
+//! Each position is represented as a NFT instance, similarly to Omnipool positions.
+//!
+//! Trades are executed within the range of the active liquidity. When the price crosses a tick used by
+//! a position, the liquidity of the position is activated or deactivated. The number of crossed ticks in a single trade
+//! is limited by `MaxTickCrossings`.
+//!
+//! Trade fee is taken from the asset in and distributed to the active liquidity. Fees are tracked per unit of
+//! liquidity and can be collected by the position owners.
+//!
+//! Trades are reported to `AMMHandler` which is used to update the oracle.
+//!
+//! Adding and removing liquidity is guarded by `PriceBarrier`, which rejects the operation when the pool price
+//! deviates too much from the oracle price.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! * `set_fee_tier` - Adds or updates a fee tier with its tick spacing.
+//! * `remove_fee_tier` - Removes a fee tier. Existing pools are not affected.
+//! * `create_pool` - Creates a new pool for an asset pair and fee tier with initial price.
+//! * `add_liquidity` - Adds liquidity in a price range. Mints position NFT.
+//! * `remove_liquidity` - Removes liquidity from a position and collects its fees. Burns NFT if position is empty.
+//! * `collect_fees` - Transfers fees earned by a position to its owner.
+//! * `sell` - Sells given amount of asset in.
+//! * `buy` - Buys given amount of asset out.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::pallet_prelude::*;
+use frame_support::require_transactional;
+use frame_support::traits::tokens::nonfungibles::{Create, Inspect, Mutate};
+use frame_support::PalletId;
+use frame_system::ensure_signed;
+use frame_system::pallet_prelude::OriginFor;
+use hydra_dx_math::concentrated::{
+	calculate_amounts_for_liquidity, calculate_fee_growth, calculate_fees_owed, calculate_liquidity_for_amounts,
+	calculate_price_from_sqrt_price, calculate_sqrt_price_at_tick, calculate_sqrt_price_from_price,
+	calculate_swap_step, calculate_tick_at_sqrt_price, MAX_SQRT_PRICE, MAX_TICK, MIN_SQRT_PRICE, MIN_TICK,
+};
+use hydra_dx_math::ratio::Ratio;
+use hydradx_traits::pools::DustRemovalAccountWhitelist;
+use hydradx_traits::registry::Inspect as RegistryInspect;
+use hydradx_traits::{price::PriceBarrier, OnTradeHandler, Source};
+use orml_traits::MultiCurrency;
+use sp_core::U256;
+use sp_runtime::traits::{AccountIdConversion, AtLeast32BitUnsigned, CheckedAdd, One, Zero};
+use sp_runtime::{ArithmeticError, DispatchError, FixedU128, Permill};
+use sp_std::vec::Vec;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarks;
+pub mod trade_execution;
+pub mod types;
+pub mod weights;
+
+pub use pallet::*;
+#[cfg(feature = "runtime-benchmarks")]
+pub use types::BenchmarkHelper;
+pub use types::{Balance, Liquidity, PoolId, PoolInfo, Position, SwapResult, Tick, TickInfo};
+pub use weights::WeightInfo;
+
+/// NFT class id type of provided nft implementation
+pub type NFTCollectionIdOf<T> =
+	<<T as Config>::NFTHandler as Inspect<<T as frame_system::Config>::AccountId>>::CollectionId;
+
+/// Pool accounts are derived from this pallet id and the pool id.
+pub const PALLET_ID: PalletId = PalletId(*b"conliqpl");
+
+/// Tick spacing of a fee tier must not exceed this value.
+pub const MAX_TICK_SPACING: u32 = 16384;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use codec::HasCompact;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Identifier for the class of asset.
+		type AssetId: Member
+			+ Parameter
+			+ Ord
+			+ Default
+			+ Copy
+			+ HasCompact
+			+ MaybeSerializeDeserialize
+			+ MaxEncodedLen
+			+ TypeInfo;
+
+		/// Multi currency mechanism
+		type Currency: MultiCurrency<Self::AccountId, CurrencyId = Self::AssetId, Balance = Balance>;
+
+		/// Asset registry mechanism to check if asset is registered.
+		type AssetRegistry: RegistryInspect<AssetId = Self::AssetId>;
+
+		/// Origin that can manage fee tiers and create pools.
+		type AuthorityOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Position identifier type
+		type PositionItemId: Member + Parameter + Default + Copy + HasCompact + AtLeast32BitUnsigned + MaxEncodedLen;
+
+		/// Collection id type
+		type CollectionId: TypeInfo + MaxEncodedLen;
+
+		/// Non fungible class id
+		#[pallet::constant]
+		type NFTCollectionId: Get<NFTCollectionIdOf<Self>>;
+
+		/// Non fungible handling - mint,burn, check owner
+		type NFTHandler: Mutate<Self::AccountId>
+			+ Create<Self::AccountId>
+			+ Inspect<Self::AccountId, ItemId = Self::PositionItemId, CollectionId = Self::CollectionId>;
+
+		/// Account whitelist manager to exclude pool accounts from dusting mechanism.
+		type DustAccountHandler: DustRemovalAccountWhitelist<Self::AccountId, Error = DispatchError>;
+
+		/// Handler called after each trade, used to update the oracle.
+		type AMMHandler: OnTradeHandler<Self::AssetId, Balance, Ratio>;
+
+		/// Oracle source identifier for this pallet.
+		#[pallet::constant]
+		type OracleSource: Get<Source>;
+
+		/// Price barrier to prevent adding or removing liquidity when the pool price is manipulated.
+		type PriceBarrier: PriceBarrier<Self::AccountId, Self::AssetId, Ratio>;
+
+		/// Minimum trading amount
+		#[pallet::constant]
+		type MinTradingLimit: Get<Balance>;
+
+		/// Max number of initialized ticks in a pool.
+		#[pallet::constant]
+		type MaxInitializedTicks: Get<u32>;
+
+		/// Max number of initialized ticks which can be crossed by a single trade.
+		#[pallet::constant]
+		type MaxTickCrossings: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::AssetId>;
+	}
+
+	/// Supported fee tiers and their tick spacing.
+	#[pallet::storage]
+	#[pallet::getter(fn fee_tier)]
+	pub type FeeTiers<T: Config> = StorageMap<_, Blake2_128Concat, Permill, u32, OptionQuery>;
+
+	/// Existing pools
+	#[pallet::storage]
+	#[pallet::getter(fn pools)]
+	pub type Pools<T: Config> = StorageMap<_, Blake2_128Concat, PoolId, PoolInfo<T::AssetId>, OptionQuery>;
+
+	/// Pool id of ordered asset pair and fee tier.
+	#[pallet::storage]
+	#[pallet::getter(fn pool_id)]
+	pub type PoolIds<T: Config> =
+		StorageMap<_, Blake2_128Concat, (T::AssetId, T::AssetId, Permill), PoolId, OptionQuery>;
+
+	/// Id of the next created pool.
+	#[pallet::storage]
+	pub(super) type NextPoolId<T: Config> = StorageValue<_, PoolId, ValueQuery>;
+
+	/// Initialized ticks of a pool.
+	#[pallet::storage]
+	#[pallet::getter(fn ticks)]
+	pub type Ticks<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, PoolId, Twox64Concat, Tick, TickInfo, OptionQuery>;
+
+	/// Sorted list of initialized ticks of a pool.
+	#[pallet::storage]
+	#[pallet::getter(fn initialized_ticks)]
+	pub type InitializedTicks<T: Config> =
+		StorageMap<_, Blake2_128Concat, PoolId, BoundedVec<Tick, T::MaxInitializedTicks>, ValueQuery>;
+
+	/// LP positions. Maps NFT instance id to corresponding position
+	#[pallet::storage]
+	#[pallet::getter(fn positions)]
+	pub type Positions<T: Config> = StorageMap<_, Blake2_128Concat, T::PositionItemId, Position, OptionQuery>;
+
+	#[pallet::storage]
+	/// Position ids sequencer
+	pub(super) type NextPositionId<T: Config> = StorageValue<_, T::PositionItemId, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Fee tier has been set.
+		FeeTierSet { fee: Permill, tick_spacing: u32 },
+		/// Fee tier has been removed.
+		FeeTierRemoved { fee: Permill },
+		/// A pool was created.
+		PoolCreated {
+			pool_id: PoolId,
+			asset_0: T::AssetId,
+			asset_1: T::AssetId,
+			fee: Permill,
+			tick_spacing: u32,
+			sqrt_price: U256,
+		},
+		/// Liquidity has been added and position NFT minted.
+		LiquidityAdded {
+			who: T::AccountId,
+			pool_id: PoolId,
+			position_id: T::PositionItemId,
+			tick_lower: Tick,
+			tick_upper: Tick,
+			liquidity: Liquidity,
+			amount_0: Balance,
+			amount_1: Balance,
+		},
+		/// Liquidity has been removed from a position.
+		LiquidityRemoved {
+			who: T::AccountId,
+			pool_id: PoolId,
+			position_id: T::PositionItemId,
+			liquidity: Liquidity,
+			amount_0: Balance,
+			amount_1: Balance,
+		},
+		/// Fees earned by a position have been collected.
+		FeesCollected {
+			who: T::AccountId,
+			position_id: T::PositionItemId,
+			amount_0: Balance,
+			amount_1: Balance,
+		},
+		/// Position has been destroyed and NFT instance burned.
+		PositionDestroyed {
+			position_id: T::PositionItemId,
+			owner: T::AccountId,
+		},
+		/// Sell trade executed. Trade fee is paid in asset in and included in `amount_in`.
+		SellExecuted {
+			who: T::AccountId,
+			pool_id: PoolId,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount_in: Balance,
+			amount_out: Balance,
+			fee: Balance,
+		},
+		/// Buy trade executed. Trade fee is paid in asset in and included in `amount_in`.
+		BuyExecuted {
+			who: T::AccountId,
+			pool_id: PoolId,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount_in: Balance,
+			amount_out: Balance,
+			fee: Balance,
+		},
+	}
+
+	#[pallet::error]
+	#[cfg_attr(test, derive(PartialEq, Eq))]
+	pub enum Error<T> {
+		/// Fee tier is not supported.
+		FeeTierNotFound,
+		/// Fee must be lower than 100%.
+		InvalidFee,
+		/// Tick spacing must be greater than zero and lower or equal to `MAX_TICK_SPACING`.
+		InvalidTickSpacing,
+		/// Pool cannot be created for the same asset.
+		SameAssets,
+		/// Asset is not registered in asset registry.
+		AssetNotRegistered,
+		/// Pool for the asset pair and fee tier already exists.
+		PoolAlreadyExists,
+		/// Pool does not exist.
+		PoolNotFound,
+		/// Initial price is zero or out of the supported price range.
+		InvalidInitialPrice,
+		/// Ticks are not ordered, out of range or not divisible by the tick spacing.
+		InvalidTickRange,
+		/// Provided amounts result in zero liquidity.
+		ZeroLiquidity,
+		/// Liquidity is lower than the minimum specified.
+		SlippageLimit,
+		/// Max number of initialized ticks in the pool has been reached.
+		TooManyInitializedTicks,
+		/// Position does not exist.
+		PositionNotFound,
+		/// Account is not the owner of the position.
+		Forbidden,
+		/// Position does not have enough liquidity.
+		InsufficientPositionLiquidity,
+		/// Asset is not in the pool.
+		AssetNotInPool,
+		/// Asset in and asset out are the same.
+		NotAllowed,
+		/// Trade amount is lower than the minimum trading limit.
+		InsufficientTradingAmount,
+		/// Pool does not have enough liquidity to execute the trade.
+		InsufficientLiquidity,
+		/// Trade crosses more initialized ticks than allowed.
+		TooManyTickCrossings,
+		/// Account does not have enough balance of asset in.
+		InsufficientBalance,
+		/// Minimum limit has not been reached during trade.
+		BuyLimitNotReached,
+		/// Maximum limit has been exceeded during trade.
+		SellLimitExceeded,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Add or update fee tier.
+		///
+		/// Tick spacing is used for pools created with the fee tier, existing pools are not affected.
+		///
+		/// Parameters:
+		/// - `origin`: Must be `T::AuthorityOrigin`
+		/// - `fee`: trade fee of the tier
+		/// - `tick_spacing`: tick spacing of pools with the fee tier
+		///
+		/// Emits `FeeTierSet` event when successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_fee_tier())]
+		pub fn set_fee_tier(origin: OriginFor<T>, fee: Permill, tick_spacing: u32) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			ensure!(fee < Permill::from_percent(100), Error::<T>::InvalidFee);
+			ensure!(
+				tick_spacing > 0 && tick_spacing <= MAX_TICK_SPACING,
+				Error::<T>::InvalidTickSpacing
+			);
+
+			FeeTiers::<T>::insert(fee, tick_spacing);
+
+			Self::deposit_event(Event::FeeTierSet { fee, tick_spacing });
+
+			Ok(())
+		}
+
+		/// Remove fee tier.
+		///
+		/// New pools cannot be created with the fee tier, existing pools are not affected.
+		///
+		/// Parameters:
+		/// - `origin`: Must be `T::AuthorityOrigin`
+		/// - `fee`: trade fee of the tier
+		///
+		/// Emits `FeeTierRemoved` event when successful.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_fee_tier())]
+		pub fn remove_fee_tier(origin: OriginFor<T>, fee: Permill) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			ensure!(FeeTiers::<T>::contains_key(fee), Error::<T>::FeeTierNotFound);
+
+			FeeTiers::<T>::remove(fee);
+
+			Self::deposit_event(Event::FeeTierRemoved { fee });
+
+			Ok(())
+		}
+
+		/// Create a concentrated liquidity pool for given asset pair and fee tier.
+		///
+		/// Pool is created without liquidity. Liquidity can be added by anyone with `add_liquidity`.
+		///
+		/// Parameters:
+		/// - `origin`: Must be `T::AuthorityOrigin`
+		/// - `asset_a`: asset id of the first asset
+		/// - `asset_b`: asset id of the second asset
+		/// - `fee`: fee tier of the pool
+		/// - `initial_price`: price of `asset_a` denominated in `asset_b`
+		///
+		/// Emits `PoolCreated` event when successful.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_pool())]
+		pub fn create_pool(
+			origin: OriginFor<T>,
+			asset_a: T::AssetId,
+			asset_b: T::AssetId,
+			fee: Permill,
+			initial_price: FixedU128,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			ensure!(asset_a != asset_b, Error::<T>::SameAssets);
+			ensure!(
+				T::AssetRegistry::exists(asset_a) && T::AssetRegistry::exists(asset_b),
+				Error::<T>::AssetNotRegistered
+			);
+
+			let tick_spacing = FeeTiers::<T>::get(fee).ok_or(Error::<T>::FeeTierNotFound)?;
+
+			let (asset_0, asset_1, price) = if asset_a < asset_b {
+				(asset_a, asset_b, initial_price)
+			} else {
+				(
+					asset_b,
+					asset_a,
+					initial_price.reciprocal().ok_or(Error::<T>::InvalidInitialPrice)?,
+				)
+			};
+
+			ensure!(
+				!PoolIds::<T>::contains_key((asset_0, asset_1, fee)),
+				Error::<T>::PoolAlreadyExists
+			);
+
+			let sqrt_price = calculate_sqrt_price_from_price(price).map_err(|_| Error::<T>::InvalidInitialPrice)?;
+			let tick = calculate_tick_at_sqrt_price(sqrt_price).map_err(|_| Error::<T>::InvalidInitialPrice)?;
+
+			let pool_id = NextPoolId::<T>::try_mutate(|next_id| -> Result<PoolId, DispatchError> {
+				let pool_id = *next_id;
+				*next_id = next_id.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+				Ok(pool_id)
+			})?;
+
+			// ensure collection is created, we can simply ignore the error if it was already created.
+			let _ = T::NFTHandler::create_collection(
+				&T::NFTCollectionId::get(),
+				&Self::protocol_account(),
+				&Self::protocol_account(),
+			);
+
+			T::DustAccountHandler::add_account(&Self::pool_account(pool_id))?;
+
+			Pools::<T>::insert(
+				pool_id,
+				PoolInfo {
+					asset_0,
+					asset_1,
+					fee,
+					tick_spacing,
+					sqrt_price,
+					tick,
+					liquidity: 0,
+					fee_growth_global_0: U256::zero(),
+					fee_growth_global_1: U256::zero(),
+				},
+			);
+			PoolIds::<T>::insert((asset_0, asset_1, fee), pool_id);
+
+			Self::deposit_event(Event::PoolCreated {
+				pool_id,
+				asset_0,
+				asset_1,
+				fee,
+				tick_spacing,
+				sqrt_price,
+			});
+
+			Ok(())
+		}
+
+		/// Add liquidity to a pool in the price range between `tick_lower` and `tick_upper`.
+		///
+		/// Maximum liquidity which can be provided with given amounts at current price is added.
+		/// If the current price is outside of the range, only one of the assets is needed.
+		///
+		/// Position NFT is minted for the caller.
+		///
+		/// Parameters:
+		/// - `origin`: liquidity provider
+		/// - `pool_id`: pool id
+		/// - `tick_lower`: lower bound of the price range, must be divisible by the tick spacing
+		/// - `tick_upper`: upper bound of the price range, must be divisible by the tick spacing
+		/// - `amount_0_max`: max amount of asset 0 (asset with lower id) to provide
+		/// - `amount_1_max`: max amount of asset 1 (asset with higher id) to provide
+		/// - `min_liquidity`: minimum liquidity of the created position
+		///
+		/// Emits `LiquidityAdded` event when successful.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_liquidity()
+			.saturating_add(T::PriceBarrier::ensure_price_weight()))]
+		pub fn add_liquidity(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			tick_lower: Tick,
+			tick_upper: Tick,
+			amount_0_max: Balance,
+			amount_1_max: Balance,
+			min_liquidity: Liquidity,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+
+			Self::ensure_tick_range(&pool, tick_lower, tick_upper)?;
+			Self::ensure_price_barrier(&who, &pool)?;
+
+			let sqrt_price_lower = calculate_sqrt_price_at_tick(tick_lower).map_err(|_| ArithmeticError::Overflow)?;
+			let sqrt_price_upper = calculate_sqrt_price_at_tick(tick_upper).map_err(|_| ArithmeticError::Overflow)?;
+
+			let liquidity = calculate_liquidity_for_amounts(
+				pool.sqrt_price,
+				sqrt_price_lower,
+				sqrt_price_upper,
+				amount_0_max,
+				amount_1_max,
+			)
+			.map_err(|_| ArithmeticError::Overflow)?;
+
+			ensure!(liquidity > 0, Error::<T>::ZeroLiquidity);
+			ensure!(liquidity >= min_liquidity, Error::<T>::SlippageLimit);
+
+			let (amount_0, amount_1) =
+				calculate_amounts_for_liquidity(pool.sqrt_price, sqrt_price_lower, sqrt_price_upper, liquidity, true)
+					.map_err(|_| ArithmeticError::Overflow)?;
+
+			ensure!(
+				amount_0 <= amount_0_max && amount_1 <= amount_1_max,
+				Error::<T>::SlippageLimit
+			);
+
+			let liquidity_delta = i128::try_from(liquidity).map_err(|_| ArithmeticError::Overflow)?;
+
+			Self::update_tick(pool_id, &pool, tick_lower, liquidity_delta, false)?;
+			Self::update_tick(pool_id, &pool, tick_upper, liquidity_delta, true)?;
+
+			if pool.tick >= tick_lower && pool.tick < tick_upper {
+				pool.liquidity = pool.liquidity.checked_add(liquidity).ok_or(ArithmeticError::Overflow)?;
+				Pools::<T>::insert(pool_id, &pool);
+			}
+
+			let (fee_growth_inside_0, fee_growth_inside_1) =
+				Self::fee_growth_inside(pool_id, &pool, tick_lower, tick_upper);
+
+			let pool_account = Self::pool_account(pool_id);
+			if amount_0 > 0 {
+				T::Currency::transfer(pool.asset_0, &who, &pool_account, amount_0)?;
+			}
+			if amount_1 > 0 {
+				T::Currency::transfer(pool.asset_1, &who, &pool_account, amount_1)?;
+			}
+
+			let position_id = Self::create_and_mint_position_instance(&who)?;
+
+			Positions::<T>::insert(
+				position_id,
+				Position {
+					pool_id,
+					tick_lower,
+					tick_upper,
+					liquidity,
+					fee_growth_inside_0_last: fee_growth_inside_0,
+					fee_growth_inside_1_last: fee_growth_inside_1,
+					fees_owed_0: 0,
+					fees_owed_1: 0,
+				},
+			);
+
+			Self::deposit_event(Event::LiquidityAdded {
+				who,
+				pool_id,
+				position_id,
+				tick_lower,
+				tick_upper,
+				liquidity,
+				amount_0,
+				amount_1,
+			});
+
+			Ok(())
+		}
+
+		/// Remove liquidity from a position.
+		///
+		/// Fees earned by the position are collected as well.
+		/// If all liquidity is removed, position is destroyed and NFT is burned.
+		///
+		/// Parameters:
+		/// - `origin`: owner of the position
+		/// - `position_id`: position NFT instance id
+		/// - `liquidity`: amount of liquidity to remove
+		///
+		/// Emits `LiquidityRemoved` event when successful.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_liquidity()
+			.saturating_add(T::PriceBarrier::ensure_price_weight()))]
+		pub fn remove_liquidity(
+			origin: OriginFor<T>,
+			position_id: T::PositionItemId,
+			liquidity: Liquidity,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_position_owner(&who, position_id)?;
+
+			let mut position = Positions::<T>::get(position_id).ok_or(Error::<T>::PositionNotFound)?;
+
+			ensure!(
+				liquidity > 0 && liquidity <= position.liquidity,
+				Error::<T>::InsufficientPositionLiquidity
+			);
+
+			let pool_id = position.pool_id;
+			let mut pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+
+			Self::ensure_price_barrier(&who, &pool)?;
+
+			// fees have to be accounted before the ticks are updated, ticks may be cleared
+			Self::update_position_fees(pool_id, &pool, &mut position)?;
+
+			let liquidity_delta = i128::try_from(liquidity)
+				.map_err(|_| ArithmeticError::Overflow)?
+				.checked_neg()
+				.ok_or(ArithmeticError::Overflow)?;
+
+			Self::update_tick(pool_id, &pool, position.tick_lower, liquidity_delta, false)?;
+			Self::update_tick(pool_id, &pool, position.tick_upper, liquidity_delta, true)?;
+
+			if pool.tick >= position.tick_lower && pool.tick < position.tick_upper {
+				pool.liquidity = pool
+					.liquidity
+					.checked_sub(liquidity)
+					.ok_or(ArithmeticError::Underflow)?;
+				Pools::<T>::insert(pool_id, &pool);
+			}
+
+			let (amount_0, amount_1) = calculate_amounts_for_liquidity(
+				pool.sqrt_price,
+				calculate_sqrt_price_at_tick(position.tick_lower).map_err(|_| ArithmeticError::Overflow)?,
+				calculate_sqrt_price_at_tick(position.tick_upper).map_err(|_| ArithmeticError::Overflow)?,
+				liquidity,
+				false,
+			)
+			.map_err(|_| ArithmeticError::Overflow)?;
+
+			let pool_account = Self::pool_account(pool_id);
+			if amount_0 > 0 {
+				T::Currency::transfer(pool.asset_0, &pool_account, &who, amount_0)?;
+			}
+			if amount_1 > 0 {
+				T::Currency::transfer(pool.asset_1, &pool_account, &who, amount_1)?;
+			}
+
+			position.liquidity = position
+				.liquidity
+				.checked_sub(liquidity)
+				.ok_or(ArithmeticError::Underflow)?;
+
+			Self::transfer_fees(&who, position_id, &pool, &mut position)?;
+
+			Self::deposit_event(Event::LiquidityRemoved {
+				who: who.clone(),
+				pool_id,
+				position_id,
+				liquidity,
+				amount_0,
+				amount_1,
+			});
+
+			if position.liquidity.is_zero() {
+				Positions::<T>::remove(position_id);
+				T::NFTHandler::burn(&T::NFTCollectionId::get(), &position_id, Some(&who))?;
+
+				Self::deposit_event(Event::PositionDestroyed {
+					position_id,
+					owner: who,
+				});
+			} else {
+				Positions::<T>::insert(position_id, position);
+			}
+
+			Ok(())
+		}
+
+		/// Collect fees earned by a position.
+		///
+		/// Parameters:
+		/// - `origin`: owner of the position
+		/// - `position_id`: position NFT instance id
+		///
+		/// Emits `FeesCollected` event when successful.
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::collect_fees())]
+		pub fn collect_fees(origin: OriginFor<T>, position_id: T::PositionItemId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_position_owner(&who, position_id)?;
+
+			let mut position = Positions::<T>::get(position_id).ok_or(Error::<T>::PositionNotFound)?;
+			let pool = Pools::<T>::get(position.pool_id).ok_or(Error::<T>::PoolNotFound)?;
+
+			Self::update_position_fees(position.pool_id, &pool, &mut position)?;
+			Self::transfer_fees(&who, position_id, &pool, &mut position)?;
+
+			Positions::<T>::insert(position_id, position);
+
+			Ok(())
+		}
+
+		/// Execute a swap of `asset_in` for `asset_out` by specifying how much to put in.
+		///
+		/// Parameters:
+		/// - `origin`: origin of the caller
+		/// - `pool_id`: pool id
+		/// - `asset_in`: ID of asset sold to the pool
+		/// - `asset_out`: ID of asset bought from the pool
+		/// - `amount_in`: amount of asset to sell
+		/// - `min_buy_amount`: minimum amount of asset out to receive
+		///
+		/// Emits `SellExecuted` event when successful.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::sell()
+			.saturating_add(T::AMMHandler::on_trade_weight()))]
+		pub fn sell(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount_in: Balance,
+			min_buy_amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				amount_in >= T::MinTradingLimit::get(),
+				Error::<T>::InsufficientTradingAmount
+			);
+
+			let (pool, result) = Self::calculate_trade(pool_id, asset_in, asset_out, amount_in, true)?;
+
+			ensure!(result.amount_out >= min_buy_amount, Error::<T>::BuyLimitNotReached);
+
+			Self::execute_trade(&who, pool_id, pool, asset_in, asset_out, &result)?;
+
+			Self::deposit_event(Event::SellExecuted {
+				who,
+				pool_id,
+				asset_in,
+				asset_out,
+				amount_in,
+				amount_out: result.amount_out,
+				fee: result.fee_amount,
+			});
+
+			Ok(())
+		}
+
+		/// Execute a swap of `asset_in` for `asset_out` by specifying how much to get out.
+		///
+		/// Parameters:
+		/// - `origin`: origin of the caller
+		/// - `pool_id`: pool id
+		/// - `asset_out`: ID of asset bought from the pool
+		/// - `asset_in`: ID of asset sold to the pool
+		/// - `amount_out`: amount of asset to receive
+		/// - `max_sell_amount`: maximum amount of asset in to pay, including fee
+		///
+		/// Emits `BuyExecuted` event when successful.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::buy()
+			.saturating_add(T::AMMHandler::on_trade_weight()))]
+		pub fn buy(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			asset_out: T::AssetId,
+			asset_in: T::AssetId,
+			amount_out: Balance,
+			max_sell_amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				amount_out >= T::MinTradingLimit::get(),
+				Error::<T>::InsufficientTradingAmount
+			);
+
+			let (pool, result) = Self::calculate_trade(pool_id, asset_in, asset_out, amount_out, false)?;
+
+			let amount_in = result
+				.amount_in
+				.checked_add(result.fee_amount)
+				.ok_or(ArithmeticError::Overflow)?;
+
+			ensure!(amount_in <= max_sell_amount, Error::<T>::SellLimitExceeded);
+
+			Self::execute_trade(&who, pool_id, pool, asset_in, asset_out, &result)?;
+
+			Self::deposit_event(Event::BuyExecuted {
+				who,
+				pool_id,
+				asset_in,
+				asset_out,
+				amount_in,
+				amount_out,
+				fee: result.fee_amount,
+			});
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Account holding the liquidity of the pool.
+	pub fn pool_account(pool_id: PoolId) -> T::AccountId {
+		PALLET_ID.into_sub_account_truncating(pool_id)
+	}
+
+	/// Owner of the position NFT collection.
+	pub fn protocol_account() -> T::AccountId {
+		PALLET_ID.into_account_truncating()
+	}
+
+	/// Calculate a trade in the pool without changing any state.
+	///
+	/// `amount` is the amount in if `exact_in` is true, otherwise it is the amount out.
+	pub(crate) fn calculate_trade(
+		pool_id: PoolId,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount: Balance,
+		exact_in: bool,
+	) -> Result<(PoolInfo<T::AssetId>, SwapResult), DispatchError> {
+		ensure!(asset_in != asset_out, Error::<T>::NotAllowed);
+
+		let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+
+		ensure!(
+			pool.contains(asset_in) && pool.contains(asset_out),
+			Error::<T>::AssetNotInPool
+		);
+
+		let result = Self::calculate_swap(pool_id, &pool, asset_in == pool.asset_0, amount, exact_in)?;
+
+		Ok((pool, result))
+	}
+
+	/// Calculate a swap through the initialized ticks of the pool.
+	fn calculate_swap(
+		pool_id: PoolId,
+		pool: &PoolInfo<T::AssetId>,
+		zero_for_one: bool,
+		amount: Balance,
+		exact_in: bool,
+	) -> Result<SwapResult, DispatchError> {
+		let initialized_ticks = InitializedTicks::<T>::get(pool_id);
+		let max_crossings = T::MaxTickCrossings::get() as usize;
+
+		let sqrt_price_limit = if zero_for_one {
+			MIN_SQRT_PRICE + U256::one()
+		} else {
+			MAX_SQRT_PRICE - U256::one()
+		};
+
+		let mut result = SwapResult {
+			amount_in: 0,
+			amount_out: 0,
+			fee_amount: 0,
+			sqrt_price: pool.sqrt_price,
+			tick: pool.tick,
+			liquidity: pool.liquidity,
+			fee_growth_global: if zero_for_one {
+				pool.fee_growth_global_0
+			} else {
+				pool.fee_growth_global_1
+			},
+			crossed_ticks: Vec::new(),
+		};
+		let mut amount_remaining = amount;
+
+		while amount_remaining > 0 && result.sqrt_price != sqrt_price_limit {
+			let sqrt_price_start = result.sqrt_price;
+
+			// next initialized tick in the direction of the swap, or the end of the price range
+			let idx = initialized_ticks.partition_point(|tick| *tick <= result.tick);
+			let next_initialized_tick = if zero_for_one {
+				idx.checked_sub(1).and_then(|i| initialized_ticks.get(i)).copied()
+			} else {
+				initialized_ticks.get(idx).copied()
+			};
+			let tick_next = next_initialized_tick.unwrap_or(if zero_for_one { MIN_TICK } else { MAX_TICK });
+
+			let sqrt_price_next = calculate_sqrt_price_at_tick(tick_next).map_err(|_| ArithmeticError::Overflow)?;
+			let sqrt_price_target = if zero_for_one {
+				sqrt_price_next.max(sqrt_price_limit)
+			} else {
+				sqrt_price_next.min(sqrt_price_limit)
+			};
+
+			let step = calculate_swap_step(
+				result.sqrt_price,
+				sqrt_price_target,
+				result.liquidity,
+				amount_remaining,
+				pool.fee,
+				exact_in,
+			)
+			.map_err(|_| ArithmeticError::Overflow)?;
+
+			result.sqrt_price = step.sqrt_price_next;
+
+			let amount_used = if exact_in {
+				step.amount_in
+					.checked_add(step.fee_amount)
+					.ok_or(ArithmeticError::Overflow)?
+			} else {
+				step.amount_out
+			};
+			amount_remaining = amount_remaining
+				.checked_sub(amount_used)
+				.ok_or(ArithmeticError::Underflow)?;
+
+			result.amount_in = result
+				.amount_in
+				.checked_add(step.amount_in)
+				.ok_or(ArithmeticError::Overflow)?;
+			result.amount_out = result
+				.amount_out
+				.checked_add(step.amount_out)
+				.ok_or(ArithmeticError::Overflow)?;
+			result.fee_amount = result
+				.fee_amount
+				.checked_add(step.fee_amount)
+				.ok_or(ArithmeticError::Overflow)?;
+
+			if result.liquidity > 0 {
+				let fee_growth =
+					calculate_fee_growth(step.fee_amount, result.liquidity).map_err(|_| ArithmeticError::Overflow)?;
+				// fee growth is allowed to overflow, only differences are used
+				result.fee_growth_global = result.fee_growth_global.overflowing_add(fee_growth).0;
+			}
+
+			if result.sqrt_price == sqrt_price_next {
+				if next_initialized_tick.is_some() {
+					ensure!(
+						result.crossed_ticks.len() < max_crossings,
+						Error::<T>::TooManyTickCrossings
+					);
+
+					let liquidity_net = Ticks::<T>::get(pool_id, tick_next)
+						.map(|info| info.liquidity_net)
+						.unwrap_or_default();
+					let liquidity_net = if zero_for_one {
+						liquidity_net.checked_neg().ok_or(ArithmeticError::Overflow)?
+					} else {
+						liquidity_net
+					};
+
+					result.liquidity = add_liquidity_delta(result.liquidity, liquidity_net)?;
+					result.crossed_ticks.push((tick_next, result.fee_growth_global));
+				}
+
+				result.tick = if zero_for_one { tick_next - 1 } else { tick_next };
+			} else if result.sqrt_price != sqrt_price_start {
+				result.tick = calculate_tick_at_sqrt_price(result.sqrt_price).map_err(|_| ArithmeticError::Overflow)?;
+			}
+		}
+
+		ensure!(amount_remaining == 0, Error::<T>::InsufficientLiquidity);
+
+		Ok(result)
+	}
+
+	/// Transfer assets of a calculated trade, update the pool state and notify the AMM handler.
+	fn execute_trade(
+		who: &T::AccountId,
+		pool_id: PoolId,
+		mut pool: PoolInfo<T::AssetId>,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		result: &SwapResult,
+	) -> DispatchResult {
+		let amount_in = result
+			.amount_in
+			.checked_add(result.fee_amount)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		ensure!(
+			T::Currency::free_balance(asset_in, who) >= amount_in,
+			Error::<T>::InsufficientBalance
+		);
+
+		let pool_account = Self::pool_account(pool_id);
+
+		T::Currency::transfer(asset_in, who, &pool_account, amount_in)?;
+		T::Currency::transfer(asset_out, &pool_account, who, result.amount_out)?;
+
+		let zero_for_one = asset_in == pool.asset_0;
+
+		for (tick, fee_growth_global) in result.crossed_ticks.iter() {
+			let (fee_growth_global_0, fee_growth_global_1) = if zero_for_one {
+				(*fee_growth_global, pool.fee_growth_global_1)
+			} else {
+				(pool.fee_growth_global_0, *fee_growth_global)
+			};
+			Ticks::<T>::mutate(pool_id, tick, |maybe_info| {
+				if let Some(info) = maybe_info {
+					info.fee_growth_outside_0 = fee_growth_global_0.overflowing_sub(info.fee_growth_outside_0).0;
+					info.fee_growth_outside_1 = fee_growth_global_1.overflowing_sub(info.fee_growth_outside_1).0;
+				}
+			});
+		}
+
+		pool.sqrt_price = result.sqrt_price;
+		pool.tick = result.tick;
+		pool.liquidity = result.liquidity;
+		if zero_for_one {
+			pool.fee_growth_global_0 = result.fee_growth_global;
+		} else {
+			pool.fee_growth_global_1 = result.fee_growth_global;
+		}
+		Pools::<T>::insert(pool_id, &pool);
+
+		let liquidity_in = T::Currency::free_balance(asset_in, &pool_account);
+		let liquidity_out = T::Currency::free_balance(asset_out, &pool_account);
+
+		// price of asset out denominated in asset in
+		let (n, d) = calculate_price_from_sqrt_price(pool.sqrt_price);
+		let price = if zero_for_one {
+			Ratio::new(d, n)
+		} else {
+			Ratio::new(n, d)
+		};
+
+		T::AMMHandler::on_trade(
+			T::OracleSource::get(),
+			asset_in,
+			asset_out,
+			amount_in,
+			result.amount_out,
+			liquidity_in,
+			liquidity_out,
+			price,
+		)
+		.map_err(|(_w, e)| e)?;
+
+		Ok(())
+	}
+
+	/// Ensure that the pool price has not been manipulated, comparing the price of asset 0 denominated in asset 1
+	/// with the oracle.
+	fn ensure_price_barrier(who: &T::AccountId, pool: &PoolInfo<T::AssetId>) -> DispatchResult {
+		// amount of asset 0 per asset 1, as the ratio of reserves in a constant product pool
+		let (n, d) = calculate_price_from_sqrt_price(pool.sqrt_price);
+		T::PriceBarrier::ensure_price(who, pool.asset_0, pool.asset_1, Ratio::new(d, n))
+	}
+
+	fn ensure_tick_range(pool: &PoolInfo<T::AssetId>, tick_lower: Tick, tick_upper: Tick) -> DispatchResult {
+		let tick_spacing = pool.tick_spacing as Tick;
+		ensure!(
+			tick_lower < tick_upper
+				&& tick_lower >= MIN_TICK
+				&& tick_upper <= MAX_TICK
+				&& tick_lower % tick_spacing == 0
+				&& tick_upper % tick_spacing == 0,
+			Error::<T>::InvalidTickRange
+		);
+		Ok(())
+	}
+
+	fn ensure_position_owner(who: &T::AccountId, position_id: T::PositionItemId) -> DispatchResult {
+		ensure!(
+			T::NFTHandler::owner(&T::NFTCollectionId::get(), &position_id).as_ref() == Some(who),
+			Error::<T>::Forbidden
+		);
+		Ok(())
+	}
+
+	/// Update liquidity of a tick used as a position bound. Ticks are initialized on first use and cleared when
+	/// no position uses them anymore.
+	fn update_tick(
+		pool_id: PoolId,
+		pool: &PoolInfo<T::AssetId>,
+		tick: Tick,
+		liquidity_delta: i128,
+		upper: bool,
+	) -> DispatchResult {
+		let mut info = Ticks::<T>::get(pool_id, tick).unwrap_or_default();
+
+		let liquidity_gross_before = info.liquidity_gross;
+		info.liquidity_gross = add_liquidity_delta(info.liquidity_gross, liquidity_delta)?;
+		ensure!(info.liquidity_gross <= i128::MAX as u128, ArithmeticError::Overflow);
+
+		info.liquidity_net = if upper {
+			info.liquidity_net.checked_sub(liquidity_delta)
+		} else {
+			info.liquidity_net.checked_add(liquidity_delta)
+		}
+		.ok_or(ArithmeticError::Overflow)?;
+
+		if liquidity_gross_before.is_zero() {
+			// by convention, all fee growth before a tick was initialized happened below the tick
+			if tick <= pool.tick {
+				info.fee_growth_outside_0 = pool.fee_growth_global_0;
+				info.fee_growth_outside_1 = pool.fee_growth_global_1;
+			}
+
+			InitializedTicks::<T>::try_mutate(pool_id, |ticks| -> DispatchResult {
+				if let Err(idx) = ticks.binary_search(&tick) {
+					ticks
+						.try_insert(idx, tick)
+						.map_err(|_| Error::<T>::TooManyInitializedTicks)?;
+				}
+				Ok(())
+			})?;
+		}
+
+		if info.liquidity_gross.is_zero() {
+			Ticks::<T>::remove(pool_id, tick);
+			InitializedTicks::<T>::mutate(pool_id, |ticks| {
+				if let Ok(idx) = ticks.binary_search(&tick) {
+					ticks.remove(idx);
+				}
+			});
+		} else {
+			Ticks::<T>::insert(pool_id, tick, info);
+		}
+
+		Ok(())
+	}
+
+	/// Calculate fee growth inside of the price range between `tick_lower` and `tick_upper`.
+	fn fee_growth_inside(
+		pool_id: PoolId,
+		pool: &PoolInfo<T::AssetId>,
+		tick_lower: Tick,
+		tick_upper: Tick,
+	) -> (U256, U256) {
+		let lower = Ticks::<T>::get(pool_id, tick_lower).unwrap_or_default();
+		let upper = Ticks::<T>::get(pool_id, tick_upper).unwrap_or_default();
+
+		let (below_0, below_1) = if pool.tick >= tick_lower {
+			(lower.fee_growth_outside_0, lower.fee_growth_outside_1)
+		} else {
+			(
+				pool.fee_growth_global_0.overflowing_sub(lower.fee_growth_outside_0).0,
+				pool.fee_growth_global_1.overflowing_sub(lower.fee_growth_outside_1).0,
+			)
+		};
+
+		let (above_0, above_1) = if pool.tick < tick_upper {
+			(upper.fee_growth_outside_0, upper.fee_growth_outside_1)
+		} else {
+			(
+				pool.fee_growth_global_0.overflowing_sub(upper.fee_growth_outside_0).0,
+				pool.fee_growth_global_1.overflowing_sub(upper.fee_growth_outside_1).0,
+			)
+		};
+
+		(
+			pool.fee_growth_global_0
+				.overflowing_sub(below_0)
+				.0
+				.overflowing_sub(above_0)
+				.0,
+			pool.fee_growth_global_1
+				.overflowing_sub(below_1)
+				.0
+				.overflowing_sub(above_1)
+				.0,
+		)
+	}
+
+	/// Add fees earned since the last update to the fees owed by the position.
+	fn update_position_fees(pool_id: PoolId, pool: &PoolInfo<T::AssetId>, position: &mut Position) -> DispatchResult {
+		let (fee_growth_inside_0, fee_growth_inside_1) =
+			Self::fee_growth_inside(pool_id, pool, position.tick_lower, position.tick_upper);
+
+		let fees_0 = calculate_fees_owed(
+			fee_growth_inside_0.overflowing_sub(position.fee_growth_inside_0_last).0,
+			position.liquidity,
+		)
+		.map_err(|_| ArithmeticError::Overflow)?;
+		let fees_1 = calculate_fees_owed(
+			fee_growth_inside_1.overflowing_sub(position.fee_growth_inside_1_last).0,
+			position.liquidity,
+		)
+		.map_err(|_| ArithmeticError::Overflow)?;
+
+		position.fees_owed_0 = position
+			.fees_owed_0
+			.checked_add(fees_0)
+			.ok_or(ArithmeticError::Overflow)?;
+		position.fees_owed_1 = position
+			.fees_owed_1
+			.checked_add(fees_1)
+			.ok_or(ArithmeticError::Overflow)?;
+		position.fee_growth_inside_0_last = fee_growth_inside_0;
+		position.fee_growth_inside_1_last = fee_growth_inside_1;
+
+		Ok(())
+	}
+
+	/// Transfer fees owed by the position to its owner.
+	fn transfer_fees(
+		who: &T::AccountId,
+		position_id: T::PositionItemId,
+		pool: &PoolInfo<T::AssetId>,
+		position: &mut Position,
+	) -> DispatchResult {
+		let (amount_0, amount_1) = (position.fees_owed_0, position.fees_owed_1);
+
+		if amount_0.is_zero() && amount_1.is_zero() {
+			return Ok(());
+		}
+
+		let pool_account = Self::pool_account(position.pool_id);
+		if amount_0 > 0 {
+			T::Currency::transfer(pool.asset_0, &pool_account, who, amount_0)?;
+		}
+		if amount_1 > 0 {
+			T::Currency::transfer(pool.asset_1, &pool_account, who, amount_1)?;
+		}
+
+		position.fees_owed_0 = 0;
+		position.fees_owed_1 = 0;
+
+		Self::deposit_event(Event::FeesCollected {
+			who: who.clone(),
+			position_id,
+			amount_0,
+			amount_1,
+		});
+
+		Ok(())
+	}
+
+	/// Generate an nft instance id and mint NFT into the class and instance.
+	#[require_transactional]
+	fn create_and_mint_position_instance(owner: &T::AccountId) -> Result<T::PositionItemId, DispatchError> {
+		<NextPositionId<T>>::try_mutate(|current_value| -> Result<T::PositionItemId, DispatchError> {
+			let next_position_id = *current_value;
+
+			T::NFTHandler::mint_into(&T::NFTCollectionId::get(), &next_position_id, owner)?;
+
+			*current_value = current_value
+				.checked_add(&T::PositionItemId::one())
+				.ok_or(ArithmeticError::Overflow)?;
+
+			Ok(next_position_id)
+		})
+	}
+}
+
+fn add_liquidity_delta(liquidity: Liquidity, delta: i128) -> Result<Liquidity, DispatchError> {
+	if delta < 0 {
+		liquidity
+			.checked_sub(delta.unsigned_abs())
+			.ok_or(ArithmeticError::Underflow.into())
+	} else {
+		liquidity
+			.checked_add(delta.unsigned_abs())
+			.ok_or(ArithmeticError::Overflow.into())
+	}
+}
@@ -0,0 +1,268 @@
+use crate::tests::mock::*;
+use crate::*;
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError::BadOrigin;
+
+fn q96() -> U256 {
+	U256::one() << 96
+}
+
+#[test]
+fn set_fee_tier_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let fee = Permill::from_parts(500);
+
+		assert_ok!(ConcentratedLiquidity::set_fee_tier(RuntimeOrigin::root(), fee, 10));
+
+		assert_eq!(ConcentratedLiquidity::fee_tier(fee), Some(10));
+		expect_events(vec![Event::FeeTierSet { fee, tick_spacing: 10 }.into()]);
+	});
+}
+
+#[test]
+fn set_fee_tier_should_update_tick_spacing_when_tier_exists() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::set_fee_tier(RuntimeOrigin::root(), FEE, 200));
+
+		assert_eq!(ConcentratedLiquidity::fee_tier(FEE), Some(200));
+	});
+}
+
+#[test]
+fn set_fee_tier_should_fail_when_origin_is_not_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::set_fee_tier(RuntimeOrigin::signed(ALICE), Permill::from_parts(500), 10),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_fee_tier_should_fail_when_tick_spacing_is_invalid() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::set_fee_tier(RuntimeOrigin::root(), Permill::from_parts(500), 0),
+			Error::<Test>::InvalidTickSpacing
+		);
+		assert_noop!(
+			ConcentratedLiquidity::set_fee_tier(RuntimeOrigin::root(), Permill::from_parts(500), MAX_TICK_SPACING + 1),
+			Error::<Test>::InvalidTickSpacing
+		);
+	});
+}
+
+#[test]
+fn set_fee_tier_should_fail_when_fee_is_hundred_percent() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::set_fee_tier(RuntimeOrigin::root(), Permill::from_percent(100), 10),
+			Error::<Test>::InvalidFee
+		);
+	});
+}
+
+#[test]
+fn remove_fee_tier_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::remove_fee_tier(RuntimeOrigin::root(), FEE));
+
+		assert!(ConcentratedLiquidity::fee_tier(FEE).is_none());
+		expect_events(vec![Event::FeeTierRemoved { fee: FEE }.into()]);
+	});
+}
+
+#[test]
+fn remove_fee_tier_should_fail_when_tier_does_not_exist() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::remove_fee_tier(RuntimeOrigin::root(), Permill::from_parts(500)),
+			Error::<Test>::FeeTierNotFound
+		);
+	});
+}
+
+#[test]
+fn remove_fee_tier_should_not_affect_existing_pools() {
+	ExtBuilder::default()
+		.with_pool(HDX, DAI, FEE, FixedU128::one())
+		.build()
+		.execute_with(|| {
+			assert_ok!(ConcentratedLiquidity::remove_fee_tier(RuntimeOrigin::root(), FEE));
+
+			assert_eq!(ConcentratedLiquidity::pools(0).unwrap().tick_spacing, TICK_SPACING);
+		});
+}
+
+#[test]
+fn create_pool_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::create_pool(
+			RuntimeOrigin::root(),
+			HDX,
+			DAI,
+			FEE,
+			FixedU128::one()
+		));
+
+		assert_eq!(
+			ConcentratedLiquidity::pools(0),
+			Some(PoolInfo {
+				asset_0: HDX,
+				asset_1: DAI,
+				fee: FEE,
+				tick_spacing: TICK_SPACING,
+				sqrt_price: q96(),
+				tick: 0,
+				liquidity: 0,
+				fee_growth_global_0: U256::zero(),
+				fee_growth_global_1: U256::zero(),
+			})
+		);
+		assert_eq!(ConcentratedLiquidity::pool_id((HDX, DAI, FEE)), Some(0));
+		assert!(DUSTER_WHITELIST.with(|v| v.borrow().contains(&ConcentratedLiquidity::pool_account(0))));
+
+		expect_events(vec![Event::PoolCreated {
+			pool_id: 0,
+			asset_0: HDX,
+			asset_1: DAI,
+			fee: FEE,
+			tick_spacing: TICK_SPACING,
+			sqrt_price: q96(),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn create_pool_should_order_assets_and_invert_price() {
+	ExtBuilder::default().build().execute_with(|| {
+		// price of DAI is 4 HDX
+		assert_ok!(ConcentratedLiquidity::create_pool(
+			RuntimeOrigin::root(),
+			DAI,
+			HDX,
+			FEE,
+			FixedU128::from(4)
+		));
+
+		let pool = ConcentratedLiquidity::pools(0).unwrap();
+		assert_eq!(pool.asset_0, HDX);
+		assert_eq!(pool.asset_1, DAI);
+		// price of HDX is 0.25 DAI
+		assert_eq!(pool.sqrt_price, q96() >> 1);
+		assert_eq!(pool.tick, -13_864);
+		assert_eq!(ConcentratedLiquidity::pool_id((HDX, DAI, FEE)), Some(0));
+	});
+}
+
+#[test]
+fn create_pool_should_assign_incremental_pool_ids() {
+	ExtBuilder::default()
+		.with_fee_tier(Permill::from_percent(1), 200)
+		.build()
+		.execute_with(|| {
+			assert_ok!(ConcentratedLiquidity::create_pool(
+				RuntimeOrigin::root(),
+				HDX,
+				DAI,
+				FEE,
+				FixedU128::one()
+			));
+			assert_ok!(ConcentratedLiquidity::create_pool(
+				RuntimeOrigin::root(),
+				HDX,
+				DAI,
+				Permill::from_percent(1),
+				FixedU128::one()
+			));
+
+			assert_eq!(ConcentratedLiquidity::pool_id((HDX, DAI, FEE)), Some(0));
+			assert_eq!(
+				ConcentratedLiquidity::pool_id((HDX, DAI, Permill::from_percent(1))),
+				Some(1)
+			);
+			assert_eq!(ConcentratedLiquidity::pools(1).unwrap().tick_spacing, 200);
+			assert_ne!(
+				ConcentratedLiquidity::pool_account(0),
+				ConcentratedLiquidity::pool_account(1)
+			);
+		});
+}
+
+#[test]
+fn create_pool_should_fail_when_origin_is_not_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::create_pool(RuntimeOrigin::signed(ALICE), HDX, DAI, FEE, FixedU128::one()),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_assets_are_same() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::create_pool(RuntimeOrigin::root(), HDX, HDX, FEE, FixedU128::one()),
+			Error::<Test>::SameAssets
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_asset_is_not_registered() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::create_pool(RuntimeOrigin::root(), HDX, 100, FEE, FixedU128::one()),
+			Error::<Test>::AssetNotRegistered
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_fee_tier_does_not_exist() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::create_pool(
+				RuntimeOrigin::root(),
+				HDX,
+				DAI,
+				Permill::from_percent(1),
+				FixedU128::one()
+			),
+			Error::<Test>::FeeTierNotFound
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_pool_exists() {
+	ExtBuilder::default()
+		.with_pool(HDX, DAI, FEE, FixedU128::one())
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				ConcentratedLiquidity::create_pool(RuntimeOrigin::root(), HDX, DAI, FEE, FixedU128::one()),
+				Error::<Test>::PoolAlreadyExists
+			);
+			assert_noop!(
+				ConcentratedLiquidity::create_pool(RuntimeOrigin::root(), DAI, HDX, FEE, FixedU128::one()),
+				Error::<Test>::PoolAlreadyExists
+			);
+		});
+}
+
+#[test]
+fn create_pool_should_fail_when_initial_price_is_zero() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::create_pool(RuntimeOrigin::root(), HDX, DAI, FEE, FixedU128::zero()),
+			Error::<Test>::InvalidInitialPrice
+		);
+		assert_noop!(
+			ConcentratedLiquidity::create_pool(RuntimeOrigin::root(), DAI, HDX, FEE, FixedU128::zero()),
+			Error::<Test>::InvalidInitialPrice
+		);
+	});
+}
@@ -0,0 +1,213 @@
+use super::add_position;
+use crate::tests::mock::*;
+use crate::*;
+use frame_support::{assert_noop, assert_ok};
+use pretty_assertions::assert_eq;
+
+fn ext() -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, HDX, 1_000 * ONE),
+			(ALICE, DAI, 1_000 * ONE),
+			(BOB, HDX, 1_000 * ONE),
+			(BOB, DAI, 1_000 * ONE),
+			(CHARLIE, HDX, 1_000 * ONE),
+			(CHARLIE, DAI, 1_000 * ONE),
+		])
+		.with_pool(HDX, DAI, FEE, FixedU128::one())
+		.build()
+}
+
+fn sell(asset_in: AssetId, asset_out: AssetId, amount: Balance) -> Balance {
+	let (_, result) = ConcentratedLiquidity::calculate_trade(0, asset_in, asset_out, amount, true).unwrap();
+	assert_ok!(ConcentratedLiquidity::sell(
+		RuntimeOrigin::signed(CHARLIE),
+		0,
+		asset_in,
+		asset_out,
+		amount,
+		0
+	));
+	result.fee_amount
+}
+
+fn collect(who: AccountId, position_id: u32) -> (Balance, Balance) {
+	let hdx_balance = Tokens::free_balance(HDX, &who);
+	let dai_balance = Tokens::free_balance(DAI, &who);
+	assert_ok!(ConcentratedLiquidity::collect_fees(
+		RuntimeOrigin::signed(who),
+		position_id
+	));
+	(
+		Tokens::free_balance(HDX, &who) - hdx_balance,
+		Tokens::free_balance(DAI, &who) - dai_balance,
+	)
+}
+
+#[test]
+fn collect_fees_should_transfer_fees_of_asset_in() {
+	ext().execute_with(|| {
+		let position_id = add_position(ALICE, 0, -600, 600, 100 * ONE, 100 * ONE);
+		let fee = sell(HDX, DAI, 10 * ONE);
+
+		let (fees_0, fees_1) = collect(ALICE, position_id);
+
+		// single position earns all fees, rounded down
+		assert!(fees_0 <= fee && fee - fees_0 <= 1);
+		assert_eq!(fees_1, 0);
+
+		let pool = ConcentratedLiquidity::pools(0).unwrap();
+		let position = ConcentratedLiquidity::positions(position_id).unwrap();
+		assert_eq!(position.fees_owed_0, 0);
+		assert_eq!(position.fees_owed_1, 0);
+		assert_eq!(position.fee_growth_inside_0_last, pool.fee_growth_global_0);
+
+		expect_events(vec![Event::FeesCollected {
+			who: ALICE,
+			position_id,
+			amount_0: fees_0,
+			amount_1: 0,
+		}
+		.into()]);
+
+		// nothing more to collect
+		assert_eq!(collect(ALICE, position_id), (0, 0));
+	});
+}
+
+#[test]
+fn collect_fees_should_transfer_fees_of_both_assets() {
+	ext().execute_with(|| {
+		let position_id = add_position(ALICE, 0, -600, 600, 100 * ONE, 100 * ONE);
+		let fee_0 = sell(HDX, DAI, 10 * ONE);
+		let fee_1 = sell(DAI, HDX, 20 * ONE);
+
+		let (fees_0, fees_1) = collect(ALICE, position_id);
+
+		assert!(fees_0 <= fee_0 && fee_0 - fees_0 <= 1);
+		assert!(fees_1 <= fee_1 && fee_1 - fees_1 <= 1);
+	});
+}
+
+#[test]
+fn fees_should_be_split_proportionally_to_liquidity() {
+	ext().execute_with(|| {
+		let alice_position = add_position(ALICE, 0, -600, 600, 100 * ONE, 100 * ONE);
+		let bob_position = add_position(BOB, 0, -600, 600, 300 * ONE, 300 * ONE);
+		let alice_liquidity = ConcentratedLiquidity::positions(alice_position).unwrap().liquidity;
+		let bob_liquidity = ConcentratedLiquidity::positions(bob_position).unwrap().liquidity;
+
+		let fee = sell(HDX, DAI, 10 * ONE);
+
+		let (alice_fees, _) = collect(ALICE, alice_position);
+		let (bob_fees, _) = collect(BOB, bob_position);
+
+		let total_liquidity = alice_liquidity + bob_liquidity;
+		let alice_share = fee * alice_liquidity / total_liquidity;
+		let bob_share = fee * bob_liquidity / total_liquidity;
+		assert!(alice_fees <= alice_share && alice_share - alice_fees <= 1);
+		assert!(bob_fees <= bob_share && bob_share - bob_fees <= 1);
+	});
+}
+
+#[test]
+fn fees_should_not_be_earned_when_position_is_out_of_range() {
+	ext().execute_with(|| {
+		let alice_position = add_position(ALICE, 0, -600, 600, 100 * ONE, 100 * ONE);
+		let bob_position = add_position(BOB, 0, 600, 1_200, 100 * ONE, 100 * ONE);
+
+		let fee = sell(HDX, DAI, 10 * ONE);
+
+		let (alice_fees, _) = collect(ALICE, alice_position);
+		assert!(alice_fees <= fee && fee - alice_fees <= 1);
+		assert_eq!(collect(BOB, bob_position), (0, 0));
+	});
+}
+
+#[test]
+fn fees_should_be_earned_only_while_position_is_in_range() {
+	ext().execute_with(|| {
+		let inner_position = add_position(ALICE, 0, -600, 600, 100 * ONE, 100 * ONE);
+		let outer_position = add_position(BOB, 0, -1_200, 1_200, 100 * ONE, 100 * ONE);
+
+		// price moves below tick -600, inner position earns fees only for the part of the trade above the tick
+		let fee = sell(HDX, DAI, 200 * ONE);
+		assert!(ConcentratedLiquidity::pools(0).unwrap().tick < -600);
+
+		let inner_liquidity = ConcentratedLiquidity::positions(inner_position).unwrap().liquidity;
+		let outer_liquidity = ConcentratedLiquidity::positions(outer_position).unwrap().liquidity;
+
+		let (inner_fees, _) = collect(ALICE, inner_position);
+		let (outer_fees, _) = collect(BOB, outer_position);
+
+		assert!(inner_fees < fee * inner_liquidity / (inner_liquidity + outer_liquidity));
+		assert!(outer_fees > fee * outer_liquidity / (inner_liquidity + outer_liquidity));
+		assert!(inner_fees + outer_fees <= fee);
+		assert!(fee - (inner_fees + outer_fees) <= 4);
+
+		// inner position is out of range and does not earn fees anymore
+		sell(HDX, DAI, ONE);
+		assert_eq!(collect(ALICE, inner_position), (0, 0));
+		assert!(collect(BOB, outer_position).0 > 0);
+
+		// price moves back into the inner range
+		sell(DAI, HDX, 300 * ONE);
+		assert!(ConcentratedLiquidity::pools(0).unwrap().tick >= -600);
+		let (_, inner_fees) = collect(ALICE, inner_position);
+		assert!(inner_fees > 0);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_transfer_earned_fees() {
+	ext().execute_with(|| {
+		let position_id = add_position(ALICE, 0, -600, 600, 100 * ONE, 100 * ONE);
+		let liquidity = ConcentratedLiquidity::positions(position_id).unwrap().liquidity;
+		let fee = sell(HDX, DAI, 10 * ONE);
+		let pool = ConcentratedLiquidity::pools(0).unwrap();
+		let fees_0 = hydra_dx_math::concentrated::calculate_fees_owed(pool.fee_growth_global_0, liquidity).unwrap();
+		assert!(fees_0 <= fee && fee - fees_0 <= 1);
+
+		assert_ok!(ConcentratedLiquidity::remove_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			position_id,
+			liquidity / 2
+		));
+
+		expect_events(vec![Event::FeesCollected {
+			who: ALICE,
+			position_id,
+			amount_0: fees_0,
+			amount_1: 0,
+		}
+		.into()]);
+
+		// fees are not paid twice
+		assert_eq!(collect(ALICE, position_id), (0, 0));
+	});
+}
+
+#[test]
+fn new_position_should_not_earn_fees_of_past_trades() {
+	ext().execute_with(|| {
+		add_position(ALICE, 0, -600, 600, 100 * ONE, 100 * ONE);
+		sell(HDX, DAI, 10 * ONE);
+		sell(DAI, HDX, 10 * ONE);
+
+		let position_id = add_position(BOB, 0, -600, 600, 100 * ONE, 100 * ONE);
+
+		assert_eq!(collect(BOB, position_id), (0, 0));
+	});
+}
+
+#[test]
+fn collect_fees_should_fail_when_caller_is_not_owner() {
+	ext().execute_with(|| {
+		let position_id = add_position(ALICE, 0, -600, 600, 100 * ONE, 100 * ONE);
+
+		assert_noop!(
+			ConcentratedLiquidity::collect_fees(RuntimeOrigin::signed(BOB), position_id),
+			Error::<Test>::Forbidden
+		);
+	});
+}
@@ -0,0 +1,434 @@
+use super::{add_position, position_amounts};
+use crate::tests::mock::*;
+use crate::*;
+use frame_support::{assert_noop, assert_ok};
+use hydra_dx_math::concentrated::{calculate_amounts_for_liquidity, calculate_liquidity_for_amounts};
+use pretty_assertions::assert_eq;
+
+fn sqrt_price(tick: Tick) -> U256 {
+	calculate_sqrt_price_at_tick(tick).unwrap()
+}
+
+fn default_ext() -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, HDX, 1_000 * ONE),
+			(ALICE, DAI, 1_000 * ONE),
+			(BOB, HDX, 1_000 * ONE),
+			(BOB, DAI, 1_000 * ONE),
+		])
+		.with_pool(HDX, DAI, FEE, FixedU128::one())
+		.build()
+}
+
+#[test]
+fn add_liquidity_should_work_when_range_contains_current_price() {
+	default_ext().execute_with(|| {
+		let pool = ConcentratedLiquidity::pools(0).unwrap();
+		let expected_liquidity =
+			calculate_liquidity_for_amounts(pool.sqrt_price, sqrt_price(-600), sqrt_price(600), 100 * ONE, 100 * ONE)
+				.unwrap();
+		let (amount_0, amount_1) = calculate_amounts_for_liquidity(
+			pool.sqrt_price,
+			sqrt_price(-600),
+			sqrt_price(600),
+			expected_liquidity,
+			true,
+		)
+		.unwrap();
+		assert!(amount_0 > 0 && amount_0 <= 100 * ONE);
+		assert!(amount_1 > 0 && amount_1 <= 100 * ONE);
+
+		assert_ok!(ConcentratedLiquidity::add_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			-600,
+			600,
+			100 * ONE,
+			100 * ONE,
+			expected_liquidity
+		));
+
+		let pool_account = ConcentratedLiquidity::pool_account(0);
+		assert_balance!(ALICE, HDX, 1_000 * ONE - amount_0);
+		assert_balance!(ALICE, DAI, 1_000 * ONE - amount_1);
+		assert_balance!(pool_account, HDX, amount_0);
+		assert_balance!(pool_account, DAI, amount_1);
+
+		assert_eq!(ConcentratedLiquidity::pools(0).unwrap().liquidity, expected_liquidity);
+		assert_eq!(
+			ConcentratedLiquidity::positions(0),
+			Some(Position {
+				pool_id: 0,
+				tick_lower: -600,
+				tick_upper: 600,
+				liquidity: expected_liquidity,
+				fee_growth_inside_0_last: U256::zero(),
+				fee_growth_inside_1_last: U256::zero(),
+				fees_owed_0: 0,
+				fees_owed_1: 0,
+			})
+		);
+		assert_eq!(position_owner(0), Some(ALICE));
+
+		assert_eq!(ConcentratedLiquidity::initialized_ticks(0).to_vec(), vec![-600, 600]);
+		let lower = ConcentratedLiquidity::ticks(0, -600).unwrap();
+		assert_eq!(lower.liquidity_gross, expected_liquidity);
+		assert_eq!(lower.liquidity_net, expected_liquidity as i128);
+		let upper = ConcentratedLiquidity::ticks(0, 600).unwrap();
+		assert_eq!(upper.liquidity_gross, expected_liquidity);
+		assert_eq!(upper.liquidity_net, -(expected_liquidity as i128));
+
+		expect_events(vec![Event::LiquidityAdded {
+			who: ALICE,
+			pool_id: 0,
+			position_id: 0,
+			tick_lower: -600,
+			tick_upper: 600,
+			liquidity: expected_liquidity,
+			amount_0,
+			amount_1,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn add_liquidity_should_require_only_asset_1_when_range_is_below_current_price() {
+	default_ext().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::add_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			-1_200,
+			-600,
+			100 * ONE,
+			100 * ONE,
+			0
+		));
+
+		assert_balance!(ALICE, HDX, 1_000 * ONE);
+		assert!(Tokens::free_balance(DAI, &ALICE) >= 900 * ONE);
+		assert!(Tokens::free_balance(DAI, &ALICE) < 1_000 * ONE);
+		// price is out of the range, liquidity is not active
+		assert_eq!(ConcentratedLiquidity::pools(0).unwrap().liquidity, 0);
+	});
+}
+
+#[test]
+fn add_liquidity_should_require_only_asset_0_when_range_is_above_current_price() {
+	default_ext().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::add_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			600,
+			1_200,
+			100 * ONE,
+			100 * ONE,
+			0
+		));
+
+		assert_balance!(ALICE, DAI, 1_000 * ONE);
+		assert!(Tokens::free_balance(HDX, &ALICE) >= 900 * ONE);
+		assert!(Tokens::free_balance(HDX, &ALICE) < 1_000 * ONE);
+		assert_eq!(ConcentratedLiquidity::pools(0).unwrap().liquidity, 0);
+	});
+}
+
+#[test]
+fn add_liquidity_should_mint_new_position_for_each_call() {
+	default_ext().execute_with(|| {
+		let first = add_position(ALICE, 0, -600, 600, 10 * ONE, 10 * ONE);
+		let second = add_position(ALICE, 0, -600, 600, 10 * ONE, 10 * ONE);
+		let third = add_position(BOB, 0, -120, 1_200, 10 * ONE, 10 * ONE);
+
+		assert_eq!((first, second, third), (0, 1, 2));
+		assert_eq!(position_owner(second), Some(ALICE));
+		assert_eq!(position_owner(third), Some(BOB));
+
+		let liquidity_0 = ConcentratedLiquidity::positions(first).unwrap().liquidity;
+		let liquidity_1 = ConcentratedLiquidity::positions(second).unwrap().liquidity;
+		let liquidity_2 = ConcentratedLiquidity::positions(third).unwrap().liquidity;
+
+		// shared ticks are initialized only once
+		assert_eq!(
+			ConcentratedLiquidity::initialized_ticks(0).to_vec(),
+			vec![-600, -120, 600, 1_200]
+		);
+		assert_eq!(
+			ConcentratedLiquidity::ticks(0, -600).unwrap().liquidity_gross,
+			liquidity_0 + liquidity_1
+		);
+		assert_eq!(
+			ConcentratedLiquidity::pools(0).unwrap().liquidity,
+			liquidity_0 + liquidity_1 + liquidity_2
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_tick_range_is_invalid() {
+	default_ext().execute_with(|| {
+		for (tick_lower, tick_upper) in [
+			(600, -600),
+			(600, 600),
+			(-630, 600),
+			(-600, 610),
+			(-887_280, 600),
+			(-600, 887_280),
+		] {
+			assert_noop!(
+				ConcentratedLiquidity::add_liquidity(
+					RuntimeOrigin::signed(ALICE),
+					0,
+					tick_lower,
+					tick_upper,
+					100 * ONE,
+					100 * ONE,
+					0
+				),
+				Error::<Test>::InvalidTickRange
+			);
+		}
+	});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_pool_does_not_exist() {
+	default_ext().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::add_liquidity(RuntimeOrigin::signed(ALICE), 1, -600, 600, 100 * ONE, 100 * ONE, 0),
+			Error::<Test>::PoolNotFound
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_liquidity_is_zero() {
+	default_ext().execute_with(|| {
+		// range is above current price and only asset 1 is provided
+		assert_noop!(
+			ConcentratedLiquidity::add_liquidity(RuntimeOrigin::signed(ALICE), 0, 600, 1_200, 0, 100 * ONE, 0),
+			Error::<Test>::ZeroLiquidity
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_liquidity_is_below_minimum() {
+	default_ext().execute_with(|| {
+		let pool = ConcentratedLiquidity::pools(0).unwrap();
+		let liquidity =
+			calculate_liquidity_for_amounts(pool.sqrt_price, sqrt_price(-600), sqrt_price(600), 100 * ONE, 100 * ONE)
+				.unwrap();
+
+		assert_noop!(
+			ConcentratedLiquidity::add_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				0,
+				-600,
+				600,
+				100 * ONE,
+				100 * ONE,
+				liquidity + 1
+			),
+			Error::<Test>::SlippageLimit
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_max_initialized_ticks_is_reached() {
+	default_ext().execute_with(|| {
+		for i in 1..=50 {
+			let tick = i * TICK_SPACING as Tick;
+			add_position(ALICE, 0, -tick, tick, ONE, ONE);
+		}
+
+		assert_noop!(
+			ConcentratedLiquidity::add_liquidity(RuntimeOrigin::signed(ALICE), 0, -3_060, 3_060, ONE, ONE, 0),
+			Error::<Test>::TooManyInitializedTicks
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_work_when_partial_liquidity_is_removed() {
+	default_ext().execute_with(|| {
+		let position_id = add_position(ALICE, 0, -600, 600, 100 * ONE, 100 * ONE);
+		let liquidity = ConcentratedLiquidity::positions(position_id).unwrap().liquidity;
+		let (amount_0, amount_1) = position_amounts(position_id);
+		let hdx_balance = Tokens::free_balance(HDX, &ALICE);
+		let dai_balance = Tokens::free_balance(DAI, &ALICE);
+
+		assert_ok!(ConcentratedLiquidity::remove_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			position_id,
+			liquidity / 2
+		));
+
+		let position = ConcentratedLiquidity::positions(position_id).unwrap();
+		assert_eq!(position.liquidity, liquidity - liquidity / 2);
+		assert_eq!(ConcentratedLiquidity::pools(0).unwrap().liquidity, position.liquidity);
+		assert_eq!(position_owner(position_id), Some(ALICE));
+
+		let received_0 = Tokens::free_balance(HDX, &ALICE) - hdx_balance;
+		let received_1 = Tokens::free_balance(DAI, &ALICE) - dai_balance;
+		assert!(received_0 <= amount_0 / 2 && amount_0 / 2 - received_0 <= 1);
+		assert!(received_1 <= amount_1 / 2 && amount_1 / 2 - received_1 <= 1);
+
+		expect_events(vec![Event::LiquidityRemoved {
+			who: ALICE,
+			pool_id: 0,
+			position_id,
+			liquidity: liquidity / 2,
+			amount_0: received_0,
+			amount_1: received_1,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_destroy_position_when_all_liquidity_is_removed() {
+	default_ext().execute_with(|| {
+		let position_id = add_position(ALICE, 0, -600, 600, 100 * ONE, 100 * ONE);
+		let liquidity = ConcentratedLiquidity::positions(position_id).unwrap().liquidity;
+
+		assert_ok!(ConcentratedLiquidity::remove_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			position_id,
+			liquidity
+		));
+
+		assert!(ConcentratedLiquidity::positions(position_id).is_none());
+		assert_eq!(position_owner(position_id), None);
+		assert_eq!(ConcentratedLiquidity::pools(0).unwrap().liquidity, 0);
+		assert!(ConcentratedLiquidity::initialized_ticks(0).is_empty());
+		assert!(ConcentratedLiquidity::ticks(0, -600).is_none());
+		assert!(ConcentratedLiquidity::ticks(0, 600).is_none());
+
+		// rounding is in favor of the pool
+		let pool_account = ConcentratedLiquidity::pool_account(0);
+		assert!(Tokens::free_balance(HDX, &pool_account) <= 1);
+		assert!(Tokens::free_balance(DAI, &pool_account) <= 1);
+		assert!(Tokens::free_balance(HDX, &ALICE) >= 1_000 * ONE - 1);
+		assert!(Tokens::free_balance(DAI, &ALICE) >= 1_000 * ONE - 1);
+
+		expect_events(vec![Event::PositionDestroyed {
+			position_id,
+			owner: ALICE,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_keep_shared_ticks_initialized() {
+	default_ext().execute_with(|| {
+		let alice_position = add_position(ALICE, 0, -600, 600, 100 * ONE, 100 * ONE);
+		let bob_position = add_position(BOB, 0, -600, 1_200, 100 * ONE, 100 * ONE);
+		let liquidity = ConcentratedLiquidity::positions(alice_position).unwrap().liquidity;
+		let bob_liquidity = ConcentratedLiquidity::positions(bob_position).unwrap().liquidity;
+
+		assert_ok!(ConcentratedLiquidity::remove_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			alice_position,
+			liquidity
+		));
+
+		assert_eq!(ConcentratedLiquidity::initialized_ticks(0).to_vec(), vec![-600, 1_200]);
+		assert_eq!(
+			ConcentratedLiquidity::ticks(0, -600).unwrap().liquidity_gross,
+			bob_liquidity
+		);
+		assert_eq!(ConcentratedLiquidity::pools(0).unwrap().liquidity, bob_liquidity);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_fail_when_caller_is_not_owner() {
+	default_ext().execute_with(|| {
+		let position_id = add_position(ALICE, 0, -600, 600, 100 * ONE, 100 * ONE);
+
+		assert_noop!(
+			ConcentratedLiquidity::remove_liquidity(RuntimeOrigin::signed(BOB), position_id, 1),
+			Error::<Test>::Forbidden
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_fail_when_position_does_not_exist() {
+	default_ext().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::remove_liquidity(RuntimeOrigin::signed(ALICE), 10, 1),
+			Error::<Test>::Forbidden
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_fail_when_liquidity_exceeds_position() {
+	default_ext().execute_with(|| {
+		let position_id = add_position(ALICE, 0, -600, 600, 100 * ONE, 100 * ONE);
+		let liquidity = ConcentratedLiquidity::positions(position_id).unwrap().liquidity;
+
+		assert_noop!(
+			ConcentratedLiquidity::remove_liquidity(RuntimeOrigin::signed(ALICE), position_id, liquidity + 1),
+			Error::<Test>::InsufficientPositionLiquidity
+		);
+		assert_noop!(
+			ConcentratedLiquidity::remove_liquidity(RuntimeOrigin::signed(ALICE), position_id, 0),
+			Error::<Test>::InsufficientPositionLiquidity
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_should_check_price_barrier_with_pool_spot_price() {
+	default_ext().execute_with(|| {
+		let pool = ConcentratedLiquidity::pools(0).unwrap();
+		let (n, d) = calculate_price_from_sqrt_price(pool.sqrt_price);
+
+		add_position(ALICE, 0, -600, 600, 100 * ONE, 100 * ONE);
+
+		assert_eq!(price_barrier_calls(), vec![(HDX, DAI, Ratio::new(d, n))]);
+	});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_price_barrier_is_triggered() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 1_000 * ONE), (ALICE, DAI, 1_000 * ONE)])
+		.with_pool(HDX, DAI, FEE, FixedU128::one())
+		.with_triggered_price_barrier()
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				ConcentratedLiquidity::add_liquidity(
+					RuntimeOrigin::signed(ALICE),
+					0,
+					-600,
+					600,
+					100 * ONE,
+					100 * ONE,
+					0
+				),
+				DispatchError::Other("price barrier")
+			);
+		});
+}
+
+#[test]
+fn remove_liquidity_should_fail_when_price_barrier_is_triggered() {
+	default_ext().execute_with(|| {
+		let position_id = add_position(ALICE, 0, -600, 600, 100 * ONE, 100 * ONE);
+		let liquidity = ConcentratedLiquidity::positions(position_id).unwrap().liquidity;
+		PRICE_BARRIER_TRIGGERED.with(|v| *v.borrow_mut() = true);
+
+		assert_noop!(
+			ConcentratedLiquidity::remove_liquidity(RuntimeOrigin::signed(ALICE), position_id, liquidity),
+			DispatchError::Other("price barrier")
+		);
+	});
+}
@@ -0,0 +1,485 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Test environment for Concentrated liquidity pallet.
+#![allow(clippy::type_complexity)]
+
+use sp_runtime::DispatchResult;
+use sp_std::prelude::*;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use crate as pallet_concentrated_liquidity;
+
+use crate::Config;
+
+use frame_support::assert_ok;
+use frame_support::traits::tokens::nonfungibles::{Create, Inspect, Mutate};
+use frame_support::traits::{Contains, Everything};
+use frame_support::weights::Weight;
+use frame_support::{
+	construct_runtime, parameter_types,
+	traits::{ConstU32, ConstU64},
+};
+use frame_system::EnsureRoot;
+use hydra_dx_math::ratio::Ratio;
+use hydradx_traits::pools::DustRemovalAccountWhitelist;
+use hydradx_traits::price::PriceBarrier;
+use hydradx_traits::{AssetKind, Inspect as InspectRegistry, OnTradeHandler, Source};
+use orml_traits::parameter_type_with_key;
+pub use orml_traits::MultiCurrency;
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	BuildStorage, DispatchError, FixedU128, Permill,
+};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub type Balance = u128;
+pub type AssetId = u32;
+pub type AccountId = u64;
+
+pub const HDX: AssetId = 0;
+pub const DAI: AssetId = 1;
+pub const DOT: AssetId = 2;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const CHARLIE: AccountId = 3;
+
+pub const ONE: Balance = 1_000_000_000_000;
+
+pub const FEE: Permill = Permill::from_parts(3_000);
+pub const TICK_SPACING: u32 = 60;
+
+pub const NFT_COLLECTION: u32 = 1000;
+
+#[macro_export]
+macro_rules! assert_balance {
+	( $x:expr, $y:expr, $z:expr) => {{
+		assert_eq!(Tokens::free_balance($y, &$x), $z);
+	}};
+}
+
+/// Trade reported to the AMM handler.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TradeInfo {
+	pub source: Source,
+	pub asset_in: AssetId,
+	pub asset_out: AssetId,
+	pub amount_in: Balance,
+	pub amount_out: Balance,
+	pub liquidity_in: Balance,
+	pub liquidity_out: Balance,
+	pub price: Ratio,
+}
+
+thread_local! {
+	pub static REGISTERED_ASSETS: RefCell<HashMap<AssetId, u8>> = RefCell::new(HashMap::default());
+	pub static POSITIONS: RefCell<HashMap<u32, u64>> = RefCell::new(HashMap::default());
+	pub static DUSTER_WHITELIST: RefCell<Vec<AccountId>> = const { RefCell::new(Vec::new()) };
+	pub static TRADES: RefCell<Vec<TradeInfo>> = const { RefCell::new(Vec::new()) };
+	pub static MAX_TICK_CROSSINGS: RefCell<u32> = const { RefCell::new(16) };
+	pub static PRICE_BARRIER_TRIGGERED: RefCell<bool> = const { RefCell::new(false) };
+	pub static PRICE_BARRIER_CALLS: RefCell<Vec<(AssetId, AssetId, Ratio)>> = const { RefCell::new(Vec::new()) };
+}
+
+construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Tokens: orml_tokens,
+		ConcentratedLiquidity: pallet_concentrated_liquidity,
+	}
+);
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeTask = RuntimeTask;
+	type Nonce = u64;
+	type Block = Block;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+	type SingleBlockMigrations = ();
+	type MultiBlockMigrator = ();
+	type PreInherents = ();
+	type PostInherents = ();
+	type PostTransactions = ();
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: AssetId| -> Balance {
+		0
+	};
+}
+
+impl orml_tokens::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type Amount = i128;
+	type CurrencyId = AssetId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type CurrencyHooks = ();
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = ();
+	type DustRemovalWhitelist = Everything;
+}
+
+parameter_types! {
+	pub const NFTCollectionId: u32 = NFT_COLLECTION;
+	pub const MinimumTradingLimit: Balance = 1000;
+	pub const MaxInitializedTicks: u32 = 100;
+	pub const TestOracleSource: Source = *b"testconc";
+	pub MaxTickCrossings: u32 = MAX_TICK_CROSSINGS.with(|v| *v.borrow());
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type Currency = Tokens;
+	type AssetRegistry = DummyRegistry;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type PositionItemId = u32;
+	type CollectionId = u32;
+	type NFTCollectionId = NFTCollectionId;
+	type NFTHandler = DummyNFT;
+	type DustAccountHandler = Whitelist;
+	type AMMHandler = DummyAMMHandler;
+	type OracleSource = TestOracleSource;
+	type PriceBarrier = MockPriceBarrier;
+	type MinTradingLimit = MinimumTradingLimit;
+	type MaxInitializedTicks = MaxInitializedTicks;
+	type MaxTickCrossings = MaxTickCrossings;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = DummyRegistry;
+}
+
+pub struct ExtBuilder {
+	endowed_accounts: Vec<(AccountId, AssetId, Balance)>,
+	registered_assets: Vec<(AssetId, u8)>,
+	fee_tiers: Vec<(Permill, u32)>,
+	pools: Vec<(AssetId, AssetId, Permill, FixedU128)>,
+	max_tick_crossings: u32,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		// If eg. tests running on one thread only, this thread local is shared.
+		// let's make sure that it is empty for each  test case
+		// or set to original default value
+		REGISTERED_ASSETS.with(|v| {
+			v.borrow_mut().clear();
+		});
+		POSITIONS.with(|v| {
+			v.borrow_mut().clear();
+		});
+		DUSTER_WHITELIST.with(|v| {
+			v.borrow_mut().clear();
+		});
+		TRADES.with(|v| {
+			v.borrow_mut().clear();
+		});
+		PRICE_BARRIER_TRIGGERED.with(|v| *v.borrow_mut() = false);
+		PRICE_BARRIER_CALLS.with(|v| v.borrow_mut().clear());
+
+		Self {
+			endowed_accounts: vec![],
+			registered_assets: vec![(HDX, 12), (DAI, 18), (DOT, 10)],
+			fee_tiers: vec![(FEE, TICK_SPACING)],
+			pools: vec![],
+			max_tick_crossings: 16,
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn with_endowed_accounts(mut self, accounts: Vec<(AccountId, AssetId, Balance)>) -> Self {
+		self.endowed_accounts = accounts;
+		self
+	}
+
+	pub fn with_registered_asset(mut self, asset: AssetId, decimals: u8) -> Self {
+		self.registered_assets.push((asset, decimals));
+		self
+	}
+
+	pub fn with_fee_tier(mut self, fee: Permill, tick_spacing: u32) -> Self {
+		self.fee_tiers.push((fee, tick_spacing));
+		self
+	}
+
+	/// Create pool of `asset_a` and `asset_b` with initial price of `asset_a` denominated in `asset_b`.
+	pub fn with_pool(mut self, asset_a: AssetId, asset_b: AssetId, fee: Permill, price: FixedU128) -> Self {
+		self.pools.push((asset_a, asset_b, fee, price));
+		self
+	}
+
+	pub fn with_max_tick_crossings(mut self, value: u32) -> Self {
+		self.max_tick_crossings = value;
+		self
+	}
+
+	pub fn with_triggered_price_barrier(self) -> Self {
+		PRICE_BARRIER_TRIGGERED.with(|v| *v.borrow_mut() = true);
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+		REGISTERED_ASSETS.with(|v| {
+			v.borrow_mut().extend(self.registered_assets.iter().copied());
+		});
+		MAX_TICK_CROSSINGS.with(|v| {
+			*v.borrow_mut() = self.max_tick_crossings;
+		});
+
+		orml_tokens::GenesisConfig::<Test> {
+			balances: self.endowed_accounts,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		let mut r: sp_io::TestExternalities = t.into();
+
+		r.execute_with(|| {
+			System::set_block_number(1);
+
+			for (fee, tick_spacing) in self.fee_tiers {
+				assert_ok!(ConcentratedLiquidity::set_fee_tier(
+					RuntimeOrigin::root(),
+					fee,
+					tick_spacing
+				));
+			}
+
+			for (asset_a, asset_b, fee, price) in self.pools {
+				assert_ok!(ConcentratedLiquidity::create_pool(
+					RuntimeOrigin::root(),
+					asset_a,
+					asset_b,
+					fee,
+					price
+				));
+			}
+		});
+
+		r
+	}
+}
+
+pub struct Whitelist;
+
+impl Contains<AccountId> for Whitelist {
+	fn contains(account: &AccountId) -> bool {
+		DUSTER_WHITELIST.with(|v| v.borrow().contains(account))
+	}
+}
+
+impl DustRemovalAccountWhitelist<AccountId> for Whitelist {
+	type Error = DispatchError;
+
+	fn add_account(account: &AccountId) -> Result<(), Self::Error> {
+		DUSTER_WHITELIST.with(|v| v.borrow_mut().push(*account));
+		Ok(())
+	}
+
+	fn remove_account(account: &AccountId) -> Result<(), Self::Error> {
+		DUSTER_WHITELIST.with(|v| {
+			let mut v = v.borrow_mut();
+
+			let idx = v.iter().position(|x| *x == *account).unwrap();
+			v.remove(idx);
+
+			Ok(())
+		})
+	}
+}
+
+pub struct DummyNFT;
+
+impl<AccountId: From<u64>> Inspect<AccountId> for DummyNFT {
+	type ItemId = u32;
+	type CollectionId = u32;
+
+	fn owner(_class: &Self::CollectionId, instance: &Self::ItemId) -> Option<AccountId> {
+		POSITIONS.with(|v| v.borrow().get(instance).map(|o| (*o).into()))
+	}
+}
+
+impl<AccountId: From<u64>> Create<AccountId> for DummyNFT {
+	fn create_collection(_class: &Self::CollectionId, _who: &AccountId, _admin: &AccountId) -> DispatchResult {
+		Ok(())
+	}
+}
+
+impl<AccountId: From<u64> + Into<u64> + Copy> Mutate<AccountId> for DummyNFT {
+	fn mint_into(_class: &Self::CollectionId, instance: &Self::ItemId, who: &AccountId) -> DispatchResult {
+		POSITIONS.with(|v| {
+			v.borrow_mut().insert(*instance, (*who).into());
+		});
+		Ok(())
+	}
+
+	fn burn(
+		_class: &Self::CollectionId,
+		instance: &Self::ItemId,
+		_maybe_check_owner: Option<&AccountId>,
+	) -> DispatchResult {
+		POSITIONS.with(|v| {
+			v.borrow_mut().remove(instance);
+		});
+		Ok(())
+	}
+}
+
+pub struct DummyRegistry;
+
+impl InspectRegistry for DummyRegistry {
+	type AssetId = AssetId;
+	type Location = u8;
+
+	fn is_sufficient(_id: Self::AssetId) -> bool {
+		unimplemented!()
+	}
+
+	fn exists(asset_id: AssetId) -> bool {
+		REGISTERED_ASSETS.with(|v| v.borrow().contains_key(&asset_id))
+	}
+
+	fn decimals(asset_id: AssetId) -> Option<u8> {
+		REGISTERED_ASSETS.with(|v| v.borrow().get(&asset_id).copied())
+	}
+
+	fn asset_type(_id: Self::AssetId) -> Option<AssetKind> {
+		unimplemented!()
+	}
+
+	fn is_banned(_id: Self::AssetId) -> bool {
+		unimplemented!()
+	}
+
+	fn asset_name(_id: Self::AssetId) -> Option<Vec<u8>> {
+		unimplemented!()
+	}
+
+	fn asset_symbol(_id: Self::AssetId) -> Option<Vec<u8>> {
+		unimplemented!()
+	}
+
+	fn existential_deposit(_id: Self::AssetId) -> Option<u128> {
+		unimplemented!()
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::types::BenchmarkHelper<AssetId> for DummyRegistry {
+	fn register_asset(asset_id: AssetId, decimals: u8) -> DispatchResult {
+		REGISTERED_ASSETS.with(|v| {
+			v.borrow_mut().insert(asset_id, decimals);
+		});
+
+		Ok(())
+	}
+}
+
+pub struct DummyAMMHandler;
+
+impl OnTradeHandler<AssetId, Balance, Ratio> for DummyAMMHandler {
+	fn on_trade(
+		source: Source,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		amount_out: Balance,
+		liquidity_in: Balance,
+		liquidity_out: Balance,
+		price: Ratio,
+	) -> Result<Weight, (Weight, DispatchError)> {
+		TRADES.with(|v| {
+			v.borrow_mut().push(TradeInfo {
+				source,
+				asset_in,
+				asset_out,
+				amount_in,
+				amount_out,
+				liquidity_in,
+				liquidity_out,
+				price,
+			})
+		});
+		Ok(Weight::zero())
+	}
+
+	fn on_trade_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+pub struct MockPriceBarrier;
+
+impl PriceBarrier<AccountId, AssetId, Ratio> for MockPriceBarrier {
+	fn ensure_price(_who: &AccountId, asset_a: AssetId, asset_b: AssetId, spot_price: Ratio) -> DispatchResult {
+		PRICE_BARRIER_CALLS.with(|v| v.borrow_mut().push((asset_a, asset_b, spot_price)));
+		if PRICE_BARRIER_TRIGGERED.with(|v| *v.borrow()) {
+			return Err(DispatchError::Other("price barrier"));
+		}
+		Ok(())
+	}
+
+	fn ensure_price_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+pub(crate) fn price_barrier_calls() -> Vec<(AssetId, AssetId, Ratio)> {
+	PRICE_BARRIER_CALLS.with(|v| v.borrow().clone())
+}
+
+pub(crate) fn last_trade() -> Option<TradeInfo> {
+	TRADES.with(|v| v.borrow().last().cloned())
+}
+
+pub(crate) fn position_owner(position_id: u32) -> Option<AccountId> {
+	POSITIONS.with(|v| v.borrow().get(&position_id).copied())
+}
+
+pub(crate) fn expect_events(e: Vec<RuntimeEvent>) {
+	e.into_iter().for_each(frame_system::Pallet::<Test>::assert_has_event);
+}
@@ -0,0 +1,46 @@
+use crate::tests::mock::*;
+use crate::*;
+use frame_support::assert_ok;
+
+mod creation;
+mod fees;
+mod liquidity;
+pub(crate) mod mock;
+mod trade_execution;
+mod trades;
+
+/// Add liquidity as `who` and return id of the minted position.
+pub(crate) fn add_position(
+	who: AccountId,
+	pool_id: PoolId,
+	tick_lower: Tick,
+	tick_upper: Tick,
+	amount_0: Balance,
+	amount_1: Balance,
+) -> u32 {
+	let position_id = NextPositionId::<Test>::get();
+	assert_ok!(ConcentratedLiquidity::add_liquidity(
+		RuntimeOrigin::signed(who),
+		pool_id,
+		tick_lower,
+		tick_upper,
+		amount_0,
+		amount_1,
+		0
+	));
+	position_id
+}
+
+/// Amounts of asset 0 and asset 1 which are withdrawn for the liquidity of the position at the current price.
+pub(crate) fn position_amounts(position_id: u32) -> (Balance, Balance) {
+	let position = Positions::<Test>::get(position_id).unwrap();
+	let pool = Pools::<Test>::get(position.pool_id).unwrap();
+	hydra_dx_math::concentrated::calculate_amounts_for_liquidity(
+		pool.sqrt_price,
+		hydra_dx_math::concentrated::calculate_sqrt_price_at_tick(position.tick_lower).unwrap(),
+		hydra_dx_math::concentrated::calculate_sqrt_price_at_tick(position.tick_upper).unwrap(),
+		position.liquidity,
+		false,
+	)
+	.unwrap()
+}
@@ -0,0 +1,179 @@
+use super::add_position;
+use crate::tests::mock::*;
+use crate::*;
+use frame_support::assert_ok;
+use hydradx_traits::router::{ExecutorError, PoolType, PoolsProvider, TradeExecution, TradeablePool};
+use pretty_assertions::assert_eq;
+use sp_runtime::FixedPointNumber;
+
+fn ext_with_liquidity() -> sp_io::TestExternalities {
+	let mut ext = ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, HDX, 1_000 * ONE),
+			(ALICE, DAI, 1_000 * ONE),
+			(BOB, HDX, 1_000 * ONE),
+			(BOB, DAI, 1_000 * ONE),
+		])
+		.with_pool(HDX, DAI, FEE, FixedU128::one())
+		.build();
+	ext.execute_with(|| {
+		add_position(ALICE, 0, -600, 600, 100 * ONE, 100 * ONE);
+	});
+	ext
+}
+
+#[test]
+fn calculate_sell_should_return_amount_received_by_sell() {
+	ext_with_liquidity().execute_with(|| {
+		let amount_in = 10 * ONE;
+		let expected = ConcentratedLiquidity::calculate_sell(PoolType::Concentrated(0), HDX, DAI, amount_in).unwrap();
+
+		assert_ok!(ConcentratedLiquidity::execute_sell(
+			RuntimeOrigin::signed(BOB),
+			PoolType::Concentrated(0),
+			HDX,
+			DAI,
+			amount_in,
+			expected
+		));
+
+		assert_balance!(BOB, HDX, 990 * ONE);
+		assert_balance!(BOB, DAI, 1_000 * ONE + expected);
+	});
+}
+
+#[test]
+fn calculate_buy_should_return_amount_paid_by_buy() {
+	ext_with_liquidity().execute_with(|| {
+		let amount_out = 10 * ONE;
+		let expected = ConcentratedLiquidity::calculate_buy(PoolType::Concentrated(0), HDX, DAI, amount_out).unwrap();
+
+		assert_ok!(ConcentratedLiquidity::execute_buy(
+			RuntimeOrigin::signed(BOB),
+			PoolType::Concentrated(0),
+			HDX,
+			DAI,
+			amount_out,
+			expected
+		));
+
+		assert_balance!(BOB, HDX, 1_000 * ONE - expected);
+		assert_balance!(BOB, DAI, 1_010 * ONE);
+	});
+}
+
+#[test]
+fn calculate_sell_should_fail_when_pool_does_not_exist() {
+	ext_with_liquidity().execute_with(|| {
+		assert_eq!(
+			ConcentratedLiquidity::calculate_sell(PoolType::Concentrated(1), HDX, DAI, ONE),
+			Err(ExecutorError::Error(Error::<Test>::PoolNotFound.into()))
+		);
+	});
+}
+
+#[test]
+fn get_liquidity_depth_should_return_pool_balance() {
+	ext_with_liquidity().execute_with(|| {
+		let pool_account = ConcentratedLiquidity::pool_account(0);
+
+		assert_eq!(
+			ConcentratedLiquidity::get_liquidity_depth(PoolType::Concentrated(0), HDX, DAI),
+			Ok(Tokens::free_balance(HDX, &pool_account))
+		);
+		assert_eq!(
+			ConcentratedLiquidity::get_liquidity_depth(PoolType::Concentrated(0), DAI, HDX),
+			Ok(Tokens::free_balance(DAI, &pool_account))
+		);
+	});
+}
+
+#[test]
+fn spot_price_with_fee_should_include_fee() {
+	ext_with_liquidity().execute_with(|| {
+		let price_hdx =
+			ConcentratedLiquidity::calculate_spot_price_with_fee(PoolType::Concentrated(0), HDX, DAI).unwrap();
+		let price_dai =
+			ConcentratedLiquidity::calculate_spot_price_with_fee(PoolType::Concentrated(0), DAI, HDX).unwrap();
+
+		// price is 1, fee is 0.3%
+		assert!(price_hdx > FixedU128::one());
+		assert!(price_hdx < FixedU128::from_rational(10_031, 10_000));
+		assert_eq!(price_hdx, price_dai);
+	});
+}
+
+#[test]
+fn spot_price_with_fee_should_be_amount_of_asset_a_for_one_asset_b() {
+	ExtBuilder::default()
+		.with_pool(DAI, HDX, FEE, FixedU128::from(4))
+		.build()
+		.execute_with(|| {
+			// one DAI costs 4 HDX
+			let price =
+				ConcentratedLiquidity::calculate_spot_price_with_fee(PoolType::Concentrated(0), HDX, DAI).unwrap();
+
+			assert!(price > FixedU128::from(4));
+			assert!(price < FixedU128::from_rational(40_121, 10_000));
+		});
+}
+
+#[test]
+fn trade_execution_should_not_support_other_pool_types() {
+	ext_with_liquidity().execute_with(|| {
+		assert_eq!(
+			ConcentratedLiquidity::calculate_sell(PoolType::XYK, HDX, DAI, ONE),
+			Err(ExecutorError::NotSupported)
+		);
+		assert_eq!(
+			ConcentratedLiquidity::calculate_buy(PoolType::Omnipool, HDX, DAI, ONE),
+			Err(ExecutorError::NotSupported)
+		);
+		assert_eq!(
+			ConcentratedLiquidity::execute_sell(RuntimeOrigin::signed(BOB), PoolType::XYK, HDX, DAI, ONE, 0),
+			Err(ExecutorError::NotSupported)
+		);
+		assert_eq!(
+			ConcentratedLiquidity::execute_buy(RuntimeOrigin::signed(BOB), PoolType::XYK, HDX, DAI, ONE, ONE),
+			Err(ExecutorError::NotSupported)
+		);
+		assert_eq!(
+			ConcentratedLiquidity::get_liquidity_depth(PoolType::Stableswap(0), HDX, DAI),
+			Err(ExecutorError::NotSupported)
+		);
+		assert_eq!(
+			ConcentratedLiquidity::calculate_spot_price_with_fee(PoolType::LBP, HDX, DAI),
+			Err(ExecutorError::NotSupported)
+		);
+	});
+}
+
+#[test]
+fn get_pools_should_return_all_pools() {
+	ExtBuilder::default()
+		.with_fee_tier(Permill::from_percent(1), 200)
+		.with_pool(HDX, DAI, FEE, FixedU128::one())
+		.with_pool(DOT, HDX, Permill::from_percent(1), FixedU128::one())
+		.build()
+		.execute_with(|| {
+			let mut pools = ConcentratedLiquidity::get_pools(u32::MAX);
+			pools.sort_by_key(|p| match p.pool {
+				PoolType::Concentrated(id) => id,
+				_ => u32::MAX,
+			});
+
+			assert_eq!(
+				pools,
+				vec![
+					TradeablePool {
+						pool: PoolType::Concentrated(0),
+						assets: vec![HDX, DAI],
+					},
+					TradeablePool {
+						pool: PoolType::Concentrated(1),
+						assets: vec![HDX, DOT],
+					},
+				]
+			);
+		});
+}
@@ -0,0 +1,398 @@
+use super::add_position;
+use crate::tests::mock::*;
+use crate::*;
+use frame_support::{assert_noop, assert_ok};
+use hydra_dx_math::concentrated::calculate_amount_0_delta;
+use pretty_assertions::assert_eq;
+
+fn sqrt_price(tick: Tick) -> U256 {
+	calculate_sqrt_price_at_tick(tick).unwrap()
+}
+
+fn ext_with_liquidity() -> sp_io::TestExternalities {
+	let mut ext = ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, HDX, 1_000 * ONE),
+			(ALICE, DAI, 1_000 * ONE),
+			(BOB, HDX, 1_000 * ONE),
+			(BOB, DAI, 1_000 * ONE),
+		])
+		.with_pool(HDX, DAI, FEE, FixedU128::one())
+		.build();
+	ext.execute_with(|| {
+		add_position(ALICE, 0, -600, 600, 100 * ONE, 100 * ONE);
+	});
+	ext
+}
+
+#[test]
+fn sell_should_work() {
+	ext_with_liquidity().execute_with(|| {
+		let (_, expected) = ConcentratedLiquidity::calculate_trade(0, HDX, DAI, ONE, true).unwrap();
+		assert_eq!(expected.amount_in + expected.fee_amount, ONE);
+		assert!(expected.amount_out < ONE - expected.fee_amount);
+		assert!(expected.fee_amount >= ONE * 3 / 1_000);
+
+		let pool_account = ConcentratedLiquidity::pool_account(0);
+		let pool_hdx = Tokens::free_balance(HDX, &pool_account);
+		let pool_dai = Tokens::free_balance(DAI, &pool_account);
+
+		assert_ok!(ConcentratedLiquidity::sell(
+			RuntimeOrigin::signed(BOB),
+			0,
+			HDX,
+			DAI,
+			ONE,
+			expected.amount_out
+		));
+
+		assert_balance!(BOB, HDX, 999 * ONE);
+		assert_balance!(BOB, DAI, 1_000 * ONE + expected.amount_out);
+		assert_balance!(pool_account, HDX, pool_hdx + ONE);
+		assert_balance!(pool_account, DAI, pool_dai - expected.amount_out);
+
+		let pool = ConcentratedLiquidity::pools(0).unwrap();
+		assert_eq!(pool.sqrt_price, expected.sqrt_price);
+		assert_eq!(pool.tick, expected.tick);
+		assert!(pool.sqrt_price < U256::one() << 96);
+		assert!(pool.tick < 0);
+		assert_eq!(pool.fee_growth_global_0, expected.fee_growth_global);
+		assert!(!pool.fee_growth_global_0.is_zero());
+		assert!(pool.fee_growth_global_1.is_zero());
+
+		expect_events(vec![Event::SellExecuted {
+			who: BOB,
+			pool_id: 0,
+			asset_in: HDX,
+			asset_out: DAI,
+			amount_in: ONE,
+			amount_out: expected.amount_out,
+			fee: expected.fee_amount,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn sell_should_move_price_up_when_asset_1_is_sold() {
+	ext_with_liquidity().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::sell(
+			RuntimeOrigin::signed(BOB),
+			0,
+			DAI,
+			HDX,
+			ONE,
+			0
+		));
+
+		let pool = ConcentratedLiquidity::pools(0).unwrap();
+		assert!(pool.sqrt_price > U256::one() << 96);
+		assert!(pool.tick >= 0);
+		assert!(pool.fee_growth_global_0.is_zero());
+		assert!(!pool.fee_growth_global_1.is_zero());
+	});
+}
+
+#[test]
+fn buy_should_work() {
+	ext_with_liquidity().execute_with(|| {
+		let (_, expected) = ConcentratedLiquidity::calculate_trade(0, HDX, DAI, ONE, false).unwrap();
+		assert_eq!(expected.amount_out, ONE);
+		let amount_in = expected.amount_in + expected.fee_amount;
+		assert!(amount_in > ONE);
+
+		assert_ok!(ConcentratedLiquidity::buy(
+			RuntimeOrigin::signed(BOB),
+			0,
+			DAI,
+			HDX,
+			ONE,
+			amount_in
+		));
+
+		assert_balance!(BOB, HDX, 1_000 * ONE - amount_in);
+		assert_balance!(BOB, DAI, 1_001 * ONE);
+
+		let pool = ConcentratedLiquidity::pools(0).unwrap();
+		assert_eq!(pool.sqrt_price, expected.sqrt_price);
+		assert_eq!(pool.tick, expected.tick);
+
+		expect_events(vec![Event::BuyExecuted {
+			who: BOB,
+			pool_id: 0,
+			asset_in: HDX,
+			asset_out: DAI,
+			amount_in,
+			amount_out: ONE,
+			fee: expected.fee_amount,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn buy_should_cost_at_most_sell_amount_of_equivalent_sell() {
+	ext_with_liquidity().execute_with(|| {
+		let (_, sell) = ConcentratedLiquidity::calculate_trade(0, HDX, DAI, 10 * ONE, true).unwrap();
+		let (_, buy) = ConcentratedLiquidity::calculate_trade(0, HDX, DAI, sell.amount_out, false).unwrap();
+
+		let buy_amount_in = buy.amount_in + buy.fee_amount;
+		assert!(buy_amount_in <= 10 * ONE);
+		assert!(10 * ONE - buy_amount_in <= 2);
+	});
+}
+
+#[test]
+fn trade_should_report_to_amm_handler() {
+	ext_with_liquidity().execute_with(|| {
+		let (_, expected) = ConcentratedLiquidity::calculate_trade(0, DAI, HDX, ONE, true).unwrap();
+
+		assert_ok!(ConcentratedLiquidity::sell(
+			RuntimeOrigin::signed(BOB),
+			0,
+			DAI,
+			HDX,
+			ONE,
+			0
+		));
+
+		let pool = ConcentratedLiquidity::pools(0).unwrap();
+		let pool_account = ConcentratedLiquidity::pool_account(0);
+		let (n, d) = calculate_price_from_sqrt_price(pool.sqrt_price);
+
+		assert_eq!(
+			last_trade(),
+			Some(TradeInfo {
+				source: *b"testconc",
+				asset_in: DAI,
+				asset_out: HDX,
+				amount_in: ONE,
+				amount_out: expected.amount_out,
+				liquidity_in: Tokens::free_balance(DAI, &pool_account),
+				liquidity_out: Tokens::free_balance(HDX, &pool_account),
+				price: Ratio::new(n, d),
+			})
+		);
+		// price of HDX denominated in DAI went up
+		assert!(n > d);
+	});
+}
+
+#[test]
+fn sell_should_fail_when_buy_limit_is_not_reached() {
+	ext_with_liquidity().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::sell(RuntimeOrigin::signed(BOB), 0, HDX, DAI, ONE, ONE),
+			Error::<Test>::BuyLimitNotReached
+		);
+	});
+}
+
+#[test]
+fn buy_should_fail_when_sell_limit_is_exceeded() {
+	ext_with_liquidity().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::buy(RuntimeOrigin::signed(BOB), 0, DAI, HDX, ONE, ONE),
+			Error::<Test>::SellLimitExceeded
+		);
+	});
+}
+
+#[test]
+fn trade_should_fail_when_amount_is_below_minimum_trading_limit() {
+	ext_with_liquidity().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::sell(RuntimeOrigin::signed(BOB), 0, HDX, DAI, 999, 0),
+			Error::<Test>::InsufficientTradingAmount
+		);
+		assert_noop!(
+			ConcentratedLiquidity::buy(RuntimeOrigin::signed(BOB), 0, DAI, HDX, 999, ONE),
+			Error::<Test>::InsufficientTradingAmount
+		);
+	});
+}
+
+#[test]
+fn trade_should_fail_when_asset_is_not_in_pool() {
+	ext_with_liquidity().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::sell(RuntimeOrigin::signed(BOB), 0, DOT, DAI, ONE, 0),
+			Error::<Test>::AssetNotInPool
+		);
+		assert_noop!(
+			ConcentratedLiquidity::buy(RuntimeOrigin::signed(BOB), 0, DOT, HDX, ONE, 10 * ONE),
+			Error::<Test>::AssetNotInPool
+		);
+	});
+}
+
+#[test]
+fn trade_should_fail_when_assets_are_same() {
+	ext_with_liquidity().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::sell(RuntimeOrigin::signed(BOB), 0, HDX, HDX, ONE, 0),
+			Error::<Test>::NotAllowed
+		);
+	});
+}
+
+#[test]
+fn trade_should_fail_when_pool_does_not_exist() {
+	ext_with_liquidity().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::sell(RuntimeOrigin::signed(BOB), 1, HDX, DAI, ONE, 0),
+			Error::<Test>::PoolNotFound
+		);
+	});
+}
+
+#[test]
+fn trade_should_fail_when_balance_is_insufficient() {
+	ext_with_liquidity().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::sell(RuntimeOrigin::signed(CHARLIE), 0, HDX, DAI, ONE, 0),
+			Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn trade_should_fail_when_pool_has_no_liquidity() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, HDX, 1_000 * ONE)])
+		.with_pool(HDX, DAI, FEE, FixedU128::one())
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				ConcentratedLiquidity::sell(RuntimeOrigin::signed(BOB), 0, HDX, DAI, ONE, 0),
+				Error::<Test>::InsufficientLiquidity
+			);
+		});
+}
+
+#[test]
+fn trade_should_fail_when_amount_exceeds_liquidity_in_range() {
+	ext_with_liquidity().execute_with(|| {
+		// all DAI in the pool is provided in range [-600, 0], more cannot be bought
+		let pool_dai = Tokens::free_balance(DAI, &ConcentratedLiquidity::pool_account(0));
+
+		assert_noop!(
+			ConcentratedLiquidity::buy(RuntimeOrigin::signed(BOB), 0, DAI, HDX, pool_dai + 1, 1_000 * ONE),
+			Error::<Test>::InsufficientLiquidity
+		);
+		assert_noop!(
+			ConcentratedLiquidity::sell(RuntimeOrigin::signed(BOB), 0, HDX, DAI, 500 * ONE, 0),
+			Error::<Test>::InsufficientLiquidity
+		);
+	});
+}
+
+#[test]
+fn sell_should_cross_initialized_tick_and_update_active_liquidity() {
+	ext_with_liquidity().execute_with(|| {
+		let inner = ConcentratedLiquidity::positions(0).unwrap().liquidity;
+		let outer_position = add_position(ALICE, 0, -1_200, 1_200, 100 * ONE, 100 * ONE);
+		let outer = ConcentratedLiquidity::positions(outer_position).unwrap().liquidity;
+		assert_eq!(ConcentratedLiquidity::pools(0).unwrap().liquidity, inner + outer);
+
+		// amount of HDX needed to move price to tick -600, with some extra to cross it
+		let amount_to_tick = calculate_amount_0_delta(sqrt_price(-600), sqrt_price(0), inner + outer, true).unwrap();
+		let amount_in = amount_to_tick * 11 / 10;
+
+		assert_ok!(ConcentratedLiquidity::sell(
+			RuntimeOrigin::signed(BOB),
+			0,
+			HDX,
+			DAI,
+			amount_in,
+			0
+		));
+
+		let pool = ConcentratedLiquidity::pools(0).unwrap();
+		assert!(pool.tick < -600 && pool.tick >= -1_200);
+		assert_eq!(pool.liquidity, outer);
+		// fee growth outside of the crossed tick is flipped to the fee growth accumulated above it
+		assert!(!ConcentratedLiquidity::ticks(0, -600)
+			.unwrap()
+			.fee_growth_outside_0
+			.is_zero());
+
+		// selling DAI moves the price back and activates the inner position again
+		assert_ok!(ConcentratedLiquidity::sell(
+			RuntimeOrigin::signed(BOB),
+			0,
+			DAI,
+			HDX,
+			200 * ONE,
+			0
+		));
+
+		let pool = ConcentratedLiquidity::pools(0).unwrap();
+		assert!(pool.tick >= 0 && pool.tick < 600);
+		assert_eq!(pool.liquidity, inner + outer);
+	});
+}
+
+#[test]
+fn buy_should_cross_initialized_tick_and_update_active_liquidity() {
+	ext_with_liquidity().execute_with(|| {
+		let inner = ConcentratedLiquidity::positions(0).unwrap().liquidity;
+		let outer_position = add_position(ALICE, 0, -1_200, 1_200, 100 * ONE, 100 * ONE);
+		let outer = ConcentratedLiquidity::positions(outer_position).unwrap().liquidity;
+
+		// buy more HDX than is available in the range [0, 600] with both positions active
+		let amount_to_tick = calculate_amount_0_delta(sqrt_price(0), sqrt_price(600), inner + outer, false).unwrap();
+
+		assert_ok!(ConcentratedLiquidity::buy(
+			RuntimeOrigin::signed(BOB),
+			0,
+			HDX,
+			DAI,
+			amount_to_tick + ONE,
+			1_000 * ONE
+		));
+
+		let pool = ConcentratedLiquidity::pools(0).unwrap();
+		assert!(pool.tick >= 600 && pool.tick < 1_200);
+		assert_eq!(pool.liquidity, outer);
+		assert_balance!(BOB, HDX, 1_000 * ONE + amount_to_tick + ONE);
+	});
+}
+
+#[test]
+fn trade_should_fail_when_too_many_ticks_are_crossed() {
+	let mut ext = ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, HDX, 1_000 * ONE),
+			(ALICE, DAI, 1_000 * ONE),
+			(BOB, HDX, 1_000 * ONE),
+		])
+		.with_pool(HDX, DAI, FEE, FixedU128::one())
+		.with_max_tick_crossings(1)
+		.build();
+	ext.execute_with(|| {
+		add_position(ALICE, 0, -600, 600, 100 * ONE, 100 * ONE);
+		add_position(ALICE, 0, -1_200, 1_200, 100 * ONE, 100 * ONE);
+		add_position(ALICE, 0, -1_800, 1_800, 100 * ONE, 100 * ONE);
+
+		let liquidity = ConcentratedLiquidity::pools(0).unwrap().liquidity;
+		let amount_to_tick = calculate_amount_0_delta(sqrt_price(-600), sqrt_price(0), liquidity, true).unwrap();
+
+		// crossing tick -600 and -1200
+		assert_noop!(
+			ConcentratedLiquidity::sell(RuntimeOrigin::signed(BOB), 0, HDX, DAI, 3 * amount_to_tick, 0),
+			Error::<Test>::TooManyTickCrossings
+		);
+
+		// crossing tick -600 only
+		assert_ok!(ConcentratedLiquidity::sell(
+			RuntimeOrigin::signed(BOB),
+			0,
+			HDX,
+			DAI,
+			amount_to_tick * 11 / 10,
+			0
+		));
+		assert!(ConcentratedLiquidity::pools(0).unwrap().tick < -600);
+	});
+}
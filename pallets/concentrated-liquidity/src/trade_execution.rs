@@ -0,0 +1,147 @@
+use crate::{Balance, Config, Error, Pallet, Pools};
+use frame_support::traits::Get;
+use frame_support::weights::Weight;
+use hydra_dx_math::concentrated::calculate_price_from_sqrt_price;
+use hydradx_traits::router::{ExecutorError, PoolType, PoolsProvider, TradeExecution, TradeablePool};
+use orml_traits::MultiCurrency;
+use sp_runtime::traits::{CheckedDiv, Saturating};
+use sp_runtime::{ArithmeticError, DispatchError, FixedPointNumber, FixedU128, Permill};
+use sp_std::vec::Vec;
+
+impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balance> for Pallet<T> {
+	type Error = DispatchError;
+
+	fn calculate_sell(
+		pool_type: PoolType<T::AssetId>,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_in: Balance,
+	) -> Result<Balance, ExecutorError<Self::Error>> {
+		match pool_type {
+			PoolType::Concentrated(pool_id) => {
+				let (_, result) = Self::calculate_trade(pool_id, asset_in, asset_out, amount_in, true)
+					.map_err(ExecutorError::Error)?;
+
+				Ok(result.amount_out)
+			}
+			_ => Err(ExecutorError::NotSupported),
+		}
+	}
+
+	fn calculate_buy(
+		pool_type: PoolType<T::AssetId>,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_out: Balance,
+	) -> Result<Balance, ExecutorError<Self::Error>> {
+		match pool_type {
+			PoolType::Concentrated(pool_id) => {
+				let (_, result) = Self::calculate_trade(pool_id, asset_in, asset_out, amount_out, false)
+					.map_err(ExecutorError::Error)?;
+
+				result
+					.amount_in
+					.checked_add(result.fee_amount)
+					.ok_or_else(|| ExecutorError::Error(ArithmeticError::Overflow.into()))
+			}
+			_ => Err(ExecutorError::NotSupported),
+		}
+	}
+
+	fn execute_sell(
+		who: T::RuntimeOrigin,
+		pool_type: PoolType<T::AssetId>,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_in: Balance,
+		min_limit: Balance,
+	) -> Result<(), ExecutorError<Self::Error>> {
+		match pool_type {
+			PoolType::Concentrated(pool_id) => {
+				Self::sell(who, pool_id, asset_in, asset_out, amount_in, min_limit).map_err(ExecutorError::Error)
+			}
+			_ => Err(ExecutorError::NotSupported),
+		}
+	}
+
+	fn execute_buy(
+		who: T::RuntimeOrigin,
+		pool_type: PoolType<T::AssetId>,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_out: Balance,
+		max_limit: Balance,
+	) -> Result<(), ExecutorError<Self::Error>> {
+		match pool_type {
+			PoolType::Concentrated(pool_id) => {
+				Self::buy(who, pool_id, asset_out, asset_in, amount_out, max_limit).map_err(ExecutorError::Error)
+			}
+			_ => Err(ExecutorError::NotSupported),
+		}
+	}
+
+	fn get_liquidity_depth(
+		pool_type: PoolType<T::AssetId>,
+		asset_a: T::AssetId,
+		_asset_b: T::AssetId,
+	) -> Result<Balance, ExecutorError<Self::Error>> {
+		match pool_type {
+			PoolType::Concentrated(pool_id) => {
+				let pool_account = Self::pool_account(pool_id);
+				Ok(T::Currency::free_balance(asset_a, &pool_account))
+			}
+			_ => Err(ExecutorError::NotSupported),
+		}
+	}
+
+	fn calculate_spot_price_with_fee(
+		pool_type: PoolType<T::AssetId>,
+		asset_a: T::AssetId,
+		asset_b: T::AssetId,
+	) -> Result<FixedU128, ExecutorError<Self::Error>> {
+		match pool_type {
+			PoolType::Concentrated(pool_id) => {
+				let pool =
+					Pools::<T>::get(pool_id).ok_or_else(|| ExecutorError::Error(Error::<T>::PoolNotFound.into()))?;
+
+				if !pool.contains(asset_a) || !pool.contains(asset_b) || asset_a == asset_b {
+					return Err(ExecutorError::Error(Error::<T>::AssetNotInPool.into()));
+				}
+
+				// price of asset 0 denominated in asset 1
+				let (n, d) = calculate_price_from_sqrt_price(pool.sqrt_price);
+
+				// amount of asset a for one unit of asset b
+				let spot_price = if asset_a == pool.asset_1 {
+					FixedU128::checked_from_rational(n, d)
+				} else {
+					FixedU128::checked_from_rational(d, n)
+				}
+				.ok_or_else(|| ExecutorError::Error(ArithmeticError::Overflow.into()))?;
+
+				let spot_price_with_fee = spot_price
+					.checked_div(&FixedU128::from(Permill::from_percent(100).saturating_sub(pool.fee)))
+					.ok_or_else(|| ExecutorError::Error(ArithmeticError::DivisionByZero.into()))?;
+
+				Ok(spot_price_with_fee)
+			}
+			_ => Err(ExecutorError::NotSupported),
+		}
+	}
+}
+
+impl<T: Config> PoolsProvider<T::AssetId> for Pallet<T> {
+	fn get_pools(limit: u32) -> Vec<TradeablePool<T::AssetId>> {
+		Pools::<T>::iter()
+			.take(limit as usize)
+			.map(|(pool_id, pool)| TradeablePool {
+				pool: PoolType::Concentrated(pool_id),
+				assets: pool.assets(),
+			})
+			.collect()
+	}
+
+	fn get_pools_weight(limit: u32) -> Weight {
+		T::DbWeight::get().reads(limit.into())
+	}
+}
@@ -0,0 +1,111 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_core::{RuntimeDebug, U256};
+use sp_runtime::Permill;
+use sp_std::vec::Vec;
+
+pub use hydra_dx_math::concentrated::Liquidity;
+
+pub type Balance = u128;
+pub type PoolId = u32;
+pub type Tick = i32;
+
+/// Concentrated liquidity pool.
+///
+/// Assets are ordered so that `asset_0 < asset_1`. Price of the pool is the price of `asset_0` denominated in `asset_1`.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct PoolInfo<AssetId> {
+	pub asset_0: AssetId,
+	pub asset_1: AssetId,
+	/// Trade fee, paid in asset in and distributed to liquidity providers
+	pub fee: Permill,
+	/// Only ticks divisible by tick spacing can be used as position bounds
+	pub tick_spacing: u32,
+	/// Current sqrt price as Q64.96 fixed point number
+	pub sqrt_price: U256,
+	/// Current tick, the greatest tick for which sqrt price at tick <= `sqrt_price`
+	pub tick: Tick,
+	/// Liquidity active at current price
+	pub liquidity: Liquidity,
+	/// Fees of asset 0 earned per unit of liquidity over the whole life of the pool, as Q128.128
+	pub fee_growth_global_0: U256,
+	/// Fees of asset 1 earned per unit of liquidity over the whole life of the pool, as Q128.128
+	pub fee_growth_global_1: U256,
+}
+
+impl<AssetId: PartialEq + Copy> PoolInfo<AssetId> {
+	pub fn contains(&self, asset_id: AssetId) -> bool {
+		self.asset_0 == asset_id || self.asset_1 == asset_id
+	}
+
+	pub fn assets(&self) -> Vec<AssetId> {
+		sp_std::vec![self.asset_0, self.asset_1]
+	}
+}
+
+/// State of an initialized tick - a tick used as a bound of at least one position.
+#[derive(Clone, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct TickInfo {
+	/// Total liquidity of positions which use the tick as a bound
+	pub liquidity_gross: Liquidity,
+	/// Liquidity added to the active liquidity when the tick is crossed from left to right
+	pub liquidity_net: i128,
+	/// Fee growth of asset 0 on the other side of the tick relative to the current tick
+	pub fee_growth_outside_0: U256,
+	/// Fee growth of asset 1 on the other side of the tick relative to the current tick
+	pub fee_growth_outside_1: U256,
+}
+
+/// Liquidity position, represented as NFT instance.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct Position {
+	pub pool_id: PoolId,
+	pub tick_lower: Tick,
+	pub tick_upper: Tick,
+	pub liquidity: Liquidity,
+	/// Fee growth of asset 0 inside the position's range at the last update
+	pub fee_growth_inside_0_last: U256,
+	/// Fee growth of asset 1 inside the position's range at the last update
+	pub fee_growth_inside_1_last: U256,
+	/// Fees of asset 0 earned and not collected yet
+	pub fees_owed_0: Balance,
+	/// Fees of asset 1 earned and not collected yet
+	pub fees_owed_1: Balance,
+}
+
+/// Result of a swap calculation. No state is changed until the result is applied.
+#[derive(Clone, Eq, PartialEq, RuntimeDebug)]
+pub struct SwapResult {
+	/// Amount of asset in, excluding fee
+	pub amount_in: Balance,
+	pub amount_out: Balance,
+	pub fee_amount: Balance,
+	/// Pool state after the swap
+	pub sqrt_price: U256,
+	pub tick: Tick,
+	pub liquidity: Liquidity,
+	/// Global fee growth of asset in after the swap
+	pub fee_growth_global: U256,
+	/// Initialized ticks crossed by the swap with global fee growth of asset in at the moment of crossing
+	pub crossed_ticks: Vec<(Tick, U256)>,
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AssetId> {
+	fn register_asset(asset_id: AssetId, decimals: u8) -> sp_runtime::DispatchResult;
+}
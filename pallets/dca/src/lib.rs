@@ -55,6 +55,31 @@
 //!
 //! If a trade fails due to other types of errors, the order is terminated without any retry logic.
 //!
+//! ### Limit orders
+//!
+//! A schedule can specify a price trigger, which makes it a limit order.
+//! The price of the trigger is the amount of `asset_out` received for one `asset_in`, taken from the last block oracle.
+//!
+//! When a limit order is planned for a block, its trade is executed only if the oracle price has crossed the trigger price
+//! in the specified direction. Otherwise the schedule is planned for the next block to check the price again.
+//! Every check of the price is charged with a fee for the weight of the check, taken from the remaining budget.
+//! A limit order waiting long for its trigger price spends its budget on the checks, and it is terminated
+//! once the remaining budget cannot cover the fee.
+//!
+//! If the trigger has an expiry block and the condition has not been met until then, the schedule is removed
+//! and the remaining budget is unreserved.
+//!
+//! Once the condition is met, the trade is executed the same way as for normal schedules, including retries.
+//!
+//! ## Managing a Schedule
+//!
+//! The owner of a schedule can change it without losing its id and retry state:
+//!
+//! - `pause` removes the schedule from its planned block while keeping the remaining budget reserved.
+//! - `resume` plans a paused schedule again.
+//! - `top_up_budget` reserves additional amount and adds it to the remaining budget.
+//! - `update_schedule` changes the period, slippage and stability threshold of the schedule.
+//!
 //! ## Terminating a Schedule
 //!
 //! Both users and technical origin can terminate a DCA schedule. However, users can only terminate schedules that they own.
@@ -89,7 +114,7 @@ use rand::{Rng, SeedableRng};
 use sp_runtime::helpers_128bit::multiply_by_rational_with_rounding;
 use sp_runtime::traits::CheckedMul;
 use sp_runtime::{
-	traits::{BlockNumberProvider, Saturating},
+	traits::{BlockNumberProvider, Saturating, Zero},
 	ArithmeticError, BoundedVec, DispatchError, FixedPointNumber, FixedU128, Percent, Permill, Rounding,
 };
 use sp_std::vec::Vec;
@@ -98,6 +123,7 @@ use sp_std::{cmp::min, vec};
 #[cfg(test)]
 mod tests;
 
+pub mod migration;
 pub mod types;
 pub mod weights;
 
@@ -125,7 +151,10 @@ pub mod pallet {
 	use orml_traits::NamedMultiReservableCurrency;
 	use sp_runtime::Percent;
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
@@ -150,6 +179,44 @@ pub mod pallet {
 				};
 
 				let weight_for_single_execution = Self::get_trade_weight(&schedule.order);
+
+				if let Some(trigger) = &schedule.trigger {
+					if trigger.is_expired(&current_blocknumber) {
+						weight.saturating_accrue(weight_for_single_execution);
+						Self::expire_schedule(schedule_id, &schedule);
+						continue;
+					}
+
+					match Self::get_trigger_price(&schedule.order) {
+						Ok(price) if trigger.is_reached(price) => {
+							Self::deposit_event(Event::TriggerPriceReached {
+								id: schedule_id,
+								who: schedule.owner.clone(),
+								price,
+							});
+						}
+						_ => {
+							let weight_for_trigger_check = Self::get_trigger_check_weight(&schedule.order);
+							weight.saturating_accrue(weight_for_trigger_check);
+
+							let next_block = current_blocknumber.saturating_add(1u32.into());
+							if let Err(e) =
+								Self::take_transaction_fee_from_user(schedule_id, &schedule, weight_for_trigger_check)
+									.and_then(|_| {
+										Self::plan_schedule_for_block(
+											&schedule.owner,
+											next_block,
+											schedule_id,
+											&mut randomness_generator,
+										)
+									}) {
+								Self::terminate_schedule(schedule_id, &schedule, e);
+							}
+							continue;
+						}
+					}
+				}
+
 				weight.saturating_accrue(weight_for_single_execution);
 
 				if let Err(e) = Self::prepare_schedule(
@@ -351,6 +418,33 @@ pub mod pallet {
 		},
 		///The DCA is completed and completely removed from the chain
 		Completed { id: ScheduleId, who: T::AccountId },
+		///The price trigger of the DCA is reached, so the trade is executed
+		TriggerPriceReached {
+			id: ScheduleId,
+			who: T::AccountId,
+			price: FixedU128,
+		},
+		///The price trigger of the DCA has expired without being reached, the DCA is completely removed from the chain
+		Expired { id: ScheduleId, who: T::AccountId },
+		///The DCA is paused and will not be executed until resumed
+		Paused { id: ScheduleId, who: T::AccountId },
+		///The paused DCA is resumed and planned for execution again
+		Resumed { id: ScheduleId, who: T::AccountId },
+		///The budget of the DCA is increased
+		BudgetToppedUp {
+			id: ScheduleId,
+			who: T::AccountId,
+			amount: Balance,
+			remaining_amount: Balance,
+		},
+		///The parameters of the DCA are updated
+		ScheduleUpdated {
+			id: ScheduleId,
+			who: T::AccountId,
+			period: BlockNumberFor<T>,
+			slippage: Option<Permill>,
+			stability_threshold: Option<Permill>,
+		},
 		///Randomness generation failed possibly coming from missing data about relay chain
 		RandomnessGenerationFailed {
 			block: BlockNumberFor<T>,
@@ -396,6 +490,14 @@ pub mod pallet {
 		PeriodTooShort,
 		///Stability threshold cannot be higher than `MaxConfigurablePriceDifferenceBetweenBlock`
 		StabilityThresholdTooHigh,
+		///The price of the trigger cannot be zero
+		InvalidTriggerPrice,
+		///The schedule is already paused
+		ScheduleAlreadyPaused,
+		///The schedule is not paused
+		ScheduleNotPaused,
+		///The amount cannot be zero
+		InvalidAmount,
 	}
 
 	/// Id sequencer for schedules
@@ -431,6 +533,11 @@ pub mod pallet {
 	pub type ScheduleIdsPerBlock<T: Config> =
 		StorageMap<_, Blake2_128Concat, BlockNumberFor<T>, BoundedVec<ScheduleId, T::MaxSchedulePerBlock>, ValueQuery>;
 
+	/// Keep tracking of the paused schedules, which are not planned for any block
+	#[pallet::storage]
+	#[pallet::getter(fn paused)]
+	pub type PausedSchedules<T: Config> = StorageMap<_, Blake2_128Concat, ScheduleId, (), OptionQuery>;
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Creates a new DCA (Dollar-Cost Averaging) schedule and plans the next execution
@@ -439,6 +546,11 @@ pub mod pallet {
 		/// If the block is not specified, the execution is planned for the next block.
 		/// If the given block is full, the execution will be planned in the subsequent block.
 		///
+		/// If the schedule has a price trigger, its trades are executed only when the oracle price crosses the trigger price.
+		/// Until then, the price is checked in every block. Each check is charged with a fee for its weight,
+		/// which is taken from the remaining budget. The schedule is terminated when the budget cannot cover the fee,
+		/// so `total_amount` of a limit order should account for the checks until the trigger price is reached.
+		///
 		/// Once the schedule is created, the specified `total_amount` will be reserved for DCA.
 		/// The reservation currency will be the `amount_in` currency of the order.
 		///
@@ -488,6 +600,15 @@ pub mod pallet {
 				},
 				Error::<T>::StabilityThresholdTooHigh
 			);
+			if let Some(trigger) = &schedule.trigger {
+				ensure!(!trigger.price.is_zero(), Error::<T>::InvalidTriggerPrice);
+				if let Some(expires_at) = trigger.expires_at {
+					ensure!(
+						expires_at > frame_system::Pallet::<T>::current_block_number(),
+						Error::<T>::BlockNumberIsNotInFuture
+					);
+				}
+			}
 
 			let transaction_fee = Self::get_transaction_fee(&schedule.order)?;
 
@@ -564,7 +685,7 @@ pub mod pallet {
 		/// Parameters:
 		/// - `origin`: schedule owner
 		/// - `schedule_id`: schedule id
-		/// - `next_execution_block`: block number where the schedule is planned.
+		/// - `next_execution_block`: block number where the schedule is planned. Not needed for paused schedules.
 		///
 		/// Emits `Terminated` event when successful.
 		///
@@ -585,33 +706,213 @@ pub mod pallet {
 
 			Self::try_unreserve_all(schedule_id, &schedule);
 
-			let next_execution_block = next_execution_block.ok_or(Error::<T>::ScheduleNotFound)?;
+			//Paused schedules are not planned for any block
+			if !PausedSchedules::<T>::contains_key(schedule_id) {
+				let next_execution_block = next_execution_block.ok_or(Error::<T>::ScheduleNotFound)?;
+				Self::remove_schedule_id_from_block(schedule_id, next_execution_block)?;
+			}
 
-			//Remove schedule id from next execution block
-			ScheduleIdsPerBlock::<T>::try_mutate_exists(
-				next_execution_block,
-				|maybe_schedule_ids| -> DispatchResult {
-					let schedule_ids = maybe_schedule_ids.as_mut().ok_or(Error::<T>::ScheduleNotFound)?;
+			Self::remove_schedule_from_storages(&schedule.owner, schedule_id);
 
-					let index = schedule_ids
-						.binary_search(&schedule_id)
-						.map_err(|_| Error::<T>::ScheduleNotFound)?;
+			Self::deposit_event(Event::Terminated {
+				id: schedule_id,
+				who: schedule.owner,
+				error: Error::<T>::ManuallyTerminated.into(),
+			});
 
-					schedule_ids.remove(index);
+			Ok(())
+		}
 
-					if schedule_ids.is_empty() {
-						*maybe_schedule_ids = None;
-					}
-					Ok(())
-				},
+		/// Pauses a DCA schedule by removing it from the block where it is planned.
+		///
+		/// The remaining budget stays reserved and the retry counter is kept, so the schedule
+		/// can be resumed later with the same id.
+		///
+		/// Parameters:
+		/// - `origin`: schedule owner
+		/// - `schedule_id`: schedule id
+		/// - `next_execution_block`: block number where the schedule is planned.
+		///
+		/// Emits `Paused` event when successful.
+		///
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::pause())]
+		#[transactional]
+		pub fn pause(
+			origin: OriginFor<T>,
+			schedule_id: ScheduleId,
+			next_execution_block: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let schedule = Schedules::<T>::get(schedule_id).ok_or(Error::<T>::ScheduleNotFound)?;
+			ensure!(who == schedule.owner, Error::<T>::Forbidden);
+			ensure!(
+				!PausedSchedules::<T>::contains_key(schedule_id),
+				Error::<T>::ScheduleAlreadyPaused
+			);
+
+			Self::remove_schedule_id_from_block(schedule_id, next_execution_block)?;
+
+			PausedSchedules::<T>::insert(schedule_id, ());
+
+			Self::deposit_event(Event::Paused { id: schedule_id, who });
+
+			Ok(())
+		}
+
+		/// Resumes a paused DCA schedule and plans the next execution for the specified block.
+		///
+		/// If the block is not specified, the execution is planned for the next block.
+		/// If the given block is full, the execution will be planned in the subsequent block.
+		///
+		/// Parameters:
+		/// - `origin`: schedule owner
+		/// - `schedule_id`: schedule id
+		/// - `start_execution_block`: first possible execution block for the schedule
+		///
+		/// Emits `Resumed` and `ExecutionPlanned` event when successful.
+		///
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::resume())]
+		#[transactional]
+		pub fn resume(
+			origin: OriginFor<T>,
+			schedule_id: ScheduleId,
+			start_execution_block: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let schedule = Schedules::<T>::get(schedule_id).ok_or(Error::<T>::ScheduleNotFound)?;
+			ensure!(who == schedule.owner, Error::<T>::Forbidden);
+			ensure!(
+				PausedSchedules::<T>::take(schedule_id).is_some(),
+				Error::<T>::ScheduleNotPaused
+			);
+
+			let blocknumber_for_next_execution = Self::get_first_execution_block(start_execution_block)?;
+
+			let mut randomness_generator =
+				Self::get_randomness_generator(frame_system::Pallet::<T>::current_block_number(), Some(schedule_id));
+			Self::plan_schedule_for_block(
+				&who,
+				blocknumber_for_next_execution,
+				schedule_id,
+				&mut randomness_generator,
 			)?;
 
-			Self::remove_schedule_from_storages(&schedule.owner, schedule_id);
+			Self::deposit_event(Event::Resumed { id: schedule_id, who });
 
-			Self::deposit_event(Event::Terminated {
+			Ok(())
+		}
+
+		/// Increases the budget of a DCA schedule.
+		///
+		/// The specified `amount` is reserved in the `amount_in` currency of the order
+		/// and added to the remaining amount of the schedule.
+		///
+		/// Parameters:
+		/// - `origin`: schedule owner
+		/// - `schedule_id`: schedule id
+		/// - `amount`: amount to add to the budget
+		///
+		/// Emits `BudgetToppedUp` event when successful.
+		///
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::top_up_budget())]
+		#[transactional]
+		pub fn top_up_budget(origin: OriginFor<T>, schedule_id: ScheduleId, amount: Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!amount.is_zero(), Error::<T>::InvalidAmount);
+
+			let remaining_amount =
+				Schedules::<T>::try_mutate(schedule_id, |maybe_schedule| -> Result<Balance, DispatchError> {
+					let schedule = maybe_schedule.as_mut().ok_or(Error::<T>::ScheduleNotFound)?;
+					ensure!(who == schedule.owner, Error::<T>::Forbidden);
+
+					schedule.total_amount = schedule
+						.total_amount
+						.checked_add(amount)
+						.ok_or(ArithmeticError::Overflow)?;
+
+					T::Currencies::reserve_named(
+						&T::NamedReserveId::get(),
+						schedule.order.get_asset_in(),
+						&who,
+						amount,
+					)?;
+
+					RemainingAmounts::<T>::try_mutate(
+						schedule_id,
+						|maybe_remaining| -> Result<Balance, DispatchError> {
+							let remaining = maybe_remaining.as_mut().ok_or(Error::<T>::InvalidState)?;
+							*remaining = remaining.checked_add(amount).ok_or(ArithmeticError::Overflow)?;
+							Ok(*remaining)
+						},
+					)
+				})?;
+
+			Self::deposit_event(Event::BudgetToppedUp {
 				id: schedule_id,
-				who: schedule.owner,
-				error: Error::<T>::ManuallyTerminated.into(),
+				who,
+				amount,
+				remaining_amount,
+			});
+
+			Ok(())
+		}
+
+		/// Updates the period, slippage and stability threshold of a DCA schedule.
+		///
+		/// The schedule keeps its id, remaining budget and retry counter.
+		/// The new period is applied when the schedule is planned next time.
+		///
+		/// Parameters:
+		/// - `origin`: schedule owner
+		/// - `schedule_id`: schedule id
+		/// - `period`: new period between executions
+		/// - `slippage`: new slippage, the default from the pallet configuration is used if not specified
+		/// - `stability_threshold`: new stability threshold, the default from the pallet configuration is used if not specified
+		///
+		/// Emits `ScheduleUpdated` event when successful.
+		///
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_schedule())]
+		#[transactional]
+		pub fn update_schedule(
+			origin: OriginFor<T>,
+			schedule_id: ScheduleId,
+			period: BlockNumberFor<T>,
+			slippage: Option<Permill>,
+			stability_threshold: Option<Permill>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				period >= BlockNumberFor::<T>::from(T::MinimalPeriod::get()),
+				Error::<T>::PeriodTooShort
+			);
+			ensure!(
+				match stability_threshold {
+					Some(threshold) => threshold <= T::MaxConfigurablePriceDifferenceBetweenBlocks::get(),
+					None => true,
+				},
+				Error::<T>::StabilityThresholdTooHigh
+			);
+
+			Schedules::<T>::try_mutate(schedule_id, |maybe_schedule| -> DispatchResult {
+				let schedule = maybe_schedule.as_mut().ok_or(Error::<T>::ScheduleNotFound)?;
+				ensure!(who == schedule.owner, Error::<T>::Forbidden);
+
+				schedule.period = period;
+				schedule.slippage = slippage;
+				schedule.stability_threshold = stability_threshold;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::ScheduleUpdated {
+				id: schedule_id,
+				who,
+				period,
+				slippage,
+				stability_threshold,
 			});
 
 			Ok(())
@@ -909,6 +1210,10 @@ impl<T: Config> Pallet<T> {
 		Self::convert_weight_to_fee(Self::get_trade_weight(order), order.get_asset_in())
 	}
 
+	pub fn get_trigger_check_fee(order: &Order<T::AssetId>) -> Result<Balance, DispatchError> {
+		Self::convert_weight_to_fee(Self::get_trigger_check_weight(order), order.get_asset_in())
+	}
+
 	fn unallocate_amount(
 		schedule_id: ScheduleId,
 		schedule: &Schedule<T::AccountId, T::AssetId, BlockNumberFor<T>>,
@@ -962,7 +1267,11 @@ impl<T: Config> Pallet<T> {
 		} else {
 			//We buy DOT with insufficient asset, for the treasury
 			//The DOT we need to buy is calculated the same way how we convert weight to insufficient fee
-			let pool_trade_fee = T::SwappablePaymentAssetSupport::calculate_fee_amount(fee_amount_in_sold_asset)?;
+			let pool_trade_fee = T::SwappablePaymentAssetSupport::calculate_fee_amount(
+				fee_currency,
+				T::PolkadotNativeAssetId::get(),
+				fee_amount_in_sold_asset,
+			)?;
 
 			//Since there is a trade fee involved in xyk buy swap, we need to unallocate that, together with amount_in
 			let effective_amount_in = fee_amount_in_sold_asset
@@ -1000,6 +1309,17 @@ impl<T: Config> Pallet<T> {
 		});
 	}
 
+	fn expire_schedule(schedule_id: ScheduleId, schedule: &Schedule<T::AccountId, T::AssetId, BlockNumberFor<T>>) {
+		Self::try_unreserve_all(schedule_id, schedule);
+
+		Self::remove_schedule_from_storages(&schedule.owner, schedule_id);
+
+		Self::deposit_event(Event::Expired {
+			id: schedule_id,
+			who: schedule.owner.clone(),
+		});
+	}
+
 	fn complete_schedule(schedule_id: ScheduleId, schedule: &Schedule<T::AccountId, T::AssetId, BlockNumberFor<T>>) {
 		Self::try_unreserve_all(schedule_id, schedule);
 
@@ -1133,6 +1453,24 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Weight of checking the trigger price of a limit order which is not reached and planning it for the next block.
+	fn get_trigger_check_weight(order: &Order<T::AssetId>) -> Weight {
+		let route = order.get_route_or_default::<T::RouteProvider>();
+		// Schedule, route, remaining amount, fee transfer accounts and planned block, oracle entries of each trade
+		let oracle_reads = 2u64.saturating_mul(route.len() as u64);
+		let check_weight = T::DbWeight::get().reads_writes(6u64.saturating_add(oracle_reads), 5);
+
+		if T::SwappablePaymentAssetSupport::is_transaction_fee_currency(order.get_asset_in()) {
+			check_weight
+		} else {
+			// Fee is paid by buying polkadot native asset with the insufficient asset
+			let fee_purchase_weight =
+				<T as Config>::WeightInfo::on_initialize_with_sell_trade_with_insufficient_fee_asset()
+					.saturating_sub(<T as Config>::WeightInfo::on_initialize_with_sell_trade());
+			check_weight.saturating_add(fee_purchase_weight)
+		}
+	}
+
 	fn convert_native_amount_to_currency(
 		asset_id: T::AssetId,
 		native_asset_amount: Balance,
@@ -1179,6 +1517,13 @@ impl<T: Config> Pallet<T> {
 		Ok(price_from_rational)
 	}
 
+	// The amount of asset out received for one asset in, so the price of the inverse route is used
+	fn get_trigger_price(order: &Order<T::AssetId>) -> Result<FixedU128, DispatchError> {
+		let route = order.get_route_or_default::<T::RouteProvider>();
+
+		Self::get_price_from_last_block_oracle(&inverse_route(route))
+	}
+
 	fn get_price_from_short_oracle(route: &[Trade<T::AssetId>]) -> Result<FixedU128, DispatchError> {
 		let price =
 			T::OraclePriceProvider::price(route, OraclePeriod::Short).ok_or(Error::<T>::CalculatingPriceError)?;
@@ -1189,11 +1534,29 @@ impl<T: Config> Pallet<T> {
 		Ok(price_from_rational)
 	}
 
+	fn remove_schedule_id_from_block(schedule_id: ScheduleId, blocknumber: BlockNumberFor<T>) -> DispatchResult {
+		ScheduleIdsPerBlock::<T>::try_mutate_exists(blocknumber, |maybe_schedule_ids| -> DispatchResult {
+			let schedule_ids = maybe_schedule_ids.as_mut().ok_or(Error::<T>::ScheduleNotFound)?;
+
+			let index = schedule_ids
+				.binary_search(&schedule_id)
+				.map_err(|_| Error::<T>::ScheduleNotFound)?;
+
+			schedule_ids.remove(index);
+
+			if schedule_ids.is_empty() {
+				*maybe_schedule_ids = None;
+			}
+			Ok(())
+		})
+	}
+
 	fn remove_schedule_from_storages(owner: &T::AccountId, schedule_id: ScheduleId) {
 		Schedules::<T>::remove(schedule_id);
 		ScheduleOwnership::<T>::remove(owner, schedule_id);
 		RemainingAmounts::<T>::remove(schedule_id);
 		RetriesOnError::<T>::remove(schedule_id);
+		PausedSchedules::<T>::remove(schedule_id);
 	}
 }
 
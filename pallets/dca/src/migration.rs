@@ -0,0 +1,86 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use frame_support::{
+	traits::{Get, StorageVersion},
+	weights::Weight,
+};
+
+pub mod v0 {
+	use super::*;
+	use codec::{Decode, Encode};
+
+	/// Schedule without the price trigger
+	#[derive(Encode, Decode, Debug, Eq, PartialEq, Clone)]
+	pub struct Schedule<AccountId, AssetId, BlockNumber> {
+		pub owner: AccountId,
+		pub period: BlockNumber,
+		pub total_amount: Balance,
+		pub max_retries: Option<u8>,
+		pub stability_threshold: Option<Permill>,
+		pub slippage: Option<Permill>,
+		pub order: Order<AssetId>,
+	}
+}
+
+/// Migrate the pallet storage to v1. This migration adds an empty price trigger to all the existing schedules.
+pub fn migrate_to_v1<T: Config>() -> Weight {
+	let on_chain_storage_version = StorageVersion::get::<Pallet<T>>();
+	//offset for storage version read
+	let mut weight: Weight = T::DbWeight::get().reads(1);
+
+	log::info!(
+		target: "runtime::dca",
+		"Running migration storage v1 for dca with storage version {:?}",
+		on_chain_storage_version,
+	);
+
+	if on_chain_storage_version < 1 {
+		let mut translated: u64 = 0;
+		Schedules::<T>::translate::<v0::Schedule<T::AccountId, T::AssetId, BlockNumberFor<T>>, _>(|_, schedule| {
+			translated.saturating_inc();
+			Some(Schedule {
+				owner: schedule.owner,
+				period: schedule.period,
+				total_amount: schedule.total_amount,
+				max_retries: schedule.max_retries,
+				stability_threshold: schedule.stability_threshold,
+				slippage: schedule.slippage,
+				order: schedule.order,
+				trigger: None,
+			})
+		});
+		weight.saturating_accrue(T::DbWeight::get().reads_writes(translated, translated));
+
+		StorageVersion::new(1).put::<Pallet<T>>();
+		//add storage version update weight
+		weight.saturating_accrue(T::DbWeight::get().writes(1));
+
+		log::info!(
+			target: "runtime::dca",
+			"Running migration storage v1 for dca was complete, {:?} schedules migrated",
+			translated,
+		);
+	} else {
+		log::warn!(
+			target: "runtime::dca",
+			"Attempted to apply migration to v1 but failed because storage version is {:?}",
+			on_chain_storage_version,
+		);
+	}
+
+	weight
+}
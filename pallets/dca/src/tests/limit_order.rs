@@ -0,0 +1,292 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::on_initialize::{proceed_to_blocknumber, set_to_blocknumber};
+use crate::tests::schedule::set_block_number;
+use crate::tests::*;
+use crate::{
+	assert_balance, assert_number_of_executed_sell_trades, assert_scheduled_ids,
+	assert_that_schedule_has_been_removed_from_storages, Error, Event as DcaEvent, Order, PriceTrigger,
+	TriggerDirection,
+};
+use frame_support::{assert_noop, assert_ok};
+use hydradx_traits::router::PoolType;
+use orml_traits::MultiCurrency;
+use orml_traits::NamedMultiReservableCurrency;
+use pretty_assertions::assert_eq;
+use sp_runtime::FixedU128;
+use std::borrow::Borrow;
+
+fn sell_order() -> Order<AssetId> {
+	Order::Sell {
+		asset_in: HDX,
+		asset_out: BTC,
+		amount_in: ONE,
+		min_amount_out: Balance::MIN,
+		route: create_bounded_vec(vec![Trade {
+			pool: PoolType::Omnipool,
+			asset_in: HDX,
+			asset_out: BTC,
+		}]),
+	}
+}
+
+#[test]
+fn limit_order_should_be_executed_when_price_is_above_trigger_price() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(5 * ONE)
+				.with_order(sell_order())
+				.with_trigger(PriceTrigger {
+					price: FixedU128::from_rational(80, 100),
+					direction: TriggerDirection::Above,
+					expires_at: None,
+				})
+				.build();
+
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, None));
+
+			//Act
+			set_to_blocknumber(502);
+
+			//Assert
+			let schedule_id = 0;
+			assert_number_of_executed_sell_trades!(1);
+			assert!(System::events().iter().any(|record| record.event
+				== DcaEvent::TriggerPriceReached {
+					id: schedule_id,
+					who: ALICE,
+					price: FixedU128::from_rational(88, 100),
+				}
+				.into()));
+			expect_events(vec![
+				DcaEvent::TradeExecuted {
+					id: schedule_id,
+					who: ALICE,
+					amount_in: ONE,
+					amount_out: *AMOUNT_OUT_FOR_OMNIPOOL_SELL,
+				}
+				.into(),
+				DcaEvent::ExecutionPlanned {
+					id: schedule_id,
+					who: ALICE,
+					block: 602,
+				}
+				.into(),
+			]);
+		});
+}
+
+#[test]
+fn limit_order_should_be_executed_when_price_is_below_trigger_price() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(5 * ONE)
+				.with_order(sell_order())
+				.with_trigger(PriceTrigger {
+					price: FixedU128::from_rational(90, 100),
+					direction: TriggerDirection::Below,
+					expires_at: None,
+				})
+				.build();
+
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, None));
+
+			//Act
+			set_to_blocknumber(502);
+
+			//Assert
+			assert_number_of_executed_sell_trades!(1);
+			assert_scheduled_ids!(602, vec![0]);
+		});
+}
+
+#[test]
+fn limit_order_should_be_replanned_for_next_block_when_trigger_price_is_not_reached() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let total_amount = 5 * ONE;
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(total_amount)
+				.with_order(sell_order())
+				.with_trigger(PriceTrigger {
+					price: FixedU128::from_rational(90, 100),
+					direction: TriggerDirection::Above,
+					expires_at: None,
+				})
+				.build();
+
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, None));
+
+			//Act
+			set_to_blocknumber(502);
+
+			//Assert
+			let schedule_id = 0;
+			assert_number_of_executed_sell_trades!(0);
+			assert_scheduled_ids!(503, vec![schedule_id]);
+			expect_events(vec![DcaEvent::ExecutionPlanned {
+				id: schedule_id,
+				who: ALICE,
+				block: 503,
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn limit_order_should_be_charged_with_trigger_check_fee_when_trigger_price_is_not_reached() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let total_amount = 5 * ONE;
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(total_amount)
+				.with_order(sell_order())
+				.with_trigger(PriceTrigger {
+					price: FixedU128::from_rational(90, 100),
+					direction: TriggerDirection::Above,
+					expires_at: None,
+				})
+				.build();
+
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule.clone(), None));
+
+			//Act
+			set_to_blocknumber(502);
+
+			//Assert
+			let schedule_id = 0;
+			let fee = DCA::get_trigger_check_fee(&schedule.order).unwrap();
+			assert!(fee < DCA::get_transaction_fee(&schedule.order).unwrap());
+			assert_balance!(TreasuryAccount::get(), HDX, fee);
+			assert_eq!(DCA::remaining_amounts(schedule_id).unwrap(), total_amount - fee);
+			assert_eq!(
+				Currencies::reserved_balance_named(&NamedReserveId::get(), HDX, &ALICE),
+				total_amount - fee
+			);
+		});
+}
+
+#[test]
+fn limit_order_should_expire_when_expiry_block_is_passed() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(5 * ONE)
+				.with_order(sell_order())
+				.with_trigger(PriceTrigger {
+					price: FixedU128::from_rational(90, 100),
+					direction: TriggerDirection::Above,
+					expires_at: Some(503),
+				})
+				.build();
+
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, None));
+
+			//Act
+			proceed_to_blocknumber(502, 603);
+
+			//Assert
+			let schedule_id = 0;
+			assert_number_of_executed_sell_trades!(0);
+			assert_that_schedule_has_been_removed_from_storages!(ALICE, schedule_id);
+			assert_eq!(
+				Currencies::reserved_balance_named(&NamedReserveId::get(), HDX, &ALICE),
+				0
+			);
+			expect_events(vec![DcaEvent::Expired {
+				id: schedule_id,
+				who: ALICE,
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn schedule_should_fail_when_trigger_price_is_zero() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			let schedule = ScheduleBuilder::new()
+				.with_trigger(PriceTrigger {
+					price: FixedU128::from(0),
+					direction: TriggerDirection::Above,
+					expires_at: None,
+				})
+				.build();
+
+			//Act and assert
+			assert_noop!(
+				DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, None),
+				Error::<Test>::InvalidTriggerPrice
+			);
+		});
+}
+
+#[test]
+fn schedule_should_fail_when_trigger_expiry_is_not_in_future() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+
+			let schedule = ScheduleBuilder::new()
+				.with_trigger(PriceTrigger {
+					price: FixedU128::from_rational(90, 100),
+					direction: TriggerDirection::Above,
+					expires_at: Some(500),
+				})
+				.build();
+
+			//Act and assert
+			assert_noop!(
+				DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, None),
+				Error::<Test>::BlockNumberIsNotInFuture
+			);
+		});
+}
@@ -147,6 +147,7 @@ impl pallet_ema_oracle::Config for Test {
 	type SupportedPeriods = SupportedPeriods;
 	type OracleWhitelist = Everything;
 	type MaxUniqueEntries = ConstU32<20>;
+	type MaxTwapCheckpoints = ConstU32<10>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 	type WeightInfo = ();
@@ -373,6 +374,9 @@ impl pallet_route_executor::Config for Test {
 	type EdToRefundCalculator = MockedEdCalculator;
 	type OraclePriceProvider = PriceProviderMock;
 	type OraclePeriod = RouteValidationOraclePeriod;
+	type PoolsProvider = ();
+	type MaxRouteDiscoveryCalculations = ConstU32<100>;
+	type MaxRouteDiscoveryPools = ConstU32<100>;
 }
 
 pub struct MockedEdCalculator;
@@ -727,7 +731,11 @@ impl SwappablePaymentAssetTrader<AccountId, AssetId, Balance> for MockedInsuffic
 		unimplemented!()
 	}
 
-	fn calculate_fee_amount(_swap_amount: Balance) -> Result<Balance, DispatchError> {
+	fn calculate_fee_amount(
+		_asset_in: AssetId,
+		_asset_out: AssetId,
+		_swap_amount: Balance,
+	) -> Result<Balance, DispatchError> {
 		unimplemented!()
 	}
 
@@ -1,14 +1,18 @@
 use crate::tests::mock::*;
-use crate::{Balance, Order, Schedule, ScheduleId};
+use crate::{Balance, Order, PriceTrigger, Schedule, ScheduleId};
 use hydradx_traits::router::PoolType;
 use hydradx_traits::router::Trade;
 use sp_runtime::traits::ConstU32;
 use sp_runtime::{BoundedVec, Permill};
 
+pub mod limit_order;
 pub mod mock;
 pub mod on_initialize;
+pub mod pause;
 pub mod schedule;
 pub mod terminate;
+pub mod top_up_budget;
+pub mod update_schedule;
 
 #[macro_export]
 macro_rules! assert_balance {
@@ -25,6 +29,7 @@ struct ScheduleBuilder {
 	pub max_retries: Option<Option<u8>>,
 	pub slippage: Option<Option<Permill>>,
 	pub stability_threshold: Option<Option<Permill>>,
+	pub trigger: Option<Option<PriceTrigger<BlockNumber>>>,
 }
 
 impl ScheduleBuilder {
@@ -36,6 +41,7 @@ impl ScheduleBuilder {
 			slippage: Some(None),
 			total_amount: Some(1000 * ONE),
 			max_retries: Some(None),
+			trigger: Some(None),
 			order: Some(Order::Buy {
 				asset_in: HDX,
 				asset_out: BTC,
@@ -85,6 +91,11 @@ impl ScheduleBuilder {
 		self
 	}
 
+	fn with_trigger(mut self, trigger: PriceTrigger<BlockNumber>) -> ScheduleBuilder {
+		self.trigger = Some(Some(trigger));
+		self
+	}
+
 	fn build(self) -> Schedule<AccountId, AssetId, BlockNumber> {
 		Schedule {
 			owner: self.owner.unwrap(),
@@ -94,6 +105,7 @@ impl ScheduleBuilder {
 			total_amount: self.total_amount.unwrap(),
 			max_retries: self.max_retries.unwrap(),
 			order: self.order.unwrap(),
+			trigger: self.trigger.unwrap(),
 		}
 	}
 }
@@ -124,5 +136,6 @@ macro_rules! assert_that_schedule_has_been_removed_from_storages {
 		assert!(DCA::owner_of($owner, $schedule_id).is_none());
 		assert!(DCA::remaining_amounts($schedule_id).is_none());
 		assert_eq!(DCA::retries_on_error($schedule_id), 0);
+		assert!(DCA::paused($schedule_id).is_none());
 	};
 }
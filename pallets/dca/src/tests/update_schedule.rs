@@ -0,0 +1,127 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::assert_scheduled_ids;
+use crate::tests::terminate::set_block_number;
+use crate::tests::*;
+use crate::{Error, Event};
+use frame_support::{assert_noop, assert_ok};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn update_schedule_should_change_parameters_and_keep_id() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			let total_amount = 100 * ONE;
+			let schedule = ScheduleBuilder::new().with_total_amount(total_amount).build();
+			let schedule_id = 0;
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::Some(600)));
+
+			//Act
+			assert_ok!(DCA::update_schedule(
+				RuntimeOrigin::signed(ALICE),
+				schedule_id,
+				200,
+				Some(Permill::from_percent(3)),
+				Some(Permill::from_percent(4))
+			));
+
+			//Assert
+			let schedule = DCA::schedules(schedule_id).unwrap();
+			assert_eq!(schedule.period, 200);
+			assert_eq!(schedule.slippage, Some(Permill::from_percent(3)));
+			assert_eq!(schedule.stability_threshold, Some(Permill::from_percent(4)));
+			assert_eq!(DCA::remaining_amounts(schedule_id), Some(total_amount));
+			assert_scheduled_ids!(600, vec![schedule_id]);
+
+			expect_events(vec![Event::ScheduleUpdated {
+				id: schedule_id,
+				who: ALICE,
+				period: 200,
+				slippage: Some(Permill::from_percent(3)),
+				stability_threshold: Some(Permill::from_percent(4)),
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn update_schedule_should_fail_when_period_is_too_short() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			let schedule = ScheduleBuilder::new().build();
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::Some(600)));
+
+			//Act and assert
+			assert_noop!(
+				DCA::update_schedule(RuntimeOrigin::signed(ALICE), 0, 1, None, None),
+				Error::<Test>::PeriodTooShort
+			);
+		});
+}
+
+#[test]
+fn update_schedule_should_fail_when_stability_threshold_is_too_high() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			let schedule = ScheduleBuilder::new().build();
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::Some(600)));
+
+			//Act and assert
+			assert_noop!(
+				DCA::update_schedule(
+					RuntimeOrigin::signed(ALICE),
+					0,
+					ONE_HUNDRED_BLOCKS,
+					None,
+					Some(Permill::from_percent(100))
+				),
+				Error::<Test>::StabilityThresholdTooHigh
+			);
+		});
+}
+
+#[test]
+fn update_schedule_should_fail_when_called_by_non_owner() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			let schedule = ScheduleBuilder::new().build();
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::Some(600)));
+
+			//Act and assert
+			assert_noop!(
+				DCA::update_schedule(RuntimeOrigin::signed(BOB), 0, ONE_HUNDRED_BLOCKS, None, None),
+				Error::<Test>::Forbidden
+			);
+		});
+}
@@ -2,7 +2,7 @@ use codec::{Decode, Encode, MaxEncodedLen};
 use hydradx_traits::router::{AssetPair, RouteProvider, Trade};
 use scale_info::TypeInfo;
 use sp_runtime::traits::ConstU32;
-use sp_runtime::{BoundedVec, Permill};
+use sp_runtime::{BoundedVec, FixedU128, Permill};
 use sp_std::vec::Vec;
 
 pub type Balance = u128;
@@ -32,6 +32,49 @@ pub struct Schedule<AccountId, AssetId, BlockNumber> {
 	pub slippage: Option<Permill>,
 	/// The order containing information to execute a specific trade by the router.
 	pub order: Order<AssetId>,
+	/// The price condition which has to be met to execute a trade.
+	/// If specified, the schedule is checked every `period` blocks until the condition is met or the trigger expires.
+	pub trigger: Option<PriceTrigger<BlockNumber>>,
+}
+
+/// Price condition of a limit order.
+///
+/// The price is the amount of `asset_out` received for one `asset_in` of the order, taken from the last block oracle.
+/// For example, a take-profit sell uses `Above`, a stop-loss sell uses `Below`.
+#[derive(Encode, Decode, Debug, Eq, PartialEq, Clone, TypeInfo, MaxEncodedLen)]
+pub struct PriceTrigger<BlockNumber> {
+	/// The price to be crossed to execute a trade.
+	pub price: FixedU128,
+	/// The direction in which the price has to cross `price`.
+	pub direction: TriggerDirection,
+	/// The block after which the schedule is removed if the condition has not been met.
+	pub expires_at: Option<BlockNumber>,
+}
+
+#[derive(Encode, Decode, Debug, Eq, PartialEq, Clone, Copy, TypeInfo, MaxEncodedLen)]
+pub enum TriggerDirection {
+	/// Executed when the price is higher than or equal to the trigger price
+	Above,
+	/// Executed when the price is lower than or equal to the trigger price
+	Below,
+}
+
+impl<BlockNumber> PriceTrigger<BlockNumber>
+where
+	BlockNumber: PartialOrd,
+{
+	pub fn is_reached(&self, price: FixedU128) -> bool {
+		match self.direction {
+			TriggerDirection::Above => price >= self.price,
+			TriggerDirection::Below => price <= self.price,
+		}
+	}
+
+	pub fn is_expired(&self, current_block: &BlockNumber) -> bool {
+		self.expires_at
+			.as_ref()
+			.map_or(false, |expires_at| current_block > expires_at)
+	}
 }
 
 #[derive(Encode, Decode, Debug, Eq, PartialEq, Clone, TypeInfo, MaxEncodedLen)]
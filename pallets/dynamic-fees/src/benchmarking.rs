@@ -0,0 +1,72 @@
+// This file is part of pallet-dynamic-fees.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::benchmarks;
+use frame_support::traits::EnsureOrigin;
+use sp_runtime::FixedU128;
+
+fn dynamic_config<T: Config>() -> AssetFeeConfig<T::Fee> {
+	AssetFeeConfig::Dynamic {
+		asset_fee_params: FeeParams {
+			min_fee: T::Fee::from_percent(1),
+			max_fee: T::Fee::from_percent(10),
+			decay: FixedU128::from_rational(1, 100_000),
+			amplification: FixedU128::from(2),
+		},
+		protocol_fee_params: FeeParams {
+			min_fee: T::Fee::from_percent(1),
+			max_fee: T::Fee::from_percent(2),
+			decay: FixedU128::from_rational(1, 100_000),
+			amplification: FixedU128::from(1),
+		},
+	}
+}
+
+benchmarks! {
+	 where_clause {
+		where T::AssetId: From<u32>,
+	}
+
+	set_asset_fee {
+		let origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+		let asset_id = T::AssetId::from(2u32);
+		let params = dynamic_config::<T>();
+	}: _<T::RuntimeOrigin>(origin, asset_id, params)
+	verify {
+		assert_eq!(AssetFeeConfiguration::<T>::get(asset_id), Some(params));
+	}
+
+	remove_asset_fee {
+		let origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+		let asset_id = T::AssetId::from(2u32);
+		let params = AssetFeeConfig::Fixed {
+			asset_fee: T::AssetFeeParameters::get().min_fee,
+			protocol_fee: T::ProtocolFeeParameters::get().min_fee,
+		};
+		crate::Pallet::<T>::set_asset_fee(origin.clone(), asset_id, params)?;
+	}: _<T::RuntimeOrigin>(origin, asset_id)
+	verify {
+		assert!(AssetFeeConfiguration::<T>::get(asset_id).is_none());
+		assert!(AssetFee::<T>::get(asset_id).is_none());
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::tests::mock::ExtBuilder::default().build(), crate::tests::mock::Test);
+}
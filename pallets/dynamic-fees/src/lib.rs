@@ -40,8 +40,17 @@
 //! The module stores last calculated fees as tuple of `(Fee, Fee, Block number)` where the first item is asset fee,
 //! the second one is protocol fee and the third one is block number indicating when the two fees were updated.
 //!
+//! Fee parameters can be overridden per asset by `AuthorityOrigin`. An asset can either use its own dynamic fee
+//! parameters or have its fees pinned to fixed values. Assets without an override use the global
+//! `AssetFeeParameters` and `ProtocolFeeParameters`.
+//!
 //! ## Interface
 //!
+//! ### Dispatchable functions
+//!
+//! * `set_asset_fee` - sets fee configuration of an asset.
+//! * `remove_asset_fee` - removes fee configuration of an asset, the global parameters are used afterwards.
+//!
 //! ### Update and retrieve fee
 //!
 //! The module provides implementation of GetByKey trait for `UpdateAndRetrieveFee` struct.
@@ -58,18 +67,23 @@
 use frame_support::traits::Get;
 use frame_system::pallet_prelude::BlockNumberFor;
 use orml_traits::GetByKey;
-use sp_runtime::traits::{BlockNumberProvider, Saturating};
+use sp_runtime::traits::{BlockNumberProvider, Saturating, Zero};
 use sp_runtime::{FixedPointOperand, PerThing, SaturatedConversion};
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod migration;
 #[cfg(test)]
 mod tests;
 pub mod traits;
 pub mod types;
+pub mod weights;
 
 pub use pallet::*;
 
 use crate::traits::{Volume, VolumeProvider};
-use crate::types::{FeeEntry, FeeParams};
+use crate::types::{AssetFeeConfig, FeeEntry, FeeParams};
+pub use crate::weights::WeightInfo;
 use hydra_dx_math::dynamic_fees::types::OracleEntry;
 use hydra_dx_math::dynamic_fees::{recalculate_asset_fee, recalculate_protocol_fee};
 
@@ -81,10 +95,13 @@ pub mod pallet {
 	use crate::traits::VolumeProvider;
 	use crate::types::FeeEntry;
 	use frame_support::pallet_prelude::*;
-	use frame_system::pallet_prelude::BlockNumberFor;
+	use frame_system::pallet_prelude::{BlockNumberFor, OriginFor};
 	use sp_runtime::traits::{BlockNumberProvider, Zero};
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::storage]
@@ -115,16 +132,123 @@ pub mod pallet {
 
 		#[pallet::constant]
 		type ProtocolFeeParameters: Get<FeeParams<Self::Fee>>;
+
+		/// Origin able to change fee configuration of an asset.
+		type AuthorityOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
 	}
 
 	#[pallet::event]
-	pub enum Event<T: Config> {}
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Fee configuration of an asset has been set.
+		AssetFeeConfigSet {
+			asset_id: T::AssetId,
+			params: AssetFeeConfig<T::Fee>,
+		},
+		/// Fee configuration of an asset has been removed.
+		AssetFeeConfigRemoved { asset_id: T::AssetId },
+	}
 
 	#[pallet::error]
-	pub enum Error<T> {}
+	#[cfg_attr(test, derive(PartialEq, Eq))]
+	pub enum Error<T> {
+		/// Minimum fee is greater than maximum fee or amplification is zero.
+		InvalidFeeParameters,
+		/// Fee configuration of an asset does not exist.
+		AssetFeeConfigNotFound,
+		/// Sum of fixed asset fee and protocol fee is not less than 100%.
+		FixedFeeOutOfBounds,
+	}
 
 	#[pallet::call]
-	impl<T: Config> Pallet<T> {}
+	impl<T: Config> Pallet<T> {
+		/// Set fee configuration of an asset.
+		///
+		/// `Fixed` configuration pins asset fee and protocol fee of the asset to given values.
+		/// Fixed fees may be outside of the global fee parameters, but their sum must be less than 100%.
+		/// `Dynamic` configuration overrides the global fee parameters used to recalculate the fees.
+		///
+		/// Fees of the asset are reset and take effect immediately.
+		///
+		/// Can be called only by `AuthorityOrigin`.
+		///
+		/// Parameters:
+		/// - `origin`: Authority origin.
+		/// - `asset_id`: Asset id.
+		/// - `params`: Fee configuration of the asset.
+		///
+		/// Emits `AssetFeeConfigSet` event when successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_asset_fee())]
+		pub fn set_asset_fee(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			params: AssetFeeConfig<T::Fee>,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			match params {
+				AssetFeeConfig::Fixed {
+					asset_fee,
+					protocol_fee,
+				} => {
+					ensure!(
+						asset_fee.saturating_add(protocol_fee) < T::Fee::one(),
+						Error::<T>::FixedFeeOutOfBounds
+					);
+				}
+				AssetFeeConfig::Dynamic {
+					asset_fee_params,
+					protocol_fee_params,
+				} => {
+					ensure!(
+						Self::is_valid_fee_params(&asset_fee_params) && Self::is_valid_fee_params(&protocol_fee_params),
+						Error::<T>::InvalidFeeParameters
+					);
+				}
+			}
+
+			AssetFeeConfiguration::<T>::insert(asset_id, params);
+
+			// Fees recalculated with previous parameters are no longer valid
+			Self::reset_fee(asset_id, &params);
+
+			Self::deposit_event(Event::AssetFeeConfigSet { asset_id, params });
+
+			Ok(())
+		}
+
+		/// Remove fee configuration of an asset.
+		///
+		/// Global fee parameters are used for the asset afterwards.
+		///
+		/// Can be called only by `AuthorityOrigin`.
+		///
+		/// Parameters:
+		/// - `origin`: Authority origin.
+		/// - `asset_id`: Asset id.
+		///
+		/// Emits `AssetFeeConfigRemoved` event when successful.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_asset_fee())]
+		pub fn remove_asset_fee(origin: OriginFor<T>, asset_id: T::AssetId) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			let params = AssetFeeConfiguration::<T>::take(asset_id).ok_or(Error::<T>::AssetFeeConfigNotFound)?;
+
+			if let AssetFeeConfig::Fixed { .. } = params {
+				// Dynamic fees start again from the minimum fees
+				AssetFee::<T>::remove(asset_id);
+			}
+
+			Self::deposit_event(Event::AssetFeeConfigRemoved { asset_id });
+
+			Ok(())
+		}
+	}
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
@@ -151,6 +275,34 @@ pub mod pallet {
 	}
 }
 
+impl<T: Config> Pallet<T> {
+	fn is_valid_fee_params(params: &FeeParams<T::Fee>) -> bool {
+		params.min_fee <= params.max_fee && !params.amplification.is_zero()
+	}
+
+	fn reset_fee(asset_id: T::AssetId, params: &AssetFeeConfig<T::Fee>) {
+		match params {
+			AssetFeeConfig::Fixed {
+				asset_fee,
+				protocol_fee,
+			} => {
+				AssetFee::<T>::insert(
+					asset_id,
+					FeeEntry {
+						asset_fee: *asset_fee,
+						protocol_fee: *protocol_fee,
+						timestamp: T::BlockNumberProvider::current_block_number(),
+						fixed: true,
+					},
+				);
+			}
+			AssetFeeConfig::Dynamic { .. } => {
+				AssetFee::<T>::remove(asset_id);
+			}
+		}
+	}
+}
+
 impl<T: Config> Pallet<T>
 where
 	<T::Fee as PerThing>::Inner: FixedPointOperand,
@@ -158,20 +310,47 @@ where
 	fn update_fee(asset_id: T::AssetId) -> (T::Fee, T::Fee) {
 		let block_number = T::BlockNumberProvider::current_block_number();
 
-		let asset_fee_params = T::AssetFeeParameters::get();
-		let protocol_fee_params = T::ProtocolFeeParameters::get();
+		let current_fee_entry = Self::current_fees(asset_id);
+
+		// Update only if it has not yet been updated this block and the fees are not fixed.
+		// Fee configuration is read only on recalculation.
+		if let Some(entry) = current_fee_entry {
+			if entry.fixed || block_number == entry.timestamp {
+				return (entry.asset_fee, entry.protocol_fee);
+			}
+		}
+
+		let (asset_fee_params, protocol_fee_params) = match Self::asset_fee_config(asset_id) {
+			Some(AssetFeeConfig::Fixed {
+				asset_fee,
+				protocol_fee,
+			}) => {
+				// Fixed fees are stored as fixed when set, so this is reached only if the entry is missing
+				AssetFee::<T>::insert(
+					asset_id,
+					FeeEntry {
+						asset_fee,
+						protocol_fee,
+						timestamp: block_number,
+						fixed: true,
+					},
+				);
+				return (asset_fee, protocol_fee);
+			}
+			Some(AssetFeeConfig::Dynamic {
+				asset_fee_params,
+				protocol_fee_params,
+			}) => (asset_fee_params, protocol_fee_params),
+			None => (T::AssetFeeParameters::get(), T::ProtocolFeeParameters::get()),
+		};
 
-		let current_fee_entry = Self::current_fees(asset_id).unwrap_or(FeeEntry {
+		let current_fee_entry = current_fee_entry.unwrap_or(FeeEntry {
 			asset_fee: asset_fee_params.min_fee,
 			protocol_fee: protocol_fee_params.min_fee,
 			timestamp: BlockNumberFor::<T>::default(),
+			fixed: false,
 		});
 
-		// Update only if it has not yet been updated this block
-		if block_number == current_fee_entry.timestamp {
-			return (current_fee_entry.asset_fee, current_fee_entry.protocol_fee);
-		}
-
 		let delta_blocks: u128 = block_number
 			.saturating_sub(current_fee_entry.timestamp)
 			.saturated_into();
@@ -210,6 +389,7 @@ where
 				asset_fee,
 				protocol_fee,
 				timestamp: block_number,
+				fixed: false,
 			},
 		);
 		(asset_fee, protocol_fee)
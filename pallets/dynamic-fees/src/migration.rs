@@ -0,0 +1,79 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use frame_support::{
+	pallet_prelude::*,
+	traits::{Get, StorageVersion},
+	weights::Weight,
+};
+
+pub mod v0 {
+	use super::*;
+
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub struct FeeEntry<Fee, Block> {
+		pub asset_fee: Fee,
+		pub protocol_fee: Fee,
+		pub timestamp: Block,
+	}
+}
+
+/// Migrate the pallet storage to v1.
+/// This migration marks fee entries of assets with a fixed fee configuration as fixed.
+/// All the existing entries were recalculated dynamically, so none of them is fixed.
+pub fn migrate_to_v1<T: Config>() -> Weight {
+	let on_chain_storage_version = StorageVersion::get::<Pallet<T>>();
+	//offset for storage version read
+	let mut weight: Weight = T::DbWeight::get().reads(1);
+
+	log::info!(
+		target: "runtime::dynamic-fees",
+		"Running migration storage v1 for dynamic-fees with storage version {:?}",
+		on_chain_storage_version,
+	);
+
+	if on_chain_storage_version < 1 {
+		let mut migrated: u64 = 0;
+		AssetFee::<T>::translate::<v0::FeeEntry<T::Fee, BlockNumberFor<T>>, _>(|_, entry| {
+			migrated.saturating_inc();
+			Some(FeeEntry {
+				asset_fee: entry.asset_fee,
+				protocol_fee: entry.protocol_fee,
+				timestamp: entry.timestamp,
+				fixed: false,
+			})
+		});
+		weight.saturating_accrue(T::DbWeight::get().reads_writes(migrated, migrated));
+
+		StorageVersion::new(1).put::<Pallet<T>>();
+		//add storage version update weight
+		weight.saturating_accrue(T::DbWeight::get().writes(1));
+
+		log::info!(
+			target: "runtime::dynamic-fees",
+			"Running migration storage v1 for dynamic-fees was complete, {:?} fee entries migrated",
+			migrated,
+		);
+	} else {
+		log::warn!(
+			target: "runtime::dynamic-fees",
+			"Attempted to apply migration to v1 but failed because storage version is {:?}",
+			on_chain_storage_version,
+		);
+	}
+
+	weight
+}
@@ -0,0 +1,282 @@
+use crate::tests::mock::*;
+use crate::tests::oracle::SingleValueOracle;
+use crate::types::{AssetFeeConfig, FeeParams};
+use crate::{AssetFee, AssetFeeConfiguration, Error, Event};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::traits::{One, Zero};
+use sp_runtime::{DispatchError, FixedU128};
+
+fn fee_params(min_fee: Fee, max_fee: Fee, amplification: FixedU128) -> FeeParams<Fee> {
+	FeeParams {
+		min_fee,
+		max_fee,
+		decay: FixedU128::zero(),
+		amplification,
+	}
+}
+
+fn dynamic_config() -> AssetFeeConfig<Fee> {
+	AssetFeeConfig::Dynamic {
+		asset_fee_params: fee_params(Fee::from_percent(1), Fee::from_percent(5), FixedU128::from(2)),
+		protocol_fee_params: fee_params(Fee::from_percent(1), Fee::from_percent(2), FixedU128::one()),
+	}
+}
+
+fn fixed_config() -> AssetFeeConfig<Fee> {
+	AssetFeeConfig::Fixed {
+		asset_fee: Fee::from_percent(3),
+		protocol_fee: Fee::from_percent(1),
+	}
+}
+
+#[test]
+fn set_asset_fee_should_store_config_when_called_by_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		// Act
+		assert_ok!(DynamicFees::set_asset_fee(RuntimeOrigin::root(), HDX, dynamic_config()));
+
+		// Assert
+		assert_eq!(AssetFeeConfiguration::<Test>::get(HDX), Some(dynamic_config()));
+		System::assert_last_event(
+			Event::AssetFeeConfigSet {
+				asset_id: HDX,
+				params: dynamic_config(),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn set_asset_fee_should_fail_when_called_by_non_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			DynamicFees::set_asset_fee(RuntimeOrigin::signed(1), HDX, fixed_config()),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_asset_fee_should_fail_when_min_fee_is_greater_than_max_fee() {
+	ExtBuilder::default().build().execute_with(|| {
+		let config = AssetFeeConfig::Dynamic {
+			asset_fee_params: fee_params(Fee::from_percent(5), Fee::from_percent(1), FixedU128::one()),
+			protocol_fee_params: fee_params(Fee::from_percent(1), Fee::from_percent(2), FixedU128::one()),
+		};
+
+		assert_noop!(
+			DynamicFees::set_asset_fee(RuntimeOrigin::root(), HDX, config),
+			Error::<Test>::InvalidFeeParameters
+		);
+	});
+}
+
+#[test]
+fn set_asset_fee_should_fail_when_amplification_is_zero() {
+	ExtBuilder::default().build().execute_with(|| {
+		let config = AssetFeeConfig::Dynamic {
+			asset_fee_params: fee_params(Fee::from_percent(1), Fee::from_percent(5), FixedU128::one()),
+			protocol_fee_params: fee_params(Fee::from_percent(1), Fee::from_percent(2), FixedU128::zero()),
+		};
+
+		assert_noop!(
+			DynamicFees::set_asset_fee(RuntimeOrigin::root(), HDX, config),
+			Error::<Test>::InvalidFeeParameters
+		);
+	});
+}
+
+#[test]
+fn fixed_fee_should_be_returned_when_asset_fee_is_fixed() {
+	ExtBuilder::default()
+		.with_oracle(SingleValueOracle::new(ONE, 2 * ONE, 50 * ONE))
+		.with_initial_fees(Fee::from_percent(2), Fee::from_percent(2), 0)
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			// Act
+			assert_ok!(DynamicFees::set_asset_fee(RuntimeOrigin::root(), HDX, fixed_config()));
+
+			// Assert
+			assert_eq!(retrieve_fee_entry(HDX), (Fee::from_percent(3), Fee::from_percent(1)));
+
+			System::set_block_number(5);
+			assert_eq!(retrieve_fee_entry(HDX), (Fee::from_percent(3), Fee::from_percent(1)));
+		});
+}
+
+#[test]
+fn fixed_fee_should_not_be_stored_again_when_unchanged() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(DynamicFees::set_asset_fee(RuntimeOrigin::root(), HDX, fixed_config()));
+
+		// Act
+		System::set_block_number(5);
+		assert_eq!(retrieve_fee_entry(HDX), (Fee::from_percent(3), Fee::from_percent(1)));
+
+		// Assert
+		assert_eq!(AssetFee::<Test>::get(HDX).map(|entry| entry.timestamp), Some(1));
+	});
+}
+
+#[test]
+fn set_asset_fee_should_fail_when_fixed_fees_are_not_less_than_one() {
+	ExtBuilder::default().build().execute_with(|| {
+		let config = AssetFeeConfig::Fixed {
+			asset_fee: Fee::from_percent(60),
+			protocol_fee: Fee::from_percent(40),
+		};
+
+		assert_noop!(
+			DynamicFees::set_asset_fee(RuntimeOrigin::root(), HDX, config),
+			Error::<Test>::FixedFeeOutOfBounds
+		);
+	});
+}
+
+#[test]
+fn set_asset_fee_should_pin_fee_below_global_min_fee() {
+	ExtBuilder::default()
+		.with_asset_fee_params(
+			Fee::from_percent(1),
+			Fee::from_percent(40),
+			FixedU128::zero(),
+			FixedU128::one(),
+		)
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			let config = AssetFeeConfig::Fixed {
+				asset_fee: Fee::zero(),
+				protocol_fee: Fee::zero(),
+			};
+
+			// Act
+			assert_ok!(DynamicFees::set_asset_fee(RuntimeOrigin::root(), HDX, config));
+
+			// Assert
+			System::set_block_number(2);
+			assert_eq!(retrieve_fee_entry(HDX), (Fee::zero(), Fee::zero()));
+		});
+}
+
+#[test]
+fn fixed_fee_entry_should_be_returned_without_reading_config() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(DynamicFees::set_asset_fee(RuntimeOrigin::root(), HDX, fixed_config()));
+		// Fixed entry is returned early, so the configuration is not consulted anymore
+		AssetFeeConfiguration::<Test>::remove(HDX);
+
+		// Act
+		System::set_block_number(5);
+
+		// Assert
+		assert_eq!(retrieve_fee_entry(HDX), (Fee::from_percent(3), Fee::from_percent(1)));
+		assert_eq!(AssetFee::<Test>::get(HDX).map(|entry| entry.fixed), Some(true));
+	});
+}
+
+#[test]
+fn asset_fee_params_should_be_used_when_asset_fee_is_dynamic() {
+	ExtBuilder::default()
+		.with_oracle(SingleValueOracle::new(ONE, 2 * ONE, 50 * ONE))
+		.with_asset_fee_params(
+			Fee::from_percent(1),
+			Fee::from_percent(40),
+			FixedU128::zero(),
+			FixedU128::one(),
+		)
+		.with_protocol_fee_params(
+			Fee::from_percent(1),
+			Fee::from_percent(40),
+			FixedU128::zero(),
+			FixedU128::one(),
+		)
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			// Act
+			assert_ok!(DynamicFees::set_asset_fee(RuntimeOrigin::root(), HDX, dynamic_config()));
+
+			// Assert - amplification of 2 doubles the fee increase
+			assert_eq!(retrieve_fee_entry(HDX), (Fee::from_percent(5), Fee::from_percent(1)));
+		});
+}
+
+#[test]
+fn set_asset_fee_should_reset_current_fees_when_asset_fee_is_dynamic() {
+	ExtBuilder::default()
+		.with_initial_fees(Fee::from_percent(20), Fee::from_percent(20), 0)
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			// Act
+			assert_ok!(DynamicFees::set_asset_fee(RuntimeOrigin::root(), HDX, dynamic_config()));
+
+			// Assert
+			assert!(AssetFee::<Test>::get(HDX).is_none());
+		});
+}
+
+#[test]
+fn remove_asset_fee_should_fall_back_to_global_params() {
+	ExtBuilder::default()
+		.with_oracle(SingleValueOracle::new(ONE, 2 * ONE, 50 * ONE))
+		.with_asset_fee_params(
+			Fee::from_percent(1),
+			Fee::from_percent(40),
+			FixedU128::zero(),
+			FixedU128::one(),
+		)
+		.with_protocol_fee_params(
+			Fee::from_percent(1),
+			Fee::from_percent(40),
+			FixedU128::zero(),
+			FixedU128::one(),
+		)
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(DynamicFees::set_asset_fee(RuntimeOrigin::root(), HDX, fixed_config()));
+
+			// Act
+			assert_ok!(DynamicFees::remove_asset_fee(RuntimeOrigin::root(), HDX));
+
+			// Assert
+			assert!(AssetFeeConfiguration::<Test>::get(HDX).is_none());
+			System::assert_last_event(Event::AssetFeeConfigRemoved { asset_id: HDX }.into());
+
+			System::set_block_number(2);
+			assert_eq!(retrieve_fee_entry(HDX), (Fee::from_percent(3), Fee::from_percent(1)));
+		});
+}
+
+#[test]
+fn remove_asset_fee_should_fail_when_config_does_not_exist() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			DynamicFees::remove_asset_fee(RuntimeOrigin::root(), HDX),
+			Error::<Test>::AssetFeeConfigNotFound
+		);
+	});
+}
+
+#[test]
+fn remove_asset_fee_should_fail_when_called_by_non_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(DynamicFees::set_asset_fee(RuntimeOrigin::root(), HDX, fixed_config()));
+
+		assert_noop!(
+			DynamicFees::remove_asset_fee(RuntimeOrigin::signed(1), HDX),
+			DispatchError::BadOrigin
+		);
+	});
+}
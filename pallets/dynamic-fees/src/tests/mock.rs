@@ -28,6 +28,7 @@ use frame_support::{
 	construct_runtime, parameter_types,
 	traits::{ConstU32, ConstU64},
 };
+use frame_system::EnsureRoot;
 use orml_traits::GetByKey;
 use sp_core::H256;
 use sp_runtime::{
@@ -115,6 +116,8 @@ impl Config for Test {
 	type Oracle = OracleProvider;
 	type AssetFeeParameters = AssetFeeParams;
 	type ProtocolFeeParameters = ProtocolFeeParams;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type WeightInfo = ();
 }
 
 pub struct ExtBuilder {
@@ -189,6 +192,7 @@ impl ExtBuilder {
 						asset_fee: initial_fee.0,
 						protocol_fee: initial_fee.1,
 						timestamp: initial_fee.2,
+						fixed: false,
 					},
 				);
 			}
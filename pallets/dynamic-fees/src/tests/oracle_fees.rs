@@ -27,6 +27,7 @@ pub fn asset_fee_should_be_update_correctly_when_volume_is_increasing() {
 					asset_fee: Fee::from_float(0.03),
 					protocol_fee: Fee::from_float(0.03),
 					timestamp: 0,
+					fixed: false,
 				},
 			);
 
@@ -10,3 +10,25 @@ pub trait VolumeProvider<AssetId, Balance> {
 
 	fn asset_liquidity(asset_id: AssetId) -> Option<Balance>;
 }
+
+impl<Balance: Default> Volume<Balance> for () {
+	fn amount_in(&self) -> Balance {
+		Balance::default()
+	}
+
+	fn amount_out(&self) -> Balance {
+		Balance::default()
+	}
+}
+
+impl<AssetId, Balance: Default> VolumeProvider<AssetId, Balance> for () {
+	type Volume = ();
+
+	fn asset_volume(_asset_id: AssetId) -> Option<Self::Volume> {
+		None
+	}
+
+	fn asset_liquidity(_asset_id: AssetId) -> Option<Balance> {
+		None
+	}
+}
@@ -7,7 +7,7 @@ use hydra_dx_math::dynamic_fees::types::FeeParams as MathFeeParams;
 
 use scale_info::TypeInfo;
 
-#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, TypeInfo)]
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct FeeParams<Fee> {
 	pub min_fee: Fee,
@@ -22,6 +22,8 @@ pub struct FeeEntry<Fee, Block> {
 	pub asset_fee: Fee,
 	pub protocol_fee: Fee,
 	pub timestamp: Block,
+	/// Fees are pinned by a `Fixed` fee configuration and are not recalculated.
+	pub fixed: bool,
 }
 
 impl<Fee> From<FeeParams<Fee>> for MathFeeParams<Fee> {
@@ -34,3 +36,16 @@ impl<Fee> From<FeeParams<Fee>> for MathFeeParams<Fee> {
 		}
 	}
 }
+
+/// Per-asset fee configuration which overrides the global fee parameters.
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum AssetFeeConfig<Fee> {
+	/// Fees are pinned to the given values and are not recalculated.
+	Fixed { asset_fee: Fee, protocol_fee: Fee },
+	/// Fees are recalculated based on oracle volume using the given parameters.
+	Dynamic {
+		asset_fee_params: FeeParams<Fee>,
+		protocol_fee_params: FeeParams<Fee>,
+	},
+}
@@ -0,0 +1,63 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the EMA oracle pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+pub use hydradx_traits::router::Trade;
+pub use hydradx_traits::{AggregatedEntry, Liquidity, OraclePeriod, Source, Volume};
+pub use pallet_ema_oracle::OracleError;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// The API to query EMA oracles.
+	pub trait EmaOracleApi<AssetId, Balance, BlockNumber, Price> where
+		AssetId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+		Price: Codec,
+	{
+		/// Oracle entry of `(asset_a, asset_b)` pair for given source and period, updated to the parent block.
+		fn get_entry(
+			source: Source,
+			asset_a: AssetId,
+			asset_b: AssetId,
+			period: OraclePeriod,
+		) -> Result<AggregatedEntry<Balance, BlockNumber, Price>, OracleError>;
+
+		/// Oracle entries of `(asset_a, asset_b)` pair for given source and all supported periods,
+		/// updated to the parent block.
+		fn get_entries(
+			source: Source,
+			asset_a: AssetId,
+			asset_b: AssetId,
+		) -> Vec<(OraclePeriod, AggregatedEntry<Balance, BlockNumber, Price>)>;
+
+		/// All whitelisted oracles as `(source, assets)` pairs.
+		fn whitelisted_oracles() -> Vec<(Source, (AssetId, AssetId))>;
+
+		/// Oracle price of `asset_in` in `asset_out` composed from the oracle prices of trades of the route,
+		/// as used by the router and the fee payment.
+		/// If `route` is not provided, the on-chain route or the default route is used.
+		fn route_price(
+			asset_in: AssetId,
+			asset_out: AssetId,
+			period: OraclePeriod,
+			route: Option<Vec<Trade<AssetId>>>,
+		) -> Option<Price>;
+	}
+}
@@ -0,0 +1,207 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! RPC interface for the EMA oracle pallet.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc, types::error::ErrorObject};
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+pub use pallet_ema_oracle_rpc_runtime_api::EmaOracleApi as EmaOracleRuntimeApi;
+use pallet_ema_oracle_rpc_runtime_api::{AggregatedEntry, OraclePeriod, Source, Trade};
+
+#[rpc(client, server)]
+pub trait EmaOracleApi<BlockHash, AssetId, Balance, BlockNumber, Price> {
+	/// Get the oracle entry of an asset pair for given source and period.
+	#[method(name = "emaOracle_getEntry")]
+	fn get_entry(
+		&self,
+		source: Source,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		period: OraclePeriod,
+		at: Option<BlockHash>,
+	) -> RpcResult<AggregatedEntry<Balance, BlockNumber, Price>>;
+
+	/// Get the oracle entries of an asset pair for given source and all supported periods.
+	#[method(name = "emaOracle_getEntries")]
+	fn get_entries(
+		&self,
+		source: Source,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(OraclePeriod, AggregatedEntry<Balance, BlockNumber, Price>)>>;
+
+	/// Get all whitelisted oracles.
+	#[method(name = "emaOracle_whitelistedOracles")]
+	fn whitelisted_oracles(&self, at: Option<BlockHash>) -> RpcResult<Vec<(Source, (AssetId, AssetId))>>;
+
+	/// Get the oracle price of `asset_in` in `asset_out` composed along a route.
+	///
+	/// If `route` is not provided, the on-chain route or the default route is used.
+	#[method(name = "emaOracle_routePrice")]
+	fn route_price(
+		&self,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		period: OraclePeriod,
+		route: Option<Vec<Trade<AssetId>>>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Price>;
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+	/// The oracle entry is not available.
+	OracleError,
+	/// The price could not be determined.
+	PriceNotAvailable,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::OracleError => 2,
+			Error::PriceNotAvailable => 3,
+		}
+	}
+}
+
+/// Provides RPC methods to query EMA oracles.
+pub struct EmaOracle<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> EmaOracle<C, Block> {
+	/// Creates a new instance of the EmaOracle RPC helper.
+	pub fn new(client: Arc<C>) -> Self {
+		Self {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+impl<C, Block, AssetId, Balance, BlockNumber, Price>
+	EmaOracleApiServer<<Block as BlockT>::Hash, AssetId, Balance, BlockNumber, Price> for EmaOracle<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: EmaOracleRuntimeApi<Block, AssetId, Balance, BlockNumber, Price>,
+	AssetId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	Balance: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	BlockNumber: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	Price: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+	fn get_entry(
+		&self,
+		source: Source,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		period: OraclePeriod,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<AggregatedEntry<Balance, BlockNumber, Price>> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let entry = api.get_entry(at_hash, source, asset_a, asset_b, period).map_err(|e| {
+			ErrorObject::owned(
+				Error::RuntimeError.into(),
+				"Unable to query oracle entry.",
+				Some(e.to_string()),
+			)
+		})?;
+
+		entry.map_err(|e| {
+			ErrorObject::owned(
+				Error::OracleError.into(),
+				"Oracle entry not available.",
+				Some(format!("{:?}", e)),
+			)
+		})
+	}
+
+	fn get_entries(
+		&self,
+		source: Source,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<(OraclePeriod, AggregatedEntry<Balance, BlockNumber, Price>)>> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.get_entries(at_hash, source, asset_a, asset_b).map_err(|e| {
+			ErrorObject::owned(
+				Error::RuntimeError.into(),
+				"Unable to query oracle entries.",
+				Some(e.to_string()),
+			)
+		})
+	}
+
+	fn whitelisted_oracles(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<(Source, (AssetId, AssetId))>> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.whitelisted_oracles(at_hash).map_err(|e| {
+			ErrorObject::owned(
+				Error::RuntimeError.into(),
+				"Unable to query whitelisted oracles.",
+				Some(e.to_string()),
+			)
+		})
+	}
+
+	fn route_price(
+		&self,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		period: OraclePeriod,
+		route: Option<Vec<Trade<AssetId>>>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Price> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let price = api
+			.route_price(at_hash, asset_in, asset_out, period, route)
+			.map_err(|e| {
+				ErrorObject::owned(
+					Error::RuntimeError.into(),
+					"Unable to query route price.",
+					Some(e.to_string()),
+				)
+			})?;
+
+		price.ok_or_else(|| {
+			ErrorObject::owned(
+				Error::PriceNotAvailable.into(),
+				"Oracle price not available for the route.",
+				None::<()>,
+			)
+		})
+	}
+}
@@ -57,6 +57,13 @@
 //! moving average logic defined in the math package this pallet depens on. There is one oracle
 //! entry for each combination of `(source, asset_pair, period)` in storage.
 //!
+//! Next to the EMA oracles the pallet keeps a bounded ring buffer of cumulative price checkpoints
+//! for each combination of source and asset pair. `TwapOracle` uses them to provide time weighted
+//! average prices (TWAP) over the length of the requested period. `MedianOracle` wraps any price
+//! oracle and returns the median price of an asset pair across multiple sources, which makes the
+//! price harder to manipulate via a single thin venue. Both implement `AggregatedPriceOracle` and
+//! can be used in place of the pallet by consumers of that trait.
+//!
 //! Oracle values are accessed lazily. This means that the storage does not contain the most recent
 //! value, but the value calculated the last time it was updated via trade or liquidity change. On a
 //! read the values are read from storage and then fast-forwarded (assuming the volume to be zero
@@ -70,11 +77,12 @@ use frame_support::pallet_prelude::*;
 use frame_support::sp_runtime::traits::{BlockNumberProvider, One, Zero};
 use frame_support::traits::Contains;
 use frame_system::pallet_prelude::BlockNumberFor;
+use hydra_dx_math::ema::{calculate_twap, median_price, CumulativePrice};
 use hydradx_traits::{
 	AggregatedEntry, AggregatedOracle, AggregatedPriceOracle, Liquidity, OnCreatePoolHandler,
 	OnLiquidityChangedHandler, OnTradeHandler, Volume,
 };
-use sp_arithmetic::traits::Saturating;
+use sp_arithmetic::traits::{SaturatedConversion, Saturating};
 use sp_std::marker::PhantomData;
 use sp_std::prelude::*;
 
@@ -142,6 +150,13 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxUniqueEntries: Get<u32>;
 
+		/// Maximum number of cumulative price checkpoints kept per oracle for TWAP calculation.
+		///
+		/// Every tracked oracle stores up to this many checkpoints, so it should be sized to the
+		/// longest period requested from `TwapOracle`, in blocks, and not more.
+		#[pallet::constant]
+		type MaxTwapCheckpoints: Get<u32>;
+
 		#[cfg(feature = "runtime-benchmarks")]
 		type BenchmarkHelper: BenchmarkHelper<AssetId>;
 	}
@@ -187,6 +202,25 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// Ring buffer of cumulative price checkpoints keyed by data source, involved asset ids and the
+	/// index in the buffer.
+	#[pallet::storage]
+	pub type TwapCheckpoints<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Twox64Concat, Source>,
+			NMapKey<Twox64Concat, (AssetId, AssetId)>,
+			NMapKey<Twox64Concat, u32>,
+		),
+		TwapCheckpoint<BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	/// Position of the newest checkpoint and number of checkpoints in the ring buffer of each oracle.
+	#[pallet::storage]
+	pub type TwapBuffers<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, Source, Twox64Concat, (AssetId, AssetId), TwapBuffer, OptionQuery>;
+
 	/// Assets that are whitelisted and tracked by the pallet.
 	#[pallet::storage]
 	pub type WhitelistedAssets<T: Config> =
@@ -221,6 +255,7 @@ pub mod pallet {
 				for period in T::SupportedPeriods::get() {
 					Pallet::<T>::update_oracle(source, ordered_pair(asset_a, asset_b), period, entry.clone());
 				}
+				Pallet::<T>::record_twap_checkpoint(source, ordered_pair(asset_a, asset_b), entry.price);
 			}
 		}
 	}
@@ -264,7 +299,8 @@ pub mod pallet {
 		}
 
 		#[pallet::call_index(1)]
-		#[pallet::weight(<T as Config>::WeightInfo::remove_oracle())]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_oracle()
+			.saturating_add(T::DbWeight::get().writes(T::MaxTwapCheckpoints::get().saturating_add(1).into())))]
 		pub fn remove_oracle(origin: OriginFor<T>, source: Source, assets: (AssetId, AssetId)) -> DispatchResult {
 			T::AuthorityOrigin::ensure_origin(origin)?;
 
@@ -284,6 +320,8 @@ pub mod pallet {
 				});
 				Oracles::<T>::remove((source, assets, period));
 			}
+			let _ = TwapCheckpoints::<T>::clear_prefix((source, assets), T::MaxTwapCheckpoints::get(), None);
+			TwapBuffers::<T>::remove(source, assets);
 
 			Self::deposit_event(Event::RemovedFromWhitelist { source, assets });
 
@@ -373,7 +411,95 @@ impl<T: Config> Pallet<T> {
 			// As we use (the old value of) the `LastBlock` entry to update the other oracles it
 			// gets updated last.
 			Self::update_oracle(src, assets, OraclePeriod::LastBlock, oracle_entry.clone());
+			Self::record_twap_checkpoint(src, assets, oracle_entry.price);
+		}
+	}
+
+	/// Record a cumulative price checkpoint with `price` for the given source and assets in the
+	/// current block. Overwrites the oldest checkpoint if the ring buffer is full.
+	fn record_twap_checkpoint(src: Source, assets: (AssetId, AssetId), price: Price) {
+		let capacity = T::MaxTwapCheckpoints::get();
+		if capacity.is_zero() {
+			return;
+		}
+		let block = T::BlockNumberProvider::current_block_number();
+		let (buffer, cumulative_price) = match TwapBuffers::<T>::get(src, assets) {
+			Some(buffer) => {
+				let newest = TwapCheckpoints::<T>::get((src, assets, buffer.head)).unwrap_or_default();
+				if newest.block == block {
+					// only the price at the end of the block is relevant
+					(buffer, newest.cumulative_price)
+				} else {
+					(buffer.pushed(capacity), newest.cumulative_at(block))
+				}
+			}
+			None => (TwapBuffer { head: 0, len: 1 }, CumulativePrice::zero()),
+		};
+		TwapCheckpoints::<T>::insert(
+			(src, assets, buffer.head),
+			TwapCheckpoint {
+				cumulative_price,
+				price,
+				block,
+			},
+		);
+		TwapBuffers::<T>::insert(src, assets, buffer);
+	}
+
+	/// Return the time weighted average price for the given source and assets over the length of
+	/// `period`, together with the number of blocks the average spans.
+	///
+	/// Like the EMA oracles the average includes the prices up to the parent block. If the oracle is
+	/// younger than the period, the average spans its whole history. Returns `None` if older
+	/// checkpoints of the period have already been overwritten in the ring buffer.
+	pub(crate) fn get_twap(
+		src: Source,
+		assets: (AssetId, AssetId),
+		period: OraclePeriod,
+	) -> Option<(Price, BlockNumberFor<T>)> {
+		let capacity = T::MaxTwapCheckpoints::get();
+		let buffer = TwapBuffers::<T>::get(src, assets)?;
+		let checkpoint_at = |position: u32| TwapCheckpoints::<T>::get((src, assets, buffer.index(position, capacity)));
+
+		let current = T::BlockNumberProvider::current_block_number();
+		let window_start = current.saturating_sub(into_blocks(period).into());
+
+		// Ignore a checkpoint recorded in the current block.
+		let mut newest_position = buffer.len.checked_sub(1)?;
+		let mut newest = checkpoint_at(newest_position)?;
+		if newest.block >= current {
+			newest_position = newest_position.checked_sub(1)?;
+			newest = checkpoint_at(newest_position)?;
+		}
+
+		// Find the newest checkpoint recorded at or before the start of the window.
+		let mut start = checkpoint_at(0)?;
+		if start.block > window_start && buffer.len >= capacity {
+			// The ring buffer does not cover the whole period.
+			return None;
 		}
+		if start.block < window_start {
+			let (mut low, mut high) = (0, newest_position);
+			while low < high {
+				let mid = low.saturating_add(high.saturating_sub(low).saturating_add(1) / 2);
+				let checkpoint = checkpoint_at(mid)?;
+				if checkpoint.block <= window_start {
+					low = mid;
+					start = checkpoint;
+				} else {
+					high = mid.saturating_sub(1);
+				}
+			}
+		}
+
+		let start_block = start.block.max(window_start);
+		let blocks = current.saturating_sub(start_block);
+		let price = calculate_twap(
+			start.cumulative_at(start_block),
+			newest.cumulative_at(current),
+			blocks.saturated_into(),
+		)?;
+		Some((price, blocks))
 	}
 
 	/// Update the oracle of the given source, assets and period with `oracle_entry`.
@@ -445,6 +571,31 @@ impl<T: Config> Pallet<T> {
 		}
 		.map(|return_entry| (return_entry, init))
 	}
+
+	/// Return the up-to-date entries of all supported periods for the given source and assets.
+	///
+	/// Entries are oriented as `(asset_a, asset_b)`. Periods without an oracle are skipped.
+	pub fn get_entries(
+		source: Source,
+		asset_a: AssetId,
+		asset_b: AssetId,
+	) -> Vec<(OraclePeriod, AggregatedEntry<Balance, BlockNumberFor<T>, Price>)> {
+		T::SupportedPeriods::get()
+			.into_iter()
+			.filter_map(|period| {
+				<Self as AggregatedOracle<AssetId, Balance, BlockNumberFor<T>, Price>>::get_entry(
+					asset_a, asset_b, period, source,
+				)
+				.ok()
+				.map(|entry| (period, entry))
+			})
+			.collect()
+	}
+
+	/// Return all whitelisted oracles as `(source, assets)` pairs.
+	pub fn whitelisted_oracles() -> Vec<(Source, (AssetId, AssetId))> {
+		WhitelistedAssets::<T>::get().into_iter().collect()
+	}
 }
 
 /// A callback handler for trading and liquidity activity that schedules oracle updates.
@@ -463,6 +614,8 @@ pub(crate) fn fractional_on_finalize_weight<T: Config>(max_entries: u32) -> Weig
 	T::WeightInfo::on_finalize_multiple_tokens(max_entries)
 		.saturating_sub(T::WeightInfo::on_finalize_no_entry())
 		.saturating_div(max_entries.into())
+		// recording the TWAP checkpoint
+		.saturating_add(T::DbWeight::get().reads_writes(2, 2))
 }
 
 impl<T: Config> OnTradeHandler<AssetId, Balance, Price> for OnActivityHandler<T> {
@@ -654,6 +807,101 @@ impl<T: Config> AggregatedPriceOracle<AssetId, BlockNumberFor<T>, Price> for Pal
 	}
 }
 
+/// Time weighted average price oracle based on the cumulative price checkpoints of the pallet.
+///
+/// The average is taken over the length of the requested period. The returned oracle age is the
+/// number of blocks the average spans, which is shorter than the period only if the oracle is
+/// younger than the period. Periods not covered by the `MaxTwapCheckpoints` checkpoints kept in
+/// the ring buffer fail with `NotPresent`.
+///
+/// Note: The average is calculated for the ordered asset pair. Prices for the reverse order are
+/// inverted averages.
+pub struct TwapOracle<T>(PhantomData<T>);
+
+impl<T: Config> AggregatedPriceOracle<AssetId, BlockNumberFor<T>, Price> for TwapOracle<T> {
+	type Error = OracleError;
+
+	fn get_price(
+		asset_a: AssetId,
+		asset_b: AssetId,
+		period: OraclePeriod,
+		source: Source,
+	) -> Result<(Price, BlockNumberFor<T>), Self::Error> {
+		if asset_a == asset_b {
+			return Err(OracleError::SameAsset);
+		};
+		let assets = ordered_pair(asset_a, asset_b);
+		Pallet::<T>::get_twap(source, assets, period)
+			.ok_or(OracleError::NotPresent)
+			.map(|(price, age)| {
+				if (asset_a, asset_b) != assets {
+					(price.inverted(), age)
+				} else {
+					(price, age)
+				}
+			})
+	}
+
+	fn get_price_weight() -> Weight {
+		// buffer, newest and oldest checkpoints and a binary search over the buffer
+		let capacity = T::MaxTwapCheckpoints::get();
+		let search_reads = u32::BITS.saturating_sub(capacity.leading_zeros());
+		T::DbWeight::get().reads(search_reads.saturating_add(4).into())
+	}
+}
+
+/// Price oracle returning the median price of an asset pair across the requested source and
+/// `Sources`, as provided by `Oracle`.
+///
+/// Sources without an oracle for the pair are skipped. The returned oracle age is the minimum age
+/// of the used oracles.
+pub struct MedianOracle<Oracle, Sources>(PhantomData<(Oracle, Sources)>);
+
+impl<AssetId, BlockNumber, Oracle, Sources> AggregatedPriceOracle<AssetId, BlockNumber, Price>
+	for MedianOracle<Oracle, Sources>
+where
+	AssetId: Copy,
+	BlockNumber: Ord + Copy,
+	Oracle: AggregatedPriceOracle<AssetId, BlockNumber, Price, Error = OracleError>,
+	Sources: Get<Vec<Source>>,
+{
+	type Error = OracleError;
+
+	fn get_price(
+		asset_a: AssetId,
+		asset_b: AssetId,
+		period: OraclePeriod,
+		source: Source,
+	) -> Result<(Price, BlockNumber), Self::Error> {
+		let mut sources = Sources::get();
+		if !sources.contains(&source) {
+			sources.push(source);
+		}
+
+		let mut prices = Vec::with_capacity(sources.len());
+		let mut oracle_age: Option<BlockNumber> = None;
+		for source in sources {
+			match Oracle::get_price(asset_a, asset_b, period, source) {
+				Ok((price, age)) => {
+					prices.push(price);
+					oracle_age = Some(oracle_age.map_or(age, |a| a.min(age)));
+				}
+				Err(OracleError::NotPresent) => continue,
+				Err(e) => return Err(e),
+			}
+		}
+
+		let price = median_price(&mut prices).ok_or(OracleError::NotPresent)?;
+		let oracle_age = oracle_age.ok_or(OracleError::NotPresent)?;
+		Ok((price, oracle_age))
+	}
+
+	fn get_price_weight() -> Weight {
+		let sources = Sources::get().len().saturating_add(1) as u64;
+		Oracle::get_price_weight().saturating_mul(sources)
+	}
+}
+
 /// Oracle whitelist based on the pallet's storage.
 pub struct OracleWhitelist<T>(PhantomData<T>);
 impl<T: Config> Contains<(Source, AssetId, AssetId)> for OracleWhitelist<T> {
@@ -0,0 +1,108 @@
+// This file is part of pallet-ema-oracle.
+
+// Copyright (C) 2022-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+pub use mock::{EmaOracle, RuntimeOrigin, Test, DOT, HDX};
+
+use frame_support::assert_ok;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn get_entries_should_return_updated_entries_of_all_supported_periods() {
+	new_test_ext().execute_with(|| {
+		// Arrange
+		System::set_block_number(1);
+		assert_ok!(OnActivityHandler::<Test>::on_trade(
+			SOURCE,
+			HDX,
+			DOT,
+			1_000,
+			500,
+			2_000,
+			1_000,
+			Price::new(2_000, 1_000),
+		));
+		EmaOracle::on_finalize(1);
+		System::set_block_number(100);
+
+		// Act
+		let entries = EmaOracle::get_entries(SOURCE, HDX, DOT);
+
+		// Assert
+		let expected: Vec<_> = supported_periods()
+			.into_iter()
+			.map(|period| (period, EmaOracle::get_entry(HDX, DOT, period, SOURCE).unwrap()))
+			.collect();
+		assert_eq!(entries, expected);
+		assert_eq!(entries[1].0, TenMinutes);
+		assert_eq!(entries[1].1.volume, Volume::from_a_in_b_out(141, 70));
+		assert_eq!(entries[1].1.liquidity, Liquidity::new(2_000, 1_000));
+	});
+}
+
+#[test]
+fn get_entries_should_return_inverted_entries_when_assets_are_in_reverse_order() {
+	new_test_ext().execute_with(|| {
+		// Arrange
+		System::set_block_number(1);
+		assert_ok!(OnActivityHandler::<Test>::on_trade(
+			SOURCE,
+			HDX,
+			DOT,
+			1_000,
+			500,
+			2_000,
+			1_000,
+			Price::new(2_000, 1_000),
+		));
+		EmaOracle::on_finalize(1);
+		System::set_block_number(2);
+
+		// Act
+		let entries = EmaOracle::get_entries(SOURCE, DOT, HDX);
+
+		// Assert
+		assert_eq!(entries.len(), supported_periods().len());
+		for (_, entry) in entries {
+			assert_eq!(entry.price, Price::new(1_000, 2_000));
+			assert_eq!(entry.liquidity, Liquidity::new(1_000, 2_000));
+		}
+	});
+}
+
+#[test]
+fn get_entries_should_return_empty_list_when_oracle_is_not_present() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(2);
+		assert!(EmaOracle::get_entries(SOURCE, HDX, DOT).is_empty());
+	});
+}
+
+#[test]
+fn whitelisted_oracles_should_return_all_whitelisted_entries() {
+	new_test_ext().execute_with(|| {
+		// Arrange
+		assert_ok!(EmaOracle::add_oracle(RuntimeOrigin::root(), SOURCE, (DOT, HDX)));
+		assert_ok!(EmaOracle::add_oracle(RuntimeOrigin::root(), SOURCE, (HDX, ACA)));
+
+		// Act
+		let oracles = EmaOracle::whitelisted_oracles();
+
+		// Assert
+		assert_eq!(oracles, vec![(SOURCE, (HDX, DOT)), (SOURCE, (HDX, ACA))]);
+	});
+}
@@ -16,8 +16,10 @@
 // limitations under the License.
 
 mod add_and_remove_oracle;
+mod entries;
 mod invariants;
 mod mock;
+mod twap;
 
 use super::*;
 use crate::OraclePeriod::*;
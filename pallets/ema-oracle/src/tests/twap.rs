@@ -0,0 +1,272 @@
+// This file is part of pallet-ema-oracle.
+
+// Copyright (C) 2022-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+pub use mock::{EmaOracle, RuntimeOrigin, Test, DOT, HDX};
+
+use frame_support::{assert_ok, parameter_types};
+use pretty_assertions::assert_eq;
+
+const OTHER_SOURCE: Source = *b"othersrc";
+const THIRD_SOURCE: Source = *b"thirdsrc";
+
+parameter_types! {
+	pub MedianSources: Vec<Source> = vec![OTHER_SOURCE, THIRD_SOURCE];
+}
+
+type Twap = TwapOracle<Test>;
+type Median = MedianOracle<EmaOracle, MedianSources>;
+
+fn trade_and_finalize(source: Source, block: BlockNumber, price: Price) {
+	System::set_block_number(block);
+	assert_ok!(OnActivityHandler::<Test>::on_trade(
+		source, HDX, DOT, 1_000, 500, 2_000, 1_000, price,
+	));
+	EmaOracle::on_finalize(block);
+}
+
+fn checkpoints(source: Source) -> Vec<TwapCheckpoint<BlockNumber>> {
+	let capacity = <Test as crate::Config>::MaxTwapCheckpoints::get();
+	let buffer = TwapBuffers::<Test>::get(source, (HDX, DOT)).unwrap_or_default();
+	(0..buffer.len)
+		.map(|position| TwapCheckpoints::<Test>::get((source, (HDX, DOT), buffer.index(position, capacity))).unwrap())
+		.collect()
+}
+
+#[test]
+fn checkpoint_should_be_recorded_when_oracle_is_updated() {
+	new_test_ext().execute_with(|| {
+		// Act
+		trade_and_finalize(SOURCE, 1, Price::from(2));
+		trade_and_finalize(SOURCE, 11, Price::from(4));
+
+		// Assert
+		assert_eq!(
+			checkpoints(SOURCE),
+			vec![
+				TwapCheckpoint {
+					cumulative_price: CumulativePrice::zero(),
+					price: Price::from(2),
+					block: 1,
+				},
+				TwapCheckpoint {
+					cumulative_price: CumulativePrice::from(20) << 128,
+					price: Price::from(4),
+					block: 11,
+				},
+			]
+		);
+	});
+}
+
+#[test]
+fn oldest_checkpoint_should_be_overwritten_when_buffer_is_full() {
+	new_test_ext().execute_with(|| {
+		// Arrange
+		let capacity = <Test as crate::Config>::MaxTwapCheckpoints::get();
+
+		// Act
+		for block in 1..=(capacity as BlockNumber + 2) {
+			trade_and_finalize(SOURCE, block, Price::from(block as u128));
+		}
+
+		// Assert
+		let checkpoints = checkpoints(SOURCE);
+		assert_eq!(checkpoints.len(), capacity as usize);
+		assert_eq!(checkpoints.first().unwrap().block, 3);
+		assert_eq!(checkpoints.last().unwrap().block, capacity as BlockNumber + 2);
+	});
+}
+
+#[test]
+fn twap_should_average_prices_over_available_history() {
+	new_test_ext().execute_with(|| {
+		// Arrange
+		trade_and_finalize(SOURCE, 1, Price::from(2));
+		trade_and_finalize(SOURCE, 11, Price::from(4));
+		System::set_block_number(21);
+
+		// Act
+		let (price, age) = Twap::get_price(HDX, DOT, TenMinutes, SOURCE).unwrap();
+
+		// Assert
+		assert_price_approx_eq!(price, Price::from(3), Price::zero());
+		assert_eq!(age, 20);
+	});
+}
+
+#[test]
+fn twap_should_only_include_blocks_of_the_period() {
+	new_test_ext().execute_with(|| {
+		// Arrange
+		for block in 1..=12 {
+			trade_and_finalize(SOURCE, block, Price::from(block as u128));
+		}
+		System::set_block_number(13);
+
+		// Act
+		let (short_price, short_age) = Twap::get_price(HDX, DOT, Short, SOURCE).unwrap();
+		let (last_block_price, last_block_age) = Twap::get_price(HDX, DOT, LastBlock, SOURCE).unwrap();
+
+		// Assert
+		// average of the prices of blocks 4 to 12
+		assert_price_approx_eq!(short_price, Price::from(8), Price::zero());
+		assert_eq!(short_age, 9);
+		assert_price_approx_eq!(last_block_price, Price::from(12), Price::zero());
+		assert_eq!(last_block_age, 1);
+	});
+}
+
+#[test]
+fn twap_should_fail_when_checkpoints_do_not_cover_period() {
+	new_test_ext().execute_with(|| {
+		// Arrange
+		let capacity = <Test as crate::Config>::MaxTwapCheckpoints::get();
+		for block in 1..=(capacity as BlockNumber + 2) {
+			trade_and_finalize(SOURCE, block, Price::from(block as u128));
+		}
+		System::set_block_number(capacity as BlockNumber + 3);
+
+		// Act & Assert
+		assert_eq!(
+			Twap::get_price(HDX, DOT, TenMinutes, SOURCE),
+			Err(OracleError::NotPresent)
+		);
+		assert!(Twap::get_price(HDX, DOT, Short, SOURCE).is_ok());
+	});
+}
+
+#[test]
+fn twap_should_be_inverted_when_assets_are_in_reverse_order() {
+	new_test_ext().execute_with(|| {
+		// Arrange
+		trade_and_finalize(SOURCE, 1, Price::from(2));
+		trade_and_finalize(SOURCE, 11, Price::from(4));
+		System::set_block_number(21);
+
+		// Act
+		let (price, _) = Twap::get_price(DOT, HDX, TenMinutes, SOURCE).unwrap();
+
+		// Assert
+		assert_price_approx_eq!(price, Price::new(1, 3), Price::zero());
+	});
+}
+
+#[test]
+fn twap_should_not_include_checkpoint_of_current_block() {
+	new_test_ext().execute_with(|| {
+		// Arrange
+		trade_and_finalize(SOURCE, 1, Price::from(2));
+
+		// Act & Assert
+		assert_eq!(
+			Twap::get_price(HDX, DOT, TenMinutes, SOURCE),
+			Err(OracleError::NotPresent)
+		);
+		System::set_block_number(2);
+		assert_eq!(Twap::get_price(HDX, DOT, TenMinutes, SOURCE).map(|(_, age)| age), Ok(1));
+	});
+}
+
+#[test]
+fn twap_should_fail_when_oracle_is_not_present() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(
+			Twap::get_price(HDX, DOT, TenMinutes, SOURCE),
+			Err(OracleError::NotPresent)
+		);
+		assert_eq!(
+			Twap::get_price(HDX, HDX, TenMinutes, SOURCE),
+			Err(OracleError::SameAsset)
+		);
+	});
+}
+
+#[test]
+fn remove_oracle_should_remove_checkpoints() {
+	new_test_ext().execute_with(|| {
+		// Arrange
+		assert_ok!(EmaOracle::add_oracle(RuntimeOrigin::root(), SOURCE, (HDX, DOT)));
+		trade_and_finalize(SOURCE, 1, Price::from(2));
+		trade_and_finalize(SOURCE, 2, Price::from(3));
+
+		// Act
+		assert_ok!(EmaOracle::remove_oracle(RuntimeOrigin::root(), SOURCE, (HDX, DOT)));
+
+		// Assert
+		assert!(TwapBuffers::<Test>::get(SOURCE, (HDX, DOT)).is_none());
+		assert_eq!(TwapCheckpoints::<Test>::iter_prefix((SOURCE, (HDX, DOT))).count(), 0);
+	});
+}
+
+#[test]
+fn median_should_return_median_price_across_sources() {
+	new_test_ext().execute_with(|| {
+		// Arrange
+		System::set_block_number(1);
+		for (source, price) in [(SOURCE, 2), (OTHER_SOURCE, 3), (THIRD_SOURCE, 100)] {
+			assert_ok!(OnActivityHandler::<Test>::on_trade(
+				source,
+				HDX,
+				DOT,
+				1_000,
+				500,
+				2_000,
+				1_000,
+				Price::from(price),
+			));
+		}
+		EmaOracle::on_finalize(1);
+		System::set_block_number(2);
+
+		// Act
+		let (price, _) = Median::get_price(HDX, DOT, LastBlock, SOURCE).unwrap();
+
+		// Assert
+		assert_price_approx_eq!(price, Price::from(3), Price::zero());
+	});
+}
+
+#[test]
+fn median_should_skip_sources_without_oracle() {
+	new_test_ext().execute_with(|| {
+		// Arrange
+		trade_and_finalize(SOURCE, 1, Price::from(2));
+		trade_and_finalize(OTHER_SOURCE, 1, Price::from(4));
+		System::set_block_number(2);
+
+		// Act
+		let (price, _) = Median::get_price(HDX, DOT, LastBlock, SOURCE).unwrap();
+
+		// Assert
+		assert_price_approx_eq!(price, Price::from(3), Price::zero());
+	});
+}
+
+#[test]
+fn median_should_fail_when_no_source_has_oracle() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(
+			Median::get_price(HDX, DOT, LastBlock, SOURCE),
+			Err(OracleError::NotPresent)
+		);
+		assert_eq!(
+			Median::get_price(HDX, HDX, LastBlock, SOURCE),
+			Err(OracleError::SameAsset)
+		);
+	});
+}
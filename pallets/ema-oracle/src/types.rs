@@ -17,7 +17,9 @@
 
 use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::sp_runtime::RuntimeDebug;
-use hydra_dx_math::ema::{calculate_new_by_integrating_incoming, update_outdated_to_current, EmaPrice};
+use hydra_dx_math::ema::{
+	accumulate_price, calculate_new_by_integrating_incoming, update_outdated_to_current, CumulativePrice, EmaPrice,
+};
 use hydra_dx_math::types::Fraction;
 use hydradx_traits::{AggregatedEntry, Liquidity, Volume};
 use scale_info::TypeInfo;
@@ -217,6 +219,75 @@ pub fn into_smoothing(period: OraclePeriod) -> Fraction {
 	}
 }
 
+/// Convert a given `period` into its length in blocks.
+/// Assumes a 6 second block time, like the smoothing factors of [`into_smoothing`].
+pub fn into_blocks(period: OraclePeriod) -> u32 {
+	match period {
+		OraclePeriod::LastBlock => 1,
+		OraclePeriod::Short => 9,
+		OraclePeriod::TenMinutes => 100,
+		OraclePeriod::Hour => 600,
+		OraclePeriod::Day => 14_400,
+		OraclePeriod::Week => 100_800,
+	}
+}
+
+/// A checkpoint of the cumulative price of an asset pair used to calculate time weighted average
+/// prices.
+#[derive(RuntimeDebug, Encode, Decode, Clone, PartialEq, Eq, Default, TypeInfo, MaxEncodedLen)]
+pub struct TwapCheckpoint<BlockNumber> {
+	/// Sum of the prices of all blocks before `block`.
+	pub cumulative_price: CumulativePrice,
+	/// The price at the end of `block`. Stays constant until the next checkpoint.
+	pub price: Price,
+	/// The block the checkpoint was recorded in.
+	pub block: BlockNumber,
+}
+
+impl<BlockNumber> TwapCheckpoint<BlockNumber>
+where
+	BlockNumber: AtLeast32BitUnsigned + Copy + UniqueSaturatedInto<u64>,
+{
+	/// Return the cumulative price at the start of `block`, i.e. including the prices of all blocks
+	/// before `block`.
+	pub fn cumulative_at(&self, block: BlockNumber) -> CumulativePrice {
+		let blocks = block.saturating_sub(self.block).saturated_into::<u64>();
+		accumulate_price(self.cumulative_price, self.price, blocks)
+	}
+}
+
+/// Bookkeeping of the ring buffer of TWAP checkpoints of an oracle.
+#[derive(RuntimeDebug, Encode, Decode, Clone, Copy, PartialEq, Eq, Default, TypeInfo, MaxEncodedLen)]
+pub struct TwapBuffer {
+	/// Index of the newest checkpoint.
+	pub head: u32,
+	/// Number of checkpoints in the buffer.
+	pub len: u32,
+}
+
+impl TwapBuffer {
+	/// Return the buffer after pushing a new checkpoint, overwriting the oldest one if the buffer
+	/// is full.
+	pub fn pushed(self, capacity: u32) -> Self {
+		Self {
+			head: self.head.saturating_add(1) % capacity.max(1),
+			len: self.len.saturating_add(1).min(capacity),
+		}
+	}
+
+	/// Return the index of the checkpoint at `position`, where position `0` is the oldest
+	/// checkpoint.
+	pub fn index(&self, position: u32, capacity: u32) -> u32 {
+		let capacity = capacity.max(1);
+		let oldest = self
+			.head
+			.saturating_add(capacity)
+			.saturating_sub(self.len.saturating_sub(1))
+			% capacity;
+		oldest.saturating_add(position) % capacity
+	}
+}
+
 impl<BlockNumber> From<(Price, Volume<Balance>, Liquidity<Balance>, BlockNumber)> for OracleEntry<BlockNumber> {
 	fn from((price, volume, liquidity, updated_at): (Price, Volume<Balance>, Liquidity<Balance>, BlockNumber)) -> Self {
 		Self {
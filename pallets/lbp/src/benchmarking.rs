@@ -36,6 +36,21 @@ const FINAL_WEIGHT: LBPWeight = 90_000_000;
 
 const DEFAULT_FEE: (u32, u32) = (2, 1_000);
 
+// the most expensive weight curve to calculate
+const WEIGHT_CURVE: WeightCurveType = WeightCurveType::ExponentialDecay {
+	rate: (MAX_EXPONENTIAL_DECAY_RATE, 1),
+};
+
+const PURCHASE_CAP: Balance = 1_000_000_000;
+
+fn sale_vesting<T: Config>() -> SaleVesting<BlockNumberFor<T>> {
+	SaleVesting {
+		start: BlockNumberFor::<T>::from(100_u32),
+		period: BlockNumberFor::<T>::from(10_u32),
+		period_count: 10,
+	}
+}
+
 fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
 	let caller: T::AccountId = account(name, index, SEED);
 	T::MultiCurrency::update_balance(ASSET_HDX, &caller, 1_000_000_000_000_000).unwrap();
@@ -49,7 +64,7 @@ benchmarks! {
 		let caller = funded_account::<T>("caller", 0);
 		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_B_ID);
 
-	}: _(RawOrigin::Root, caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, caller, 0)
+	}: _(RawOrigin::Root, caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, caller, 0, Some(PURCHASE_CAP), true, Some(sale_vesting::<T>()))
 	verify {
 		assert!(PoolData::<T>::contains_key(&pool_id));
 	}
@@ -62,25 +77,30 @@ benchmarks! {
 		let new_end = Some(BlockNumberFor::<T>::from(100_u32));
 		let new_initial_weight = 45_250_600;
 		let new_final_weight = 55_250_600;
+		let new_weight_curve = WEIGHT_CURVE;
 		let fee = (5, 1000);
 
-		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, fee, caller.clone(), 0)?;
+		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, fee, caller.clone(), 0, None, false, None)?;
 		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
 
-	}: _(RawOrigin::Signed(caller.clone()), pool_id.clone(), Some(caller.clone()), new_start, new_end, Some(new_initial_weight), Some(new_final_weight), Some(DEFAULT_FEE), Some(fee_collector), Some(1))
+	}: _(RawOrigin::Signed(caller.clone()), pool_id.clone(), Some(caller.clone()), new_start, new_end, Some(new_initial_weight), Some(new_final_weight), Some(new_weight_curve), Some(DEFAULT_FEE), Some(fee_collector), Some(1), Some(Some(PURCHASE_CAP)), Some(true), Some(Some(sale_vesting::<T>())))
 	verify {
 		let pool_data = LBP::<T>::pool_data(pool_id).unwrap();
 		assert_eq!(pool_data.start, new_start);
 		assert_eq!(pool_data.end, new_end);
 		assert_eq!(pool_data.initial_weight, new_initial_weight);
 		assert_eq!(pool_data.final_weight, new_final_weight);
+		assert_eq!(pool_data.weight_curve, new_weight_curve);
+		assert_eq!(pool_data.purchase_cap, Some(PURCHASE_CAP));
+		assert!(pool_data.allowlist);
+		assert_eq!(pool_data.vesting, Some(sale_vesting::<T>()));
 	}
 
 	add_liquidity {
 		let caller = funded_account::<T>("caller", 0);
 		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_B_ID);
 
-		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, caller.clone(), 0)?;
+		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, caller.clone(), 0, None, false, None)?;
 		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
 
 	}: _(RawOrigin::Signed(caller), (ASSET_A_ID, 1_000_000_000_u128), (ASSET_B_ID, 2_000_000_000_u128))
@@ -93,7 +113,7 @@ benchmarks! {
 		let caller = funded_account::<T>("caller", 0);
 		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_B_ID);
 
-		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, caller.clone(), 0)?;
+		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, caller.clone(), 0, None, false, None)?;
 		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
 
 	}: _(RawOrigin::Signed(caller.clone()), pool_id.clone())
@@ -113,20 +133,21 @@ benchmarks! {
 
 		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_B_ID);
 
-		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, fee_collector, 0)?;
+		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WEIGHT_CURVE, DEFAULT_FEE, fee_collector, 0, None, false, None)?;
 		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
 
 		let start = BlockNumberFor::<T>::from(1u32);
 		let end = BlockNumberFor::<T>::from(11u32);
 
-		LBP::<T>::update_pool_data(RawOrigin::Signed(caller.clone()).into(), pool_id, None, Some(start), Some(end), None, None, None, None, None)?;
+		LBP::<T>::update_pool_data(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), None, Some(start), Some(end), None, None, None, None, None, None, Some(Some(PURCHASE_CAP)), Some(true), Some(Some(sale_vesting::<T>())))?;
+		LBP::<T>::add_to_allowlist(RawOrigin::Signed(caller.clone()).into(), pool_id, caller.clone())?;
 
 		frame_system::Pallet::<T>::set_block_number(BlockNumberFor::<T>::from(2u32));
 
 	}: _(RawOrigin::Signed(caller.clone()), asset_in, asset_out, amount, max_limit)
 	verify{
 		assert_eq!(T::MultiCurrency::free_balance(asset_in, &caller), 999998900000000);
-		assert_eq!(T::MultiCurrency::free_balance(asset_out, &caller), 999998069275212);
+		assert_eq!(T::MultiCurrency::free_balance(asset_out, &caller), 999998314854162);
 	}
 
 	buy {
@@ -138,20 +159,81 @@ benchmarks! {
 		let max_limit: Balance = 1_000_000_000;
 		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_B_ID);
 
-		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, fee_collector, 0)?;
+		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WEIGHT_CURVE, DEFAULT_FEE, fee_collector, 0, None, false, None)?;
 		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
 
 		let start = BlockNumberFor::<T>::from(1u32);
 		let end = BlockNumberFor::<T>::from(11u32);
 
-		LBP::<T>::update_pool_data(RawOrigin::Signed(caller.clone()).into(), pool_id, None, Some(start), Some(end), None, None, None, None, None)?;
+		LBP::<T>::update_pool_data(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), None, Some(start), Some(end), None, None, None, None, None, None, Some(Some(PURCHASE_CAP)), Some(true), Some(Some(sale_vesting::<T>())))?;
+		LBP::<T>::add_to_allowlist(RawOrigin::Signed(caller.clone()).into(), pool_id, caller.clone())?;
 
 		frame_system::Pallet::<T>::set_block_number(BlockNumberFor::<T>::from(2u32));
 
 	}: _(RawOrigin::Signed(caller.clone()), asset_out, asset_in, amount, max_limit)
 	verify{
 		assert_eq!(T::MultiCurrency::free_balance(asset_out, &caller), 999998100000000);
-		assert_eq!(T::MultiCurrency::free_balance(asset_in, &caller), 999998851241411);
+		assert_eq!(T::MultiCurrency::free_balance(asset_in, &caller), 999998971048760);
+	}
+
+	add_to_allowlist {
+		let caller = funded_account::<T>("caller", 0);
+		let buyer: T::AccountId = account("buyer", 0, SEED);
+		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_B_ID);
+
+		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, caller.clone(), 0, None, true, None)?;
+		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
+
+	}: _(RawOrigin::Signed(caller), pool_id.clone(), buyer.clone())
+	verify {
+		assert!(Allowlist::<T>::contains_key(&pool_id, &buyer));
+		assert_eq!(LBP::<T>::allowlist_length(&pool_id), 1);
+	}
+
+	remove_from_allowlist {
+		let caller = funded_account::<T>("caller", 0);
+		let buyer: T::AccountId = account("buyer", 0, SEED);
+		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_B_ID);
+
+		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, caller.clone(), 0, None, true, None)?;
+		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
+
+		LBP::<T>::add_to_allowlist(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), buyer.clone())?;
+
+	}: _(RawOrigin::Signed(caller), pool_id.clone(), buyer.clone())
+	verify {
+		assert!(!Allowlist::<T>::contains_key(&pool_id, &buyer));
+		assert_eq!(LBP::<T>::allowlist_length(&pool_id), 0);
+	}
+
+	claim_vested {
+		let caller = funded_account::<T>("caller", 0);
+		let fee_collector = funded_account::<T>("fee_collector", 0);
+		let asset_in: AssetId = ASSET_A_ID;
+		let asset_out: AssetId = ASSET_B_ID;
+		let amount : Balance = 100_000_000;
+		let max_limit: Balance = 1_000_000_000;
+		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_B_ID);
+
+		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WEIGHT_CURVE, DEFAULT_FEE, fee_collector, 0, None, false, Some(sale_vesting::<T>()))?;
+		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
+
+		let start = BlockNumberFor::<T>::from(1u32);
+		let end = BlockNumberFor::<T>::from(11u32);
+
+		LBP::<T>::update_pool_data(RawOrigin::Signed(caller.clone()).into(), pool_id, None, Some(start), Some(end), None, None, None, None, None, None, None, None, None)?;
+
+		frame_system::Pallet::<T>::set_block_number(BlockNumberFor::<T>::from(2u32));
+
+		LBP::<T>::buy(RawOrigin::Signed(caller.clone()).into(), asset_out, asset_in, amount, max_limit)?;
+
+		// half of the vesting periods have passed
+		frame_system::Pallet::<T>::set_block_number(BlockNumberFor::<T>::from(150u32));
+
+	}: _(RawOrigin::Signed(caller.clone()), asset_out)
+	verify {
+		assert_eq!(T::LockedBalance::get_by_lock(VESTING_LOCK_ID, asset_out, caller.clone()), amount / 2);
+		assert!(VestedPurchases::<T>::contains_key(&caller, asset_out));
 	}
 
 	// The following benchmarks should have similar LBP setup as the corresponding benchmarks
@@ -169,13 +251,14 @@ benchmarks! {
 
 		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_B_ID);
 
-		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, fee_collector, 0)?;
+		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WEIGHT_CURVE, DEFAULT_FEE, fee_collector, 0, None, false, None)?;
 		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
 
 		let start = BlockNumberFor::<T>::from(1u32);
 		let end = BlockNumberFor::<T>::from(11u32);
 
-		LBP::<T>::update_pool_data(RawOrigin::Signed(caller.clone()).into(), pool_id, None, Some(start), Some(end), None, None, None, None, None)?;
+		LBP::<T>::update_pool_data(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), None, Some(start), Some(end), None, None, None, None, None, None, Some(Some(PURCHASE_CAP)), Some(true), Some(Some(sale_vesting::<T>())))?;
+		LBP::<T>::add_to_allowlist(RawOrigin::Signed(caller.clone()).into(), pool_id, caller.clone())?;
 
 		frame_system::Pallet::<T>::set_block_number(BlockNumberFor::<T>::from(2u32));
 
@@ -190,7 +273,7 @@ benchmarks! {
 	verify{
 		if e != 0 {
 			assert_eq!(T::MultiCurrency::free_balance(asset_in, &caller), 999998900000000);
-			assert_eq!(T::MultiCurrency::free_balance(asset_out, &caller), 999998069275212);
+			assert_eq!(T::MultiCurrency::free_balance(asset_out, &caller), 999998314854162);
 		}
 	}
 
@@ -207,13 +290,14 @@ benchmarks! {
 
 		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_B_ID);
 
-		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, fee_collector, 0)?;
+		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WEIGHT_CURVE, DEFAULT_FEE, fee_collector, 0, None, false, None)?;
 		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
 
 		let start = BlockNumberFor::<T>::from(1u32);
 		let end = BlockNumberFor::<T>::from(11u32);
 
-		LBP::<T>::update_pool_data(RawOrigin::Signed(caller.clone()).into(), pool_id, None, Some(start), Some(end), None, None, None, None, None)?;
+		LBP::<T>::update_pool_data(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), None, Some(start), Some(end), None, None, None, None, None, None, Some(Some(PURCHASE_CAP)), Some(true), Some(Some(sale_vesting::<T>())))?;
+		LBP::<T>::add_to_allowlist(RawOrigin::Signed(caller.clone()).into(), pool_id, caller.clone())?;
 
 		frame_system::Pallet::<T>::set_block_number(BlockNumberFor::<T>::from(2u32));
 
@@ -228,7 +312,7 @@ benchmarks! {
 	verify{
 		if e != 0 {
 			assert_eq!(T::MultiCurrency::free_balance(asset_out, &caller), 999998100000000);
-			assert_eq!(T::MultiCurrency::free_balance(asset_in, &caller), 999998851241411);
+			assert_eq!(T::MultiCurrency::free_balance(asset_in, &caller), 999998971048760);
 		}
 	}
 
@@ -243,13 +327,14 @@ benchmarks! {
 
 		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_B_ID);
 
-		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, fee_collector, 0)?;
+		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WEIGHT_CURVE, DEFAULT_FEE, fee_collector, 0, None, false, None)?;
 		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
 
 		let start = BlockNumberFor::<T>::from(1u32);
 		let end = BlockNumberFor::<T>::from(11u32);
 
-		LBP::<T>::update_pool_data(RawOrigin::Signed(caller).into(), pool_id, None, Some(start), Some(end), None, None, None, None, None)?;
+		LBP::<T>::update_pool_data(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), None, Some(start), Some(end), None, None, None, None, None, None, Some(Some(PURCHASE_CAP)), Some(true), None)?;
+		LBP::<T>::add_to_allowlist(RawOrigin::Signed(caller.clone()).into(), pool_id, caller)?;
 
 		frame_system::Pallet::<T>::set_block_number(BlockNumberFor::<T>::from(2u32));
 
@@ -268,13 +353,14 @@ benchmarks! {
 
 		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_B_ID);
 
-		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, fee_collector, 0)?;
+		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WEIGHT_CURVE, DEFAULT_FEE, fee_collector, 0, None, false, None)?;
 		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
 
 		let start = BlockNumberFor::<T>::from(1u32);
 		let end = BlockNumberFor::<T>::from(11u32);
 
-		LBP::<T>::update_pool_data(RawOrigin::Signed(caller).into(), pool_id, None, Some(start), Some(end), None, None, None, None, None)?;
+		LBP::<T>::update_pool_data(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), None, Some(start), Some(end), None, None, None, None, None, None, Some(Some(PURCHASE_CAP)), Some(true), None)?;
+		LBP::<T>::add_to_allowlist(RawOrigin::Signed(caller.clone()).into(), pool_id, caller)?;
 
 		frame_system::Pallet::<T>::set_block_number(BlockNumberFor::<T>::from(2u32));
 
@@ -298,6 +384,9 @@ mod tests {
 			assert_ok!(Pallet::<Test>::test_benchmark_remove_liquidity());
 			assert_ok!(Pallet::<Test>::test_benchmark_sell());
 			assert_ok!(Pallet::<Test>::test_benchmark_buy());
+			assert_ok!(Pallet::<Test>::test_benchmark_add_to_allowlist());
+			assert_ok!(Pallet::<Test>::test_benchmark_remove_from_allowlist());
+			assert_ok!(Pallet::<Test>::test_benchmark_claim_vested());
 			assert_ok!(Pallet::<Test>::test_benchmark_router_execution_sell());
 			assert_ok!(Pallet::<Test>::test_benchmark_router_execution_buy());
 		});
@@ -110,6 +110,9 @@ proptest! {
 					(0, 1),
 					CHARLIE,
 					0,
+				None,
+				false,
+				None,
 				));
 				assert_ok!(LBPPallet::update_pool_data(
 					Origin::signed(ALICE),
@@ -122,6 +125,10 @@ proptest! {
 					None,
 					None,
 					None,
+					None,
+				None,
+				None,
+				None,
 				));
 
 				let block_num = 10;
@@ -170,6 +177,9 @@ proptest! {
 					(0, 1),
 					CHARLIE,
 					0,
+				None,
+				false,
+				None,
 				));
 				assert_ok!(LBPPallet::update_pool_data(
 					Origin::signed(ALICE),
@@ -182,6 +192,10 @@ proptest! {
 					None,
 					None,
 					None,
+					None,
+				None,
+				None,
+				None,
 				));
 
 				let block_num = 10;
@@ -229,6 +243,9 @@ proptest! {
 					(0, 1),
 					CHARLIE,
 					0,
+				None,
+				false,
+				None,
 				));
 				assert_ok!(LBPPallet::update_pool_data(
 					Origin::signed(ALICE),
@@ -241,6 +258,10 @@ proptest! {
 					None,
 					None,
 					None,
+					None,
+				None,
+				None,
+				None,
 				));
 
 				let block_num = 10;
@@ -288,6 +309,9 @@ proptest! {
 					(0, 1),
 					CHARLIE,
 					0,
+				None,
+				false,
+				None,
 				));
 				assert_ok!(LBPPallet::update_pool_data(
 					Origin::signed(ALICE),
@@ -300,6 +324,10 @@ proptest! {
 					None,
 					None,
 					None,
+					None,
+				None,
+				None,
+				None,
 				));
 
 				let block_num = 10;
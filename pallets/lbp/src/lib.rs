@@ -24,8 +24,9 @@
 pub use crate::types::{Amount, AssetId, AssetPair, Balance};
 use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::sp_runtime::{
-	traits::{AtLeast32BitUnsigned, BlockNumberProvider, Saturating, Zero},
-	DispatchError, RuntimeDebug,
+	helpers_128bit::multiply_by_rational_with_rounding,
+	traits::{AtLeast32BitUnsigned, BlockNumberProvider, CheckedDiv, Saturating, UniqueSaturatedInto, Zero},
+	DispatchError, PerThing, Permill, Rounding, RuntimeDebug,
 };
 use frame_support::{
 	dispatch,
@@ -33,11 +34,13 @@ use frame_support::{
 	ensure,
 	traits::{EnsureOrigin, Get, LockIdentifier},
 	transactional,
+	weights::Weight,
 };
 use frame_system::ensure_signed;
 use frame_system::pallet_prelude::BlockNumberFor;
+use hydra_dx_math::ratio::Ratio;
 use hydra_dx_math::types::LBPWeight;
-use hydradx_traits::{AMMTransfer, AssetPairAccountIdFor, CanCreatePool, LockedBalance, AMM};
+use hydradx_traits::{price::PriceBarrier, AMMTransfer, AssetPairAccountIdFor, CanCreatePool, LockedBalance, AMM};
 use orml_traits::{MultiCurrency, MultiCurrencyExtended, MultiLockableCurrency};
 
 use scale_info::TypeInfo;
@@ -61,6 +64,7 @@ pub mod weights;
 #[cfg(test)]
 mod invariants;
 
+pub mod migration;
 mod provider;
 mod trade_execution;
 pub mod types;
@@ -75,8 +79,68 @@ type PoolId<T> = <T as frame_system::Config>::AccountId;
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Default, RuntimeDebug, Encode, Decode, Copy, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub enum WeightCurveType {
+	/// Weight changes linearly from the initial to the final weight.
 	#[default]
 	Linear,
+	/// Difference between the current and the final weight decays exponentially.
+	/// Weight changes quickly at the beginning of the sale and slowly at the end.
+	/// `rate` is a rational number, higher rate makes the curve steeper.
+	ExponentialDecay { rate: (u32, u32) },
+	/// Sale is divided into `steps` equally long periods with constant weight.
+	/// Weight changes by the same amount at the beginning of every period.
+	Step { steps: u32 },
+	/// Weight changes linearly from the initial weight to `weight` at `breakpoint` fraction of the sale
+	/// and then linearly to the final weight.
+	PiecewiseLinear { breakpoint: Permill, weight: LBPWeight },
+}
+
+/// Vesting schedule of the distributed asset bought from a pool.
+///
+/// Bought amount is locked in the account of the buyer and an equal part of it is released
+/// at the end of every period.
+///
+/// Native asset is vested by `Config::NativeVesting` (`orml_vesting` in the runtime), other assets
+/// are vested by the `VESTING_LOCK_ID` lock of this pallet.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(RuntimeDebug, Encode, Decode, Copy, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct SaleVesting<BlockNumber> {
+	/// block at which the vesting starts
+	pub start: BlockNumber,
+	/// number of blocks between two vesting releases
+	pub period: BlockNumber,
+	/// number of vesting releases
+	pub period_count: u32,
+}
+
+impl<BlockNumber: AtLeast32BitUnsigned + Copy> SaleVesting<BlockNumber> {
+	/// Part of `total` which is still locked at block `now`.
+	pub fn locked_amount(&self, total: Balance, now: BlockNumber) -> Balance {
+		if self.period_count.is_zero() {
+			return Zero::zero();
+		}
+
+		let elapsed_periods: u32 = now
+			.saturating_sub(self.start)
+			.checked_div(&self.period)
+			.unwrap_or_else(Zero::zero)
+			.unique_saturated_into();
+		let remaining_periods = self.period_count.saturating_sub(elapsed_periods);
+
+		multiply_by_rational_with_rounding(total, remaining_periods.into(), self.period_count.into(), Rounding::Up)
+			.unwrap_or(total)
+	}
+}
+
+/// Distributed asset bought by an account from pools with vesting.
+///
+/// Purchases of the same asset with the same vesting schedule are merged, so an account has at most
+/// one vested purchase of an asset.
+#[derive(RuntimeDebug, Encode, Decode, Copy, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct VestedPurchase<BlockNumber> {
+	/// vesting schedule of the bought amount
+	pub vesting: SaleVesting<BlockNumber>,
+	/// total bought amount
+	pub amount: Balance,
 }
 
 /// Max weight corresponds to 100%
@@ -85,9 +149,15 @@ pub const MAX_WEIGHT: LBPWeight = 100_000_000;
 /// Max sale duration is 14 days, assuming 6 sec blocks
 pub const MAX_SALE_DURATION: u32 = (60 * 60 * 24 / 6) * 14;
 
+/// Max rate of the exponential decay weight curve
+pub const MAX_EXPONENTIAL_DECAY_RATE: u32 = 10;
+
 /// Lock Identifier for the collected fees
 pub const COLLECTOR_LOCK_ID: LockIdentifier = *b"lbpcllct";
 
+/// Lock Identifier for the vested purchases of assets other than the native asset
+pub const VESTING_LOCK_ID: LockIdentifier = *b"lbpvestd";
+
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(RuntimeDebug, Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub struct Pool<AccountId, BlockNumber: AtLeast32BitUnsigned + Copy> {
@@ -120,6 +190,15 @@ pub struct Pool<AccountId, BlockNumber: AtLeast32BitUnsigned + Copy> {
 
 	/// repayment target of the accumulated asset in fee collectors account, when this target is reached fee drops from 20% to fee
 	pub repay_target: Balance,
+
+	/// max amount of the distributed asset that can be bought by a single account
+	pub purchase_cap: Option<Balance>,
+
+	/// only accounts in the allowlist of the pool can buy the distributed asset
+	pub allowlist: bool,
+
+	/// vesting schedule of the bought distributed asset
+	pub vesting: Option<SaleVesting<BlockNumber>>,
 }
 
 impl<AccountId, BlockNumber: AtLeast32BitUnsigned + Copy> Pool<AccountId, BlockNumber> {
@@ -133,6 +212,9 @@ impl<AccountId, BlockNumber: AtLeast32BitUnsigned + Copy> Pool<AccountId, BlockN
 		fee: (u32, u32),
 		fee_collector: AccountId,
 		repay_target: Balance,
+		purchase_cap: Option<Balance>,
+		allowlist: bool,
+		vesting: Option<SaleVesting<BlockNumber>>,
 	) -> Self {
 		Pool {
 			owner: pool_owner,
@@ -145,6 +227,9 @@ impl<AccountId, BlockNumber: AtLeast32BitUnsigned + Copy> Pool<AccountId, BlockN
 			fee,
 			fee_collector,
 			repay_target,
+			purchase_cap,
+			allowlist,
+			vesting,
 		}
 	}
 }
@@ -160,17 +245,54 @@ pub trait LBPWeightCalculation<BlockNumber: AtLeast32BitUnsigned> {
 	) -> Option<LBPWeight>;
 }
 
+/// Vesting of the native asset bought from pools with vesting.
+///
+/// Purchases of other assets are vested by the lock of this pallet.
+pub trait NativeVesting<AccountId, BlockNumber> {
+	/// Transfer `amount` of the native asset from `from` to `to` and vest it by `vesting`.
+	fn vested_transfer(
+		from: &AccountId,
+		to: &AccountId,
+		amount: Balance,
+		vesting: SaleVesting<BlockNumber>,
+	) -> DispatchResult;
+
+	/// Weight of the vested transfer.
+	fn vested_transfer_weight() -> Weight;
+}
+
 pub struct LBPWeightFunction;
 impl<BlockNumber: AtLeast32BitUnsigned> LBPWeightCalculation<BlockNumber> for LBPWeightFunction {
 	fn calculate_weight(
-		_weight_curve: WeightCurveType,
+		weight_curve: WeightCurveType,
 		start: BlockNumber,
 		end: BlockNumber,
 		initial_weight: LBPWeight,
 		final_weight: LBPWeight,
 		at: BlockNumber,
 	) -> Option<LBPWeight> {
-		hydra_dx_math::lbp::calculate_linear_weights(start, end, initial_weight, final_weight, at).ok()
+		match weight_curve {
+			WeightCurveType::Linear => {
+				hydra_dx_math::lbp::calculate_linear_weights(start, end, initial_weight, final_weight, at)
+			}
+			WeightCurveType::ExponentialDecay { rate } => {
+				hydra_dx_math::lbp::calculate_exponential_weights(start, end, initial_weight, final_weight, rate, at)
+			}
+			WeightCurveType::Step { steps } => {
+				hydra_dx_math::lbp::calculate_step_weights(start, end, initial_weight, final_weight, steps, at)
+			}
+			WeightCurveType::PiecewiseLinear { breakpoint, weight } => {
+				hydra_dx_math::lbp::calculate_piecewise_linear_weights(
+					start,
+					end,
+					initial_weight,
+					final_weight,
+					&[(breakpoint, weight)],
+					at,
+				)
+			}
+		}
+		.ok()
 	}
 }
 
@@ -180,7 +302,10 @@ pub mod pallet {
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::OriginFor;
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
@@ -224,6 +349,24 @@ pub mod pallet {
 
 		/// The block number provider
 		type BlockNumberProvider: BlockNumberProvider<BlockNumber = BlockNumberFor<Self>>;
+
+		/// Price barrier to prevent adding or removing liquidity when the pool price is manipulated.
+		type PriceBarrier: PriceBarrier<Self::AccountId, AssetId, Ratio>;
+
+		/// Max number of accounts in the allowlist of a pool
+		#[pallet::constant]
+		type MaxAllowlistLength: Get<u32>;
+
+		/// Max number of purchase records removed together with a pool
+		#[pallet::constant]
+		type MaxPurchasedRemovals: Get<u32>;
+
+		/// Native asset identifier
+		#[pallet::constant]
+		type NativeAssetId: Get<AssetId>;
+
+		/// Vesting of the native asset bought from pools with vesting
+		type NativeVesting: NativeVesting<Self::AccountId, BlockNumberFor<Self>>;
 	}
 
 	#[pallet::hooks]
@@ -306,6 +449,33 @@ pub mod pallet {
 
 		/// Not more than one fee collector per asset id
 		FeeCollectorWithAssetAlreadyUsed,
+
+		/// Parameters of the weight curve are invalid
+		InvalidWeightCurve,
+
+		/// Purchase cap can not be zero
+		InvalidPurchaseCap,
+
+		/// Parameters of the vesting are invalid
+		InvalidVesting,
+
+		/// Account is not in the allowlist of the pool
+		NotAllowlisted,
+
+		/// Account is already in the allowlist of the pool
+		AlreadyAllowlisted,
+
+		/// Allowlist of the pool is full
+		AllowlistFull,
+
+		/// Account would buy more than the purchase cap of the pool
+		PurchaseCapExceeded,
+
+		/// Account has a purchase of the asset with a different vesting schedule which is not fully released
+		VestingScheduleMismatch,
+
+		/// Account has no vested purchase of the asset
+		NoVestedPurchase,
 	}
 
 	#[pallet::event]
@@ -362,6 +532,20 @@ pub mod pallet {
 			fee_asset: AssetId,
 			fee_amount: BalanceOf<T>,
 		},
+
+		/// Account was added to the allowlist of a pool.
+		AddedToAllowlist { pool: PoolId<T>, who: T::AccountId },
+
+		/// Account was removed from the allowlist of a pool.
+		RemovedFromAllowlist { pool: PoolId<T>, who: T::AccountId },
+
+		/// Vested part of a purchase was released.
+		VestedClaimed {
+			who: T::AccountId,
+			asset: AssetId,
+			amount: Balance,
+			remaining: Balance,
+		},
 	}
 
 	/// Details of a pool.
@@ -376,6 +560,44 @@ pub mod pallet {
 	pub type FeeCollectorWithAsset<T: Config> =
 		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, AssetId, bool, ValueQuery>;
 
+	/// Accounts allowed to buy the distributed asset from a pool with enabled allowlist.
+	#[pallet::storage]
+	#[pallet::getter(fn allowlist)]
+	pub type Allowlist<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, PoolId<T>, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+	/// Number of accounts in the allowlist of a pool.
+	#[pallet::storage]
+	#[pallet::getter(fn allowlist_length)]
+	pub type AllowlistLength<T: Config> = StorageMap<_, Blake2_128Concat, PoolId<T>, u32, ValueQuery>;
+
+	/// Amount of the distributed asset bought by an account from a pool with purchase cap.
+	/// Purchases are removed when the pool is destroyed. Amount is stored together with the start of the sale,
+	/// so purchases which were not removed are not counted in a following sale of the same asset pair.
+	#[pallet::storage]
+	pub type Purchased<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		PoolId<T>,
+		Blake2_128Concat,
+		T::AccountId,
+		(BlockNumberFor<T>, Balance),
+		OptionQuery,
+	>;
+
+	/// Distributed assets bought by an account from pools with vesting which are not fully released yet.
+	#[pallet::storage]
+	#[pallet::getter(fn vested_purchase)]
+	pub type VestedPurchases<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		AssetId,
+		VestedPurchase<BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
 	#[pallet::extra_constants]
 	impl<T: Config> Pallet<T> {
 		pub fn repay_fee() -> (u32, u32) {
@@ -405,12 +627,17 @@ pub mod pallet {
 		/// this should be higher than final weight
 		/// - `final_weight`: Final weight of the asset_a. 1_000_000 corresponding to 1% and 100_000_000 to 100%
 		/// this should be lower than initial weight
-		/// - `weight_curve`: The weight function used to update the LBP weights. Currently,
-		/// there is only one weight function implemented, the linear function.
+		/// - `weight_curve`: The weight function used to update the LBP weights. Linear, exponential decay,
+		/// step and piecewise linear functions are implemented.
 		/// - `fee`: The trading fee charged on every trade distributed to `fee_collector`.
 		/// - `fee_collector`: The account to which trading fees will be transferred.
 		/// - `repay_target`: The amount of tokens to repay to separate fee_collector account. Until this amount is
 		/// reached, fee will be increased to 20% and taken from the pool
+		/// - `purchase_cap`: Max amount of `asset_b` which can be bought by a single account. This parameter is optional.
+		/// - `allowlist`: If set, only accounts added to the allowlist by the pool owner can buy `asset_b`.
+		/// - `vesting`: Vesting schedule of the bought `asset_b`. Bought tokens are locked in the account of
+		/// the buyer and released by `claim_vested`, or by `NativeVesting` if `asset_b` is the native asset.
+		/// Bought tokens are not locked if not set.
 		///
 		/// Emits `PoolCreated` event when successful.
 		///
@@ -433,6 +660,9 @@ pub mod pallet {
 			fee: (u32, u32),
 			fee_collector: T::AccountId,
 			repay_target: Balance,
+			purchase_cap: Option<Balance>,
+			allowlist: bool,
+			vesting: Option<SaleVesting<BlockNumberFor<T>>>,
 		) -> DispatchResult {
 			T::CreatePoolOrigin::ensure_origin(origin)?;
 
@@ -475,6 +705,9 @@ pub mod pallet {
 				fee,
 				fee_collector.clone(),
 				repay_target,
+				purchase_cap,
+				allowlist,
+				vesting,
 			);
 
 			Self::validate_pool_data(&pool_data)?;
@@ -517,8 +750,13 @@ pub mod pallet {
 		/// - `end`: The new ending time of the sale. This parameter is optional.
 		/// - `initial_weight`: The new initial weight. This parameter is optional.
 		/// - `final_weight`: The new final weight. This parameter is optional.
+		/// - `weight_curve`: The new weight function used to update the LBP weights. This parameter is optional.
 		/// - `fee`: The new trading fee charged on every trade. This parameter is optional.
 		/// - `fee_collector`: The new receiver of trading fees. This parameter is optional.
+		/// - `repay_target`: The new repayment target of the accumulated asset. This parameter is optional.
+		/// - `purchase_cap`: The new purchase cap, `Some(None)` removes the cap. This parameter is optional.
+		/// - `allowlist`: Enable or disable the allowlist. This parameter is optional.
+		/// - `vesting`: The new vesting schedule, `Some(None)` removes the vesting. This parameter is optional.
 		///
 		/// Emits `PoolUpdated` event when successful.
 		#[pallet::call_index(1)]
@@ -531,9 +769,13 @@ pub mod pallet {
 			end: Option<BlockNumberFor<T>>,
 			initial_weight: Option<LBPWeight>,
 			final_weight: Option<LBPWeight>,
+			weight_curve: Option<WeightCurveType>,
 			fee: Option<(u32, u32)>,
 			fee_collector: Option<T::AccountId>,
 			repay_target: Option<Balance>,
+			purchase_cap: Option<Option<Balance>>,
+			allowlist: Option<bool>,
+			vesting: Option<Option<SaleVesting<BlockNumberFor<T>>>>,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
@@ -545,8 +787,11 @@ pub mod pallet {
 					start.is_some()
 						|| end.is_some() || initial_weight.is_some()
 						|| final_weight.is_some()
+						|| weight_curve.is_some()
 						|| fee.is_some() || fee_collector.is_some()
-						|| repay_target.is_some(),
+						|| repay_target.is_some()
+						|| purchase_cap.is_some()
+						|| allowlist.is_some() || vesting.is_some(),
 					Error::<T>::NothingToUpdate
 				);
 
@@ -563,6 +808,8 @@ pub mod pallet {
 
 				pool.final_weight = final_weight.unwrap_or(pool.final_weight);
 
+				pool.weight_curve = weight_curve.unwrap_or(pool.weight_curve);
+
 				pool.fee = fee.unwrap_or(pool.fee);
 
 				// Handle update of fee collector - validate and replace old fee collector
@@ -585,6 +832,12 @@ pub mod pallet {
 
 				pool.repay_target = repay_target.unwrap_or(pool.repay_target);
 
+				pool.purchase_cap = purchase_cap.unwrap_or(pool.purchase_cap);
+
+				pool.allowlist = allowlist.unwrap_or(pool.allowlist);
+
+				pool.vesting = vesting.unwrap_or(pool.vesting);
+
 				Self::validate_pool_data(pool)?;
 
 				Self::deposit_event(Event::PoolUpdated {
@@ -608,7 +861,10 @@ pub mod pallet {
 		///
 		/// Emits `LiquidityAdded` event when successful.
 		#[pallet::call_index(2)]
-		#[pallet::weight(<T as Config>::WeightInfo::add_liquidity())]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::add_liquidity()
+				.saturating_add(T::PriceBarrier::ensure_price_weight())
+		)]
 		pub fn add_liquidity(
 			origin: OriginFor<T>,
 			amount_a: (AssetId, BalanceOf<T>),
@@ -629,6 +885,13 @@ pub mod pallet {
 				Error::<T>::CannotAddZeroLiquidity
 			);
 
+			T::PriceBarrier::ensure_price(
+				&who,
+				asset_a,
+				asset_b,
+				Self::spot_price_ratio(&pool_id, &pool_data, asset_a, asset_b)?,
+			)?;
+
 			if !amount_a.is_zero() {
 				ensure!(
 					T::MultiCurrency::free_balance(asset_a, &who) >= amount_a,
@@ -669,7 +932,12 @@ pub mod pallet {
 		///
 		/// Emits 'LiquidityRemoved' when successful.
 		#[pallet::call_index(3)]
-		#[pallet::weight(<T as Config>::WeightInfo::remove_liquidity())]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::remove_liquidity()
+				.saturating_add(T::PriceBarrier::ensure_price_weight())
+				.saturating_add(T::DbWeight::get().writes(T::MaxAllowlistLength::get().into()))
+				.saturating_add(T::DbWeight::get().writes(T::MaxPurchasedRemovals::get().into()))
+		)]
 		pub fn remove_liquidity(origin: OriginFor<T>, pool_id: PoolId<T>) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
@@ -681,6 +949,13 @@ pub mod pallet {
 
 			let (asset_a, asset_b) = pool_data.assets;
 
+			T::PriceBarrier::ensure_price(
+				&who,
+				asset_a,
+				asset_b,
+				Self::spot_price_ratio(&pool_id, &pool_data, asset_a, asset_b)?,
+			)?;
+
 			let amount_a = T::MultiCurrency::free_balance(asset_a, &pool_id);
 			let amount_b = T::MultiCurrency::free_balance(asset_b, &pool_id);
 
@@ -693,6 +968,9 @@ pub mod pallet {
 
 			<FeeCollectorWithAsset<T>>::remove(pool_data.fee_collector, pool_data.assets.0);
 			<PoolData<T>>::remove(&pool_id);
+			let _ = <Allowlist<T>>::clear_prefix(&pool_id, T::MaxAllowlistLength::get(), None);
+			<AllowlistLength<T>>::remove(&pool_id);
+			let _ = <Purchased<T>>::clear_prefix(&pool_id, T::MaxPurchasedRemovals::get(), None);
 
 			Self::deposit_event(Event::LiquidityRemoved {
 				who: pool_id,
@@ -720,7 +998,7 @@ pub mod pallet {
 		///
 		/// Emits `SellExecuted` when successful.
 		#[pallet::call_index(4)]
-		#[pallet::weight(<T as Config>::WeightInfo::sell())]
+		#[pallet::weight(<T as Config>::WeightInfo::sell().saturating_add(T::NativeVesting::vested_transfer_weight()))]
 		pub fn sell(
 			origin: OriginFor<T>,
 			asset_in: AssetId,
@@ -750,7 +1028,7 @@ pub mod pallet {
 		///
 		/// Emits `BuyExecuted` when successful.
 		#[pallet::call_index(5)]
-		#[pallet::weight(<T as Config>::WeightInfo::buy())]
+		#[pallet::weight(<T as Config>::WeightInfo::buy().saturating_add(T::NativeVesting::vested_transfer_weight()))]
 		pub fn buy(
 			origin: OriginFor<T>,
 			asset_out: AssetId,
@@ -764,6 +1042,112 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Add an account to the allowlist of a pool.
+		///
+		/// Only allowlisted accounts can buy the distributed asset from a pool with enabled allowlist.
+		///
+		/// The dispatch origin for this call must be signed by the pool owner.
+		///
+		/// Parameters:
+		/// - `pool_id`: The identifier of the pool.
+		/// - `who`: The account to add to the allowlist.
+		///
+		/// Emits `AddedToAllowlist` event when successful.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_to_allowlist())]
+		pub fn add_to_allowlist(origin: OriginFor<T>, pool_id: PoolId<T>, who: T::AccountId) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+
+			let pool_data = <PoolData<T>>::try_get(&pool_id).map_err(|_| Error::<T>::PoolNotFound)?;
+
+			ensure!(owner == pool_data.owner, Error::<T>::NotOwner);
+
+			ensure!(
+				!<Allowlist<T>>::contains_key(&pool_id, &who),
+				Error::<T>::AlreadyAllowlisted
+			);
+
+			<AllowlistLength<T>>::try_mutate(&pool_id, |length| -> DispatchResult {
+				ensure!(*length < T::MaxAllowlistLength::get(), Error::<T>::AllowlistFull);
+				*length = length.checked_add(1).ok_or(Error::<T>::Overflow)?;
+				Ok(())
+			})?;
+
+			<Allowlist<T>>::insert(&pool_id, &who, ());
+
+			Self::deposit_event(Event::AddedToAllowlist { pool: pool_id, who });
+
+			Ok(())
+		}
+
+		/// Remove an account from the allowlist of a pool.
+		///
+		/// The dispatch origin for this call must be signed by the pool owner.
+		///
+		/// Parameters:
+		/// - `pool_id`: The identifier of the pool.
+		/// - `who`: The account to remove from the allowlist.
+		///
+		/// Emits `RemovedFromAllowlist` event when successful.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_from_allowlist())]
+		pub fn remove_from_allowlist(origin: OriginFor<T>, pool_id: PoolId<T>, who: T::AccountId) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+
+			let pool_data = <PoolData<T>>::try_get(&pool_id).map_err(|_| Error::<T>::PoolNotFound)?;
+
+			ensure!(owner == pool_data.owner, Error::<T>::NotOwner);
+
+			ensure!(<Allowlist<T>>::contains_key(&pool_id, &who), Error::<T>::NotAllowlisted);
+
+			<Allowlist<T>>::remove(&pool_id, &who);
+			<AllowlistLength<T>>::mutate(&pool_id, |length| *length = length.saturating_sub(1));
+
+			Self::deposit_event(Event::RemovedFromAllowlist { pool: pool_id, who });
+
+			Ok(())
+		}
+
+		/// Release the vested part of the distributed asset bought from pools with vesting.
+		///
+		/// Vested amount is unlocked in the account of the caller. The purchase is removed
+		/// when it is fully released.
+		///
+		/// Purchases of the native asset are released by `NativeVesting` and can't be claimed here.
+		///
+		/// Parameters:
+		/// - `asset`: The identifier of the bought asset.
+		///
+		/// Emits `VestedClaimed` event when successful.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::claim_vested())]
+		pub fn claim_vested(origin: OriginFor<T>, asset: AssetId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let purchase = <VestedPurchases<T>>::get(&who, asset).ok_or(Error::<T>::NoVestedPurchase)?;
+
+			let locked_before = T::LockedBalance::get_by_lock(VESTING_LOCK_ID, asset, who.clone());
+			let remaining = purchase
+				.vesting
+				.locked_amount(purchase.amount, T::BlockNumberProvider::current_block_number());
+
+			if remaining.is_zero() {
+				T::MultiCurrency::remove_lock(VESTING_LOCK_ID, asset, &who)?;
+				<VestedPurchases<T>>::remove(&who, asset);
+			} else {
+				T::MultiCurrency::set_lock(VESTING_LOCK_ID, asset, &who, remaining)?;
+			}
+
+			Self::deposit_event(Event::VestedClaimed {
+				who,
+				asset,
+				amount: locked_before.saturating_sub(remaining),
+				remaining,
+			});
+
+			Ok(())
+		}
 	}
 }
 
@@ -824,8 +1208,36 @@ impl<T: Config> Pallet<T> {
 			Error::<T>::InvalidWeight
 		);
 
+		ensure!(
+			match pool_data.weight_curve {
+				WeightCurveType::Linear => true,
+				WeightCurveType::ExponentialDecay { rate } =>
+					!rate.0.is_zero()
+						&& !rate.1.is_zero() && u64::from(rate.0)
+						<= u64::from(rate.1).saturating_mul(MAX_EXPONENTIAL_DECAY_RATE.into()),
+				WeightCurveType::Step { steps } => !steps.is_zero(),
+				// same limits as for initial and final weights apply to the weight at the breakpoint
+				WeightCurveType::PiecewiseLinear { breakpoint, weight } =>
+					!breakpoint.is_zero()
+						&& breakpoint < Permill::one()
+						&& weight < MAX_WEIGHT && weight >= MAX_WEIGHT / 50,
+			},
+			Error::<T>::InvalidWeightCurve
+		);
+
 		ensure!(!pool_data.fee.1.is_zero(), Error::<T>::FeeAmountInvalid);
 
+		ensure!(
+			pool_data.purchase_cap.map_or(true, |cap| !cap.is_zero()),
+			Error::<T>::InvalidPurchaseCap
+		);
+
+		ensure!(
+			pool_data.vesting.map_or(true, |vesting| !vesting.period.is_zero()
+				&& !vesting.period_count.is_zero()),
+			Error::<T>::InvalidVesting
+		);
+
 		Ok(())
 	}
 
@@ -847,6 +1259,39 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Weight adjusted spot price of `asset_b` denominated in `asset_a`.
+	fn spot_price_ratio(
+		pool_id: &PoolId<T>,
+		pool_data: &Pool<T::AccountId, BlockNumberFor<T>>,
+		asset_a: AssetId,
+		asset_b: AssetId,
+	) -> Result<Ratio, DispatchError> {
+		// weights outside of the sale are the weights at the nearest end of the sale
+		let weights = match (pool_data.start, pool_data.end) {
+			(Some(start), Some(end)) => {
+				let now = T::BlockNumberProvider::current_block_number();
+				Self::calculate_weights(pool_data, now.clamp(start, end))?
+			}
+			_ => (
+				pool_data.initial_weight,
+				MAX_WEIGHT.saturating_sub(pool_data.initial_weight),
+			),
+		};
+		let (weight_a, weight_b) = if asset_a == pool_data.assets.0 {
+			weights
+		} else {
+			(weights.1, weights.0)
+		};
+
+		hydra_dx_math::lbp::calculate_spot_price_ratio(
+			T::MultiCurrency::free_balance(asset_a, pool_id),
+			T::MultiCurrency::free_balance(asset_b, pool_id),
+			weight_a,
+			weight_b,
+		)
+		.map_err(|_| Error::<T>::InvalidWeight.into())
+	}
+
 	/// return true if now is in interval <pool.start, pool.end>
 	fn is_pool_running(pool_data: &Pool<T::AccountId, BlockNumberFor<T>>) -> bool {
 		let now = T::BlockNumberProvider::current_block_number();
@@ -876,6 +1321,73 @@ impl<T: Config> Pallet<T> {
 		Self::collected_fees(pool) < pool.repay_target
 	}
 
+	/// returns amount of the distributed asset bought by `who` in the current sale of the pool
+	pub fn purchased(
+		pool_id: &PoolId<T>,
+		pool_data: &Pool<T::AccountId, BlockNumberFor<T>>,
+		who: &T::AccountId,
+	) -> Balance {
+		match <Purchased<T>>::get(pool_id, who) {
+			// purchases from a previous sale of the same asset pair are not counted
+			Some((start, amount)) if Some(start) == pool_data.start => amount,
+			_ => Zero::zero(),
+		}
+	}
+
+	/// checks allowlist and purchase cap of the pool when `who` buys `amount` of the distributed asset
+	fn ensure_purchase_allowed(
+		who: &T::AccountId,
+		pool_id: &PoolId<T>,
+		pool_data: &Pool<T::AccountId, BlockNumberFor<T>>,
+		amount: Balance,
+	) -> DispatchResult {
+		ensure!(
+			!pool_data.allowlist || <Allowlist<T>>::contains_key(pool_id, who),
+			Error::<T>::NotAllowlisted
+		);
+
+		if let Some(purchase_cap) = pool_data.purchase_cap {
+			let purchased = Self::purchased(pool_id, pool_data, who)
+				.checked_add(amount)
+				.ok_or(Error::<T>::Overflow)?;
+
+			ensure!(purchased <= purchase_cap, Error::<T>::PurchaseCapExceeded);
+		}
+
+		Ok(())
+	}
+
+	/// locks bought `amount` of `asset` in the account of the buyer until it is released by the vesting schedule
+	fn vest_purchase(
+		who: &T::AccountId,
+		asset: AssetId,
+		amount: Balance,
+		vesting: SaleVesting<BlockNumberFor<T>>,
+	) -> DispatchResult {
+		let now = T::BlockNumberProvider::current_block_number();
+
+		<VestedPurchases<T>>::try_mutate(who, asset, |maybe_purchase| -> DispatchResult {
+			let total = match maybe_purchase {
+				Some(purchase) if purchase.vesting == vesting => {
+					purchase.amount.checked_add(amount).ok_or(Error::<T>::Overflow)?
+				}
+				// purchase with a different vesting schedule can be replaced only when it is fully released
+				Some(purchase) => {
+					ensure!(
+						purchase.vesting.locked_amount(purchase.amount, now).is_zero(),
+						Error::<T>::VestingScheduleMismatch
+					);
+					amount
+				}
+				None => amount,
+			};
+
+			*maybe_purchase = Some(VestedPurchase { vesting, amount: total });
+
+			T::MultiCurrency::set_lock(VESTING_LOCK_ID, asset, who, vesting.locked_amount(total, now))
+		})
+	}
+
 	#[transactional]
 	fn execute_trade(transfer: &AMMTransfer<T::AccountId, AssetId, AssetPair, Balance>) -> DispatchResult {
 		let pool_account = Self::get_pair_id(transfer.assets);
@@ -888,12 +1400,39 @@ impl<T: Config> Pallet<T> {
 			&pool_account,
 			transfer.amount,
 		)?;
-		T::MultiCurrency::transfer(
-			transfer.assets.asset_out,
-			&pool_account,
-			&transfer.origin,
-			transfer.amount_b,
-		)?;
+
+		// Distributed asset is bought from the pool
+		let is_purchase = transfer.assets.asset_out == pool.assets.1;
+
+		match pool.vesting.filter(|_| is_purchase) {
+			// native asset is vested by `NativeVesting` instead of the lock of this pallet
+			Some(vesting) if transfer.assets.asset_out == T::NativeAssetId::get() => {
+				T::NativeVesting::vested_transfer(&pool_account, &transfer.origin, transfer.amount_b, vesting)?;
+			}
+			maybe_vesting => {
+				T::MultiCurrency::transfer(
+					transfer.assets.asset_out,
+					&pool_account,
+					&transfer.origin,
+					transfer.amount_b,
+				)?;
+
+				if let Some(vesting) = maybe_vesting {
+					Self::vest_purchase(&transfer.origin, transfer.assets.asset_out, transfer.amount_b, vesting)?;
+				}
+			}
+		}
+
+		if is_purchase && pool.purchase_cap.is_some() {
+			let purchased = Self::purchased(&pool_account, &pool, &transfer.origin)
+				.checked_add(transfer.amount_b)
+				.ok_or(Error::<T>::Overflow)?;
+			<Purchased<T>>::insert(
+				&pool_account,
+				&transfer.origin,
+				(pool.start.unwrap_or_default(), purchased),
+			);
+		}
 
 		// Fee is deducted from the sent out amount of accumulated asset and transferred to the fee collector
 		let (fee_asset, fee_amount) = transfer.fee;
@@ -1047,6 +1586,8 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, BalanceOf<T>> for Pallet<T
 
 			ensure!(min_bought <= amount_out, Error::<T>::TradingLimitReached);
 
+			Self::ensure_purchase_allowed(who, &pool_id, &pool_data, amount_out)?;
+
 			let amount_without_fee = amount.checked_sub(fee).ok_or(Error::<T>::Overflow)?;
 
 			Ok(AMMTransfer {
@@ -1221,6 +1762,8 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, BalanceOf<T>> for Pallet<T
 
 			ensure!(max_sold >= calculated_in, Error::<T>::TradingLimitReached);
 
+			Self::ensure_purchase_allowed(who, &pool_id, &pool_data, amount)?;
+
 			Ok(AMMTransfer {
 				origin: who.clone(),
 				assets,
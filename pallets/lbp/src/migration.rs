@@ -0,0 +1,93 @@
+// This file is part of HydraDX-node.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use frame_support::traits::StorageVersion;
+
+pub mod v0 {
+	use super::*;
+
+	#[derive(RuntimeDebug, Encode, Decode, Clone, PartialEq, Eq, TypeInfo)]
+	pub struct Pool<AccountId, BlockNumber: AtLeast32BitUnsigned + Copy> {
+		pub owner: AccountId,
+		pub start: Option<BlockNumber>,
+		pub end: Option<BlockNumber>,
+		pub assets: (AssetId, AssetId),
+		pub initial_weight: LBPWeight,
+		pub final_weight: LBPWeight,
+		pub weight_curve: WeightCurveType,
+		pub fee: (u32, u32),
+		pub fee_collector: AccountId,
+		pub repay_target: Balance,
+	}
+}
+
+/// Migrate the pallet storage to v1.
+/// This migration adds purchase cap, allowlist and vesting parameters to all the existing pools.
+/// Existing pools have no purchase cap, no allowlist and no vesting.
+pub fn migrate_to_v1<T: Config>() -> Weight {
+	let on_chain_storage_version = StorageVersion::get::<Pallet<T>>();
+	//offset for storage version read
+	let mut weight: Weight = T::DbWeight::get().reads(1);
+
+	log::info!(
+		target: "runtime::lbp",
+		"Running migration storage v1 for lbp with storage version {:?}",
+		on_chain_storage_version,
+	);
+
+	if on_chain_storage_version < 1 {
+		let mut migrated: u64 = 0;
+		PoolData::<T>::translate::<v0::Pool<T::AccountId, BlockNumberFor<T>>, _>(|_, pool| {
+			migrated.saturating_inc();
+			Some(Pool {
+				owner: pool.owner,
+				start: pool.start,
+				end: pool.end,
+				assets: pool.assets,
+				initial_weight: pool.initial_weight,
+				final_weight: pool.final_weight,
+				weight_curve: pool.weight_curve,
+				fee: pool.fee,
+				fee_collector: pool.fee_collector,
+				repay_target: pool.repay_target,
+				purchase_cap: None,
+				allowlist: false,
+				vesting: None,
+			})
+		});
+		weight.saturating_accrue(T::DbWeight::get().reads_writes(migrated, migrated));
+
+		StorageVersion::new(1).put::<Pallet<T>>();
+		//add storage version update weight
+		weight.saturating_accrue(T::DbWeight::get().writes(1));
+
+		log::info!(
+			target: "runtime::lbp",
+			"Running migration storage v1 for lbp was complete, {:?} pools migrated",
+			migrated,
+		);
+	} else {
+		log::warn!(
+			target: "runtime::lbp",
+			"Attempted to apply migration to v1 but failed because storage version is {:?}",
+			on_chain_storage_version,
+		);
+	}
+
+	weight
+}
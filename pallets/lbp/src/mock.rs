@@ -15,6 +15,7 @@ use sp_runtime::{
 	traits::{BlakeTwo256, IdentityLookup},
 	BuildStorage,
 };
+use std::cell::RefCell;
 use std::collections::BTreeMap;
 
 pub type Amount = i128;
@@ -54,6 +55,9 @@ pub const SAMPLE_POOL_DATA: Pool<AccountId, BlockNumber> = Pool {
 	fee: DEFAULT_FEE,
 	fee_collector: CHARLIE,
 	repay_target: 0,
+	purchase_cap: None,
+	allowlist: false,
+	vesting: None,
 };
 
 pub const SAMPLE_AMM_TRANSFER: AMMTransfer<AccountId, AssetId, AssetPair, Balance> = AMMTransfer {
@@ -159,6 +163,8 @@ parameter_types! {
 	pub const MinPoolLiquidity: Balance = 1_000;
 	pub const MaxInRatio: u128 = 3;
 	pub const MaxOutRatio: u128 = 3;
+	pub const MaxAllowlistLength: u32 = 3;
+	pub const MaxPurchasedRemovals: u32 = 3;
 }
 
 pub struct MultiLockedBalance();
@@ -175,6 +181,33 @@ impl LockedBalance<AssetId, AccountId, Balance> for MultiLockedBalance {
 	}
 }
 
+thread_local! {
+	pub static NATIVE_VESTED: RefCell<Vec<(AccountId, Balance, SaleVesting<BlockNumber>)>> = RefCell::new(vec![]);
+}
+
+pub struct MockNativeVesting;
+
+impl NativeVesting<AccountId, BlockNumber> for MockNativeVesting {
+	fn vested_transfer(
+		from: &AccountId,
+		to: &AccountId,
+		amount: Balance,
+		vesting: SaleVesting<BlockNumber>,
+	) -> DispatchResult {
+		<Currency as MultiCurrency<AccountId>>::transfer(NativeAssetId::get(), from, to, amount)?;
+		NATIVE_VESTED.with(|v| v.borrow_mut().push((*to, amount, vesting)));
+		Ok(())
+	}
+
+	fn vested_transfer_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+pub fn native_vested() -> Vec<(AccountId, Balance, SaleVesting<BlockNumber>)> {
+	NATIVE_VESTED.with(|v| v.borrow().clone())
+}
+
 impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type MultiCurrency = Currency;
@@ -188,6 +221,11 @@ impl Config for Test {
 	type MaxInRatio = MaxInRatio;
 	type MaxOutRatio = MaxOutRatio;
 	type BlockNumberProvider = System;
+	type PriceBarrier = ();
+	type MaxAllowlistLength = MaxAllowlistLength;
+	type MaxPurchasedRemovals = MaxPurchasedRemovals;
+	type NativeAssetId = NativeAssetId;
+	type NativeVesting = MockNativeVesting;
 }
 
 pub struct ExtBuilder {
@@ -218,6 +256,8 @@ impl ExtBuilder {
 	}
 
 	pub fn build(self) -> sp_io::TestExternalities {
+		NATIVE_VESTED.with(|v| v.borrow_mut().clear());
+
 		let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
 
 		orml_tokens::GenesisConfig::<Test> {
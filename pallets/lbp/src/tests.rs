@@ -55,6 +55,9 @@ pub fn predefined_test_ext() -> sp_io::TestExternalities {
 			DEFAULT_FEE,
 			CHARLIE,
 			0,
+			None,
+			false,
+			None,
 		));
 
 		assert_ok!(LBPPallet::update_pool_data(
@@ -68,6 +71,10 @@ pub fn predefined_test_ext() -> sp_io::TestExternalities {
 			None,
 			None,
 			None,
+			None,
+			None,
+			None,
+			None,
 		));
 
 		let pool_data2 = Pool {
@@ -81,6 +88,9 @@ pub fn predefined_test_ext() -> sp_io::TestExternalities {
 			fee: DEFAULT_FEE,
 			fee_collector: CHARLIE,
 			repay_target: 0,
+			purchase_cap: None,
+			allowlist: false,
+			vesting: None,
 		};
 
 		assert_eq!(<PoolData<Test>>::get(KUSD_BSX_POOL_ID).unwrap(), pool_data2);
@@ -122,6 +132,9 @@ pub fn predefined_test_ext_with_repay_target() -> sp_io::TestExternalities {
 			DEFAULT_FEE,
 			CHARLIE,
 			initial_liquidity,
+			None,
+			false,
+			None,
 		));
 
 		assert_ok!(LBPPallet::update_pool_data(
@@ -135,6 +148,10 @@ pub fn predefined_test_ext_with_repay_target() -> sp_io::TestExternalities {
 			None,
 			None,
 			None,
+			None,
+			None,
+			None,
+			None,
 		));
 
 		assert_ok!(LBPPallet::add_liquidity(
@@ -165,6 +182,9 @@ pub fn start_50_50_lbp_without_fee_and_repay_target(
 		(0, 1),
 		CHARLIE,
 		0,
+		None,
+		false,
+		None,
 	));
 
 	let pool_id = LBPPallet::get_pair_id(AssetPair { asset_in, asset_out });
@@ -180,6 +200,10 @@ pub fn start_50_50_lbp_without_fee_and_repay_target(
 		None,
 		None,
 		None,
+		None,
+		None,
+		None,
+		None,
 	));
 
 	//start sale
@@ -212,6 +236,9 @@ fn validate_pool_data_should_work() {
 			fee: DEFAULT_FEE,
 			fee_collector: CHARLIE,
 			repay_target: 0,
+			purchase_cap: None,
+			allowlist: false,
+			vesting: None,
 		};
 		assert_ok!(LBPPallet::validate_pool_data(&pool_data));
 
@@ -227,6 +254,9 @@ fn validate_pool_data_should_work() {
 			fee: DEFAULT_FEE,
 			fee_collector: CHARLIE,
 			repay_target: 0,
+			purchase_cap: None,
+			allowlist: false,
+			vesting: None,
 		};
 		assert_ok!(LBPPallet::validate_pool_data(&pool_data));
 
@@ -241,6 +271,9 @@ fn validate_pool_data_should_work() {
 			fee: DEFAULT_FEE,
 			fee_collector: CHARLIE,
 			repay_target: 0,
+			purchase_cap: None,
+			allowlist: false,
+			vesting: None,
 		};
 		assert_noop!(
 			LBPPallet::validate_pool_data(&pool_data),
@@ -258,6 +291,9 @@ fn validate_pool_data_should_work() {
 			fee: DEFAULT_FEE,
 			fee_collector: CHARLIE,
 			repay_target: 0,
+			purchase_cap: None,
+			allowlist: false,
+			vesting: None,
 		};
 		assert_noop!(
 			LBPPallet::validate_pool_data(&pool_data),
@@ -280,6 +316,9 @@ fn max_sale_duration_ckeck() {
 			fee: DEFAULT_FEE,
 			fee_collector: CHARLIE,
 			repay_target: 0,
+			purchase_cap: None,
+			allowlist: false,
+			vesting: None,
 		}));
 		assert_noop!(
 			LBPPallet::validate_pool_data(&Pool {
@@ -293,6 +332,9 @@ fn max_sale_duration_ckeck() {
 				fee: DEFAULT_FEE,
 				fee_collector: CHARLIE,
 				repay_target: 0,
+				purchase_cap: None,
+				allowlist: false,
+				vesting: None,
 			}),
 			Error::<Test>::MaxSaleDurationExceeded
 		);
@@ -313,6 +355,9 @@ fn calculate_weights_should_work() {
 			fee: DEFAULT_FEE,
 			fee_collector: CHARLIE,
 			repay_target: 0,
+			purchase_cap: None,
+			allowlist: false,
+			vesting: None,
 		};
 		assert_eq!(LBPPallet::calculate_weights(&pool_data, 170), Ok((38333333, 61666667)));
 
@@ -358,6 +403,168 @@ fn calculate_weights_should_work() {
 	});
 }
 
+#[test]
+fn calculate_weights_should_work_with_non_linear_curves() {
+	new_test_ext().execute_with(|| {
+		let mut pool_data = Pool {
+			owner: ALICE,
+			start: Some(100),
+			end: Some(200),
+			assets: (KUSD, BSX),
+			initial_weight: 50_000_000,
+			final_weight: 33_333_333,
+			weight_curve: WeightCurveType::ExponentialDecay { rate: (1, 1) },
+			fee: DEFAULT_FEE,
+			fee_collector: CHARLIE,
+			repay_target: 0,
+			purchase_cap: None,
+			allowlist: false,
+			vesting: None,
+		};
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 100),
+			Ok((50_000_000, 50_000_000))
+		);
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 150),
+			Ok((39_625_677, 60_374_323))
+		);
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 200),
+			Ok((33_333_333, 66_666_667))
+		);
+
+		pool_data.weight_curve = WeightCurveType::ExponentialDecay { rate: (5, 1) };
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 170),
+			Ok((33_726_975, 66_273_025))
+		);
+
+		pool_data.weight_curve = WeightCurveType::Step { steps: 4 };
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 124),
+			Ok((50_000_000, 50_000_000))
+		);
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 170),
+			Ok((41_666_666, 58_333_334))
+		);
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 200),
+			Ok((33_333_333, 66_666_667))
+		);
+
+		pool_data.weight_curve = WeightCurveType::PiecewiseLinear {
+			breakpoint: Permill::from_percent(50),
+			weight: 40_000_000,
+		};
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 150),
+			Ok((40_000_000, 60_000_000))
+		);
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 170),
+			Ok((37_333_333, 62_666_667))
+		);
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 200),
+			Ok((33_333_333, 66_666_667))
+		);
+
+		// out of bound
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 210),
+			Err(Error::<Test>::WeightCalculationError.into())
+		);
+	});
+}
+
+#[test]
+fn create_pool_with_non_linear_curve_should_work() {
+	new_test_ext().execute_with(|| {
+		let weight_curve = WeightCurveType::PiecewiseLinear {
+			breakpoint: Permill::from_percent(25),
+			weight: 50_000_000,
+		};
+
+		assert_ok!(LBPPallet::create_pool(
+			Origin::root(),
+			ALICE,
+			KUSD,
+			1_000_000_000,
+			BSX,
+			2_000_000_000,
+			80_000_000,
+			10_000_000,
+			weight_curve,
+			DEFAULT_FEE,
+			CHARLIE,
+			0,
+			None,
+			false,
+			None,
+		));
+
+		assert_eq!(
+			LBPPallet::pool_data(KUSD_BSX_POOL_ID).unwrap().weight_curve,
+			weight_curve
+		);
+	});
+}
+
+#[test]
+fn create_pool_with_invalid_weight_curve_should_not_work() {
+	new_test_ext().execute_with(|| {
+		let invalid_curves = vec![
+			WeightCurveType::ExponentialDecay { rate: (0, 1) },
+			WeightCurveType::ExponentialDecay { rate: (1, 0) },
+			WeightCurveType::ExponentialDecay {
+				rate: (MAX_EXPONENTIAL_DECAY_RATE + 1, 1),
+			},
+			WeightCurveType::Step { steps: 0 },
+			WeightCurveType::PiecewiseLinear {
+				breakpoint: Permill::zero(),
+				weight: 50_000_000,
+			},
+			WeightCurveType::PiecewiseLinear {
+				breakpoint: Permill::one(),
+				weight: 50_000_000,
+			},
+			WeightCurveType::PiecewiseLinear {
+				breakpoint: Permill::from_percent(50),
+				weight: 1_000_000,
+			},
+			WeightCurveType::PiecewiseLinear {
+				breakpoint: Permill::from_percent(50),
+				weight: MAX_WEIGHT,
+			},
+		];
+
+		for weight_curve in invalid_curves {
+			assert_noop!(
+				LBPPallet::create_pool(
+					Origin::root(),
+					ALICE,
+					KUSD,
+					1_000_000_000,
+					BSX,
+					2_000_000_000,
+					80_000_000,
+					10_000_000,
+					weight_curve,
+					DEFAULT_FEE,
+					CHARLIE,
+					0,
+					None,
+					false,
+					None,
+				),
+				Error::<Test>::InvalidWeightCurve
+			);
+		}
+	});
+}
+
 #[test]
 fn create_pool_should_work() {
 	new_test_ext().execute_with(|| {
@@ -374,6 +581,9 @@ fn create_pool_should_work() {
 			DEFAULT_FEE,
 			CHARLIE,
 			0,
+			None,
+			false,
+			None,
 		));
 
 		assert_eq!(Currency::free_balance(KUSD, &KUSD_BSX_POOL_ID), 1_000_000_000);
@@ -429,6 +639,9 @@ fn create_pool_from_basic_origin_should_not_work() {
 				DEFAULT_FEE,
 				CHARLIE,
 				0,
+				None,
+				false,
+				None,
 			),
 			BadOrigin
 		);
@@ -451,6 +664,9 @@ fn create_same_pool_should_not_work() {
 			DEFAULT_FEE,
 			CHARLIE,
 			0,
+			None,
+			false,
+			None,
 		));
 
 		assert_noop!(
@@ -467,6 +683,9 @@ fn create_same_pool_should_not_work() {
 				DEFAULT_FEE,
 				CHARLIE,
 				0,
+				None,
+				false,
+				None,
 			),
 			Error::<Test>::PoolAlreadyExists
 		);
@@ -499,6 +718,9 @@ fn create_pool_with_same_assets_should_not_work() {
 				DEFAULT_FEE,
 				CHARLIE,
 				0,
+				None,
+				false,
+				None,
 			),
 			Error::<Test>::CannotCreatePoolWithSameAssets
 		);
@@ -521,6 +743,9 @@ fn create_pool_with_non_existing_fee_collector_with_asset_should_work() {
 			DEFAULT_FEE,
 			CHARLIE,
 			0,
+			None,
+			false,
+			None,
 		));
 
 		assert_ok!(LBPPallet::create_pool(
@@ -536,6 +761,9 @@ fn create_pool_with_non_existing_fee_collector_with_asset_should_work() {
 			DEFAULT_FEE,
 			CHARLIE,
 			0,
+			None,
+			false,
+			None,
 		),);
 	});
 }
@@ -556,6 +784,9 @@ fn create_pool_with_existing_fee_collector_with_asset_should_not_work() {
 			DEFAULT_FEE,
 			CHARLIE,
 			0,
+			None,
+			false,
+			None,
 		));
 
 		assert_noop!(
@@ -572,6 +803,9 @@ fn create_pool_with_existing_fee_collector_with_asset_should_not_work() {
 				DEFAULT_FEE,
 				CHARLIE,
 				0,
+				None,
+				false,
+				None,
 			),
 			Error::<Test>::FeeCollectorWithAssetAlreadyUsed
 		);
@@ -595,6 +829,9 @@ fn create_pool_with_insufficient_liquidity_should_not_work() {
 				DEFAULT_FEE,
 				CHARLIE,
 				0,
+				None,
+				false,
+				None,
 			),
 			Error::<Test>::InsufficientLiquidity
 		);
@@ -613,6 +850,9 @@ fn create_pool_with_insufficient_liquidity_should_not_work() {
 				DEFAULT_FEE,
 				CHARLIE,
 				0,
+				None,
+				false,
+				None,
 			),
 			Error::<Test>::InsufficientLiquidity
 		);
@@ -631,6 +871,9 @@ fn create_pool_with_insufficient_liquidity_should_not_work() {
 				DEFAULT_FEE,
 				CHARLIE,
 				0,
+				None,
+				false,
+				None,
 			),
 			Error::<Test>::InsufficientLiquidity
 		);
@@ -654,6 +897,9 @@ fn create_pool_with_insufficient_balance_should_not_work() {
 				DEFAULT_FEE,
 				CHARLIE,
 				0,
+				None,
+				false,
+				None,
 			),
 			Error::<Test>::InsufficientAssetBalance
 		);
@@ -672,9 +918,13 @@ fn update_pool_data_should_work() {
 			Some(18),
 			Some(10_000_000),
 			Some(80_000_000),
+			None,
 			Some((5, 100)),
 			Some(BOB),
 			None,
+			None,
+			None,
+			None,
 		));
 
 		// verify changes
@@ -703,6 +953,10 @@ fn update_pool_data_should_work() {
 			None,
 			None,
 			None,
+			None,
+			None,
+			None,
+			None,
 		));
 
 		// verify changes
@@ -726,6 +980,10 @@ fn update_pool_data_should_work() {
 			None,
 			None,
 			None,
+			None,
+			None,
+			None,
+			None,
 		));
 
 		// verify changes
@@ -747,8 +1005,12 @@ fn update_pool_data_should_work() {
 			None,
 			None,
 			None,
+			None,
 			Some(ALICE),
 			None,
+			None,
+			None,
+			None,
 		));
 
 		// verify changes
@@ -769,9 +1031,13 @@ fn update_pool_data_should_work() {
 			Some(18),
 			Some(10_000_000),
 			Some(80_000_000),
+			None,
 			Some((6, 1_000)),
 			None,
 			None,
+			None,
+			None,
+			None,
 		));
 
 		// verify changes
@@ -796,7 +1062,11 @@ fn update_pool_data_should_work() {
 			None,
 			None,
 			None,
+			None,
 			Some(repayment),
+			None,
+			None,
+			None,
 		));
 		let updated_pool_data_6 = LBPPallet::pool_data(KUSD_BSX_POOL_ID).unwrap();
 		assert_eq!(updated_pool_data_6.repay_target, repayment);
@@ -848,9 +1118,13 @@ fn update_non_existing_pool_data_should_not_work() {
 				Some(18),
 				Some(10_000_000),
 				Some(80_000_000),
+				None,
 				Some((5, 100)),
 				None,
 				None,
+				None,
+				None,
+				None,
 			),
 			Error::<Test>::PoolNotFound
 		);
@@ -870,9 +1144,13 @@ fn update_pool_with_invalid_data_should_not_work() {
 				Some(10),
 				Some(10_000_000),
 				Some(80_000_000),
+				None,
 				Some((5, 100)),
 				None,
 				None,
+				None,
+				None,
+				None,
 			),
 			Error::<Test>::InvalidBlockRange
 		);
@@ -888,9 +1166,13 @@ fn update_pool_with_invalid_data_should_not_work() {
 				Some(20),
 				Some(10_000_000),
 				Some(80_000_000),
+				None,
 				Some((5, 100)),
 				None,
 				None,
+				None,
+				None,
+				None,
 			),
 			Error::<Test>::InvalidBlockRange
 		);
@@ -904,9 +1186,13 @@ fn update_pool_with_invalid_data_should_not_work() {
 				Some(20),
 				Some(10_000_000),
 				Some(80_000_000),
+				None,
 				Some((5, 100)),
 				None,
 				None,
+				None,
+				None,
+				None,
 			),
 			Error::<Test>::InvalidBlockRange
 		);
@@ -920,9 +1206,13 @@ fn update_pool_with_invalid_data_should_not_work() {
 				Some(0),
 				Some(10_000_000),
 				Some(80_000_000),
+				None,
 				Some((5, 100)),
 				None,
 				None,
+				None,
+				None,
+				None,
 			),
 			Error::<Test>::InvalidBlockRange
 		);
@@ -944,12 +1234,72 @@ fn update_pool_data_without_changes_should_not_work() {
 				None,
 				None,
 				None,
+				None,
+				None,
+				None,
+				None,
 			),
 			Error::<Test>::NothingToUpdate
 		);
 	});
 }
 
+#[test]
+fn update_pool_data_should_update_weight_curve() {
+	predefined_test_ext().execute_with(|| {
+		assert_ok!(LBPPallet::update_pool_data(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			None,
+			None,
+			None,
+			None,
+			None,
+			Some(WeightCurveType::Step { steps: 10 }),
+			None,
+			None,
+			None,
+			None,
+			None,
+			None,
+		));
+
+		let updated_pool_data = LBPPallet::pool_data(KUSD_BSX_POOL_ID).unwrap();
+		assert_eq!(updated_pool_data.weight_curve, WeightCurveType::Step { steps: 10 });
+
+		expect_events(vec![Event::PoolUpdated {
+			pool: KUSD_BSX_POOL_ID,
+			data: updated_pool_data,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn update_pool_data_with_invalid_weight_curve_should_not_work() {
+	predefined_test_ext().execute_with(|| {
+		assert_noop!(
+			LBPPallet::update_pool_data(
+				Origin::signed(ALICE),
+				KUSD_BSX_POOL_ID,
+				None,
+				None,
+				None,
+				None,
+				None,
+				Some(WeightCurveType::Step { steps: 0 }),
+				None,
+				None,
+				None,
+				None,
+				None,
+				None,
+			),
+			Error::<Test>::InvalidWeightCurve
+		);
+	});
+}
+
 #[test]
 fn update_pool_data_by_non_owner_should_not_work() {
 	predefined_test_ext().execute_with(|| {
@@ -965,6 +1315,10 @@ fn update_pool_data_by_non_owner_should_not_work() {
 				None,
 				None,
 				None,
+				None,
+				None,
+				None,
+				None,
 			),
 			Error::<Test>::NotOwner
 		);
@@ -985,6 +1339,10 @@ fn update_pool_owner_by_new_owner_should_work() {
 			None,
 			None,
 			None,
+			None,
+			None,
+			None,
+			None,
 		));
 
 		let pool_data1 = LBPPallet::pool_data(KUSD_BSX_POOL_ID).unwrap();
@@ -1000,6 +1358,10 @@ fn update_pool_owner_by_new_owner_should_work() {
 			None,
 			None,
 			None,
+			None,
+			None,
+			None,
+			None,
 		));
 
 		let pool_data2 = LBPPallet::pool_data(KUSD_BSX_POOL_ID).unwrap();
@@ -1033,6 +1395,10 @@ fn update_pool_data_for_running_lbp_should_not_work() {
 			None,
 			None,
 			None,
+			None,
+			None,
+			None,
+			None,
 		));
 
 		set_block_number(16);
@@ -1047,9 +1413,13 @@ fn update_pool_data_for_running_lbp_should_not_work() {
 				Some(30),
 				Some(10_000_000),
 				Some(80_000_000),
+				None,
 				Some((5, 100)),
 				Some(BOB),
 				None,
+				None,
+				None,
+				None,
 			),
 			Error::<Test>::SaleStarted
 		);
@@ -1080,7 +1450,10 @@ fn update_pool_with_existing_fee_collector_should_not_work() {
 			DEFAULT_FEE,
 			BOB,
 			0,
-		));
+			None,
+			false,
+			None,
+		));
 
 		assert_noop!(
 			LBPPallet::update_pool_data(
@@ -1091,9 +1464,13 @@ fn update_pool_with_existing_fee_collector_should_not_work() {
 				Some(18),
 				Some(10_000_000),
 				Some(80_000_000),
+				None,
 				Some((5, 100)),
 				Some(BOB),
 				None,
+				None,
+				None,
+				None,
 			),
 			Error::<Test>::FeeCollectorWithAssetAlreadyUsed
 		);
@@ -1116,6 +1493,9 @@ fn update_pool_interval_should_work() {
 			DEFAULT_FEE,
 			CHARLIE,
 			0,
+			None,
+			false,
+			None,
 		));
 
 		set_block_number(15);
@@ -1132,6 +1512,10 @@ fn update_pool_interval_should_work() {
 				None,
 				None,
 				None,
+				None,
+				None,
+				None,
+				None,
 			),
 			Error::<Test>::InvalidBlockRange
 		);
@@ -1147,6 +1531,10 @@ fn update_pool_interval_should_work() {
 			None,
 			None,
 			None,
+			None,
+			None,
+			None,
+			None,
 		));
 
 		// verify changes
@@ -1537,6 +1925,9 @@ fn remove_liquidity_from_not_started_pool_should_work() {
 			DEFAULT_FEE,
 			CHARLIE,
 			0,
+			None,
+			false,
+			None,
 		));
 
 		let user_balance_a_before = Currency::free_balance(HDX, &ALICE);
@@ -1914,6 +2305,9 @@ fn zero_weight_should_not_work() {
 				DEFAULT_FEE,
 				CHARLIE,
 				0,
+				None,
+				false,
+				None,
 			),
 			Error::<Test>::InvalidWeight
 		);
@@ -1925,9 +2319,13 @@ fn zero_weight_should_not_work() {
 			end: Some(18),
 			initial_weight: Some(0),
 			final_weight: Some(80),
+			weight_curve: None,
 			fee: Some((5, 100)),
 			fee_collector: Some(BOB),
 			repay_target: Some(0),
+			purchase_cap: None,
+			allowlist: None,
+			vesting: None,
 		});
 
 		assert_noop!(call.dispatch(Origin::signed(ALICE)), Error::<Test>::InvalidWeight);
@@ -2308,6 +2706,9 @@ fn buy_should_work() {
 			DEFAULT_FEE,
 			CHARLIE,
 			0,
+			None,
+			false,
+			None,
 		));
 
 		let pool_data1 = LBPPallet::pool_data(pool_id2).unwrap();
@@ -2359,6 +2760,10 @@ fn buy_should_work() {
 			None,
 			None,
 			None,
+			None,
+			None,
+			None,
+			None,
 			None
 		));
 
@@ -2488,6 +2893,10 @@ fn update_pool_data_after_sale_should_not_work() {
 				None,
 				None,
 				None,
+				None,
+				None,
+				None,
+				None,
 			),
 			Error::<Test>::SaleStarted
 		);
@@ -2535,6 +2944,9 @@ fn sell_should_work() {
 			DEFAULT_FEE,
 			CHARLIE,
 			0,
+			None,
+			false,
+			None,
 		));
 		let pool_data1 = LBPPallet::pool_data(pool_id2).unwrap();
 
@@ -2586,6 +2998,10 @@ fn sell_should_work() {
 			None,
 			None,
 			None,
+			None,
+			None,
+			None,
+			None,
 			None
 		));
 
@@ -2832,6 +3248,9 @@ fn zero_fee_should_work() {
 			(0, 100),
 			CHARLIE,
 			0,
+			None,
+			false,
+			None,
 		));
 
 		assert_ok!(LBPPallet::update_pool_data(
@@ -2844,6 +3263,10 @@ fn zero_fee_should_work() {
 			None,
 			None,
 			None,
+			None,
+			None,
+			None,
+			None,
 			None
 		));
 
@@ -2871,6 +3294,9 @@ fn invalid_fee_should_not_work() {
 				(10, 0),
 				CHARLIE,
 				0,
+				None,
+				false,
+				None,
 			),
 			Error::<Test>::FeeAmountInvalid
 		);
@@ -2971,6 +3397,9 @@ fn amm_trait_should_work() {
 			(400, 1_000),
 			CHARLIE,
 			0,
+			None,
+			false,
+			None,
 		));
 
 		let pool_id = LBPPallet::get_pair_id(AssetPair {
@@ -3000,6 +3429,9 @@ fn get_spot_price_should_work() {
 			DEFAULT_FEE,
 			CHARLIE,
 			0,
+			None,
+			false,
+			None,
 		));
 
 		assert_ok!(LBPPallet::update_pool_data(
@@ -3012,6 +3444,10 @@ fn get_spot_price_should_work() {
 			None,
 			None,
 			None,
+			None,
+			None,
+			None,
+			None,
 			None
 		));
 
@@ -3154,6 +3590,9 @@ fn simulate_lbp_event_should_work() {
 			fee,
 			fee_collector,
 			0,
+			None,
+			false,
+			None,
 		));
 
 		assert_ok!(LBPPallet::update_pool_data(
@@ -3166,6 +3605,10 @@ fn simulate_lbp_event_should_work() {
 			None,
 			None,
 			None,
+			None,
+			None,
+			None,
+			None,
 			None
 		));
 
@@ -3442,6 +3885,9 @@ fn validate_trade_should_not_work() {
 			(10, 1),
 			CHARLIE,
 			0,
+			None,
+			false,
+			None,
 		));
 		let pool_id2 = LBPPallet::get_pair_id(AssetPair {
 			asset_in: KUSD,
@@ -3457,6 +3903,10 @@ fn validate_trade_should_not_work() {
 			None,
 			None,
 			None,
+			None,
+			None,
+			None,
+			None,
 			None
 		));
 	});
@@ -3516,6 +3966,9 @@ fn calculate_fees_should_work() {
 			(10, 1),
 			CHARLIE,
 			0,
+			None,
+			false,
+			None,
 		));
 
 		assert_ok!(LBPPallet::update_pool_data(
@@ -3529,6 +3982,10 @@ fn calculate_fees_should_work() {
 			None,
 			None,
 			None,
+			None,
+			None,
+			None,
+			None,
 		));
 
 		let pool = LBPPallet::pool_data(HDX_BSX_POOL_ID).unwrap();
@@ -3566,6 +4023,9 @@ fn can_create_should_work() {
 			DEFAULT_FEE,
 			CHARLIE,
 			0,
+			None,
+			false,
+			None,
 		));
 		// pool is not initialized
 		assert!(!DisallowWhenLBPPoolRunning::<Test>::can_create(
@@ -3584,6 +4044,10 @@ fn can_create_should_work() {
 			None,
 			None,
 			None,
+			None,
+			None,
+			None,
+			None,
 		));
 		// pool is initialized but is not running
 		assert!(!DisallowWhenLBPPoolRunning::<Test>::can_create(
@@ -3783,6 +4247,9 @@ fn simulate_lbp_event_with_repayment() {
 			fee,
 			fee_collector,
 			0,
+			None,
+			false,
+			None,
 		));
 
 		assert_ok!(LBPPallet::update_pool_data(
@@ -3795,6 +4262,10 @@ fn simulate_lbp_event_with_repayment() {
 			None,
 			None,
 			None,
+			None,
+			None,
+			None,
+			None,
 			None
 		));
 
@@ -3863,6 +4334,702 @@ fn simulate_lbp_event_with_repayment() {
 		assert_eq!(Currency::free_balance(sold_asset, &fee_collector), 0);
 	});
 }
+mod sale_restrictions {
+	use super::*;
+	use crate::mock::{native_vested, MultiLockedBalance};
+	use hydradx_traits::router::{ExecutorError, PoolType, TradeExecution};
+
+	const PURCHASE_CAP: Balance = 100_000_000;
+	const VESTING: SaleVesting<u64> = SaleVesting {
+		start: 50,
+		period: 10,
+		period_count: 5,
+	};
+
+	fn start_restricted_sale(purchase_cap: Option<Balance>, allowlist: bool, vesting: Option<SaleVesting<u64>>) {
+		assert_ok!(LBPPallet::create_pool(
+			Origin::root(),
+			ALICE,
+			KUSD,
+			1_000_000_000,
+			BSX,
+			2_000_000_000,
+			20_000_000,
+			80_000_000,
+			WeightCurveType::Linear,
+			DEFAULT_FEE,
+			CHARLIE,
+			0,
+			purchase_cap,
+			allowlist,
+			vesting,
+		));
+
+		assert_ok!(LBPPallet::update_pool_data(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			None,
+			SALE_START,
+			SALE_END,
+			None,
+			None,
+			None,
+			None,
+			None,
+			None,
+			None,
+			None,
+			None,
+		));
+	}
+
+	#[test]
+	fn create_pool_with_sale_restrictions_should_work() {
+		new_test_ext().execute_with(|| {
+			start_restricted_sale(Some(PURCHASE_CAP), true, Some(VESTING));
+
+			let pool_data = LBPPallet::pool_data(KUSD_BSX_POOL_ID).unwrap();
+			assert_eq!(pool_data.purchase_cap, Some(PURCHASE_CAP));
+			assert!(pool_data.allowlist);
+			assert_eq!(pool_data.vesting, Some(VESTING));
+		});
+	}
+
+	#[test]
+	fn create_pool_with_invalid_sale_restrictions_should_not_work() {
+		new_test_ext().execute_with(|| {
+			let create_pool = |purchase_cap: Option<Balance>, vesting: Option<SaleVesting<u64>>| {
+				LBPPallet::create_pool(
+					Origin::root(),
+					ALICE,
+					KUSD,
+					1_000_000_000,
+					BSX,
+					2_000_000_000,
+					20_000_000,
+					80_000_000,
+					WeightCurveType::Linear,
+					DEFAULT_FEE,
+					CHARLIE,
+					0,
+					purchase_cap,
+					false,
+					vesting,
+				)
+			};
+
+			assert_noop!(create_pool(Some(0), None), Error::<Test>::InvalidPurchaseCap);
+
+			assert_noop!(
+				create_pool(None, Some(SaleVesting { period: 0, ..VESTING })),
+				Error::<Test>::InvalidVesting
+			);
+
+			assert_noop!(
+				create_pool(
+					None,
+					Some(SaleVesting {
+						period_count: 0,
+						..VESTING
+					})
+				),
+				Error::<Test>::InvalidVesting
+			);
+		});
+	}
+
+	#[test]
+	fn update_pool_data_should_update_sale_restrictions() {
+		predefined_test_ext().execute_with(|| {
+			assert_ok!(LBPPallet::update_pool_data(
+				Origin::signed(ALICE),
+				KUSD_BSX_POOL_ID,
+				None,
+				None,
+				None,
+				None,
+				None,
+				None,
+				None,
+				None,
+				None,
+				Some(Some(PURCHASE_CAP)),
+				Some(true),
+				Some(Some(VESTING)),
+			));
+
+			let pool_data = LBPPallet::pool_data(KUSD_BSX_POOL_ID).unwrap();
+			assert_eq!(pool_data.purchase_cap, Some(PURCHASE_CAP));
+			assert!(pool_data.allowlist);
+			assert_eq!(pool_data.vesting, Some(VESTING));
+
+			// parameters are removed by setting them to None
+			assert_ok!(LBPPallet::update_pool_data(
+				Origin::signed(ALICE),
+				KUSD_BSX_POOL_ID,
+				None,
+				None,
+				None,
+				None,
+				None,
+				None,
+				None,
+				None,
+				None,
+				Some(None),
+				Some(false),
+				Some(None),
+			));
+
+			let pool_data = LBPPallet::pool_data(KUSD_BSX_POOL_ID).unwrap();
+			assert_eq!(pool_data.purchase_cap, None);
+			assert!(!pool_data.allowlist);
+			assert_eq!(pool_data.vesting, None);
+
+			assert_noop!(
+				LBPPallet::update_pool_data(
+					Origin::signed(ALICE),
+					KUSD_BSX_POOL_ID,
+					None,
+					None,
+					None,
+					None,
+					None,
+					None,
+					None,
+					None,
+					None,
+					Some(Some(0)),
+					None,
+					None,
+				),
+				Error::<Test>::InvalidPurchaseCap
+			);
+		});
+	}
+
+	#[test]
+	fn add_to_allowlist_should_work() {
+		predefined_test_ext().execute_with(|| {
+			assert_ok!(LBPPallet::add_to_allowlist(
+				Origin::signed(ALICE),
+				KUSD_BSX_POOL_ID,
+				BOB
+			));
+
+			assert!(<Allowlist<Test>>::contains_key(KUSD_BSX_POOL_ID, BOB));
+			assert_eq!(LBPPallet::allowlist_length(KUSD_BSX_POOL_ID), 1);
+
+			expect_events(vec![Event::AddedToAllowlist {
+				pool: KUSD_BSX_POOL_ID,
+				who: BOB,
+			}
+			.into()]);
+		});
+	}
+
+	#[test]
+	fn add_to_allowlist_should_not_work_when_invalid() {
+		predefined_test_ext().execute_with(|| {
+			assert_noop!(
+				LBPPallet::add_to_allowlist(Origin::signed(BOB), KUSD_BSX_POOL_ID, BOB),
+				Error::<Test>::NotOwner
+			);
+
+			assert_noop!(
+				LBPPallet::add_to_allowlist(Origin::signed(ALICE), HDX_BSX_POOL_ID, BOB),
+				Error::<Test>::PoolNotFound
+			);
+
+			assert_ok!(LBPPallet::add_to_allowlist(
+				Origin::signed(ALICE),
+				KUSD_BSX_POOL_ID,
+				BOB
+			));
+			assert_noop!(
+				LBPPallet::add_to_allowlist(Origin::signed(ALICE), KUSD_BSX_POOL_ID, BOB),
+				Error::<Test>::AlreadyAllowlisted
+			);
+
+			assert_ok!(LBPPallet::add_to_allowlist(
+				Origin::signed(ALICE),
+				KUSD_BSX_POOL_ID,
+				CHARLIE
+			));
+			assert_ok!(LBPPallet::add_to_allowlist(
+				Origin::signed(ALICE),
+				KUSD_BSX_POOL_ID,
+				ALICE
+			));
+			assert_noop!(
+				LBPPallet::add_to_allowlist(Origin::signed(ALICE), KUSD_BSX_POOL_ID, 4),
+				Error::<Test>::AllowlistFull
+			);
+		});
+	}
+
+	#[test]
+	fn remove_from_allowlist_should_work() {
+		predefined_test_ext().execute_with(|| {
+			assert_ok!(LBPPallet::add_to_allowlist(
+				Origin::signed(ALICE),
+				KUSD_BSX_POOL_ID,
+				BOB
+			));
+
+			assert_noop!(
+				LBPPallet::remove_from_allowlist(Origin::signed(BOB), KUSD_BSX_POOL_ID, BOB),
+				Error::<Test>::NotOwner
+			);
+
+			assert_ok!(LBPPallet::remove_from_allowlist(
+				Origin::signed(ALICE),
+				KUSD_BSX_POOL_ID,
+				BOB
+			));
+
+			assert!(!<Allowlist<Test>>::contains_key(KUSD_BSX_POOL_ID, BOB));
+			assert_eq!(LBPPallet::allowlist_length(KUSD_BSX_POOL_ID), 0);
+
+			expect_events(vec![Event::RemovedFromAllowlist {
+				pool: KUSD_BSX_POOL_ID,
+				who: BOB,
+			}
+			.into()]);
+
+			assert_noop!(
+				LBPPallet::remove_from_allowlist(Origin::signed(ALICE), KUSD_BSX_POOL_ID, BOB),
+				Error::<Test>::NotAllowlisted
+			);
+		});
+	}
+
+	#[test]
+	fn purchase_should_not_work_when_buyer_is_not_allowlisted() {
+		new_test_ext().execute_with(|| {
+			start_restricted_sale(None, true, None);
+			run_to_sale_start();
+
+			assert_noop!(
+				LBPPallet::buy(Origin::signed(BOB), BSX, KUSD, 1_000_000, 10_000_000),
+				Error::<Test>::NotAllowlisted
+			);
+			assert_noop!(
+				LBPPallet::sell(Origin::signed(BOB), KUSD, BSX, 1_000_000, 1_000),
+				Error::<Test>::NotAllowlisted
+			);
+
+			// selling the distributed asset back to the pool is not restricted
+			assert_ok!(LBPPallet::sell(Origin::signed(BOB), BSX, KUSD, 1_000_000, 1_000));
+
+			assert_ok!(LBPPallet::add_to_allowlist(
+				Origin::signed(ALICE),
+				KUSD_BSX_POOL_ID,
+				BOB
+			));
+
+			assert_ok!(LBPPallet::buy(Origin::signed(BOB), BSX, KUSD, 1_000_000, 10_000_000));
+			assert_ok!(LBPPallet::sell(Origin::signed(BOB), KUSD, BSX, 1_000_000, 1_000));
+		});
+	}
+
+	#[test]
+	fn purchase_cap_should_limit_amount_bought_by_account() {
+		new_test_ext().execute_with(|| {
+			start_restricted_sale(Some(PURCHASE_CAP), false, None);
+			run_to_sale_start();
+
+			assert_ok!(LBPPallet::buy(Origin::signed(BOB), BSX, KUSD, 60_000_000, 100_000_000));
+
+			assert_noop!(
+				LBPPallet::buy(Origin::signed(BOB), BSX, KUSD, 50_000_000, 100_000_000),
+				Error::<Test>::PurchaseCapExceeded
+			);
+
+			assert_ok!(LBPPallet::buy(Origin::signed(BOB), BSX, KUSD, 40_000_000, 100_000_000));
+
+			let pool_data = LBPPallet::pool_data(KUSD_BSX_POOL_ID).unwrap();
+			assert_eq!(LBPPallet::purchased(&KUSD_BSX_POOL_ID, &pool_data, &BOB), PURCHASE_CAP);
+
+			assert_noop!(
+				LBPPallet::sell(Origin::signed(BOB), KUSD, BSX, 1_000_000, 1_000),
+				Error::<Test>::PurchaseCapExceeded
+			);
+
+			// the cap is tracked per account
+			assert_ok!(LBPPallet::buy(
+				Origin::signed(ALICE),
+				BSX,
+				KUSD,
+				60_000_000,
+				100_000_000
+			));
+			assert_eq!(LBPPallet::purchased(&KUSD_BSX_POOL_ID, &pool_data, &ALICE), 60_000_000);
+		});
+	}
+
+	#[test]
+	fn trade_execution_should_respect_sale_restrictions() {
+		new_test_ext().execute_with(|| {
+			start_restricted_sale(Some(PURCHASE_CAP), true, None);
+			run_to_sale_start();
+
+			assert_eq!(
+				<LBPPallet as TradeExecution<Origin, AccountId, AssetId, Balance>>::execute_buy(
+					Origin::signed(BOB),
+					PoolType::LBP,
+					KUSD,
+					BSX,
+					1_000_000,
+					10_000_000
+				),
+				Err(ExecutorError::Error(Error::<Test>::NotAllowlisted.into()))
+			);
+
+			assert_ok!(LBPPallet::add_to_allowlist(
+				Origin::signed(ALICE),
+				KUSD_BSX_POOL_ID,
+				BOB
+			));
+
+			assert_eq!(
+				<LBPPallet as TradeExecution<Origin, AccountId, AssetId, Balance>>::execute_buy(
+					Origin::signed(BOB),
+					PoolType::LBP,
+					KUSD,
+					BSX,
+					PURCHASE_CAP + 1,
+					1_000_000_000
+				),
+				Err(ExecutorError::Error(Error::<Test>::PurchaseCapExceeded.into()))
+			);
+
+			assert_ok!(
+				<LBPPallet as TradeExecution<Origin, AccountId, AssetId, Balance>>::execute_sell(
+					Origin::signed(BOB),
+					PoolType::LBP,
+					KUSD,
+					BSX,
+					1_000_000,
+					1_000
+				)
+			);
+		});
+	}
+
+	fn vesting_lock(who: AccountId, asset: AssetId) -> Balance {
+		MultiLockedBalance::get_by_lock(VESTING_LOCK_ID, asset, who)
+	}
+
+	#[test]
+	fn purchased_tokens_should_be_vested_when_pool_has_vesting() {
+		new_test_ext().execute_with(|| {
+			start_restricted_sale(None, false, Some(VESTING));
+			run_to_sale_start();
+
+			let bob_bsx_before = Currency::free_balance(BSX, &BOB);
+
+			assert_ok!(LBPPallet::buy(Origin::signed(BOB), BSX, KUSD, 1_000_000, 10_000_000));
+
+			// the distributed asset is not the native asset
+			assert_ne!(BSX, HDX);
+			assert_eq!(
+				LBPPallet::vested_purchase(BOB, BSX),
+				Some(VestedPurchase {
+					vesting: VESTING,
+					amount: 1_000_000,
+				})
+			);
+			assert_eq!(Currency::free_balance(BSX, &BOB), bob_bsx_before + 1_000_000);
+			assert_eq!(vesting_lock(BOB, BSX), 1_000_000);
+
+			// selling the distributed asset does not vest anything
+			assert_ok!(LBPPallet::sell(Origin::signed(BOB), BSX, KUSD, 1_000_000, 1_000));
+			assert_eq!(LBPPallet::vested_purchase(BOB, BSX).unwrap().amount, 1_000_000);
+			assert_eq!(vesting_lock(BOB, BSX), 1_000_000);
+		});
+	}
+
+	#[test]
+	fn vested_tokens_should_not_be_transferable() {
+		ExtBuilder::default()
+			.with_endowed_accounts(vec![(CHARLIE, KUSD, INITIAL_BALANCE)])
+			.build()
+			.execute_with(|| {
+				start_restricted_sale(None, false, Some(VESTING));
+				run_to_sale_start();
+
+				assert_ok!(LBPPallet::buy(
+					Origin::signed(CHARLIE),
+					BSX,
+					KUSD,
+					1_000_000,
+					10_000_000
+				));
+
+				assert_noop!(
+					<Currency as MultiCurrency<AccountId>>::transfer(BSX, &CHARLIE, &ALICE, 1_000_000),
+					orml_tokens::Error::<Test>::LiquidityRestrictions
+				);
+			});
+	}
+
+	#[test]
+	fn purchases_should_be_merged_into_one_vested_purchase() {
+		new_test_ext().execute_with(|| {
+			start_restricted_sale(None, false, Some(VESTING));
+			run_to_sale_start();
+
+			for _ in 0..150 {
+				assert_ok!(LBPPallet::buy(Origin::signed(BOB), BSX, KUSD, 1_000_000, 10_000_000));
+			}
+
+			assert_eq!(
+				LBPPallet::vested_purchase(BOB, BSX),
+				Some(VestedPurchase {
+					vesting: VESTING,
+					amount: 150_000_000,
+				})
+			);
+			assert_eq!(vesting_lock(BOB, BSX), 150_000_000);
+		});
+	}
+
+	#[test]
+	fn claim_vested_should_release_vested_part_of_purchase() {
+		new_test_ext().execute_with(|| {
+			start_restricted_sale(None, false, Some(VESTING));
+			run_to_sale_start();
+
+			assert_ok!(LBPPallet::buy(Origin::signed(BOB), BSX, KUSD, 1_000_000, 10_000_000));
+
+			// nothing is released before the vesting starts
+			assert_ok!(LBPPallet::claim_vested(Origin::signed(BOB), BSX));
+			assert_eq!(vesting_lock(BOB, BSX), 1_000_000);
+
+			// 2 of 5 periods have passed
+			set_block_number(VESTING.start + 2 * VESTING.period);
+			assert_ok!(LBPPallet::claim_vested(Origin::signed(BOB), BSX));
+
+			assert_eq!(vesting_lock(BOB, BSX), 600_000);
+			expect_events(vec![Event::VestedClaimed {
+				who: BOB,
+				asset: BSX,
+				amount: 400_000,
+				remaining: 600_000,
+			}
+			.into()]);
+
+			// the whole purchase is released at the end of the vesting
+			set_block_number(VESTING.start + 5 * VESTING.period);
+			assert_ok!(LBPPallet::claim_vested(Origin::signed(BOB), BSX));
+
+			assert_eq!(vesting_lock(BOB, BSX), 0);
+			assert_eq!(LBPPallet::vested_purchase(BOB, BSX), None);
+			expect_events(vec![Event::VestedClaimed {
+				who: BOB,
+				asset: BSX,
+				amount: 600_000,
+				remaining: 0,
+			}
+			.into()]);
+
+			assert_noop!(
+				LBPPallet::claim_vested(Origin::signed(BOB), BSX),
+				Error::<Test>::NoVestedPurchase
+			);
+		});
+	}
+
+	#[test]
+	fn purchase_with_different_vesting_should_not_work_when_previous_purchase_is_locked() {
+		new_test_ext().execute_with(|| {
+			start_restricted_sale(None, false, Some(VESTING));
+			assert_ok!(LBPPallet::create_pool(
+				Origin::root(),
+				ALICE,
+				HDX,
+				1_000_000_000,
+				BSX,
+				2_000_000_000,
+				20_000_000,
+				80_000_000,
+				WeightCurveType::Linear,
+				DEFAULT_FEE,
+				CHARLIE,
+				0,
+				None,
+				false,
+				Some(SaleVesting { period: 20, ..VESTING }),
+			));
+			assert_ok!(LBPPallet::update_pool_data(
+				Origin::signed(ALICE),
+				HDX_BSX_POOL_ID,
+				None,
+				SALE_START,
+				SALE_END,
+				None,
+				None,
+				None,
+				None,
+				None,
+				None,
+				None,
+				None,
+				None,
+			));
+			run_to_sale_start();
+
+			assert_ok!(LBPPallet::buy(Origin::signed(BOB), BSX, KUSD, 1_000_000, 10_000_000));
+
+			assert_noop!(
+				LBPPallet::buy(Origin::signed(BOB), BSX, HDX, 1_000_000, 10_000_000),
+				Error::<Test>::VestingScheduleMismatch
+			);
+
+			// purchase from a pool with the same vesting schedule is merged
+			assert_ok!(LBPPallet::buy(Origin::signed(BOB), BSX, KUSD, 1_000_000, 10_000_000));
+			assert_eq!(LBPPallet::vested_purchase(BOB, BSX).unwrap().amount, 2_000_000);
+		});
+	}
+
+	#[test]
+	fn purchased_native_asset_should_be_vested_by_native_vesting() {
+		const KUSD_HDX_POOL_ID: AccountId = 2_000;
+
+		new_test_ext().execute_with(|| {
+			assert_ok!(LBPPallet::create_pool(
+				Origin::root(),
+				ALICE,
+				KUSD,
+				1_000_000_000,
+				HDX,
+				2_000_000_000,
+				20_000_000,
+				80_000_000,
+				WeightCurveType::Linear,
+				DEFAULT_FEE,
+				CHARLIE,
+				0,
+				None,
+				false,
+				Some(VESTING),
+			));
+			assert_ok!(LBPPallet::update_pool_data(
+				Origin::signed(ALICE),
+				KUSD_HDX_POOL_ID,
+				None,
+				SALE_START,
+				SALE_END,
+				None,
+				None,
+				None,
+				None,
+				None,
+				None,
+				None,
+				None,
+				None,
+			));
+			run_to_sale_start();
+
+			let bob_hdx_before = Currency::free_balance(HDX, &BOB);
+
+			assert_ok!(LBPPallet::buy(Origin::signed(BOB), HDX, KUSD, 1_000_000, 10_000_000));
+
+			assert_eq!(native_vested(), vec![(BOB, 1_000_000, VESTING)]);
+			assert_eq!(Currency::free_balance(HDX, &BOB), bob_hdx_before + 1_000_000);
+
+			// native asset is not vested by the lock of the pallet
+			assert_eq!(LBPPallet::vested_purchase(BOB, HDX), None);
+			assert_eq!(vesting_lock(BOB, HDX), 0);
+			assert_noop!(
+				LBPPallet::claim_vested(Origin::signed(BOB), HDX),
+				Error::<Test>::NoVestedPurchase
+			);
+
+			// selling the native asset does not vest anything
+			assert_ok!(LBPPallet::sell(Origin::signed(BOB), HDX, KUSD, 1_000_000, 1_000));
+			assert_eq!(native_vested().len(), 1);
+		});
+	}
+
+	#[test]
+	fn purchases_from_previous_sale_should_not_count_toward_purchase_cap() {
+		new_test_ext().execute_with(|| {
+			start_restricted_sale(Some(PURCHASE_CAP), true, None);
+			assert_ok!(LBPPallet::add_to_allowlist(
+				Origin::signed(ALICE),
+				KUSD_BSX_POOL_ID,
+				BOB
+			));
+			run_to_sale_start();
+
+			assert_ok!(LBPPallet::buy(
+				Origin::signed(BOB),
+				BSX,
+				KUSD,
+				PURCHASE_CAP,
+				1_000_000_000
+			));
+
+			run_to_sale_end();
+			assert_ok!(LBPPallet::remove_liquidity(Origin::signed(ALICE), KUSD_BSX_POOL_ID));
+
+			// the allowlist and the purchases are cleared together with the pool
+			assert!(!<Allowlist<Test>>::contains_key(KUSD_BSX_POOL_ID, BOB));
+			assert_eq!(LBPPallet::allowlist_length(KUSD_BSX_POOL_ID), 0);
+			assert!(!<Purchased<Test>>::contains_key(KUSD_BSX_POOL_ID, BOB));
+
+			assert_ok!(LBPPallet::create_pool(
+				Origin::root(),
+				ALICE,
+				KUSD,
+				1_000_000_000,
+				BSX,
+				2_000_000_000,
+				20_000_000,
+				80_000_000,
+				WeightCurveType::Linear,
+				DEFAULT_FEE,
+				CHARLIE,
+				0,
+				Some(PURCHASE_CAP),
+				false,
+				None,
+			));
+			assert_ok!(LBPPallet::update_pool_data(
+				Origin::signed(ALICE),
+				KUSD_BSX_POOL_ID,
+				None,
+				Some(50),
+				Some(60),
+				None,
+				None,
+				None,
+				None,
+				None,
+				None,
+				None,
+				None,
+				None,
+			));
+			set_block_number(50);
+
+			let pool_data = LBPPallet::pool_data(KUSD_BSX_POOL_ID).unwrap();
+			assert_eq!(LBPPallet::purchased(&KUSD_BSX_POOL_ID, &pool_data, &BOB), 0);
+
+			assert_ok!(LBPPallet::buy(
+				Origin::signed(BOB),
+				BSX,
+				KUSD,
+				PURCHASE_CAP,
+				1_000_000_000
+			));
+		});
+	}
+}
+
 mod spot_price_calculation {
 	use super::*;
 	use crate::mock::RuntimeOrigin;
@@ -1,5 +1,7 @@
 use crate::*;
-use hydradx_traits::router::{ExecutorError, PoolType, TradeExecution};
+use frame_support::traits::Get;
+use frame_support::weights::Weight;
+use hydradx_traits::router::{ExecutorError, PoolType, PoolsProvider, TradeExecution, TradeablePool};
 use hydradx_traits::AMM;
 use orml_traits::MultiCurrency;
 use sp_runtime::traits::BlockNumberProvider;
@@ -196,3 +198,20 @@ impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, AssetId, Balance>
 		Ok(spot_price_with_fee)
 	}
 }
+
+impl<T: Config> PoolsProvider<AssetId> for Pallet<T> {
+	fn get_pools(limit: u32) -> Vec<TradeablePool<AssetId>> {
+		PoolData::<T>::iter_values()
+			.filter(|pool| Self::is_pool_running(pool))
+			.take(limit as usize)
+			.map(|pool| TradeablePool {
+				pool: PoolType::LBP,
+				assets: vec![pool.assets.0, pool.assets.1],
+			})
+			.collect()
+	}
+
+	fn get_pools_weight(limit: u32) -> Weight {
+		T::DbWeight::get().reads(limit.into())
+	}
+}
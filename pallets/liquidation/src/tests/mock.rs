@@ -242,6 +242,9 @@ impl pallet_route_executor::Config for Test {
 	type DefaultRoutePoolType = DefaultRoutePoolType;
 	type TechnicalOrigin = EnsureRoot<Self::AccountId>;
 	type WeightInfo = ();
+	type PoolsProvider = Omnipool;
+	type MaxRouteDiscoveryCalculations = ConstU32<100>;
+	type MaxRouteDiscoveryPools = ConstU32<100>;
 }
 
 parameter_types! {
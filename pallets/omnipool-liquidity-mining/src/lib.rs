@@ -671,6 +671,9 @@ pub mod pallet {
 		/// `origin` account. Minted NFT represents deposit in the liquidity mining. User can
 		/// deposit omnipool position as a whole(all the LP shares in the position).
 		///
+		/// Deposited omnipool position can't be split or merged in the omnipool until it's withdrawn
+		/// from the liquidity mining.
+		///
 		/// Parameters:
 		/// - `origin`: owner of the omnipool position to deposit into the liquidity mining.
 		/// - `global_farm_id`: id of global farm to which user wants to deposit LP shares.
@@ -340,3 +340,60 @@ fn deposit_shares_should_fail_with_asset_not_found_when_omnipool_deosnt_exists()
 			);
 		});
 }
+
+#[test]
+fn deposited_position_should_not_be_split_or_merged_when_deposited_in_farm() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP1, KSM, 5000 * ONE),
+			(LP2, DOT, 2000 * ONE),
+			(GC, HDX, 100_000_000 * ONE),
+		])
+		.with_registered_asset(KSM)
+		.with_registered_asset(DOT)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(KSM, FixedU128::from_float(0.65), LP1, 2000 * ONE)
+		.with_liquidity(LP1, KSM, 1_000 * ONE)
+		.with_global_farm(
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			GC,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_yield_farm(GC, 1, KSM, FixedU128::one(), None)
+		.build()
+		.execute_with(|| {
+			let global_farm_id = 1;
+			let yield_farm_id = 2;
+			let deposited_position_id = 2;
+			let position_id = 3;
+
+			//Arrange
+			assert_ok!(OmnipoolMining::deposit_shares(
+				RuntimeOrigin::signed(LP1),
+				global_farm_id,
+				yield_farm_id,
+				deposited_position_id
+			));
+
+			//Act & assert - position is owned by liquidity mining while deposited
+			assert_noop!(
+				Omnipool::split_position(RuntimeOrigin::signed(LP1), deposited_position_id, 100 * ONE),
+				pallet_omnipool::Error::<Test>::Forbidden
+			);
+			assert_noop!(
+				Omnipool::merge_positions(RuntimeOrigin::signed(LP1), position_id, deposited_position_id),
+				pallet_omnipool::Error::<Test>::Forbidden
+			);
+			assert_noop!(
+				Omnipool::merge_positions(RuntimeOrigin::signed(LP1), deposited_position_id, position_id),
+				pallet_omnipool::Error::<Test>::Forbidden
+			);
+		});
+}
@@ -0,0 +1,549 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Omnipool subpools pallet
+//!
+//! ## Overview
+//!
+//! Omnipool subpools pallet allows to move correlated assets ( eg. stablecoins ) from the Omnipool
+//! into a Stableswap pool ( subpool ). The share token of the subpool is then added to the Omnipool instead
+//! of the migrated assets.
+//!
+//! When an asset is migrated, its whole Omnipool reserve is transferred to the subpool account,
+//! corresponding amount of subpool shares is minted to the Omnipool account and the hub reserve
+//! of the asset is transferred to the share token state.
+//!
+//! Existing Omnipool positions of migrated assets are not changed during the migration. Details
+//! of the migration are stored and any position can be converted later into a position of the share
+//! token by calling `convert_position`.
+//!
+//! Trades between any combination of Omnipool assets and migrated assets are supported via `sell` and `buy`,
+//! which route the trade through the subpool and the Omnipool as needed.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! * `create_subpool` - Creates new subpool from two Omnipool assets.
+//! * `migrate_asset_to_subpool` - Moves an Omnipool asset into an existing subpool.
+//! * `convert_position` - Converts an Omnipool position of a migrated asset into a position of the share token.
+//! * `sell` - Sells asset in for asset out, trading through subpools if needed.
+//! * `buy` - Buys asset out for asset in, trading through subpools if needed.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::pallet_prelude::*;
+use frame_support::{require_transactional, transactional};
+use frame_system::pallet_prelude::*;
+use hydra_dx_math::omnipool::types::Position as MathPosition;
+use hydra_dx_math::omnipool_subpools::types::MigrationDetails;
+use hydradx_traits::router::{ExecutorError, PoolType, Trade, TradeExecution};
+use orml_traits::MultiCurrency;
+use pallet_omnipool::types::{AssetReserveState, Tradability};
+use primitives::{AssetId, Balance};
+use sp_runtime::Permill;
+use sp_std::num::NonZeroU16;
+use sp_std::prelude::*;
+
+#[cfg(test)]
+mod tests;
+
+pub mod types;
+pub mod weights;
+
+use types::AssetDetail;
+pub use weights::WeightInfo;
+
+pub use pallet::*;
+
+type OmnipoolPallet<T> = pallet_omnipool::Pallet<T>;
+type StableswapPallet<T> = pallet_stableswap::Pallet<T>;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config:
+		frame_system::Config + pallet_omnipool::Config<AssetId = AssetId> + pallet_stableswap::Config<AssetId = AssetId>
+	{
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Origin that can create subpools and migrate assets.
+		type AuthorityOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Trade executor used to route trades through Omnipool and subpools.
+		type TradeExecutor: TradeExecution<OriginFor<Self>, Self::AccountId, AssetId, Balance, Error = DispatchError>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::storage]
+	#[pallet::getter(fn migrated_asset)]
+	/// Details of assets migrated from Omnipool to a subpool.
+	pub type MigratedAssets<T: Config> = StorageMap<_, Blake2_128Concat, AssetId, AssetDetail<AssetId>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn subpools)]
+	/// Existing subpools.
+	pub type Subpools<T: Config> = StorageMap<_, Blake2_128Concat, AssetId, ()>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Subpool was created from two Omnipool assets.
+		SubpoolCreated {
+			pool_id: AssetId,
+			assets: (AssetId, AssetId),
+		},
+		/// Asset was migrated from Omnipool to a subpool.
+		AssetMigrated { asset_id: AssetId, pool_id: AssetId },
+		/// Position of a migrated asset was converted to a position of the subpool share token.
+		PositionConverted {
+			position_id: T::PositionItemId,
+			pool_id: AssetId,
+		},
+		/// Asset was sold through the subpools and the Omnipool.
+		SellExecuted {
+			who: T::AccountId,
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount_in: Balance,
+			amount_out: Balance,
+		},
+		/// Asset was bought through the subpools and the Omnipool.
+		BuyExecuted {
+			who: T::AccountId,
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount_in: Balance,
+			amount_out: Balance,
+		},
+	}
+
+	#[pallet::error]
+	#[cfg_attr(test, derive(PartialEq, Eq))]
+	pub enum Error<T> {
+		/// Operation is not allowed for given asset.
+		NotAllowed,
+		/// Subpool does not exist.
+		SubpoolNotFound,
+		/// Asset has not been migrated to a subpool.
+		AssetNotMigrated,
+		/// Position does not exist.
+		PositionNotFound,
+		/// Trade between given assets is not supported.
+		TradeNotSupported,
+		/// Minimum limit has not been reached during trade.
+		BuyLimitNotReached,
+		/// Maximum limit has been exceeded during trade.
+		SellLimitExceeded,
+		/// Math calculation failed.
+		MathError,
+		/// Asset has already been migrated to a subpool.
+		AssetAlreadyMigrated,
+		/// Share asset of a subpool cannot be migrated to a subpool.
+		ShareAssetNotAllowed,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create new subpool by migrating two Omnipool assets into new Stableswap pool.
+		///
+		/// Whole Omnipool reserves of both assets are moved to the subpool. Share token of the subpool
+		/// is added to the Omnipool with hub reserve of both assets.
+		///
+		/// Parameters:
+		/// - `origin`: Must be T::AuthorityOrigin
+		/// - `share_asset`: Preregistered share asset identifier
+		/// - `asset_a`: First Omnipool asset to migrate
+		/// - `asset_b`: Second Omnipool asset to migrate
+		/// - `share_asset_weight_cap`: Weight cap of the share asset in the Omnipool
+		/// - `amplification`: Amplification of the new subpool
+		/// - `fee`: Trade fee of the new subpool
+		///
+		/// Emits `SubpoolCreated` event when successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_subpool())]
+		#[transactional]
+		pub fn create_subpool(
+			origin: OriginFor<T>,
+			share_asset: AssetId,
+			asset_a: AssetId,
+			asset_b: AssetId,
+			share_asset_weight_cap: Permill,
+			amplification: NonZeroU16,
+			fee: Permill,
+		) -> DispatchResult {
+			<T as Config>::AuthorityOrigin::ensure_origin(origin)?;
+
+			let hdx_asset_id = <T as pallet_omnipool::Config>::HdxAssetId::get();
+			ensure!(
+				asset_a != hdx_asset_id && asset_b != hdx_asset_id,
+				Error::<T>::NotAllowed
+			);
+			Self::ensure_can_be_migrated(asset_a)?;
+			Self::ensure_can_be_migrated(asset_b)?;
+
+			let asset_state_a = OmnipoolPallet::<T>::load_asset_state(asset_a)?;
+			let asset_state_b = OmnipoolPallet::<T>::load_asset_state(asset_b)?;
+
+			let subpool_state = hydra_dx_math::omnipool_subpools::create_subpool_initial_state(
+				&(&asset_state_a).into(),
+				&(&asset_state_b).into(),
+			)
+			.ok_or(Error::<T>::MathError)?;
+
+			StableswapPallet::<T>::do_create_pool(share_asset, &[asset_a, asset_b], amplification, fee)?;
+
+			for (asset_id, asset_state) in [(asset_a, asset_state_a), (asset_b, asset_state_b)] {
+				let (details, _) = hydra_dx_math::omnipool_subpools::calculate_asset_migration_details(
+					&(&asset_state).into(),
+					None,
+					0,
+				)
+				.ok_or(Error::<T>::MathError)?;
+				Self::move_asset_to_subpool(share_asset, asset_id, &asset_state, details)?;
+			}
+
+			<T as pallet_omnipool::Config>::Currency::deposit(
+				share_asset,
+				&OmnipoolPallet::<T>::protocol_account(),
+				subpool_state.reserve,
+			)?;
+
+			OmnipoolPallet::<T>::add_asset(
+				share_asset,
+				(subpool_state, share_asset_weight_cap, Tradability::default()).into(),
+			)?;
+
+			Subpools::<T>::insert(share_asset, ());
+
+			Self::deposit_event(Event::SubpoolCreated {
+				pool_id: share_asset,
+				assets: (asset_a, asset_b),
+			});
+
+			Ok(())
+		}
+
+		/// Migrate an Omnipool asset into an existing subpool.
+		///
+		/// Whole Omnipool reserve of the asset is moved to the subpool. Corresponding amount of share tokens
+		/// is minted to the Omnipool and share token state is updated with the hub reserve of the asset.
+		///
+		/// Parameters:
+		/// - `origin`: Must be T::AuthorityOrigin
+		/// - `pool_id`: Subpool identifier
+		/// - `asset_id`: Omnipool asset to migrate
+		///
+		/// Emits `AssetMigrated` event when successful.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::migrate_asset_to_subpool())]
+		#[transactional]
+		pub fn migrate_asset_to_subpool(origin: OriginFor<T>, pool_id: AssetId, asset_id: AssetId) -> DispatchResult {
+			<T as Config>::AuthorityOrigin::ensure_origin(origin)?;
+
+			ensure!(Subpools::<T>::contains_key(pool_id), Error::<T>::SubpoolNotFound);
+			ensure!(
+				asset_id != <T as pallet_omnipool::Config>::HdxAssetId::get(),
+				Error::<T>::NotAllowed
+			);
+			Self::ensure_can_be_migrated(asset_id)?;
+
+			let asset_state = OmnipoolPallet::<T>::load_asset_state(asset_id)?;
+			let subpool_state = OmnipoolPallet::<T>::load_asset_state(pool_id)?;
+			let share_issuance = <T as pallet_omnipool::Config>::Currency::total_issuance(pool_id);
+
+			StableswapPallet::<T>::do_add_asset_to_pool(pool_id, asset_id)?;
+
+			let (details, delta) = hydra_dx_math::omnipool_subpools::calculate_asset_migration_details(
+				&(&asset_state).into(),
+				Some(&(&subpool_state).into()),
+				share_issuance,
+			)
+			.ok_or(Error::<T>::MathError)?;
+			let delta = delta.ok_or(Error::<T>::MathError)?;
+			let share_tokens = details.share_tokens;
+
+			Self::move_asset_to_subpool(pool_id, asset_id, &asset_state, details)?;
+
+			<T as pallet_omnipool::Config>::Currency::deposit(
+				pool_id,
+				&OmnipoolPallet::<T>::protocol_account(),
+				share_tokens,
+			)?;
+
+			OmnipoolPallet::<T>::update_asset_state(pool_id, delta)?;
+
+			Ok(())
+		}
+
+		/// Convert Omnipool position of a migrated asset into a position of the subpool share token.
+		///
+		/// Can be called by anyone. Position owner is not changed.
+		///
+		/// Parameters:
+		/// - `origin`: Signed origin
+		/// - `position_id`: Omnipool position identifier
+		///
+		/// Emits `PositionConverted` event when successful.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::convert_position())]
+		#[transactional]
+		pub fn convert_position(origin: OriginFor<T>, position_id: T::PositionItemId) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let position = OmnipoolPallet::<T>::positions(position_id).ok_or(Error::<T>::PositionNotFound)?;
+			let migration = MigratedAssets::<T>::get(position.asset_id).ok_or(Error::<T>::AssetNotMigrated)?;
+
+			let converted =
+				hydra_dx_math::omnipool_subpools::convert_position(MathPosition::from(&position), migration.into())
+					.ok_or(Error::<T>::MathError)?;
+
+			let new_position = pallet_omnipool::types::Position {
+				asset_id: migration.pool_id,
+				amount: converted.amount,
+				shares: converted.shares,
+				price: converted.price,
+			};
+
+			OmnipoolPallet::<T>::set_position(position_id, &new_position)?;
+
+			Self::deposit_event(Event::PositionConverted {
+				position_id,
+				pool_id: migration.pool_id,
+			});
+
+			Ok(())
+		}
+
+		/// Sell `amount` of `asset_in` for `asset_out`.
+		///
+		/// Trade is routed through the subpools of migrated assets and the Omnipool.
+		///
+		/// Parameters:
+		/// - `origin`: Signed origin
+		/// - `asset_in`: Asset to sell
+		/// - `asset_out`: Asset to buy
+		/// - `amount`: Amount of `asset_in` to sell
+		/// - `min_buy_amount`: Minimum amount of `asset_out` to receive
+		///
+		/// Emits `SellExecuted` event when successful.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::sell(Pallet::<T>::trade_route(*asset_in, *asset_out).len() as u32))]
+		#[transactional]
+		pub fn sell(
+			origin: OriginFor<T>,
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount: Balance,
+			min_buy_amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin.clone())?;
+
+			let route = Self::trade_route(asset_in, asset_out);
+
+			let mut amounts = Vec::with_capacity(route.len() + 1);
+			amounts.push(amount);
+			for trade in route.iter() {
+				let amount_in = amounts.last().copied().unwrap_or_default();
+				let amount_out =
+					T::TradeExecutor::calculate_sell(trade.pool, trade.asset_in, trade.asset_out, amount_in)
+						.map_err(Self::executor_error)?;
+				amounts.push(amount_out);
+			}
+
+			let amount_out = amounts.last().copied().unwrap_or_default();
+			ensure!(amount_out >= min_buy_amount, Error::<T>::BuyLimitNotReached);
+
+			for (idx, trade) in route.iter().enumerate() {
+				T::TradeExecutor::execute_sell(
+					origin.clone(),
+					trade.pool,
+					trade.asset_in,
+					trade.asset_out,
+					amounts[idx],
+					amounts[idx + 1],
+				)
+				.map_err(Self::executor_error)?;
+			}
+
+			Self::deposit_event(Event::SellExecuted {
+				who,
+				asset_in,
+				asset_out,
+				amount_in: amount,
+				amount_out,
+			});
+
+			Ok(())
+		}
+
+		/// Buy `amount` of `asset_out` for `asset_in`.
+		///
+		/// Trade is routed through the subpools of migrated assets and the Omnipool.
+		///
+		/// Parameters:
+		/// - `origin`: Signed origin
+		/// - `asset_out`: Asset to buy
+		/// - `asset_in`: Asset to sell
+		/// - `amount`: Amount of `asset_out` to buy
+		/// - `max_sell_amount`: Maximum amount of `asset_in` to pay
+		///
+		/// Emits `BuyExecuted` event when successful.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::buy(Pallet::<T>::trade_route(*asset_in, *asset_out).len() as u32))]
+		#[transactional]
+		pub fn buy(
+			origin: OriginFor<T>,
+			asset_out: AssetId,
+			asset_in: AssetId,
+			amount: Balance,
+			max_sell_amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin.clone())?;
+
+			let route = Self::trade_route(asset_in, asset_out);
+
+			let mut amounts = Vec::with_capacity(route.len() + 1);
+			amounts.push(amount);
+			for trade in route.iter().rev() {
+				let amount_out = amounts.last().copied().unwrap_or_default();
+				let amount_in =
+					T::TradeExecutor::calculate_buy(trade.pool, trade.asset_in, trade.asset_out, amount_out)
+						.map_err(Self::executor_error)?;
+				amounts.push(amount_in);
+			}
+			amounts.reverse();
+
+			let amount_in = amounts.first().copied().unwrap_or_default();
+			ensure!(amount_in <= max_sell_amount, Error::<T>::SellLimitExceeded);
+
+			for (idx, trade) in route.iter().enumerate() {
+				T::TradeExecutor::execute_buy(
+					origin.clone(),
+					trade.pool,
+					trade.asset_in,
+					trade.asset_out,
+					amounts[idx + 1],
+					amounts[idx],
+				)
+				.map_err(Self::executor_error)?;
+			}
+
+			Self::deposit_event(Event::BuyExecuted {
+				who,
+				asset_in,
+				asset_out,
+				amount_in,
+				amount_out: amount,
+			});
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Move whole Omnipool reserve of an asset to the subpool and remove the asset from Omnipool.
+	/// Emits `AssetMigrated` event.
+	#[require_transactional]
+	fn move_asset_to_subpool(
+		pool_id: AssetId,
+		asset_id: AssetId,
+		asset_state: &AssetReserveState<Balance>,
+		details: MigrationDetails,
+	) -> DispatchResult {
+		<T as pallet_omnipool::Config>::Currency::transfer(
+			asset_id,
+			&OmnipoolPallet::<T>::protocol_account(),
+			&StableswapPallet::<T>::pool_account(pool_id),
+			asset_state.reserve,
+		)?;
+
+		OmnipoolPallet::<T>::remove_asset(asset_id)?;
+
+		MigratedAssets::<T>::insert(asset_id, AssetDetail::new(pool_id, details));
+
+		Self::deposit_event(Event::AssetMigrated { asset_id, pool_id });
+
+		Ok(())
+	}
+
+	/// Ensure that the asset is neither migrated to a subpool nor a share asset of a subpool.
+	fn ensure_can_be_migrated(asset_id: AssetId) -> DispatchResult {
+		ensure!(
+			!MigratedAssets::<T>::contains_key(asset_id),
+			Error::<T>::AssetAlreadyMigrated
+		);
+		ensure!(!Subpools::<T>::contains_key(asset_id), Error::<T>::ShareAssetNotAllowed);
+		Ok(())
+	}
+
+	/// Returns subpool of given asset if the asset has been migrated.
+	pub fn subpool_of(asset_id: AssetId) -> Option<AssetId> {
+		MigratedAssets::<T>::get(asset_id).map(|detail| detail.pool_id)
+	}
+
+	/// Build list of trades needed to trade `asset_in` for `asset_out`.
+	pub fn trade_route(asset_in: AssetId, asset_out: AssetId) -> Vec<Trade<AssetId>> {
+		let trade = |pool, asset_in, asset_out| Trade {
+			pool,
+			asset_in,
+			asset_out,
+		};
+
+		match (Self::subpool_of(asset_in), Self::subpool_of(asset_out)) {
+			(Some(pool_in), Some(pool_out)) if pool_in == pool_out => {
+				vec![trade(PoolType::Stableswap(pool_in), asset_in, asset_out)]
+			}
+			(Some(pool_in), Some(pool_out)) => vec![
+				trade(PoolType::Stableswap(pool_in), asset_in, pool_in),
+				trade(PoolType::Omnipool, pool_in, pool_out),
+				trade(PoolType::Stableswap(pool_out), pool_out, asset_out),
+			],
+			(Some(pool_in), None) if pool_in == asset_out => {
+				vec![trade(PoolType::Stableswap(pool_in), asset_in, asset_out)]
+			}
+			(Some(pool_in), None) => vec![
+				trade(PoolType::Stableswap(pool_in), asset_in, pool_in),
+				trade(PoolType::Omnipool, pool_in, asset_out),
+			],
+			(None, Some(pool_out)) if pool_out == asset_in => {
+				vec![trade(PoolType::Stableswap(pool_out), asset_in, asset_out)]
+			}
+			(None, Some(pool_out)) => vec![
+				trade(PoolType::Omnipool, asset_in, pool_out),
+				trade(PoolType::Stableswap(pool_out), pool_out, asset_out),
+			],
+			(None, None) => vec![trade(PoolType::Omnipool, asset_in, asset_out)],
+		}
+	}
+
+	fn executor_error(error: ExecutorError<DispatchError>) -> DispatchError {
+		match error {
+			ExecutorError::NotSupported => Error::<T>::TradeNotSupported.into(),
+			ExecutorError::Error(e) => e,
+		}
+	}
+}
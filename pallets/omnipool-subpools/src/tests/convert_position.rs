@@ -0,0 +1,83 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::mock::*;
+use crate::{Error, Event};
+use frame_support::{assert_noop, assert_ok};
+use pretty_assertions::assert_eq;
+use sp_runtime::FixedU128;
+
+const USDT_POSITION_ID: u32 = 2;
+
+#[test]
+fn convert_position_should_change_position_asset_to_share_asset() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, USDT, 2000 * ONE), (ALICE, USDC, 2000 * ONE)])
+		.with_token(USDT, FixedU128::from_float(0.65), ALICE, 1000 * ONE)
+		.with_token(USDC, FixedU128::from_float(0.65), ALICE, 1000 * ONE)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			let usdt_state = Omnipool::load_asset_state(USDT).unwrap();
+			let position = Omnipool::positions(USDT_POSITION_ID).unwrap();
+			assert_eq!(position.asset_id, USDT);
+			create_subpool();
+
+			//Act
+			assert_ok!(OmnipoolSubpools::convert_position(
+				RuntimeOrigin::signed(BOB),
+				USDT_POSITION_ID
+			));
+
+			//Assert
+			let converted = Omnipool::positions(USDT_POSITION_ID).unwrap();
+			assert_eq!(converted.asset_id, SHARE_ASSET);
+			// Position holds all USDT shares, so it receives all share tokens and hub reserve of USDT
+			assert_eq!(converted.shares, usdt_state.hub_reserve);
+			assert_eq!(converted.amount, usdt_state.hub_reserve);
+			assert_eq!(get_mock_minted_position(USDT_POSITION_ID), Some(ALICE));
+
+			expect_events(vec![Event::PositionConverted {
+				position_id: USDT_POSITION_ID,
+				pool_id: SHARE_ASSET,
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn convert_position_should_fail_when_asset_is_not_migrated() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, USDT, 2000 * ONE)])
+		.with_token(USDT, FixedU128::from_float(0.65), ALICE, 1000 * ONE)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				OmnipoolSubpools::convert_position(RuntimeOrigin::signed(ALICE), USDT_POSITION_ID),
+				Error::<Test>::AssetNotMigrated
+			);
+		});
+}
+
+#[test]
+fn convert_position_should_fail_when_position_does_not_exist() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			OmnipoolSubpools::convert_position(RuntimeOrigin::signed(ALICE), 1000),
+			Error::<Test>::PositionNotFound
+		);
+	});
+}
@@ -0,0 +1,209 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::mock::*;
+use crate::{Error, Event, MigratedAssets, Subpools};
+use frame_support::assert_noop;
+use orml_traits::MultiCurrency;
+use pretty_assertions::assert_eq;
+use sp_runtime::{FixedU128, Permill};
+use std::num::NonZeroU16;
+
+#[test]
+fn create_subpool_should_migrate_both_assets_from_omnipool() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, USDT, 2000 * ONE), (ALICE, USDC, 2000 * ONE)])
+		.with_token(USDT, FixedU128::from_float(0.65), ALICE, 1000 * ONE)
+		.with_token(USDC, FixedU128::from_float(0.65), ALICE, 1000 * ONE)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			let usdt_state = Omnipool::load_asset_state(USDT).unwrap();
+			let usdc_state = Omnipool::load_asset_state(USDC).unwrap();
+
+			//Act
+			create_subpool();
+
+			//Assert
+			assert!(!Omnipool::exists(USDT));
+			assert!(!Omnipool::exists(USDC));
+			assert!(Omnipool::exists(SHARE_ASSET));
+			assert!(Subpools::<Test>::contains_key(SHARE_ASSET));
+
+			let pool = pallet_stableswap::Pools::<Test>::get(SHARE_ASSET).unwrap();
+			assert_eq!(pool.assets.to_vec(), vec![USDT, USDC]);
+
+			let pool_account = Stableswap::pool_account(SHARE_ASSET);
+			assert_eq!(Tokens::free_balance(USDT, &pool_account), 1000 * ONE);
+			assert_eq!(Tokens::free_balance(USDC, &pool_account), 1000 * ONE);
+			assert_eq!(Tokens::free_balance(USDT, &Omnipool::protocol_account()), 0);
+			assert_eq!(Tokens::free_balance(USDC, &Omnipool::protocol_account()), 0);
+
+			let share_state = Omnipool::load_asset_state(SHARE_ASSET).unwrap();
+			let hub_reserve = usdt_state.hub_reserve + usdc_state.hub_reserve;
+			assert_eq!(share_state.hub_reserve, hub_reserve);
+			assert_eq!(share_state.shares, hub_reserve);
+			assert_eq!(share_state.reserve, hub_reserve);
+			assert_eq!(Tokens::total_issuance(SHARE_ASSET), hub_reserve);
+
+			let usdt_detail = MigratedAssets::<Test>::get(USDT).unwrap();
+			assert_eq!(usdt_detail.pool_id, SHARE_ASSET);
+			assert_eq!(usdt_detail.shares, usdt_state.shares);
+			assert_eq!(usdt_detail.hub_reserve, usdt_state.hub_reserve);
+			assert_eq!(usdt_detail.share_tokens, usdt_state.hub_reserve);
+			assert!(MigratedAssets::<Test>::contains_key(USDC));
+
+			expect_events(vec![
+				Event::AssetMigrated {
+					asset_id: USDT,
+					pool_id: SHARE_ASSET,
+				}
+				.into(),
+				Event::AssetMigrated {
+					asset_id: USDC,
+					pool_id: SHARE_ASSET,
+				}
+				.into(),
+				Event::SubpoolCreated {
+					pool_id: SHARE_ASSET,
+					assets: (USDT, USDC),
+				}
+				.into(),
+			]);
+		});
+}
+
+#[test]
+fn create_subpool_should_fail_when_called_by_non_authority() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, USDT, 2000 * ONE), (ALICE, USDC, 2000 * ONE)])
+		.with_token(USDT, FixedU128::from_float(0.65), ALICE, 1000 * ONE)
+		.with_token(USDC, FixedU128::from_float(0.65), ALICE, 1000 * ONE)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				OmnipoolSubpools::create_subpool(
+					RuntimeOrigin::signed(ALICE),
+					SHARE_ASSET,
+					USDT,
+					USDC,
+					Permill::from_percent(100),
+					NonZeroU16::new(100).unwrap(),
+					Permill::zero(),
+				),
+				sp_runtime::DispatchError::BadOrigin
+			);
+		});
+}
+
+#[test]
+fn create_subpool_should_fail_when_native_asset_is_migrated() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, USDT, 2000 * ONE)])
+		.with_token(USDT, FixedU128::from_float(0.65), ALICE, 1000 * ONE)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				OmnipoolSubpools::create_subpool(
+					RuntimeOrigin::root(),
+					SHARE_ASSET,
+					HDX,
+					USDT,
+					Permill::from_percent(100),
+					NonZeroU16::new(100).unwrap(),
+					Permill::zero(),
+				),
+				Error::<Test>::NotAllowed
+			);
+		});
+}
+
+#[test]
+fn create_subpool_should_fail_when_asset_is_not_in_omnipool() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, USDT, 2000 * ONE)])
+		.with_token(USDT, FixedU128::from_float(0.65), ALICE, 1000 * ONE)
+		.with_registered_asset(USDC)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				OmnipoolSubpools::create_subpool(
+					RuntimeOrigin::root(),
+					SHARE_ASSET,
+					USDT,
+					USDC,
+					Permill::from_percent(100),
+					NonZeroU16::new(100).unwrap(),
+					Permill::zero(),
+				),
+				pallet_omnipool::Error::<Test>::AssetNotFound
+			);
+		});
+}
+
+#[test]
+fn create_subpool_should_fail_when_asset_is_already_migrated() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, USDT, 2000 * ONE), (ALICE, USDC, 2000 * ONE)])
+		.with_token(USDT, FixedU128::from_float(0.65), ALICE, 1000 * ONE)
+		.with_token(USDC, FixedU128::from_float(0.65), ALICE, 1000 * ONE)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			create_subpool();
+
+			//Act & Assert
+			assert_noop!(
+				OmnipoolSubpools::create_subpool(
+					RuntimeOrigin::root(),
+					SHARE_ASSET_2,
+					USDT,
+					DAI,
+					Permill::from_percent(100),
+					NonZeroU16::new(100).unwrap(),
+					Permill::zero(),
+				),
+				Error::<Test>::AssetAlreadyMigrated
+			);
+		});
+}
+
+#[test]
+fn create_subpool_should_fail_when_asset_is_share_asset_of_subpool() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, USDT, 2000 * ONE), (ALICE, USDC, 2000 * ONE)])
+		.with_token(USDT, FixedU128::from_float(0.65), ALICE, 1000 * ONE)
+		.with_token(USDC, FixedU128::from_float(0.65), ALICE, 1000 * ONE)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			create_subpool();
+
+			//Act & Assert
+			assert_noop!(
+				OmnipoolSubpools::create_subpool(
+					RuntimeOrigin::root(),
+					SHARE_ASSET_2,
+					SHARE_ASSET,
+					DAI,
+					Permill::from_percent(100),
+					NonZeroU16::new(100).unwrap(),
+					Permill::zero(),
+				),
+				Error::<Test>::ShareAssetNotAllowed
+			);
+		});
+}
@@ -0,0 +1,162 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::mock::*;
+use crate::{Error, Event, MigratedAssets};
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::MultiCurrency;
+use pretty_assertions::assert_eq;
+use sp_runtime::FixedU128;
+
+#[test]
+fn migrate_asset_to_subpool_should_add_asset_to_existing_subpool() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, USDT, 2000 * ONE), (ALICE, USDC, 2000 * ONE)])
+		.with_token(USDT, FixedU128::from_float(0.65), ALICE, 1000 * ONE)
+		.with_token(USDC, FixedU128::from_float(0.65), ALICE, 1000 * ONE)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			create_subpool();
+			let dai_state = Omnipool::load_asset_state(DAI).unwrap();
+			let share_state = Omnipool::load_asset_state(SHARE_ASSET).unwrap();
+			let share_issuance = Tokens::total_issuance(SHARE_ASSET);
+
+			//Act
+			assert_ok!(OmnipoolSubpools::migrate_asset_to_subpool(
+				RuntimeOrigin::root(),
+				SHARE_ASSET,
+				DAI
+			));
+
+			//Assert
+			assert!(!Omnipool::exists(DAI));
+
+			let pool = pallet_stableswap::Pools::<Test>::get(SHARE_ASSET).unwrap();
+			assert_eq!(pool.assets.to_vec(), vec![DAI, USDT, USDC]);
+
+			let pool_account = Stableswap::pool_account(SHARE_ASSET);
+			assert_eq!(Tokens::free_balance(DAI, &pool_account), dai_state.reserve);
+			assert_eq!(Tokens::free_balance(DAI, &Omnipool::protocol_account()), 0);
+
+			let detail = MigratedAssets::<Test>::get(DAI).unwrap();
+			assert_eq!(detail.pool_id, SHARE_ASSET);
+			assert_eq!(detail.hub_reserve, dai_state.hub_reserve);
+
+			let updated_share_state = Omnipool::load_asset_state(SHARE_ASSET).unwrap();
+			assert_eq!(
+				updated_share_state.hub_reserve,
+				share_state.hub_reserve + dai_state.hub_reserve
+			);
+			assert_eq!(
+				Tokens::total_issuance(SHARE_ASSET),
+				share_issuance + detail.share_tokens
+			);
+			assert_eq!(updated_share_state.reserve, share_state.reserve + detail.share_tokens);
+
+			expect_events(vec![Event::AssetMigrated {
+				asset_id: DAI,
+				pool_id: SHARE_ASSET,
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn migrate_asset_to_subpool_should_fail_when_subpool_does_not_exist() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			OmnipoolSubpools::migrate_asset_to_subpool(RuntimeOrigin::root(), SHARE_ASSET, DAI),
+			Error::<Test>::SubpoolNotFound
+		);
+	});
+}
+
+#[test]
+fn migrate_asset_to_subpool_should_fail_when_native_asset_is_migrated() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, USDT, 2000 * ONE), (ALICE, USDC, 2000 * ONE)])
+		.with_token(USDT, FixedU128::from_float(0.65), ALICE, 1000 * ONE)
+		.with_token(USDC, FixedU128::from_float(0.65), ALICE, 1000 * ONE)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			create_subpool();
+
+			//Act & Assert
+			assert_noop!(
+				OmnipoolSubpools::migrate_asset_to_subpool(RuntimeOrigin::root(), SHARE_ASSET, HDX),
+				Error::<Test>::NotAllowed
+			);
+		});
+}
+
+#[test]
+fn migrate_asset_to_subpool_should_fail_when_called_by_non_authority() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, USDT, 2000 * ONE), (ALICE, USDC, 2000 * ONE)])
+		.with_token(USDT, FixedU128::from_float(0.65), ALICE, 1000 * ONE)
+		.with_token(USDC, FixedU128::from_float(0.65), ALICE, 1000 * ONE)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			create_subpool();
+
+			//Act & Assert
+			assert_noop!(
+				OmnipoolSubpools::migrate_asset_to_subpool(RuntimeOrigin::signed(ALICE), SHARE_ASSET, DAI),
+				sp_runtime::DispatchError::BadOrigin
+			);
+		});
+}
+
+#[test]
+fn migrate_asset_to_subpool_should_fail_when_asset_is_already_migrated() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, USDT, 2000 * ONE), (ALICE, USDC, 2000 * ONE)])
+		.with_token(USDT, FixedU128::from_float(0.65), ALICE, 1000 * ONE)
+		.with_token(USDC, FixedU128::from_float(0.65), ALICE, 1000 * ONE)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			create_subpool();
+
+			//Act & Assert
+			assert_noop!(
+				OmnipoolSubpools::migrate_asset_to_subpool(RuntimeOrigin::root(), SHARE_ASSET, USDT),
+				Error::<Test>::AssetAlreadyMigrated
+			);
+		});
+}
+
+#[test]
+fn migrate_asset_to_subpool_should_fail_when_asset_is_share_asset_of_subpool() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, USDT, 2000 * ONE), (ALICE, USDC, 2000 * ONE)])
+		.with_token(USDT, FixedU128::from_float(0.65), ALICE, 1000 * ONE)
+		.with_token(USDC, FixedU128::from_float(0.65), ALICE, 1000 * ONE)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			create_subpool();
+
+			//Act & Assert
+			assert_noop!(
+				OmnipoolSubpools::migrate_asset_to_subpool(RuntimeOrigin::root(), SHARE_ASSET, SHARE_ASSET),
+				Error::<Test>::ShareAssetNotAllowed
+			);
+		});
+}
@@ -0,0 +1,199 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::mock::*;
+use crate::{Error, Event};
+use frame_support::{assert_noop, assert_ok};
+use hydradx_traits::router::{PoolType, Trade};
+use orml_traits::MultiCurrency;
+use pretty_assertions::assert_eq;
+use sp_runtime::FixedU128;
+
+fn with_subpool() -> sp_io::TestExternalities {
+	let mut ext = ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, USDT, 2000 * ONE),
+			(ALICE, USDC, 2000 * ONE),
+			(BOB, HDX, 1000 * ONE),
+			(BOB, USDT, 1000 * ONE),
+		])
+		.with_token(USDT, FixedU128::from_float(0.65), ALICE, 1000 * ONE)
+		.with_token(USDC, FixedU128::from_float(0.65), ALICE, 1000 * ONE)
+		.build();
+	ext.execute_with(create_subpool);
+	ext
+}
+
+#[test]
+fn trade_route_should_go_through_subpools_of_migrated_assets() {
+	with_subpool().execute_with(|| {
+		assert_eq!(
+			OmnipoolSubpools::trade_route(USDT, USDC),
+			vec![Trade {
+				pool: PoolType::Stableswap(SHARE_ASSET),
+				asset_in: USDT,
+				asset_out: USDC,
+			}]
+		);
+		assert_eq!(
+			OmnipoolSubpools::trade_route(HDX, USDT),
+			vec![
+				Trade {
+					pool: PoolType::Omnipool,
+					asset_in: HDX,
+					asset_out: SHARE_ASSET,
+				},
+				Trade {
+					pool: PoolType::Stableswap(SHARE_ASSET),
+					asset_in: SHARE_ASSET,
+					asset_out: USDT,
+				}
+			]
+		);
+		assert_eq!(
+			OmnipoolSubpools::trade_route(HDX, DAI),
+			vec![Trade {
+				pool: PoolType::Omnipool,
+				asset_in: HDX,
+				asset_out: DAI,
+			}]
+		);
+	});
+}
+
+#[test]
+fn sell_should_work_when_selling_omnipool_asset_for_migrated_asset() {
+	with_subpool().execute_with(|| {
+		//Arrange
+		let amount = 10 * ONE;
+
+		//Act
+		assert_ok!(OmnipoolSubpools::sell(
+			RuntimeOrigin::signed(BOB),
+			HDX,
+			USDT,
+			amount,
+			ONE
+		));
+
+		//Assert
+		assert_eq!(Tokens::free_balance(HDX, &BOB), 1000 * ONE - amount);
+		assert!(Tokens::free_balance(USDT, &BOB) > 1000 * ONE + ONE);
+		assert_eq!(Tokens::free_balance(SHARE_ASSET, &BOB), 0);
+
+		expect_events(vec![Event::SellExecuted {
+			who: BOB,
+			asset_in: HDX,
+			asset_out: USDT,
+			amount_in: amount,
+			amount_out: Tokens::free_balance(USDT, &BOB) - 1000 * ONE,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn sell_should_work_when_selling_migrated_asset_for_omnipool_asset() {
+	with_subpool().execute_with(|| {
+		//Arrange
+		let amount = 10 * ONE;
+
+		//Act
+		assert_ok!(OmnipoolSubpools::sell(
+			RuntimeOrigin::signed(BOB),
+			USDT,
+			DAI,
+			amount,
+			ONE
+		));
+
+		//Assert
+		assert_eq!(Tokens::free_balance(USDT, &BOB), 1000 * ONE - amount);
+		assert!(Tokens::free_balance(DAI, &BOB) > ONE);
+		assert_eq!(Tokens::free_balance(SHARE_ASSET, &BOB), 0);
+	});
+}
+
+#[test]
+fn sell_should_work_when_both_assets_are_in_same_subpool() {
+	with_subpool().execute_with(|| {
+		//Arrange
+		let amount = 10 * ONE;
+
+		//Act
+		assert_ok!(OmnipoolSubpools::sell(
+			RuntimeOrigin::signed(BOB),
+			USDT,
+			USDC,
+			amount,
+			9 * ONE
+		));
+
+		//Assert
+		assert_eq!(Tokens::free_balance(USDT, &BOB), 1000 * ONE - amount);
+		assert!(Tokens::free_balance(USDC, &BOB) >= 9 * ONE);
+	});
+}
+
+#[test]
+fn sell_should_fail_when_buy_limit_is_not_reached() {
+	with_subpool().execute_with(|| {
+		assert_noop!(
+			OmnipoolSubpools::sell(RuntimeOrigin::signed(BOB), HDX, USDT, 10 * ONE, 1000 * ONE),
+			Error::<Test>::BuyLimitNotReached
+		);
+	});
+}
+
+#[test]
+fn buy_should_work_when_buying_migrated_asset_for_omnipool_asset() {
+	with_subpool().execute_with(|| {
+		//Arrange
+		let amount = ONE;
+
+		//Act
+		assert_ok!(OmnipoolSubpools::buy(
+			RuntimeOrigin::signed(BOB),
+			USDT,
+			HDX,
+			amount,
+			100 * ONE
+		));
+
+		//Assert
+		assert_eq!(Tokens::free_balance(USDT, &BOB), 1000 * ONE + amount);
+		assert!(Tokens::free_balance(HDX, &BOB) < 1000 * ONE);
+
+		expect_events(vec![Event::BuyExecuted {
+			who: BOB,
+			asset_in: HDX,
+			asset_out: USDT,
+			amount_in: 1000 * ONE - Tokens::free_balance(HDX, &BOB),
+			amount_out: amount,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn buy_should_fail_when_sell_limit_is_exceeded() {
+	with_subpool().execute_with(|| {
+		assert_noop!(
+			OmnipoolSubpools::buy(RuntimeOrigin::signed(BOB), USDT, HDX, ONE, 1),
+			Error::<Test>::SellLimitExceeded
+		);
+	});
+}
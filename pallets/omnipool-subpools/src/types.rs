@@ -0,0 +1,45 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::pallet_prelude::RuntimeDebug;
+use hydra_dx_math::omnipool_subpools::types::MigrationDetails;
+use pallet_omnipool::types::Balance;
+use scale_info::TypeInfo;
+
+/// Details of an asset migrated from Omnipool to a subpool.
+///
+/// Stored at the time of migration and used to convert existing Omnipool positions of the asset.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct AssetDetail<AssetId> {
+	/// Subpool the asset was migrated to
+	pub pool_id: AssetId,
+	/// Price of the asset in share tokens at the time of migration
+	pub price: (Balance, Balance),
+	/// Omnipool shares of the asset at the time of migration
+	pub shares: Balance,
+	/// Hub reserve of the asset at the time of migration
+	pub hub_reserve: Balance,
+	/// Amount of share tokens minted for the asset
+	pub share_tokens: Balance,
+}
+
+impl<AssetId> AssetDetail<AssetId> {
+	pub fn new(pool_id: AssetId, details: MigrationDetails) -> Self {
+		Self {
+			pool_id,
+			price: details.price,
+			shares: details.shares,
+			hub_reserve: details.hub_reserve,
+			share_tokens: details.share_tokens,
+		}
+	}
+}
+
+impl<AssetId> From<AssetDetail<AssetId>> for MigrationDetails {
+	fn from(detail: AssetDetail<AssetId>) -> Self {
+		Self {
+			price: detail.price,
+			shares: detail.shares,
+			hub_reserve: detail.hub_reserve,
+			share_tokens: detail.share_tokens,
+		}
+	}
+}
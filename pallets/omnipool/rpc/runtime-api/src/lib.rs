@@ -0,0 +1,54 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the omnipool pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+pub use pallet_omnipool::types::{AssetReserveState, Position, PositionValue, TvlCapInfo};
+use sp_runtime::{DispatchError, FixedU128};
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// The API to query Omnipool state.
+	pub trait OmnipoolApi<AssetId, AccountId, PositionId, Balance> where
+		AssetId: Codec,
+		AccountId: Codec,
+		PositionId: Codec,
+		Balance: Codec,
+	{
+		/// Current state of an asset in the Omnipool including its reserve.
+		fn asset_state(asset_id: AssetId) -> Result<AssetReserveState<Balance>, DispatchError>;
+
+		/// All positions owned by `who`.
+		fn positions(who: AccountId) -> Vec<(PositionId, Position<Balance, AssetId>)>;
+
+		/// Value of a position in asset and hub asset if all its liquidity was removed,
+		/// including the withdrawal fee.
+		fn position_value(position_id: PositionId) -> Result<PositionValue<Balance>, DispatchError>;
+
+		/// Spot price of an asset denominated in hub asset, without fees.
+		fn lrna_spot_price(asset_id: AssetId) -> Result<FixedU128, DispatchError>;
+
+		/// TVL of the Omnipool denominated in `stable_asset` and amount of `asset_id`
+		/// which can still be added before `tvl_cap` is reached.
+		fn tvl_cap_info(
+			asset_id: AssetId,
+			stable_asset: AssetId,
+			tvl_cap: Balance,
+		) -> Result<TvlCapInfo<Balance>, DispatchError>;
+	}
+}
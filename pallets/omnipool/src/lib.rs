@@ -115,8 +115,10 @@ pub mod traits;
 pub mod types;
 pub mod weights;
 
-use crate::traits::{AssetInfo, OmnipoolHooks};
-use crate::types::{AssetReserveState, AssetState, Balance, Position, SimpleImbalance, Tradability};
+use crate::traits::{AssetInfo, ExternalPriceProvider, OmnipoolHooks};
+use crate::types::{
+	AssetReserveState, AssetState, Balance, Position, PositionValue, SimpleImbalance, Tradability, TvlCapInfo,
+};
 pub use pallet::*;
 pub use weights::WeightInfo;
 
@@ -363,6 +365,21 @@ pub mod pallet {
 
 		/// Asset's weight cap has been updated.
 		AssetWeightCapUpdated { asset_id: T::AssetId, cap: Permill },
+
+		/// LP Position was split into two positions.
+		PositionSplit {
+			who: T::AccountId,
+			position_id: T::PositionItemId,
+			new_position_id: T::PositionItemId,
+			shares: Balance,
+		},
+
+		/// LP Position was merged into another position of the same asset.
+		PositionsMerged {
+			who: T::AccountId,
+			position_id: T::PositionItemId,
+			merged_position_id: T::PositionItemId,
+		},
 	}
 
 	#[pallet::error]
@@ -432,6 +449,12 @@ pub mod pallet {
 		ExistentialDepositNotAvailable,
 		/// Slippage protection
 		SlippageLimit,
+		/// Position cannot be merged with itself.
+		SamePositionMerge,
+		/// Positions of different assets cannot be merged.
+		PositionAssetMismatch,
+		/// Positions with different prices cannot be merged.
+		PositionPriceMismatch,
 	}
 
 	#[pallet::call]
@@ -1557,6 +1580,149 @@ pub mod pallet {
 			});
 			Ok(())
 		}
+
+		/// Split LP position into two positions.
+		///
+		/// `shares` are moved from the position to a new position and NFT instance is minted for the caller.
+		/// Amount of asset is split proportionally to shares, price of the original position is kept for both positions.
+		///
+		/// Only owner of position can perform this action. Positions deposited in liquidity mining are owned
+		/// by the liquidity mining pallet and must be withdrawn first, otherwise `Forbidden` error is returned.
+		///
+		/// Parameters:
+		/// - `position_id`: The identifier of position to split.
+		/// - `shares`: Amount of shares moved to the new position. Must be less than shares of the position.
+		///
+		/// Emits `PositionUpdated`, `PositionCreated` and `PositionSplit` events when successful.
+		///
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as Config>::WeightInfo::split_position())]
+		#[transactional]
+		pub fn split_position(origin: OriginFor<T>, position_id: T::PositionItemId, shares: Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(shares > Balance::zero(), Error::<T>::InvalidSharesAmount);
+
+			let position = Self::load_position(position_id, who.clone())?;
+
+			ensure!(position.shares > shares, Error::<T>::InsufficientShares);
+
+			let (updated_position, new_position) = position.split(shares).ok_or(ArithmeticError::Overflow)?;
+
+			ensure!(
+				!updated_position.amount.is_zero() && !new_position.amount.is_zero(),
+				Error::<T>::InvalidSharesAmount
+			);
+
+			let new_position_id = Self::create_and_mint_position_instance(&who)?;
+
+			<Positions<T>>::insert(position_id, updated_position.clone());
+			<Positions<T>>::insert(new_position_id, new_position.clone());
+
+			let price = updated_position
+				.price_from_rational()
+				.ok_or(ArithmeticError::DivisionByZero)?;
+
+			Self::deposit_event(Event::PositionUpdated {
+				position_id,
+				owner: who.clone(),
+				asset: updated_position.asset_id,
+				amount: updated_position.amount,
+				shares: updated_position.shares,
+				price,
+			});
+
+			Self::deposit_event(Event::PositionCreated {
+				position_id: new_position_id,
+				owner: who.clone(),
+				asset: new_position.asset_id,
+				amount: new_position.amount,
+				shares: new_position.shares,
+				price,
+			});
+
+			Self::deposit_event(Event::PositionSplit {
+				who,
+				position_id,
+				new_position_id,
+				shares,
+			});
+
+			Ok(())
+		}
+
+		/// Merge two LP positions of the same asset into one.
+		///
+		/// Amount and shares of `other_position_id` are added to `position_id`.
+		/// Only positions with the same price can be merged, so the merged position withdraws the same
+		/// as both positions withdrawn separately. Positions split by `split_position` always have the same price.
+		/// `other_position_id` is destroyed and its NFT instance is burned.
+		///
+		/// Only owner of both positions can perform this action. Positions deposited in liquidity mining are owned
+		/// by the liquidity mining pallet and must be withdrawn first, otherwise `Forbidden` error is returned.
+		///
+		/// Parameters:
+		/// - `position_id`: The identifier of position which is kept.
+		/// - `other_position_id`: The identifier of position which is merged into `position_id`.
+		///
+		/// Emits `PositionUpdated`, `PositionDestroyed` and `PositionsMerged` events when successful.
+		///
+		#[pallet::call_index(16)]
+		#[pallet::weight(<T as Config>::WeightInfo::merge_positions())]
+		#[transactional]
+		pub fn merge_positions(
+			origin: OriginFor<T>,
+			position_id: T::PositionItemId,
+			other_position_id: T::PositionItemId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(position_id != other_position_id, Error::<T>::SamePositionMerge);
+
+			let position = Self::load_position(position_id, who.clone())?;
+			let other_position = Self::load_position(other_position_id, who.clone())?;
+
+			ensure!(
+				position.asset_id == other_position.asset_id,
+				Error::<T>::PositionAssetMismatch
+			);
+			ensure!(
+				position.price_from_rational() == other_position.price_from_rational(),
+				Error::<T>::PositionPriceMismatch
+			);
+
+			let merged_position = position.merge(other_position).ok_or(ArithmeticError::Overflow)?;
+
+			<Positions<T>>::insert(position_id, merged_position.clone());
+
+			// Destroy merged position and burn NFT
+			<Positions<T>>::remove(other_position_id);
+			T::NFTHandler::burn(&T::NFTCollectionId::get(), &other_position_id, Some(&who))?;
+
+			Self::deposit_event(Event::PositionUpdated {
+				position_id,
+				owner: who.clone(),
+				asset: merged_position.asset_id,
+				amount: merged_position.amount,
+				shares: merged_position.shares,
+				price: merged_position
+					.price_from_rational()
+					.ok_or(ArithmeticError::DivisionByZero)?,
+			});
+
+			Self::deposit_event(Event::PositionDestroyed {
+				position_id: other_position_id,
+				owner: who.clone(),
+			});
+
+			Self::deposit_event(Event::PositionsMerged {
+				who,
+				position_id,
+				merged_position_id: other_position_id,
+			});
+
+			Ok(())
+		}
 	}
 
 	#[pallet::hooks]
@@ -2007,6 +2173,78 @@ impl<T: Config> Pallet<T> {
 		Positions::<T>::get(position_id).ok_or_else(|| Error::<T>::PositionNotFound.into())
 	}
 
+	/// Calculate value of a position if all its liquidity was removed at current state.
+	/// Dynamic withdrawal fee is included.
+	pub fn position_value(position_id: T::PositionItemId) -> Result<PositionValue<Balance>, DispatchError> {
+		let position = Positions::<T>::get(position_id).ok_or(Error::<T>::PositionNotFound)?;
+		let asset_state = Self::load_asset_state(position.asset_id)?;
+
+		let ext_asset_price = T::ExternalPriceOracle::get_price(T::HubAssetId::get(), position.asset_id)?;
+		ensure!(!ext_asset_price.is_zero(), Error::<T>::InvalidOraclePrice);
+
+		let withdrawal_fee = hydra_dx_math::omnipool::calculate_withdrawal_fee(
+			asset_state.price().ok_or(ArithmeticError::DivisionByZero)?,
+			FixedU128::checked_from_rational(ext_asset_price.n, ext_asset_price.d)
+				.ok_or(Error::<T>::InvalidOraclePrice)?,
+			T::MinWithdrawalFee::get(),
+		);
+
+		let current_imbalance = <HubAssetImbalance<T>>::get();
+		let state_changes = hydra_dx_math::omnipool::calculate_remove_liquidity_state_changes(
+			&(&asset_state).into(),
+			position.shares,
+			&(&position).into(),
+			I129 {
+				value: current_imbalance.value,
+				negative: current_imbalance.negative,
+			},
+			Self::get_hub_asset_balance_of_protocol_account(),
+			withdrawal_fee,
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+
+		Ok(PositionValue {
+			amount: *state_changes.asset.delta_reserve,
+			hub_amount: state_changes.lp_hub_amount,
+			withdrawal_fee,
+		})
+	}
+
+	/// Spot price of an asset denominated in hub asset, without fees.
+	pub fn lrna_spot_price(asset_id: T::AssetId) -> Result<FixedU128, DispatchError> {
+		let asset_state = Self::load_asset_state(asset_id)?;
+		hydra_dx_math::omnipool::calculate_lrna_spot_price(&(&asset_state).into(), None)
+			.ok_or_else(|| ArithmeticError::DivisionByZero.into())
+	}
+
+	/// Calculate TVL of the Omnipool denominated in `stable_asset` and amount of `asset_id`
+	/// which can still be added before `tvl_cap` is reached.
+	pub fn tvl_cap_info(
+		asset_id: T::AssetId,
+		stable_asset: T::AssetId,
+		tvl_cap: Balance,
+	) -> Result<TvlCapInfo<Balance>, DispatchError> {
+		let asset_state = Self::load_asset_state(asset_id)?;
+		let stable_asset_state = Self::load_asset_state(stable_asset)?;
+		let total_hub_reserve = Self::get_hub_asset_balance_of_protocol_account();
+
+		let tvl = hydra_dx_math::omnipool::calculate_tvl(
+			total_hub_reserve,
+			(stable_asset_state.reserve, stable_asset_state.hub_reserve),
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+
+		let cap_difference = hydra_dx_math::omnipool::calculate_tvl_cap_difference(
+			&(&asset_state).into(),
+			&(&stable_asset_state).into(),
+			tvl_cap,
+			total_hub_reserve,
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+
+		Ok(TvlCapInfo { tvl, cap_difference })
+	}
+
 	pub fn is_hub_asset_allowed(operation: Tradability) -> bool {
 		HubAssetTradability::<T>::get().contains(operation)
 	}
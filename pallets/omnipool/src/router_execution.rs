@@ -1,13 +1,16 @@
-use crate::types::Balance;
-use crate::{Config, Error, HubAssetImbalance, Pallet};
+use crate::types::{Balance, Tradability};
+use crate::{Assets, Config, Error, HubAssetImbalance, Pallet};
+use frame_support::weights::Weight;
 use frame_system::pallet_prelude::OriginFor;
 use hydra_dx_math::omnipool::types::I129;
 
-use hydradx_traits::router::{ExecutorError, PoolType, TradeExecution};
+use hydradx_traits::router::{ExecutorError, PoolType, PoolsProvider, TradeExecution, TradeablePool};
 use orml_traits::{GetByKey, MultiCurrency};
 use sp_runtime::traits::Get;
 use sp_runtime::DispatchError::Corruption;
 use sp_runtime::{ArithmeticError, DispatchError, FixedPointNumber, FixedU128};
+use sp_std::vec;
+use sp_std::vec::Vec;
 
 // dev note: The code is calculate sell and buy is copied from the corresponding functions.
 // This is not ideal and should be refactored to avoid code duplication.
@@ -208,3 +211,26 @@ impl<T: Config> TradeExecution<OriginFor<T>, T::AccountId, T::AssetId, Balance>
 		Ok(spot_price)
 	}
 }
+
+impl<T: Config> PoolsProvider<T::AssetId> for Pallet<T> {
+	fn get_pools(limit: u32) -> Vec<TradeablePool<T::AssetId>> {
+		let assets: Vec<T::AssetId> = Assets::<T>::iter()
+			.filter(|(_, state)| state.tradable.contains(Tradability::SELL | Tradability::BUY))
+			.map(|(asset_id, _)| asset_id)
+			.take(limit as usize)
+			.collect();
+
+		if assets.len() < 2 {
+			return vec![];
+		}
+
+		vec![TradeablePool {
+			pool: PoolType::Omnipool,
+			assets,
+		}]
+	}
+
+	fn get_pools_weight(limit: u32) -> Weight {
+		T::DbWeight::get().reads(limit.into())
+	}
+}
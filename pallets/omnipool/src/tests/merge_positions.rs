@@ -0,0 +1,309 @@
+use super::*;
+use frame_support::assert_noop;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn merge_positions_should_work_when_positions_have_same_price() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+
+			let other_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::split_position(
+				RuntimeOrigin::signed(LP1),
+				position_id,
+				150 * ONE
+			));
+
+			let position = Positions::<Test>::get(position_id).unwrap();
+			let other_position = Positions::<Test>::get(other_position_id).unwrap();
+
+			// Act
+			assert_ok!(Omnipool::merge_positions(
+				RuntimeOrigin::signed(LP1),
+				position_id,
+				other_position_id
+			));
+
+			// Assert
+			assert_eq!(
+				Positions::<Test>::get(position_id).unwrap(),
+				Position {
+					asset_id,
+					amount: position.amount + other_position.amount,
+					shares: position.shares + other_position.shares,
+					price: position.price,
+				}
+			);
+			assert_eq!(Positions::<Test>::get(other_position_id), None);
+
+			assert_eq!(get_mock_minted_position(position_id), Some(LP1));
+			assert_eq!(get_mock_minted_position(other_position_id), None);
+
+			expect_events(vec![Event::PositionsMerged {
+				who: LP1,
+				position_id,
+				merged_position_id: other_position_id,
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn merge_positions_should_fail_when_positions_have_different_prices() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			// - add liquidity twice with a sell in between so positions have different prices
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+
+			assert_ok!(Omnipool::sell(
+				RuntimeOrigin::signed(LP2),
+				asset_id,
+				HDX,
+				100 * ONE,
+				10 * ONE
+			));
+
+			let other_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 200 * ONE));
+
+			// Act & Assert
+			assert_noop!(
+				Omnipool::merge_positions(RuntimeOrigin::signed(LP1), position_id, other_position_id),
+				Error::<Test>::PositionPriceMismatch
+			);
+		});
+}
+
+#[test]
+fn merge_positions_should_restore_position_when_split_positions_are_merged() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+			let position = Positions::<Test>::get(position_id).unwrap();
+
+			let new_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::split_position(
+				RuntimeOrigin::signed(LP1),
+				position_id,
+				150 * ONE
+			));
+
+			// Act
+			assert_ok!(Omnipool::merge_positions(
+				RuntimeOrigin::signed(LP1),
+				position_id,
+				new_position_id
+			));
+
+			// Assert
+			let merged_position = Positions::<Test>::get(position_id).unwrap();
+			assert_eq!(merged_position.amount, position.amount);
+			assert_eq!(merged_position.shares, position.shares);
+			assert_eq!(merged_position.price_from_rational(), position.price_from_rational());
+		});
+}
+
+/// Withdraws all liquidity of two positions split from one position after a trade moved the price.
+/// Returns the amounts of the asset and LRNA received by the LP.
+fn withdraw_split_positions(price_up: bool, merge: bool) -> (Balance, Balance) {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP3, asset_id, 100 * ONE),
+			(LP1, asset_id, 5000 * ONE),
+			(LP2, asset_id, 5000 * ONE),
+			(LP2, DAI, 50000 * ONE),
+		])
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP3, 100 * ONE)
+		.build()
+		.execute_with(|| {
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+			let shares = Positions::<Test>::get(position_id).unwrap().shares;
+
+			let other_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::split_position(
+				RuntimeOrigin::signed(LP1),
+				position_id,
+				shares / 4
+			));
+
+			if price_up {
+				assert_ok!(Omnipool::buy(
+					RuntimeOrigin::signed(LP2),
+					asset_id,
+					DAI,
+					200 * ONE,
+					500000 * ONE
+				));
+			} else {
+				assert_ok!(Omnipool::sell(
+					RuntimeOrigin::signed(LP2),
+					asset_id,
+					DAI,
+					100 * ONE,
+					ONE
+				));
+			}
+
+			let asset_before = Tokens::free_balance(asset_id, &LP1);
+			let lrna_before = Tokens::free_balance(LRNA, &LP1);
+
+			if merge {
+				assert_ok!(Omnipool::merge_positions(
+					RuntimeOrigin::signed(LP1),
+					position_id,
+					other_position_id
+				));
+				assert_ok!(Omnipool::remove_liquidity(
+					RuntimeOrigin::signed(LP1),
+					position_id,
+					shares
+				));
+			} else {
+				assert_ok!(Omnipool::remove_liquidity(
+					RuntimeOrigin::signed(LP1),
+					position_id,
+					shares - shares / 4
+				));
+				assert_ok!(Omnipool::remove_liquidity(
+					RuntimeOrigin::signed(LP1),
+					other_position_id,
+					shares / 4
+				));
+			}
+
+			(
+				Tokens::free_balance(asset_id, &LP1) - asset_before,
+				Tokens::free_balance(LRNA, &LP1) - lrna_before,
+			)
+		})
+}
+
+#[test]
+fn merged_position_should_withdraw_same_as_positions_withdrawn_separately() {
+	for price_up in [true, false] {
+		let (merged_asset, merged_lrna) = withdraw_split_positions(price_up, true);
+		let (separate_asset, separate_lrna) = withdraw_split_positions(price_up, false);
+
+		// two withdrawals round twice
+		assert!(merged_asset.abs_diff(separate_asset) <= 10);
+		assert!(merged_lrna.abs_diff(separate_lrna) <= 10);
+		// LP receives LRNA only when the price went up
+		assert_eq!(merged_lrna > 0, price_up);
+	}
+}
+
+#[test]
+fn merge_positions_should_fail_when_positions_are_of_different_assets() {
+	let asset_a: AssetId = 1_000;
+	let asset_b: AssetId = 2_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_a, 5000 * ONE))
+		.add_endowed_accounts((LP1, asset_b, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_a, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_b, 5000 * ONE))
+		.with_registered_asset(asset_b)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_a, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.with_token(asset_b, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_a, 400 * ONE));
+			let other_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_b, 400 * ONE));
+
+			// Act & Assert
+			assert_noop!(
+				Omnipool::merge_positions(RuntimeOrigin::signed(LP1), position_id, other_position_id),
+				Error::<Test>::PositionAssetMismatch
+			);
+		});
+}
+
+#[test]
+fn merge_positions_should_fail_when_position_is_merged_with_itself() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+
+			// Act & Assert
+			assert_noop!(
+				Omnipool::merge_positions(RuntimeOrigin::signed(LP1), position_id, position_id),
+				Error::<Test>::SamePositionMerge
+			);
+		});
+}
+
+#[test]
+fn merge_positions_should_fail_when_caller_is_not_owner_of_both_positions() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+			let other_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP2), asset_id, 400 * ONE));
+
+			// Act & Assert
+			assert_noop!(
+				Omnipool::merge_positions(RuntimeOrigin::signed(LP1), position_id, other_position_id),
+				Error::<Test>::Forbidden
+			);
+			assert_noop!(
+				Omnipool::merge_positions(RuntimeOrigin::signed(LP1), other_position_id, position_id),
+				Error::<Test>::Forbidden
+			);
+		});
+}
@@ -13,11 +13,14 @@ mod sell;
 mod add_liquidity_with_limit;
 mod barrier;
 mod imbalance;
+mod merge_positions;
 pub(crate) mod mock;
+mod position_value;
 mod positions;
 mod refund;
 mod remove_liquidity_with_limit;
 mod remove_token;
+mod split_position;
 mod spot_price;
 mod tradability;
 mod types;
@@ -0,0 +1,92 @@
+use super::*;
+use crate::types::TvlCapInfo;
+use frame_support::assert_noop;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn position_value_should_match_amount_received_when_all_liquidity_is_removed() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 1_000, 2000 * ONE),
+			(LP1, 1_000, 5000 * ONE),
+		])
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.with_min_withdrawal_fee(Permill::from_percent(1))
+		.build()
+		.execute_with(|| {
+			//Arrange
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), 1_000, 400 * ONE));
+			assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP1), 1_000, DAI, 100 * ONE, 0));
+			let balance_before = Tokens::free_balance(1_000, &LP1);
+
+			//Act
+			let value = Omnipool::position_value(position_id).unwrap();
+
+			//Assert
+			assert_eq!(value.withdrawal_fee, FixedU128::from_rational(1, 100));
+			assert_ok!(Omnipool::remove_liquidity(
+				RuntimeOrigin::signed(LP1),
+				position_id,
+				400 * ONE
+			));
+			assert_eq!(Tokens::free_balance(1_000, &LP1) - balance_before, value.amount);
+			assert_eq!(Tokens::free_balance(LRNA, &LP1), value.hub_amount);
+		});
+}
+
+#[test]
+fn position_value_should_fail_when_position_does_not_exist() {
+	ExtBuilder::default()
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.build()
+		.execute_with(|| {
+			assert_noop!(Omnipool::position_value(1_000), Error::<Test>::PositionNotFound);
+		});
+}
+
+#[test]
+fn lrna_spot_price_should_be_reserve_to_hub_reserve_ratio() {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP2, 1_000, 2000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let state = Omnipool::load_asset_state(1_000).unwrap();
+
+			assert_eq!(
+				Omnipool::lrna_spot_price(1_000).unwrap(),
+				FixedU128::from_rational(state.reserve, state.hub_reserve)
+			);
+		});
+}
+
+#[test]
+fn tvl_cap_info_should_return_tvl_in_stable_asset() {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP2, 1_000, 2000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let stable_state = Omnipool::load_asset_state(DAI).unwrap();
+			let total_hub_reserve = Tokens::free_balance(LRNA, &Omnipool::protocol_account());
+			let expected_tvl = total_hub_reserve * stable_state.reserve / stable_state.hub_reserve;
+
+			assert_eq!(
+				Omnipool::tvl_cap_info(1_000, DAI, expected_tvl).unwrap(),
+				TvlCapInfo {
+					tvl: expected_tvl,
+					cap_difference: 0,
+				}
+			);
+
+			let info = Omnipool::tvl_cap_info(1_000, DAI, 2 * expected_tvl).unwrap();
+			assert_eq!(info.tvl, expected_tvl);
+			assert!(info.cap_difference > 0);
+		});
+}
@@ -0,0 +1,200 @@
+use super::*;
+use frame_support::assert_noop;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn split_position_should_work_when_shares_are_less_than_position_shares() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+			let position = Positions::<Test>::get(position_id).unwrap();
+			let new_position_id = <NextPositionId<Test>>::get();
+
+			// Act
+			assert_ok!(Omnipool::split_position(
+				RuntimeOrigin::signed(LP1),
+				position_id,
+				100 * ONE
+			));
+
+			// Assert
+			assert_eq!(
+				Positions::<Test>::get(position_id).unwrap(),
+				Position {
+					asset_id,
+					amount: 300 * ONE,
+					shares: 300 * ONE,
+					price: position.price,
+				}
+			);
+			assert_eq!(
+				Positions::<Test>::get(new_position_id).unwrap(),
+				Position {
+					asset_id,
+					amount: 100 * ONE,
+					shares: 100 * ONE,
+					price: position.price,
+				}
+			);
+
+			assert_eq!(get_mock_minted_position(position_id), Some(LP1));
+			assert_eq!(get_mock_minted_position(new_position_id), Some(LP1));
+
+			assert_asset_state!(
+				asset_id,
+				AssetReserveState {
+					reserve: 2400 * ONE,
+					hub_reserve: 1560 * ONE,
+					shares: 2400 * ONE,
+					protocol_shares: Balance::zero(),
+					cap: DEFAULT_WEIGHT_CAP,
+					tradable: Tradability::default(),
+				}
+			);
+
+			expect_events(vec![Event::PositionSplit {
+				who: LP1,
+				position_id,
+				new_position_id,
+				shares: 100 * ONE,
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn split_position_should_split_amount_proportionally_when_amount_differs_from_shares() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+			let position = Position {
+				asset_id,
+				amount: 300 * ONE,
+				shares: 400 * ONE,
+				price: Positions::<Test>::get(position_id).unwrap().price,
+			};
+			assert_ok!(Omnipool::set_position(position_id, &position));
+			let new_position_id = <NextPositionId<Test>>::get();
+
+			// Act
+			assert_ok!(Omnipool::split_position(
+				RuntimeOrigin::signed(LP1),
+				position_id,
+				100 * ONE
+			));
+
+			// Assert
+			let updated_position = Positions::<Test>::get(position_id).unwrap();
+			let new_position = Positions::<Test>::get(new_position_id).unwrap();
+
+			assert_eq!(new_position.amount, 75 * ONE);
+			assert_eq!(new_position.shares, 100 * ONE);
+			assert_eq!(updated_position.amount, 225 * ONE);
+			assert_eq!(updated_position.shares, 300 * ONE);
+		});
+}
+
+#[test]
+fn split_position_should_fail_when_caller_is_not_position_owner() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+
+			// Act & Assert
+			assert_noop!(
+				Omnipool::split_position(RuntimeOrigin::signed(LP2), position_id, 100 * ONE),
+				Error::<Test>::Forbidden
+			);
+		});
+}
+
+#[test]
+fn split_position_should_fail_when_shares_are_not_less_than_position_shares() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+
+			// Act & Assert
+			assert_noop!(
+				Omnipool::split_position(RuntimeOrigin::signed(LP1), position_id, 400 * ONE),
+				Error::<Test>::InsufficientShares
+			);
+			assert_noop!(
+				Omnipool::split_position(RuntimeOrigin::signed(LP1), position_id, 500 * ONE),
+				Error::<Test>::InsufficientShares
+			);
+		});
+}
+
+#[test]
+fn split_position_should_fail_when_shares_are_zero() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+
+			// Act & Assert
+			assert_noop!(
+				Omnipool::split_position(RuntimeOrigin::signed(LP1), position_id, 0),
+				Error::<Test>::InvalidSharesAmount
+			);
+		});
+}
+
+#[test]
+fn split_position_should_fail_when_position_does_not_exist() {
+	ExtBuilder::default()
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.build()
+		.execute_with(|| {
+			// Act & Assert
+			assert_noop!(
+				Omnipool::split_position(RuntimeOrigin::signed(LP1), 1_000, 100 * ONE),
+				Error::<Test>::Forbidden
+			);
+		});
+}
@@ -1,5 +1,6 @@
 use super::*;
 use frame_support::assert_noop;
+use hydradx_traits::router::PoolsProvider;
 
 #[test]
 fn sell_asset_tradable_state_should_work_when_hub_asset_new_state_contains_sell_or_buy() {
@@ -56,3 +57,41 @@ fn sell_asset_tradable_state_should_fail_when_hub_asset_new_state_contains_liqui
 			);
 		});
 }
+
+#[test]
+fn get_pools_should_count_only_tradable_assets_towards_limit() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 100, 2000 * ONE),
+			(LP3, 200, 2000 * ONE),
+		])
+		.with_registered_asset(100)
+		.with_registered_asset(200)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(100, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.with_token(200, FixedU128::from_float(0.65), LP3, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			assert_ok!(Omnipool::set_asset_tradable_state(
+				RuntimeOrigin::root(),
+				HDX,
+				Tradability::FROZEN
+			));
+			assert_ok!(Omnipool::set_asset_tradable_state(
+				RuntimeOrigin::root(),
+				DAI,
+				Tradability::FROZEN
+			));
+
+			// Act
+			let pools = <Omnipool as PoolsProvider<AssetId>>::get_pools(2);
+
+			// Assert
+			assert_eq!(pools.len(), 1);
+			let mut assets = pools[0].assets.clone();
+			assets.sort();
+			assert_eq!(assets, vec![100, 200]);
+		});
+}
@@ -4,6 +4,7 @@ use super::*;
 use codec::MaxEncodedLen;
 use frame_support::pallet_prelude::*;
 use hydra_dx_math::omnipool::types::{AssetReserveState as MathReserveState, AssetStateChange, BalanceUpdate};
+use primitive_types::U256;
 use sp_runtime::{FixedPointNumber, FixedU128};
 use sp_std::ops::{Add, Sub};
 
@@ -137,6 +138,48 @@ where
 	}
 }
 
+impl<AssetId: Copy> Position<Balance, AssetId> {
+	/// Split `shares` off the position.
+	///
+	/// Amount is split proportionally to shares, price is kept the same for both parts.
+	/// Returns updated position and the new position holding the split `shares`.
+	pub(super) fn split(self, shares: Balance) -> Option<(Self, Self)> {
+		let amount = U256::from(self.amount)
+			.checked_mul(U256::from(shares))?
+			.checked_div(U256::from(self.shares))?;
+		let amount = Balance::try_from(amount).ok()?;
+
+		let new_position = Self {
+			asset_id: self.asset_id,
+			amount,
+			shares,
+			price: self.price,
+		};
+
+		let updated_position = Self {
+			asset_id: self.asset_id,
+			amount: self.amount.checked_sub(amount)?,
+			shares: self.shares.checked_sub(shares)?,
+			price: self.price,
+		};
+
+		Some((updated_position, new_position))
+	}
+
+	/// Merge `other` position into this one.
+	///
+	/// Amounts and shares are added together, price is kept. Withdrawn amounts are not linear in the position
+	/// price, so only positions with the same price should be merged.
+	pub(super) fn merge(self, other: Self) -> Option<Self> {
+		Some(Self {
+			asset_id: self.asset_id,
+			amount: self.amount.checked_add(other.amount)?,
+			shares: self.shares.checked_add(other.shares)?,
+			price: self.price,
+		})
+	}
+}
+
 /// Simple type to represent imbalance which can be positive or negative.
 // Note: Simple prefix is used not to confuse with Imbalance trait from frame_support.
 #[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
@@ -214,7 +257,7 @@ impl<Balance: CheckedAdd + CheckedSub + PartialOrd + Copy> Sub<Balance> for Simp
 }
 
 /// Asset state representation including asset pool reserve.
-#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
 pub struct AssetReserveState<Balance> {
 	/// Quantity of asset in omnipool
 	pub reserve: Balance,
@@ -319,3 +362,23 @@ where
 		})
 	}
 }
+
+/// Value of a position if all its liquidity was removed at current state.
+#[derive(Clone, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct PositionValue<Balance> {
+	/// Amount of asset received by LP
+	pub amount: Balance,
+	/// Amount of hub asset received by LP
+	pub hub_amount: Balance,
+	/// Withdrawal fee applied
+	pub withdrawal_fee: FixedU128,
+}
+
+/// TVL of the Omnipool and TVL cap usage of an asset.
+#[derive(Clone, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct TvlCapInfo<Balance> {
+	/// Total value locked denominated in the stable asset
+	pub tvl: Balance,
+	/// Amount of asset that can still be added before TVL cap is reached
+	pub cap_difference: Balance,
+}
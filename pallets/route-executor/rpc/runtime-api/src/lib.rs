@@ -0,0 +1,57 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the route executor pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+pub use hydradx_traits::router::{AmountInAndOut, RouteQuote, Trade};
+use sp_runtime::DispatchError;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// The API to simulate trades executed by the router.
+	pub trait RouterApi<AssetId, Balance> where
+		AssetId: Codec,
+		Balance: Codec,
+	{
+		/// Simulate a sell of `amount_in` of `asset_in` for `asset_out`.
+		/// If `route` is not provided, the on-chain route or the default route is used.
+		fn quote_sell(
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount_in: Balance,
+			route: Option<Vec<Trade<AssetId>>>,
+		) -> Result<RouteQuote<AssetId, Balance>, DispatchError>;
+
+		/// Simulate a buy of `amount_out` of `asset_out` for `asset_in`.
+		/// If `route` is not provided, the on-chain route or the default route is used.
+		fn quote_buy(
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount_out: Balance,
+			route: Option<Vec<Trade<AssetId>>>,
+		) -> Result<RouteQuote<AssetId, Balance>, DispatchError>;
+
+		/// Find the route with the biggest amount out for a sell of `amount_in` of `asset_in` for `asset_out`
+		/// and simulate the sell with it.
+		fn find_best_sell_route(
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount_in: Balance,
+		) -> Result<RouteQuote<AssetId, Balance>, DispatchError>;
+	}
+}
@@ -0,0 +1,172 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! RPC interface for the route executor pallet.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc, types::error::ErrorObject};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+pub use pallet_route_executor_rpc_runtime_api::RouterApi as RouterRuntimeApi;
+use pallet_route_executor_rpc_runtime_api::{RouteQuote, Trade};
+
+/// Type of the simulated trade
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum TradeType {
+	/// `amount` is the amount of asset in to sell
+	Sell,
+	/// `amount` is the amount of asset out to buy
+	Buy,
+}
+
+#[rpc(client, server)]
+pub trait RouterApi<BlockHash, AssetId, Balance> {
+	/// Simulate a sell or a buy through the router without executing it.
+	///
+	/// If `route` is not provided, the on-chain route or the default route is used.
+	#[method(name = "router_quote")]
+	fn quote(
+		&self,
+		trade_type: TradeType,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount: Balance,
+		route: Option<Vec<Trade<AssetId>>>,
+		at: Option<BlockHash>,
+	) -> RpcResult<RouteQuote<AssetId, Balance>>;
+
+	/// Find the best route for a sell on-chain and simulate the sell with it.
+	#[method(name = "router_findBestRoute")]
+	fn find_best_route(
+		&self,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		at: Option<BlockHash>,
+	) -> RpcResult<RouteQuote<AssetId, Balance>>;
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+	/// The route could not be calculated.
+	QuoteError,
+	/// No route has been found.
+	RouteNotFound,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::QuoteError => 2,
+			Error::RouteNotFound => 3,
+		}
+	}
+}
+
+/// Provides RPC methods to query router quotes.
+pub struct Router<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> Router<C, Block> {
+	/// Creates a new instance of the Router RPC helper.
+	pub fn new(client: Arc<C>) -> Self {
+		Self {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+impl<C, Block, AssetId, Balance> RouterApiServer<<Block as BlockT>::Hash, AssetId, Balance> for Router<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: RouterRuntimeApi<Block, AssetId, Balance>,
+	AssetId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	Balance: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+	fn quote(
+		&self,
+		trade_type: TradeType,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount: Balance,
+		route: Option<Vec<Trade<AssetId>>>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<RouteQuote<AssetId, Balance>> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let quote = match trade_type {
+			TradeType::Sell => api.quote_sell(at_hash, asset_in, asset_out, amount, route),
+			TradeType::Buy => api.quote_buy(at_hash, asset_in, asset_out, amount, route),
+		}
+		.map_err(|e| {
+			ErrorObject::owned(
+				Error::RuntimeError.into(),
+				"Unable to query router quote.",
+				Some(e.to_string()),
+			)
+		})?;
+
+		quote.map_err(|e| {
+			ErrorObject::owned(
+				Error::QuoteError.into(),
+				"Unable to calculate router quote.",
+				Some(format!("{:?}", e)),
+			)
+		})
+	}
+
+	fn find_best_route(
+		&self,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<RouteQuote<AssetId, Balance>> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let quote = api
+			.find_best_sell_route(at_hash, asset_in, asset_out, amount_in)
+			.map_err(|e| {
+				ErrorObject::owned(
+					Error::RuntimeError.into(),
+					"Unable to query best route.",
+					Some(e.to_string()),
+				)
+			})?;
+
+		quote.map_err(|e| {
+			ErrorObject::owned(
+				Error::RouteNotFound.into(),
+				"Unable to find route.",
+				Some(format!("{:?}", e)),
+			)
+		})
+	}
+}
@@ -33,15 +33,20 @@ use hydra_dx_math::support::rational::{round_u512_to_rational, Rounding};
 use frame_system::pallet_prelude::OriginFor;
 use frame_system::{ensure_signed, Origin};
 use hydradx_traits::registry::Inspect as RegistryInspect;
-use hydradx_traits::router::{inverse_route, AssetPair, RefundEdCalculator, RouteProvider, RouteSpotPriceProvider};
+use hydradx_traits::router::{
+	inverse_route, AssetPair, PoolsProvider, RefundEdCalculator, RouteProvider, RouteSpotPriceProvider, TradeablePool,
+};
 pub use hydradx_traits::router::{
-	AmmTradeWeights, AmountInAndOut, ExecutorError, PoolType, RouterT, Trade, TradeExecution,
+	AmmTradeWeights, AmountInAndOut, ExecutorError, PoolType, RouteQuote, RouterT, Trade, TradeExecution,
 };
 use orml_traits::arithmetic::{CheckedAdd, CheckedSub};
 use sp_core::U512;
-use sp_runtime::traits::{AccountIdConversion, CheckedDiv};
-use sp_runtime::{ArithmeticError, DispatchError, FixedPointNumber, FixedU128, Saturating, TransactionOutcome};
-use sp_std::{vec, vec::Vec};
+use sp_runtime::traits::{AccountIdConversion, CheckedDiv, Zero};
+use sp_runtime::{
+	ArithmeticError, DispatchError, FixedPointNumber, FixedPointOperand, FixedU128, Permill, SaturatedConversion,
+	Saturating, TransactionOutcome,
+};
+use sp_std::{collections::btree_map::BTreeMap, vec, vec::Vec};
 
 #[cfg(test)]
 mod tests;
@@ -49,12 +54,14 @@ pub mod weights;
 
 mod types;
 
+pub use types::{SplitAmount, SplitRoute};
 pub use weights::WeightInfo;
 
 // Re-export pallet items so that they can be accessed from the crate namespace.
 pub use pallet::*;
 
 pub const MAX_NUMBER_OF_TRADES: u32 = 5;
+pub const MAX_NUMBER_OF_SPLIT_ROUTES: u32 = 5;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -89,7 +96,8 @@ pub mod pallet {
 			+ CheckedAdd
 			+ CheckedDiv
 			+ Saturating
-			+ Zero;
+			+ Zero
+			+ FixedPointOperand;
 
 		/// Native Asset Id
 		#[pallet::constant]
@@ -126,6 +134,17 @@ pub mod pallet {
 		/// Origin able to set route without validation
 		type TechnicalOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+		/// Provider of all the pools which are considered in the on-chain route discovery
+		type PoolsProvider: PoolsProvider<Self::AssetId>;
+
+		/// Max number of trade calculations done by a single route discovery
+		#[pallet::constant]
+		type MaxRouteDiscoveryCalculations: Get<u32>;
+
+		/// Max number of pools read from every pool provider by a single route discovery
+		#[pallet::constant]
+		type MaxRouteDiscoveryPools: Get<u32>;
+
 		/// Weight information for the extrinsics.
 		type WeightInfo: AmmTradeWeights<Trade<Self::AssetId>>;
 	}
@@ -142,6 +161,12 @@ pub mod pallet {
 		},
 		///The route with trades has been successfully executed
 		RouteUpdated { asset_ids: Vec<T::AssetId> },
+		///The routes of a split trade have been successfully executed
+		SplitExecuted {
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			legs: Vec<AmountInAndOut<T::Balance>>,
+		},
 	}
 
 	#[pallet::error]
@@ -166,6 +191,12 @@ pub mod pallet {
 		InvalidRouteExecution,
 		/// Trading same assets is not allowed.
 		NotAllowed,
+		/// No route has been found between the assets
+		NoRouteFound,
+		/// The max number of routes in a split trade is reached
+		MaxSplitRoutesExceeded,
+		/// The amounts of the split routes are invalid
+		InvalidSplit,
 	}
 
 	///Flag to indicate when to skip ED handling
@@ -238,54 +269,14 @@ pub mod pallet {
 		) -> DispatchResult {
 			let who = ensure_signed(origin.clone())?;
 
-			ensure!(asset_in != asset_out, Error::<T>::NotAllowed);
-			Self::ensure_route_size(route.len())?;
-
-			let asset_pair = AssetPair::new(asset_in, asset_out);
-			let route = Self::get_route_or_default(route, asset_pair)?;
-			Self::ensure_route_arguments(&asset_pair, &route)?;
-
-			let user_balance_of_asset_in_before_trade =
-				T::Currency::reducible_balance(asset_in, &who, Preservation::Expendable, Fortitude::Polite);
+			let route = Self::get_validated_route(asset_in, asset_out, route)?;
 
 			let trade_amounts = Self::calculate_buy_trade_amounts(&route, amount_out)?;
 
 			let first_trade = trade_amounts.last().ok_or(Error::<T>::RouteCalculationFailed)?;
 			ensure!(first_trade.amount_in <= max_amount_in, Error::<T>::TradingLimitReached);
 
-			let route_length = route.len();
-			for (trade_index, (trade_amount, trade)) in trade_amounts.iter().rev().zip(route).enumerate() {
-				Self::disable_ed_handling_for_insufficient_assets(route_length, trade_index, trade);
-				let user_balance_of_asset_out_before_trade =
-					T::Currency::reducible_balance(trade.asset_out, &who, Preservation::Preserve, Fortitude::Polite);
-				let execution_result = T::AMM::execute_buy(
-					origin.clone(),
-					trade.pool,
-					trade.asset_in,
-					trade.asset_out,
-					trade_amount.amount_out,
-					trade_amount.amount_in,
-				);
-
-				handle_execution_error!(execution_result);
-
-				Self::ensure_that_user_received_asset_out_at_most(
-					who.clone(),
-					trade.asset_in,
-					trade.asset_out,
-					user_balance_of_asset_out_before_trade,
-					trade_amount.amount_out,
-				)?;
-			}
-
-			SkipEd::<T>::kill();
-
-			Self::ensure_that_user_spent_asset_in_at_least(
-				who,
-				asset_in,
-				user_balance_of_asset_in_before_trade,
-				first_trade.amount_in,
-			)?;
+			Self::execute_buy_route(origin, &who, asset_in, route, &trade_amounts)?;
 
 			Self::deposit_event(Event::Executed {
 				asset_in,
@@ -445,6 +436,178 @@ pub mod pallet {
 
 			Self::do_sell(origin, asset_in, asset_out, amount_in, min_amount_out, route)
 		}
+
+		/// Executes a sell with the best route found on-chain for the given asset pair and amount.
+		///
+		/// The route is discovered by searching all the pools provided by `PoolsProvider`, up to `MAX_NUMBER_OF_TRADES` trades.
+		/// The price for each trade is determined by the corresponding AMM.
+		///
+		/// - `origin`: The executor of the trade
+		/// - `asset_in`: The identifier of the asset to sell
+		/// - `asset_out`: The identifier of the asset to receive
+		/// - `amount_in`: The amount of `asset_in` to sell
+		/// - `min_amount_out`: The minimum amount of `asset_out` to receive.
+		///
+		/// Emits `RouteExecuted` when successful.
+		///
+		/// Fails with `NoRouteFound` error when there is no route between the assets
+		///
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::sell_with_best_route_weight())]
+		#[transactional]
+		pub fn sell_with_best_route(
+			origin: OriginFor<T>,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount_in: T::Balance,
+			min_amount_out: T::Balance,
+		) -> DispatchResultWithPostInfo {
+			let _ = ensure_signed(origin.clone())?;
+
+			let (route, _) = Self::find_best_sell_route(asset_in, asset_out, amount_in)?;
+			let actual_weight =
+				T::WeightInfo::find_best_route_weight().saturating_add(T::WeightInfo::sell_weight(&route));
+
+			Self::do_sell(origin, asset_in, asset_out, amount_in, min_amount_out, route)?;
+
+			Ok(Some(actual_weight).into())
+		}
+
+		/// Executes a sell split between several routes.
+		/// The routes are executed one after another, and the price for each trade is determined by the corresponding AMM.
+		///
+		/// - `origin`: The executor of the trade
+		/// - `asset_in`: The identifier of the asset to sell
+		/// - `asset_out`: The identifier of the asset to receive
+		/// - `amount_in`: The total amount of `asset_in` to sell
+		/// - `min_amount_out`: The minimum total amount of `asset_out` to receive from all the routes.
+		/// - `routes`: Series of [`SplitRoute`], each with a route and a weight or an explicit amount of `asset_in` sold through the route.
+		/// 		   Explicit amounts are taken first, then the rest of `amount_in` is distributed between the weighted routes.
+		/// 		   If the route of a [`SplitRoute`] is empty, the on-chain or the default route is used.
+		///
+		/// Emits `SplitExecuted` with the amounts of each route and `Executed` with the total amounts when successful.
+		#[pallet::call_index(6)]
+		#[pallet::weight(Pallet::<T>::sell_split_weight(*asset_in, *asset_out, routes))]
+		#[transactional]
+		pub fn sell_split(
+			origin: OriginFor<T>,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount_in: T::Balance,
+			min_amount_out: T::Balance,
+			routes: Vec<SplitRoute<T::AssetId, T::Balance>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin.clone())?;
+
+			let legs = Self::split_amount(amount_in, routes)?;
+
+			let mut leg_amounts = Vec::<AmountInAndOut<T::Balance>>::with_capacity(legs.len());
+			let mut total_amount_out = T::Balance::zero();
+			for (route, leg_amount_in) in legs {
+				let route = Self::get_validated_route(asset_in, asset_out, route)?;
+
+				// Amounts are calculated right before the execution, as the previous routes may have changed the pools
+				let trade_amounts = Self::calculate_sell_trade_amounts(&route, leg_amount_in)?;
+				let leg_amount_out = trade_amounts
+					.last()
+					.ok_or(Error::<T>::RouteCalculationFailed)?
+					.amount_out;
+
+				Self::execute_sell_route(origin.clone(), &who, asset_in, asset_out, route, &trade_amounts)?;
+
+				total_amount_out = total_amount_out
+					.checked_add(&leg_amount_out)
+					.ok_or(ArithmeticError::Overflow)?;
+				leg_amounts.push(AmountInAndOut {
+					amount_in: leg_amount_in,
+					amount_out: leg_amount_out,
+				});
+			}
+
+			ensure!(total_amount_out >= min_amount_out, Error::<T>::TradingLimitReached);
+
+			Self::deposit_event(Event::SplitExecuted {
+				asset_in,
+				asset_out,
+				legs: leg_amounts,
+			});
+			Self::deposit_event(Event::Executed {
+				asset_in,
+				asset_out,
+				amount_in,
+				amount_out: total_amount_out,
+			});
+
+			Ok(())
+		}
+
+		/// Executes a buy split between several routes.
+		/// The routes are executed one after another, and the price for each trade is determined by the corresponding AMM.
+		///
+		/// - `origin`: The executor of the trade
+		/// - `asset_in`: The identifier of the asset to be swapped to buy `asset_out`
+		/// - `asset_out`: The identifier of the asset to buy
+		/// - `amount_out`: The total amount of `asset_out` to buy
+		/// - `max_amount_in`: The max total amount of `asset_in` to spend on all the routes.
+		/// - `routes`: Series of [`SplitRoute`], each with a route and a weight or an explicit amount of `asset_out` bought through the route.
+		/// 		   Explicit amounts are taken first, then the rest of `amount_out` is distributed between the weighted routes.
+		/// 		   If the route of a [`SplitRoute`] is empty, the on-chain or the default route is used.
+		///
+		/// Emits `SplitExecuted` with the amounts of each route and `Executed` with the total amounts when successful.
+		#[pallet::call_index(7)]
+		#[pallet::weight(Pallet::<T>::buy_split_weight(*asset_in, *asset_out, routes))]
+		#[transactional]
+		pub fn buy_split(
+			origin: OriginFor<T>,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount_out: T::Balance,
+			max_amount_in: T::Balance,
+			routes: Vec<SplitRoute<T::AssetId, T::Balance>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin.clone())?;
+
+			let legs = Self::split_amount(amount_out, routes)?;
+
+			let mut leg_amounts = Vec::<AmountInAndOut<T::Balance>>::with_capacity(legs.len());
+			let mut total_amount_in = T::Balance::zero();
+			for (route, leg_amount_out) in legs {
+				let route = Self::get_validated_route(asset_in, asset_out, route)?;
+
+				// Amounts are calculated right before the execution, as the previous routes may have changed the pools
+				let trade_amounts = Self::calculate_buy_trade_amounts(&route, leg_amount_out)?;
+				let leg_amount_in = trade_amounts
+					.last()
+					.ok_or(Error::<T>::RouteCalculationFailed)?
+					.amount_in;
+
+				Self::execute_buy_route(origin.clone(), &who, asset_in, route, &trade_amounts)?;
+
+				total_amount_in = total_amount_in
+					.checked_add(&leg_amount_in)
+					.ok_or(ArithmeticError::Overflow)?;
+				leg_amounts.push(AmountInAndOut {
+					amount_in: leg_amount_in,
+					amount_out: leg_amount_out,
+				});
+			}
+
+			ensure!(total_amount_in <= max_amount_in, Error::<T>::TradingLimitReached);
+
+			Self::deposit_event(Event::SplitExecuted {
+				asset_in,
+				asset_out,
+				legs: leg_amounts,
+			});
+			Self::deposit_event(Event::Executed {
+				asset_in,
+				asset_out,
+				amount_in: total_amount_in,
+				amount_out,
+			});
+
+			Ok(())
+		}
 	}
 }
 
@@ -464,16 +627,7 @@ impl<T: Config> Pallet<T> {
 	) -> Result<(), DispatchError> {
 		let who = ensure_signed(origin.clone())?;
 
-		ensure!(asset_in != asset_out, Error::<T>::NotAllowed);
-
-		Self::ensure_route_size(route.len())?;
-
-		let asset_pair = AssetPair::new(asset_in, asset_out);
-		let route = Self::get_route_or_default(route, asset_pair)?;
-		Self::ensure_route_arguments(&asset_pair, &route)?;
-
-		let user_balance_of_asset_out_before_trade =
-			T::Currency::reducible_balance(asset_out, &who, Preservation::Preserve, Fortitude::Polite);
+		let route = Self::get_validated_route(asset_in, asset_out, route)?;
 
 		let trade_amounts = Self::calculate_sell_trade_amounts(&route, amount_in)?;
 
@@ -483,12 +637,38 @@ impl<T: Config> Pallet<T> {
 			Error::<T>::TradingLimitReached
 		);
 
+		Self::execute_sell_route(origin, &who, asset_in, asset_out, route, &trade_amounts)?;
+
+		Self::deposit_event(Event::Executed {
+			asset_in,
+			asset_out,
+			amount_in,
+			amount_out: last_trade_amount.amount_out,
+		});
+
+		Ok(())
+	}
+
+	/// Executes the sell trades of the route with the already calculated trade amounts
+	fn execute_sell_route(
+		origin: T::RuntimeOrigin,
+		who: &T::AccountId,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		route: Vec<Trade<T::AssetId>>,
+		trade_amounts: &[AmountInAndOut<T::Balance>],
+	) -> Result<(), DispatchError> {
+		let last_trade_amount = trade_amounts.last().ok_or(Error::<T>::RouteCalculationFailed)?;
+
+		let user_balance_of_asset_out_before_trade =
+			T::Currency::reducible_balance(asset_out, who, Preservation::Preserve, Fortitude::Polite);
+
 		let route_length = route.len();
-		for (trade_index, (trade_amount, trade)) in trade_amounts.iter().zip(route.clone()).enumerate() {
+		for (trade_index, (trade_amount, trade)) in trade_amounts.iter().zip(route).enumerate() {
 			Self::disable_ed_handling_for_insufficient_assets(route_length, trade_index, trade);
 
 			let user_balance_of_asset_in_before_trade =
-				T::Currency::reducible_balance(trade.asset_in, &who, Preservation::Expendable, Fortitude::Polite);
+				T::Currency::reducible_balance(trade.asset_in, who, Preservation::Expendable, Fortitude::Polite);
 
 			let execution_result = T::AMM::execute_sell(
 				origin.clone(),
@@ -512,21 +692,269 @@ impl<T: Config> Pallet<T> {
 		SkipEd::<T>::kill();
 
 		Self::ensure_that_user_received_asset_out_at_most(
-			who,
+			who.clone(),
 			asset_in,
 			asset_out,
 			user_balance_of_asset_out_before_trade,
 			last_trade_amount.amount_out,
-		)?;
+		)
+	}
 
-		Self::deposit_event(Event::Executed {
+	/// Executes the buy trades of the route with the already calculated trade amounts.
+	/// The trade amounts are expected in reversed order, as returned by `calculate_buy_trade_amounts`.
+	fn execute_buy_route(
+		origin: T::RuntimeOrigin,
+		who: &T::AccountId,
+		asset_in: T::AssetId,
+		route: Vec<Trade<T::AssetId>>,
+		trade_amounts: &[AmountInAndOut<T::Balance>],
+	) -> Result<(), DispatchError> {
+		let first_trade = trade_amounts.last().ok_or(Error::<T>::RouteCalculationFailed)?;
+
+		let user_balance_of_asset_in_before_trade =
+			T::Currency::reducible_balance(asset_in, who, Preservation::Expendable, Fortitude::Polite);
+
+		let route_length = route.len();
+		for (trade_index, (trade_amount, trade)) in trade_amounts.iter().rev().zip(route).enumerate() {
+			Self::disable_ed_handling_for_insufficient_assets(route_length, trade_index, trade);
+			let user_balance_of_asset_out_before_trade =
+				T::Currency::reducible_balance(trade.asset_out, who, Preservation::Preserve, Fortitude::Polite);
+			let execution_result = T::AMM::execute_buy(
+				origin.clone(),
+				trade.pool,
+				trade.asset_in,
+				trade.asset_out,
+				trade_amount.amount_out,
+				trade_amount.amount_in,
+			);
+
+			handle_execution_error!(execution_result);
+
+			Self::ensure_that_user_received_asset_out_at_most(
+				who.clone(),
+				trade.asset_in,
+				trade.asset_out,
+				user_balance_of_asset_out_before_trade,
+				trade_amount.amount_out,
+			)?;
+		}
+
+		SkipEd::<T>::kill();
+
+		Self::ensure_that_user_spent_asset_in_at_least(
+			who.clone(),
 			asset_in,
-			asset_out,
-			amount_in,
-			amount_out: last_trade_amount.amount_out,
-		});
+			user_balance_of_asset_in_before_trade,
+			first_trade.amount_in,
+		)
+	}
 
-		Ok(())
+	/// Distributes `amount` between the split routes.
+	///
+	/// Explicit amounts are taken first, then the rest is distributed between the weighted routes proportionally to their weights.
+	/// The last weighted route receives the remainder, so the whole amount is traded.
+	fn split_amount(
+		amount: T::Balance,
+		split_routes: Vec<SplitRoute<T::AssetId, T::Balance>>,
+	) -> Result<Vec<(Vec<Trade<T::AssetId>>, T::Balance)>, DispatchError> {
+		ensure!(!split_routes.is_empty(), Error::<T>::InvalidSplit);
+		ensure!(
+			(split_routes.len() as u32) <= MAX_NUMBER_OF_SPLIT_ROUTES,
+			Error::<T>::MaxSplitRoutesExceeded
+		);
+
+		let mut explicit_amount = T::Balance::zero();
+		let mut total_weight: u32 = 0;
+		for split_route in split_routes.iter() {
+			match split_route.amount {
+				SplitAmount::Amount(leg_amount) => {
+					explicit_amount = explicit_amount
+						.checked_add(&leg_amount)
+						.ok_or(ArithmeticError::Overflow)?;
+				}
+				SplitAmount::Weight(weight) => {
+					total_weight = total_weight.checked_add(weight).ok_or(ArithmeticError::Overflow)?;
+				}
+			}
+		}
+
+		let weighted_amount = amount.checked_sub(&explicit_amount).ok_or(Error::<T>::InvalidSplit)?;
+		ensure!(total_weight > 0 || weighted_amount.is_zero(), Error::<T>::InvalidSplit);
+
+		let mut remaining_amount = weighted_amount;
+		let mut remaining_weight = total_weight;
+		let mut legs = Vec::with_capacity(split_routes.len());
+		for split_route in split_routes {
+			let leg_amount = match split_route.amount {
+				SplitAmount::Amount(leg_amount) => leg_amount,
+				SplitAmount::Weight(weight) => {
+					let leg_amount = if weight == remaining_weight {
+						remaining_amount
+					} else {
+						FixedU128::checked_from_rational(weight, total_weight)
+							.and_then(|share| share.checked_mul_int(weighted_amount))
+							.ok_or(ArithmeticError::Overflow)?
+					};
+					remaining_weight = remaining_weight.saturating_sub(weight);
+					remaining_amount = remaining_amount.saturating_sub(leg_amount);
+					leg_amount
+				}
+			};
+			ensure!(!leg_amount.is_zero(), Error::<T>::InvalidSplit);
+
+			legs.push((split_route.route, leg_amount));
+		}
+
+		Ok(legs)
+	}
+
+	fn sell_split_weight(
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		split_routes: &[SplitRoute<T::AssetId, T::Balance>],
+	) -> Weight {
+		Self::split_weight(asset_in, asset_out, split_routes, |route| {
+			T::WeightInfo::sell_weight(route)
+		})
+	}
+
+	fn buy_split_weight(
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		split_routes: &[SplitRoute<T::AssetId, T::Balance>],
+	) -> Weight {
+		Self::split_weight(asset_in, asset_out, split_routes, |route| {
+			T::WeightInfo::buy_weight(route)
+		})
+	}
+
+	/// Weight of a split trade, where `route_weight` is the weight of a single route.
+	/// An empty route is weighed as the on-chain or default route it is replaced with.
+	fn split_weight(
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		split_routes: &[SplitRoute<T::AssetId, T::Balance>],
+		route_weight: impl Fn(&[Trade<T::AssetId>]) -> Weight,
+	) -> Weight {
+		// Depositing the `SplitExecuted` and `Executed` events
+		let base_weight = T::DbWeight::get().writes(2);
+
+		split_routes.iter().fold(base_weight, |weight, split_route| {
+			let leg_weight = if split_route.route.is_empty() {
+				let route = <Pallet<T> as RouteProvider<T::AssetId>>::get_route(AssetPair::new(asset_in, asset_out));
+				T::WeightInfo::get_route_weight().saturating_add(route_weight(&route))
+			} else {
+				route_weight(&split_route.route)
+			};
+			weight.saturating_add(leg_weight)
+		})
+	}
+
+	fn get_validated_route(
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		route: Vec<Trade<T::AssetId>>,
+	) -> Result<Vec<Trade<T::AssetId>>, DispatchError> {
+		ensure!(asset_in != asset_out, Error::<T>::NotAllowed);
+		Self::ensure_route_size(route.len())?;
+
+		let asset_pair = AssetPair::new(asset_in, asset_out);
+		let route = Self::get_route_or_default(route, asset_pair)?;
+		Self::ensure_route_arguments(&asset_pair, &route)?;
+
+		Ok(route)
+	}
+
+	/// Searches the pool graph for the sell route with the biggest amount out for the given `amount_in`.
+	///
+	/// The graph is built from the pools provided by `PoolsProvider`, where every pair of assets within a pool is an edge.
+	/// The edges of an asset are enumerated from its pools only when the search reaches the asset.
+	/// The search expands the routes hop by hop, up to `MAX_NUMBER_OF_TRADES` trades, keeping only the best partial route
+	/// for each asset on every hop. The number of trade calculations is limited by `MaxRouteDiscoveryCalculations`
+	/// and the number of pools read from every provider by `MaxRouteDiscoveryPools`.
+	///
+	/// Returns the best route together with its amount out.
+	pub fn find_best_sell_route(
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_in: T::Balance,
+	) -> Result<(Vec<Trade<T::AssetId>>, T::Balance), DispatchError> {
+		ensure!(asset_in != asset_out, Error::<T>::NotAllowed);
+
+		// Pools are indexed by their assets, so building the graph is linear in the number of pool assets
+		let pools = T::PoolsProvider::get_pools(T::MaxRouteDiscoveryPools::get());
+		let mut pools_of_asset = BTreeMap::<T::AssetId, Vec<&TradeablePool<T::AssetId>>>::new();
+		for pool in pools.iter() {
+			for asset in pool.assets.iter() {
+				pools_of_asset.entry(*asset).or_default().push(pool);
+			}
+		}
+
+		let max_calculations = T::MaxRouteDiscoveryCalculations::get();
+		let mut calculations: u32 = 0;
+		let mut best_route: Option<(Vec<Trade<T::AssetId>>, T::Balance)> = None;
+		let mut partial_routes: Vec<(Vec<Trade<T::AssetId>>, T::Balance)> = vec![(vec![], amount_in)];
+
+		'search: for _ in 0..MAX_NUMBER_OF_TRADES {
+			let mut next_partial_routes = BTreeMap::<T::AssetId, (Vec<Trade<T::AssetId>>, T::Balance)>::new();
+
+			for (route, amount) in partial_routes {
+				let current_asset = route.last().map_or(asset_in, |trade| trade.asset_out);
+				let Some(current_pools) = pools_of_asset.get(&current_asset) else {
+					continue;
+				};
+				let neighbours = current_pools.iter().copied().flat_map(move |pool| {
+					pool.assets
+						.iter()
+						.filter(move |asset| **asset != current_asset)
+						.map(move |asset| (&pool.pool, asset))
+				});
+
+				for (pool, next_asset) in neighbours {
+					let is_visited =
+						*next_asset == asset_in || route.iter().any(|trade| trade.asset_out == *next_asset);
+					if is_visited {
+						continue;
+					}
+
+					if calculations >= max_calculations {
+						break 'search;
+					}
+					calculations.saturating_inc();
+
+					let amount_out = match T::AMM::calculate_sell(*pool, current_asset, *next_asset, amount) {
+						Ok(amount_out) if !amount_out.is_zero() => amount_out,
+						_ => continue,
+					};
+
+					let mut new_route = route.clone();
+					new_route.push(Trade {
+						pool: *pool,
+						asset_in: current_asset,
+						asset_out: *next_asset,
+					});
+
+					if *next_asset == asset_out {
+						// Routes with less trades are found first, so they are preferred in case of equal amounts
+						if best_route
+							.as_ref()
+							.map_or(true, |(_, best_amount)| amount_out > *best_amount)
+						{
+							best_route = Some((new_route, amount_out));
+						}
+					} else if next_partial_routes
+						.get(next_asset)
+						.map_or(true, |(_, best_amount)| amount_out > *best_amount)
+					{
+						next_partial_routes.insert(*next_asset, (new_route, amount_out));
+					}
+				}
+			}
+
+			partial_routes = next_partial_routes.into_values().collect();
+		}
+
+		best_route.ok_or(Error::<T>::NoRouteFound.into())
 	}
 
 	fn ensure_route_size(route_length: usize) -> Result<(), DispatchError> {
@@ -687,6 +1115,7 @@ impl<T: Config> Pallet<T> {
 			PoolType::Stableswap(pool_id) => pool_id,
 			PoolType::XYK => first_route.asset_out,
 			PoolType::LBP => first_route.asset_out,
+			PoolType::Concentrated(_) => first_route.asset_out,
 		};
 
 		let asset_in_liquidity = T::AMM::get_liquidity_depth(first_route.pool, first_route.asset_in, asset_b);
@@ -802,6 +1231,92 @@ impl<T: Config> Pallet<T> {
 	}
 }
 
+impl<T: Config> Pallet<T> {
+	/// Simulates a sell without executing it.
+	///
+	/// If `route` is empty, the on-chain route is used. If no on-chain route is present, then the default route is used.
+	///
+	/// Returns the route used, the amounts of each trade, the spot price of the route and the price impact of the sell.
+	pub fn quote_sell(
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_in: T::Balance,
+		route: Vec<Trade<T::AssetId>>,
+	) -> Result<RouteQuote<T::AssetId, T::Balance>, DispatchError> {
+		let route = Self::get_validated_route(asset_in, asset_out, route)?;
+
+		let trade_amounts = Self::calculate_sell_trade_amounts(&route, amount_in)?;
+		let amount_out = trade_amounts
+			.last()
+			.ok_or(Error::<T>::RouteCalculationFailed)?
+			.amount_out;
+
+		let spot_price = <Self as RouteSpotPriceProvider<T::AssetId>>::spot_price_with_fee(&route);
+		let price_impact = spot_price
+			.and_then(|price| price.reciprocal())
+			.and_then(|price| price.checked_mul_int(amount_in))
+			.map(|expected_amount_out| Self::calculate_price_impact(expected_amount_out, amount_out));
+
+		Ok(RouteQuote {
+			route,
+			amounts: trade_amounts,
+			amount_in,
+			amount_out,
+			spot_price,
+			price_impact,
+		})
+	}
+
+	/// Simulates a buy without executing it.
+	///
+	/// If `route` is empty, the on-chain route is used. If no on-chain route is present, then the default route is used.
+	///
+	/// Returns the route used, the amounts of each trade, the spot price of the route and the price impact of the buy.
+	pub fn quote_buy(
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_out: T::Balance,
+		route: Vec<Trade<T::AssetId>>,
+	) -> Result<RouteQuote<T::AssetId, T::Balance>, DispatchError> {
+		let route = Self::get_validated_route(asset_in, asset_out, route)?;
+
+		// Trade amounts of a buy are calculated backwards, so we reverse them to match the order of the trades
+		let mut trade_amounts = Self::calculate_buy_trade_amounts(&route, amount_out)?;
+		trade_amounts.reverse();
+		let amount_in = trade_amounts
+			.first()
+			.ok_or(Error::<T>::RouteCalculationFailed)?
+			.amount_in;
+
+		let spot_price = <Self as RouteSpotPriceProvider<T::AssetId>>::spot_price_with_fee(&route);
+		let price_impact = spot_price
+			.and_then(|price| price.checked_mul_int(amount_out))
+			.map(|expected_amount_in| Self::calculate_price_impact(expected_amount_in, amount_in));
+
+		Ok(RouteQuote {
+			route,
+			amounts: trade_amounts,
+			amount_in,
+			amount_out,
+			spot_price,
+			price_impact,
+		})
+	}
+
+	/// Relative difference between the amount expected by the spot price and the calculated amount.
+	/// Saturates at 100%.
+	fn calculate_price_impact(expected_amount: T::Balance, calculated_amount: T::Balance) -> Permill {
+		let expected_amount: u128 = expected_amount.saturated_into();
+		let calculated_amount: u128 = calculated_amount.saturated_into();
+
+		if expected_amount == 0 {
+			return Permill::zero();
+		}
+
+		Permill::from_rational(expected_amount.abs_diff(calculated_amount), expected_amount)
+	}
+}
+
 impl<T: Config> RouterT<T::RuntimeOrigin, T::AssetId, T::Balance, Trade<T::AssetId>, AmountInAndOut<T::Balance>>
 	for Pallet<T>
 {
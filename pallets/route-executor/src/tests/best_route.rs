@@ -0,0 +1,272 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::{Error, Event, Trade};
+use frame_support::{assert_noop, assert_ok};
+use hydradx_traits::router::{PoolType, TradeablePool};
+use pretty_assertions::assert_eq;
+use sp_runtime::DispatchError::BadOrigin;
+
+fn pools() -> Vec<TradeablePool<AssetId>> {
+	vec![
+		TradeablePool {
+			pool: PoolType::Omnipool,
+			assets: vec![HDX, AUSD, DOT],
+		},
+		TradeablePool {
+			pool: PoolType::XYK,
+			assets: vec![HDX, MOVR],
+		},
+		TradeablePool {
+			pool: PoolType::XYK,
+			assets: vec![MOVR, AUSD],
+		},
+	]
+}
+
+fn hdx_movr_ausd_route() -> Vec<Trade<AssetId>> {
+	vec![
+		Trade {
+			pool: PoolType::XYK,
+			asset_in: HDX,
+			asset_out: MOVR,
+		},
+		Trade {
+			pool: PoolType::XYK,
+			asset_in: MOVR,
+			asset_out: AUSD,
+		},
+	]
+}
+
+#[test]
+fn find_best_sell_route_should_return_route_with_biggest_amount_out() {
+	ExtBuilder::default().with_pools(pools()).build().execute_with(|| {
+		//Act
+		let (route, amount_out) = Router::find_best_sell_route(HDX, AUSD, 100).unwrap();
+
+		//Assert
+		assert_eq!(route, hdx_movr_ausd_route());
+		assert_eq!(amount_out, XYK_SELL_CALCULATION_RESULT);
+	});
+}
+
+#[test]
+fn find_best_sell_route_should_prefer_route_with_less_trades_when_amounts_are_equal() {
+	ExtBuilder::default()
+		.with_pools(vec![TradeablePool {
+			pool: PoolType::Omnipool,
+			assets: vec![HDX, DOT, AUSD],
+		}])
+		.build()
+		.execute_with(|| {
+			//Act
+			let (route, amount_out) = Router::find_best_sell_route(HDX, AUSD, 100).unwrap();
+
+			//Assert
+			assert_eq!(route, default_omnipool_route());
+			assert_eq!(amount_out, OMNIPOOL_SELL_CALCULATION_RESULT);
+		});
+}
+
+#[test]
+fn find_best_sell_route_should_use_pools_of_different_types() {
+	ExtBuilder::default()
+		.with_pools(vec![
+			TradeablePool {
+				pool: PoolType::Stableswap(STABLE_SHARE_ASSET),
+				assets: vec![KSM, SDN, STABLE_SHARE_ASSET],
+			},
+			TradeablePool {
+				pool: PoolType::LBP,
+				assets: vec![SDN, RMRK],
+			},
+		])
+		.build()
+		.execute_with(|| {
+			//Act
+			let (route, amount_out) = Router::find_best_sell_route(KSM, RMRK, 100).unwrap();
+
+			//Assert
+			assert_eq!(
+				route,
+				vec![
+					Trade {
+						pool: PoolType::Stableswap(STABLE_SHARE_ASSET),
+						asset_in: KSM,
+						asset_out: SDN,
+					},
+					Trade {
+						pool: PoolType::LBP,
+						asset_in: SDN,
+						asset_out: RMRK,
+					}
+				]
+			);
+			assert_eq!(amount_out, LBP_SELL_CALCULATION_RESULT);
+		});
+}
+
+#[test]
+fn find_best_sell_route_should_fail_when_assets_are_not_connected() {
+	ExtBuilder::default().with_pools(pools()).build().execute_with(|| {
+		//Act and assert
+		assert_noop!(Router::find_best_sell_route(HDX, KSM, 100), Error::<Test>::NoRouteFound);
+	});
+}
+
+#[test]
+fn find_best_sell_route_should_fail_when_there_are_no_pools() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act and assert
+		assert_noop!(
+			Router::find_best_sell_route(HDX, AUSD, 100),
+			Error::<Test>::NoRouteFound
+		);
+	});
+}
+
+#[test]
+fn find_best_sell_route_should_fail_when_all_calculations_fail() {
+	ExtBuilder::default().with_pools(pools()).build().execute_with(|| {
+		//Act and assert
+		assert_noop!(
+			Router::find_best_sell_route(HDX, AUSD, INVALID_CALCULATION_AMOUNT),
+			Error::<Test>::NoRouteFound
+		);
+	});
+}
+
+#[test]
+fn find_best_sell_route_should_fail_when_route_needs_more_trades_than_allowed() {
+	let chain = [HDX, AUSD, MOVR, KSM, RMRK, SDN, DOT];
+	let xyk_pools = chain
+		.windows(2)
+		.map(|assets| TradeablePool {
+			pool: PoolType::XYK,
+			assets: assets.to_vec(),
+		})
+		.collect();
+
+	ExtBuilder::default().with_pools(xyk_pools).build().execute_with(|| {
+		//Act and assert
+		assert_ok!(Router::find_best_sell_route(HDX, SDN, 100));
+		assert_noop!(Router::find_best_sell_route(HDX, DOT, 100), Error::<Test>::NoRouteFound);
+	});
+}
+
+#[test]
+fn find_best_sell_route_should_not_use_pools_above_max_route_discovery_pools() {
+	let mut pools = vec![
+		TradeablePool {
+			pool: PoolType::XYK,
+			assets: vec![KSM, SDN],
+		};
+		MaxRouteDiscoveryPools::get() as usize
+	];
+	pools.push(TradeablePool {
+		pool: PoolType::Omnipool,
+		assets: vec![HDX, AUSD],
+	});
+
+	ExtBuilder::default().with_pools(pools).build().execute_with(|| {
+		//Act and assert
+		assert_noop!(
+			Router::find_best_sell_route(HDX, AUSD, 100),
+			Error::<Test>::NoRouteFound
+		);
+	});
+}
+
+#[test]
+fn find_best_sell_route_should_fail_when_selling_same_assets() {
+	ExtBuilder::default().with_pools(pools()).build().execute_with(|| {
+		//Act and assert
+		assert_noop!(Router::find_best_sell_route(HDX, HDX, 100), Error::<Test>::NotAllowed);
+	});
+}
+
+#[test]
+fn sell_with_best_route_should_execute_trades_of_best_route() {
+	ExtBuilder::default().with_pools(pools()).build().execute_with(|| {
+		//Arrange
+		let amount_to_sell = 100;
+		let limit = 5;
+
+		//Act
+		assert_ok!(Router::sell_with_best_route(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			AUSD,
+			amount_to_sell,
+			limit
+		));
+
+		//Assert
+		assert_executed_sell_trades(vec![
+			(PoolType::XYK, amount_to_sell, HDX, MOVR),
+			(PoolType::XYK, XYK_SELL_CALCULATION_RESULT, MOVR, AUSD),
+		]);
+		expect_events(vec![Event::Executed {
+			asset_in: HDX,
+			asset_out: AUSD,
+			amount_in: amount_to_sell,
+			amount_out: XYK_SELL_CALCULATION_RESULT,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn sell_with_best_route_should_fail_when_min_limit_is_not_reached() {
+	ExtBuilder::default().with_pools(pools()).build().execute_with(|| {
+		//Act and assert
+		assert_noop!(
+			Router::sell_with_best_route(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				AUSD,
+				100,
+				XYK_SELL_CALCULATION_RESULT + 1
+			),
+			Error::<Test>::TradingLimitReached
+		);
+	});
+}
+
+#[test]
+fn sell_with_best_route_should_fail_when_no_route_is_found() {
+	ExtBuilder::default().with_pools(pools()).build().execute_with(|| {
+		//Act and assert
+		assert_noop!(
+			Router::sell_with_best_route(RuntimeOrigin::signed(ALICE), HDX, KSM, 100, 1),
+			Error::<Test>::NoRouteFound
+		);
+	});
+}
+
+#[test]
+fn sell_with_best_route_should_fail_when_called_with_non_signed_origin() {
+	ExtBuilder::default().with_pools(pools()).build().execute_with(|| {
+		//Act and assert
+		assert_noop!(
+			Router::sell_with_best_route(RuntimeOrigin::none(), HDX, AUSD, 100, 1),
+			BadOrigin
+		);
+	});
+}
@@ -20,11 +20,14 @@ use crate::{Config, Trade};
 use frame_support::{
 	parameter_types,
 	traits::{Everything, Nothing},
+	weights::Weight,
 };
 use frame_system::EnsureRoot;
 use frame_system::{ensure_signed, pallet_prelude::OriginFor};
 use hydra_dx_math::ratio::Ratio;
-use hydradx_traits::router::{ExecutorError, PoolType, RefundEdCalculator, TradeExecution};
+use hydradx_traits::router::{
+	ExecutorError, PoolType, PoolsProvider, RefundEdCalculator, TradeExecution, TradeablePool,
+};
 use orml_traits::parameter_type_with_key;
 use pallet_currencies::{fungibles::FungibleCurrencies, BasicCurrencyAdapter, MockBoundErc20, MockErc20Currency};
 use pretty_assertions::assert_eq;
@@ -149,7 +152,8 @@ parameter_types! {
 	pub NativeCurrencyId: AssetId = HDX;
 	pub DefaultRoutePoolType: PoolType<AssetId> = PoolType::Omnipool;
 	pub const RouteValidationOraclePeriod: OraclePeriod = OraclePeriod::TenMinutes;
-
+	pub const MaxRouteDiscoveryCalculations: u32 = 100;
+	pub const MaxRouteDiscoveryPools: u32 = 10;
 }
 
 impl Config for Test {
@@ -165,9 +169,28 @@ impl Config for Test {
 	type OraclePeriod = RouteValidationOraclePeriod;
 	type DefaultRoutePoolType = DefaultRoutePoolType;
 	type TechnicalOrigin = EnsureRoot<Self::AccountId>;
+	type PoolsProvider = MockedPoolsProvider;
+	type MaxRouteDiscoveryCalculations = MaxRouteDiscoveryCalculations;
+	type MaxRouteDiscoveryPools = MaxRouteDiscoveryPools;
 	type WeightInfo = ();
 }
 
+thread_local! {
+	pub static POOLS: RefCell<Vec<TradeablePool<AssetId>>> = RefCell::new(Vec::default());
+}
+
+pub struct MockedPoolsProvider;
+
+impl PoolsProvider<AssetId> for MockedPoolsProvider {
+	fn get_pools(limit: u32) -> Vec<TradeablePool<AssetId>> {
+		POOLS.with(|v| v.borrow().iter().take(limit as usize).cloned().collect())
+	}
+
+	fn get_pools_weight(_limit: u32) -> Weight {
+		Weight::zero()
+	}
+}
+
 pub struct MockedEdCalculator;
 
 impl RefundEdCalculator<Balance> for MockedEdCalculator {
@@ -275,6 +298,7 @@ pub const HDX_AUSD_TRADE_IN_XYK: Trade<AssetId> = Trade {
 
 pub struct ExtBuilder {
 	endowed_accounts: Vec<(AccountId, AssetId, Balance)>,
+	pools: Vec<TradeablePool<AssetId>>,
 }
 
 // Returns default values for genesis config
@@ -282,6 +306,7 @@ impl Default for ExtBuilder {
 	fn default() -> Self {
 		Self {
 			endowed_accounts: vec![(ALICE, HDX, 1000u128)],
+			pools: vec![],
 		}
 	}
 }
@@ -292,9 +317,18 @@ impl ExtBuilder {
 		self
 	}
 
+	pub fn with_pools(mut self, pools: Vec<TradeablePool<AssetId>>) -> Self {
+		self.pools = pools;
+		self
+	}
+
 	pub fn build(self) -> sp_io::TestExternalities {
 		let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
 
+		POOLS.with(|v| {
+			*v.borrow_mut() = self.pools;
+		});
+
 		pallet_balances::GenesisConfig::<Test> {
 			balances: vec![
 				(ALICE, ALICE_INITIAL_NATIVE_BALANCE),
@@ -0,0 +1,179 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::{Error, Trade};
+use frame_support::{assert_noop, assert_ok};
+use hydradx_traits::router::{AmountInAndOut, AssetPair, PoolType, RouteQuote};
+use pretty_assertions::assert_eq;
+use sp_runtime::{FixedU128, Permill};
+
+fn hdx_movr_ausd_route() -> Vec<Trade<AssetId>> {
+	vec![
+		Trade {
+			pool: PoolType::XYK,
+			asset_in: HDX,
+			asset_out: MOVR,
+		},
+		Trade {
+			pool: PoolType::Omnipool,
+			asset_in: MOVR,
+			asset_out: AUSD,
+		},
+	]
+}
+
+#[test]
+fn quote_sell_should_return_amounts_for_each_trade() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let amount_to_sell = 100;
+
+		//Act
+		let quote = Router::quote_sell(HDX, AUSD, amount_to_sell, hdx_movr_ausd_route()).unwrap();
+
+		//Assert
+		assert_eq!(
+			quote,
+			RouteQuote {
+				route: hdx_movr_ausd_route(),
+				amounts: vec![
+					AmountInAndOut {
+						amount_in: amount_to_sell,
+						amount_out: XYK_SELL_CALCULATION_RESULT,
+					},
+					AmountInAndOut {
+						amount_in: XYK_SELL_CALCULATION_RESULT,
+						amount_out: OMNIPOOL_SELL_CALCULATION_RESULT,
+					},
+				],
+				amount_in: amount_to_sell,
+				amount_out: OMNIPOOL_SELL_CALCULATION_RESULT,
+				spot_price: Some(FixedU128::from_rational(1, 100)),
+				price_impact: Some(Permill::from_rational(9_990u32, 10_000u32)),
+			}
+		);
+	});
+}
+
+#[test]
+fn quote_buy_should_return_amounts_in_order_of_trades() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let amount_to_buy = 10;
+
+		//Act
+		let quote = Router::quote_buy(HDX, AUSD, amount_to_buy, hdx_movr_ausd_route()).unwrap();
+
+		//Assert
+		assert_eq!(
+			quote.amounts,
+			vec![
+				AmountInAndOut {
+					amount_in: XYK_BUY_CALCULATION_RESULT,
+					amount_out: OMNIPOOL_BUY_CALCULATION_RESULT,
+				},
+				AmountInAndOut {
+					amount_in: OMNIPOOL_BUY_CALCULATION_RESULT,
+					amount_out: amount_to_buy,
+				},
+			]
+		);
+		assert_eq!(quote.amount_in, XYK_BUY_CALCULATION_RESULT);
+		assert_eq!(quote.amount_out, amount_to_buy);
+		assert_eq!(quote.route, hdx_movr_ausd_route());
+	});
+}
+
+#[test]
+fn quote_sell_should_use_default_route_when_no_route_specified_or_stored() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act
+		let quote = Router::quote_sell(HDX, AUSD, 100, vec![]).unwrap();
+
+		//Assert
+		assert_eq!(quote.route, default_omnipool_route());
+		assert_eq!(quote.amount_out, OMNIPOOL_SELL_CALCULATION_RESULT);
+	});
+}
+
+#[test]
+fn quote_sell_should_use_onchain_route_when_no_route_specified() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		assert_ok!(Router::force_insert_route(
+			RuntimeOrigin::root(),
+			AssetPair::new(HDX, AUSD),
+			hdx_movr_ausd_route()
+		));
+
+		//Act
+		let quote = Router::quote_sell(HDX, AUSD, 100, vec![]).unwrap();
+
+		//Assert
+		assert_eq!(quote.route, hdx_movr_ausd_route());
+		assert_eq!(quote.amount_out, OMNIPOOL_SELL_CALCULATION_RESULT);
+	});
+}
+
+#[test]
+fn quote_buy_should_use_inverse_onchain_route_when_asset_pair_is_not_ordered() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		assert_ok!(Router::force_insert_route(
+			RuntimeOrigin::root(),
+			AssetPair::new(HDX, AUSD),
+			hdx_movr_ausd_route()
+		));
+
+		//Act
+		let quote = Router::quote_buy(AUSD, HDX, 10, vec![]).unwrap();
+
+		//Assert
+		assert_eq!(
+			quote.route,
+			hydradx_traits::router::inverse_route(hdx_movr_ausd_route())
+		);
+	});
+}
+
+#[test]
+fn quote_sell_should_fail_when_assets_are_the_same() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(Router::quote_sell(HDX, HDX, 100, vec![]), Error::<Test>::NotAllowed);
+	});
+}
+
+#[test]
+fn quote_sell_should_fail_when_route_does_not_match_assets() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Router::quote_sell(HDX, DOT, 100, hdx_movr_ausd_route()),
+			Error::<Test>::InvalidRoute
+		);
+	});
+}
+
+#[test]
+fn quote_buy_should_fail_when_calculation_fails_in_amm() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Router::quote_buy(HDX, AUSD, INVALID_CALCULATION_AMOUNT, vec![]),
+			sp_runtime::DispatchError::Other("Some error happened")
+		);
+	});
+}
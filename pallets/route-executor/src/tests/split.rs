@@ -0,0 +1,421 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::{Error, Event, SplitAmount, SplitRoute, Trade, MAX_NUMBER_OF_SPLIT_ROUTES};
+use frame_support::{assert_noop, assert_ok};
+use hydradx_traits::router::{AmountInAndOut, PoolType};
+use sp_runtime::DispatchError::BadOrigin;
+
+const HDX_AUSD_TRADE_IN_OMNIPOOL: Trade<AssetId> = Trade {
+	pool: PoolType::Omnipool,
+	asset_in: HDX,
+	asset_out: AUSD,
+};
+
+const HDX_AUSD_TRADE_IN_LBP: Trade<AssetId> = Trade {
+	pool: PoolType::LBP,
+	asset_in: HDX,
+	asset_out: AUSD,
+};
+
+fn split_route(trade: Trade<AssetId>, amount: SplitAmount<Balance>) -> SplitRoute<AssetId, Balance> {
+	SplitRoute {
+		route: vec![trade],
+		amount,
+	}
+}
+
+#[test]
+fn sell_split_should_distribute_amount_by_weights() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let amount_to_sell = 100;
+		let limit = 5;
+
+		let routes = vec![
+			split_route(HDX_AUSD_TRADE_IN_XYK, SplitAmount::Weight(3)),
+			split_route(HDX_AUSD_TRADE_IN_OMNIPOOL, SplitAmount::Weight(1)),
+		];
+
+		//Act
+		assert_ok!(Router::sell_split(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			AUSD,
+			amount_to_sell,
+			limit,
+			routes
+		));
+
+		//Assert
+		assert_executed_sell_trades(vec![
+			(PoolType::XYK, 75, HDX, AUSD),
+			(PoolType::Omnipool, 25, HDX, AUSD),
+		]);
+		expect_events(vec![
+			Event::SplitExecuted {
+				asset_in: HDX,
+				asset_out: AUSD,
+				legs: vec![
+					AmountInAndOut {
+						amount_in: 75,
+						amount_out: XYK_SELL_CALCULATION_RESULT,
+					},
+					AmountInAndOut {
+						amount_in: 25,
+						amount_out: OMNIPOOL_SELL_CALCULATION_RESULT,
+					},
+				],
+			}
+			.into(),
+			Event::Executed {
+				asset_in: HDX,
+				asset_out: AUSD,
+				amount_in: amount_to_sell,
+				amount_out: XYK_SELL_CALCULATION_RESULT + OMNIPOOL_SELL_CALCULATION_RESULT,
+			}
+			.into(),
+		]);
+	});
+}
+
+#[test]
+fn sell_split_should_take_explicit_amounts_before_weights() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let amount_to_sell = 100;
+		let limit = 5;
+
+		let routes = vec![
+			split_route(HDX_AUSD_TRADE_IN_XYK, SplitAmount::Weight(1)),
+			split_route(HDX_AUSD_TRADE_IN_OMNIPOOL, SplitAmount::Amount(30)),
+		];
+
+		//Act
+		assert_ok!(Router::sell_split(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			AUSD,
+			amount_to_sell,
+			limit,
+			routes
+		));
+
+		//Assert
+		assert_executed_sell_trades(vec![
+			(PoolType::XYK, 70, HDX, AUSD),
+			(PoolType::Omnipool, 30, HDX, AUSD),
+		]);
+	});
+}
+
+#[test]
+fn sell_split_should_give_remainder_to_last_weighted_route() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let amount_to_sell = 100;
+		let limit = 5;
+
+		let routes = vec![
+			split_route(HDX_AUSD_TRADE_IN_XYK, SplitAmount::Weight(1)),
+			split_route(HDX_AUSD_TRADE_IN_OMNIPOOL, SplitAmount::Weight(1)),
+			split_route(HDX_AUSD_TRADE_IN_LBP, SplitAmount::Weight(1)),
+		];
+
+		//Act
+		assert_ok!(Router::sell_split(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			AUSD,
+			amount_to_sell,
+			limit,
+			routes
+		));
+
+		//Assert
+		assert_executed_sell_trades(vec![
+			(PoolType::XYK, 33, HDX, AUSD),
+			(PoolType::Omnipool, 33, HDX, AUSD),
+			(PoolType::LBP, 34, HDX, AUSD),
+		]);
+	});
+}
+
+#[test]
+fn sell_split_should_use_default_route_when_route_is_empty() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let amount_to_sell = 100;
+		let limit = 5;
+
+		let routes = vec![
+			split_route(HDX_AUSD_TRADE_IN_XYK, SplitAmount::Weight(1)),
+			SplitRoute {
+				route: vec![],
+				amount: SplitAmount::Weight(1),
+			},
+		];
+
+		//Act
+		assert_ok!(Router::sell_split(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			AUSD,
+			amount_to_sell,
+			limit,
+			routes
+		));
+
+		//Assert
+		assert_executed_sell_trades(vec![
+			(PoolType::XYK, 50, HDX, AUSD),
+			(PoolType::Omnipool, 50, HDX, AUSD),
+		]);
+	});
+}
+
+#[test]
+fn sell_split_should_fail_when_total_amount_out_is_below_limit() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let routes = vec![
+			split_route(HDX_AUSD_TRADE_IN_XYK, SplitAmount::Weight(1)),
+			split_route(HDX_AUSD_TRADE_IN_OMNIPOOL, SplitAmount::Weight(1)),
+		];
+
+		//Act and assert
+		assert_noop!(
+			Router::sell_split(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				AUSD,
+				100,
+				XYK_SELL_CALCULATION_RESULT + OMNIPOOL_SELL_CALCULATION_RESULT + 1,
+				routes
+			),
+			Error::<Test>::TradingLimitReached
+		);
+	});
+}
+
+#[test]
+fn sell_split_should_fail_when_explicit_amounts_exceed_amount_in() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let routes = vec![
+			split_route(HDX_AUSD_TRADE_IN_XYK, SplitAmount::Amount(60)),
+			split_route(HDX_AUSD_TRADE_IN_OMNIPOOL, SplitAmount::Amount(50)),
+		];
+
+		//Act and assert
+		assert_noop!(
+			Router::sell_split(RuntimeOrigin::signed(ALICE), HDX, AUSD, 100, 5, routes),
+			Error::<Test>::InvalidSplit
+		);
+	});
+}
+
+#[test]
+fn sell_split_should_fail_when_explicit_amounts_do_not_cover_amount_in() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let routes = vec![
+			split_route(HDX_AUSD_TRADE_IN_XYK, SplitAmount::Amount(40)),
+			split_route(HDX_AUSD_TRADE_IN_OMNIPOOL, SplitAmount::Amount(50)),
+		];
+
+		//Act and assert
+		assert_noop!(
+			Router::sell_split(RuntimeOrigin::signed(ALICE), HDX, AUSD, 100, 5, routes),
+			Error::<Test>::InvalidSplit
+		);
+	});
+}
+
+#[test]
+fn sell_split_should_fail_when_route_receives_zero_amount() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let routes = vec![
+			split_route(HDX_AUSD_TRADE_IN_XYK, SplitAmount::Amount(100)),
+			split_route(HDX_AUSD_TRADE_IN_OMNIPOOL, SplitAmount::Weight(1)),
+		];
+
+		//Act and assert
+		assert_noop!(
+			Router::sell_split(RuntimeOrigin::signed(ALICE), HDX, AUSD, 100, 5, routes),
+			Error::<Test>::InvalidSplit
+		);
+	});
+}
+
+#[test]
+fn sell_split_should_fail_when_no_route_is_specified() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act and assert
+		assert_noop!(
+			Router::sell_split(RuntimeOrigin::signed(ALICE), HDX, AUSD, 100, 5, vec![]),
+			Error::<Test>::InvalidSplit
+		);
+	});
+}
+
+#[test]
+fn sell_split_should_fail_when_max_number_of_routes_is_exceeded() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let routes =
+			vec![split_route(HDX_AUSD_TRADE_IN_XYK, SplitAmount::Weight(1)); MAX_NUMBER_OF_SPLIT_ROUTES as usize + 1];
+
+		//Act and assert
+		assert_noop!(
+			Router::sell_split(RuntimeOrigin::signed(ALICE), HDX, AUSD, 100, 5, routes),
+			Error::<Test>::MaxSplitRoutesExceeded
+		);
+	});
+}
+
+#[test]
+fn sell_split_should_fail_when_route_does_not_match_assets() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let routes = vec![
+			split_route(HDX_AUSD_TRADE_IN_XYK, SplitAmount::Weight(1)),
+			split_route(
+				Trade {
+					pool: PoolType::XYK,
+					asset_in: HDX,
+					asset_out: MOVR,
+				},
+				SplitAmount::Weight(1),
+			),
+		];
+
+		//Act and assert
+		assert_noop!(
+			Router::sell_split(RuntimeOrigin::signed(ALICE), HDX, AUSD, 100, 5, routes),
+			Error::<Test>::InvalidRoute
+		);
+	});
+}
+
+#[test]
+fn sell_split_should_fail_when_called_with_non_signed_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let routes = vec![split_route(HDX_AUSD_TRADE_IN_XYK, SplitAmount::Weight(1))];
+
+		//Act and assert
+		assert_noop!(
+			Router::sell_split(RuntimeOrigin::none(), HDX, AUSD, 100, 5, routes),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn buy_split_should_distribute_amount_by_weights() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let amount_to_buy = 10;
+		let limit = 10;
+
+		let routes = vec![
+			split_route(HDX_AUSD_TRADE_IN_XYK, SplitAmount::Weight(1)),
+			split_route(HDX_AUSD_TRADE_IN_OMNIPOOL, SplitAmount::Weight(1)),
+		];
+
+		//Act
+		assert_ok!(Router::buy_split(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			AUSD,
+			amount_to_buy,
+			limit,
+			routes
+		));
+
+		//Assert
+		assert_executed_buy_trades(vec![(PoolType::XYK, 5, HDX, AUSD), (PoolType::Omnipool, 5, HDX, AUSD)]);
+		expect_events(vec![
+			Event::SplitExecuted {
+				asset_in: HDX,
+				asset_out: AUSD,
+				legs: vec![
+					AmountInAndOut {
+						amount_in: XYK_BUY_CALCULATION_RESULT,
+						amount_out: 5,
+					},
+					AmountInAndOut {
+						amount_in: OMNIPOOL_BUY_CALCULATION_RESULT,
+						amount_out: 5,
+					},
+				],
+			}
+			.into(),
+			Event::Executed {
+				asset_in: HDX,
+				asset_out: AUSD,
+				amount_in: XYK_BUY_CALCULATION_RESULT + OMNIPOOL_BUY_CALCULATION_RESULT,
+				amount_out: amount_to_buy,
+			}
+			.into(),
+		]);
+	});
+}
+
+#[test]
+fn buy_split_should_fail_when_total_amount_in_is_above_limit() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let routes = vec![
+			split_route(HDX_AUSD_TRADE_IN_XYK, SplitAmount::Weight(1)),
+			split_route(HDX_AUSD_TRADE_IN_OMNIPOOL, SplitAmount::Weight(1)),
+		];
+
+		//Act and assert
+		assert_noop!(
+			Router::buy_split(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				AUSD,
+				10,
+				XYK_BUY_CALCULATION_RESULT + OMNIPOOL_BUY_CALCULATION_RESULT - 1,
+				routes
+			),
+			Error::<Test>::TradingLimitReached
+		);
+	});
+}
+
+#[test]
+fn buy_split_should_fail_when_explicit_amounts_exceed_amount_out() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let routes = vec![
+			split_route(HDX_AUSD_TRADE_IN_XYK, SplitAmount::Amount(6)),
+			split_route(HDX_AUSD_TRADE_IN_OMNIPOOL, SplitAmount::Amount(5)),
+		];
+
+		//Act and assert
+		assert_noop!(
+			Router::buy_split(RuntimeOrigin::signed(ALICE), HDX, AUSD, 10, 100, routes),
+			Error::<Test>::InvalidSplit
+		);
+	});
+}
@@ -1,5 +1,7 @@
 use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::pallet_prelude::TypeInfo;
+use hydradx_traits::router::Trade;
+use sp_std::vec::Vec;
 
 #[derive(Debug, Encode, Decode, Copy, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub enum SkipEd {
@@ -7,3 +9,19 @@ pub enum SkipEd {
 	LockAndUnlock,
 	Unlock,
 }
+
+/// Specifies which part of the total amount of a split trade is traded through a route
+#[derive(Debug, Encode, Decode, Copy, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub enum SplitAmount<Balance> {
+	/// Share of the amount left after the explicit amounts, relative to the sum of all weights
+	Weight(u32),
+	/// Explicit amount traded through the route
+	Amount(Balance),
+}
+
+/// A route of a split trade, together with the amount traded through it
+#[derive(Debug, Encode, Decode, Clone, PartialEq, Eq, TypeInfo)]
+pub struct SplitRoute<AssetId, Balance> {
+	pub route: Vec<Trade<AssetId>>,
+	pub amount: SplitAmount<Balance>,
+}
@@ -19,16 +19,17 @@
 
 use super::*;
 
-use crate::types::AssetAmount;
+use crate::types::{AssetAmount, RateSource};
 use frame_benchmarking::account;
 use frame_benchmarking::benchmarks;
+use frame_support::traits::ConstU32;
 use frame_support::traits::EnsureOrigin;
 use frame_support::BoundedVec;
 use frame_system::{Pallet as System, RawOrigin};
 use hydradx_traits::router::{PoolType, TradeExecution};
 use orml_traits::MultiCurrency;
 use orml_traits::MultiCurrencyExtended;
-use sp_runtime::Permill;
+use sp_runtime::{FixedU128, Permill};
 
 const ASSET_ID_OFFSET: u32 = 2_000;
 
@@ -716,5 +717,113 @@ benchmarks! {
 		assert!(<crate::Pallet::<T> as TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balance>>::calculate_spot_price_with_fee(PoolType::Stableswap(pool_id), asset_in, asset_out).is_ok());
 	}
 
+	create_pool_with_rates {
+		let mut asset_ids: Vec<T::AssetId> = Vec::new() ;
+		let mut rates = Vec::new();
+		for idx in 0..MAX_ASSETS_IN_POOL{
+			let asset_id = idx + ASSET_ID_OFFSET;
+			T::BenchmarkHelper::register_asset(asset_id.into(), 12)?;
+			asset_ids.push(asset_id.into());
+			rates.push((asset_id.into(), RateSource::Value((1_000_000_000_000u128 + idx as u128, 1_000_000_000_000u128))));
+		}
+		let pool_id = 1000u32;
+		T::BenchmarkHelper::register_asset(pool_id.into(), 18)?;
+		let amplification = 100u16;
+		let trade_fee = Permill::from_percent(1);
+		let rates: BoundedVec<_, ConstU32<MAX_ASSETS_IN_POOL>> = rates.try_into().unwrap();
+		let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+	}: _<T::RuntimeOrigin>(successful_origin, pool_id.into(), asset_ids, amplification, trade_fee, rates)
+	verify {
+		assert!(<Pools<T>>::get::<T::AssetId>(pool_id.into()).is_some());
+		assert!(<PoolRateSources<T>>::get::<T::AssetId>(pool_id.into()).is_some());
+	}
+
+	update_asset_rates {
+		let mut asset_ids: Vec<T::AssetId> = Vec::new() ;
+		let mut rates = Vec::new();
+		for idx in 0..MAX_ASSETS_IN_POOL{
+			let asset_id = idx + ASSET_ID_OFFSET;
+			T::BenchmarkHelper::register_asset(asset_id.into(), 12)?;
+			asset_ids.push(asset_id.into());
+			rates.push((asset_id.into(), RateSource::Value((1_000_000_000_000u128 + idx as u128, 1_000_000_000_000u128))));
+		}
+		let pool_id: T::AssetId = 1000u32.into();
+		T::BenchmarkHelper::register_asset(pool_id, 18)?;
+		let amplification = 100u16;
+		let trade_fee = Permill::from_percent(1);
+		let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+		crate::Pallet::<T>::create_pool(successful_origin,
+			pool_id,
+			asset_ids,
+			amplification,
+			trade_fee,
+		)?;
+		let rates: BoundedVec<_, ConstU32<MAX_ASSETS_IN_POOL>> = rates.try_into().unwrap();
+		let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+	}: _<T::RuntimeOrigin>(successful_origin, pool_id, rates)
+	verify {
+		assert_eq!(<PoolRateSources<T>>::get(pool_id).unwrap().len(), MAX_ASSETS_IN_POOL as usize);
+	}
+
+	schedule_fee_change {
+		let mut asset_ids: Vec<T::AssetId> = Vec::new() ;
+		for idx in 0..MAX_ASSETS_IN_POOL{
+			let asset_id: T::AssetId = (idx + ASSET_ID_OFFSET).into();
+			T::BenchmarkHelper::register_asset(asset_id, 12)?;
+			asset_ids.push(asset_id);
+		}
+		let pool_id: T::AssetId = 1000u32.into();
+		T::BenchmarkHelper::register_asset(pool_id, 18)?;
+		let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+		crate::Pallet::<T>::create_pool(successful_origin.clone(),
+			pool_id,
+			asset_ids,
+			100u16,
+			Permill::from_percent(1),
+		)?;
+
+		// Worst case is when fee is already changing
+		crate::Pallet::<T>::schedule_fee_change(successful_origin.clone(),
+			pool_id,
+			Permill::from_percent(3),
+			100u32.into(),
+			1000u32.into(),
+		)?;
+
+		System::<T>::set_block_number(550u32.into());
+	}: _<T::RuntimeOrigin>(successful_origin, pool_id, Permill::from_percent(1), 551u32.into(), 1000u32.into())
+	verify {
+		let ramp = crate::Pallet::<T>::pool_fee_ramps(pool_id).unwrap();
+		assert_eq!(ramp.initial_fee, Permill::from_percent(2));
+		assert_eq!(ramp.final_fee, Permill::from_percent(1));
+	}
+
+	set_dynamic_fee_params {
+		let mut asset_ids: Vec<T::AssetId> = Vec::new() ;
+		for idx in 0..MAX_ASSETS_IN_POOL{
+			let asset_id: T::AssetId = (idx + ASSET_ID_OFFSET).into();
+			T::BenchmarkHelper::register_asset(asset_id, 12)?;
+			asset_ids.push(asset_id);
+		}
+		let pool_id: T::AssetId = 1000u32.into();
+		T::BenchmarkHelper::register_asset(pool_id, 18)?;
+		let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+		crate::Pallet::<T>::create_pool(successful_origin.clone(),
+			pool_id,
+			asset_ids,
+			100u16,
+			Permill::from_percent(1),
+		)?;
+		let params = FeeParams {
+			min_fee: Permill::from_rational(5u32, 10_000u32),
+			max_fee: Permill::from_percent(1),
+			decay: FixedU128::from_rational(1, 10_000),
+			amplification: FixedU128::from(2),
+		};
+	}: _<T::RuntimeOrigin>(successful_origin, pool_id, Some(params))
+	verify {
+		assert_eq!(crate::Pallet::<T>::dynamic_fee_params(pool_id), Some(params));
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::tests::mock::ExtBuilder::default().build(), crate::tests::mock::Test);
 }
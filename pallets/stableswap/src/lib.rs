@@ -29,6 +29,23 @@
 //!
 //! This is currently used to update on-chain oracle.
 //!
+//! ### Target rates
+//!
+//! Each pool asset can have a target rate which is the value of one unit of the asset relative to other assets in the pool.
+//! Reserves are scaled by the rates before they are used in calculations, which allows pools of assets which are not
+//! pegged 1:1, such as liquid staking or yield-bearing assets.
+//!
+//! Rate can be a fixed value, a price provided by an oracle or a rate provided by an external source.
+//! Pools created by `create_pool` use 1:1 rates. Rates can be set by `create_pool_with_rates` or `update_asset_rates`.
+//!
+//! ### Fees
+//!
+//! Pool fee can be changed immediately by `update_pool_fee` or scheduled to change linearly over a range of blocks
+//! by `schedule_fee_change`, same as amplification.
+//!
+//! Alternatively, pool can use dynamic fee set by `set_dynamic_fee_params`. Dynamic fee is recalculated once per block
+//! from the volume of pool's share asset, same as fees of the Omnipool assets in `pallet-dynamic-fees`.
+//!
 //! ### Terminology
 //!
 //! * **LP** - liquidity provider
@@ -56,10 +73,10 @@ extern crate core;
 use frame_support::pallet_prelude::{DispatchResult, Get};
 use frame_support::{ensure, require_transactional, transactional, PalletId};
 use frame_system::pallet_prelude::BlockNumberFor;
-use hydradx_traits::{registry::Inspect, AccountIdFor};
+use hydradx_traits::{price::PriceBarrier, registry::Inspect, AccountIdFor, AggregatedPriceOracle};
 pub use pallet::*;
-use sp_runtime::traits::{AccountIdConversion, BlockNumberProvider, Zero};
-use sp_runtime::{ArithmeticError, DispatchError, Permill, SaturatedConversion};
+use sp_runtime::traits::{AccountIdConversion, BlockNumberProvider, One, Zero};
+use sp_runtime::{ArithmeticError, DispatchError, FixedU128, Permill, SaturatedConversion};
 use sp_std::num::NonZeroU16;
 use sp_std::prelude::*;
 use sp_std::vec;
@@ -68,10 +85,21 @@ mod trade_execution;
 pub mod types;
 pub mod weights;
 
-use crate::types::{AssetAmount, Balance, PoolInfo, PoolState, StableswapHooks, Tradability};
+use crate::types::{
+	AssetAmount, Balance, DynamicFeeEntry, ExternalRateProvider, FeeRamp, PoolInfo, PoolState, RateSource,
+	StableswapHooks, Tradability,
+};
+use frame_support::weights::Weight;
+use hydra_dx_math::dynamic_fees::recalculate_asset_fee;
+use hydra_dx_math::dynamic_fees::types::OracleEntry;
+use hydra_dx_math::ema::EmaPrice;
 use hydra_dx_math::stableswap::types::AssetReserve;
+use hydra_dx_math::support::rational::{round_to_rational, Rounding};
 use hydradx_traits::pools::DustRemovalAccountWhitelist;
 use orml_traits::MultiCurrency;
+use pallet_dynamic_fees::traits::{Volume, VolumeProvider};
+use pallet_dynamic_fees::types::FeeParams;
+use sp_core::U256;
 use sp_std::collections::btree_map::BTreeMap;
 pub use weights::WeightInfo;
 
@@ -90,6 +118,9 @@ pub const POOL_IDENTIFIER: &[u8] = b"sts";
 
 pub const MAX_ASSETS_IN_POOL: u32 = 5;
 
+/// Maximum amplification of dynamic fee parameters of a pool.
+pub const MAX_DYNAMIC_FEE_AMPLIFICATION: u128 = 100;
+
 const D_ITERATIONS: u8 = hydra_dx_math::stableswap::MAX_D_ITERATIONS;
 const Y_ITERATIONS: u8 = hydra_dx_math::stableswap::MAX_Y_ITERATIONS;
 
@@ -146,6 +177,19 @@ pub mod pallet {
 		/// Hooks are actions executed on add_liquidity, sell or buy.
 		type Hooks: StableswapHooks<Self::AssetId>;
 
+		/// Oracle providing target rates of assets which use `RateSource::Oracle`.
+		type TargetRateOracle: AggregatedPriceOracle<Self::AssetId, BlockNumberFor<Self>, EmaPrice>;
+
+		/// Provider of target rates of assets which use `RateSource::External`.
+		type ExternalRateProvider: ExternalRateProvider<Self::AssetId>;
+
+		/// Provider of volume and liquidity of pool share assets, used to calculate dynamic fees.
+		type VolumeOracle: VolumeProvider<Self::AssetId, Balance>;
+
+		/// Price barrier to prevent adding or removing liquidity when the pool price is manipulated.
+		/// Share price of each pool asset is checked.
+		type PriceBarrier: PriceBarrier<Self::AccountId, Self::AssetId, EmaPrice>;
+
 		/// Minimum pool liquidity
 		#[pallet::constant]
 		type MinPoolLiquidity: Get<Balance>;
@@ -176,6 +220,30 @@ pub mod pallet {
 	pub type AssetTradability<T: Config> =
 		StorageDoubleMap<_, Blake2_128Concat, T::AssetId, Blake2_128Concat, T::AssetId, Tradability, ValueQuery>;
 
+	/// Target rate sources of pool assets, in the same order as pool assets.
+	/// Pool without rate sources uses 1:1 rates.
+	#[pallet::storage]
+	#[pallet::getter(fn pool_rate_sources)]
+	pub type PoolRateSources<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, BoundedVec<RateSource<T::AssetId>, ConstU32<MAX_ASSETS_IN_POOL>>>;
+
+	/// Scheduled fee changes of pools.
+	#[pallet::storage]
+	#[pallet::getter(fn pool_fee_ramps)]
+	pub type PoolFeeRamps<T: Config> = StorageMap<_, Blake2_128Concat, T::AssetId, FeeRamp<BlockNumberFor<T>>>;
+
+	/// Dynamic fee parameters of pools.
+	/// Pool with dynamic fee parameters does not use its static fee.
+	#[pallet::storage]
+	#[pallet::getter(fn dynamic_fee_params)]
+	pub type PoolDynamicFeeParams<T: Config> = StorageMap<_, Blake2_128Concat, T::AssetId, FeeParams<Permill>>;
+
+	/// Last calculated dynamic fees of pools.
+	#[pallet::storage]
+	#[pallet::getter(fn dynamic_fees)]
+	pub type PoolDynamicFees<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, DynamicFeeEntry<BlockNumberFor<T>>>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -241,6 +309,24 @@ pub mod pallet {
 		},
 		/// A pool has been destroyed.
 		PoolDestroyed { pool_id: T::AssetId },
+		/// Target rate sources of pool assets have been updated.
+		RatesUpdated {
+			pool_id: T::AssetId,
+			rates: Vec<RateSource<T::AssetId>>,
+		},
+		/// Fee of a pool has been scheduled to change.
+		FeeChanging {
+			pool_id: T::AssetId,
+			current_fee: Permill,
+			final_fee: Permill,
+			start_block: BlockNumberFor<T>,
+			end_block: BlockNumberFor<T>,
+		},
+		/// Dynamic fee parameters of a pool have been set or removed.
+		DynamicFeeParamsUpdated {
+			pool_id: T::AssetId,
+			params: Option<FeeParams<Permill>>,
+		},
 	}
 
 	#[pallet::error]
@@ -317,6 +403,22 @@ pub mod pallet {
 
 		/// Failed to retrieve asset decimals.
 		UnknownDecimals,
+
+		/// Rate is not valid. Numerator and denominator must be greater than zero.
+		InvalidRate,
+
+		/// Rate of an asset could not be retrieved from its source.
+		RateNotAvailable,
+
+		/// Rate sources must be provided for all pool assets.
+		IncorrectRateSources,
+
+		/// New fee is equal to the current fee.
+		SameFee,
+
+		/// Invalid dynamic fee parameters. Minimum fee must not be greater than maximum fee.
+		/// Decay must be in `(0, 1]` and amplification in `(0, MAX_DYNAMIC_FEE_AMPLIFICATION]`.
+		InvalidFeeParams,
 	}
 
 	#[pallet::call]
@@ -372,6 +474,8 @@ pub mod pallet {
 		///
 		/// if pool does not exist, `PoolNotFound` is returned.
 		///
+		/// Scheduled fee change of the pool is cancelled.
+		///
 		/// Parameters:
 		/// - `origin`: Must be T::AuthorityOrigin
 		/// - `pool_id`: pool to update
@@ -388,6 +492,7 @@ pub mod pallet {
 				let pool = maybe_pool.as_mut().ok_or(Error::<T>::PoolNotFound)?;
 
 				pool.fee = fee;
+				PoolFeeRamps::<T>::remove(pool_id);
 				Self::deposit_event(Event::FeeUpdated { pool_id, fee });
 				Ok(())
 			})
@@ -470,7 +575,10 @@ pub mod pallet {
 		/// Emits `LiquidityAdded` event when successful.
 		#[pallet::call_index(3)]
 		#[pallet::weight(<T as Config>::WeightInfo::add_liquidity()
-							.saturating_add(T::Hooks::on_liquidity_changed_weight(MAX_ASSETS_IN_POOL as usize)))]
+							.saturating_add(T::Hooks::on_liquidity_changed_weight(MAX_ASSETS_IN_POOL as usize))
+							.saturating_add(Pallet::<T>::rates_weight())
+							.saturating_add(Pallet::<T>::fee_weight())
+							.saturating_add(Pallet::<T>::price_barrier_weight()))]
 		#[transactional]
 		pub fn add_liquidity(
 			origin: OriginFor<T>,
@@ -507,7 +615,10 @@ pub mod pallet {
 		/// Emits `LiquidityAdded` event when successful.
 		#[pallet::call_index(4)]
 		#[pallet::weight(<T as Config>::WeightInfo::add_liquidity_shares()
-							.saturating_add(T::Hooks::on_liquidity_changed_weight(MAX_ASSETS_IN_POOL as usize)))]
+							.saturating_add(T::Hooks::on_liquidity_changed_weight(MAX_ASSETS_IN_POOL as usize))
+							.saturating_add(Pallet::<T>::rates_weight())
+							.saturating_add(Pallet::<T>::fee_weight())
+							.saturating_add(Pallet::<T>::price_barrier_weight()))]
 		#[transactional]
 		pub fn add_liquidity_shares(
 			origin: OriginFor<T>,
@@ -546,7 +657,10 @@ pub mod pallet {
 		/// Emits `LiquidityRemoved` event when successful.
 		#[pallet::call_index(5)]
 		#[pallet::weight(<T as Config>::WeightInfo::remove_liquidity_one_asset()
-							.saturating_add(T::Hooks::on_liquidity_changed_weight(MAX_ASSETS_IN_POOL as usize)))]
+							.saturating_add(T::Hooks::on_liquidity_changed_weight(MAX_ASSETS_IN_POOL as usize))
+							.saturating_add(Pallet::<T>::rates_weight())
+							.saturating_add(Pallet::<T>::fee_weight())
+							.saturating_add(Pallet::<T>::price_barrier_weight()))]
 		#[transactional]
 		pub fn remove_liquidity_one_asset(
 			origin: OriginFor<T>,
@@ -573,11 +687,10 @@ pub mod pallet {
 
 			// Retrive pool state.
 			let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			Self::update_dynamic_fee(pool_id);
 			let asset_idx = pool.find_asset(asset_id).ok_or(Error::<T>::AssetNotInPool)?;
 			let pool_account = Self::pool_account(pool_id);
-			let initial_reserves = pool
-				.reserves_with_decimals::<T>(&pool_account)
-				.ok_or(Error::<T>::UnknownDecimals)?;
+			let initial_reserves = Self::pool_reserves(pool_id, &pool)?;
 			let share_issuance = T::Currency::total_issuance(pool_id);
 
 			ensure!(
@@ -587,6 +700,7 @@ pub mod pallet {
 			);
 
 			let amplification = Self::get_amplification(&pool);
+			Self::ensure_price_barrier(&who, pool_id, &pool, &initial_reserves, share_issuance)?;
 
 			//Calculate how much asset user will receive. Note that the fee is already subtracted from the amount.
 			let (amount, fee) = hydra_dx_math::stableswap::calculate_withdraw_one_asset::<D_ITERATIONS, Y_ITERATIONS>(
@@ -595,7 +709,7 @@ pub mod pallet {
 				asset_idx,
 				share_issuance,
 				amplification,
-				pool.fee,
+				Self::get_fee(pool_id, &pool),
 			)
 			.ok_or(ArithmeticError::Overflow)?;
 
@@ -636,7 +750,10 @@ pub mod pallet {
 		/// Emits `LiquidityRemoved` event when successful.
 		#[pallet::call_index(6)]
 		#[pallet::weight(<T as Config>::WeightInfo::withdraw_asset_amount()
-							.saturating_add(T::Hooks::on_liquidity_changed_weight(MAX_ASSETS_IN_POOL as usize)))]
+							.saturating_add(T::Hooks::on_liquidity_changed_weight(MAX_ASSETS_IN_POOL as usize))
+							.saturating_add(Pallet::<T>::rates_weight())
+							.saturating_add(Pallet::<T>::fee_weight())
+							.saturating_add(Pallet::<T>::price_barrier_weight()))]
 		#[transactional]
 		pub fn withdraw_asset_amount(
 			origin: OriginFor<T>,
@@ -655,13 +772,13 @@ pub mod pallet {
 
 			// Retrieve pool state.
 			let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			Self::update_dynamic_fee(pool_id);
 			let asset_idx = pool.find_asset(asset_id).ok_or(Error::<T>::AssetNotInPool)?;
 			let pool_account = Self::pool_account(pool_id);
-			let initial_reserves = pool
-				.reserves_with_decimals::<T>(&pool_account)
-				.ok_or(Error::<T>::UnknownDecimals)?;
+			let initial_reserves = Self::pool_reserves(pool_id, &pool)?;
 			let share_issuance = T::Currency::total_issuance(pool_id);
 			let amplification = Self::get_amplification(&pool);
+			Self::ensure_price_barrier(&who, pool_id, &pool, &initial_reserves, share_issuance)?;
 
 			// Calculate how much shares user needs to provide to receive `amount` of asset.
 			let shares = hydra_dx_math::stableswap::calculate_shares_for_amount::<D_ITERATIONS>(
@@ -670,7 +787,7 @@ pub mod pallet {
 				amount,
 				amplification,
 				share_issuance,
-				pool.fee,
+				Self::get_fee(pool_id, &pool),
 			)
 			.ok_or(ArithmeticError::Overflow)?;
 
@@ -715,7 +832,9 @@ pub mod pallet {
 		///
 		#[pallet::call_index(7)]
 		#[pallet::weight(<T as Config>::WeightInfo::sell()
-							.saturating_add(T::Hooks::on_trade_weight(MAX_ASSETS_IN_POOL as usize)))]
+							.saturating_add(T::Hooks::on_trade_weight(MAX_ASSETS_IN_POOL as usize))
+							.saturating_add(Pallet::<T>::rates_weight())
+							.saturating_add(Pallet::<T>::fee_weight()))]
 		#[transactional]
 		pub fn sell(
 			origin: OriginFor<T>,
@@ -747,10 +866,9 @@ pub mod pallet {
 
 			let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
 			let pool_account = Self::pool_account(pool_id);
-			let initial_reserves = pool
-				.reserves_with_decimals::<T>(&pool_account)
-				.ok_or(Error::<T>::UnknownDecimals)?;
+			let initial_reserves = Self::pool_reserves(pool_id, &pool)?;
 
+			Self::update_dynamic_fee(pool_id);
 			let (amount_out, fee_amount) = Self::calculate_out_amount(pool_id, asset_in, asset_out, amount_in)?;
 			ensure!(amount_out >= min_buy_amount, Error::<T>::BuyLimitNotReached);
 
@@ -771,7 +889,7 @@ pub mod pallet {
 			});
 
 			#[cfg(feature = "try-runtime")]
-			Self::ensure_trade_invariant(pool_id, &initial_reserves, pool.fee);
+			Self::ensure_trade_invariant(pool_id, &initial_reserves, Self::get_fee(pool_id, &pool));
 
 			Ok(())
 		}
@@ -790,7 +908,9 @@ pub mod pallet {
 		///
 		#[pallet::call_index(8)]
 		#[pallet::weight(<T as Config>::WeightInfo::buy()
-							.saturating_add(T::Hooks::on_trade_weight(MAX_ASSETS_IN_POOL as usize)))]
+							.saturating_add(T::Hooks::on_trade_weight(MAX_ASSETS_IN_POOL as usize))
+							.saturating_add(Pallet::<T>::rates_weight())
+							.saturating_add(Pallet::<T>::fee_weight()))]
 		#[transactional]
 		pub fn buy(
 			origin: OriginFor<T>,
@@ -817,10 +937,9 @@ pub mod pallet {
 
 			let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
 			let pool_account = Self::pool_account(pool_id);
-			let initial_reserves = pool
-				.reserves_with_decimals::<T>(&pool_account)
-				.ok_or(Error::<T>::UnknownDecimals)?;
+			let initial_reserves = Self::pool_reserves(pool_id, &pool)?;
 
+			Self::update_dynamic_fee(pool_id);
 			let (amount_in, fee_amount) = Self::calculate_in_amount(pool_id, asset_in, asset_out, amount_out)?;
 
 			let pool_account = Self::pool_account(pool_id);
@@ -849,7 +968,7 @@ pub mod pallet {
 			});
 
 			#[cfg(feature = "try-runtime")]
-			Self::ensure_trade_invariant(pool_id, &initial_reserves, pool.fee);
+			Self::ensure_trade_invariant(pool_id, &initial_reserves, Self::get_fee(pool_id, &pool));
 
 			Ok(())
 		}
@@ -882,7 +1001,9 @@ pub mod pallet {
 		}
 
 		#[pallet::call_index(10)]
-		#[pallet::weight(<T as Config>::WeightInfo::remove_liquidity())]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_liquidity()
+							.saturating_add(Pallet::<T>::rates_weight())
+							.saturating_add(Pallet::<T>::price_barrier_weight()))]
 		#[transactional]
 		pub fn remove_liquidity(
 			origin: OriginFor<T>,
@@ -899,10 +1020,9 @@ pub mod pallet {
 
 			let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
 			let pool_account = Self::pool_account(pool_id);
-			let initial_reserves = pool
-				.reserves_with_decimals::<T>(&pool_account)
-				.ok_or(Error::<T>::UnknownDecimals)?;
+			let initial_reserves = Self::pool_reserves(pool_id, &pool)?;
 			let share_issuance = T::Currency::total_issuance(pool_id);
+			Self::ensure_price_barrier(&who, pool_id, &pool, &initial_reserves, share_issuance)?;
 
 			// We want to ensure that given min amounts are correct. It must contain all pool assets.
 			// We convert vec of min amounts to a map.
@@ -953,6 +1073,10 @@ pub mod pallet {
 			} else {
 				// Remove the pool.
 				Pools::<T>::remove(pool_id);
+				PoolRateSources::<T>::remove(pool_id);
+				PoolFeeRamps::<T>::remove(pool_id);
+				PoolDynamicFeeParams::<T>::remove(pool_id);
+				PoolDynamicFees::<T>::remove(pool_id);
 				let _ = AssetTradability::<T>::clear_prefix(pool_id, MAX_ASSETS_IN_POOL, None);
 				T::DustAccountHandler::remove_account(&Self::pool_account(pool_id))?;
 				Self::deposit_event(Event::PoolDestroyed { pool_id });
@@ -971,6 +1095,226 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Create a stable pool with given list of assets and target rate sources of the assets.
+		///
+		/// Same as `create_pool` but reserves of pool assets are scaled by their target rates in calculations.
+		///
+		/// Parameters:
+		/// - `origin`: Must be T::AuthorityOrigin
+		/// - `share_asset`: Preregistered share asset identifier
+		/// - `assets`: List of Asset ids
+		/// - `amplification`: Pool amplification
+		/// - `fee`: fee to be applied on trade and liquidity operations
+		/// - `rates`: target rate source of each pool asset
+		///
+		/// Emits `PoolCreated` and `RatesUpdated` events if successful.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_pool_with_rates()
+							.saturating_add(Pallet::<T>::rates_weight()))]
+		#[transactional]
+		pub fn create_pool_with_rates(
+			origin: OriginFor<T>,
+			share_asset: T::AssetId,
+			assets: Vec<T::AssetId>,
+			amplification: u16,
+			fee: Permill,
+			rates: BoundedVec<(T::AssetId, RateSource<T::AssetId>), ConstU32<MAX_ASSETS_IN_POOL>>,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			let amplification = NonZeroU16::new(amplification).ok_or(Error::<T>::InvalidAmplification)?;
+
+			let pool_id = Self::do_create_pool(share_asset, &assets, amplification, fee)?;
+			let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+
+			ensure!(rates.len() == pool.assets.len(), Error::<T>::IncorrectRateSources);
+			let mut sources = Vec::with_capacity(pool.assets.len());
+			for asset_id in pool.assets.iter() {
+				let (_, source) = rates
+					.iter()
+					.find(|(id, _)| id == asset_id)
+					.ok_or(Error::<T>::IncorrectRateSources)?;
+				Self::validate_rate_source(source)?;
+				sources.push(source.clone());
+			}
+			let sources: BoundedVec<RateSource<T::AssetId>, ConstU32<MAX_ASSETS_IN_POOL>> =
+				sources.try_into().map_err(|_| Error::<T>::MaxAssetsExceeded)?;
+			PoolRateSources::<T>::insert(pool_id, &sources);
+
+			// Make sure that rates can be retrieved, so the pool is usable.
+			Self::get_rates(pool_id, &pool)?;
+
+			Self::deposit_event(Event::PoolCreated {
+				pool_id,
+				assets,
+				amplification,
+				fee,
+			});
+
+			Self::deposit_event(Event::AmplificationChanging {
+				pool_id,
+				current_amplification: amplification,
+				final_amplification: amplification,
+				start_block: T::BlockNumberProvider::current_block_number(),
+				end_block: T::BlockNumberProvider::current_block_number(),
+			});
+
+			Self::deposit_event(Event::RatesUpdated {
+				pool_id,
+				rates: sources.into_inner(),
+			});
+			Ok(())
+		}
+
+		/// Update target rate sources of pool assets.
+		///
+		/// Only rate sources of given assets are updated. If the pool has no rate sources yet,
+		/// remaining assets use 1:1 rate.
+		///
+		/// Parameters:
+		/// - `origin`: Must be T::AuthorityOrigin
+		/// - `pool_id`: pool to update
+		/// - `rates`: list of assets and their new target rate sources
+		///
+		/// Emits `RatesUpdated` event if successful.
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_asset_rates()
+							.saturating_add(Pallet::<T>::rates_weight()))]
+		#[transactional]
+		pub fn update_asset_rates(
+			origin: OriginFor<T>,
+			pool_id: T::AssetId,
+			rates: BoundedVec<(T::AssetId, RateSource<T::AssetId>), ConstU32<MAX_ASSETS_IN_POOL>>,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			ensure!(!rates.is_empty(), Error::<T>::IncorrectRateSources);
+
+			let mut sources = PoolRateSources::<T>::get(pool_id)
+				.map(|v| v.into_inner())
+				.unwrap_or_else(|| {
+					pool.assets
+						.iter()
+						.map(|_| RateSource::default())
+						.collect::<Vec<RateSource<T::AssetId>>>()
+				});
+			ensure!(sources.len() == pool.assets.len(), Error::<T>::IncorrectRateSources);
+
+			for (asset_id, source) in rates.into_iter() {
+				let asset_idx = pool.find_asset(asset_id).ok_or(Error::<T>::AssetNotInPool)?;
+				Self::validate_rate_source(&source)?;
+				sources[asset_idx] = source;
+			}
+			let sources: BoundedVec<RateSource<T::AssetId>, ConstU32<MAX_ASSETS_IN_POOL>> =
+				sources.try_into().map_err(|_| Error::<T>::MaxAssetsExceeded)?;
+			PoolRateSources::<T>::insert(pool_id, &sources);
+
+			// Make sure that rates can be retrieved, so the pool is usable.
+			Self::get_rates(pool_id, &pool)?;
+
+			Self::deposit_event(Event::RatesUpdated {
+				pool_id,
+				rates: sources.into_inner(),
+			});
+			Ok(())
+		}
+
+		/// Schedule a change of pool's fee.
+		///
+		/// Fee changes linearly from the current fee to `final_fee` between `start_block` and `end_block`,
+		/// same as amplification. Pool's fee is set to `final_fee` which is used once the change is finished.
+		///
+		/// Parameters:
+		/// - `origin`: Must be T::AuthorityOrigin
+		/// - `pool_id`: pool to update
+		/// - `final_fee`: new desired pool fee
+		/// - `start_block`: block number when the fee starts to change
+		/// - `end_block`: block number when the fee reaches `final_fee`
+		///
+		/// Emits `FeeChanging` event if successful.
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as Config>::WeightInfo::schedule_fee_change())]
+		#[transactional]
+		pub fn schedule_fee_change(
+			origin: OriginFor<T>,
+			pool_id: T::AssetId,
+			final_fee: Permill,
+			start_block: BlockNumberFor<T>,
+			end_block: BlockNumberFor<T>,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			let current_block = T::BlockNumberProvider::current_block_number();
+			ensure!(
+				end_block > start_block && start_block >= current_block,
+				Error::<T>::PastBlock
+			);
+
+			Pools::<T>::try_mutate(pool_id, |maybe_pool| -> DispatchResult {
+				let pool = maybe_pool.as_mut().ok_or(Error::<T>::PoolNotFound)?;
+
+				let current_fee = Self::get_static_fee(pool_id, pool);
+				ensure!(current_fee != final_fee, Error::<T>::SameFee);
+
+				pool.fee = final_fee;
+				PoolFeeRamps::<T>::insert(
+					pool_id,
+					FeeRamp {
+						initial_fee: current_fee,
+						final_fee,
+						initial_block: start_block,
+						final_block: end_block,
+					},
+				);
+
+				Self::deposit_event(Event::FeeChanging {
+					pool_id,
+					current_fee,
+					final_fee,
+					start_block,
+					end_block,
+				});
+				Ok(())
+			})
+		}
+
+		/// Set or remove dynamic fee parameters of a pool.
+		///
+		/// Fee of a pool with dynamic fee parameters is recalculated once per block from the volume
+		/// of pool's share asset provided by `T::VolumeOracle`, starting at `min_fee`.
+		/// Static fee and scheduled fee change of the pool are used again once the parameters are removed.
+		/// Decay must be in `(0, 1]` and amplification in `(0, MAX_DYNAMIC_FEE_AMPLIFICATION]`.
+		///
+		/// Parameters:
+		/// - `origin`: Must be T::AuthorityOrigin
+		/// - `pool_id`: pool to update
+		/// - `params`: dynamic fee parameters, `None` to remove them
+		///
+		/// Emits `DynamicFeeParamsUpdated` event if successful.
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_dynamic_fee_params())]
+		#[transactional]
+		pub fn set_dynamic_fee_params(
+			origin: OriginFor<T>,
+			pool_id: T::AssetId,
+			params: Option<FeeParams<Permill>>,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			ensure!(Pools::<T>::contains_key(pool_id), Error::<T>::PoolNotFound);
+
+			if let Some(params) = params {
+				ensure!(Self::is_valid_fee_params(&params), Error::<T>::InvalidFeeParams);
+			}
+
+			PoolDynamicFeeParams::<T>::set(pool_id, params);
+			PoolDynamicFees::<T>::remove(pool_id);
+
+			Self::deposit_event(Event::DynamicFeeParamsUpdated { pool_id, params });
+			Ok(())
+		}
 	}
 
 	#[pallet::hooks]
@@ -996,10 +1340,7 @@ impl<T: Config> Pallet<T> {
 		let index_in = pool.find_asset(asset_in).ok_or(Error::<T>::AssetNotInPool)?;
 		let index_out = pool.find_asset(asset_out).ok_or(Error::<T>::AssetNotInPool)?;
 
-		let pool_account = Self::pool_account(pool_id);
-		let initial_reserves = pool
-			.reserves_with_decimals::<T>(&pool_account)
-			.ok_or(Error::<T>::UnknownDecimals)?;
+		let initial_reserves = Self::pool_reserves(pool_id, &pool)?;
 
 		ensure!(!initial_reserves[index_in].is_zero(), Error::<T>::InsufficientLiquidity);
 		ensure!(
@@ -1014,7 +1355,7 @@ impl<T: Config> Pallet<T> {
 			index_out,
 			amount_in,
 			amplification,
-			pool.fee,
+			Self::get_fee(pool_id, &pool),
 		)
 		.ok_or_else(|| ArithmeticError::Overflow.into())
 	}
@@ -1032,10 +1373,7 @@ impl<T: Config> Pallet<T> {
 		let index_in = pool.find_asset(asset_in).ok_or(Error::<T>::AssetNotInPool)?;
 		let index_out = pool.find_asset(asset_out).ok_or(Error::<T>::AssetNotInPool)?;
 
-		let pool_account = Self::pool_account(pool_id);
-		let initial_reserves = pool
-			.reserves_with_decimals::<T>(&pool_account)
-			.ok_or(Error::<T>::UnknownDecimals)?;
+		let initial_reserves = Self::pool_reserves(pool_id, &pool)?;
 
 		ensure!(
 			initial_reserves[index_out].amount > amount_out,
@@ -1050,13 +1388,13 @@ impl<T: Config> Pallet<T> {
 			index_out,
 			amount_out,
 			amplification,
-			pool.fee,
+			Self::get_fee(pool_id, &pool),
 		)
 		.ok_or_else(|| ArithmeticError::Overflow.into())
 	}
 
 	#[require_transactional]
-	fn do_create_pool(
+	pub fn do_create_pool(
 		share_asset: T::AssetId,
 		assets: &[T::AssetId],
 		amplification: NonZeroU16,
@@ -1100,6 +1438,41 @@ impl<T: Config> Pallet<T> {
 		Ok(share_asset)
 	}
 
+	/// Add new asset to an existing pool.
+	///
+	/// Liquidity of the asset must be transferred to the pool account separately.
+	/// No events emitted.
+	#[require_transactional]
+	pub fn do_add_asset_to_pool(pool_id: T::AssetId, asset_id: T::AssetId) -> DispatchResult {
+		ensure!(T::AssetInspection::exists(asset_id), Error::<T>::AssetNotRegistered);
+		ensure!(asset_id != pool_id, Error::<T>::ShareAssetInPoolAssets);
+
+		Pools::<T>::try_mutate(pool_id, |maybe_pool| -> DispatchResult {
+			let pool = maybe_pool.as_mut().ok_or(Error::<T>::PoolNotFound)?;
+			ensure!(pool.find_asset(asset_id).is_none(), Error::<T>::IncorrectAssets);
+
+			let mut pool_assets = pool.assets.to_vec();
+			pool_assets.push(asset_id);
+			pool_assets.sort();
+			let asset_idx = pool_assets
+				.iter()
+				.position(|v| *v == asset_id)
+				.ok_or(Error::<T>::AssetNotInPool)?;
+
+			pool.assets = pool_assets.try_into().map_err(|_| Error::<T>::MaxAssetsExceeded)?;
+
+			// New asset uses 1:1 rate in pools with rate sources.
+			PoolRateSources::<T>::try_mutate_exists(pool_id, |maybe_sources| -> DispatchResult {
+				if let Some(sources) = maybe_sources.as_mut() {
+					sources
+						.try_insert(asset_idx, RateSource::default())
+						.map_err(|_| Error::<T>::MaxAssetsExceeded)?;
+				}
+				Ok(())
+			})
+		})
+	}
+
 	#[require_transactional]
 	fn do_add_liquidity(
 		who: &T::AccountId,
@@ -1107,6 +1480,7 @@ impl<T: Config> Pallet<T> {
 		assets: &[AssetAmount<T::AssetId>],
 	) -> Result<Balance, DispatchError> {
 		let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+		Self::update_dynamic_fee(pool_id);
 		ensure!(assets.len() <= pool.assets.len(), Error::<T>::MaxAssetsExceeded);
 		let mut added_assets = BTreeMap::<T::AssetId, Balance>::new();
 		for asset in assets.iter() {
@@ -1128,29 +1502,20 @@ impl<T: Config> Pallet<T> {
 		}
 
 		let pool_account = Self::pool_account(pool_id);
-		let mut initial_reserves = Vec::with_capacity(pool.assets.len());
+		let initial_reserves = Self::pool_reserves(pool_id, &pool)?;
 		let mut updated_reserves = Vec::with_capacity(pool.assets.len());
 		let mut added_amounts = Vec::with_capacity(pool.assets.len());
-		for pool_asset in pool.assets.iter() {
-			let decimals = Self::retrieve_decimals(*pool_asset).ok_or(Error::<T>::UnknownDecimals)?;
-			let reserve = T::Currency::free_balance(*pool_asset, &pool_account);
-			initial_reserves.push(AssetReserve {
-				amount: reserve,
-				decimals,
-			});
+		for (pool_asset, reserve) in pool.assets.iter().zip(initial_reserves.iter()) {
 			if let Some(liq_added) = added_assets.remove(pool_asset) {
-				let inc_reserve = reserve.checked_add(liq_added).ok_or(ArithmeticError::Overflow)?;
+				let inc_reserve = reserve.amount.checked_add(liq_added).ok_or(ArithmeticError::Overflow)?;
 				updated_reserves.push(AssetReserve {
 					amount: inc_reserve,
-					decimals,
+					..*reserve
 				});
 				added_amounts.push(liq_added);
 			} else {
 				ensure!(!reserve.is_zero(), Error::<T>::InvalidInitialLiquidity);
-				updated_reserves.push(AssetReserve {
-					amount: reserve,
-					decimals,
-				});
+				updated_reserves.push(*reserve);
 				added_amounts.push(0);
 			}
 		}
@@ -1159,12 +1524,13 @@ impl<T: Config> Pallet<T> {
 
 		let amplification = Self::get_amplification(&pool);
 		let share_issuance = T::Currency::total_issuance(pool_id);
+		Self::ensure_price_barrier(who, pool_id, &pool, &initial_reserves, share_issuance)?;
 		let share_amount = hydra_dx_math::stableswap::calculate_shares::<D_ITERATIONS>(
 			&initial_reserves,
 			&updated_reserves,
 			amplification,
 			share_issuance,
-			pool.fee,
+			Self::get_fee(pool_id, &pool),
 		)
 		.ok_or(ArithmeticError::Overflow)?;
 
@@ -1204,26 +1570,27 @@ impl<T: Config> Pallet<T> {
 			Error::<T>::NotAllowed
 		);
 		let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+		Self::update_dynamic_fee(pool_id);
 		let asset_idx = pool.find_asset(asset_id).ok_or(Error::<T>::AssetNotInPool)?;
 		let share_issuance = T::Currency::total_issuance(pool_id);
 		let amplification = Self::get_amplification(&pool);
 		let pool_account = Self::pool_account(pool_id);
-		let initial_reserves = pool
-			.reserves_with_decimals::<T>(&pool_account)
-			.ok_or(Error::<T>::UnknownDecimals)?;
+		let initial_reserves = Self::pool_reserves(pool_id, &pool)?;
 
 		// Ensure that initial liquidity has been already provided
 		for reserve in initial_reserves.iter() {
 			ensure!(!reserve.amount.is_zero(), Error::<T>::InvalidInitialLiquidity);
 		}
 
+		Self::ensure_price_barrier(who, pool_id, &pool, &initial_reserves, share_issuance)?;
+
 		let (amount_in, _) = hydra_dx_math::stableswap::calculate_add_one_asset::<D_ITERATIONS, Y_ITERATIONS>(
 			&initial_reserves,
 			shares,
 			asset_idx,
 			share_issuance,
 			amplification,
-			pool.fee,
+			Self::get_fee(pool_id, &pool),
 		)
 		.ok_or(ArithmeticError::Overflow)?;
 
@@ -1252,7 +1619,7 @@ impl<T: Config> Pallet<T> {
 	}
 
 	#[inline]
-	fn pool_account(pool_id: T::AssetId) -> T::AccountId {
+	pub fn pool_account(pool_id: T::AssetId) -> T::AccountId {
 		T::ShareAccountId::from_assets(&pool_id, Some(POOL_IDENTIFIER))
 	}
 
@@ -1271,12 +1638,206 @@ impl<T: Config> Pallet<T> {
 	pub(crate) fn retrieve_decimals(asset_id: T::AssetId) -> Option<u8> {
 		T::AssetInspection::decimals(asset_id)
 	}
+
+	/// Retrieve pool reserves scaled by target rates of pool assets.
+	pub(crate) fn pool_reserves(
+		pool_id: T::AssetId,
+		pool: &PoolInfo<T::AssetId, BlockNumberFor<T>>,
+	) -> Result<Vec<AssetReserve>, DispatchError> {
+		let pool_account = Self::pool_account(pool_id);
+		let reserves = pool
+			.reserves_with_decimals::<T>(&pool_account)
+			.ok_or(Error::<T>::UnknownDecimals)?;
+		let rates = Self::get_rates(pool_id, pool)?;
+		Ok(reserves
+			.into_iter()
+			.zip(rates)
+			.map(|(reserve, rate)| reserve.with_rate(rate))
+			.collect())
+	}
+
+	/// Retrieve target rates of pool assets. Rates are in the same order as pool assets.
+	pub fn get_rates(
+		pool_id: T::AssetId,
+		pool: &PoolInfo<T::AssetId, BlockNumberFor<T>>,
+	) -> Result<Vec<(Balance, Balance)>, DispatchError> {
+		let Some(sources) = PoolRateSources::<T>::get(pool_id) else {
+			return Ok(vec![(1, 1); pool.assets.len()]);
+		};
+		ensure!(sources.len() == pool.assets.len(), Error::<T>::IncorrectRateSources);
+
+		pool.assets
+			.iter()
+			.zip(sources.iter())
+			.map(|(asset_id, source)| Self::retrieve_rate(pool_id, *asset_id, source))
+			.collect()
+	}
+
+	fn retrieve_rate(
+		pool_id: T::AssetId,
+		asset_id: T::AssetId,
+		source: &RateSource<T::AssetId>,
+	) -> Result<(Balance, Balance), DispatchError> {
+		let rate = match source {
+			RateSource::Value(rate) => *rate,
+			RateSource::Oracle((oracle_source, period, reference_asset)) => {
+				if asset_id == *reference_asset {
+					return Ok((1, 1));
+				}
+				let (price, _) = T::TargetRateOracle::get_price(asset_id, *reference_asset, *period, *oracle_source)
+					.map_err(|_| Error::<T>::RateNotAvailable)?;
+
+				// Oracle price is a ratio of asset amounts, so it needs to be adjusted by asset decimals
+				// because reserves are normalized to the same precision before the rate is applied.
+				let asset_decimals = Self::retrieve_decimals(asset_id).ok_or(Error::<T>::UnknownDecimals)?;
+				let reference_decimals =
+					Self::retrieve_decimals(*reference_asset).ok_or(Error::<T>::UnknownDecimals)?;
+				let n = U256::from(price.n).saturating_mul(U256::from(10u128.saturating_pow(asset_decimals as u32)));
+				let d =
+					U256::from(price.d).saturating_mul(U256::from(10u128.saturating_pow(reference_decimals as u32)));
+				round_to_rational((n, d), Rounding::Nearest)
+			}
+			RateSource::External => {
+				T::ExternalRateProvider::rate(pool_id, asset_id).ok_or(Error::<T>::RateNotAvailable)?
+			}
+		};
+		ensure!(!rate.0.is_zero() && !rate.1.is_zero(), Error::<T>::InvalidRate);
+		Ok(rate)
+	}
+
+	fn validate_rate_source(source: &RateSource<T::AssetId>) -> DispatchResult {
+		match source {
+			RateSource::Value(rate) => {
+				ensure!(!rate.0.is_zero() && !rate.1.is_zero(), Error::<T>::InvalidRate);
+			}
+			RateSource::Oracle((_, _, reference_asset)) => {
+				ensure!(
+					T::AssetInspection::exists(*reference_asset),
+					Error::<T>::AssetNotRegistered
+				);
+			}
+			RateSource::External => {}
+		}
+		Ok(())
+	}
+
+	fn is_valid_fee_params(params: &FeeParams<Permill>) -> bool {
+		params.min_fee <= params.max_fee
+			&& !params.decay.is_zero()
+			&& params.decay <= FixedU128::one()
+			&& !params.amplification.is_zero()
+			&& params.amplification <= FixedU128::from(MAX_DYNAMIC_FEE_AMPLIFICATION)
+	}
+
+	/// Retrieve current fee of a pool.
+	///
+	/// Dynamic fee is used if pool has dynamic fee parameters, otherwise static fee.
+	/// Recalculated dynamic fee is not stored, see `update_dynamic_fee`.
+	pub(crate) fn get_fee(pool_id: T::AssetId, pool: &PoolInfo<T::AssetId, BlockNumberFor<T>>) -> Permill {
+		match PoolDynamicFeeParams::<T>::get(pool_id) {
+			Some(params) => Self::compute_dynamic_fee(pool_id, params, PoolDynamicFees::<T>::get(pool_id)).fee,
+			None => Self::get_static_fee(pool_id, pool),
+		}
+	}
+
+	/// Retrieve pool's fee taking into account scheduled fee change.
+	pub(crate) fn get_static_fee(pool_id: T::AssetId, pool: &PoolInfo<T::AssetId, BlockNumberFor<T>>) -> Permill {
+		let Some(ramp) = PoolFeeRamps::<T>::get(pool_id) else {
+			return pool.fee;
+		};
+		hydra_dx_math::stableswap::calculate_ramped_fee(
+			ramp.initial_fee,
+			ramp.final_fee,
+			ramp.initial_block.saturated_into(),
+			ramp.final_block.saturated_into(),
+			T::BlockNumberProvider::current_block_number().saturated_into(),
+		)
+	}
+
+	/// Recalculate dynamic fee of a pool and store it if the pool has dynamic fee parameters.
+	///
+	/// Called only when a trade or liquidity change is executed, so quotes do not change the fee
+	/// used by the next trade.
+	fn update_dynamic_fee(pool_id: T::AssetId) {
+		let Some(params) = PoolDynamicFeeParams::<T>::get(pool_id) else {
+			return;
+		};
+		let current_entry = PoolDynamicFees::<T>::get(pool_id);
+		let entry = Self::compute_dynamic_fee(pool_id, params, current_entry);
+		if current_entry != Some(entry) {
+			PoolDynamicFees::<T>::insert(pool_id, entry);
+		}
+	}
+
+	/// Recalculate dynamic fee of a pool from the volume of its share asset.
+	/// Fee is recalculated only once per block, same as in `pallet_dynamic_fees`.
+	fn compute_dynamic_fee(
+		pool_id: T::AssetId,
+		params: FeeParams<Permill>,
+		current_entry: Option<DynamicFeeEntry<BlockNumberFor<T>>>,
+	) -> DynamicFeeEntry<BlockNumberFor<T>> {
+		let block_number = T::BlockNumberProvider::current_block_number();
+
+		let current_entry = current_entry.unwrap_or(DynamicFeeEntry {
+			fee: params.min_fee,
+			updated_at: BlockNumberFor::<T>::default(),
+		});
+
+		// Recalculate only if it has not yet been updated this block
+		if block_number == current_entry.updated_at {
+			return current_entry;
+		}
+
+		let delta_blocks: u128 = block_number.saturating_sub(current_entry.updated_at).saturated_into();
+
+		let Some(volume) = T::VolumeOracle::asset_volume(pool_id) else {
+			return current_entry;
+		};
+		let Some(liquidity) = T::VolumeOracle::asset_liquidity(pool_id) else {
+			return current_entry;
+		};
+
+		let fee = recalculate_asset_fee(
+			OracleEntry {
+				amount_in: volume.amount_in(),
+				amount_out: volume.amount_out(),
+				liquidity,
+			},
+			current_entry.fee,
+			delta_blocks,
+			params.into(),
+		);
+
+		DynamicFeeEntry {
+			fee,
+			updated_at: block_number,
+		}
+	}
+
+	/// Weight of retrieving current fee of a pool.
+	pub fn fee_weight() -> Weight {
+		// dynamic fee params and last dynamic fee read on update and on retrieval, fee ramp and volume of each pool asset
+		T::DbWeight::get().reads_writes(5 + MAX_ASSETS_IN_POOL as u64, 1)
+	}
+
+	/// Weight of checking the price barrier for all assets of a pool.
+	pub fn price_barrier_weight() -> Weight {
+		T::PriceBarrier::ensure_price_weight().saturating_mul(MAX_ASSETS_IN_POOL as u64)
+	}
+
+	/// Weight of retrieving target rates of all assets of a pool.
+	pub fn rates_weight() -> Weight {
+		let max_assets = MAX_ASSETS_IN_POOL as u64;
+		T::DbWeight::get()
+			.reads(1)
+			.saturating_add(T::TargetRateOracle::get_price_weight().saturating_mul(max_assets))
+			.saturating_add(T::ExternalRateProvider::rate_weight().saturating_mul(max_assets))
+	}
 }
 
 impl<T: Config> Pallet<T> {
 	fn calculate_shares(pool_id: T::AssetId, assets: &[AssetAmount<T::AssetId>]) -> Result<Balance, DispatchError> {
 		let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
-		let pool_account = Self::pool_account(pool_id);
 
 		ensure!(assets.len() <= pool.assets.len(), Error::<T>::MaxAssetsExceeded);
 
@@ -1298,38 +1859,30 @@ impl<T: Config> Pallet<T> {
 			}
 		}
 
-		let mut initial_reserves = Vec::with_capacity(pool.assets.len());
+		let initial_reserves = Self::pool_reserves(pool_id, &pool)?;
 		let mut updated_reserves = Vec::with_capacity(pool.assets.len());
-		for pool_asset in pool.assets.iter() {
-			let decimals = Self::retrieve_decimals(*pool_asset).ok_or(Error::<T>::UnknownDecimals)?;
-			let reserve = T::Currency::free_balance(*pool_asset, &pool_account);
-			initial_reserves.push(AssetReserve {
-				amount: reserve,
-				decimals,
-			});
+		for (pool_asset, reserve) in pool.assets.iter().zip(initial_reserves.iter()) {
 			if let Some(liq_added) = added_assets.remove(pool_asset) {
-				let inc_reserve = reserve.checked_add(liq_added).ok_or(ArithmeticError::Overflow)?;
+				let inc_reserve = reserve.amount.checked_add(liq_added).ok_or(ArithmeticError::Overflow)?;
 				updated_reserves.push(AssetReserve {
 					amount: inc_reserve,
-					decimals,
+					..*reserve
 				});
 			} else {
 				ensure!(!reserve.is_zero(), Error::<T>::InvalidInitialLiquidity);
-				updated_reserves.push(AssetReserve {
-					amount: reserve,
-					decimals,
-				});
+				updated_reserves.push(*reserve);
 			}
 		}
 
 		let amplification = Self::get_amplification(&pool);
 		let share_issuance = T::Currency::total_issuance(pool_id);
+		Self::ensure_price_barrier(who, pool_id, &pool, &initial_reserves, share_issuance)?;
 		let share_amount = hydra_dx_math::stableswap::calculate_shares::<D_ITERATIONS>(
 			&initial_reserves,
 			&updated_reserves,
 			amplification,
 			share_issuance,
-			pool.fee,
+			Self::get_fee(pool_id, &pool),
 		)
 		.ok_or(ArithmeticError::Overflow)?;
 
@@ -1347,6 +1900,33 @@ impl<T: Config> Pallet<T> {
 		T::Hooks::on_liquidity_changed(pool_id, state)
 	}
 
+	// Ensure that share price of each pool asset passes the price barrier.
+	// Not checked when the pool has no liquidity yet.
+	fn ensure_price_barrier(
+		who: &T::AccountId,
+		pool_id: T::AssetId,
+		pool: &PoolInfo<T::AssetId, BlockNumberFor<T>>,
+		reserves: &[AssetReserve],
+		share_issuance: Balance,
+	) -> DispatchResult {
+		if share_issuance.is_zero() || reserves.iter().any(|r| r.is_zero()) {
+			return Ok(());
+		}
+
+		let share_prices = hydra_dx_math::stableswap::calculate_share_prices::<D_ITERATIONS>(
+			reserves,
+			Self::get_amplification(pool),
+			share_issuance,
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+
+		for (asset_id, (n, d)) in pool.assets.iter().zip(share_prices) {
+			T::PriceBarrier::ensure_price(who, *asset_id, pool_id, EmaPrice::new(n, d))?;
+		}
+
+		Ok(())
+	}
+
 	// Trigger on_trade hook. Initial reserves are required to calculate delta.
 	// We need new updated reserves and new share price of each asset in pool, so for this, we can simply query the storage after the update.
 	fn call_on_trade_hook(
@@ -1366,12 +1946,9 @@ impl<T: Config> Pallet<T> {
 		initial_issuance: Option<Balance>,
 	) -> Result<PoolState<T::AssetId>, DispatchError> {
 		let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
-		let pool_account = Self::pool_account(pool_id);
 		let amplification = Self::get_amplification(&pool);
 		let share_issuance = T::Currency::total_issuance(pool_id);
-		let updated_reserves = pool
-			.reserves_with_decimals::<T>(&pool_account)
-			.ok_or(Error::<T>::UnknownDecimals)?;
+		let updated_reserves = Self::pool_reserves(pool_id, &pool)?;
 		let share_prices = hydra_dx_math::stableswap::calculate_share_prices::<D_ITERATIONS>(
 			&updated_reserves,
 			amplification,
@@ -1401,7 +1978,7 @@ impl<T: Config> Pallet<T> {
 	#[cfg(feature = "try-runtime")]
 	fn ensure_add_liquidity_invariant(pool_id: T::AssetId, initial_reserves: &[AssetReserve]) {
 		let pool = Pools::<T>::get(pool_id).unwrap();
-		let final_reserves = pool.reserves_with_decimals::<T>(&Self::pool_account(pool_id)).unwrap();
+		let final_reserves = Self::pool_reserves(pool_id, &pool).unwrap();
 		debug_assert_ne!(
 			initial_reserves.iter().map(|v| v.amount).collect::<Vec<u128>>(),
 			final_reserves.iter().map(|v| v.amount).collect::<Vec<u128>>(),
@@ -1422,7 +1999,7 @@ impl<T: Config> Pallet<T> {
 	#[cfg(feature = "try-runtime")]
 	fn ensure_remove_liquidity_invariant(pool_id: T::AssetId, initial_reserves: &[AssetReserve]) {
 		let pool = Pools::<T>::get(pool_id).unwrap();
-		let final_reserves = pool.reserves_with_decimals::<T>(&Self::pool_account(pool_id)).unwrap();
+		let final_reserves = Self::pool_reserves(pool_id, &pool).unwrap();
 		debug_assert_ne!(
 			initial_reserves.iter().map(|v| v.amount).collect::<Vec<u128>>(),
 			final_reserves.iter().map(|v| v.amount).collect::<Vec<u128>>(),
@@ -1442,7 +2019,7 @@ impl<T: Config> Pallet<T> {
 	#[cfg(feature = "try-runtime")]
 	fn ensure_trade_invariant(pool_id: T::AssetId, initial_reserves: &[AssetReserve], fee: Permill) {
 		let pool = Pools::<T>::get(pool_id).unwrap();
-		let final_reserves = pool.reserves_with_decimals::<T>(&Self::pool_account(pool_id)).unwrap();
+		let final_reserves = Self::pool_reserves(pool_id, &pool).unwrap();
 		debug_assert_ne!(
 			initial_reserves.iter().map(|v| v.amount).collect::<Vec<u128>>(),
 			final_reserves.iter().map(|v| v.amount).collect::<Vec<u128>>(),
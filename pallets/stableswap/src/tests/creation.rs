@@ -397,3 +397,77 @@ fn create_pool_should_fail_when_number_of_assets_exceeds_maximum() {
 			);
 		});
 }
+
+#[test]
+fn add_asset_to_pool_should_work_when_asset_is_registered() {
+	let asset_a: AssetId = 1;
+	let asset_b: AssetId = 2;
+	let asset_c: AssetId = 3;
+	let pool_id: AssetId = 100;
+
+	ExtBuilder::default()
+		.with_registered_asset("pool".as_bytes().to_vec(), pool_id, 12)
+		.with_registered_asset("one".as_bytes().to_vec(), asset_a, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), asset_b, 12)
+		.with_registered_asset("three".as_bytes().to_vec(), asset_c, 12)
+		.build()
+		.execute_with(|| {
+			assert_ok!(Stableswap::create_pool(
+				RuntimeOrigin::root(),
+				pool_id,
+				vec![asset_a, asset_c],
+				100,
+				Permill::from_percent(0),
+			));
+
+			assert_ok!(Stableswap::do_add_asset_to_pool(pool_id, asset_b));
+
+			assert_eq!(
+				<Pools<Test>>::get(pool_id).unwrap().assets.to_vec(),
+				vec![asset_a, asset_b, asset_c]
+			);
+		});
+}
+
+#[test]
+fn add_asset_to_pool_should_fail_when_asset_is_already_in_pool() {
+	let asset_a: AssetId = 1;
+	let asset_b: AssetId = 2;
+	let pool_id: AssetId = 100;
+
+	ExtBuilder::default()
+		.with_registered_asset("pool".as_bytes().to_vec(), pool_id, 12)
+		.with_registered_asset("one".as_bytes().to_vec(), asset_a, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), asset_b, 12)
+		.build()
+		.execute_with(|| {
+			assert_ok!(Stableswap::create_pool(
+				RuntimeOrigin::root(),
+				pool_id,
+				vec![asset_a, asset_b],
+				100,
+				Permill::from_percent(0),
+			));
+
+			assert_noop!(
+				Stableswap::do_add_asset_to_pool(pool_id, asset_b),
+				Error::<Test>::IncorrectAssets
+			);
+		});
+}
+
+#[test]
+fn add_asset_to_pool_should_fail_when_pool_does_not_exist() {
+	let asset_a: AssetId = 1;
+	let pool_id: AssetId = 100;
+
+	ExtBuilder::default()
+		.with_registered_asset("one".as_bytes().to_vec(), asset_a, 12)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Stableswap::do_add_asset_to_pool(pool_id, asset_a),
+				Error::<Test>::PoolNotFound
+			);
+		});
+}
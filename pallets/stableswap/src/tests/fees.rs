@@ -0,0 +1,454 @@
+use crate::tests::mock::*;
+use crate::types::{AssetAmount, DynamicFeeEntry, FeeRamp};
+use crate::{Error, Event, PoolDynamicFees, PoolFeeRamps, Pools, MAX_DYNAMIC_FEE_AMPLIFICATION};
+use frame_support::{assert_noop, assert_ok};
+use hydra_dx_math::dynamic_fees::recalculate_asset_fee;
+use hydra_dx_math::dynamic_fees::types::OracleEntry;
+use pallet_dynamic_fees::types::FeeParams;
+use sp_runtime::traits::Zero;
+use sp_runtime::{FixedU128, Permill};
+
+const ASSET_A: AssetId = 1;
+const ASSET_B: AssetId = 2;
+const POOL_ID: AssetId = 100;
+const STATIC_POOL_ID: AssetId = 101;
+
+fn ext() -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, ASSET_A, 1_000 * ONE),
+			(ALICE, ASSET_B, 1_000 * ONE),
+			(BOB, ASSET_A, 100 * ONE),
+		])
+		.with_registered_asset("pool".as_bytes().to_vec(), POOL_ID, 18)
+		.with_registered_asset("static".as_bytes().to_vec(), STATIC_POOL_ID, 18)
+		.with_registered_asset("one".as_bytes().to_vec(), ASSET_A, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), ASSET_B, 12)
+		.build()
+}
+
+fn create_pool(pool_id: AssetId, fee: Permill) {
+	assert_ok!(Stableswap::create_pool(
+		RuntimeOrigin::root(),
+		pool_id,
+		vec![ASSET_A, ASSET_B],
+		100,
+		fee,
+	));
+	assert_ok!(Stableswap::add_liquidity(
+		RuntimeOrigin::signed(ALICE),
+		pool_id,
+		vec![
+			AssetAmount::new(ASSET_A, 100 * ONE),
+			AssetAmount::new(ASSET_B, 100 * ONE),
+		]
+	));
+}
+
+fn current_fee(pool_id: AssetId) -> Permill {
+	let pool = Pools::<Test>::get(pool_id).unwrap();
+	Stableswap::get_fee(pool_id, &pool)
+}
+
+fn fee_params() -> FeeParams<Permill> {
+	FeeParams {
+		min_fee: Permill::from_rational(5u32, 10_000u32),
+		max_fee: Permill::from_percent(5),
+		decay: FixedU128::from_rational(1, 100_000),
+		amplification: FixedU128::from(2),
+	}
+}
+
+#[test]
+fn schedule_fee_change_should_store_fee_ramp_when_successful() {
+	ext().execute_with(|| {
+		// Arrange
+		System::set_block_number(1);
+		create_pool(POOL_ID, Permill::from_percent(1));
+
+		// Act
+		assert_ok!(Stableswap::schedule_fee_change(
+			RuntimeOrigin::root(),
+			POOL_ID,
+			Permill::from_percent(3),
+			10,
+			110,
+		));
+
+		// Assert
+		assert_eq!(
+			PoolFeeRamps::<Test>::get(POOL_ID),
+			Some(FeeRamp {
+				initial_fee: Permill::from_percent(1),
+				final_fee: Permill::from_percent(3),
+				initial_block: 10,
+				final_block: 110,
+			})
+		);
+		assert_eq!(Pools::<Test>::get(POOL_ID).unwrap().fee, Permill::from_percent(3));
+		expect_events(vec![Event::FeeChanging {
+			pool_id: POOL_ID,
+			current_fee: Permill::from_percent(1),
+			final_fee: Permill::from_percent(3),
+			start_block: 10,
+			end_block: 110,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn schedule_fee_change_should_fail_when_start_block_is_in_past() {
+	ext().execute_with(|| {
+		// Arrange
+		create_pool(POOL_ID, Permill::from_percent(1));
+		System::set_block_number(50);
+
+		// Act & Assert
+		assert_noop!(
+			Stableswap::schedule_fee_change(RuntimeOrigin::root(), POOL_ID, Permill::from_percent(3), 10, 110),
+			Error::<Test>::PastBlock
+		);
+	});
+}
+
+#[test]
+fn schedule_fee_change_should_fail_when_fee_is_same() {
+	ext().execute_with(|| {
+		// Arrange
+		create_pool(POOL_ID, Permill::from_percent(1));
+
+		// Act & Assert
+		assert_noop!(
+			Stableswap::schedule_fee_change(RuntimeOrigin::root(), POOL_ID, Permill::from_percent(1), 10, 110),
+			Error::<Test>::SameFee
+		);
+	});
+}
+
+#[test]
+fn fee_should_change_linearly_when_fee_change_is_scheduled() {
+	ext().execute_with(|| {
+		// Arrange
+		create_pool(POOL_ID, Permill::from_percent(1));
+		assert_ok!(Stableswap::schedule_fee_change(
+			RuntimeOrigin::root(),
+			POOL_ID,
+			Permill::from_percent(3),
+			10,
+			110,
+		));
+
+		// Act & Assert
+		System::set_block_number(5);
+		assert_eq!(current_fee(POOL_ID), Permill::from_percent(1));
+		System::set_block_number(60);
+		assert_eq!(current_fee(POOL_ID), Permill::from_percent(2));
+		System::set_block_number(200);
+		assert_eq!(current_fee(POOL_ID), Permill::from_percent(3));
+	});
+}
+
+#[test]
+fn schedule_fee_change_should_start_from_current_fee_when_fee_is_already_changing() {
+	ext().execute_with(|| {
+		// Arrange
+		create_pool(POOL_ID, Permill::from_percent(1));
+		assert_ok!(Stableswap::schedule_fee_change(
+			RuntimeOrigin::root(),
+			POOL_ID,
+			Permill::from_percent(3),
+			10,
+			110,
+		));
+		System::set_block_number(60);
+
+		// Act
+		assert_ok!(Stableswap::schedule_fee_change(
+			RuntimeOrigin::root(),
+			POOL_ID,
+			Permill::from_percent(1),
+			60,
+			160,
+		));
+
+		// Assert
+		assert_eq!(
+			PoolFeeRamps::<Test>::get(POOL_ID).unwrap().initial_fee,
+			Permill::from_percent(2)
+		);
+		assert_eq!(current_fee(POOL_ID), Permill::from_percent(2));
+	});
+}
+
+#[test]
+fn update_pool_fee_should_cancel_scheduled_fee_change() {
+	ext().execute_with(|| {
+		// Arrange
+		create_pool(POOL_ID, Permill::from_percent(1));
+		assert_ok!(Stableswap::schedule_fee_change(
+			RuntimeOrigin::root(),
+			POOL_ID,
+			Permill::from_percent(3),
+			10,
+			110,
+		));
+		System::set_block_number(60);
+
+		// Act
+		assert_ok!(Stableswap::update_pool_fee(
+			RuntimeOrigin::root(),
+			POOL_ID,
+			Permill::from_percent(4)
+		));
+
+		// Assert
+		assert!(PoolFeeRamps::<Test>::get(POOL_ID).is_none());
+		assert_eq!(current_fee(POOL_ID), Permill::from_percent(4));
+	});
+}
+
+#[test]
+fn set_dynamic_fee_params_should_fail_when_min_fee_is_greater_than_max_fee() {
+	ext().execute_with(|| {
+		// Arrange
+		create_pool(POOL_ID, Permill::from_percent(1));
+		let params = FeeParams {
+			min_fee: Permill::from_percent(2),
+			max_fee: Permill::from_percent(1),
+			..fee_params()
+		};
+
+		// Act & Assert
+		assert_noop!(
+			Stableswap::set_dynamic_fee_params(RuntimeOrigin::root(), POOL_ID, Some(params)),
+			Error::<Test>::InvalidFeeParams
+		);
+	});
+}
+
+#[test]
+fn set_dynamic_fee_params_should_fail_when_decay_or_amplification_is_out_of_bounds() {
+	ext().execute_with(|| {
+		// Arrange
+		create_pool(POOL_ID, Permill::from_percent(1));
+		let invalid_params = vec![
+			FeeParams {
+				decay: FixedU128::zero(),
+				..fee_params()
+			},
+			FeeParams {
+				decay: FixedU128::from(2),
+				..fee_params()
+			},
+			FeeParams {
+				amplification: FixedU128::zero(),
+				..fee_params()
+			},
+			FeeParams {
+				amplification: FixedU128::from(MAX_DYNAMIC_FEE_AMPLIFICATION + 1),
+				..fee_params()
+			},
+		];
+
+		// Act & Assert
+		for params in invalid_params {
+			assert_noop!(
+				Stableswap::set_dynamic_fee_params(RuntimeOrigin::root(), POOL_ID, Some(params)),
+				Error::<Test>::InvalidFeeParams
+			);
+		}
+	});
+}
+
+#[test]
+fn set_dynamic_fee_params_should_fail_when_pool_does_not_exist() {
+	ext().execute_with(|| {
+		assert_noop!(
+			Stableswap::set_dynamic_fee_params(RuntimeOrigin::root(), POOL_ID, Some(fee_params())),
+			Error::<Test>::PoolNotFound
+		);
+	});
+}
+
+#[test]
+fn dynamic_fee_should_be_min_fee_when_volume_is_not_available() {
+	ext().execute_with(|| {
+		// Arrange
+		System::set_block_number(1);
+		create_pool(POOL_ID, Permill::from_percent(1));
+
+		// Act
+		assert_ok!(Stableswap::set_dynamic_fee_params(
+			RuntimeOrigin::root(),
+			POOL_ID,
+			Some(fee_params())
+		));
+
+		// Assert
+		expect_events(vec![Event::DynamicFeeParamsUpdated {
+			pool_id: POOL_ID,
+			params: Some(fee_params()),
+		}
+		.into()]);
+		System::set_block_number(2);
+		assert_eq!(current_fee(POOL_ID), fee_params().min_fee);
+	});
+}
+
+#[test]
+fn dynamic_fee_should_be_recalculated_from_share_volume() {
+	ext().execute_with(|| {
+		// Arrange
+		create_pool(POOL_ID, Permill::from_percent(1));
+		assert_ok!(Stableswap::set_dynamic_fee_params(
+			RuntimeOrigin::root(),
+			POOL_ID,
+			Some(fee_params())
+		));
+		set_share_volume(POOL_ID, 0, 2 * ONE, 200 * ONE);
+		System::set_block_number(2);
+
+		// Act
+		let fee = current_fee(POOL_ID);
+
+		// Assert
+		let expected = recalculate_asset_fee(
+			OracleEntry {
+				amount_in: 0,
+				amount_out: 2 * ONE,
+				liquidity: 200 * ONE,
+			},
+			fee_params().min_fee,
+			2,
+			fee_params().into(),
+		);
+		assert!(fee > fee_params().min_fee);
+		assert_eq!(fee, expected);
+		assert!(PoolDynamicFees::<Test>::get(POOL_ID).is_none());
+	});
+}
+
+#[test]
+fn sell_should_store_dynamic_fee() {
+	ext().execute_with(|| {
+		// Arrange
+		create_pool(POOL_ID, Permill::from_percent(1));
+		assert_ok!(Stableswap::set_dynamic_fee_params(
+			RuntimeOrigin::root(),
+			POOL_ID,
+			Some(fee_params())
+		));
+		set_share_volume(POOL_ID, 0, 2 * ONE, 200 * ONE);
+		System::set_block_number(2);
+		let fee = current_fee(POOL_ID);
+
+		// Act
+		assert_ok!(Stableswap::sell(
+			RuntimeOrigin::signed(BOB),
+			POOL_ID,
+			ASSET_A,
+			ASSET_B,
+			10 * ONE,
+			0,
+		));
+
+		// Assert
+		assert_eq!(
+			PoolDynamicFees::<Test>::get(POOL_ID),
+			Some(DynamicFeeEntry { fee, updated_at: 2 })
+		);
+	});
+}
+
+#[test]
+fn dynamic_fee_should_be_updated_once_per_block() {
+	ext().execute_with(|| {
+		// Arrange
+		create_pool(POOL_ID, Permill::from_percent(1));
+		assert_ok!(Stableswap::set_dynamic_fee_params(
+			RuntimeOrigin::root(),
+			POOL_ID,
+			Some(fee_params())
+		));
+		set_share_volume(POOL_ID, 0, 2 * ONE, 200 * ONE);
+		System::set_block_number(2);
+		let fee = current_fee(POOL_ID);
+		assert_ok!(Stableswap::sell(
+			RuntimeOrigin::signed(BOB),
+			POOL_ID,
+			ASSET_A,
+			ASSET_B,
+			10 * ONE,
+			0,
+		));
+
+		// Act
+		set_share_volume(POOL_ID, 0, 20 * ONE, 200 * ONE);
+
+		// Assert
+		assert_eq!(current_fee(POOL_ID), fee);
+	});
+}
+
+#[test]
+fn sell_should_use_dynamic_fee_when_dynamic_fee_params_are_set() {
+	ext().execute_with(|| {
+		// Arrange
+		create_pool(POOL_ID, Permill::zero());
+		create_pool(STATIC_POOL_ID, Permill::from_percent(1));
+		assert_ok!(Stableswap::set_dynamic_fee_params(
+			RuntimeOrigin::root(),
+			POOL_ID,
+			Some(FeeParams {
+				min_fee: Permill::from_percent(1),
+				max_fee: Permill::from_percent(1),
+				..fee_params()
+			})
+		));
+
+		// Act
+		assert_ok!(Stableswap::sell(
+			RuntimeOrigin::signed(BOB),
+			POOL_ID,
+			ASSET_A,
+			ASSET_B,
+			10 * ONE,
+			0,
+		));
+		let received = Tokens::free_balance(ASSET_B, &BOB);
+		assert_ok!(Stableswap::sell(
+			RuntimeOrigin::signed(BOB),
+			STATIC_POOL_ID,
+			ASSET_A,
+			ASSET_B,
+			10 * ONE,
+			0,
+		));
+
+		// Assert
+		assert_eq!(Tokens::free_balance(ASSET_B, &BOB) - received, received);
+	});
+}
+
+#[test]
+fn removing_dynamic_fee_params_should_restore_static_fee() {
+	ext().execute_with(|| {
+		// Arrange
+		create_pool(POOL_ID, Permill::from_percent(1));
+		assert_ok!(Stableswap::set_dynamic_fee_params(
+			RuntimeOrigin::root(),
+			POOL_ID,
+			Some(fee_params())
+		));
+		System::set_block_number(2);
+		assert_eq!(current_fee(POOL_ID), fee_params().min_fee);
+
+		// Act
+		assert_ok!(Stableswap::set_dynamic_fee_params(RuntimeOrigin::root(), POOL_ID, None));
+
+		// Assert
+		assert!(PoolDynamicFees::<Test>::get(POOL_ID).is_none());
+		assert_eq!(current_fee(POOL_ID), Permill::from_percent(1));
+	});
+}
@@ -73,6 +73,11 @@ thread_local! {
 	pub static DUSTER_WHITELIST: RefCell<Vec<AccountId>> = const { RefCell::new(Vec::new()) };
 	pub static LAST_LIQUDITY_CHANGE_HOOK: RefCell<Option<(AssetId, PoolState<AssetId>)>> = const { RefCell::new(None) };
 	pub static LAST_TRADE_HOOK: RefCell<Option<(AssetId, AssetId, AssetId, PoolState<AssetId>)>> = const { RefCell::new(None) };
+	pub static ORACLE_PRICES: RefCell<HashMap<(AssetId, AssetId), (Balance, Balance)>> = RefCell::new(HashMap::default());
+	pub static EXTERNAL_RATES: RefCell<HashMap<(AssetId, AssetId), (Balance, Balance)>> = RefCell::new(HashMap::default());
+	pub static SHARE_VOLUMES: RefCell<HashMap<AssetId, (Balance, Balance, Balance)>> = RefCell::new(HashMap::default());
+	pub static PRICE_BARRIER_TRIGGERED: RefCell<bool> = const { RefCell::new(false) };
+	pub static PRICE_BARRIER_CALLS: RefCell<Vec<(AssetId, AssetId, EmaPrice)>> = const { RefCell::new(Vec::new()) };
 }
 
 construct_runtime!(
@@ -186,6 +191,10 @@ impl Config for Test {
 	type BlockNumberProvider = System;
 	type DustAccountHandler = Whitelist;
 	type Hooks = DummyHookAdapter;
+	type TargetRateOracle = DummyOracle;
+	type ExternalRateProvider = DummyExternalRates;
+	type VolumeOracle = DummyVolumeOracle;
+	type PriceBarrier = DummyPriceBarrier;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = DummyRegistry;
 }
@@ -215,6 +224,21 @@ impl Default for ExtBuilder {
 		POOL_IDS.with(|v| {
 			v.borrow_mut().clear();
 		});
+		ORACLE_PRICES.with(|v| {
+			v.borrow_mut().clear();
+		});
+		EXTERNAL_RATES.with(|v| {
+			v.borrow_mut().clear();
+		});
+		SHARE_VOLUMES.with(|v| {
+			v.borrow_mut().clear();
+		});
+		PRICE_BARRIER_TRIGGERED.with(|v| {
+			*v.borrow_mut() = false;
+		});
+		PRICE_BARRIER_CALLS.with(|v| {
+			v.borrow_mut().clear();
+		});
 		Self {
 			endowed_accounts: vec![],
 			registered_assets: vec![],
@@ -316,9 +340,12 @@ impl ExtBuilder {
 
 #[cfg(feature = "runtime-benchmarks")]
 use crate::types::BenchmarkHelper;
-use crate::types::{AssetAmount, PoolInfo, PoolState, StableswapHooks};
+use crate::types::{AssetAmount, ExternalRateProvider, PoolInfo, PoolState, StableswapHooks};
+use hydra_dx_math::ema::EmaPrice;
 use hydradx_traits::pools::DustRemovalAccountWhitelist;
-use hydradx_traits::{AccountIdFor, Inspect};
+use hydradx_traits::price::PriceBarrier;
+use hydradx_traits::{AccountIdFor, AggregatedPriceOracle, Inspect, OraclePeriod, Source};
+use pallet_dynamic_fees::traits::{Volume, VolumeProvider};
 use sp_runtime::traits::Zero;
 
 pub struct DummyRegistry;
@@ -434,6 +461,114 @@ impl StableswapHooks<AssetId> for DummyHookAdapter {
 	}
 }
 
+pub struct DummyOracle;
+
+impl AggregatedPriceOracle<AssetId, u64, EmaPrice> for DummyOracle {
+	type Error = ();
+
+	fn get_price(
+		asset_a: AssetId,
+		asset_b: AssetId,
+		_period: OraclePeriod,
+		_source: Source,
+	) -> Result<(EmaPrice, u64), Self::Error> {
+		let price = ORACLE_PRICES
+			.with(|v| v.borrow().get(&(asset_a, asset_b)).copied())
+			.ok_or(())?;
+		Ok((EmaPrice::new(price.0, price.1), 0))
+	}
+
+	fn get_price_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+pub struct DummyExternalRates;
+
+impl ExternalRateProvider<AssetId> for DummyExternalRates {
+	fn rate(pool_id: AssetId, asset_id: AssetId) -> Option<(Balance, Balance)> {
+		EXTERNAL_RATES.with(|v| v.borrow().get(&(pool_id, asset_id)).copied())
+	}
+
+	fn rate_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+pub struct DummyVolume(Balance, Balance);
+
+impl Volume<Balance> for DummyVolume {
+	fn amount_in(&self) -> Balance {
+		self.0
+	}
+
+	fn amount_out(&self) -> Balance {
+		self.1
+	}
+}
+
+pub struct DummyVolumeOracle;
+
+impl VolumeProvider<AssetId, Balance> for DummyVolumeOracle {
+	type Volume = DummyVolume;
+
+	fn asset_volume(asset_id: AssetId) -> Option<Self::Volume> {
+		SHARE_VOLUMES.with(|v| {
+			v.borrow()
+				.get(&asset_id)
+				.map(|(a_in, a_out, _)| DummyVolume(*a_in, *a_out))
+		})
+	}
+
+	fn asset_liquidity(asset_id: AssetId) -> Option<Balance> {
+		SHARE_VOLUMES.with(|v| v.borrow().get(&asset_id).map(|(_, _, liquidity)| *liquidity))
+	}
+}
+
+pub(crate) fn set_share_volume(pool_id: AssetId, amount_in: Balance, amount_out: Balance, liquidity: Balance) {
+	SHARE_VOLUMES.with(|v| {
+		v.borrow_mut().insert(pool_id, (amount_in, amount_out, liquidity));
+	});
+}
+
+pub struct DummyPriceBarrier;
+
+impl PriceBarrier<AccountId, AssetId, EmaPrice> for DummyPriceBarrier {
+	fn ensure_price(_who: &AccountId, asset_a: AssetId, asset_b: AssetId, spot_price: EmaPrice) -> DispatchResult {
+		PRICE_BARRIER_CALLS.with(|v| v.borrow_mut().push((asset_a, asset_b, spot_price)));
+		if PRICE_BARRIER_TRIGGERED.with(|v| *v.borrow()) {
+			return Err(DispatchError::Other("price barrier"));
+		}
+		Ok(())
+	}
+
+	fn ensure_price_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+pub(crate) fn set_price_barrier_triggered(triggered: bool) {
+	PRICE_BARRIER_TRIGGERED.with(|v| {
+		*v.borrow_mut() = triggered;
+	});
+}
+
+pub(crate) fn price_barrier_calls() -> Vec<(AssetId, AssetId, EmaPrice)> {
+	PRICE_BARRIER_CALLS.with(|v| v.borrow().clone())
+}
+
+pub(crate) fn set_oracle_price(asset_a: AssetId, asset_b: AssetId, price: (Balance, Balance)) {
+	ORACLE_PRICES.with(|v| {
+		v.borrow_mut().insert((asset_a, asset_b), price);
+	});
+}
+
+pub(crate) fn set_external_rate(pool_id: AssetId, asset_id: AssetId, rate: (Balance, Balance)) {
+	EXTERNAL_RATES.with(|v| {
+		v.borrow_mut().insert((pool_id, asset_id), rate);
+	});
+}
+
 pub(crate) fn last_liquidity_changed_hook_state() -> Option<(AssetId, PoolState<AssetId>)> {
 	LAST_LIQUDITY_CHANGE_HOOK.with(|v| v.borrow().clone())
 }
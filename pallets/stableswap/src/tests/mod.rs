@@ -6,10 +6,13 @@ mod add_liquidity;
 mod amplification;
 mod calculate_spot_price;
 mod creation;
+mod fees;
 mod hooks;
 mod invariants;
 pub(crate) mod mock;
 mod price;
+mod price_barrier;
+mod rates;
 mod remove_liquidity;
 mod trades;
 mod update_pool;
@@ -24,9 +27,8 @@ macro_rules! to_precision {
 }
 
 pub(crate) fn get_share_price(pool_id: AssetId, asset_idx: usize) -> FixedU128 {
-	let pool_account = pool_account(pool_id);
 	let pool = <Pools<Test>>::get(pool_id).unwrap();
-	let balances = pool.reserves_with_decimals::<Test>(&pool_account).unwrap();
+	let balances = Stableswap::pool_reserves(pool_id, &pool).unwrap();
 	let amp = Pallet::<Test>::get_amplification(&pool);
 	let issuance = Tokens::total_issuance(pool_id);
 	let share_price =
@@ -35,9 +37,8 @@ pub(crate) fn get_share_price(pool_id: AssetId, asset_idx: usize) -> FixedU128 {
 }
 
 pub(crate) fn asset_spot_price(pool_id: AssetId, asset_id: AssetId) -> FixedU128 {
-	let pool_account = pool_account(pool_id);
 	let pool = <Pools<Test>>::get(pool_id).unwrap();
-	let balances = pool.reserves_with_decimals::<Test>(&pool_account).unwrap();
+	let balances = Stableswap::pool_reserves(pool_id, &pool).unwrap();
 	let amp = Pallet::<Test>::get_amplification(&pool);
 	let asset_idx = pool.find_asset(asset_id).unwrap();
 	let d = hydra_dx_math::stableswap::calculate_d::<D_ITERATIONS>(&balances, amp).unwrap();
@@ -0,0 +1,136 @@
+use crate::tests::mock::*;
+use crate::types::AssetAmount;
+use crate::{Pools, D_ITERATIONS};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+use hydra_dx_math::ema::EmaPrice;
+use sp_runtime::{DispatchError, Permill};
+
+const ASSET_A: AssetId = 1;
+const ASSET_B: AssetId = 2;
+const POOL_ID: AssetId = 100;
+
+fn ext() -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, ASSET_A, 1_000 * ONE),
+			(ALICE, ASSET_B, 1_000 * ONE),
+			(BOB, ASSET_A, 100 * ONE),
+		])
+		.with_registered_asset("pool".as_bytes().to_vec(), POOL_ID, 18)
+		.with_registered_asset("one".as_bytes().to_vec(), ASSET_A, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), ASSET_B, 12)
+		.build()
+}
+
+fn create_pool() {
+	assert_ok!(Stableswap::create_pool(
+		RuntimeOrigin::root(),
+		POOL_ID,
+		vec![ASSET_A, ASSET_B],
+		100,
+		Permill::zero(),
+	));
+	assert_ok!(Stableswap::add_liquidity(
+		RuntimeOrigin::signed(ALICE),
+		POOL_ID,
+		vec![
+			AssetAmount::new(ASSET_A, 100 * ONE),
+			AssetAmount::new(ASSET_B, 200 * ONE),
+		]
+	));
+}
+
+fn share_prices() -> Vec<(AssetId, AssetId, EmaPrice)> {
+	let pool = Pools::<Test>::get(POOL_ID).unwrap();
+	let reserves = Stableswap::pool_reserves(POOL_ID, &pool).unwrap();
+	let amplification = Stableswap::get_amplification(&pool);
+	let issuance = Tokens::total_issuance(POOL_ID);
+	hydra_dx_math::stableswap::calculate_share_prices::<D_ITERATIONS>(&reserves, amplification, issuance)
+		.unwrap()
+		.into_iter()
+		.zip(pool.assets.iter())
+		.map(|((n, d), asset_id)| (*asset_id, POOL_ID, EmaPrice::new(n, d)))
+		.collect()
+}
+
+#[test]
+fn initial_liquidity_should_not_be_checked_by_price_barrier() {
+	ext().execute_with(|| {
+		// Act
+		create_pool();
+
+		// Assert
+		assert!(price_barrier_calls().is_empty());
+	});
+}
+
+#[test]
+fn add_liquidity_should_check_share_price_of_each_asset() {
+	ext().execute_with(|| {
+		// Arrange
+		create_pool();
+		let expected = share_prices();
+
+		// Act
+		assert_ok!(Stableswap::add_liquidity(
+			RuntimeOrigin::signed(BOB),
+			POOL_ID,
+			vec![AssetAmount::new(ASSET_A, 10 * ONE)]
+		));
+
+		// Assert
+		assert_eq!(price_barrier_calls(), expected);
+	});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_price_barrier_is_triggered() {
+	ext().execute_with(|| {
+		// Arrange
+		create_pool();
+		set_price_barrier_triggered(true);
+
+		// Act & Assert
+		assert_noop!(
+			Stableswap::add_liquidity(
+				RuntimeOrigin::signed(BOB),
+				POOL_ID,
+				vec![AssetAmount::new(ASSET_A, 10 * ONE)]
+			),
+			DispatchError::Other("price barrier")
+		);
+		assert_noop!(
+			Stableswap::add_liquidity_shares(RuntimeOrigin::signed(BOB), POOL_ID, ONE, ASSET_A, 10 * ONE),
+			DispatchError::Other("price barrier")
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_fail_when_price_barrier_is_triggered() {
+	ext().execute_with(|| {
+		// Arrange
+		create_pool();
+		set_price_barrier_triggered(true);
+		let shares = Tokens::free_balance(POOL_ID, &ALICE) / 10;
+
+		// Act & Assert
+		assert_noop!(
+			Stableswap::remove_liquidity_one_asset(RuntimeOrigin::signed(ALICE), POOL_ID, ASSET_A, shares, 0),
+			DispatchError::Other("price barrier")
+		);
+		assert_noop!(
+			Stableswap::withdraw_asset_amount(RuntimeOrigin::signed(ALICE), POOL_ID, ASSET_A, ONE, shares),
+			DispatchError::Other("price barrier")
+		);
+		assert_noop!(
+			Stableswap::remove_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				POOL_ID,
+				shares,
+				BoundedVec::truncate_from(vec![AssetAmount::new(ASSET_A, 0), AssetAmount::new(ASSET_B, 0)])
+			),
+			DispatchError::Other("price barrier")
+		);
+	});
+}
@@ -0,0 +1,384 @@
+use crate::tests::mock::*;
+use crate::types::{AssetAmount, RateSource};
+use crate::{Error, Event, PoolRateSources, MAX_ASSETS_IN_POOL};
+use frame_support::traits::ConstU32;
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+use hydradx_traits::OraclePeriod;
+use sp_runtime::Permill;
+
+const ASSET_A: AssetId = 1;
+const ASSET_B: AssetId = 2;
+const ASSET_C: AssetId = 3;
+const POOL_ID: AssetId = 100;
+const UNIT_POOL_ID: AssetId = 101;
+
+const ORACLE_SOURCE: [u8; 8] = *b"testsrc_";
+
+fn ext() -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, ASSET_A, 1_000 * ONE),
+			(ALICE, ASSET_B, 1_000 * ONE),
+			(BOB, ASSET_A, 100 * ONE),
+			(BOB, ASSET_B, 100 * ONE),
+		])
+		.with_registered_asset("pool".as_bytes().to_vec(), POOL_ID, 18)
+		.with_registered_asset("unit".as_bytes().to_vec(), UNIT_POOL_ID, 18)
+		.with_registered_asset("one".as_bytes().to_vec(), ASSET_A, 18)
+		.with_registered_asset("two".as_bytes().to_vec(), ASSET_B, 18)
+		.with_registered_asset("three".as_bytes().to_vec(), ASSET_C, 18)
+		.build()
+}
+
+fn rates(
+	rates: Vec<(AssetId, RateSource<AssetId>)>,
+) -> BoundedVec<(AssetId, RateSource<AssetId>), ConstU32<MAX_ASSETS_IN_POOL>> {
+	rates.try_into().unwrap()
+}
+
+// Creates pool with unit rates and balanced liquidity, which is equivalent to the rate pool with 1:2 rate of asset b.
+fn create_unit_pool() {
+	assert_ok!(Stableswap::create_pool(
+		RuntimeOrigin::root(),
+		UNIT_POOL_ID,
+		vec![ASSET_A, ASSET_B],
+		100,
+		Permill::zero(),
+	));
+	assert_ok!(Stableswap::add_liquidity(
+		RuntimeOrigin::signed(ALICE),
+		UNIT_POOL_ID,
+		vec![
+			AssetAmount::new(ASSET_A, 100 * ONE),
+			AssetAmount::new(ASSET_B, 100 * ONE),
+		]
+	));
+}
+
+fn create_rate_pool(rate_b: RateSource<AssetId>) {
+	assert_ok!(Stableswap::create_pool_with_rates(
+		RuntimeOrigin::root(),
+		POOL_ID,
+		vec![ASSET_A, ASSET_B],
+		100,
+		Permill::zero(),
+		rates(vec![(ASSET_A, RateSource::Value((1, 1))), (ASSET_B, rate_b)]),
+	));
+	assert_ok!(Stableswap::add_liquidity(
+		RuntimeOrigin::signed(ALICE),
+		POOL_ID,
+		vec![
+			AssetAmount::new(ASSET_A, 100 * ONE),
+			AssetAmount::new(ASSET_B, 200 * ONE),
+		]
+	));
+}
+
+fn sell_a_for_b(pool_id: AssetId) -> Balance {
+	let balance_before = Tokens::free_balance(ASSET_B, &BOB);
+	assert_ok!(Stableswap::sell(
+		RuntimeOrigin::signed(BOB),
+		pool_id,
+		ASSET_A,
+		ASSET_B,
+		10 * ONE,
+		0,
+	));
+	Tokens::free_balance(ASSET_B, &BOB) - balance_before
+}
+
+#[test]
+fn create_pool_with_rates_should_store_rate_sources_in_pool_asset_order() {
+	ext().execute_with(|| {
+		// Arrange
+		System::set_block_number(1);
+		let oracle_source = RateSource::Oracle((ORACLE_SOURCE, OraclePeriod::Short, ASSET_A));
+		set_oracle_price(ASSET_C, ASSET_A, (11, 10));
+
+		// Act
+		assert_ok!(Stableswap::create_pool_with_rates(
+			RuntimeOrigin::root(),
+			POOL_ID,
+			vec![ASSET_C, ASSET_B, ASSET_A],
+			100,
+			Permill::zero(),
+			rates(vec![
+				(ASSET_C, oracle_source.clone()),
+				(ASSET_A, RateSource::Value((1, 1))),
+				(ASSET_B, RateSource::Value((2, 1))),
+			]),
+		));
+
+		// Assert
+		let expected = vec![RateSource::Value((1, 1)), RateSource::Value((2, 1)), oracle_source];
+		assert_eq!(PoolRateSources::<Test>::get(POOL_ID).unwrap().into_inner(), expected);
+		expect_events(vec![Event::RatesUpdated {
+			pool_id: POOL_ID,
+			rates: expected,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn create_pool_with_rates_should_fail_when_rate_of_asset_is_missing() {
+	ext().execute_with(|| {
+		assert_noop!(
+			Stableswap::create_pool_with_rates(
+				RuntimeOrigin::root(),
+				POOL_ID,
+				vec![ASSET_A, ASSET_B],
+				100,
+				Permill::zero(),
+				rates(vec![(ASSET_A, RateSource::Value((1, 1)))]),
+			),
+			Error::<Test>::IncorrectRateSources
+		);
+		assert_noop!(
+			Stableswap::create_pool_with_rates(
+				RuntimeOrigin::root(),
+				POOL_ID,
+				vec![ASSET_A, ASSET_B],
+				100,
+				Permill::zero(),
+				rates(vec![
+					(ASSET_A, RateSource::Value((1, 1))),
+					(ASSET_C, RateSource::Value((1, 1)))
+				]),
+			),
+			Error::<Test>::IncorrectRateSources
+		);
+	});
+}
+
+#[test]
+fn create_pool_with_rates_should_fail_when_rate_is_zero() {
+	ext().execute_with(|| {
+		assert_noop!(
+			Stableswap::create_pool_with_rates(
+				RuntimeOrigin::root(),
+				POOL_ID,
+				vec![ASSET_A, ASSET_B],
+				100,
+				Permill::zero(),
+				rates(vec![
+					(ASSET_A, RateSource::Value((1, 1))),
+					(ASSET_B, RateSource::Value((0, 1)))
+				]),
+			),
+			Error::<Test>::InvalidRate
+		);
+	});
+}
+
+#[test]
+fn create_pool_with_rates_should_fail_when_rate_is_not_available() {
+	ext().execute_with(|| {
+		assert_noop!(
+			Stableswap::create_pool_with_rates(
+				RuntimeOrigin::root(),
+				POOL_ID,
+				vec![ASSET_A, ASSET_B],
+				100,
+				Permill::zero(),
+				rates(vec![
+					(ASSET_A, RateSource::Value((1, 1))),
+					(
+						ASSET_B,
+						RateSource::Oracle((ORACLE_SOURCE, OraclePeriod::Short, ASSET_A))
+					)
+				]),
+			),
+			Error::<Test>::RateNotAvailable
+		);
+		assert_noop!(
+			Stableswap::create_pool_with_rates(
+				RuntimeOrigin::root(),
+				POOL_ID,
+				vec![ASSET_A, ASSET_B],
+				100,
+				Permill::zero(),
+				rates(vec![
+					(ASSET_A, RateSource::Value((1, 1))),
+					(ASSET_B, RateSource::External)
+				]),
+			),
+			Error::<Test>::RateNotAvailable
+		);
+	});
+}
+
+#[test]
+fn sell_should_scale_amounts_by_fixed_rate() {
+	ext().execute_with(|| {
+		// Arrange
+		create_unit_pool();
+		create_rate_pool(RateSource::Value((1, 2)));
+
+		// Act
+		let expected = sell_a_for_b(UNIT_POOL_ID);
+		let received = sell_a_for_b(POOL_ID);
+
+		// Assert
+		assert_eq!(received, 2 * expected);
+	});
+}
+
+#[test]
+fn sell_should_scale_amounts_by_oracle_rate() {
+	ext().execute_with(|| {
+		// Arrange
+		set_oracle_price(ASSET_B, ASSET_A, (1, 2));
+		create_unit_pool();
+		create_rate_pool(RateSource::Oracle((ORACLE_SOURCE, OraclePeriod::Short, ASSET_A)));
+
+		// Act
+		let expected = sell_a_for_b(UNIT_POOL_ID);
+		let received = sell_a_for_b(POOL_ID);
+
+		// Assert
+		assert_eq!(received, 2 * expected);
+	});
+}
+
+#[test]
+fn sell_should_scale_amounts_by_external_rate() {
+	ext().execute_with(|| {
+		// Arrange
+		set_external_rate(POOL_ID, ASSET_B, (1, 2));
+		create_unit_pool();
+		create_rate_pool(RateSource::External);
+
+		// Act
+		let expected = sell_a_for_b(UNIT_POOL_ID);
+		let received = sell_a_for_b(POOL_ID);
+
+		// Assert
+		assert_eq!(received, 2 * expected);
+	});
+}
+
+#[test]
+fn sell_should_fail_when_rate_is_no_longer_available() {
+	ext().execute_with(|| {
+		// Arrange
+		set_external_rate(POOL_ID, ASSET_B, (1, 2));
+		create_rate_pool(RateSource::External);
+		EXTERNAL_RATES.with(|v| v.borrow_mut().clear());
+
+		// Act & Assert
+		assert_noop!(
+			Stableswap::sell(RuntimeOrigin::signed(BOB), POOL_ID, ASSET_A, ASSET_B, 10 * ONE, 0),
+			Error::<Test>::RateNotAvailable
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_should_mint_same_shares_as_unit_pool_when_amount_is_scaled_by_rate() {
+	ext().execute_with(|| {
+		// Arrange
+		create_unit_pool();
+		create_rate_pool(RateSource::Value((1, 2)));
+
+		// Act
+		assert_ok!(Stableswap::add_liquidity(
+			RuntimeOrigin::signed(BOB),
+			UNIT_POOL_ID,
+			vec![AssetAmount::new(ASSET_B, 10 * ONE)]
+		));
+		assert_ok!(Stableswap::add_liquidity(
+			RuntimeOrigin::signed(BOB),
+			POOL_ID,
+			vec![AssetAmount::new(ASSET_B, 20 * ONE)]
+		));
+
+		// Assert
+		assert_eq!(
+			Tokens::free_balance(POOL_ID, &BOB),
+			Tokens::free_balance(UNIT_POOL_ID, &BOB)
+		);
+	});
+}
+
+#[test]
+fn update_asset_rates_should_use_unit_rate_for_remaining_assets_when_pool_has_no_rates() {
+	ext().execute_with(|| {
+		// Arrange
+		System::set_block_number(1);
+		create_unit_pool();
+
+		// Act
+		assert_ok!(Stableswap::update_asset_rates(
+			RuntimeOrigin::root(),
+			UNIT_POOL_ID,
+			rates(vec![(ASSET_B, RateSource::Value((1, 2)))]),
+		));
+
+		// Assert
+		let expected = vec![RateSource::Value((1, 1)), RateSource::Value((1, 2))];
+		assert_eq!(
+			PoolRateSources::<Test>::get(UNIT_POOL_ID).unwrap().into_inner(),
+			expected
+		);
+		expect_events(vec![Event::RatesUpdated {
+			pool_id: UNIT_POOL_ID,
+			rates: expected,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn update_asset_rates_should_update_only_given_assets() {
+	ext().execute_with(|| {
+		// Arrange
+		set_external_rate(POOL_ID, ASSET_B, (1, 2));
+		create_rate_pool(RateSource::External);
+
+		// Act
+		assert_ok!(Stableswap::update_asset_rates(
+			RuntimeOrigin::root(),
+			POOL_ID,
+			rates(vec![(ASSET_A, RateSource::Value((3, 2)))]),
+		));
+
+		// Assert
+		assert_eq!(
+			PoolRateSources::<Test>::get(POOL_ID).unwrap().into_inner(),
+			vec![RateSource::Value((3, 2)), RateSource::External]
+		);
+	});
+}
+
+#[test]
+fn update_asset_rates_should_fail_when_asset_is_not_in_pool() {
+	ext().execute_with(|| {
+		create_unit_pool();
+
+		assert_noop!(
+			Stableswap::update_asset_rates(
+				RuntimeOrigin::root(),
+				UNIT_POOL_ID,
+				rates(vec![(ASSET_C, RateSource::Value((1, 2)))]),
+			),
+			Error::<Test>::AssetNotInPool
+		);
+	});
+}
+
+#[test]
+fn update_asset_rates_should_fail_when_origin_is_not_authority() {
+	ext().execute_with(|| {
+		create_unit_pool();
+
+		assert_noop!(
+			Stableswap::update_asset_rates(
+				RuntimeOrigin::signed(ALICE),
+				UNIT_POOL_ID,
+				rates(vec![(ASSET_B, RateSource::Value((1, 2)))]),
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+	});
+}
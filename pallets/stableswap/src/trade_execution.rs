@@ -1,11 +1,13 @@
 use crate::types::AssetAmount;
 use crate::{Balance, Config, Error, Pallet, Pools, D_ITERATIONS, Y_ITERATIONS};
+use frame_support::weights::Weight;
 use hydra_dx_math::stableswap::types::AssetReserve;
-use hydradx_traits::router::{ExecutorError, PoolType, TradeExecution};
+use hydradx_traits::router::{ExecutorError, PoolType, PoolsProvider, TradeExecution, TradeablePool};
 use orml_traits::MultiCurrency;
 use sp_core::Get;
 use sp_runtime::{ArithmeticError, DispatchError, FixedU128};
 use sp_std::vec;
+use sp_std::vec::Vec;
 
 impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balance> for Pallet<T>
 where
@@ -28,18 +30,20 @@ where
 					let asset_idx = pool
 						.find_asset(asset_out)
 						.ok_or_else(|| ExecutorError::Error(Error::<T>::AssetNotInPool.into()))?;
-					let pool_account = Self::pool_account(pool_id);
-					let balances = pool
-						.reserves_with_decimals::<T>(&pool_account)
-						.ok_or_else(|| ExecutorError::Error(Error::<T>::UnknownDecimals.into()))?;
+					let balances = Self::pool_reserves(pool_id, &pool).map_err(ExecutorError::Error)?;
 					let share_issuance = T::Currency::total_issuance(pool_id);
 
 					let amplification = Self::get_amplification(&pool);
-					let (amount, _) = hydra_dx_math::stableswap::calculate_withdraw_one_asset::<
-						D_ITERATIONS,
-						Y_ITERATIONS,
-					>(&balances, amount_in, asset_idx, share_issuance, amplification, pool.fee)
-					.ok_or_else(|| ExecutorError::Error(ArithmeticError::Overflow.into()))?;
+					let (amount, _) =
+						hydra_dx_math::stableswap::calculate_withdraw_one_asset::<D_ITERATIONS, Y_ITERATIONS>(
+							&balances,
+							amount_in,
+							asset_idx,
+							share_issuance,
+							amplification,
+							Self::get_fee(pool_id, &pool),
+						)
+						.ok_or_else(|| ExecutorError::Error(ArithmeticError::Overflow.into()))?;
 
 					Ok(amount)
 				} else if asset_out == pool_id {
@@ -79,10 +83,7 @@ where
 					let asset_idx = pool
 						.find_asset(asset_in)
 						.ok_or_else(|| ExecutorError::Error(Error::<T>::AssetNotInPool.into()))?;
-					let pool_account = Self::pool_account(pool_id);
-					let balances = pool
-						.reserves_with_decimals::<T>(&pool_account)
-						.ok_or_else(|| ExecutorError::Error(Error::<T>::UnknownDecimals.into()))?;
+					let balances = Self::pool_reserves(pool_id, &pool).map_err(ExecutorError::Error)?;
 					let share_issuance = T::Currency::total_issuance(pool_id);
 					let amplification = Self::get_amplification(&pool);
 
@@ -92,7 +93,7 @@ where
 						asset_idx,
 						share_issuance,
 						amplification,
-						pool.fee,
+						Self::get_fee(pool_id, &pool),
 					)
 					.ok_or_else(|| ExecutorError::Error(ArithmeticError::Overflow.into()))?;
 
@@ -103,10 +104,7 @@ where
 					let asset_idx = pool
 						.find_asset(asset_out)
 						.ok_or_else(|| ExecutorError::Error(Error::<T>::AssetNotInPool.into()))?;
-					let pool_account = Self::pool_account(pool_id);
-					let balances = pool
-						.reserves_with_decimals::<T>(&pool_account)
-						.ok_or_else(|| ExecutorError::Error(Error::<T>::UnknownDecimals.into()))?;
+					let balances = Self::pool_reserves(pool_id, &pool).map_err(ExecutorError::Error)?;
 					let share_issuance = T::Currency::total_issuance(pool_id);
 					let amplification = Self::get_amplification(&pool);
 
@@ -119,7 +117,7 @@ where
 						amount_out,
 						amplification,
 						share_issuance,
-						pool.fee,
+						Self::get_fee(pool_id, &pool),
 					)
 					.ok_or_else(|| ExecutorError::Error(ArithmeticError::Overflow.into()))?;
 
@@ -211,12 +209,9 @@ where
 	) -> Result<FixedU128, ExecutorError<Self::Error>> {
 		match pool_type {
 			PoolType::Stableswap(pool_id) => {
-				let pool_account = Self::pool_account(pool_id);
 				let pool =
 					Pools::<T>::get(pool_id).ok_or_else(|| ExecutorError::Error(Error::<T>::PoolNotFound.into()))?;
-				let balances = pool
-					.reserves_with_decimals::<T>(&pool_account)
-					.ok_or_else(|| ExecutorError::Error(Error::<T>::UnknownDecimals.into()))?;
+				let balances = Self::pool_reserves(pool_id, &pool).map_err(ExecutorError::Error)?;
 
 				let assets_with_reserves = pool
 					.assets
@@ -236,7 +231,7 @@ where
 					asset_b.into(),
 					share_issuance,
 					min_trade_limit,
-					Some(pool.fee),
+					Some(Self::get_fee(pool_id, &pool)),
 				)
 				.ok_or_else(|| ExecutorError::Error(ArithmeticError::Overflow.into()))?;
 
@@ -246,3 +241,23 @@ where
 		}
 	}
 }
+
+impl<T: Config> PoolsProvider<T::AssetId> for Pallet<T> {
+	fn get_pools(limit: u32) -> Vec<TradeablePool<T::AssetId>> {
+		Pools::<T>::iter()
+			.take(limit as usize)
+			.map(|(pool_id, pool)| {
+				let mut assets = pool.assets.to_vec();
+				assets.push(pool_id);
+				TradeablePool {
+					pool: PoolType::Stableswap(pool_id),
+					assets,
+				}
+			})
+			.collect()
+	}
+
+	fn get_pools_weight(limit: u32) -> Weight {
+		T::DbWeight::get().reads(limit.into())
+	}
+}
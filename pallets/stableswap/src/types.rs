@@ -14,6 +14,7 @@ use frame_support::traits::ConstU32;
 use frame_support::weights::Weight;
 use frame_support::BoundedVec;
 use hydra_dx_math::stableswap::types::AssetReserve;
+use hydradx_traits::{OraclePeriod, Source};
 use orml_traits::MultiCurrency;
 use scale_info::TypeInfo;
 use sp_core::RuntimeDebug;
@@ -65,15 +66,67 @@ where
 			.map(|asset| {
 				let reserve = T::Currency::free_balance((*asset).into(), account);
 				let decimals = Pallet::<T>::retrieve_decimals((*asset).into())?;
-				Some(AssetReserve {
-					amount: reserve,
-					decimals,
-				})
+				Some(AssetReserve::new(reserve, decimals))
 			})
 			.collect()
 	}
 }
 
+/// Source of the target rate of a pool asset.
+///
+/// Rate is expressed as (numerator, denominator) and it is the value of one unit of the asset
+/// relative to other assets in the pool. Reserve of the asset is scaled by the rate in calculations.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum RateSource<AssetId> {
+	/// Fixed rate.
+	Value((Balance, Balance)),
+	/// Rate is the oracle price of the asset denominated in given reference asset.
+	Oracle((Source, OraclePeriod, AssetId)),
+	/// Rate is provided by `Config::ExternalRateProvider`.
+	External,
+}
+
+impl<AssetId> Default for RateSource<AssetId> {
+	fn default() -> Self {
+		RateSource::Value((1, 1))
+	}
+}
+
+/// Provider of target rates of assets which use `RateSource::External`.
+pub trait ExternalRateProvider<AssetId> {
+	fn rate(pool_id: AssetId, asset_id: AssetId) -> Option<(Balance, Balance)>;
+
+	fn rate_weight() -> Weight;
+}
+
+impl<AssetId> ExternalRateProvider<AssetId> for () {
+	fn rate(_pool_id: AssetId, _asset_id: AssetId) -> Option<(Balance, Balance)> {
+		None
+	}
+
+	fn rate_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+/// Scheduled change of pool fee.
+///
+/// Fee changes linearly from `initial_fee` to `final_fee` between `initial_block` and `final_block`.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, Copy, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct FeeRamp<BlockNumber> {
+	pub initial_fee: Permill,
+	pub final_fee: Permill,
+	pub initial_block: BlockNumber,
+	pub final_block: BlockNumber,
+}
+
+/// Last calculated dynamic fee of a pool and block number when it was calculated.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, Copy, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct DynamicFeeEntry<BlockNumber> {
+	pub fee: Permill,
+	pub updated_at: BlockNumber,
+}
+
 #[derive(Debug, Clone, Encode, Decode, PartialEq, Eq, TypeInfo, Default)]
 pub struct AssetAmount<AssetId> {
 	pub asset_id: AssetId,
@@ -1005,6 +1005,38 @@ impl<T: Config> Pallet<T> {
 	pub fn get_position_votes(position_id: T::PositionItemId) -> Voting<T::MaxVotes> {
 		PositionVotes::<T>::get(position_id)
 	}
+
+	/// Returns points of the position and its `(claimable, unclaimable)` rewards as they would be
+	/// if the position was claimed in the current block.
+	///
+	/// Points for votes in finished referenda which were not processed yet are not included.
+	///
+	/// Return `(points, claimable_rewards, unclaimable_rewards)`
+	pub fn get_position_points_and_rewards(position_id: T::PositionItemId) -> Option<(Point, Balance, Balance)> {
+		let position = Positions::<T>::get(position_id)?;
+		let staking = Staking::<T>::get();
+
+		let pending_rewards = T::Currency::free_balance(T::NativeAssetId::get(), &Self::pot_account_id())
+			.saturating_sub(staking.pot_reserved_balance);
+		let accumulated_rps = if staking.total_stake.is_zero() || pending_rewards.is_zero() {
+			staking.accumulated_reward_per_stake
+		} else {
+			math::calculate_accumulated_rps(
+				staking.accumulated_reward_per_stake,
+				pending_rewards,
+				staking.total_stake,
+			)?
+		};
+
+		let current_period = Self::get_current_period()?;
+		let created_at = Self::get_period_number(position.created_at)?;
+
+		let points = Self::get_points(&position, current_period, created_at)?;
+		let (claimable_rewards, unclaimable_rewards, _) =
+			Self::calculate_rewards(&position, accumulated_rps, current_period, created_at)?;
+
+		Some((points, claimable_rewards, unclaimable_rewards))
+	}
 }
 
 pub struct SigmoidPercentage<T, B>(sp_std::marker::PhantomData<(T, B)>);
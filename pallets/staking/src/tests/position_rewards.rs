@@ -0,0 +1,88 @@
+use super::*;
+
+use mock::Staking;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn get_position_points_and_rewards_should_return_none_when_position_does_not_exist() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			assert_eq!(Staking::get_position_points_and_rewards(0), None);
+		});
+}
+
+#[test]
+fn get_position_points_and_rewards_should_match_claimed_rewards() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, HDX, 150_000 * ONE),
+			(BOB, HDX, 250_000 * ONE),
+			(CHARLIE, HDX, 10_000 * ONE),
+			(DAVE, HDX, 100_000 * ONE),
+		])
+		.start_at_block(1_452_987)
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 200_000 * ONE),
+			(BOB, 120_000 * ONE, 1_452_987, 0),
+			(CHARLIE, 10_000 * ONE, 1_455_000, 10_000 * ONE),
+			(DAVE, 10 * ONE, 1_465_000, 1),
+		])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_pending_rewards(10_000 * ONE);
+			set_block_number(1_700_000);
+			let bob_position_id = Staking::get_user_position_id(&BOB).unwrap().unwrap();
+
+			//Act
+			let (points, claimable, unclaimable) = Staking::get_position_points_and_rewards(bob_position_id).unwrap();
+
+			//Assert
+			assert!(claimable > 0);
+			assert_ok!(Staking::claim(RuntimeOrigin::signed(BOB), bob_position_id));
+
+			let Some(RuntimeEvent::Staking(Event::<Test>::RewardsClaimed {
+				paid_rewards,
+				slashed_points,
+				slashed_unpaid_rewards,
+				..
+			})) = System::events().last().map(|record| record.event.clone())
+			else {
+				panic!("RewardsClaimed event expected");
+			};
+			assert_eq!(paid_rewards, claimable);
+			assert_eq!(slashed_points, points);
+			assert_eq!(slashed_unpaid_rewards, unclaimable);
+		});
+}
+
+#[test]
+fn get_position_points_and_rewards_should_not_return_claimable_rewards_when_in_unclaimable_periods() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 150_000 * ONE), (BOB, HDX, 250_000 * ONE)])
+		.start_at_block(1_452_987)
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 200_000 * ONE),
+			(BOB, 120_000 * ONE, 1_452_987, 0),
+		])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_pending_rewards(10_000 * ONE);
+			set_block_number(1_452_988);
+			let bob_position_id = Staking::get_user_position_id(&BOB).unwrap().unwrap();
+
+			//Act
+			let (_, claimable, unclaimable) = Staking::get_position_points_and_rewards(bob_position_id).unwrap();
+
+			//Assert
+			assert_eq!(claimable, 0);
+			assert!(unclaimable > 0);
+		});
+}
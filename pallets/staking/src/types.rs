@@ -58,6 +58,10 @@ impl<BlockNumber> Position<BlockNumber> {
 	pub fn get_action_points(&self) -> Point {
 		self.action_points
 	}
+
+	pub fn get_stake(&self) -> Balance {
+		self.stake
+	}
 }
 
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo, Default)]
@@ -683,8 +683,12 @@ where
 				fee_in_dot.into(),
 			)
 			.map_err(|_| TransactionValidityError::Invalid(InvalidTransaction::Payment))?;
-			let pool_fee = T::SwappablePaymentAssetSupport::calculate_fee_amount(amount_in)
-				.map_err(|_| TransactionValidityError::Invalid(InvalidTransaction::Payment))?;
+			let pool_fee = T::SwappablePaymentAssetSupport::calculate_fee_amount(
+				currency,
+				T::PolkadotNativeAssetId::get(),
+				amount_in,
+			)
+			.map_err(|_| TransactionValidityError::Invalid(InvalidTransaction::Payment))?;
 			let max_limit = amount_in.saturating_add(pool_fee);
 
 			T::SwappablePaymentAssetSupport::buy(
@@ -204,7 +204,11 @@ impl SwappablePaymentAssetTrader<AccountId, AssetId, Balance> for MockedInsuffic
 		unimplemented!()
 	}
 
-	fn calculate_fee_amount(_swap_amount: Balance) -> Result<Balance, DispatchError> {
+	fn calculate_fee_amount(
+		_asset_in: AssetId,
+		_asset_out: AssetId,
+		_swap_amount: Balance,
+	) -> Result<Balance, DispatchError> {
 		unimplemented!()
 	}
 
@@ -0,0 +1,84 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::account;
+use frame_benchmarking::benchmarks;
+use frame_support::traits::tokens::fungibles::Mutate;
+use frame_system::RawOrigin;
+
+const ONE: Balance = 1_000_000_000_000;
+
+benchmarks! {
+	where_clause { where
+		T::Currency: Mutate<T::AccountId>,
+		T::AssetId: From<u32>,
+	}
+
+	set_target_asset{
+		let target: T::AssetId = 0u32.into();
+	}: _(RawOrigin::Root, target)
+	verify {
+		assert_eq!(TargetAsset::<T>::get(), Some(target));
+	}
+
+	set_threshold{
+		let target: T::AssetId = 0u32.into();
+		let asset_id: T::AssetId = 1u32.into();
+		TargetAsset::<T>::put(target);
+	}: _(RawOrigin::Root, asset_id, ONE)
+	verify {
+		assert_eq!(ConversionThresholds::<T>::get(asset_id), Some(ONE));
+	}
+
+	remove_threshold{
+		let asset_id: T::AssetId = 1u32.into();
+		ConversionThresholds::<T>::insert(asset_id, ONE);
+	}: _(RawOrigin::Root, asset_id)
+	verify {
+		assert_eq!(ConversionThresholds::<T>::get(asset_id), None);
+	}
+
+	convert{
+		let caller: T::AccountId = account("caller", 0, 1);
+		let target: T::AssetId = 1u32.into();
+		let asset_id: T::AssetId = 0u32.into();
+		TargetAsset::<T>::put(target);
+		ConversionThresholds::<T>::insert(asset_id, ONE);
+		T::Currency::mint_into(asset_id, &T::TreasuryAccount::get(), 1_000 * ONE)?;
+		// successful conversion clears the failure of the asset
+		ConversionFailures::<T>::insert(asset_id, (1, BlockNumberFor::<T>::from(1u32)));
+	}: _(RawOrigin::Signed(caller), asset_id)
+	verify {
+		assert_eq!(ConversionFailures::<T>::get(asset_id), None);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Pallet;
+	use crate::tests::mock::*;
+	use frame_benchmarking::impl_benchmark_test_suite;
+	impl_benchmark_test_suite!(
+		Pallet,
+		super::ExtBuilder::default()
+			.with_price((1, 0), EmaPrice::new(1, 1))
+			.build(),
+		super::Test
+	);
+}
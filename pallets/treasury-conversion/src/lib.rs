@@ -0,0 +1,464 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Treasury conversion pallet
+//!
+//! Automatic conversion of assets accumulated in the treasury account to a single target asset.
+//!
+//! ## Overview
+//!
+//! Trade, transaction and OTC fees are sent to the treasury account in many different assets.
+//! This pallet consolidates them by selling them for a target asset selected by governance.
+//!
+//! Governance configures the target asset and a conversion threshold for every asset that should be converted.
+//! Once the treasury balance of such asset reaches its threshold, the whole reducible balance is sold
+//! in `on_idle` hook through the router. Conversions are limited by the remaining block weight
+//! and by `MaxConversionsPerBlock`. Assets which were not processed in a block are processed in the following blocks.
+//!
+//! Minimum amount received from a conversion is derived from the oracle price and `MaxSlippage`.
+//! Conversion which fails in `on_idle` (eg. no oracle price or trade limit not reached) is skipped and retried
+//! after `ConversionRetryDelay` blocks. The delay doubles with every consecutive failure of the asset, up to
+//! `2^MAX_RETRY_DELAY_EXPONENT` times the initial delay. Successful conversion or setting the threshold of the asset
+//! resets the delay.
+//!
+//! Conversion of an asset whose balance reached the threshold can also be triggered by any account via `convert`.
+//!
+//! ### Terminology
+//!
+//! * **Target asset:**  id of an asset to which all configured assets are converted.
+//! * **Threshold:**  minimum treasury balance of an asset which triggers the conversion.
+//!
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod weights;
+
+#[cfg(any(feature = "runtime-benchmarks", test))]
+mod benchmarking;
+#[cfg(test)]
+mod tests;
+
+use frame_support::pallet_prelude::{DispatchResult, Get, Weight};
+use frame_support::traits::fungibles::Inspect;
+use frame_support::traits::tokens::{Fortitude, Preservation};
+use frame_support::{ensure, transactional};
+use frame_system::{
+	ensure_signed,
+	pallet_prelude::{BlockNumberFor, OriginFor},
+	RawOrigin,
+};
+use hydra_dx_math::ema::EmaPrice;
+use hydradx_traits::price::PriceProvider;
+use hydradx_traits::router::{AmmTradeWeights, AmountInAndOut, AssetPair, RouteProvider, RouterT, Trade};
+use sp_runtime::helpers_128bit::multiply_by_rational_with_rounding;
+use sp_runtime::traits::{Saturating, Zero};
+use sp_runtime::{ArithmeticError, DispatchError, Permill, Rounding};
+
+pub use pallet::*;
+
+pub use weights::WeightInfo;
+
+pub type Balance = u128;
+
+/// Maximum number of times the retry delay of a failing conversion is doubled.
+pub const MAX_RETRY_DELAY_EXPONENT: u32 = 10;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Origin that can set the target asset and conversion thresholds.
+		type AuthorityOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Asset type
+		type AssetId: frame_support::traits::tokens::AssetId + MaybeSerializeDeserialize + Copy;
+
+		/// Support for balance queries.
+		type Currency: Inspect<Self::AccountId, AssetId = Self::AssetId, Balance = Balance>;
+
+		/// Router used to sell accumulated assets.
+		type RouteExecutor: RouterT<
+			Self::RuntimeOrigin,
+			Self::AssetId,
+			Balance,
+			Trade<Self::AssetId>,
+			AmountInAndOut<Balance>,
+		>;
+
+		/// Provider of the route used for conversions.
+		type RouteProvider: RouteProvider<Self::AssetId>;
+
+		/// Oracle price provider used to calculate minimum amount received from a conversion.
+		type PriceProvider: PriceProvider<Self::AssetId, Price = EmaPrice>;
+
+		/// Account which holds the assets to convert.
+		type TreasuryAccount: Get<Self::AccountId>;
+
+		/// Maximum allowed difference between the oracle price and the execution price of a conversion.
+		#[pallet::constant]
+		type MaxSlippage: Get<Permill>;
+
+		/// Maximum number of conversions executed in one block.
+		#[pallet::constant]
+		type MaxConversionsPerBlock: Get<u32>;
+
+		/// Number of blocks for which `on_idle` skips an asset after its first failed conversion.
+		#[pallet::constant]
+		type ConversionRetryDelay: Get<BlockNumberFor<Self>>;
+
+		/// Weight information for the trades executed by the router.
+		type AmmTradeWeights: AmmTradeWeights<Trade<Self::AssetId>>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Asset to which the treasury assets are converted.
+	#[pallet::storage]
+	#[pallet::getter(fn target_asset)]
+	pub(super) type TargetAsset<T: Config> = StorageValue<_, T::AssetId, OptionQuery>;
+
+	/// Conversion thresholds.
+	/// Maps an asset to minimum treasury balance which triggers its conversion.
+	#[pallet::storage]
+	#[pallet::getter(fn conversion_threshold)]
+	pub(super) type ConversionThresholds<T: Config> = StorageMap<_, Blake2_128Concat, T::AssetId, Balance, OptionQuery>;
+
+	/// Last asset processed in `on_idle`.
+	/// Processing continues from the following asset in the next block.
+	#[pallet::storage]
+	#[pallet::getter(fn last_processed_asset)]
+	pub(super) type LastProcessedAsset<T: Config> = StorageValue<_, T::AssetId, OptionQuery>;
+
+	/// Failed conversions.
+	/// Maps an asset to the number of its consecutive failed conversions in `on_idle`
+	/// and the block from which its conversion is retried.
+	#[pallet::storage]
+	#[pallet::getter(fn conversion_failure)]
+	pub(super) type ConversionFailures<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, (u32, BlockNumberFor<T>), OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Target asset has been set.
+		TargetAssetSet { asset_id: T::AssetId },
+		/// Conversion threshold has been set.
+		ThresholdSet { asset_id: T::AssetId, threshold: Balance },
+		/// Conversion threshold has been removed.
+		ThresholdRemoved { asset_id: T::AssetId },
+		/// Treasury asset has been converted to target asset.
+		Converted {
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount_in: Balance,
+			amount_out: Balance,
+		},
+		/// Conversion of treasury asset failed. Conversion is retried in `on_idle` from `retry_at` block.
+		ConversionFailed {
+			asset_id: T::AssetId,
+			error: DispatchError,
+			retry_at: BlockNumberFor<T>,
+		},
+	}
+
+	#[pallet::error]
+	#[cfg_attr(test, derive(PartialEq, Eq))]
+	pub enum Error<T> {
+		/// Target asset has not been set.
+		TargetAssetNotSet,
+		/// Asset is the target asset and cannot be converted.
+		AssetIsTargetAsset,
+		/// Target asset must not have a conversion threshold set.
+		AssetHasThreshold,
+		/// Conversion threshold is not set for the asset.
+		ThresholdNotFound,
+		/// Conversion threshold must be greater than zero.
+		ZeroThreshold,
+		/// Treasury balance of the asset is below the conversion threshold.
+		BelowThreshold,
+		/// Price for given asset pair not found.
+		PriceNotFound,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the asset to which treasury assets are converted.
+		///
+		/// Can be called only by `AuthorityOrigin`.
+		///
+		/// Parameters:
+		/// - `asset_id`: Id of the target asset. Must not have a conversion threshold set.
+		///
+		/// Emits `TargetAssetSet` event when successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_target_asset())]
+		pub fn set_target_asset(origin: OriginFor<T>, asset_id: T::AssetId) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+			ensure!(
+				!ConversionThresholds::<T>::contains_key(asset_id),
+				Error::<T>::AssetHasThreshold
+			);
+
+			TargetAsset::<T>::put(asset_id);
+			Self::deposit_event(Event::TargetAssetSet { asset_id });
+			Ok(())
+		}
+
+		/// Set conversion threshold of an asset.
+		///
+		/// Resets the retry delay of failed conversions of the asset.
+		///
+		/// Can be called only by `AuthorityOrigin`.
+		///
+		/// Parameters:
+		/// - `asset_id`: Id of an asset to convert. Must not be the target asset.
+		/// - `threshold`: Minimum treasury balance of the asset which triggers the conversion.
+		///
+		/// Emits `ThresholdSet` event when successful.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_threshold())]
+		pub fn set_threshold(origin: OriginFor<T>, asset_id: T::AssetId, threshold: Balance) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+			ensure!(!threshold.is_zero(), Error::<T>::ZeroThreshold);
+			ensure!(
+				TargetAsset::<T>::get() != Some(asset_id),
+				Error::<T>::AssetIsTargetAsset
+			);
+
+			ConversionThresholds::<T>::insert(asset_id, threshold);
+			ConversionFailures::<T>::remove(asset_id);
+			Self::deposit_event(Event::ThresholdSet { asset_id, threshold });
+			Ok(())
+		}
+
+		/// Remove conversion threshold of an asset.
+		///
+		/// The asset is no longer converted.
+		///
+		/// Can be called only by `AuthorityOrigin`.
+		///
+		/// Parameters:
+		/// - `asset_id`: Id of an asset.
+		///
+		/// Emits `ThresholdRemoved` event when successful.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_threshold())]
+		pub fn remove_threshold(origin: OriginFor<T>, asset_id: T::AssetId) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+			ensure!(
+				ConversionThresholds::<T>::contains_key(asset_id),
+				Error::<T>::ThresholdNotFound
+			);
+
+			ConversionThresholds::<T>::remove(asset_id);
+			ConversionFailures::<T>::remove(asset_id);
+			Self::deposit_event(Event::ThresholdRemoved { asset_id });
+			Ok(())
+		}
+
+		/// Convert treasury balance of an asset to the target asset.
+		///
+		/// Treasury balance of the asset must reach its conversion threshold.
+		///
+		/// Parameters:
+		/// - `asset_id`: Id of an asset to convert.
+		///
+		/// Emits `Converted` event when successful.
+		#[pallet::call_index(3)]
+		#[pallet::weight({
+			let route = TargetAsset::<T>::get()
+				.map(|target| T::RouteProvider::get_route(AssetPair::new(*asset_id, target)))
+				.unwrap_or_default();
+			<T as Config>::WeightInfo::convert().saturating_add(T::AmmTradeWeights::sell_weight(&route))
+		})]
+		pub fn convert(origin: OriginFor<T>, asset_id: T::AssetId) -> DispatchResult {
+			ensure_signed(origin)?;
+			let target = TargetAsset::<T>::get().ok_or(Error::<T>::TargetAssetNotSet)?;
+			Self::do_convert(asset_id, target)
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::process_conversions(n, remaining_weight)
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Convert assets whose treasury balance reached the threshold, starting after the last processed asset.
+	/// Assets whose conversion failed are skipped until their retry block.
+	///
+	/// Returns consumed weight.
+	fn process_conversions(block_number: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+		// read of target asset and last processed asset, write of last processed asset
+		let mut used_weight = T::DbWeight::get().reads_writes(2, 1);
+		if remaining_weight.any_lt(used_weight) {
+			return Weight::zero();
+		}
+
+		let Some(target) = TargetAsset::<T>::get() else {
+			return used_weight;
+		};
+
+		let start = LastProcessedAsset::<T>::get();
+		let mut iter = match start {
+			Some(asset_id) => ConversionThresholds::<T>::iter_from(ConversionThresholds::<T>::hashed_key_for(asset_id)),
+			None => ConversionThresholds::<T>::iter(),
+		};
+
+		let mut conversions = 0u32;
+		let mut last_processed = start;
+		let mut finished = true;
+
+		while conversions < T::MaxConversionsPerBlock::get() {
+			// reads of the threshold entry, the treasury balance and the conversion failure
+			let check_weight = T::DbWeight::get().reads(3);
+			if remaining_weight.any_lt(used_weight.saturating_add(check_weight)) {
+				finished = false;
+				break;
+			}
+
+			let Some((asset_id, threshold)) = iter.next() else {
+				break;
+			};
+
+			let balance = Self::convertible_balance(asset_id);
+			let failure = ConversionFailures::<T>::get(asset_id);
+			let is_delayed = failure.is_some_and(|(_, retry_at)| block_number < retry_at);
+			if balance < threshold || asset_id == target || is_delayed {
+				used_weight.saturating_accrue(check_weight);
+				last_processed = Some(asset_id);
+				continue;
+			}
+
+			let route = T::RouteProvider::get_route(AssetPair::new(asset_id, target));
+			// failed conversion is recorded
+			let convert_weight = <T as Config>::WeightInfo::convert()
+				.saturating_add(T::AmmTradeWeights::sell_weight(&route))
+				.saturating_add(T::DbWeight::get().writes(1));
+			if remaining_weight.any_lt(used_weight.saturating_add(convert_weight)) {
+				finished = false;
+				break;
+			}
+
+			if let Err(error) = Self::do_convert(asset_id, target) {
+				let failures = failure.map_or(0, |(failures, _)| failures).saturating_add(1);
+				let retry_at = block_number.saturating_add(Self::retry_delay(failures));
+				ConversionFailures::<T>::insert(asset_id, (failures, retry_at));
+				Self::deposit_event(Event::ConversionFailed {
+					asset_id,
+					error,
+					retry_at,
+				});
+			}
+
+			used_weight.saturating_accrue(convert_weight);
+			last_processed = Some(asset_id);
+			conversions += 1;
+		}
+
+		if finished && conversions < T::MaxConversionsPerBlock::get() {
+			LastProcessedAsset::<T>::kill();
+		} else if let Some(asset_id) = last_processed {
+			LastProcessedAsset::<T>::put(asset_id);
+		}
+
+		used_weight
+	}
+
+	/// Sell the whole reducible treasury balance of `asset_id` for `target`.
+	#[transactional]
+	fn do_convert(asset_id: T::AssetId, target: T::AssetId) -> DispatchResult {
+		ensure!(asset_id != target, Error::<T>::AssetIsTargetAsset);
+		let threshold = ConversionThresholds::<T>::get(asset_id).ok_or(Error::<T>::ThresholdNotFound)?;
+
+		let amount_in = Self::convertible_balance(asset_id);
+		ensure!(
+			!amount_in.is_zero() && amount_in >= threshold,
+			Error::<T>::BelowThreshold
+		);
+
+		let min_amount_out = Self::calculate_min_amount_out(asset_id, target, amount_in)?;
+		let route = T::RouteProvider::get_route(AssetPair::new(asset_id, target));
+
+		let treasury = T::TreasuryAccount::get();
+		let target_balance = T::Currency::balance(target, &treasury);
+
+		T::RouteExecutor::sell(
+			RawOrigin::Signed(treasury.clone()).into(),
+			asset_id,
+			target,
+			amount_in,
+			min_amount_out,
+			route,
+		)?;
+
+		let amount_out = T::Currency::balance(target, &treasury).saturating_sub(target_balance);
+
+		ConversionFailures::<T>::remove(asset_id);
+
+		Self::deposit_event(Event::Converted {
+			asset_in: asset_id,
+			asset_out: target,
+			amount_in,
+			amount_out,
+		});
+
+		Ok(())
+	}
+
+	/// Number of blocks for which an asset is skipped after `failures` consecutive failed conversions.
+	fn retry_delay(failures: u32) -> BlockNumberFor<T> {
+		let exponent = failures.saturating_sub(1).min(MAX_RETRY_DELAY_EXPONENT);
+		T::ConversionRetryDelay::get().saturating_mul(2u32.saturating_pow(exponent).into())
+	}
+
+	/// Treasury balance of an asset which can be sold without killing the treasury account.
+	fn convertible_balance(asset_id: T::AssetId) -> Balance {
+		T::Currency::reducible_balance(
+			asset_id,
+			&T::TreasuryAccount::get(),
+			Preservation::Preserve,
+			Fortitude::Polite,
+		)
+	}
+
+	/// Expected amount of `target` received for `amount_in` of `asset_id` at the oracle price,
+	/// reduced by `MaxSlippage`.
+	fn calculate_min_amount_out(
+		asset_id: T::AssetId,
+		target: T::AssetId,
+		amount_in: Balance,
+	) -> Result<Balance, DispatchError> {
+		let price = T::PriceProvider::get_price(target, asset_id).ok_or(Error::<T>::PriceNotFound)?;
+		let expected_amount_out = multiply_by_rational_with_rounding(amount_in, price.n, price.d, Rounding::Down)
+			.ok_or(ArithmeticError::Overflow)?;
+		let slippage_amount = T::MaxSlippage::get().mul_ceil(expected_amount_out);
+
+		Ok(expected_amount_out.saturating_sub(slippage_amount))
+	}
+}
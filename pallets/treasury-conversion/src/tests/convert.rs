@@ -0,0 +1,140 @@
+use crate::tests::mock::*;
+use crate::*;
+use frame_support::{assert_noop, assert_ok};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn convert_should_sell_treasury_balance_for_target_asset() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(TREASURY, DAI, 1_000 * ONE)])
+		.with_target_asset(HDX)
+		.with_thresholds(vec![(DAI, 100 * ONE)])
+		.with_price((HDX, DAI), EmaPrice::new(2, 1))
+		.build()
+		.execute_with(|| {
+			// Act
+			assert_ok!(TreasuryConversion::convert(RuntimeOrigin::signed(ALICE), DAI));
+
+			// Assert
+			assert_balance!(TREASURY, DAI, 0);
+			assert_balance!(TREASURY, HDX, 2_000 * ONE);
+			expect_events(vec![Event::Converted {
+				asset_in: DAI,
+				asset_out: HDX,
+				amount_in: 1_000 * ONE,
+				amount_out: 2_000 * ONE,
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn convert_should_work_when_execution_price_is_within_slippage() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(TREASURY, DAI, 1_000 * ONE)])
+		.with_target_asset(HDX)
+		.with_thresholds(vec![(DAI, 100 * ONE)])
+		.with_price((HDX, DAI), EmaPrice::new(2, 1))
+		.with_trade_price((HDX, DAI), EmaPrice::new(199, 100))
+		.build()
+		.execute_with(|| {
+			// Act
+			assert_ok!(TreasuryConversion::convert(RuntimeOrigin::signed(ALICE), DAI));
+
+			// Assert
+			assert_balance!(TREASURY, DAI, 0);
+			assert_balance!(TREASURY, HDX, 1_990 * ONE);
+		});
+}
+
+#[test]
+fn convert_should_fail_when_execution_price_exceeds_slippage() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(TREASURY, DAI, 1_000 * ONE)])
+		.with_target_asset(HDX)
+		.with_thresholds(vec![(DAI, 100 * ONE)])
+		.with_price((HDX, DAI), EmaPrice::new(2, 1))
+		.with_trade_price((HDX, DAI), EmaPrice::new(197, 100))
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				TreasuryConversion::convert(RuntimeOrigin::signed(ALICE), DAI),
+				DispatchError::Other("TradingLimitReached")
+			);
+		});
+}
+
+#[test]
+fn convert_should_fail_when_balance_is_below_threshold() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(TREASURY, DAI, 99 * ONE)])
+		.with_target_asset(HDX)
+		.with_thresholds(vec![(DAI, 100 * ONE)])
+		.with_price((HDX, DAI), EmaPrice::new(2, 1))
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				TreasuryConversion::convert(RuntimeOrigin::signed(ALICE), DAI),
+				Error::<Test>::BelowThreshold
+			);
+		});
+}
+
+#[test]
+fn convert_should_fail_when_target_asset_is_not_set() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(TREASURY, DAI, 1_000 * ONE)])
+		.with_thresholds(vec![(DAI, 100 * ONE)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				TreasuryConversion::convert(RuntimeOrigin::signed(ALICE), DAI),
+				Error::<Test>::TargetAssetNotSet
+			);
+		});
+}
+
+#[test]
+fn convert_should_fail_when_threshold_is_not_set() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(TREASURY, DAI, 1_000 * ONE)])
+		.with_target_asset(HDX)
+		.with_price((HDX, DAI), EmaPrice::new(2, 1))
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				TreasuryConversion::convert(RuntimeOrigin::signed(ALICE), DAI),
+				Error::<Test>::ThresholdNotFound
+			);
+		});
+}
+
+#[test]
+fn convert_should_fail_when_asset_is_target_asset() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(TREASURY, HDX, 1_000 * ONE)])
+		.with_target_asset(HDX)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				TreasuryConversion::convert(RuntimeOrigin::signed(ALICE), HDX),
+				Error::<Test>::AssetIsTargetAsset
+			);
+		});
+}
+
+#[test]
+fn convert_should_fail_when_oracle_price_is_not_available() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(TREASURY, DAI, 1_000 * ONE)])
+		.with_target_asset(HDX)
+		.with_thresholds(vec![(DAI, 100 * ONE)])
+		.with_trade_price((HDX, DAI), EmaPrice::new(2, 1))
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				TreasuryConversion::convert(RuntimeOrigin::signed(ALICE), DAI),
+				Error::<Test>::PriceNotFound
+			);
+		});
+}
@@ -0,0 +1,351 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate as pallet_treasury_conversion;
+use crate::*;
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use frame_support::{
+	construct_runtime, parameter_types,
+	sp_runtime::traits::{BlakeTwo256, ConstU32, ConstU64, IdentityLookup},
+	traits::Everything,
+};
+use sp_core::H256;
+
+use frame_support::dispatch::DispatchResultWithPostInfo;
+use frame_system::EnsureRoot;
+use hydradx_traits::router::AssetPair;
+use orml_traits::parameter_type_with_key;
+pub use orml_traits::MultiCurrency;
+use orml_traits::MultiCurrencyExtended;
+use sp_runtime::{BuildStorage, DispatchError};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub(crate) type AccountId = u64;
+pub(crate) type AssetId = u32;
+
+pub(crate) const ONE: Balance = 1_000_000_000_000;
+
+pub const HDX: AssetId = 0;
+pub const DAI: AssetId = 2;
+pub const DOT: AssetId = 5;
+pub const BTC: AssetId = 6;
+
+pub const ALICE: AccountId = 1;
+pub const TREASURY: AccountId = 400;
+
+thread_local! {
+	pub static ORACLE_PRICES: RefCell<HashMap<(AssetId, AssetId), EmaPrice>> = RefCell::new(HashMap::default());
+	pub static TRADE_PRICES: RefCell<HashMap<(AssetId, AssetId), EmaPrice>> = RefCell::new(HashMap::default());
+	pub static MAX_CONVERSIONS: RefCell<u32> = const { RefCell::new(10) };
+}
+
+construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		TreasuryConversion: pallet_treasury_conversion,
+		Tokens: orml_tokens,
+	}
+);
+
+parameter_types! {
+	pub const TreasuryAccount: AccountId = TREASURY;
+	pub const MaxSlippage: Permill = Permill::from_percent(1);
+	pub const ConversionRetryDelay: u64 = 10;
+}
+
+pub struct MaxConversionsPerBlock;
+
+impl Get<u32> for MaxConversionsPerBlock {
+	fn get() -> u32 {
+		MAX_CONVERSIONS.with(|v| *v.borrow())
+	}
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type AssetId = AssetId;
+	type Currency = Tokens;
+	type RouteExecutor = MockRouter;
+	type RouteProvider = MockRouter;
+	type PriceProvider = OraclePrice;
+	type TreasuryAccount = TreasuryAccount;
+	type MaxSlippage = MaxSlippage;
+	type MaxConversionsPerBlock = MaxConversionsPerBlock;
+	type ConversionRetryDelay = ConversionRetryDelay;
+	type AmmTradeWeights = ();
+	type WeightInfo = ();
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeTask = RuntimeTask;
+	type Nonce = u64;
+	type Block = Block;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+	type SingleBlockMigrations = ();
+	type MultiBlockMigrator = ();
+	type PreInherents = ();
+	type PostInherents = ();
+	type PostTransactions = ();
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_asset_id: AssetId| -> Balance {
+		0
+	};
+}
+
+impl orml_tokens::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type Amount = i128;
+	type CurrencyId = AssetId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type CurrencyHooks = ();
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = ();
+	type DustRemovalWhitelist = Everything;
+}
+
+pub struct ExtBuilder {
+	endowed_accounts: Vec<(AccountId, AssetId, Balance)>,
+	target_asset: Option<AssetId>,
+	thresholds: Vec<(AssetId, Balance)>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		ORACLE_PRICES.with(|v| {
+			v.borrow_mut().clear();
+		});
+		TRADE_PRICES.with(|v| {
+			v.borrow_mut().clear();
+		});
+		MAX_CONVERSIONS.with(|v| {
+			*v.borrow_mut() = 10;
+		});
+
+		Self {
+			endowed_accounts: vec![],
+			target_asset: None,
+			thresholds: vec![],
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn with_endowed_accounts(mut self, accounts: Vec<(AccountId, AssetId, Balance)>) -> Self {
+		self.endowed_accounts.extend(accounts);
+		self
+	}
+
+	pub fn with_target_asset(mut self, asset_id: AssetId) -> Self {
+		self.target_asset = Some(asset_id);
+		self
+	}
+
+	pub fn with_thresholds(mut self, thresholds: Vec<(AssetId, Balance)>) -> Self {
+		self.thresholds.extend(thresholds);
+		self
+	}
+
+	/// Sets both oracle and trade price of `pair`.
+	/// Price is the amount of `pair.0` received for one unit of `pair.1`.
+	pub fn with_price(self, pair: (AssetId, AssetId), price: EmaPrice) -> Self {
+		ORACLE_PRICES.with(|v| {
+			let mut m = v.borrow_mut();
+			m.insert(pair, price);
+			m.insert((pair.1, pair.0), price.inverted());
+		});
+		self.with_trade_price(pair, price)
+	}
+
+	pub fn with_trade_price(self, pair: (AssetId, AssetId), price: EmaPrice) -> Self {
+		TRADE_PRICES.with(|v| {
+			let mut m = v.borrow_mut();
+			m.insert(pair, price);
+			m.insert((pair.1, pair.0), price.inverted());
+		});
+		self
+	}
+
+	pub fn with_max_conversions_per_block(self, max: u32) -> Self {
+		MAX_CONVERSIONS.with(|v| {
+			*v.borrow_mut() = max;
+		});
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+		orml_tokens::GenesisConfig::<Test> {
+			balances: self.endowed_accounts.clone(),
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		let mut r: sp_io::TestExternalities = t.into();
+
+		r.execute_with(|| {
+			if let Some(asset_id) = self.target_asset {
+				TargetAsset::<Test>::put(asset_id);
+			}
+			for (asset_id, threshold) in self.thresholds.iter() {
+				ConversionThresholds::<Test>::insert(asset_id, threshold);
+			}
+		});
+
+		r.execute_with(|| {
+			System::set_block_number(1);
+		});
+
+		r
+	}
+}
+
+pub fn expect_events(e: Vec<RuntimeEvent>) {
+	e.into_iter().for_each(frame_system::Pallet::<Test>::assert_has_event);
+}
+
+#[macro_export]
+macro_rules! assert_balance {
+	( $x:expr, $y:expr, $z:expr) => {{
+		assert_eq!(Tokens::free_balance($y, &$x), $z);
+	}};
+}
+
+pub struct OraclePrice;
+
+impl PriceProvider<AssetId> for OraclePrice {
+	type Price = EmaPrice;
+
+	fn get_price(asset_a: AssetId, asset_b: AssetId) -> Option<Self::Price> {
+		if asset_a == asset_b {
+			return Some(EmaPrice::one());
+		}
+		ORACLE_PRICES.with(|v| v.borrow().get(&(asset_a, asset_b)).copied())
+	}
+}
+
+pub struct MockRouter;
+
+impl RouteProvider<AssetId> for MockRouter {}
+
+impl RouterT<RuntimeOrigin, AssetId, Balance, Trade<AssetId>, AmountInAndOut<Balance>> for MockRouter {
+	fn sell(
+		origin: RuntimeOrigin,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		min_amount_out: Balance,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResult {
+		let who = ensure_signed(origin)?;
+		let price = TRADE_PRICES
+			.with(|v| v.borrow().get(&(asset_out, asset_in)).copied())
+			.ok_or(DispatchError::Other("PoolNotFound"))?;
+		let amount_out = multiply_by_rational_with_rounding(amount_in, price.n, price.d, Rounding::Down)
+			.ok_or(ArithmeticError::Overflow)?;
+		ensure!(
+			amount_out >= min_amount_out,
+			DispatchError::Other("TradingLimitReached")
+		);
+
+		Tokens::withdraw(asset_in, &who, amount_in)?;
+		Tokens::update_balance(asset_out, &who, amount_out as i128)?;
+		Ok(())
+	}
+
+	fn sell_all(
+		_origin: RuntimeOrigin,
+		_asset_in: AssetId,
+		_asset_out: AssetId,
+		_min_amount_out: Balance,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn buy(
+		_origin: RuntimeOrigin,
+		_asset_in: AssetId,
+		_asset_out: AssetId,
+		_amount_out: Balance,
+		_max_amount_in: Balance,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn calculate_sell_trade_amounts(
+		_route: &[Trade<AssetId>],
+		_amount_in: Balance,
+	) -> Result<Vec<AmountInAndOut<Balance>>, DispatchError> {
+		unimplemented!()
+	}
+
+	fn calculate_buy_trade_amounts(
+		_route: &[Trade<AssetId>],
+		_amount_out: Balance,
+	) -> Result<Vec<AmountInAndOut<Balance>>, DispatchError> {
+		unimplemented!()
+	}
+
+	fn set_route(
+		_origin: RuntimeOrigin,
+		_asset_pair: AssetPair<AssetId>,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResultWithPostInfo {
+		unimplemented!()
+	}
+
+	fn force_insert_route(
+		_origin: RuntimeOrigin,
+		_asset_pair: AssetPair<AssetId>,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResultWithPostInfo {
+		unimplemented!()
+	}
+}
@@ -0,0 +1,4 @@
+mod convert;
+pub(crate) mod mock;
+mod on_idle;
+mod settings;
@@ -0,0 +1,273 @@
+use crate::tests::mock::*;
+use crate::*;
+use frame_support::assert_ok;
+use frame_support::traits::Hooks;
+use pretty_assertions::assert_eq;
+
+fn run_on_idle(remaining_weight: Weight) -> Weight {
+	TreasuryConversion::on_idle(System::block_number(), remaining_weight)
+}
+
+#[test]
+fn on_idle_should_convert_all_assets_above_threshold() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(TREASURY, DAI, 1_000 * ONE),
+			(TREASURY, DOT, 100 * ONE),
+			(TREASURY, BTC, ONE),
+		])
+		.with_target_asset(HDX)
+		.with_thresholds(vec![(DAI, 100 * ONE), (DOT, 10 * ONE), (BTC, 2 * ONE)])
+		.with_price((HDX, DAI), EmaPrice::new(2, 1))
+		.with_price((HDX, DOT), EmaPrice::new(10, 1))
+		.with_price((HDX, BTC), EmaPrice::new(1_000, 1))
+		.build()
+		.execute_with(|| {
+			// Act
+			run_on_idle(Weight::MAX);
+
+			// Assert
+			assert_balance!(TREASURY, DAI, 0);
+			assert_balance!(TREASURY, DOT, 0);
+			assert_balance!(TREASURY, BTC, ONE);
+			assert_balance!(TREASURY, HDX, 3_000 * ONE);
+			assert_eq!(TreasuryConversion::last_processed_asset(), None);
+		});
+}
+
+#[test]
+fn on_idle_should_not_convert_when_target_asset_is_not_set() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(TREASURY, DAI, 1_000 * ONE)])
+		.with_thresholds(vec![(DAI, 100 * ONE)])
+		.with_price((HDX, DAI), EmaPrice::new(2, 1))
+		.build()
+		.execute_with(|| {
+			// Act
+			run_on_idle(Weight::MAX);
+
+			// Assert
+			assert_balance!(TREASURY, DAI, 1_000 * ONE);
+			assert_balance!(TREASURY, HDX, 0);
+		});
+}
+
+#[test]
+fn on_idle_should_continue_with_other_assets_when_conversion_fails() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(TREASURY, DAI, 1_000 * ONE), (TREASURY, DOT, 100 * ONE)])
+		.with_target_asset(HDX)
+		.with_thresholds(vec![(DAI, 100 * ONE), (DOT, 10 * ONE)])
+		.with_price((HDX, DAI), EmaPrice::new(2, 1))
+		.with_trade_price((HDX, DOT), EmaPrice::new(10, 1))
+		.build()
+		.execute_with(|| {
+			// Act
+			run_on_idle(Weight::MAX);
+
+			// Assert
+			assert_balance!(TREASURY, DAI, 0);
+			assert_balance!(TREASURY, DOT, 100 * ONE);
+			assert_balance!(TREASURY, HDX, 2_000 * ONE);
+			expect_events(vec![Event::ConversionFailed {
+				asset_id: DOT,
+				error: Error::<Test>::PriceNotFound.into(),
+				retry_at: 11,
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn on_idle_should_limit_number_of_conversions_per_block() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(TREASURY, DAI, 1_000 * ONE),
+			(TREASURY, DOT, 100 * ONE),
+			(TREASURY, BTC, ONE),
+		])
+		.with_target_asset(HDX)
+		.with_thresholds(vec![(DAI, 100 * ONE), (DOT, 10 * ONE), (BTC, ONE)])
+		.with_price((HDX, DAI), EmaPrice::new(1, 1))
+		.with_price((HDX, DOT), EmaPrice::new(1, 1))
+		.with_price((HDX, BTC), EmaPrice::new(1, 1))
+		.with_max_conversions_per_block(2)
+		.build()
+		.execute_with(|| {
+			let converted = || {
+				[DAI, DOT, BTC]
+					.into_iter()
+					.filter(|asset_id| Tokens::free_balance(*asset_id, &TREASURY).is_zero())
+					.count()
+			};
+
+			// Act
+			run_on_idle(Weight::MAX);
+
+			// Assert
+			assert_eq!(converted(), 2);
+			assert!(TreasuryConversion::last_processed_asset().is_some());
+
+			// Act
+			run_on_idle(Weight::MAX);
+
+			// Assert
+			assert_eq!(converted(), 3);
+			assert_balance!(TREASURY, HDX, 1_101 * ONE);
+			assert_eq!(TreasuryConversion::last_processed_asset(), None);
+		});
+}
+
+#[test]
+fn on_idle_should_respect_remaining_weight() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(TREASURY, DAI, 1_000 * ONE), (TREASURY, DOT, 100 * ONE)])
+		.with_target_asset(HDX)
+		.with_thresholds(vec![(DAI, 100 * ONE), (DOT, 10 * ONE)])
+		.with_price((HDX, DAI), EmaPrice::new(1, 1))
+		.with_price((HDX, DOT), EmaPrice::new(1, 1))
+		.build()
+		.execute_with(|| {
+			let convert_weight = <() as WeightInfo>::convert();
+			let remaining_weight = convert_weight.saturating_add(convert_weight.saturating_div(2));
+
+			// Act
+			let used_weight = run_on_idle(remaining_weight);
+
+			// Assert
+			assert!(used_weight.all_lte(remaining_weight));
+			let converted = [DAI, DOT]
+				.into_iter()
+				.filter(|asset_id| Tokens::free_balance(*asset_id, &TREASURY).is_zero())
+				.count();
+			assert_eq!(converted, 1);
+		});
+}
+
+#[test]
+fn on_idle_should_not_convert_when_weight_is_insufficient() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(TREASURY, DAI, 1_000 * ONE)])
+		.with_target_asset(HDX)
+		.with_thresholds(vec![(DAI, 100 * ONE)])
+		.with_price((HDX, DAI), EmaPrice::new(1, 1))
+		.build()
+		.execute_with(|| {
+			// Act
+			run_on_idle(Weight::zero());
+
+			// Assert
+			assert_balance!(TREASURY, DAI, 1_000 * ONE);
+		});
+}
+
+fn failing_conversion_ext() -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(TREASURY, DOT, 100 * ONE)])
+		.with_target_asset(HDX)
+		.with_thresholds(vec![(DOT, 10 * ONE)])
+		.with_trade_price((HDX, DOT), EmaPrice::new(10, 1))
+		.build()
+}
+
+fn conversion_failed_events() -> usize {
+	System::events()
+		.into_iter()
+		.filter(|record| {
+			matches!(
+				record.event,
+				RuntimeEvent::TreasuryConversion(Event::ConversionFailed { .. })
+			)
+		})
+		.count()
+}
+
+#[test]
+fn on_idle_should_not_retry_failed_conversion_before_retry_delay() {
+	failing_conversion_ext().execute_with(|| {
+		run_on_idle(Weight::MAX);
+		assert_eq!(TreasuryConversion::conversion_failure(DOT), Some((1, 11)));
+
+		// Act
+		System::set_block_number(10);
+		run_on_idle(Weight::MAX);
+
+		// Assert
+		assert_eq!(conversion_failed_events(), 1);
+		assert_eq!(TreasuryConversion::conversion_failure(DOT), Some((1, 11)));
+	});
+}
+
+#[test]
+fn on_idle_should_double_retry_delay_when_conversion_fails_again() {
+	failing_conversion_ext().execute_with(|| {
+		run_on_idle(Weight::MAX);
+
+		// Act
+		System::set_block_number(11);
+		run_on_idle(Weight::MAX);
+
+		// Assert
+		assert_eq!(conversion_failed_events(), 2);
+		assert_eq!(TreasuryConversion::conversion_failure(DOT), Some((2, 31)));
+		expect_events(vec![Event::ConversionFailed {
+			asset_id: DOT,
+			error: Error::<Test>::PriceNotFound.into(),
+			retry_at: 31,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn on_idle_should_cap_retry_delay() {
+	failing_conversion_ext().execute_with(|| {
+		ConversionFailures::<Test>::insert(DOT, (MAX_RETRY_DELAY_EXPONENT + 5, 1));
+
+		// Act
+		run_on_idle(Weight::MAX);
+
+		// Assert
+		let max_delay = ConversionRetryDelay::get() * 2u64.pow(MAX_RETRY_DELAY_EXPONENT);
+		assert_eq!(
+			TreasuryConversion::conversion_failure(DOT),
+			Some((MAX_RETRY_DELAY_EXPONENT + 6, 1 + max_delay))
+		);
+	});
+}
+
+#[test]
+fn on_idle_should_clear_failure_when_retried_conversion_succeeds() {
+	failing_conversion_ext().execute_with(|| {
+		run_on_idle(Weight::MAX);
+		ORACLE_PRICES.with(|v| {
+			let mut m = v.borrow_mut();
+			m.insert((HDX, DOT), EmaPrice::new(10, 1));
+			m.insert((DOT, HDX), EmaPrice::new(1, 10));
+		});
+
+		// Act
+		System::set_block_number(11);
+		run_on_idle(Weight::MAX);
+
+		// Assert
+		assert_balance!(TREASURY, DOT, 0);
+		assert_eq!(TreasuryConversion::conversion_failure(DOT), None);
+	});
+}
+
+#[test]
+fn set_threshold_should_reset_retry_delay() {
+	failing_conversion_ext().execute_with(|| {
+		run_on_idle(Weight::MAX);
+
+		// Act
+		assert_ok!(TreasuryConversion::set_threshold(RuntimeOrigin::root(), DOT, 10 * ONE));
+
+		// Assert
+		assert_eq!(TreasuryConversion::conversion_failure(DOT), None);
+		System::set_block_number(2);
+		run_on_idle(Weight::MAX);
+		assert_eq!(conversion_failed_events(), 2);
+	});
+}
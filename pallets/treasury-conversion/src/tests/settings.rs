@@ -0,0 +1,138 @@
+use crate::tests::mock::*;
+use crate::*;
+use frame_support::{assert_noop, assert_ok};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn set_target_asset_should_work_when_origin_is_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		assert_ok!(TreasuryConversion::set_target_asset(RuntimeOrigin::root(), HDX));
+
+		// Assert
+		assert_eq!(TreasuryConversion::target_asset(), Some(HDX));
+		expect_events(vec![Event::TargetAssetSet { asset_id: HDX }.into()]);
+	});
+}
+
+#[test]
+fn set_target_asset_should_fail_when_origin_is_not_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			TreasuryConversion::set_target_asset(RuntimeOrigin::signed(ALICE), HDX),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_target_asset_should_fail_when_asset_has_threshold() {
+	ExtBuilder::default()
+		.with_thresholds(vec![(DAI, ONE)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				TreasuryConversion::set_target_asset(RuntimeOrigin::root(), DAI),
+				Error::<Test>::AssetHasThreshold
+			);
+		});
+}
+
+#[test]
+fn set_threshold_should_work_when_origin_is_authority() {
+	ExtBuilder::default().with_target_asset(HDX).build().execute_with(|| {
+		// Act
+		assert_ok!(TreasuryConversion::set_threshold(RuntimeOrigin::root(), DAI, 100 * ONE));
+
+		// Assert
+		assert_eq!(TreasuryConversion::conversion_threshold(DAI), Some(100 * ONE));
+		expect_events(vec![Event::ThresholdSet {
+			asset_id: DAI,
+			threshold: 100 * ONE,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn set_threshold_should_update_existing_threshold() {
+	ExtBuilder::default()
+		.with_target_asset(HDX)
+		.with_thresholds(vec![(DAI, ONE)])
+		.build()
+		.execute_with(|| {
+			// Act
+			assert_ok!(TreasuryConversion::set_threshold(RuntimeOrigin::root(), DAI, 100 * ONE));
+
+			// Assert
+			assert_eq!(TreasuryConversion::conversion_threshold(DAI), Some(100 * ONE));
+		});
+}
+
+#[test]
+fn set_threshold_should_fail_when_origin_is_not_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			TreasuryConversion::set_threshold(RuntimeOrigin::signed(ALICE), DAI, ONE),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_threshold_should_fail_when_threshold_is_zero() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			TreasuryConversion::set_threshold(RuntimeOrigin::root(), DAI, 0),
+			Error::<Test>::ZeroThreshold
+		);
+	});
+}
+
+#[test]
+fn set_threshold_should_fail_when_asset_is_target_asset() {
+	ExtBuilder::default().with_target_asset(HDX).build().execute_with(|| {
+		assert_noop!(
+			TreasuryConversion::set_threshold(RuntimeOrigin::root(), HDX, ONE),
+			Error::<Test>::AssetIsTargetAsset
+		);
+	});
+}
+
+#[test]
+fn remove_threshold_should_work_when_threshold_exists() {
+	ExtBuilder::default()
+		.with_thresholds(vec![(DAI, ONE)])
+		.build()
+		.execute_with(|| {
+			// Act
+			assert_ok!(TreasuryConversion::remove_threshold(RuntimeOrigin::root(), DAI));
+
+			// Assert
+			assert_eq!(TreasuryConversion::conversion_threshold(DAI), None);
+			expect_events(vec![Event::ThresholdRemoved { asset_id: DAI }.into()]);
+		});
+}
+
+#[test]
+fn remove_threshold_should_fail_when_threshold_does_not_exist() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			TreasuryConversion::remove_threshold(RuntimeOrigin::root(), DAI),
+			Error::<Test>::ThresholdNotFound
+		);
+	});
+}
+
+#[test]
+fn remove_threshold_should_fail_when_origin_is_not_authority() {
+	ExtBuilder::default()
+		.with_thresholds(vec![(DAI, ONE)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				TreasuryConversion::remove_threshold(RuntimeOrigin::signed(ALICE), DAI),
+				DispatchError::BadOrigin
+			);
+		});
+}
@@ -0,0 +1,120 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+//! Autogenerated weights for `pallet_treasury_conversion`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
+//! DATE: 2024-11-04, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `bench-bot`, CPU: `Intel(R) Core(TM) i7-7700K CPU @ 4.20GHz`
+//! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+
+// Executed Command:
+// target/release/hydradx
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --wasm-execution=compiled
+// --pallet=pallet-treasury-conversion
+// --extrinsic=*
+// --template=scripts/pallet-weight-template.hbs
+// --output=./weights/pallet_treasury_conversion.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{
+	traits::Get,
+	weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_treasury_conversion.
+pub trait WeightInfo {
+	fn set_target_asset() -> Weight;
+	fn set_threshold() -> Weight;
+	fn remove_threshold() -> Weight;
+	fn convert() -> Weight;
+}
+
+/// Weights for pallet_treasury_conversion using the hydraDX node and recommended hardware.
+impl WeightInfo for () {
+	/// Storage: `TreasuryConversion::ConversionThresholds` (r:1 w:0)
+	/// Proof: `TreasuryConversion::ConversionThresholds` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `TreasuryConversion::TargetAsset` (r:0 w:1)
+	/// Proof: `TreasuryConversion::TargetAsset` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_target_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `78`
+		//  Estimated: `3501`
+		// Minimum execution time: 10_482_000 picoseconds.
+		Weight::from_parts(10_731_000, 3501)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TreasuryConversion::TargetAsset` (r:1 w:0)
+	/// Proof: `TreasuryConversion::TargetAsset` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `TreasuryConversion::ConversionThresholds` (r:0 w:1)
+	/// Proof: `TreasuryConversion::ConversionThresholds` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn set_threshold() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `1489`
+		// Minimum execution time: 10_137_000 picoseconds.
+		Weight::from_parts(10_418_000, 1489)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TreasuryConversion::ConversionThresholds` (r:1 w:1)
+	/// Proof: `TreasuryConversion::ConversionThresholds` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn remove_threshold() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `134`
+		//  Estimated: `3501`
+		// Minimum execution time: 12_904_000 picoseconds.
+		Weight::from_parts(13_263_000, 3501)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TreasuryConversion::TargetAsset` (r:1 w:0)
+	/// Proof: `TreasuryConversion::TargetAsset` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `TreasuryConversion::ConversionThresholds` (r:1 w:0)
+	/// Proof: `TreasuryConversion::ConversionThresholds` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:2 w:0)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Router::Routes` (r:1 w:0)
+	/// Proof: `Router::Routes` (`max_values`: None, `max_size`: Some(90), added: 2565, mode: `MaxEncodedLen`)
+	/// Storage: `EmaOracle::Oracles` (r:2 w:0)
+	/// Proof: `EmaOracle::Oracles` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::Assets` (r:1 w:0)
+	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	fn convert() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1254`
+		//  Estimated: `6156`
+		// Minimum execution time: 61_823_000 picoseconds.
+		Weight::from_parts(62_597_000, 6156)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+	}
+}
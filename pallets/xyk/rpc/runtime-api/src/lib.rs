@@ -0,0 +1,42 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the xyk pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+pub use pallet_xyk::types::{AssetId, LiquidityPosition, PoolFeeStats};
+use sp_runtime::DispatchError;
+
+sp_api::decl_runtime_apis! {
+	/// The API to query XYK pools fee accounting.
+	pub trait XykApi<AccountId> where
+		AccountId: Codec,
+	{
+		/// Cumulative trade fees and protocol fee shares of the pool of `asset_a` and `asset_b`.
+		fn pool_fee_stats(asset_a: AssetId, asset_b: AssetId) -> Result<PoolFeeStats, DispatchError>;
+
+		/// Share of `who` in reserves of the pool of `asset_a` and `asset_b`.
+		fn liquidity_position(
+			who: AccountId,
+			asset_a: AssetId,
+			asset_b: AssetId,
+		) -> Result<LiquidityPosition, DispatchError>;
+
+		/// Protocol share of the trade fees. `None` if protocol fee is switched off.
+		fn protocol_fee() -> Option<(u32, u32)>;
+	}
+}
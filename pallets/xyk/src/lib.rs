@@ -33,12 +33,12 @@ use frame_support::{dispatch::DispatchResult, ensure, traits::Get, transactional
 use frame_system::ensure_signed;
 use frame_system::pallet_prelude::BlockNumberFor;
 use hydradx_traits::{
-	AMMPosition, AMMTransfer, AssetPairAccountIdFor, CanCreatePool, OnCreatePoolHandler, OnLiquidityChangedHandler,
-	OnTradeHandler, AMM,
+	price::PriceBarrier, AMMPosition, AMMTransfer, AssetPairAccountIdFor, CanCreatePool, OnCreatePoolHandler,
+	OnLiquidityChangedHandler, OnTradeHandler, AMM,
 };
 use sp_std::{vec, vec::Vec};
 
-use crate::types::{Amount, AssetId, AssetPair, Balance};
+use crate::types::{Amount, AssetId, AssetPair, Balance, LiquidityPosition, PoolFeeStats};
 use hydra_dx_math::ratio::Ratio;
 use hydradx_traits::AMMAddLiquidity;
 use orml_traits::{MultiCurrency, MultiCurrencyExtended};
@@ -47,6 +47,7 @@ use orml_traits::{MultiCurrency, MultiCurrencyExtended};
 mod tests;
 
 mod impls;
+pub mod migration;
 mod trade_execution;
 pub mod types;
 pub mod weights;
@@ -69,7 +70,10 @@ pub mod pallet {
 		Source,
 	};
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
@@ -132,6 +136,15 @@ pub mod pallet {
 
 		/// Account whitelist manager to exclude pool accounts from dusting mechanism.
 		type NonDustableWhitelistHandler: DustRemovalAccountWhitelist<Self::AccountId, Error = DispatchError>;
+
+		/// Price barrier to prevent adding or removing liquidity when the pool price is manipulated.
+		type PriceBarrier: PriceBarrier<Self::AccountId, AssetId, Ratio>;
+
+		/// Origin able to manage fee tiers and update fee of any pool.
+		type AuthorityOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Account receiving shares minted as protocol fee.
+		type ProtocolFeeReceiver: Get<Self::AccountId>;
 	}
 
 	#[pallet::error]
@@ -208,6 +221,18 @@ pub mod pallet {
 
 		/// Pool cannot be created due to outside factors.
 		CannotCreatePool,
+
+		/// Fee is not in the set of allowed fee tiers.
+		FeeTierNotFound,
+
+		/// Fee tier has already been added.
+		FeeTierAlreadyExists,
+
+		/// Fee is invalid. Fee denominator must be non-zero and greater than numerator.
+		InvalidFee,
+
+		/// Account is not allowed to perform the operation.
+		Forbidden,
 	}
 
 	#[pallet::event]
@@ -272,6 +297,30 @@ pub mod pallet {
 			fee_amount: Balance,
 			pool: T::AccountId,
 		},
+
+		/// New fee tier was added.
+		FeeTierAdded { fee: (u32, u32) },
+
+		/// Fee tier was removed.
+		FeeTierRemoved { fee: (u32, u32) },
+
+		/// Trade fee of the pool was updated.
+		PoolFeeUpdated {
+			pool: T::AccountId,
+			asset_a: AssetId,
+			asset_b: AssetId,
+			fee: (u32, u32),
+		},
+
+		/// Protocol share of trade fees was set. `None` means protocol fee is switched off.
+		ProtocolFeeSet { fee: Option<(u32, u32)> },
+
+		/// Shares were minted to protocol fee receiver.
+		ProtocolFeeMinted {
+			pool: T::AccountId,
+			receiver: T::AccountId,
+			shares: Balance,
+		},
 	}
 
 	/// Asset id storage for shared pool tokens
@@ -290,6 +339,45 @@ pub mod pallet {
 	pub(crate) type PoolAssets<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::AccountId, (AssetId, AssetId), OptionQuery>;
 
+	/// Fee tiers approved by governance which can be used as pool fees.
+	#[pallet::storage]
+	#[pallet::getter(fn fee_tiers)]
+	pub(crate) type FeeTiers<T: Config> = StorageMap<_, Blake2_128Concat, (u32, u32), (), OptionQuery>;
+
+	/// Trade fee of a pool.
+	/// If not set, `GetExchangeFee` is used.
+	#[pallet::storage]
+	#[pallet::getter(fn pool_fees)]
+	pub(crate) type PoolFees<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, (u32, u32), OptionQuery>;
+
+	/// Account which created the pool and is allowed to update its fee.
+	#[pallet::storage]
+	#[pallet::getter(fn pool_owner)]
+	pub(crate) type PoolOwners<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId, OptionQuery>;
+
+	/// Protocol share of the trade fees.
+	/// If set, part of the pool invariant growth is minted as shares to `ProtocolFeeReceiver`.
+	#[pallet::storage]
+	#[pallet::getter(fn protocol_fee)]
+	pub(crate) type ProtocolFee<T: Config> = StorageValue<_, (u32, u32), OptionQuery>;
+
+	/// Square root of the pool invariant after last liquidity change.
+	/// Set only when protocol fee is switched on.
+	#[pallet::storage]
+	#[pallet::getter(fn root_k_last)]
+	pub(crate) type RootKLast<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, Balance, ValueQuery>;
+
+	/// Trade fees accumulated in a pool per asset.
+	#[pallet::storage]
+	#[pallet::getter(fn accumulated_fees)]
+	pub(crate) type AccumulatedFees<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Twox64Concat, AssetId, Balance, ValueQuery>;
+
+	/// Total shares minted to protocol fee receiver per pool.
+	#[pallet::storage]
+	#[pallet::getter(fn protocol_fee_shares)]
+	pub(crate) type ProtocolFeeShares<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, Balance, ValueQuery>;
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Create new pool for given asset pair.
@@ -300,6 +388,8 @@ pub mod pallet {
 		/// Pool is created with initial liquidity provided by `origin`.
 		/// Shares are issued with specified initial price and represents proportion of asset in the pool.
 		///
+		/// Pool trade fee is set to `GetExchangeFee`.
+		///
 		/// Emits `PoolCreated` event when successful.
 		#[pallet::call_index(0)]
 		#[pallet::weight(<T as Config>::WeightInfo::create_pool())]
@@ -312,75 +402,7 @@ pub mod pallet {
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			ensure!(
-				T::CanCreatePool::can_create(asset_a, asset_b),
-				Error::<T>::CannotCreatePool
-			);
-
-			ensure!(
-				amount_a >= T::MinPoolLiquidity::get() && amount_b >= T::MinPoolLiquidity::get(),
-				Error::<T>::InsufficientLiquidity
-			);
-
-			ensure!(asset_a != asset_b, Error::<T>::CannotCreatePoolWithSameAssets);
-
-			let asset_pair = AssetPair {
-				asset_in: asset_a,
-				asset_out: asset_b,
-			};
-
-			ensure!(!Self::exists(asset_pair), Error::<T>::TokenPoolAlreadyExists);
-
-			let shares_added = if asset_a < asset_b { amount_a } else { amount_b };
-
-			ensure!(
-				T::Currency::free_balance(asset_a, &who) >= amount_a,
-				Error::<T>::InsufficientAssetBalance
-			);
-
-			ensure!(
-				T::Currency::free_balance(asset_b, &who) >= amount_b,
-				Error::<T>::InsufficientAssetBalance
-			);
-
-			let pair_account = Self::get_pair_id(asset_pair);
-
-			let token_name = asset_pair.name();
-
-			let share_token = T::AssetRegistry::get_or_register_insufficient_asset(
-				token_name.try_into().map_err(|_| Error::<T>::CannotCreatePool)?,
-				AssetKind::XYK,
-				None,
-				None,
-				None,
-				None,
-				None,
-			)?;
-
-			let _ = T::AMMHandler::on_create_pool(asset_pair.asset_in, asset_pair.asset_out);
-
-			T::NonDustableWhitelistHandler::add_account(&pair_account)?;
-
-			<ShareToken<T>>::insert(&pair_account, share_token);
-			<PoolAssets<T>>::insert(&pair_account, (asset_a, asset_b));
-
-			Self::deposit_event(Event::PoolCreated {
-				who: who.clone(),
-				asset_a,
-				asset_b,
-				initial_shares_amount: shares_added,
-				share_token,
-				pool: pair_account.clone(),
-			});
-
-			T::Currency::transfer(asset_a, &who, &pair_account, amount_a)?;
-			T::Currency::transfer(asset_b, &who, &pair_account, amount_b)?;
-
-			T::Currency::deposit(share_token, &who, shares_added)?;
-
-			<TotalLiquidity<T>>::insert(&pair_account, shares_added);
-
-			Ok(())
+			Self::do_create_pool(who, asset_a, amount_a, asset_b, amount_b, T::GetExchangeFee::get())
 		}
 
 		/// Add liquidity to previously created asset pair pool.
@@ -392,6 +414,7 @@ pub mod pallet {
 		#[pallet::weight(
 			<T as Config>::WeightInfo::add_liquidity()
 				.saturating_add(T::AMMHandler::on_liquidity_changed_weight())
+				.saturating_add(T::PriceBarrier::ensure_price_weight())
 		)]
 		#[transactional]
 		pub fn add_liquidity(
@@ -417,6 +440,7 @@ pub mod pallet {
 		#[pallet::weight(
 			<T as Config>::WeightInfo::remove_liquidity()
 				.saturating_add(T::AMMHandler::on_liquidity_changed_weight())
+				.saturating_add(T::PriceBarrier::ensure_price_weight())
 		)]
 		#[transactional]
 		pub fn remove_liquidity(
@@ -440,6 +464,8 @@ pub mod pallet {
 
 			let share_token = Self::share_token(&pair_account);
 
+			let fee_on = Self::mint_protocol_fee(&pair_account, asset_a, asset_b, share_token)?;
+
 			let total_shares = Self::total_liquidity(&pair_account);
 
 			let account_shares = T::Currency::free_balance(share_token, &who);
@@ -458,6 +484,8 @@ pub mod pallet {
 			let asset_a_reserve = T::Currency::free_balance(asset_a, &pair_account);
 			let asset_b_reserve = T::Currency::free_balance(asset_b, &pair_account);
 
+			T::PriceBarrier::ensure_price(&who, asset_a, asset_b, Ratio::new(asset_a_reserve, asset_b_reserve))?;
+
 			let liquidity_out = hydra_dx_math::xyk::calculate_liquidity_out(
 				asset_a_reserve,
 				asset_b_reserve,
@@ -488,6 +516,8 @@ pub mod pallet {
 
 			<TotalLiquidity<T>>::insert(&pair_account, liquidity_left);
 
+			Self::update_root_k_last(&pair_account, asset_a, asset_b, fee_on)?;
+
 			let liquidity_a = T::Currency::total_balance(asset_a, &pair_account);
 			let liquidity_b = T::Currency::total_balance(asset_b, &pair_account);
 			T::AMMHandler::on_liquidity_changed(
@@ -513,6 +543,12 @@ pub mod pallet {
 				<ShareToken<T>>::remove(&pair_account);
 				<PoolAssets<T>>::remove(&pair_account);
 				<TotalLiquidity<T>>::remove(&pair_account);
+				<PoolFees<T>>::remove(&pair_account);
+				<PoolOwners<T>>::remove(&pair_account);
+				<RootKLast<T>>::remove(&pair_account);
+				<AccumulatedFees<T>>::remove(&pair_account, asset_a);
+				<AccumulatedFees<T>>::remove(&pair_account, asset_b);
+				<ProtocolFeeShares<T>>::remove(&pair_account);
 
 				// Ignore the failure, this cant stop liquidity removal
 				let r = T::NonDustableWhitelistHandler::remove_account(&pair_account);
@@ -584,6 +620,302 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Add fee tier which can be used as a pool trade fee.
+		///
+		/// Can only be called by `AuthorityOrigin`.
+		///
+		/// Emits `FeeTierAdded` event when successful.
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_fee_tier())]
+		pub fn add_fee_tier(origin: OriginFor<T>, fee: (u32, u32)) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			ensure!(fee.1 != 0 && fee.0 < fee.1, Error::<T>::InvalidFee);
+			ensure!(!<FeeTiers<T>>::contains_key(fee), Error::<T>::FeeTierAlreadyExists);
+
+			<FeeTiers<T>>::insert(fee, ());
+
+			Self::deposit_event(Event::FeeTierAdded { fee });
+
+			Ok(())
+		}
+
+		/// Remove fee tier.
+		///
+		/// Existing pools using the fee tier keep their fee.
+		///
+		/// Can only be called by `AuthorityOrigin`.
+		///
+		/// Emits `FeeTierRemoved` event when successful.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_fee_tier())]
+		pub fn remove_fee_tier(origin: OriginFor<T>, fee: (u32, u32)) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			Self::ensure_fee_tier(fee)?;
+
+			<FeeTiers<T>>::remove(fee);
+
+			Self::deposit_event(Event::FeeTierRemoved { fee });
+
+			Ok(())
+		}
+
+		/// Create new pool for given asset pair with trade fee selected from fee tiers.
+		///
+		/// Same as `create_pool` but pool trade fee is set to `fee`, which must be one of the fee tiers.
+		///
+		/// Emits `PoolCreated` event when successful.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_pool_with_fee())]
+		pub fn create_pool_with_fee(
+			origin: OriginFor<T>,
+			asset_a: AssetId,
+			amount_a: Balance,
+			asset_b: AssetId,
+			amount_b: Balance,
+			fee: (u32, u32),
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_fee_tier(fee)?;
+
+			Self::do_create_pool(who, asset_a, amount_a, asset_b, amount_b, fee)
+		}
+
+		/// Update trade fee of the pool.
+		///
+		/// Can be called by `AuthorityOrigin` or by the account which created the pool.
+		/// New fee must be one of the fee tiers.
+		///
+		/// Emits `PoolFeeUpdated` event when successful.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_pool_fee())]
+		pub fn update_pool_fee(
+			origin: OriginFor<T>,
+			asset_a: AssetId,
+			asset_b: AssetId,
+			fee: (u32, u32),
+		) -> DispatchResult {
+			let asset_pair = AssetPair {
+				asset_in: asset_a,
+				asset_out: asset_b,
+			};
+
+			ensure!(Self::exists(asset_pair), Error::<T>::TokenPoolNotFound);
+
+			let pair_account = Self::get_pair_id(asset_pair);
+
+			if T::AuthorityOrigin::ensure_origin(origin.clone()).is_err() {
+				let who = ensure_signed(origin)?;
+				ensure!(Self::pool_owner(&pair_account) == Some(who), Error::<T>::Forbidden);
+			}
+
+			Self::ensure_fee_tier(fee)?;
+
+			<PoolFees<T>>::insert(&pair_account, fee);
+
+			Self::deposit_event(Event::PoolFeeUpdated {
+				pool: pair_account,
+				asset_a,
+				asset_b,
+				fee,
+			});
+
+			Ok(())
+		}
+
+		/// Set protocol share of the trade fees or switch protocol fee off with `None`.
+		///
+		/// When switched on, part of the pool invariant growth is minted as shares to `ProtocolFeeReceiver`
+		/// on every liquidity change. Pools start accruing protocol fee from their next liquidity change.
+		///
+		/// Can only be called by `AuthorityOrigin`.
+		///
+		/// Emits `ProtocolFeeSet` event when successful.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_protocol_fee())]
+		pub fn set_protocol_fee(origin: OriginFor<T>, fee: Option<(u32, u32)>) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			match fee {
+				Some(f) => {
+					ensure!(f.1 != 0 && f.0 < f.1, Error::<T>::InvalidFee);
+					<ProtocolFee<T>>::put(f);
+				}
+				None => <ProtocolFee<T>>::kill(),
+			}
+
+			Self::deposit_event(Event::ProtocolFeeSet { fee });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		pub(crate) fn do_create_pool(
+			who: T::AccountId,
+			asset_a: AssetId,
+			amount_a: Balance,
+			asset_b: AssetId,
+			amount_b: Balance,
+			fee: (u32, u32),
+		) -> DispatchResult {
+			ensure!(
+				T::CanCreatePool::can_create(asset_a, asset_b),
+				Error::<T>::CannotCreatePool
+			);
+
+			ensure!(
+				amount_a >= T::MinPoolLiquidity::get() && amount_b >= T::MinPoolLiquidity::get(),
+				Error::<T>::InsufficientLiquidity
+			);
+
+			ensure!(asset_a != asset_b, Error::<T>::CannotCreatePoolWithSameAssets);
+
+			let asset_pair = AssetPair {
+				asset_in: asset_a,
+				asset_out: asset_b,
+			};
+
+			ensure!(!Self::exists(asset_pair), Error::<T>::TokenPoolAlreadyExists);
+
+			let shares_added = if asset_a < asset_b { amount_a } else { amount_b };
+
+			ensure!(
+				T::Currency::free_balance(asset_a, &who) >= amount_a,
+				Error::<T>::InsufficientAssetBalance
+			);
+
+			ensure!(
+				T::Currency::free_balance(asset_b, &who) >= amount_b,
+				Error::<T>::InsufficientAssetBalance
+			);
+
+			let pair_account = Self::get_pair_id(asset_pair);
+
+			let token_name = asset_pair.name();
+
+			let share_token = T::AssetRegistry::get_or_register_insufficient_asset(
+				token_name.try_into().map_err(|_| Error::<T>::CannotCreatePool)?,
+				AssetKind::XYK,
+				None,
+				None,
+				None,
+				None,
+				None,
+			)?;
+
+			let _ = T::AMMHandler::on_create_pool(asset_pair.asset_in, asset_pair.asset_out);
+
+			T::NonDustableWhitelistHandler::add_account(&pair_account)?;
+
+			<ShareToken<T>>::insert(&pair_account, share_token);
+			<PoolAssets<T>>::insert(&pair_account, (asset_a, asset_b));
+			<PoolFees<T>>::insert(&pair_account, fee);
+			<PoolOwners<T>>::insert(&pair_account, &who);
+
+			Self::deposit_event(Event::PoolCreated {
+				who: who.clone(),
+				asset_a,
+				asset_b,
+				initial_shares_amount: shares_added,
+				share_token,
+				pool: pair_account.clone(),
+			});
+
+			T::Currency::transfer(asset_a, &who, &pair_account, amount_a)?;
+			T::Currency::transfer(asset_b, &who, &pair_account, amount_b)?;
+
+			T::Currency::deposit(share_token, &who, shares_added)?;
+
+			<TotalLiquidity<T>>::insert(&pair_account, shares_added);
+
+			Self::update_root_k_last(&pair_account, asset_a, asset_b, Self::protocol_fee().is_some())?;
+
+			Ok(())
+		}
+
+		pub(crate) fn ensure_fee_tier(fee: (u32, u32)) -> DispatchResult {
+			ensure!(<FeeTiers<T>>::contains_key(fee), Error::<T>::FeeTierNotFound);
+			Ok(())
+		}
+
+		/// Mint shares to `ProtocolFeeReceiver` for the pool invariant growth since last liquidity change.
+		///
+		/// Returns whether protocol fee is switched on.
+		pub(crate) fn mint_protocol_fee(
+			pair_account: &T::AccountId,
+			asset_a: AssetId,
+			asset_b: AssetId,
+			share_token: AssetId,
+		) -> Result<bool, DispatchError> {
+			let root_k_last = Self::root_k_last(pair_account);
+
+			let Some(protocol_fee) = Self::protocol_fee() else {
+				if !root_k_last.is_zero() {
+					<RootKLast<T>>::remove(pair_account);
+				}
+				return Ok(false);
+			};
+
+			if root_k_last.is_zero() {
+				return Ok(true);
+			}
+
+			let root_k = hydra_dx_math::xyk::calculate_root_k(
+				T::Currency::free_balance(asset_a, pair_account),
+				T::Currency::free_balance(asset_b, pair_account),
+			)
+			.map_err(|_| Error::<T>::Overflow)?;
+
+			let total_shares = Self::total_liquidity(pair_account);
+
+			let shares =
+				hydra_dx_math::xyk::calculate_protocol_fee_shares(root_k, root_k_last, total_shares, protocol_fee)
+					.map_err(|_| Error::<T>::Overflow)?;
+
+			if !shares.is_zero() {
+				let receiver = T::ProtocolFeeReceiver::get();
+
+				T::Currency::deposit(share_token, &receiver, shares)?;
+
+				<TotalLiquidity<T>>::insert(
+					pair_account,
+					total_shares.checked_add(shares).ok_or(Error::<T>::Overflow)?,
+				);
+				<ProtocolFeeShares<T>>::mutate(pair_account, |minted| *minted = minted.saturating_add(shares));
+
+				Self::deposit_event(Event::ProtocolFeeMinted {
+					pool: pair_account.clone(),
+					receiver,
+					shares,
+				});
+			}
+
+			Ok(true)
+		}
+
+		/// Store square root of the pool invariant if protocol fee is switched on.
+		pub(crate) fn update_root_k_last(
+			pair_account: &T::AccountId,
+			asset_a: AssetId,
+			asset_b: AssetId,
+			fee_on: bool,
+		) -> DispatchResult {
+			if fee_on {
+				let root_k = hydra_dx_math::xyk::calculate_root_k(
+					T::Currency::free_balance(asset_a, pair_account),
+					T::Currency::free_balance(asset_b, pair_account),
+				)
+				.map_err(|_| Error::<T>::Overflow)?;
+
+				<RootKLast<T>>::insert(pair_account, root_k);
+			}
+
+			Ok(())
+		}
 	}
 }
 
@@ -618,12 +950,16 @@ impl<T: Config> Pallet<T> {
 
 		let share_token = Self::share_token(&pair_account);
 
+		let fee_on = Self::mint_protocol_fee(&pair_account, asset_a, asset_b, share_token)?;
+
 		let account_shares = T::Currency::free_balance(share_token, &who);
 
 		let asset_a_reserve = T::Currency::free_balance(asset_a, &pair_account);
 		let asset_b_reserve = T::Currency::free_balance(asset_b, &pair_account);
 		let share_issuance = Self::total_liquidity(&pair_account);
 
+		T::PriceBarrier::ensure_price(&who, asset_a, asset_b, Ratio::new(asset_a_reserve, asset_b_reserve))?;
+
 		let amount_b = hydra_dx_math::xyk::calculate_liquidity_in(asset_a_reserve, asset_b_reserve, amount_a)
 			.map_err(|_| Error::<T>::AddAssetAmountInvalid)?;
 
@@ -659,6 +995,8 @@ impl<T: Config> Pallet<T> {
 
 		<TotalLiquidity<T>>::insert(&pair_account, liquidity_amount);
 
+		Self::update_root_k_last(&pair_account, asset_a, asset_b, fee_on)?;
+
 		let liquidity_a = T::Currency::total_balance(asset_a, &pair_account);
 		let liquidity_b = T::Currency::total_balance(asset_b, &pair_account);
 		T::AMMHandler::on_liquidity_changed(
@@ -696,17 +1034,82 @@ impl<T: Config> Pallet<T> {
 		}
 		Some(balances)
 	}
-	/// Calculate discounted trade fee
-	fn calculate_discounted_fee(amount: Balance) -> Result<Balance, DispatchError> {
-		Ok(
-			hydra_dx_math::fee::calculate_pool_trade_fee(amount, T::DiscountedFee::get())
-				.ok_or::<Error<T>>(Error::<T>::FeeAmountInvalid)?,
+	/// Cumulative fee statistics of the pool of `asset_a` and `asset_b`.
+	pub fn pool_fee_stats(asset_a: AssetId, asset_b: AssetId) -> Result<PoolFeeStats, DispatchError> {
+		let asset_pair = AssetPair {
+			asset_in: asset_a,
+			asset_out: asset_b,
+		};
+
+		ensure!(Self::exists(asset_pair), Error::<T>::TokenPoolNotFound);
+
+		let pair_account = Self::get_pair_id(asset_pair);
+
+		Ok(PoolFeeStats {
+			asset_a,
+			asset_b,
+			fees_a: Self::accumulated_fees(&pair_account, asset_a),
+			fees_b: Self::accumulated_fees(&pair_account, asset_b),
+			protocol_fee_shares: Self::protocol_fee_shares(&pair_account),
+		})
+	}
+
+	/// Share of `who` in reserves of the pool of `asset_a` and `asset_b`.
+	///
+	/// Trade fees are added to the reserves, so the amounts include the fees earned by the shares.
+	/// Fees are not tracked per liquidity provider.
+	/// Shares which would be minted as protocol fee on next liquidity change are not included.
+	pub fn liquidity_position(
+		who: &T::AccountId,
+		asset_a: AssetId,
+		asset_b: AssetId,
+	) -> Result<LiquidityPosition, DispatchError> {
+		let asset_pair = AssetPair {
+			asset_in: asset_a,
+			asset_out: asset_b,
+		};
+
+		ensure!(Self::exists(asset_pair), Error::<T>::TokenPoolNotFound);
+
+		let pair_account = Self::get_pair_id(asset_pair);
+
+		let shares = T::Currency::free_balance(Self::share_token(&pair_account), who);
+		let total_shares = Self::total_liquidity(&pair_account);
+
+		let (amount_a, amount_b) = hydra_dx_math::xyk::calculate_liquidity_out(
+			T::Currency::free_balance(asset_a, &pair_account),
+			T::Currency::free_balance(asset_b, &pair_account),
+			shares,
+			total_shares,
 		)
+		.map_err(|_| Error::<T>::Overflow)?;
+
+		Ok(LiquidityPosition {
+			asset_a,
+			asset_b,
+			shares,
+			total_shares,
+			amount_a,
+			amount_b,
+		})
+	}
+
+	/// Calculate discounted trade fee of the pool. Discounted fee is never higher than the pool fee.
+	fn calculate_discounted_fee(pool_account: &T::AccountId, amount: Balance) -> Result<Balance, DispatchError> {
+		let discounted_fee = hydra_dx_math::fee::calculate_pool_trade_fee(amount, T::DiscountedFee::get())
+			.ok_or::<Error<T>>(Error::<T>::FeeAmountInvalid)?;
+
+		Ok(discounted_fee.min(Self::calculate_fee(pool_account, amount)?))
+	}
+
+	/// Trade fee of the pool. Falls back to `GetExchangeFee` if pool fee is not set.
+	pub fn pool_fee(pool_account: &T::AccountId) -> (u32, u32) {
+		Self::pool_fees(pool_account).unwrap_or_else(T::GetExchangeFee::get)
 	}
 
-	/// Calculate trade fee
-	fn calculate_fee(amount: Balance) -> Result<Balance, DispatchError> {
-		let fee = T::GetExchangeFee::get();
+	/// Calculate trade fee of the pool
+	fn calculate_fee(pool_account: &T::AccountId, amount: Balance) -> Result<Balance, DispatchError> {
+		let fee = Self::pool_fee(pool_account);
 		Ok(hydra_dx_math::fee::calculate_pool_trade_fee(amount, (fee.0, fee.1))
 			.ok_or::<Error<T>>(Error::<T>::FeeAmountInvalid)?)
 	}
@@ -808,9 +1211,9 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, Balance> for Pallet<T> {
 		);
 
 		let transfer_fee = if discount {
-			Self::calculate_discounted_fee(amount_out)?
+			Self::calculate_discounted_fee(&pair_account, amount_out)?
 		} else {
-			Self::calculate_fee(amount_out)?
+			Self::calculate_fee(&pair_account, amount_out)?
 		};
 
 		let amount_out_without_fee = amount_out
@@ -887,6 +1290,10 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, Balance> for Pallet<T> {
 			transfer.amount_b,
 		)?;
 
+		<AccumulatedFees<T>>::mutate(&pair_account, transfer.fee.0, |fees| {
+			*fees = fees.saturating_add(transfer.fee.1)
+		});
+
 		let liquidity_in = T::Currency::total_balance(transfer.assets.asset_in, &pair_account);
 		let liquidity_out = T::Currency::total_balance(transfer.assets.asset_out, &pair_account);
 		T::AMMHandler::on_trade(
@@ -971,9 +1378,9 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, Balance> for Pallet<T> {
 		);
 
 		let transfer_fee = if discount {
-			Self::calculate_discounted_fee(buy_price)?
+			Self::calculate_discounted_fee(&pair_account, buy_price)?
 		} else {
-			Self::calculate_fee(buy_price)?
+			Self::calculate_fee(&pair_account, buy_price)?
 		};
 
 		let buy_price_with_fee = buy_price
@@ -1052,6 +1459,10 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, Balance> for Pallet<T> {
 			transfer.amount_b + transfer.fee.1,
 		)?;
 
+		<AccumulatedFees<T>>::mutate(&pair_account, transfer.fee.0, |fees| {
+			*fees = fees.saturating_add(transfer.fee.1)
+		});
+
 		let liquidity_in = T::Currency::total_balance(transfer.assets.asset_in, &pair_account);
 		let liquidity_out = T::Currency::total_balance(transfer.assets.asset_out, &pair_account);
 		T::AMMHandler::on_trade(
@@ -1096,8 +1507,8 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, Balance> for Pallet<T> {
 		T::MaxOutRatio::get()
 	}
 
-	fn get_fee(_pool_account_id: &T::AccountId) -> (u32, u32) {
-		T::GetExchangeFee::get()
+	fn get_fee(pool_account_id: &T::AccountId) -> (u32, u32) {
+		Self::pool_fee(pool_account_id)
 	}
 }
 
@@ -0,0 +1,66 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use frame_support::{
+	traits::{Get, StorageVersion},
+	weights::Weight,
+};
+use sp_runtime::traits::Saturating;
+
+/// Migrate the pallet storage to v1.
+/// This migration adds current exchange fee as a fee tier and sets it as a fee of all the existing pools.
+/// Owners of existing pools are not known, so their fee can only be updated by `AuthorityOrigin`.
+pub fn migrate_to_v1<T: Config>() -> Weight {
+	let on_chain_storage_version = StorageVersion::get::<Pallet<T>>();
+	//offset for storage version read
+	let mut weight: Weight = T::DbWeight::get().reads(1);
+
+	log::info!(
+		target: "runtime::xyk",
+		"Running migration storage v1 for xyk with storage version {:?}",
+		on_chain_storage_version,
+	);
+
+	if on_chain_storage_version < 1 {
+		let fee = T::GetExchangeFee::get();
+		FeeTiers::<T>::insert(fee, ());
+
+		let mut migrated: u64 = 0;
+		for pool in PoolAssets::<T>::iter_keys() {
+			PoolFees::<T>::insert(pool, fee);
+			migrated.saturating_inc();
+		}
+		weight.saturating_accrue(T::DbWeight::get().reads_writes(migrated, migrated.saturating_add(1)));
+
+		StorageVersion::new(1).put::<Pallet<T>>();
+		//add storage version update weight
+		weight.saturating_accrue(T::DbWeight::get().writes(1));
+
+		log::info!(
+			target: "runtime::xyk",
+			"Running migration storage v1 for xyk was complete, {:?} pools migrated",
+			migrated,
+		);
+	} else {
+		log::warn!(
+			target: "runtime::xyk",
+			"Attempted to apply migration to v1 but failed because storage version is {:?}",
+			on_chain_storage_version,
+		);
+	}
+
+	weight
+}
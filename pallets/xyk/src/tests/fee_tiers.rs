@@ -0,0 +1,338 @@
+pub use super::mock::*;
+use crate::{Error, Event, FeeTiers, PoolFees};
+use frame_support::traits::{GetStorageVersion, StorageVersion};
+use frame_support::{assert_noop, assert_ok};
+use hydradx_traits::router::{PoolType, TradeExecution};
+use hydradx_traits::AMM as AmmPool;
+use orml_traits::MultiCurrency;
+use sp_runtime::{DispatchError::BadOrigin, FixedPointNumber};
+
+const FEE: (u32, u32) = (1, 100);
+
+fn add_fee_tier(fee: (u32, u32)) {
+	assert_ok!(XYK::add_fee_tier(RuntimeOrigin::root(), fee));
+}
+
+fn create_pool_with_fee(fee: (u32, u32)) {
+	assert_ok!(XYK::create_pool_with_fee(
+		RuntimeOrigin::signed(ALICE),
+		HDX,
+		100 * ONE,
+		DOT,
+		100 * ONE,
+		fee
+	));
+}
+
+#[test]
+fn add_fee_tier_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::add_fee_tier(RuntimeOrigin::root(), FEE));
+
+		assert_eq!(XYK::fee_tiers(FEE), Some(()));
+		expect_events(vec![Event::FeeTierAdded { fee: FEE }.into()]);
+	});
+}
+
+#[test]
+fn add_fee_tier_should_fail_when_origin_is_not_authority() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(XYK::add_fee_tier(RuntimeOrigin::signed(ALICE), FEE), BadOrigin);
+	});
+}
+
+#[test]
+fn add_fee_tier_should_fail_when_fee_is_invalid() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			XYK::add_fee_tier(RuntimeOrigin::root(), (1, 0)),
+			Error::<Test>::InvalidFee
+		);
+		assert_noop!(
+			XYK::add_fee_tier(RuntimeOrigin::root(), (100, 100)),
+			Error::<Test>::InvalidFee
+		);
+	});
+}
+
+#[test]
+fn add_fee_tier_should_fail_when_tier_already_exists() {
+	new_test_ext().execute_with(|| {
+		add_fee_tier(FEE);
+
+		assert_noop!(
+			XYK::add_fee_tier(RuntimeOrigin::root(), FEE),
+			Error::<Test>::FeeTierAlreadyExists
+		);
+	});
+}
+
+#[test]
+fn remove_fee_tier_should_work() {
+	new_test_ext().execute_with(|| {
+		add_fee_tier(FEE);
+
+		assert_ok!(XYK::remove_fee_tier(RuntimeOrigin::root(), FEE));
+
+		assert_eq!(XYK::fee_tiers(FEE), None);
+		expect_events(vec![Event::FeeTierRemoved { fee: FEE }.into()]);
+	});
+}
+
+#[test]
+fn remove_fee_tier_should_fail_when_tier_does_not_exist() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			XYK::remove_fee_tier(RuntimeOrigin::root(), FEE),
+			Error::<Test>::FeeTierNotFound
+		);
+	});
+}
+
+#[test]
+fn remove_fee_tier_should_not_change_fee_of_existing_pools() {
+	new_test_ext().execute_with(|| {
+		add_fee_tier(FEE);
+		create_pool_with_fee(FEE);
+
+		assert_ok!(XYK::remove_fee_tier(RuntimeOrigin::root(), FEE));
+
+		assert_eq!(XYK::get_fee(&HDX_DOT_POOL_ID), FEE);
+	});
+}
+
+#[test]
+fn create_pool_should_store_default_fee_and_owner() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::create_pool(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			100 * ONE,
+			DOT,
+			100 * ONE,
+		));
+
+		assert_eq!(XYK::pool_fees(HDX_DOT_POOL_ID), Some((2, 1_000)));
+		assert_eq!(XYK::pool_owner(HDX_DOT_POOL_ID), Some(ALICE));
+	});
+}
+
+#[test]
+fn create_pool_with_fee_should_store_fee_and_owner() {
+	new_test_ext().execute_with(|| {
+		add_fee_tier(FEE);
+
+		create_pool_with_fee(FEE);
+
+		assert_eq!(XYK::pool_fees(HDX_DOT_POOL_ID), Some(FEE));
+		assert_eq!(XYK::pool_owner(HDX_DOT_POOL_ID), Some(ALICE));
+		assert_eq!(XYK::get_fee(&HDX_DOT_POOL_ID), FEE);
+	});
+}
+
+#[test]
+fn create_pool_with_fee_should_fail_when_fee_is_not_tier() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			XYK::create_pool_with_fee(RuntimeOrigin::signed(ALICE), HDX, 100 * ONE, DOT, 100 * ONE, FEE),
+			Error::<Test>::FeeTierNotFound
+		);
+	});
+}
+
+#[test]
+fn sell_should_charge_pool_fee() {
+	new_test_ext().execute_with(|| {
+		add_fee_tier(FEE);
+		create_pool_with_fee(FEE);
+
+		let amount_out = hydra_dx_math::xyk::calculate_out_given_in(100 * ONE, 100 * ONE, ONE).unwrap();
+		let fee = hydra_dx_math::fee::calculate_pool_trade_fee(amount_out, FEE).unwrap();
+		let dot_balance = Currency::free_balance(DOT, &BOB);
+
+		assert_ok!(XYK::sell(RuntimeOrigin::signed(BOB), HDX, DOT, ONE, 0, false));
+
+		assert_eq!(Currency::free_balance(DOT, &BOB), dot_balance + amount_out - fee);
+		expect_events(vec![Event::SellExecuted {
+			who: BOB,
+			asset_in: HDX,
+			asset_out: DOT,
+			amount: ONE,
+			sale_price: amount_out - fee,
+			fee_asset: DOT,
+			fee_amount: fee,
+			pool: HDX_DOT_POOL_ID,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn buy_should_charge_pool_fee() {
+	new_test_ext().execute_with(|| {
+		add_fee_tier(FEE);
+		create_pool_with_fee(FEE);
+
+		let amount_in = hydra_dx_math::xyk::calculate_in_given_out(100 * ONE, 100 * ONE, ONE).unwrap();
+		let fee = hydra_dx_math::fee::calculate_pool_trade_fee(amount_in, FEE).unwrap();
+		let hdx_balance = Currency::free_balance(HDX, &BOB);
+
+		assert_ok!(XYK::buy(RuntimeOrigin::signed(BOB), DOT, HDX, ONE, 2 * ONE, false));
+
+		assert_eq!(Currency::free_balance(HDX, &BOB), hdx_balance - amount_in - fee);
+	});
+}
+
+#[test]
+fn discounted_fee_should_not_exceed_pool_fee() {
+	new_test_ext().execute_with(|| {
+		// lower than the discounted fee
+		let low_fee = (1, 100_000);
+		add_fee_tier(low_fee);
+		create_pool_with_fee(low_fee);
+
+		let amount_out = hydra_dx_math::xyk::calculate_out_given_in(100 * ONE, 100 * ONE, ONE).unwrap();
+		let fee = hydra_dx_math::fee::calculate_pool_trade_fee(amount_out, low_fee).unwrap();
+		let discounted_fee = hydra_dx_math::fee::calculate_pool_trade_fee(amount_out, (7, 10_000)).unwrap();
+		assert!(fee < discounted_fee);
+		assert_eq!(XYK::calculate_discounted_fee(&HDX_DOT_POOL_ID, amount_out), Ok(fee));
+
+		assert_ok!(XYK::sell(RuntimeOrigin::signed(BOB), DOT, HDX, ONE, 0, true));
+
+		expect_events(vec![Event::SellExecuted {
+			who: BOB,
+			asset_in: DOT,
+			asset_out: HDX,
+			amount: ONE,
+			sale_price: amount_out - fee,
+			fee_asset: HDX,
+			fee_amount: fee,
+			pool: HDX_DOT_POOL_ID,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn update_pool_fee_should_work_when_called_by_owner() {
+	new_test_ext().execute_with(|| {
+		add_fee_tier(FEE);
+		create_pool_with_fee(FEE);
+		add_fee_tier((5, 1_000));
+
+		assert_ok!(XYK::update_pool_fee(RuntimeOrigin::signed(ALICE), HDX, DOT, (5, 1_000)));
+
+		assert_eq!(XYK::get_fee(&HDX_DOT_POOL_ID), (5, 1_000));
+		expect_events(vec![Event::PoolFeeUpdated {
+			pool: HDX_DOT_POOL_ID,
+			asset_a: HDX,
+			asset_b: DOT,
+			fee: (5, 1_000),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn update_pool_fee_should_work_when_called_by_authority() {
+	new_test_ext().execute_with(|| {
+		add_fee_tier(FEE);
+		create_pool_with_fee(FEE);
+		add_fee_tier((5, 1_000));
+
+		assert_ok!(XYK::update_pool_fee(RuntimeOrigin::root(), DOT, HDX, (5, 1_000)));
+
+		assert_eq!(XYK::get_fee(&HDX_DOT_POOL_ID), (5, 1_000));
+	});
+}
+
+#[test]
+fn update_pool_fee_should_fail_when_called_by_other_account() {
+	new_test_ext().execute_with(|| {
+		add_fee_tier(FEE);
+		create_pool_with_fee(FEE);
+		add_fee_tier((5, 1_000));
+
+		assert_noop!(
+			XYK::update_pool_fee(RuntimeOrigin::signed(BOB), HDX, DOT, (5, 1_000)),
+			Error::<Test>::Forbidden
+		);
+	});
+}
+
+#[test]
+fn update_pool_fee_should_fail_when_fee_is_not_tier() {
+	new_test_ext().execute_with(|| {
+		add_fee_tier(FEE);
+		create_pool_with_fee(FEE);
+
+		assert_noop!(
+			XYK::update_pool_fee(RuntimeOrigin::signed(ALICE), HDX, DOT, (5, 1_000)),
+			Error::<Test>::FeeTierNotFound
+		);
+	});
+}
+
+#[test]
+fn update_pool_fee_should_fail_when_pool_does_not_exist() {
+	new_test_ext().execute_with(|| {
+		add_fee_tier(FEE);
+
+		assert_noop!(
+			XYK::update_pool_fee(RuntimeOrigin::root(), HDX, DOT, FEE),
+			Error::<Test>::TokenPoolNotFound
+		);
+	});
+}
+
+#[test]
+fn destroying_pool_should_remove_pool_fee_and_owner() {
+	new_test_ext().execute_with(|| {
+		add_fee_tier(FEE);
+		create_pool_with_fee(FEE);
+
+		let share_token = XYK::share_token(HDX_DOT_POOL_ID);
+		let shares = Currency::free_balance(share_token, &ALICE);
+		assert_ok!(XYK::remove_liquidity(RuntimeOrigin::signed(ALICE), HDX, DOT, shares));
+
+		assert_eq!(XYK::pool_fees(HDX_DOT_POOL_ID), None);
+		assert_eq!(XYK::pool_owner(HDX_DOT_POOL_ID), None);
+	});
+}
+
+#[test]
+fn spot_price_with_fee_should_use_pool_fee() {
+	new_test_ext().execute_with(|| {
+		add_fee_tier(FEE);
+		create_pool_with_fee(FEE);
+
+		let price = XYK::calculate_spot_price_with_fee(PoolType::XYK, HDX, DOT).unwrap();
+		let expected = hydra_dx_math::xyk::calculate_spot_price_with_fee(100 * ONE, 100 * ONE, Some(FEE))
+			.unwrap()
+			.reciprocal()
+			.unwrap();
+
+		assert_eq!(price, expected);
+	});
+}
+
+#[test]
+fn migration_should_set_current_fee_for_existing_pools() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::create_pool(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			100 * ONE,
+			DOT,
+			100 * ONE,
+		));
+		PoolFees::<Test>::remove(HDX_DOT_POOL_ID);
+		StorageVersion::new(0).put::<XYK>();
+
+		crate::migration::migrate_to_v1::<Test>();
+
+		assert_eq!(XYK::pool_fees(HDX_DOT_POOL_ID), Some((2, 1_000)));
+		assert!(FeeTiers::<Test>::contains_key((2, 1_000)));
+		assert_eq!(XYK::on_chain_storage_version(), StorageVersion::new(1));
+	});
+}
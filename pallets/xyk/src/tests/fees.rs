@@ -9,38 +9,41 @@ use crate::types::AssetPair;
 #[test]
 fn fee_calculation() {
 	ExtBuilder::default().build().execute_with(|| {
-		assert_eq!(XYK::calculate_fee(100_000), Ok(200));
-		assert_eq!(XYK::calculate_fee(10_000), Ok(20));
+		assert_eq!(XYK::calculate_fee(&HDX_DOT_POOL_ID, 100_000), Ok(200));
+		assert_eq!(XYK::calculate_fee(&HDX_DOT_POOL_ID, 10_000), Ok(20));
 
-		assert_eq!(XYK::calculate_discounted_fee(9_999), Ok(0));
-		assert_eq!(XYK::calculate_discounted_fee(10_000), Ok(7));
-		assert_eq!(XYK::calculate_discounted_fee(100_000), Ok(70));
+		assert_eq!(XYK::calculate_discounted_fee(&HDX_DOT_POOL_ID, 9_999), Ok(0));
+		assert_eq!(XYK::calculate_discounted_fee(&HDX_DOT_POOL_ID, 10_000), Ok(7));
+		assert_eq!(XYK::calculate_discounted_fee(&HDX_DOT_POOL_ID, 100_000), Ok(70));
 	});
 	ExtBuilder::default()
 		.with_exchange_fee((10, 1000))
 		.with_discounted_fee((10, 1000))
 		.build()
 		.execute_with(|| {
-			assert_eq!(XYK::calculate_fee(100_000), Ok(1_000));
-			assert_eq!(XYK::calculate_fee(10_000), Ok(100));
+			assert_eq!(XYK::calculate_fee(&HDX_DOT_POOL_ID, 100_000), Ok(1_000));
+			assert_eq!(XYK::calculate_fee(&HDX_DOT_POOL_ID, 10_000), Ok(100));
 
-			assert_eq!(XYK::calculate_discounted_fee(999), Ok(0));
-			assert_eq!(XYK::calculate_discounted_fee(1_000), Ok(10));
-			assert_eq!(XYK::calculate_discounted_fee(10_000), Ok(100));
+			assert_eq!(XYK::calculate_discounted_fee(&HDX_DOT_POOL_ID, 999), Ok(0));
+			assert_eq!(XYK::calculate_discounted_fee(&HDX_DOT_POOL_ID, 1_000), Ok(10));
+			assert_eq!(XYK::calculate_discounted_fee(&HDX_DOT_POOL_ID, 10_000), Ok(100));
 		});
 
 	ExtBuilder::default()
 		.with_exchange_fee((10, 0))
 		.build()
 		.execute_with(|| {
-			assert_eq!(XYK::calculate_fee(100000), Ok(0));
+			assert_eq!(XYK::calculate_fee(&HDX_DOT_POOL_ID, 100000), Ok(0));
 		});
 
 	ExtBuilder::default()
 		.with_exchange_fee((10, 1))
 		.build()
 		.execute_with(|| {
-			assert_noop!(XYK::calculate_fee(u128::MAX), Error::<Test>::FeeAmountInvalid);
+			assert_noop!(
+				XYK::calculate_fee(&HDX_DOT_POOL_ID, u128::MAX),
+				Error::<Test>::FeeAmountInvalid
+			);
 		});
 }
 
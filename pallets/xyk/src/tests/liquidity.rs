@@ -1,7 +1,9 @@
 pub use super::mock::*;
 use crate::types::{AssetPair, Balance};
 use crate::{Error, Event};
+use frame_support::sp_runtime::DispatchError;
 use frame_support::{assert_noop, assert_ok};
+use hydra_dx_math::ratio::Ratio;
 use hydradx_traits::AMM as AmmPool;
 use orml_traits::MultiCurrency;
 
@@ -687,3 +689,78 @@ fn share_ratio_calculations_are_correct() {
 			}
 		});
 }
+
+#[test]
+fn add_liquidity_should_check_price_barrier_with_pool_spot_price() {
+	new_test_ext().execute_with(|| {
+		// Arrange
+		assert_ok!(XYK::create_pool(
+			RuntimeOrigin::signed(ALICE),
+			DOT,
+			100_000_000,
+			HDX,
+			65_400_000
+		));
+
+		// Act
+		assert_ok!(XYK::add_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			DOT,
+			HDX,
+			400_000,
+			1_000_000_000_000
+		));
+
+		// Assert
+		assert_eq!(
+			price_barrier_calls(),
+			vec![(DOT, HDX, Ratio::new(100_000_000, 65_400_000))]
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_price_barrier_is_triggered() {
+	ExtBuilder::default()
+		.with_triggered_price_barrier()
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(XYK::create_pool(
+				RuntimeOrigin::signed(ALICE),
+				DOT,
+				100_000_000,
+				HDX,
+				65_400_000
+			));
+
+			// Act & Assert
+			assert_noop!(
+				XYK::add_liquidity(RuntimeOrigin::signed(ALICE), DOT, HDX, 400_000, 1_000_000_000_000),
+				DispatchError::Other("price barrier")
+			);
+		});
+}
+
+#[test]
+fn remove_liquidity_should_fail_when_price_barrier_is_triggered() {
+	ExtBuilder::default()
+		.with_triggered_price_barrier()
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(XYK::create_pool(
+				RuntimeOrigin::signed(ALICE),
+				DOT,
+				100_000_000,
+				HDX,
+				65_400_000
+			));
+
+			// Act & Assert
+			assert_noop!(
+				XYK::remove_liquidity(RuntimeOrigin::signed(ALICE), DOT, HDX, 1_000_000),
+				DispatchError::Other("price barrier")
+			);
+		});
+}
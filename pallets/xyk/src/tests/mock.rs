@@ -28,9 +28,10 @@ use sp_runtime::{
 
 use crate::types::{AssetId, Balance};
 use frame_support::traits::{Everything, Get, Nothing};
+use frame_support::weights::Weight;
 use hydradx_traits::{AssetPairAccountIdFor, CanCreatePool, Source};
 
-use frame_system::EnsureSigned;
+use frame_system::{EnsureRoot, EnsureSigned};
 use hydradx_traits::pools::DustRemovalAccountWhitelist;
 use std::cell::RefCell;
 
@@ -69,6 +70,8 @@ thread_local! {
 		static EXCHANGE_FEE: RefCell<(u32, u32)> = const { RefCell::new((2, 1_000)) };
 		static DISCOUNTED_FEE: RefCell<(u32, u32)> = const { RefCell::new((7, 10_000)) };
 		static MAX_OUT_RATIO: RefCell<u128> = const { RefCell::new(3) };
+		static PRICE_BARRIER_TRIGGERED: RefCell<bool> = const { RefCell::new(false) };
+		static PRICE_BARRIER_CALLS: RefCell<Vec<(AssetId, AssetId, Ratio)>> = const { RefCell::new(Vec::new()) };
 }
 
 struct ExchangeFee;
@@ -193,6 +196,7 @@ parameter_types! {
 	pub ExchangeFeeRate: (u32, u32) = ExchangeFee::get();
 	pub DiscountedFeeRate: (u32, u32) = DiscountedFee::get();
 	pub const OracleSourceIdentifier: Source = *b"hydraxyk";
+	pub const ProtocolFeeReceiver: AccountId = TREASURY;
 }
 
 pub struct Disallow10_10Pool();
@@ -219,6 +223,9 @@ impl xyk::Config for Test {
 	type AMMHandler = ();
 	type DiscountedFee = DiscountedFeeRate;
 	type NonDustableWhitelistHandler = Whitelist;
+	type PriceBarrier = MockPriceBarrier;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type ProtocolFeeReceiver = ProtocolFeeReceiver;
 	type OracleSource = OracleSourceIdentifier;
 }
 
@@ -229,6 +236,8 @@ pub struct ExtBuilder {
 // Returns default values for genesis config
 impl Default for ExtBuilder {
 	fn default() -> Self {
+		PRICE_BARRIER_TRIGGERED.with(|v| *v.borrow_mut() = false);
+		PRICE_BARRIER_CALLS.with(|v| v.borrow_mut().clear());
 		Self {
 			endowed_accounts: vec![
 				(ALICE, HDX, 1_000_000_000_000_000u128),
@@ -266,6 +275,11 @@ impl ExtBuilder {
 		self
 	}
 
+	pub fn with_triggered_price_barrier(self) -> Self {
+		PRICE_BARRIER_TRIGGERED.with(|v| *v.borrow_mut() = true);
+		self
+	}
+
 	pub fn build(self) -> sp_io::TestExternalities {
 		let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
 
@@ -302,3 +316,23 @@ impl DustRemovalAccountWhitelist<AccountId> for Whitelist {
 		Ok(())
 	}
 }
+
+pub struct MockPriceBarrier;
+
+impl PriceBarrier<AccountId, AssetId, Ratio> for MockPriceBarrier {
+	fn ensure_price(_who: &AccountId, asset_a: AssetId, asset_b: AssetId, spot_price: Ratio) -> DispatchResult {
+		PRICE_BARRIER_CALLS.with(|v| v.borrow_mut().push((asset_a, asset_b, spot_price)));
+		if PRICE_BARRIER_TRIGGERED.with(|v| *v.borrow()) {
+			return Err(DispatchError::Other("price barrier"));
+		}
+		Ok(())
+	}
+
+	fn ensure_price_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+pub fn price_barrier_calls() -> Vec<(AssetId, AssetId, Ratio)> {
+	PRICE_BARRIER_CALLS.with(|v| v.borrow().clone())
+}
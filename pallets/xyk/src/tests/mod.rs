@@ -1,8 +1,10 @@
 mod amm_position;
 mod creation;
+mod fee_tiers;
 mod fees;
 mod invariants;
 mod liquidity;
 pub(crate) mod mock;
+mod protocol_fee;
 mod spot_price;
 mod trades;
@@ -0,0 +1,264 @@
+pub use super::mock::*;
+use crate::types::{LiquidityPosition, PoolFeeStats};
+use crate::{Error, Event};
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::MultiCurrency;
+use sp_runtime::DispatchError::BadOrigin;
+
+const PROTOCOL_FEE: (u32, u32) = (1, 6);
+
+fn create_pool() {
+	assert_ok!(XYK::create_pool(
+		RuntimeOrigin::signed(ALICE),
+		HDX,
+		100 * ONE,
+		DOT,
+		100 * ONE,
+	));
+}
+
+fn pool_reserves() -> (Balance, Balance) {
+	(
+		Currency::free_balance(HDX, &HDX_DOT_POOL_ID),
+		Currency::free_balance(DOT, &HDX_DOT_POOL_ID),
+	)
+}
+
+#[test]
+fn set_protocol_fee_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::set_protocol_fee(RuntimeOrigin::root(), Some(PROTOCOL_FEE)));
+
+		assert_eq!(XYK::protocol_fee(), Some(PROTOCOL_FEE));
+		expect_events(vec![Event::ProtocolFeeSet {
+			fee: Some(PROTOCOL_FEE),
+		}
+		.into()]);
+
+		assert_ok!(XYK::set_protocol_fee(RuntimeOrigin::root(), None));
+
+		assert_eq!(XYK::protocol_fee(), None);
+		expect_events(vec![Event::ProtocolFeeSet { fee: None }.into()]);
+	});
+}
+
+#[test]
+fn set_protocol_fee_should_fail_when_origin_is_not_authority() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			XYK::set_protocol_fee(RuntimeOrigin::signed(ALICE), Some(PROTOCOL_FEE)),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_protocol_fee_should_fail_when_fee_is_invalid() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			XYK::set_protocol_fee(RuntimeOrigin::root(), Some((1, 0))),
+			Error::<Test>::InvalidFee
+		);
+		assert_noop!(
+			XYK::set_protocol_fee(RuntimeOrigin::root(), Some((6, 6))),
+			Error::<Test>::InvalidFee
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_store_root_k_when_protocol_fee_is_on() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::set_protocol_fee(RuntimeOrigin::root(), Some(PROTOCOL_FEE)));
+
+		create_pool();
+
+		assert_eq!(XYK::root_k_last(HDX_DOT_POOL_ID), 100 * ONE);
+	});
+}
+
+#[test]
+fn add_liquidity_should_mint_protocol_fee_when_invariant_grew() {
+	new_test_ext().execute_with(|| {
+		//Arrange
+		assert_ok!(XYK::set_protocol_fee(RuntimeOrigin::root(), Some(PROTOCOL_FEE)));
+		create_pool();
+		assert_ok!(XYK::sell(RuntimeOrigin::signed(BOB), HDX, DOT, 10 * ONE, 0, false));
+
+		let (reserve_hdx, reserve_dot) = pool_reserves();
+		let root_k = hydra_dx_math::xyk::calculate_root_k(reserve_hdx, reserve_dot).unwrap();
+		let expected_shares =
+			hydra_dx_math::xyk::calculate_protocol_fee_shares(root_k, 100 * ONE, 100 * ONE, PROTOCOL_FEE).unwrap();
+		assert!(expected_shares > 0);
+
+		//Act
+		assert_ok!(XYK::add_liquidity(RuntimeOrigin::signed(ALICE), HDX, DOT, ONE, 2 * ONE));
+
+		//Assert
+		let share_token = XYK::share_token(HDX_DOT_POOL_ID);
+		assert_eq!(Currency::free_balance(share_token, &TREASURY), expected_shares);
+		assert_eq!(XYK::protocol_fee_shares(HDX_DOT_POOL_ID), expected_shares);
+		assert_eq!(
+			XYK::total_liquidity(HDX_DOT_POOL_ID),
+			Currency::free_balance(share_token, &ALICE) + expected_shares
+		);
+
+		let (reserve_hdx, reserve_dot) = pool_reserves();
+		assert_eq!(
+			XYK::root_k_last(HDX_DOT_POOL_ID),
+			hydra_dx_math::xyk::calculate_root_k(reserve_hdx, reserve_dot).unwrap()
+		);
+
+		expect_events(vec![Event::ProtocolFeeMinted {
+			pool: HDX_DOT_POOL_ID,
+			receiver: TREASURY,
+			shares: expected_shares,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_mint_protocol_fee_when_invariant_grew() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::set_protocol_fee(RuntimeOrigin::root(), Some(PROTOCOL_FEE)));
+		create_pool();
+		assert_ok!(XYK::sell(RuntimeOrigin::signed(BOB), HDX, DOT, 10 * ONE, 0, false));
+
+		assert_ok!(XYK::remove_liquidity(RuntimeOrigin::signed(ALICE), HDX, DOT, 10 * ONE));
+
+		let share_token = XYK::share_token(HDX_DOT_POOL_ID);
+		assert!(Currency::free_balance(share_token, &TREASURY) > 0);
+		assert_eq!(
+			XYK::protocol_fee_shares(HDX_DOT_POOL_ID),
+			Currency::free_balance(share_token, &TREASURY)
+		);
+	});
+}
+
+#[test]
+fn protocol_fee_should_not_be_minted_when_switched_off() {
+	new_test_ext().execute_with(|| {
+		create_pool();
+		assert_ok!(XYK::sell(RuntimeOrigin::signed(BOB), HDX, DOT, 10 * ONE, 0, false));
+
+		assert_ok!(XYK::add_liquidity(RuntimeOrigin::signed(ALICE), HDX, DOT, ONE, 2 * ONE));
+
+		let share_token = XYK::share_token(HDX_DOT_POOL_ID);
+		assert_eq!(Currency::free_balance(share_token, &TREASURY), 0);
+		assert_eq!(XYK::root_k_last(HDX_DOT_POOL_ID), 0);
+	});
+}
+
+#[test]
+fn protocol_fee_should_not_be_minted_for_growth_before_switch_on() {
+	new_test_ext().execute_with(|| {
+		create_pool();
+		assert_ok!(XYK::sell(RuntimeOrigin::signed(BOB), HDX, DOT, 10 * ONE, 0, false));
+		assert_ok!(XYK::set_protocol_fee(RuntimeOrigin::root(), Some(PROTOCOL_FEE)));
+
+		assert_ok!(XYK::add_liquidity(RuntimeOrigin::signed(ALICE), HDX, DOT, ONE, 2 * ONE));
+
+		let share_token = XYK::share_token(HDX_DOT_POOL_ID);
+		assert_eq!(Currency::free_balance(share_token, &TREASURY), 0);
+		assert!(XYK::root_k_last(HDX_DOT_POOL_ID) > 0);
+	});
+}
+
+#[test]
+fn switching_protocol_fee_off_should_remove_root_k_on_next_liquidity_change() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::set_protocol_fee(RuntimeOrigin::root(), Some(PROTOCOL_FEE)));
+		create_pool();
+		assert_ok!(XYK::sell(RuntimeOrigin::signed(BOB), HDX, DOT, 10 * ONE, 0, false));
+		assert_ok!(XYK::set_protocol_fee(RuntimeOrigin::root(), None));
+
+		assert_ok!(XYK::add_liquidity(RuntimeOrigin::signed(ALICE), HDX, DOT, ONE, 2 * ONE));
+
+		let share_token = XYK::share_token(HDX_DOT_POOL_ID);
+		assert_eq!(Currency::free_balance(share_token, &TREASURY), 0);
+		assert_eq!(XYK::root_k_last(HDX_DOT_POOL_ID), 0);
+	});
+}
+
+#[test]
+fn trades_should_accumulate_fees() {
+	new_test_ext().execute_with(|| {
+		create_pool();
+
+		let amount_out = hydra_dx_math::xyk::calculate_out_given_in(100 * ONE, 100 * ONE, ONE).unwrap();
+		let sell_fee = hydra_dx_math::fee::calculate_pool_trade_fee(amount_out, (2, 1_000)).unwrap();
+		assert_ok!(XYK::sell(RuntimeOrigin::signed(BOB), HDX, DOT, ONE, 0, false));
+
+		let (reserve_hdx, reserve_dot) = pool_reserves();
+		let amount_in = hydra_dx_math::xyk::calculate_in_given_out(reserve_hdx, reserve_dot, ONE).unwrap();
+		let buy_fee = hydra_dx_math::fee::calculate_pool_trade_fee(amount_in, (2, 1_000)).unwrap();
+		assert_ok!(XYK::buy(RuntimeOrigin::signed(BOB), HDX, DOT, ONE, 2 * ONE, false));
+
+		assert_eq!(
+			XYK::pool_fee_stats(HDX, DOT),
+			Ok(PoolFeeStats {
+				asset_a: HDX,
+				asset_b: DOT,
+				fees_a: 0,
+				fees_b: sell_fee + buy_fee,
+				protocol_fee_shares: 0,
+			})
+		);
+		assert_eq!(XYK::pool_fee_stats(DOT, HDX).unwrap().fees_a, sell_fee + buy_fee);
+	});
+}
+
+#[test]
+fn liquidity_position_should_return_share_of_reserves() {
+	new_test_ext().execute_with(|| {
+		create_pool();
+		assert_ok!(XYK::add_liquidity(
+			RuntimeOrigin::signed(BOB),
+			HDX,
+			DOT,
+			100 * ONE,
+			200 * ONE
+		));
+		assert_ok!(XYK::sell(RuntimeOrigin::signed(CHARLIE), HDX, DOT, 10 * ONE, 0, false));
+
+		let (reserve_hdx, reserve_dot) = pool_reserves();
+
+		let position = XYK::liquidity_position(&BOB, HDX, DOT).unwrap();
+
+		assert_eq!(
+			position,
+			LiquidityPosition {
+				asset_a: HDX,
+				asset_b: DOT,
+				shares: 100 * ONE,
+				total_shares: 200 * ONE,
+				amount_a: reserve_hdx / 2,
+				amount_b: reserve_dot / 2,
+			}
+		);
+	});
+}
+
+#[test]
+fn liquidity_position_should_fail_when_pool_does_not_exist() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			XYK::liquidity_position(&BOB, HDX, DOT),
+			Error::<Test>::TokenPoolNotFound
+		);
+	});
+}
+
+#[test]
+fn destroying_pool_should_remove_fee_accounting() {
+	new_test_ext().execute_with(|| {
+		create_pool();
+		assert_ok!(XYK::sell(RuntimeOrigin::signed(BOB), HDX, DOT, ONE, 0, false));
+
+		assert_ok!(XYK::remove_liquidity(RuntimeOrigin::signed(ALICE), HDX, DOT, 100 * ONE));
+
+		assert_eq!(XYK::accumulated_fees(HDX_DOT_POOL_ID, DOT), 0);
+		assert_eq!(XYK::protocol_fee_shares(HDX_DOT_POOL_ID), 0);
+	});
+}
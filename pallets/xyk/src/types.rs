@@ -22,7 +22,7 @@ pub type Price = FixedU128;
 
 use codec::{Decode, Encode};
 use scale_info::TypeInfo;
-use sp_runtime::FixedU128;
+use sp_runtime::{FixedU128, RuntimeDebug};
 use sp_std::vec::Vec;
 
 #[cfg(feature = "std")]
@@ -67,3 +67,31 @@ impl AssetPair {
 		buf
 	}
 }
+
+/// Cumulative fee statistics of a pool.
+#[derive(Clone, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct PoolFeeStats {
+	pub asset_a: AssetId,
+	pub asset_b: AssetId,
+	/// Trade fees paid in asset a and accumulated in the pool
+	pub fees_a: Balance,
+	/// Trade fees paid in asset b and accumulated in the pool
+	pub fees_b: Balance,
+	/// Shares minted to protocol fee receiver
+	pub protocol_fee_shares: Balance,
+}
+
+/// Share of liquidity provider in a pool.
+#[derive(Clone, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct LiquidityPosition {
+	pub asset_a: AssetId,
+	pub asset_b: AssetId,
+	/// Shares owned by liquidity provider
+	pub shares: Balance,
+	/// Total shares of the pool
+	pub total_shares: Balance,
+	/// Amount of asset a received if all shares were removed
+	pub amount_a: Balance,
+	/// Amount of asset b received if all shares were removed
+	pub amount_b: Balance,
+}
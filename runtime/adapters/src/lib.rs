@@ -43,12 +43,12 @@ use orml_traits::GetByKey;
 use orml_xcm_support::{OnDepositFail, UnknownAsset as UnknownAssetT};
 use pallet_circuit_breaker::WeightInfo;
 use pallet_ema_oracle::{OnActivityHandler, OracleError, Price};
-use pallet_omnipool::traits::{AssetInfo, ExternalPriceProvider, OmnipoolHooks};
+use pallet_omnipool::traits::{AssetInfo, ExternalPriceProvider, OmnipoolHooks, ShouldAllow};
 use pallet_stableswap::types::{PoolState, StableswapHooks};
 use pallet_transaction_multi_payment::DepositFee;
 use polkadot_xcm::v4::prelude::*;
 use primitive_types::{U128, U512};
-use primitives::constants::chain::{STABLESWAP_SOURCE, XYK_SOURCE};
+use primitives::constants::chain::{CONCENTRATED_SOURCE, STABLESWAP_SOURCE, XYK_SOURCE};
 use primitives::{constants::chain::OMNIPOOL_SOURCE, AccountId, AssetId, Balance, BlockNumber, CollectionId};
 use sp_runtime::traits::BlockNumberProvider;
 use sp_std::vec::Vec;
@@ -518,6 +518,19 @@ where
 	}
 }
 
+/// Checks omnipool's liquidity operations against the price barrier of the circuit breaker.
+pub struct OmnipoolPriceBarrier<Runtime>(PhantomData<Runtime>);
+
+impl<Runtime> ShouldAllow<AccountId, AssetId, Price> for OmnipoolPriceBarrier<Runtime>
+where
+	Runtime: pallet_circuit_breaker::Config<AssetId = AssetId> + frame_system::Config<AccountId = AccountId>,
+{
+	fn ensure_price(who: &AccountId, asset_a: AssetId, asset_b: AssetId, current_price: Price) -> Result<(), ()> {
+		pallet_circuit_breaker::Pallet::<Runtime>::ensure_price(who, OMNIPOOL_SOURCE, asset_a, asset_b, current_price)
+			.map_err(|_| ())
+	}
+}
+
 pub struct OraclePriceProvider<AssetId, AggregatedPriceGetter, Lrna>(
 	PhantomData<(AssetId, AggregatedPriceGetter, Lrna)>,
 );
@@ -600,6 +613,15 @@ where
 						Err(_) => return None,
 					}
 				}
+				PoolType::Concentrated(_) => {
+					let price_result = AggregatedPriceGetter::get_price(asset_a, asset_b, period, CONCENTRATED_SOURCE);
+
+					match price_result {
+						Ok(price) => price.0,
+						Err(OracleError::SameAsset) => EmaPrice::from(1),
+						Err(_) => return None,
+					}
+				}
 				_ => return None,
 			};
 
@@ -859,6 +881,55 @@ where
 	}
 }
 
+/// Provides volume and liquidity of stableswap pool's share asset to calculate dynamic fees of the pool.
+///
+/// Oracle records stableswap trades as volume of each traded asset going into the (asset, share asset) pair.
+/// The volume is converted to share units and treated as outgoing volume of the share asset,
+/// so the pool fee increases with trading volume relative to the share issuance.
+pub struct StableswapShareVolumeProvider<Runtime, Period>(PhantomData<(Runtime, Period)>);
+
+impl<Runtime, Period> pallet_dynamic_fees::traits::VolumeProvider<AssetId, Balance>
+	for StableswapShareVolumeProvider<Runtime, Period>
+where
+	Runtime: pallet_ema_oracle::Config + pallet_stableswap::Config<AssetId = AssetId>,
+	Period: Get<OraclePeriod>,
+{
+	type Volume = OracleVolume;
+
+	fn asset_volume(pool_id: AssetId) -> Option<Self::Volume> {
+		let pool = pallet_stableswap::Pallet::<Runtime>::pools(pool_id)?;
+
+		let mut volume_in = Balance::zero();
+		let mut volume_out = Balance::zero();
+		for asset_id in pool.assets.iter() {
+			let entry =
+				pallet_ema_oracle::Pallet::<Runtime>::get_entry(*asset_id, pool_id, Period::get(), STABLESWAP_SOURCE)
+					.ok()?;
+			// Price is amount of asset per one share.
+			let to_shares = |amount: Balance| {
+				sp_runtime::helpers_128bit::multiply_by_rational_with_rounding(
+					amount,
+					entry.price.d,
+					entry.price.n,
+					sp_runtime::Rounding::Down,
+				)
+			};
+			volume_in = volume_in.saturating_add(to_shares(entry.volume.a_out)?);
+			volume_out = volume_out.saturating_add(to_shares(entry.volume.a_in)?);
+		}
+		Some(OracleVolume(volume_in, volume_out))
+	}
+
+	fn asset_liquidity(pool_id: AssetId) -> Option<Balance> {
+		let pool = pallet_stableswap::Pallet::<Runtime>::pools(pool_id)?;
+		let asset_id = pool.assets.first()?;
+		let entry =
+			pallet_ema_oracle::Pallet::<Runtime>::get_entry(*asset_id, pool_id, Period::get(), STABLESWAP_SOURCE)
+				.ok()?;
+		Some(entry.liquidity.b)
+	}
+}
+
 pub struct VestingInfo<Runtime>(PhantomData<Runtime>);
 
 impl<Runtime> pallet_staking::traits::VestingDetails<AccountId, Balance> for VestingInfo<Runtime>
@@ -1,4 +1,4 @@
-use frame_support::traits::tokens::{Fortitude, Preservation};
+use frame_support::traits::tokens::{ConversionFromAssetBalance, Fortitude, Preservation};
 use frame_support::weights::Weight;
 use hydra_dx_math::ema::EmaPrice;
 use hydradx_traits::fee::SwappablePaymentAssetTrader;
@@ -11,7 +11,7 @@ use primitives::{AccountId, AssetId, Balance};
 use sp_core::Get;
 use sp_runtime::helpers_128bit::multiply_by_rational_with_rounding;
 use sp_runtime::traits::Convert;
-use sp_runtime::Rounding;
+use sp_runtime::{ArithmeticError, DispatchError, Rounding};
 use sp_std::marker::PhantomData;
 
 pub struct OraclePriceProviderUsingRoute<RP, OP, P>(PhantomData<(RP, OP, P)>);
@@ -134,3 +134,33 @@ where
 		}
 	}
 }
+
+/// Converts balance of an asset to balance of the native asset using price of the given price provider.
+///
+/// Used to value treasury spends in any asset against spend limits denominated in the native asset.
+pub struct NativeBalanceConverter<PriceProv, NativeAssetId>(PhantomData<(PriceProv, NativeAssetId)>);
+
+impl<PriceProv, NativeAssetId> ConversionFromAssetBalance<Balance, AssetId, Balance>
+	for NativeBalanceConverter<PriceProv, NativeAssetId>
+where
+	PriceProv: PriceProvider<AssetId, Price = EmaPrice>,
+	NativeAssetId: Get<AssetId>,
+{
+	type Error = DispatchError;
+
+	fn from_asset_balance(balance: Balance, asset_id: AssetId) -> Result<Balance, Self::Error> {
+		let native_asset = NativeAssetId::get();
+		if asset_id == native_asset {
+			return Ok(balance);
+		}
+
+		let price = PriceProv::get_price(native_asset, asset_id).ok_or(DispatchError::Other("Price not available"))?;
+
+		// Rounded up so the value of a spend is never underestimated
+		multiply_by_rational_with_rounding(balance, price.n, price.d, Rounding::Up)
+			.ok_or(ArithmeticError::Overflow.into())
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn ensure_successful(_asset_id: AssetId) {}
+}
@@ -243,6 +243,7 @@ parameter_types! {
 	pub MinimumWithdrawalFee: Permill = Permill::from_rational(1u32,10000);
 	pub XYKExchangeFee: (u32, u32) = (3, 1_000);
 	pub const DiscountedFee: (u32, u32) = (7, 10_000);
+	pub const XYKProtocolFeeReceiver: AccountId = CHARLIE;
 }
 
 impl pallet_xyk::Config for Test {
@@ -262,6 +263,9 @@ impl pallet_xyk::Config for Test {
 	type AMMHandler = ();
 	type DiscountedFee = DiscountedFee;
 	type NonDustableWhitelistHandler = DummyDuster;
+	type PriceBarrier = ();
+	type AuthorityOrigin = EnsureRoot<Self::AccountId>;
+	type ProtocolFeeReceiver = XYKProtocolFeeReceiver;
 }
 
 pub struct Whitelist;
@@ -343,6 +347,9 @@ impl pallet_route_executor::Config for Test {
 	type TechnicalOrigin = EnsureRoot<Self::AccountId>;
 	type OraclePeriod = RouteValidationOraclePeriod;
 	type WeightInfo = ();
+	type PoolsProvider = ();
+	type MaxRouteDiscoveryCalculations = ConstU32<100>;
+	type MaxRouteDiscoveryPools = ConstU32<100>;
 }
 
 pub struct MockedEdCalculator;
@@ -0,0 +1,49 @@
+use crate::price::NativeBalanceConverter;
+use frame_support::parameter_types;
+use frame_support::traits::tokens::ConversionFromAssetBalance;
+use hydra_dx_math::ema::EmaPrice;
+use hydradx_traits::price::PriceProvider;
+use primitives::{AssetId, Balance};
+use sp_runtime::DispatchError;
+
+const HDX: AssetId = 0;
+const DAI: AssetId = 2;
+const DOT: AssetId = 5;
+
+parameter_types! {
+	pub const NativeAssetId: AssetId = HDX;
+}
+
+pub struct MockPriceProvider;
+
+impl PriceProvider<AssetId> for MockPriceProvider {
+	type Price = EmaPrice;
+
+	fn get_price(asset_a: AssetId, asset_b: AssetId) -> Option<Self::Price> {
+		match (asset_a, asset_b) {
+			// 1 DAI = 50 HDX
+			(HDX, DAI) => Some(EmaPrice::new(50, 1)),
+			_ => None,
+		}
+	}
+}
+
+type Converter = NativeBalanceConverter<MockPriceProvider, NativeAssetId>;
+
+#[test]
+fn native_balance_converter_should_not_convert_native_asset() {
+	assert_eq!(Converter::from_asset_balance(1_000, HDX), Ok(1_000));
+}
+
+#[test]
+fn native_balance_converter_should_convert_using_price() {
+	assert_eq!(Converter::from_asset_balance(1_000, DAI), Ok(50_000));
+}
+
+#[test]
+fn native_balance_converter_should_fail_when_price_is_not_available() {
+	assert_eq!(
+		Converter::from_asset_balance(1_000 as Balance, DOT),
+		Err(DispatchError::Other("Price not available"))
+	);
+}
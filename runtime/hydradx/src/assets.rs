@@ -21,8 +21,8 @@ use crate::system::NativeAssetId;
 
 use hydradx_adapters::{
 	AssetFeeOraclePriceProvider, EmaOraclePriceAdapter, FreezableNFT, MultiCurrencyLockedBalance, OmnipoolHookAdapter,
-	OracleAssetVolumeProvider, PriceAdjustmentAdapter, RelayChainBlockHashProvider, RelayChainBlockNumberProvider,
-	StableswapHooksAdapter, VestingInfo,
+	OmnipoolPriceBarrier, OracleAssetVolumeProvider, PriceAdjustmentAdapter, RelayChainBlockHashProvider,
+	RelayChainBlockNumberProvider, StableswapHooksAdapter, StableswapShareVolumeProvider, VestingInfo,
 };
 
 pub use hydradx_traits::{
@@ -40,7 +40,7 @@ use pallet_otc::NamedReserveIdentifier;
 use pallet_stableswap::weights::WeightInfo as StableswapWeights;
 use pallet_transaction_multi_payment::{AddTxAssetOnAccount, RemoveTxAssetOnKilled};
 use primitives::constants::{
-	chain::{OMNIPOOL_SOURCE, XYK_SOURCE},
+	chain::{CONCENTRATED_SOURCE, OMNIPOOL_SOURCE, STABLESWAP_SOURCE, XYK_SOURCE},
 	currency::{NATIVE_EXISTENTIAL_DEPOSIT, UNITS},
 	time::DAYS,
 };
@@ -65,6 +65,7 @@ use orml_traits::{
 	currency::{MultiCurrency, MultiLockableCurrency, MutationHooks, OnDeposit, OnTransfer},
 	GetByKey, Happened,
 };
+use pallet_concentrated_liquidity::weights::WeightInfo as ConcentratedLiquidityWeights;
 use pallet_dynamic_fees::types::FeeParams;
 use pallet_lbp::weights::WeightInfo as LbpWeights;
 use pallet_route_executor::{weights::WeightInfo as RouterWeights, AmmTradeWeights, MAX_NUMBER_OF_TRADES};
@@ -205,7 +206,11 @@ impl SufficiencyCheck {
 
 				let amount_in_without_fee =
 					XykPaymentAssetSupport::calculate_in_given_out(fee_payment_asset, dot_asset_id, ed_in_dot)?;
-				let trade_fee = XykPaymentAssetSupport::calculate_fee_amount(amount_in_without_fee)?;
+				let trade_fee = XykPaymentAssetSupport::calculate_fee_amount(
+					fee_payment_asset,
+					dot_asset_id,
+					amount_in_without_fee,
+				)?;
 				let ed_in_fee_asset = amount_in_without_fee.saturating_add(trade_fee);
 
 				//NOTE: Account doesn't have enough funds to pay ED if this fail.
@@ -411,6 +416,60 @@ impl orml_vesting::Config for Runtime {
 	type BlockNumberProvider = RelayChainBlockNumberProvider<Runtime>;
 }
 
+/// Vests the native asset bought from LBP pools with vesting through `orml_vesting`.
+///
+/// Bought amount is sent to the vesting pallet account and vested transferred to the buyer.
+/// `orml_vesting` releases the same amount every period, so the remainder of `amount` which
+/// can't be split into the periods is transferred to the buyer without vesting.
+pub struct LBPNativeVesting;
+
+impl pallet_lbp::NativeVesting<AccountId, BlockNumber> for LBPNativeVesting {
+	fn vested_transfer(
+		from: &AccountId,
+		to: &AccountId,
+		amount: Balance,
+		vesting: pallet_lbp::SaleVesting<BlockNumber>,
+	) -> DispatchResult {
+		let per_period = amount
+			.checked_div(vesting.period_count.into())
+			.ok_or(ArithmeticError::DivisionByZero)?;
+		let vested = per_period.saturating_mul(vesting.period_count.into());
+		let vesting_account: AccountId = VestingPalletId::get().into_account_truncating();
+
+		<Balances as Currency<AccountId>>::transfer(
+			from,
+			&vesting_account,
+			vested,
+			frame_support::traits::ExistenceRequirement::AllowDeath,
+		)?;
+		<Balances as Currency<AccountId>>::transfer(
+			from,
+			to,
+			amount.saturating_sub(vested),
+			frame_support::traits::ExistenceRequirement::AllowDeath,
+		)?;
+
+		orml_vesting::Pallet::<Runtime>::vested_transfer(
+			RawOrigin::Root.into(),
+			to.clone().into(),
+			orml_vesting::VestingSchedule {
+				start: vesting.start,
+				period: vesting.period,
+				period_count: vesting.period_count,
+				per_period,
+			},
+		)
+	}
+
+	fn vested_transfer_weight() -> Weight {
+		<weights::pallet_balances::HydraWeight<Runtime> as pallet_balances::WeightInfo>::transfer_allow_death()
+			.saturating_mul(2)
+			.saturating_add(
+				<weights::orml_vesting::HydraWeight<Runtime> as orml_vesting::WeightInfo>::vested_transfer(),
+			)
+	}
+}
+
 parameter_types! {
 	pub ClaimMessagePrefix: &'static [u8] = b"I hereby claim all my HDX tokens to wallet:";
 }
@@ -528,6 +587,7 @@ impl pallet_omnipool::Config for Runtime {
 			OmnipoolMaxAllowedPriceDifference,
 			CircuitBreakerWhitelist,
 		>,
+		OmnipoolPriceBarrier<Runtime>,
 	);
 	type ExternalPriceOracle = EmaOraclePriceAdapter<EmaOracleSpotPriceShort, Runtime>;
 	type Fee = pallet_dynamic_fees::UpdateAndRetrieveFees<Runtime>;
@@ -544,6 +604,10 @@ impl Contains<AccountId> for CircuitBreakerWhitelist {
 parameter_types! {
 	pub const DefaultMaxNetTradeVolumeLimitPerBlock: (u32, u32) = (5_000, 10_000);	// 50%
 	pub const DefaultMaxLiquidityLimitPerBlock: Option<(u32, u32)> = Some((500, 10_000));	// 5%
+	// Price barriers are enabled per pool or per asset by the technical committee
+	pub const DefaultMaxPriceDeviation: Option<Permill> = None;
+	// The reference price of a price barrier is the median of the TWAPs of the pair across these sources
+	pub PriceBarrierOracleSources: Vec<Source> = vec![OMNIPOOL_SOURCE, XYK_SOURCE, STABLESWAP_SOURCE];
 }
 
 impl pallet_circuit_breaker::Config for Runtime {
@@ -556,6 +620,9 @@ impl pallet_circuit_breaker::Config for Runtime {
 	type DefaultMaxAddLiquidityLimitPerBlock = DefaultMaxLiquidityLimitPerBlock;
 	type DefaultMaxRemoveLiquidityLimitPerBlock = DefaultMaxLiquidityLimitPerBlock;
 	type OmnipoolHubAsset = LRNA;
+	type PriceBarrierOracle =
+		pallet_ema_oracle::MedianOracle<pallet_ema_oracle::TwapOracle<Runtime>, PriceBarrierOracleSources>;
+	type DefaultMaxPriceDeviation = DefaultMaxPriceDeviation;
 	type WeightInfo = weights::pallet_circuit_breaker::HydraWeight<Runtime>;
 }
 
@@ -588,6 +655,10 @@ impl pallet_ema_oracle::Config for Runtime {
 	/// With every asset trading against LRNA we will only have as many pairs as there will be assets, so
 	/// 40 seems a decent upper bound for the foreseeable future.
 	type MaxUniqueEntries = ConstU32<40>;
+	/// One checkpoint is recorded per block with activity. The only TWAP served is the `Short` (9 blocks)
+	/// period used by the price barrier, so 16 checkpoints cover it even with trading in every block.
+	/// `TwapOracle` rejects periods which the checkpoints no longer cover.
+	type MaxTwapCheckpoints = ConstU32<16>;
 	type WeightInfo = weights::pallet_ema_oracle::HydraWeight<Runtime>;
 	#[cfg(feature = "runtime-benchmarks")]
 	/// Should take care of the overhead introduced by `OracleWhitelist`.
@@ -978,6 +1049,12 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 				}
 				PoolType::XYK => weights::pallet_xyk::HydraWeight::<Runtime>::router_execution_sell(c, e)
 					.saturating_add(<Runtime as pallet_xyk::Config>::AMMHandler::on_trade_weight()),
+				PoolType::Concentrated(_) => {
+					weights::pallet_concentrated_liquidity::HydraWeight::<Runtime>::router_execution_sell(c, e)
+						.saturating_add(
+							<Runtime as pallet_concentrated_liquidity::Config>::AMMHandler::on_trade_weight(),
+						)
+				}
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -1026,6 +1103,12 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 				}
 				PoolType::XYK => weights::pallet_xyk::HydraWeight::<Runtime>::router_execution_buy(c, e)
 					.saturating_add(<Runtime as pallet_xyk::Config>::AMMHandler::on_trade_weight()),
+				PoolType::Concentrated(_) => {
+					weights::pallet_concentrated_liquidity::HydraWeight::<Runtime>::router_execution_buy(c, e)
+						.saturating_add(
+							<Runtime as pallet_concentrated_liquidity::Config>::AMMHandler::on_trade_weight(),
+						)
+				}
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -1058,6 +1141,12 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 				}
 				PoolType::XYK => weights::pallet_xyk::HydraWeight::<Runtime>::router_execution_buy(c, e)
 					.saturating_add(<Runtime as pallet_xyk::Config>::AMMHandler::on_trade_weight()),
+				PoolType::Concentrated(_) => {
+					weights::pallet_concentrated_liquidity::HydraWeight::<Runtime>::router_execution_buy(c, e)
+						.saturating_add(
+							<Runtime as pallet_concentrated_liquidity::Config>::AMMHandler::on_trade_weight(),
+						)
+				}
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -1082,6 +1171,12 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 				}
 				PoolType::XYK => weights::pallet_xyk::HydraWeight::<Runtime>::router_execution_sell(c, e)
 					.saturating_add(<Runtime as pallet_xyk::Config>::AMMHandler::on_trade_weight()),
+				PoolType::Concentrated(_) => {
+					weights::pallet_concentrated_liquidity::HydraWeight::<Runtime>::router_execution_sell(c, e)
+						.saturating_add(
+							<Runtime as pallet_concentrated_liquidity::Config>::AMMHandler::on_trade_weight(),
+						)
+				}
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -1106,6 +1201,12 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 				}
 				PoolType::XYK => weights::pallet_xyk::HydraWeight::<Runtime>::router_execution_buy(c, e)
 					.saturating_add(<Runtime as pallet_xyk::Config>::AMMHandler::on_trade_weight()),
+				PoolType::Concentrated(_) => {
+					weights::pallet_concentrated_liquidity::HydraWeight::<Runtime>::router_execution_buy(c, e)
+						.saturating_add(
+							<Runtime as pallet_concentrated_liquidity::Config>::AMMHandler::on_trade_weight(),
+						)
+				}
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -1138,6 +1239,9 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 					weights::pallet_stableswap::HydraWeight::<Runtime>::router_execution_sell(1, 0)
 				}
 				PoolType::XYK => weights::pallet_xyk::HydraWeight::<Runtime>::router_execution_sell(1, 0),
+				PoolType::Concentrated(_) => {
+					weights::pallet_concentrated_liquidity::HydraWeight::<Runtime>::router_execution_sell(1, 0)
+				}
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -1151,6 +1255,9 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 					weights::pallet_stableswap::HydraWeight::<Runtime>::router_execution_sell(1, 0)
 				}
 				PoolType::XYK => weights::pallet_xyk::HydraWeight::<Runtime>::router_execution_sell(1, 0),
+				PoolType::Concentrated(_) => {
+					weights::pallet_concentrated_liquidity::HydraWeight::<Runtime>::router_execution_sell(1, 0)
+				}
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -1183,6 +1290,9 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 					weights::pallet_stableswap::HydraWeight::<Runtime>::calculate_spot_price_with_fee()
 				}
 				PoolType::XYK => weights::pallet_xyk::HydraWeight::<Runtime>::calculate_spot_price_with_fee(),
+				PoolType::Concentrated(_) => {
+					weights::pallet_concentrated_liquidity::HydraWeight::<Runtime>::calculate_spot_price_with_fee()
+				}
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -1193,12 +1303,65 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 	fn get_route_weight() -> Weight {
 		weights::pallet_route_executor::HydraWeight::<Runtime>::get_route()
 	}
+
+	// Used in Router::find_best_sell_route, which calls AMM::calculate_sell at most MaxRouteDiscoveryCalculations times
+	fn find_best_route_weight() -> Weight {
+		let max_calculations: u64 = MaxRouteDiscoveryCalculations::get().into();
+
+		// We take the most expensive calculate_sell of all the AMMs
+		let calculate_sell_weight = [
+			weights::pallet_omnipool::HydraWeight::<Runtime>::router_execution_sell(1, 0),
+			weights::pallet_lbp::HydraWeight::<Runtime>::router_execution_sell(1, 0),
+			weights::pallet_stableswap::HydraWeight::<Runtime>::router_execution_sell(1, 0),
+			weights::pallet_xyk::HydraWeight::<Runtime>::router_execution_sell(1, 0),
+			weights::pallet_concentrated_liquidity::HydraWeight::<Runtime>::router_execution_sell(1, 0),
+		]
+		.into_iter()
+		.fold(Weight::zero(), |max_weight, weight| max_weight.max(weight));
+
+		// Building the pool graph reads at most MaxRouteDiscoveryPools pools of every AMM
+		let pools_read_weight =
+			<Runtime as pallet_route_executor::Config>::PoolsProvider::get_pools_weight(MaxRouteDiscoveryPools::get());
+
+		calculate_sell_weight
+			.saturating_mul(max_calculations)
+			.saturating_add(pools_read_weight)
+	}
+
+	// Used in Router::sell_with_best_route extrinsic, which finds the best route and executes it with the longest possible route
+	fn sell_with_best_route_weight() -> Weight {
+		let worst_case_sell_weight = [
+			PoolType::Omnipool,
+			PoolType::LBP,
+			PoolType::Stableswap(0),
+			PoolType::XYK,
+			PoolType::Concentrated(0),
+		]
+		.into_iter()
+		.map(|pool| {
+			let route = vec![
+				Trade {
+					pool,
+					asset_in: NativeAssetId::get(),
+					asset_out: NativeAssetId::get(),
+				};
+				MAX_NUMBER_OF_TRADES as usize
+			];
+			Self::sell_weight(&route)
+		})
+		.fold(Weight::zero(), |max_weight, weight| max_weight.max(weight));
+
+		Self::find_best_route_weight()
+			.saturating_add(worst_case_sell_weight)
+			.saturating_add(Self::skip_ed_handling_overweight())
+	}
 }
 
 parameter_types! {
 	pub const DefaultRoutePoolType: PoolType<AssetId> = PoolType::Omnipool;
 	pub const RouteValidationOraclePeriod: OraclePeriod = OraclePeriod::TenMinutes;
-
+	pub const MaxRouteDiscoveryCalculations: u32 = 500;
+	pub const MaxRouteDiscoveryPools: u32 = 200;
 }
 
 impl pallet_route_executor::Config for Runtime {
@@ -1207,7 +1370,7 @@ impl pallet_route_executor::Config for Runtime {
 	type Balance = Balance;
 	type Currency = FungibleCurrencies<Runtime>;
 	type WeightInfo = RouterWeightInfo;
-	type AMM = (Omnipool, Stableswap, XYK, LBP);
+	type AMM = (Omnipool, Stableswap, XYK, LBP, ConcentratedLiquidity);
 	type DefaultRoutePoolType = DefaultRoutePoolType;
 	type NativeAssetId = NativeAssetId;
 	type InspectRegistry = AssetRegistry;
@@ -1215,6 +1378,9 @@ impl pallet_route_executor::Config for Runtime {
 	type EdToRefundCalculator = RefundAndLockedEdCalculator;
 	type OraclePriceProvider = hydradx_adapters::OraclePriceProvider<AssetId, EmaOracle, LRNA>;
 	type OraclePeriod = RouteValidationOraclePeriod;
+	type PoolsProvider = (Omnipool, Stableswap, XYK, LBP, ConcentratedLiquidity);
+	type MaxRouteDiscoveryCalculations = MaxRouteDiscoveryCalculations;
+	type MaxRouteDiscoveryPools = MaxRouteDiscoveryPools;
 }
 
 parameter_types! {
@@ -1279,6 +1445,8 @@ impl pallet_dynamic_fees::Config for Runtime {
 	type Oracle = OracleAssetVolumeProvider<Runtime, LRNA, DynamicFeesOraclePeriod>;
 	type AssetFeeParameters = AssetFeeParams;
 	type ProtocolFeeParameters = ProtocolFeeParams;
+	type AuthorityOrigin = SuperMajorityTechCommittee;
+	type WeightInfo = weights::pallet_dynamic_fees::HydraWeight<Runtime>;
 }
 
 // Stableswap
@@ -1318,7 +1486,7 @@ use hydradx_traits::fee::{InspectTransactionFeeCurrency, SwappablePaymentAssetTr
 use hydradx_traits::price::PriceProvider;
 #[cfg(feature = "runtime-benchmarks")]
 use hydradx_traits::registry::Create;
-use hydradx_traits::router::RefundEdCalculator;
+use hydradx_traits::router::{PoolsProvider, RefundEdCalculator};
 use pallet_referrals::traits::Convert;
 use pallet_referrals::{FeeDistribution, Level};
 #[cfg(feature = "runtime-benchmarks")]
@@ -1355,6 +1523,13 @@ impl<T: pallet_asset_registry::Config> BenchmarkHelper<AssetId> for RegisterAsse
 	}
 }
 
+#[cfg(feature = "runtime-benchmarks")]
+impl<T: pallet_asset_registry::Config> pallet_concentrated_liquidity::BenchmarkHelper<AssetId> for RegisterAsset<T> {
+	fn register_asset(asset_id: AssetId, decimals: u8) -> DispatchResult {
+		<Self as BenchmarkHelper<AssetId>>::register_asset(asset_id, decimals)
+	}
+}
+
 #[cfg(feature = "runtime-benchmarks")]
 impl<T: pallet_ema_oracle::Config> pallet_ema_oracle::BenchmarkHelper<AssetId> for RegisterAsset<T> {
 	fn register_asset(asset_id: AssetId) -> DispatchResult {
@@ -1381,6 +1556,10 @@ impl<T: pallet_ema_oracle::Config> pallet_ema_oracle::BenchmarkHelper<AssetId> f
 	}
 }
 
+parameter_types! {
+	pub const StableswapOracleSource: Source = STABLESWAP_SOURCE;
+}
+
 impl pallet_stableswap::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type BlockNumberProvider = System;
@@ -1391,6 +1570,10 @@ impl pallet_stableswap::Config for Runtime {
 	type AuthorityOrigin = EnsureRoot<AccountId>;
 	type DustAccountHandler = Duster;
 	type Hooks = StableswapHooksAdapter<Runtime>;
+	type TargetRateOracle = EmaOracle;
+	type ExternalRateProvider = ();
+	type VolumeOracle = StableswapShareVolumeProvider<Runtime, DynamicFeesOraclePeriod>;
+	type PriceBarrier = pallet_circuit_breaker::OraclePriceBarrier<Runtime, StableswapOracleSource>;
 	type MinPoolLiquidity = MinPoolLiquidity;
 	type MinTradingLimit = MinTradingLimit;
 	type AmplificationRange = StableswapAmplificationRange;
@@ -1399,6 +1582,13 @@ impl pallet_stableswap::Config for Runtime {
 	type BenchmarkHelper = RegisterAsset<Runtime>;
 }
 
+impl pallet_omnipool_subpools::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type TradeExecutor = (Omnipool, Stableswap);
+	type WeightInfo = weights::pallet_omnipool_subpools::HydraWeight<Runtime>;
+}
+
 // Bonds
 parameter_types! {
 	pub ProtocolFee: Permill = Permill::from_percent(2);
@@ -1510,6 +1700,11 @@ where
 	}
 }
 
+parameter_types! {
+	pub const LBPMaxAllowlistLength: u32 = 1_000;
+	pub const LBPMaxPurchasedRemovals: u32 = 1_000;
+}
+
 impl pallet_lbp::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type MultiCurrency = Currencies;
@@ -1523,6 +1718,12 @@ impl pallet_lbp::Config for Runtime {
 	type MaxInRatio = MaxInRatio;
 	type MaxOutRatio = MaxOutRatio;
 	type BlockNumberProvider = RelayChainBlockNumberProvider<Runtime>;
+	// LBP pools are not tracked by the oracle
+	type PriceBarrier = ();
+	type MaxAllowlistLength = LBPMaxAllowlistLength;
+	type MaxPurchasedRemovals = LBPMaxPurchasedRemovals;
+	type NativeAssetId = NativeAssetId;
+	type NativeVesting = LBPNativeVesting;
 }
 
 parameter_types! {
@@ -1548,6 +1749,38 @@ impl pallet_xyk::Config for Runtime {
 	type DiscountedFee = DiscountedFee;
 	type NonDustableWhitelistHandler = Duster;
 	type OracleSource = XYKOracleSourceIdentifier;
+	type PriceBarrier = pallet_circuit_breaker::OraclePriceBarrier<Runtime, XYKOracleSourceIdentifier>;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type ProtocolFeeReceiver = TreasuryAccount;
+}
+
+parameter_types! {
+	pub const ConcentratedLiquidityCollectionId: CollectionId = 4242_u128;
+	pub const ConcentratedLiquidityOracleSource: Source = CONCENTRATED_SOURCE;
+	pub const ConcentratedLiquidityMaxInitializedTicks: u32 = 1_000;
+	pub const ConcentratedLiquidityMaxTickCrossings: u32 = 16;
+}
+
+impl pallet_concentrated_liquidity::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type Currency = Currencies;
+	type AssetRegistry = AssetRegistry;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type PositionItemId = ItemId;
+	type CollectionId = CollectionId;
+	type NFTCollectionId = ConcentratedLiquidityCollectionId;
+	type NFTHandler = Uniques;
+	type DustAccountHandler = Duster;
+	type AMMHandler = pallet_ema_oracle::OnActivityHandler<Runtime>;
+	type OracleSource = ConcentratedLiquidityOracleSource;
+	type PriceBarrier = pallet_circuit_breaker::OraclePriceBarrier<Runtime, ConcentratedLiquidityOracleSource>;
+	type MinTradingLimit = MinTradingLimit;
+	type MaxInitializedTicks = ConcentratedLiquidityMaxInitializedTicks;
+	type MaxTickCrossings = ConcentratedLiquidityMaxTickCrossings;
+	type WeightInfo = weights::pallet_concentrated_liquidity::HydraWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = RegisterAsset<Runtime>;
 }
 
 parameter_types! {
@@ -1626,6 +1859,40 @@ impl pallet_liquidation::Config for Runtime {
 	type WeightInfo = weights::pallet_liquidation::HydraWeight<Runtime>;
 }
 
+parameter_types! {
+	pub const TreasuryConversionOraclePeriod: OraclePeriod = OraclePeriod::TenMinutes;
+	pub const TreasuryConversionMaxSlippage: Permill = Permill::from_percent(3);
+	pub const MaxTreasuryConversionsPerBlock: u32 = 5;
+	// 10 minutes, doubled with every consecutive failure up to a week
+	pub const TreasuryConversionRetryDelay: BlockNumber = 100;
+}
+
+impl pallet_treasury_conversion::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type AssetId = AssetId;
+	type Currency = FungibleCurrencies<Runtime>;
+	#[cfg(not(feature = "runtime-benchmarks"))]
+	type RouteExecutor = Router;
+	#[cfg(feature = "runtime-benchmarks")]
+	type RouteExecutor = pallet_route_executor::DummyRouter<Runtime>;
+	type RouteProvider = Router;
+	#[cfg(not(feature = "runtime-benchmarks"))]
+	type PriceProvider = OraclePriceProviderUsingRoute<
+		Router,
+		OraclePriceProvider<AssetId, EmaOracle, LRNA>,
+		TreasuryConversionOraclePeriod,
+	>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type PriceProvider = ReferralsDummyPriceProvider;
+	type TreasuryAccount = TreasuryAccount;
+	type MaxSlippage = TreasuryConversionMaxSlippage;
+	type MaxConversionsPerBlock = MaxTreasuryConversionsPerBlock;
+	type ConversionRetryDelay = TreasuryConversionRetryDelay;
+	type AmmTradeWeights = RouterWeightInfo;
+	type WeightInfo = weights::pallet_treasury_conversion::HydraWeight<Runtime>;
+}
+
 pub struct ConvertViaOmnipool<SP>(PhantomData<SP>);
 impl<SP> Convert<AccountId, AssetId, Balance> for ConvertViaOmnipool<SP>
 where
@@ -1811,8 +2078,13 @@ impl SwappablePaymentAssetTrader<AccountId, AssetId, Balance> for XykPaymentAsse
 		XYK::exists(pallet_xyk::types::AssetPair::new(from, into))
 	}
 
-	fn calculate_fee_amount(swap_amount: Balance) -> Result<Balance, DispatchError> {
-		let xyk_exchange_rate = XYKExchangeFee::get();
+	fn calculate_fee_amount(
+		asset_in: AssetId,
+		asset_out: AssetId,
+		swap_amount: Balance,
+	) -> Result<Balance, DispatchError> {
+		let asset_pair_account = XYK::get_pair_id(AssetPair::new(asset_in, asset_out));
+		let xyk_exchange_rate = XYK::get_fee(&asset_pair_account);
 
 		hydra_dx_math::fee::calculate_pool_trade_fee(swap_amount, xyk_exchange_rate)
 			.ok_or(ArithmeticError::Overflow.into())
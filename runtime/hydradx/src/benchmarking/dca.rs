@@ -34,7 +34,7 @@ use hydradx_traits::router::PoolType;
 use orml_benchmarking::runtime_benchmarks;
 use orml_traits::{MultiCurrency, MultiCurrencyExtended, NamedMultiReservableCurrency};
 use pallet_dca::types::{Order, Schedule, ScheduleId};
-use pallet_dca::{ScheduleIdsPerBlock, Schedules};
+use pallet_dca::{PausedSchedules, ScheduleIdsPerBlock, Schedules};
 use pallet_route_executor::Trade;
 use pallet_route_executor::MAX_NUMBER_OF_TRADES;
 use scale_info::prelude::vec::Vec;
@@ -79,6 +79,7 @@ fn schedule_fake(
 				asset_out,
 			}]),
 		},
+		trigger: None,
 	};
 	schedule1
 }
@@ -111,6 +112,7 @@ fn schedule_buy_fake(
 				asset_out,
 			}]),
 		},
+		trigger: None,
 	};
 	schedule1
 }
@@ -139,6 +141,7 @@ fn schedule_sell_fake(
 				asset_out,
 			}]),
 		},
+		trigger: None,
 	};
 	schedule1
 }
@@ -453,6 +456,7 @@ runtime_benchmarks! {
 				max_amount_in: Balance::MAX,
 				route: create_bounded_vec(route),
 			},
+			trigger: None,
 		};
 
 		let execution_block = 105u32;
@@ -494,6 +498,87 @@ runtime_benchmarks! {
 		assert!(<Schedules<Runtime>>::get::<ScheduleId>(schedule_id).is_none());
 	}
 
+	pause {
+		let caller: AccountId = create_account_with_native_balance()?;
+
+		<Currencies as MultiCurrencyExtended<AccountId>>::update_balance(HDX, &caller, 100_000_000_000_000_000i128)?;
+
+		let amount_sell = 200 * ONE;
+		let schedule1 = schedule_fake(caller.clone(), HDX, DAI, amount_sell);
+		let schedule_id : ScheduleId = 0;
+
+		set_period(99);
+		let execution_block = 100u32;
+		assert_ok!(DCA::schedule(RawOrigin::Signed(caller.clone()).into(), schedule1, Option::Some(execution_block)));
+
+	}: _(RawOrigin::Signed(caller), schedule_id, 105)
+	verify {
+		assert!(<PausedSchedules<Runtime>>::get::<ScheduleId>(schedule_id).is_some());
+		assert!(DCA::schedule_ids_per_block(105).is_empty());
+	}
+
+	resume {
+		let caller: AccountId = create_account_with_native_balance()?;
+
+		<Currencies as MultiCurrencyExtended<AccountId>>::update_balance(HDX, &caller, 100_000_000_000_000_000i128)?;
+
+		let amount_sell = 200 * ONE;
+		let schedule1 = schedule_fake(caller.clone(), HDX, DAI, amount_sell);
+		let schedule_id : ScheduleId = 0;
+
+		set_period(99);
+		let execution_block = 105u32;
+		assert_ok!(DCA::schedule(RawOrigin::Signed(caller.clone()).into(), schedule1.clone(), Option::Some(execution_block)));
+		assert_ok!(DCA::pause(RawOrigin::Signed(caller.clone()).into(), schedule_id, execution_block));
+
+		//We fill blocks with schedules leaving only one place
+		let number_of_all_schedules = MaxSchedulesPerBlock::get() + MaxSchedulesPerBlock::get() * RETRY_TO_SEARCH_FOR_FREE_BLOCK - 1;
+		for i in 0..number_of_all_schedules {
+			assert_ok!(DCA::schedule(RawOrigin::Signed(caller.clone()).into(), schedule1.clone(), Option::Some(execution_block)));
+		}
+
+	}: _(RawOrigin::Signed(caller), schedule_id, Some(execution_block))
+	verify {
+		assert!(<PausedSchedules<Runtime>>::get::<ScheduleId>(schedule_id).is_none());
+		assert_eq!((MaxSchedulesPerBlock::get()) as usize, <ScheduleIdsPerBlock<Runtime>>::get::<BlockNumber>(execution_block + DELAY_AFTER_LAST_RADIUS).len());
+	}
+
+	top_up_budget {
+		let caller: AccountId = create_account_with_native_balance()?;
+
+		<Currencies as MultiCurrencyExtended<AccountId>>::update_balance(HDX, &caller, 100_000_000_000_000_000i128)?;
+
+		let amount_sell = 200 * ONE;
+		let schedule1 = schedule_fake(caller.clone(), HDX, DAI, amount_sell);
+		let total_amount = schedule1.total_amount;
+		let schedule_id : ScheduleId = 0;
+		let top_up_amount = 1000 * ONE;
+
+		set_period(99);
+		assert_ok!(DCA::schedule(RawOrigin::Signed(caller.clone()).into(), schedule1, Option::Some(100u32)));
+
+	}: _(RawOrigin::Signed(caller), schedule_id, top_up_amount)
+	verify {
+		assert_eq!(DCA::remaining_amounts(schedule_id), Some(total_amount + top_up_amount));
+	}
+
+	update_schedule {
+		let caller: AccountId = create_account_with_native_balance()?;
+
+		<Currencies as MultiCurrencyExtended<AccountId>>::update_balance(HDX, &caller, 100_000_000_000_000_000i128)?;
+
+		let amount_sell = 200 * ONE;
+		let schedule1 = schedule_fake(caller.clone(), HDX, DAI, amount_sell);
+		let schedule_id : ScheduleId = 0;
+
+		set_period(99);
+		assert_ok!(DCA::schedule(RawOrigin::Signed(caller.clone()).into(), schedule1, Option::Some(100u32)));
+
+	}: _(RawOrigin::Signed(caller), schedule_id, 200u32, Some(Permill::from_percent(10)), Some(Permill::from_percent(5)))
+	verify {
+		assert_eq!(<Schedules<Runtime>>::get::<ScheduleId>(schedule_id).unwrap().period, 200u32);
+	}
+
 }
 
 pub const INITIAL_BALANCE: Balance = 10_000_000 * ONE;
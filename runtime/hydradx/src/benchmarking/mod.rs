@@ -6,6 +6,7 @@ pub mod duster;
 pub mod dynamic_evm_fee;
 pub mod multi_payment;
 pub mod omnipool;
+pub mod omnipool_subpools;
 pub mod route_executor;
 pub mod tokens;
 pub mod vesting;
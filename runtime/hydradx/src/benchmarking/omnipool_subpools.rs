@@ -0,0 +1,208 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{AccountId, AssetId, Balance, Omnipool, OmnipoolSubpools, Runtime};
+
+use super::omnipool::{init, update_balance};
+use super::*;
+
+use frame_benchmarking::account;
+use frame_benchmarking::BenchmarkError;
+use frame_support::sp_runtime::{traits::One, FixedU128, Permill};
+use frame_system::RawOrigin;
+use orml_benchmarking::runtime_benchmarks;
+use orml_traits::MultiCurrency;
+use sp_std::num::NonZeroU16;
+
+const ONE: Balance = 1_000_000_000_000;
+
+fn add_omnipool_token(name: &[u8]) -> Result<AssetId, BenchmarkError> {
+	let token_id =
+		register_asset(name.to_vec(), Balance::one()).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+	let owner: AccountId = account("owner", 0, 1);
+
+	update_balance(token_id, &Omnipool::protocol_account(), 1_000_000 * ONE);
+	Omnipool::add_token(
+		RawOrigin::Root.into(),
+		token_id,
+		FixedU128::from((1, 2)),
+		Permill::from_percent(100),
+		owner,
+	)?;
+
+	Ok(token_id)
+}
+
+/// Create subpool from two new Omnipool tokens. Returns share asset id and the pool assets.
+fn create_subpool() -> Result<(AssetId, AssetId, AssetId), BenchmarkError> {
+	init()?;
+
+	let asset_a = add_omnipool_token(b"STB1")?;
+	let asset_b = add_omnipool_token(b"STB2")?;
+	let share_asset = register_asset(b"SHARE".to_vec(), Balance::one())
+		.map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+
+	OmnipoolSubpools::create_subpool(
+		RawOrigin::Root.into(),
+		share_asset,
+		asset_a,
+		asset_b,
+		Permill::from_percent(100),
+		NonZeroU16::new(100).unwrap(),
+		Permill::from_percent(0),
+	)?;
+
+	Ok((share_asset, asset_a, asset_b))
+}
+
+/// Create pools for a trade routed through `hops` pools. Returns asset in and asset out of the trade.
+fn trade_assets(hops: u32) -> Result<(AssetId, AssetId), BenchmarkError> {
+	let (_, asset_a, _) = create_subpool()?;
+	let asset_c = add_omnipool_token(b"STB3")?;
+
+	match hops {
+		1 => Ok((add_omnipool_token(b"STB4")?, asset_c)),
+		2 => Ok((asset_a, asset_c)),
+		_ => {
+			let asset_d = add_omnipool_token(b"STB4")?;
+			let share_asset_2 = register_asset(b"SHARE2".to_vec(), Balance::one())
+				.map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+			OmnipoolSubpools::create_subpool(
+				RawOrigin::Root.into(),
+				share_asset_2,
+				asset_c,
+				asset_d,
+				Permill::from_percent(100),
+				NonZeroU16::new(100).unwrap(),
+				Permill::from_percent(0),
+			)?;
+			Ok((asset_a, asset_c))
+		}
+	}
+}
+
+runtime_benchmarks! {
+	{Runtime, pallet_omnipool_subpools}
+
+	create_subpool {
+		init()?;
+		let asset_a = add_omnipool_token(b"STB1")?;
+		let asset_b = add_omnipool_token(b"STB2")?;
+		let share_asset = register_asset(b"SHARE".to_vec(), Balance::one()).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+	}: _(RawOrigin::Root, share_asset, asset_a, asset_b, Permill::from_percent(100), NonZeroU16::new(100).unwrap(), Permill::from_percent(0))
+	verify {
+		assert!(OmnipoolSubpools::subpools(share_asset).is_some());
+		assert!(Omnipool::assets(asset_a).is_none());
+	}
+
+	migrate_asset_to_subpool {
+		let (share_asset, _, _) = create_subpool()?;
+		let asset_c = add_omnipool_token(b"STB3")?;
+	}: _(RawOrigin::Root, share_asset, asset_c)
+	verify {
+		assert!(OmnipoolSubpools::migrated_asset(asset_c).is_some());
+		assert!(Omnipool::assets(asset_c).is_none());
+	}
+
+	convert_position {
+		init()?;
+		let position_id = Omnipool::next_position_id();
+		let asset_a = add_omnipool_token(b"STB1")?;
+		let asset_b = add_omnipool_token(b"STB2")?;
+		let share_asset = register_asset(b"SHARE".to_vec(), Balance::one()).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+		OmnipoolSubpools::create_subpool(RawOrigin::Root.into(), share_asset, asset_a, asset_b, Permill::from_percent(100), NonZeroU16::new(100).unwrap(), Permill::from_percent(0))?;
+
+		let caller: AccountId = account("caller", 0, 1);
+	}: _(RawOrigin::Signed(caller), position_id)
+	verify {
+		assert_eq!(Omnipool::positions(position_id).unwrap().asset_id, share_asset);
+	}
+
+	sell {
+		let c in 1 .. 3;
+		let (asset_in, asset_out) = trade_assets(c)?;
+
+		let seller: AccountId = account("seller", 0, 1);
+		let amount = 10 * ONE;
+		update_balance(asset_in, &seller, 100 * ONE);
+	}: _(RawOrigin::Signed(seller.clone()), asset_in, asset_out, amount, 0)
+	verify {
+		assert_eq!(OmnipoolSubpools::trade_route(asset_in, asset_out).len(), c as usize);
+		assert_eq!(<Runtime as pallet_omnipool::Config>::Currency::free_balance(asset_in, &seller), 90 * ONE);
+		assert!(<Runtime as pallet_omnipool::Config>::Currency::free_balance(asset_out, &seller) > 0);
+	}
+
+	buy {
+		let c in 1 .. 3;
+		let (asset_in, asset_out) = trade_assets(c)?;
+
+		let buyer: AccountId = account("buyer", 0, 1);
+		let amount = ONE;
+		update_balance(asset_in, &buyer, 100 * ONE);
+	}: _(RawOrigin::Signed(buyer.clone()), asset_out, asset_in, amount, 100 * ONE)
+	verify {
+		assert_eq!(OmnipoolSubpools::trade_route(asset_in, asset_out).len(), c as usize);
+		assert_eq!(<Runtime as pallet_omnipool::Config>::Currency::free_balance(asset_out, &buyer), amount);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::NativeExistentialDeposit;
+	use orml_benchmarking::impl_benchmark_test_suite;
+	use sp_runtime::BuildStorage;
+
+	fn new_test_ext() -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::<crate::Runtime>::default()
+			.build_storage()
+			.unwrap();
+
+		pallet_asset_registry::GenesisConfig::<crate::Runtime> {
+			registered_assets: vec![
+				(
+					Some(1),
+					Some(b"LRNA".to_vec().try_into().unwrap()),
+					1_000u128,
+					None,
+					None,
+					None,
+					true,
+				),
+				(
+					Some(2),
+					Some(b"DAI".to_vec().try_into().unwrap()),
+					1_000u128,
+					None,
+					None,
+					None,
+					true,
+				),
+			],
+			native_asset_name: b"HDX".to_vec().try_into().unwrap(),
+			native_existential_deposit: NativeExistentialDeposit::get(),
+			native_decimals: 12,
+			native_symbol: b"HDX".to_vec().try_into().unwrap(),
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		sp_io::TestExternalities::new(t)
+	}
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
@@ -77,10 +77,15 @@ fn setup_lbp(caller: AccountId, asset_in: AssetId, asset_out: AssetId) -> Dispat
 		asset_out_amount,
 		initial_weight,
 		final_weight,
-		pallet_lbp::WeightCurveType::Linear,
+		pallet_lbp::WeightCurveType::ExponentialDecay {
+			rate: (pallet_lbp::MAX_EXPONENTIAL_DECAY_RATE, 1),
+		},
 		fee,
 		fee_collector,
 		repay_target,
+		Some(asset_out_amount),
+		false,
+		None,
 	)?;
 	ensure!(
 		pallet_lbp::PoolData::<Runtime>::contains_key(&pool_id),
@@ -101,6 +106,10 @@ fn setup_lbp(caller: AccountId, asset_in: AssetId, asset_out: AssetId) -> Dispat
 		None,
 		None,
 		None,
+		None,
+		None,
+		None,
+		None,
 	)?;
 
 	System::set_block_number(2u32);
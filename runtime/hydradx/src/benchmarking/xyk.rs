@@ -291,6 +291,69 @@ runtime_benchmarks! {
 	}: {
 		assert!(<XYK as TradeExecution<RuntimeOrigin, AccountId, AssetId, Balance>>::calculate_spot_price_with_fee(PoolType::XYK, asset_a, asset_b).is_ok());
 	}
+
+	create_pool_with_fee {
+		let asset_a = register_external_asset(b"TKNA".to_vec()).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+		let asset_b = register_external_asset(b"TKNB".to_vec()).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+		let fee_asset = register_asset(b"FEE".to_vec(), 1u128).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+
+		let caller = funded_account::<Runtime>("caller", 0, &[asset_a, asset_b, fee_asset]);
+
+		init_fee_asset(fee_asset)?;
+		MultiTransactionPayment::set_currency(RawOrigin::Signed(caller.clone()).into(), fee_asset)?;
+
+		let fee = (1, 100);
+		XYK::add_fee_tier(RawOrigin::Root.into(), fee)?;
+
+		let amount_a : Balance = INITIAL_BALANCE;
+		let amount_b : Balance = INITIAL_BALANCE;
+	}: _(RawOrigin::Signed(caller.clone()), asset_a, amount_a, asset_b, amount_b, fee)
+	verify {
+		assert_eq!(Currencies::free_balance(asset_a, &caller), 0);
+		assert_eq!(Currencies::free_balance(asset_b, &caller), 0);
+		assert_eq!(XYK::pool_fees(XYK::pair_account_from_assets(asset_a, asset_b)), Some(fee));
+	}
+
+	add_fee_tier {
+		let fee = (1, 100);
+	}: _(RawOrigin::Root, fee)
+	verify {
+		assert!(XYK::fee_tiers(fee).is_some());
+	}
+
+	remove_fee_tier {
+		let fee = (1, 100);
+		XYK::add_fee_tier(RawOrigin::Root.into(), fee)?;
+	}: _(RawOrigin::Root, fee)
+	verify {
+		assert!(XYK::fee_tiers(fee).is_none());
+	}
+
+	update_pool_fee {
+		let asset_a = register_external_asset(b"TKNA".to_vec()).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+		let asset_b = register_external_asset(b"TKNB".to_vec()).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+		let fee_asset = register_asset(b"FEE".to_vec(), 1u128).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+
+		let maker = funded_account::<Runtime>("maker", 0, &[asset_a, asset_b, fee_asset]);
+
+		init_fee_asset(fee_asset)?;
+		MultiTransactionPayment::set_currency(RawOrigin::Signed(maker.clone()).into(), fee_asset)?;
+
+		XYK::create_pool(RawOrigin::Signed(maker.clone()).into(), asset_a, INITIAL_BALANCE, asset_b, INITIAL_BALANCE)?;
+
+		let fee = (1, 100);
+		XYK::add_fee_tier(RawOrigin::Root.into(), fee)?;
+	}: _(RawOrigin::Signed(maker), asset_a, asset_b, fee)
+	verify {
+		assert_eq!(XYK::pool_fees(XYK::pair_account_from_assets(asset_a, asset_b)), Some(fee));
+	}
+
+	set_protocol_fee {
+		let fee = Some((1, 6));
+	}: _(RawOrigin::Root, fee)
+	verify {
+		assert_eq!(XYK::protocol_fee(), fee);
+	}
 }
 
 #[cfg(test)]
@@ -124,7 +124,7 @@ where
 				let amount_in =
 					SwappablePaymentAssetSupport::calculate_in_given_out(account_fee_currency, dot, fee_in_dot)
 						.map_err(|_| Error::<T>::WithdrawFailed)?;
-				let pool_fee = SwappablePaymentAssetSupport::calculate_fee_amount(amount_in)
+				let pool_fee = SwappablePaymentAssetSupport::calculate_fee_amount(account_fee_currency, dot, amount_in)
 					.map_err(|_| Error::<T>::WithdrawFailed)?;
 				let max_limit = amount_in.saturating_add(pool_fee);
 
@@ -0,0 +1,289 @@
+//                    :                     $$\   $$\                 $$\                    $$$$$$$\  $$\   $$\
+//                  !YJJ^                   $$ |  $$ |                $$ |                   $$  __$$\ $$ |  $$ |
+//                7B5. ~B5^                 $$ |  $$ |$$\   $$\  $$$$$$$ | $$$$$$\  $$$$$$\  $$ |  $$ |\$$\ $$  |
+//             .?B@G    ~@@P~               $$$$$$$$ |$$ |  $$ |$$  __$$ |$$  __$$\ \____$$\ $$ |  $$ | \$$$$  /
+//           :?#@@@Y    .&@@@P!.            $$  __$$ |$$ |  $$ |$$ /  $$ |$$ |  \__|$$$$$$$ |$$ |  $$ | $$  $$<
+//         ^?J^7P&@@!  .5@@#Y~!J!.          $$ |  $$ |$$ |  $$ |$$ |  $$ |$$ |     $$  __$$ |$$ |  $$ |$$  /\$$\
+//       ^JJ!.   :!J5^ ?5?^    ^?Y7.        $$ |  $$ |\$$$$$$$ |\$$$$$$$ |$$ |     \$$$$$$$ |$$$$$$$  |$$ /  $$ |
+//     ~PP: 7#B5!.         :?P#G: 7G?.      \__|  \__| \____$$ | \_______|\__|      \_______|\_______/ \__|  \__|
+//  .!P@G    7@@@#Y^    .!P@@@#.   ~@&J:              $$\   $$ |
+//  !&@@J    :&@@@@P.   !&@@@@5     #@@P.             \$$$$$$  |
+//   :J##:   Y@@&P!      :JB@@&~   ?@G!                \______/
+//     .?P!.?GY7:   .. .    ^?PP^:JP~
+//       .7Y7.  .!YGP^ ?BP?^   ^JJ^         This file is part of https://github.com/galacticcouncil/HydraDX-node
+//         .!Y7Y#@@#:   ?@@@G?JJ^           Built with <3 for decentralisation.
+//            !G@@@Y    .&@@&J:
+//              ^5@#.   7@#?.               Copyright (C) 2021-2023  Intergalactic, Limited (GIB).
+//                :5P^.?G7.                 SPDX-License-Identifier: Apache-2.0
+//                  :?Y!                    Licensed under the Apache License, Version 2.0 (the "License");
+//                                          you may not use this file except in compliance with the License.
+//                                          http://www.apache.org/licenses/LICENSE-2.0
+
+//! Precompile exposing EMA oracle prices through the Chainlink `AggregatorV3Interface`.
+//!
+//! Every asset pair and oracle period has its own precompile address. The price of the pair is composed
+//! from the Omnipool oracles of both assets against LRNA. It is the price of one `asset_a` in `asset_b`,
+//! adjusted by the decimals of the assets and expressed with `DECIMALS` decimals.
+
+use crate::evm::precompiles::{
+	handle::{EvmDataWriter, FunctionModifier, PrecompileHandleExt},
+	revert,
+	substrate::RuntimeHelper,
+	succeed, EvmResult, Output,
+};
+use crate::LRNA;
+use codec::{Decode, Encode, EncodeLike};
+use frame_support::traits::{Get, Time};
+use hex_literal::hex;
+use hydradx_adapters::OraclePriceProvider;
+use hydradx_traits::registry::Inspect as InspectRegistry;
+use hydradx_traits::router::{PoolType, Trade};
+use hydradx_traits::{AggregatedPriceOracle, OraclePeriod, PriceOracle};
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use pallet_ema_oracle::{ordered_pair, OracleError};
+use pallet_evm::{GasWeightMapping, Precompile, PrecompileHandle, PrecompileResult};
+use primitive_types::{H160, U256};
+use primitives::constants::{chain::OMNIPOOL_SOURCE, time::SECS_PER_BLOCK};
+use primitives::{AssetId, BlockNumber};
+use sp_runtime::traits::{UniqueSaturatedInto, Zero};
+use sp_runtime::RuntimeDebug;
+use sp_std::{marker::PhantomData, vec::Vec};
+
+/// Number of decimals of the price returned by the precompile.
+pub const DECIMALS: u8 = 18;
+
+/// Version of the aggregator interface.
+pub const VERSION: u8 = 1;
+
+#[module_evm_utility_macro::generate_function_selector]
+#[derive(RuntimeDebug, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u32)]
+pub enum Function {
+	Decimals = "decimals()",
+	Description = "description()",
+	Version = "version()",
+	GetRoundData = "getRoundData(uint80)",
+	LatestRoundData = "latestRoundData()",
+}
+
+/// Returns the precompile address of the oracle of given asset pair and period.
+///
+/// The period is encoded in the 12th byte, `asset_a` and `asset_b` in the last 8 bytes of the address.
+pub fn encode_oracle_address(asset_a: AssetId, asset_b: AssetId, period: OraclePeriod) -> H160 {
+	let mut address = [0u8; 20];
+	address[10] = 1;
+	address[11] = period.encode()[0];
+	address[12..16].copy_from_slice(&asset_a.to_be_bytes());
+	address[16..20].copy_from_slice(&asset_b.to_be_bytes());
+
+	H160::from(address)
+}
+
+/// Returns the asset pair and period of the oracle precompile address.
+pub fn decode_oracle_address(address: H160) -> Option<(AssetId, AssetId, OraclePeriod)> {
+	if !is_oracle_address(address) {
+		return None;
+	}
+
+	let bytes = address.to_fixed_bytes();
+	let period = OraclePeriod::decode(&mut &bytes[11..12]).ok()?;
+	let asset_a = AssetId::from_be_bytes(bytes[12..16].try_into().ok()?);
+	let asset_b = AssetId::from_be_bytes(bytes[16..20].try_into().ok()?);
+
+	Some((asset_a, asset_b, period))
+}
+
+pub fn is_oracle_address(address: H160) -> bool {
+	let oracle_address_prefix = &(H160::from(hex!("0000000000000000000001000000000000000000"))[0..11]);
+
+	&address.to_fixed_bytes()[0..11] == oracle_address_prefix
+}
+
+pub struct ChainlinkOraclePrecompile<Runtime>(PhantomData<Runtime>);
+
+impl<Runtime> Precompile for ChainlinkOraclePrecompile<Runtime>
+where
+	Runtime: frame_system::Config + pallet_evm::Config + pallet_ema_oracle::Config + pallet_asset_registry::Config,
+	AssetId: EncodeLike<<Runtime as pallet_asset_registry::Config>::AssetId>,
+	<Runtime as pallet_asset_registry::Config>::AssetId: From<AssetId>,
+	pallet_ema_oracle::Pallet<Runtime>:
+		AggregatedPriceOracle<AssetId, BlockNumber, pallet_ema_oracle::Price, Error = OracleError>,
+{
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		let Some((asset_a, asset_b, period)) = decode_oracle_address(handle.code_address()) else {
+			return Err(revert("invalid oracle address"));
+		};
+
+		let selector = handle.read_selector()?;
+
+		handle.check_function_modifier(FunctionModifier::View)?;
+
+		match selector {
+			Function::Decimals => Ok(succeed(Output::encode_uint::<u8>(DECIMALS))),
+			Function::Description => Self::description(asset_a, asset_b, handle),
+			Function::Version => Ok(succeed(Output::encode_uint::<u8>(VERSION))),
+			Function::GetRoundData => Self::get_round_data(asset_a, asset_b, period, handle),
+			Function::LatestRoundData => Self::latest_round_data(asset_a, asset_b, period, handle),
+		}
+	}
+}
+
+/// Data of a single round of an aggregator.
+struct RoundData {
+	round_id: U256,
+	answer: U256,
+	started_at: U256,
+	updated_at: U256,
+}
+
+impl<Runtime> ChainlinkOraclePrecompile<Runtime>
+where
+	Runtime: frame_system::Config + pallet_evm::Config + pallet_ema_oracle::Config + pallet_asset_registry::Config,
+	AssetId: EncodeLike<<Runtime as pallet_asset_registry::Config>::AssetId>,
+	<Runtime as pallet_asset_registry::Config>::AssetId: From<AssetId>,
+	pallet_ema_oracle::Pallet<Runtime>:
+		AggregatedPriceOracle<AssetId, BlockNumber, pallet_ema_oracle::Price, Error = OracleError>,
+{
+	/// Description of the pair in the `SYMBOL_A / SYMBOL_B` format used by Chainlink.
+	fn description(asset_a: AssetId, asset_b: AssetId, handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost().saturating_mul(2))?;
+
+		let symbol_a = Self::symbol(asset_a)?;
+		let symbol_b = Self::symbol(asset_b)?;
+
+		let description = [symbol_a.as_slice(), b" / ", symbol_b.as_slice()].concat();
+
+		Ok(succeed(Output::encode_bytes(&description)))
+	}
+
+	fn get_round_data(
+		asset_a: AssetId,
+		asset_b: AssetId,
+		period: OraclePeriod,
+		handle: &mut impl PrecompileHandle,
+	) -> PrecompileResult {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(1)?;
+		let round_id = input.read::<U256>()?;
+
+		// Only the latest round is available, historical prices are not kept by the oracle
+		let round_data = Self::round_data(asset_a, asset_b, period, handle)?;
+		if round_data.round_id != round_id {
+			return Err(revert("No data present"));
+		}
+
+		Ok(succeed(Self::encode_round_data(round_data)))
+	}
+
+	fn latest_round_data(
+		asset_a: AssetId,
+		asset_b: AssetId,
+		period: OraclePeriod,
+		handle: &mut impl PrecompileHandle,
+	) -> PrecompileResult {
+		let round_data = Self::round_data(asset_a, asset_b, period, handle)?;
+
+		Ok(succeed(Self::encode_round_data(round_data)))
+	}
+
+	/// The round is identified by the block the oracles of the pair were last updated in, the oldest one
+	/// of both assets. The timestamp of the round is derived from the number of blocks since the update.
+	fn round_data(
+		asset_a: AssetId,
+		asset_b: AssetId,
+		period: OraclePeriod,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<RoundData> {
+		// Prices of both assets against LRNA
+		handle.record_cost(
+			<Runtime as pallet_evm::Config>::GasWeightMapping::weight_to_gas(
+				<pallet_ema_oracle::Pallet<Runtime> as AggregatedPriceOracle<
+					AssetId,
+					BlockNumber,
+					pallet_ema_oracle::Price,
+				>>::get_price_weight(),
+			)
+			.saturating_mul(2),
+		)?;
+		// Last update of both oracles, decimals of both assets, block number and timestamp
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost().saturating_mul(6))?;
+
+		let route = [Trade {
+			pool: PoolType::Omnipool,
+			asset_in: asset_a,
+			asset_out: asset_b,
+		}];
+		let price = OraclePriceProvider::<AssetId, pallet_ema_oracle::Pallet<Runtime>, LRNA>::price(&route, period)
+			.filter(|price| !price.n.is_zero())
+			.ok_or_else(|| revert("No data present"))?;
+		let updated_in = Self::last_update(asset_a)
+			.into_iter()
+			.chain(Self::last_update(asset_b))
+			.min()
+			.ok_or_else(|| revert("No data present"))?;
+
+		let decimals_a = Self::decimals(asset_a)?;
+		let decimals_b = Self::decimals(asset_b)?;
+
+		// Oracle price is the ratio of `asset_a` amount to `asset_b` amount, the answer is the price of
+		// one `asset_a` in `asset_b`.
+		let ten = U256::from(10);
+		let answer = ten
+			.checked_pow(U256::from(decimals_a.saturating_add(DECIMALS)))
+			.and_then(|scale| U256::from(price.d).checked_mul(scale))
+			.zip(
+				ten.checked_pow(U256::from(decimals_b))
+					.and_then(|scale| U256::from(price.n).checked_mul(scale)),
+			)
+			.map(|(numerator, denominator)| numerator / denominator)
+			.ok_or_else(|| revert("price overflow"))?;
+
+		let current_block: u64 = frame_system::Pallet::<Runtime>::block_number().unique_saturated_into();
+		let blocks_since_update = current_block.saturating_sub(updated_in);
+
+		// Blockchain time is in ms while Ethereum use second timestamps.
+		let now: u64 = <Runtime as pallet_evm::Config>::Timestamp::now().unique_saturated_into();
+		let updated_at = (now / 1000).saturating_sub(blocks_since_update.saturating_mul(SECS_PER_BLOCK));
+
+		Ok(RoundData {
+			round_id: U256::from(updated_in),
+			answer,
+			started_at: U256::from(updated_at),
+			updated_at: U256::from(updated_at),
+		})
+	}
+
+	/// Block in which the Omnipool oracle of the asset was last updated. LRNA has no oracle of its own.
+	fn last_update(asset_id: AssetId) -> Option<u64> {
+		let lrna = LRNA::get();
+		if asset_id == lrna {
+			return None;
+		}
+		pallet_ema_oracle::Pallet::<Runtime>::oracle((
+			OMNIPOOL_SOURCE,
+			ordered_pair(asset_id, lrna),
+			OraclePeriod::LastBlock,
+		))
+		.map(|(entry, _)| entry.updated_at.unique_saturated_into())
+	}
+
+	/// Encodes `(uint80 roundId, int256 answer, uint256 startedAt, uint256 updatedAt, uint80 answeredInRound)`.
+	fn encode_round_data(round_data: RoundData) -> Vec<u8> {
+		EvmDataWriter::new()
+			.write(round_data.round_id)
+			.write(round_data.answer)
+			.write(round_data.started_at)
+			.write(round_data.updated_at)
+			.write(round_data.round_id)
+			.build()
+	}
+
+	fn symbol(asset_id: AssetId) -> EvmResult<Vec<u8>> {
+		<pallet_asset_registry::Pallet<Runtime>>::asset_symbol(asset_id.into())
+			.ok_or_else(|| revert("Non-existing asset."))
+	}
+
+	fn decimals(asset_id: AssetId) -> EvmResult<u8> {
+		<pallet_asset_registry::Pallet<Runtime>>::decimals(asset_id.into()).ok_or_else(|| revert("Non-existing asset."))
+	}
+}
@@ -0,0 +1,117 @@
+//                    :                     $$\   $$\                 $$\                    $$$$$$$\  $$\   $$\
+//                  !YJJ^                   $$ |  $$ |                $$ |                   $$  __$$\ $$ |  $$ |
+//                7B5. ~B5^                 $$ |  $$ |$$\   $$\  $$$$$$$ | $$$$$$\  $$$$$$\  $$ |  $$ |\$$\ $$  |
+//             .?B@G    ~@@P~               $$$$$$$$ |$$ |  $$ |$$  __$$ |$$  __$$\ \____$$\ $$ |  $$ | \$$$$  /
+//           :?#@@@Y    .&@@@P!.            $$  __$$ |$$ |  $$ |$$ /  $$ |$$ |  \__|$$$$$$$ |$$ |  $$ | $$  $$<
+//         ^?J^7P&@@!  .5@@#Y~!J!.          $$ |  $$ |$$ |  $$ |$$ |  $$ |$$ |     $$  __$$ |$$ |  $$ |$$  /\$$\
+//       ^JJ!.   :!J5^ ?5?^    ^?Y7.        $$ |  $$ |\$$$$$$$ |\$$$$$$$ |$$ |     \$$$$$$$ |$$$$$$$  |$$ /  $$ |
+//     ~PP: 7#B5!.         :?P#G: 7G?.      \__|  \__| \____$$ | \_______|\__|      \_______|\_______/ \__|  \__|
+//  .!P@G    7@@@#Y^    .!P@@@#.   ~@&J:              $$\   $$ |
+//  !&@@J    :&@@@@P.   !&@@@@5     #@@P.             \$$$$$$  |
+//   :J##:   Y@@&P!      :JB@@&~   ?@G!                \______/
+//     .?P!.?GY7:   .. .    ^?PP^:JP~
+//       .7Y7.  .!YGP^ ?BP?^   ^JJ^         This file is part of https://github.com/galacticcouncil/HydraDX-node
+//         .!Y7Y#@@#:   ?@@@G?JJ^           Built with <3 for decentralisation.
+//            !G@@@Y    .&@@&J:
+//              ^5@#.   7@#?.               Copyright (C) 2021-2023  Intergalactic, Limited (GIB).
+//                :5P^.?G7.                 SPDX-License-Identifier: Apache-2.0
+//                  :?Y!                    Licensed under the Apache License, Version 2.0 (the "License");
+//                                          you may not use this file except in compliance with the License.
+//                                          http://www.apache.org/licenses/LICENSE-2.0
+
+//! Precompile for voting in democracy referenda.
+//!
+//! Votes are cast on behalf of the account of the caller. Votes of accounts with a staking position are
+//! recorded by staking as well, so EVM accounts earn points for taking part in governance.
+
+use crate::evm::precompiles::{
+	handle::{FunctionModifier, PrecompileHandleExt},
+	revert,
+	substrate::RuntimeHelper,
+	succeed,
+};
+use crate::evm::ExtendedAddressMapping;
+use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
+use frame_support::traits::Currency;
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use pallet_democracy::{AccountVote, Conviction, ReferendumIndex, Vote};
+use pallet_evm::{AddressMapping, Precompile, PrecompileHandle, PrecompileResult};
+use primitives::Balance;
+use sp_runtime::{traits::Dispatchable, RuntimeDebug};
+use sp_std::marker::PhantomData;
+
+#[module_evm_utility_macro::generate_function_selector]
+#[derive(RuntimeDebug, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u32)]
+pub enum Function {
+	Vote = "vote(uint256,bool,uint256,uint8)",
+	RemoveVote = "removeVote(uint256)",
+}
+
+pub struct DemocracyPrecompile<Runtime>(PhantomData<Runtime>);
+
+impl<Runtime> Precompile for DemocracyPrecompile<Runtime>
+where
+	Runtime: frame_system::Config + pallet_evm::Config + pallet_democracy::Config,
+	<Runtime as pallet_democracy::Config>::Currency: Currency<Runtime::AccountId, Balance = Balance>,
+	Runtime::RuntimeCall:
+		Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo + From<pallet_democracy::Call<Runtime>>,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+	<Runtime as frame_system::Config>::AccountId: From<sp_runtime::AccountId32>,
+{
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		let selector = handle.read_selector()?;
+
+		handle.check_function_modifier(FunctionModifier::NonPayable)?;
+
+		match selector {
+			Function::Vote => Self::vote(handle),
+			Function::RemoveVote => Self::remove_vote(handle),
+		}
+	}
+}
+
+impl<Runtime> DemocracyPrecompile<Runtime>
+where
+	Runtime: frame_system::Config + pallet_evm::Config + pallet_democracy::Config,
+	<Runtime as pallet_democracy::Config>::Currency: Currency<Runtime::AccountId, Balance = Balance>,
+	Runtime::RuntimeCall:
+		Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo + From<pallet_democracy::Call<Runtime>>,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+	<Runtime as frame_system::Config>::AccountId: From<sp_runtime::AccountId32>,
+{
+	/// Casts a standard vote. `conviction` is the lock multiplier from 0 (`None`) to 6 (`Locked6x`).
+	fn vote(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(4)?;
+
+		let ref_index = input.read::<ReferendumIndex>()?;
+		let aye = input.read::<bool>()?;
+		let balance = input.read::<Balance>()?;
+		let conviction = Conviction::try_from(input.read::<u8>()?).map_err(|_| revert("invalid conviction"))?;
+
+		let vote = AccountVote::Standard {
+			vote: Vote { aye, conviction },
+			balance,
+		};
+
+		Self::dispatch(handle, pallet_democracy::Call::<Runtime>::vote { ref_index, vote })
+	}
+
+	fn remove_vote(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(1)?;
+
+		let index = input.read::<ReferendumIndex>()?;
+
+		Self::dispatch(handle, pallet_democracy::Call::<Runtime>::remove_vote { index })
+	}
+
+	fn dispatch(handle: &mut impl PrecompileHandle, call: pallet_democracy::Call<Runtime>) -> PrecompileResult {
+		let who: Runtime::AccountId = ExtendedAddressMapping::into_account_id(handle.context().caller).into();
+
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(who).into(), call)?;
+
+		Ok(succeed([]))
+	}
+}
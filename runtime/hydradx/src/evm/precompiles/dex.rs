@@ -0,0 +1,292 @@
+//                    :                     $$\   $$\                 $$\                    $$$$$$$\  $$\   $$\
+//                  !YJJ^                   $$ |  $$ |                $$ |                   $$  __$$\ $$ |  $$ |
+//                7B5. ~B5^                 $$ |  $$ |$$\   $$\  $$$$$$$ | $$$$$$\  $$$$$$\  $$ |  $$ |\$$\ $$  |
+//             .?B@G    ~@@P~               $$$$$$$$ |$$ |  $$ |$$  __$$ |$$  __$$\ \____$$\ $$ |  $$ | \$$$$  /
+//           :?#@@@Y    .&@@@P!.            $$  __$$ |$$ |  $$ |$$ /  $$ |$$ |  \__|$$$$$$$ |$$ |  $$ | $$  $$<
+//         ^?J^7P&@@!  .5@@#Y~!J!.          $$ |  $$ |$$ |  $$ |$$ |  $$ |$$ |     $$  __$$ |$$ |  $$ |$$  /\$$\
+//       ^JJ!.   :!J5^ ?5?^    ^?Y7.        $$ |  $$ |\$$$$$$$ |\$$$$$$$ |$$ |     \$$$$$$$ |$$$$$$$  |$$ /  $$ |
+//     ~PP: 7#B5!.         :?P#G: 7G?.      \__|  \__| \____$$ | \_______|\__|      \_______|\_______/ \__|  \__|
+//  .!P@G    7@@@#Y^    .!P@@@#.   ~@&J:              $$\   $$ |
+//  !&@@J    :&@@@@P.   !&@@@@5     #@@P.             \$$$$$$  |
+//   :J##:   Y@@&P!      :JB@@&~   ?@G!                \______/
+//     .?P!.?GY7:   .. .    ^?PP^:JP~
+//       .7Y7.  .!YGP^ ?BP?^   ^JJ^         This file is part of https://github.com/galacticcouncil/HydraDX-node
+//         .!Y7Y#@@#:   ?@@@G?JJ^           Built with <3 for decentralisation.
+//            !G@@@Y    .&@@&J:
+//              ^5@#.   7@#?.               Copyright (C) 2021-2023  Intergalactic, Limited (GIB).
+//                :5P^.?G7.                 SPDX-License-Identifier: Apache-2.0
+//                  :?Y!                    Licensed under the Apache License, Version 2.0 (the "License");
+//                                          you may not use this file except in compliance with the License.
+//                                          http://www.apache.org/licenses/LICENSE-2.0
+
+//! Precompile for trading through the Router.
+//!
+//! Assets are addressed by their ERC20 addresses. Trades are executed on the on-chain route of the asset pair,
+//! or on the default route if no on-chain route is set.
+
+use crate::evm::{
+	precompiles::{
+		erc20_mapping::HydraErc20Mapping,
+		handle::{FunctionModifier, PrecompileHandleExt},
+		revert,
+		substrate::RuntimeHelper,
+		succeed, Address, EvmResult, Output,
+	},
+	ExtendedAddressMapping,
+};
+use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
+use frame_support::traits::fungibles::Inspect;
+use frame_support::weights::Weight;
+use hydradx_traits::evm::Erc20Mapping;
+use hydradx_traits::router::{
+	AmmTradeWeights, AmountInAndOut, AssetPair, RouteProvider, RouteSpotPriceProvider, RouterT, Trade,
+};
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use pallet_evm::{AddressMapping, GasWeightMapping, Precompile, PrecompileHandle, PrecompileResult};
+use primitives::{AssetId, Balance};
+use sp_runtime::{traits::Dispatchable, DispatchError, RuntimeDebug};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+#[module_evm_utility_macro::generate_function_selector]
+#[derive(RuntimeDebug, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u32)]
+pub enum Function {
+	Sell = "sell(address,address,uint256,uint256)",
+	Buy = "buy(address,address,uint256,uint256)",
+	GetAmountOut = "getAmountOut(address,address,uint256)",
+	GetAmountIn = "getAmountIn(address,address,uint256)",
+	SpotPrice = "spotPrice(address,address)",
+}
+
+type RouterWeights<Runtime> = <Runtime as pallet_route_executor::Config>::WeightInfo;
+
+pub struct DexPrecompile<Runtime>(PhantomData<Runtime>);
+
+impl<Runtime> Precompile for DexPrecompile<Runtime>
+where
+	Runtime:
+		frame_system::Config + pallet_evm::Config + pallet_route_executor::Config<AssetId = AssetId, Balance = Balance>,
+	Runtime::RuntimeCall:
+		Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo + From<pallet_route_executor::Call<Runtime>>,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+	<Runtime as frame_system::Config>::AccountId: From<sp_runtime::AccountId32>,
+{
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		let selector = handle.read_selector()?;
+
+		handle.check_function_modifier(match selector {
+			Function::Sell | Function::Buy => FunctionModifier::NonPayable,
+			_ => FunctionModifier::View,
+		})?;
+
+		match selector {
+			Function::Sell => Self::sell(handle),
+			Function::Buy => Self::buy(handle),
+			Function::GetAmountOut => Self::get_amount_out(handle),
+			Function::GetAmountIn => Self::get_amount_in(handle),
+			Function::SpotPrice => Self::spot_price(handle),
+		}
+	}
+}
+
+impl<Runtime> DexPrecompile<Runtime>
+where
+	Runtime:
+		frame_system::Config + pallet_evm::Config + pallet_route_executor::Config<AssetId = AssetId, Balance = Balance>,
+	Runtime::RuntimeCall:
+		Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo + From<pallet_route_executor::Call<Runtime>>,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+	<Runtime as frame_system::Config>::AccountId: From<sp_runtime::AccountId32>,
+{
+	fn sell(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		// Parse input
+		let mut input = handle.read_input()?;
+		input.expect_arguments(4)?;
+
+		let asset_in = Self::asset_id(input.read::<Address>()?)?;
+		let asset_out = Self::asset_id(input.read::<Address>()?)?;
+		let amount_in = input.read::<Balance>()?;
+		let min_amount_out = input.read::<Balance>()?;
+
+		let route = Self::route(handle, asset_in, asset_out)?;
+
+		// Balance of the caller is read before and after the trade
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost().saturating_mul(2))?;
+
+		let who: Runtime::AccountId = ExtendedAddressMapping::into_account_id(handle.context().caller).into();
+
+		log::debug!(target: "evm", "dex: sell {:?} -> {:?}, amount_in: {:?}", asset_in, asset_out, amount_in);
+
+		let balance_before = Self::balance(asset_out, &who);
+
+		// Dispatched as a runtime call, so the trade is subject to the call filter and the transaction pause
+		let call = pallet_route_executor::Call::<Runtime>::sell {
+			asset_in,
+			asset_out,
+			amount_in,
+			min_amount_out,
+			route,
+		};
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(who.clone()).into(), call)?;
+
+		let amount_out = Self::balance(asset_out, &who).saturating_sub(balance_before);
+
+		Ok(succeed(Output::encode_uint::<u128>(amount_out)))
+	}
+
+	fn buy(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		// Parse input
+		let mut input = handle.read_input()?;
+		input.expect_arguments(4)?;
+
+		let asset_in = Self::asset_id(input.read::<Address>()?)?;
+		let asset_out = Self::asset_id(input.read::<Address>()?)?;
+		let amount_out = input.read::<Balance>()?;
+		let max_amount_in = input.read::<Balance>()?;
+
+		let route = Self::route(handle, asset_in, asset_out)?;
+
+		// Balance of the caller is read before and after the trade
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost().saturating_mul(2))?;
+
+		let who: Runtime::AccountId = ExtendedAddressMapping::into_account_id(handle.context().caller).into();
+
+		log::debug!(target: "evm", "dex: buy {:?} -> {:?}, amount_out: {:?}", asset_in, asset_out, amount_out);
+
+		let balance_before = Self::balance(asset_in, &who);
+
+		// Dispatched as a runtime call, so the trade is subject to the call filter and the transaction pause
+		let call = pallet_route_executor::Call::<Runtime>::buy {
+			asset_in,
+			asset_out,
+			amount_out,
+			max_amount_in,
+			route,
+		};
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(who.clone()).into(), call)?;
+
+		let amount_in = balance_before.saturating_sub(Self::balance(asset_in, &who));
+
+		Ok(succeed(Output::encode_uint::<u128>(amount_in)))
+	}
+
+	fn get_amount_out(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		// Parse input
+		let mut input = handle.read_input()?;
+		input.expect_arguments(3)?;
+
+		let asset_in = Self::asset_id(input.read::<Address>()?)?;
+		let asset_out = Self::asset_id(input.read::<Address>()?)?;
+		let amount_in = input.read::<Balance>()?;
+
+		let route = Self::route(handle, asset_in, asset_out)?;
+		// There is no dedicated weight for calculation of sell trade amounts, weight of the sell is an upper bound
+		Self::record_weight(handle, RouterWeights::<Runtime>::sell_weight(&route))?;
+
+		let trade_amounts = <pallet_route_executor::Pallet<Runtime> as RouterT<
+			<Runtime as frame_system::Config>::RuntimeOrigin,
+			AssetId,
+			Balance,
+			Trade<AssetId>,
+			AmountInAndOut<Balance>,
+		>>::calculate_sell_trade_amounts(&route, amount_in)
+		.map_err(Self::dispatch_error_to_revert)?;
+
+		let amount_out = trade_amounts
+			.last()
+			.map(|amounts| amounts.amount_out)
+			.ok_or_else(|| revert("route calculation failed"))?;
+
+		Ok(succeed(Output::encode_uint::<u128>(amount_out)))
+	}
+
+	fn get_amount_in(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		// Parse input
+		let mut input = handle.read_input()?;
+		input.expect_arguments(3)?;
+
+		let asset_in = Self::asset_id(input.read::<Address>()?)?;
+		let asset_out = Self::asset_id(input.read::<Address>()?)?;
+		let amount_out = input.read::<Balance>()?;
+
+		let route = Self::route(handle, asset_in, asset_out)?;
+		Self::record_weight(
+			handle,
+			RouterWeights::<Runtime>::calculate_buy_trade_amounts_weight(&route),
+		)?;
+
+		// Trade amounts of a buy are calculated backwards, the first trade of the route is the last one
+		let trade_amounts = <pallet_route_executor::Pallet<Runtime> as RouterT<
+			<Runtime as frame_system::Config>::RuntimeOrigin,
+			AssetId,
+			Balance,
+			Trade<AssetId>,
+			AmountInAndOut<Balance>,
+		>>::calculate_buy_trade_amounts(&route, amount_out)
+		.map_err(Self::dispatch_error_to_revert)?;
+
+		let amount_in = trade_amounts
+			.last()
+			.map(|amounts| amounts.amount_in)
+			.ok_or_else(|| revert("route calculation failed"))?;
+
+		Ok(succeed(Output::encode_uint::<u128>(amount_in)))
+	}
+
+	/// Spot price of `asset_out` denominated in `asset_in`, including fees, with 18 decimals precision.
+	fn spot_price(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		// Parse input
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+
+		let asset_in = Self::asset_id(input.read::<Address>()?)?;
+		let asset_out = Self::asset_id(input.read::<Address>()?)?;
+
+		let route = Self::route(handle, asset_in, asset_out)?;
+		Self::record_weight(
+			handle,
+			RouterWeights::<Runtime>::calculate_spot_price_with_fee_weight(&route),
+		)?;
+
+		let spot_price =
+			<pallet_route_executor::Pallet<Runtime> as RouteSpotPriceProvider<AssetId>>::spot_price_with_fee(&route)
+				.ok_or_else(|| revert("spot price not available"))?;
+
+		Ok(succeed(Output::encode_uint::<u128>(spot_price.into_inner())))
+	}
+
+	fn asset_id(address: Address) -> EvmResult<AssetId> {
+		HydraErc20Mapping::decode_evm_address(address.into()).ok_or_else(|| revert("invalid asset address"))
+	}
+
+	fn route(
+		handle: &mut impl PrecompileHandle,
+		asset_in: AssetId,
+		asset_out: AssetId,
+	) -> EvmResult<Vec<Trade<AssetId>>> {
+		if asset_in == asset_out {
+			return Err(revert("assets must be different"));
+		}
+
+		Self::record_weight(handle, RouterWeights::<Runtime>::get_route_weight())?;
+
+		Ok(
+			<pallet_route_executor::Pallet<Runtime> as RouteProvider<AssetId>>::get_route(AssetPair::new(
+				asset_in, asset_out,
+			)),
+		)
+	}
+
+	fn record_weight(handle: &mut impl PrecompileHandle, weight: Weight) -> EvmResult {
+		handle.record_cost(<Runtime as pallet_evm::Config>::GasWeightMapping::weight_to_gas(weight))?;
+		Ok(())
+	}
+
+	fn balance(asset_id: AssetId, who: &Runtime::AccountId) -> Balance {
+		<<Runtime as pallet_route_executor::Config>::Currency as Inspect<Runtime::AccountId>>::balance(asset_id, who)
+	}
+
+	fn dispatch_error_to_revert(e: DispatchError) -> pallet_evm::PrecompileFailure {
+		revert(Into::<&str>::into(e))
+	}
+}
@@ -21,7 +21,14 @@
 
 use core::marker::PhantomData;
 
-use crate::evm::precompiles::{erc20_mapping::is_asset_address, multicurrency::MultiCurrencyPrecompile};
+use crate::evm::precompiles::{
+	chainlink_adapter::{is_oracle_address, ChainlinkOraclePrecompile},
+	democracy::DemocracyPrecompile,
+	dex::DexPrecompile,
+	erc20_mapping::is_asset_address,
+	multicurrency::MultiCurrencyPrecompile,
+	staking::StakingPrecompile,
+};
 use codec::Decode;
 use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
 use pallet_evm::{
@@ -40,10 +47,14 @@ use hex_literal::hex;
 use primitive_types::{H160, U256};
 use sp_std::{borrow::ToOwned, vec::Vec};
 
+pub mod chainlink_adapter;
 pub mod costs;
+pub mod democracy;
+pub mod dex;
 pub mod erc20_mapping;
 pub mod handle;
 pub mod multicurrency;
+pub mod staking;
 pub mod substrate;
 
 pub type EvmResult<T = ()> = Result<T, PrecompileFailure>;
@@ -82,6 +93,9 @@ impl<R> HydraDXPrecompiles<R> {
 // See also
 // https://docs.moonbeam.network/builders/pallets-precompiles/precompiles/overview/#precompiled-contract-addresses
 pub const DISPATCH_ADDR: H160 = addr(1025);
+pub const DEX_ADDR: H160 = addr(1026);
+pub const STAKING_ADDR: H160 = addr(1027);
+pub const DEMOCRACY_ADDR: H160 = addr(1028);
 
 pub const ECRECOVER: H160 = H160(hex!("0000000000000000000000000000000000000001"));
 pub const SHA256: H160 = H160(hex!("0000000000000000000000000000000000000002"));
@@ -106,6 +120,10 @@ where
 	R::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo + Decode,
 	<R::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<R::AccountId>>,
 	MultiCurrencyPrecompile<R>: Precompile,
+	DexPrecompile<R>: Precompile,
+	ChainlinkOraclePrecompile<R>: Precompile,
+	StakingPrecompile<R>: Precompile,
+	DemocracyPrecompile<R>: Precompile,
 {
 	fn execute(&self, handle: &mut impl PrecompileHandle) -> Option<PrecompileResult> {
 		let context = handle.context();
@@ -143,8 +161,16 @@ where
 			))
 		} else if address == DISPATCH_ADDR {
 			Some(pallet_evm_precompile_dispatch::Dispatch::<R>::execute(handle))
+		} else if address == DEX_ADDR {
+			Some(DexPrecompile::<R>::execute(handle))
+		} else if address == STAKING_ADDR {
+			Some(StakingPrecompile::<R>::execute(handle))
+		} else if address == DEMOCRACY_ADDR {
+			Some(DemocracyPrecompile::<R>::execute(handle))
 		} else if is_asset_address(address) {
 			Some(MultiCurrencyPrecompile::<R>::execute(handle))
+		} else if is_oracle_address(address) {
+			Some(ChainlinkOraclePrecompile::<R>::execute(handle))
 		} else {
 			None
 		}
@@ -159,7 +185,13 @@ where
 }
 
 pub fn is_precompile(address: H160) -> bool {
-	address == DISPATCH_ADDR || is_asset_address(address) || is_standard_precompile(address)
+	address == DISPATCH_ADDR
+		|| address == DEX_ADDR
+		|| address == STAKING_ADDR
+		|| address == DEMOCRACY_ADDR
+		|| is_asset_address(address)
+		|| is_oracle_address(address)
+		|| is_standard_precompile(address)
 }
 
 // This is a reimplementation of the upstream u64->H160 conversion
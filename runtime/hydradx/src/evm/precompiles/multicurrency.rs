@@ -27,22 +27,102 @@ use crate::{
 			erc20_mapping::HydraErc20Mapping,
 			handle::{EvmDataWriter, FunctionModifier, PrecompileHandleExt},
 			substrate::RuntimeHelper,
-			succeed, Address, Output,
+			succeed, Address, EvmResult, Output,
 		},
 		ExtendedAddressMapping,
 	},
 	Currencies,
 };
 use codec::EncodeLike;
-use frame_support::traits::{IsType, OriginTrait};
+use ethabi::Token;
+use frame_support::storage::types::{Key as NMapKey, StorageDoubleMap, StorageNMap, ValueQuery};
+use frame_support::traits::{Get, IsType, OriginTrait, StorageInstance, Time};
+use frame_support::{Blake2_128Concat, Twox64Concat};
+use hex_literal::hex;
 use hydradx_traits::evm::{Erc20Mapping, InspectEvmAccounts};
 use hydradx_traits::registry::Inspect as InspectRegistry;
 use orml_traits::{MultiCurrency as MultiCurrencyT, MultiCurrency};
 use pallet_evm::{AddressMapping, ExitRevert, Precompile, PrecompileFailure, PrecompileHandle, PrecompileResult};
-use primitive_types::H160;
+use primitive_types::{H160, H256, U256};
 use primitives::{AssetId, Balance};
-use sp_runtime::traits::Dispatchable;
+use sp_io::hashing::keccak_256;
+use sp_runtime::traits::{Dispatchable, UniqueSaturatedInto};
 use sp_std::marker::PhantomData;
+use sp_std::{vec, vec::Vec};
+
+/// Storage prefix for allowances.
+pub struct Allowances;
+
+impl StorageInstance for Allowances {
+	const STORAGE_PREFIX: &'static str = "Allowances";
+
+	fn pallet_prefix() -> &'static str {
+		"MultiCurrencyPrecompile"
+	}
+}
+
+/// Storage type used to store ERC20 allowances of registry assets.
+pub type AllowancesStorage = StorageNMap<
+	Allowances,
+	(
+		// Asset
+		NMapKey<Twox64Concat, AssetId>,
+		// Owner
+		NMapKey<Blake2_128Concat, H160>,
+		// Spender
+		NMapKey<Blake2_128Concat, H160>,
+	),
+	// Allowance
+	Balance,
+	ValueQuery,
+>;
+
+/// Storage prefix for nonces.
+pub struct Nonces;
+
+impl StorageInstance for Nonces {
+	const STORAGE_PREFIX: &'static str = "Nonces";
+
+	fn pallet_prefix() -> &'static str {
+		"MultiCurrencyPrecompile"
+	}
+}
+
+/// Storage type used to store EIP2612 nonces of registry assets.
+pub type NoncesStorage = StorageDoubleMap<
+	Nonces,
+	// Asset
+	Twox64Concat,
+	AssetId,
+	// Owner
+	Blake2_128Concat,
+	H160,
+	// Nonce
+	U256,
+	ValueQuery,
+>;
+
+/// Transfer(address indexed from, address indexed to, uint256 value)
+pub const SELECTOR_LOG_TRANSFER: &[u8; 32] = module_evm_utility_macro::keccak256!("Transfer(address,address,uint256)");
+
+/// Approval(address indexed owner, address indexed spender, uint256 value)
+pub const SELECTOR_LOG_APPROVAL: &[u8; 32] = module_evm_utility_macro::keccak256!("Approval(address,address,uint256)");
+
+/// EIP2612 permit typehash.
+pub const PERMIT_TYPEHASH: &[u8; 32] = module_evm_utility_macro::keccak256!(
+	"Permit(address owner,address spender,uint256 value,uint256 nonce,uint256 deadline)"
+);
+
+/// EIP712 domain typehash used to compute domain separator of an asset.
+pub const PERMIT_DOMAIN: &[u8; 32] = module_evm_utility_macro::keccak256!(
+	"EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)"
+);
+
+/// Cost of signature recovery, same as the `ECRecover` precompile.
+const ECRECOVER_GAS_COST: u64 = 3_000;
+
+/// Half of the secp256k1 curve order. Signatures with higher `s` are malleable and rejected (EIP-2).
+const SECP256K1_HALF_ORDER: H256 = H256(hex!["7fffffffffffffffffffffffffffffff5d576e7357a4501ddfe92f46681b20a0"]);
 
 pub struct MultiCurrencyPrecompile<Runtime>(PhantomData<Runtime>);
 
@@ -73,8 +153,12 @@ where
 			};
 
 			handle.check_function_modifier(match selector {
-				Function::Transfer => FunctionModifier::NonPayable,
-				Function::TransferFrom => FunctionModifier::NonPayable,
+				Function::Transfer
+				| Function::TransferFrom
+				| Function::Approve
+				| Function::IncreaseAllowance
+				| Function::DecreaseAllowance
+				| Function::Permit => FunctionModifier::NonPayable,
 				_ => FunctionModifier::View,
 			})?;
 
@@ -85,9 +169,14 @@ where
 				Function::TotalSupply => Self::total_supply(asset_id, handle),
 				Function::BalanceOf => Self::balance_of(asset_id, handle),
 				Function::Transfer => Self::transfer(asset_id, handle),
-				Function::Allowance => Self::allowance(handle),
-				Function::Approve => Self::not_supported(),
+				Function::Allowance => Self::allowance(asset_id, handle),
+				Function::Approve => Self::approve(asset_id, handle),
 				Function::TransferFrom => Self::transfer_from(asset_id, handle),
+				Function::IncreaseAllowance => Self::increase_allowance(asset_id, handle),
+				Function::DecreaseAllowance => Self::decrease_allowance(asset_id, handle),
+				Function::Permit => Self::permit(asset_id, handle),
+				Function::Nonces => Self::nonces(asset_id, handle),
+				Function::DomainSeparator => Self::domain_separator(asset_id, handle),
 			};
 		}
 		Err(PrecompileFailure::Revert {
@@ -220,88 +309,326 @@ where
 		let to: H160 = input.read::<Address>()?.into();
 		let amount = input.read::<Balance>()?;
 
-		let origin = ExtendedAddressMapping::into_account_id(handle.context().caller);
-		let to = ExtendedAddressMapping::into_account_id(to);
+		let caller = handle.context().caller;
 
-		log::debug!(target: "evm", "multicurrency: transfer from: {:?}, to: {:?}, amount: {:?}", origin, to, amount);
-
-		<pallet_currencies::Pallet<Runtime> as MultiCurrency<Runtime::AccountId>>::transfer(
-			asset_id,
-			&(<sp_runtime::AccountId32 as Into<Runtime::AccountId>>::into(origin)),
-			&(<sp_runtime::AccountId32 as Into<Runtime::AccountId>>::into(to)),
-			amount,
-		)
-		.map_err(|e| PrecompileFailure::Revert {
-			exit_status: ExitRevert::Reverted,
-			output: Into::<&str>::into(e).as_bytes().to_vec(),
-		})?;
+		Self::do_transfer(asset_id, handle, caller, to, amount)?;
 
 		Ok(succeed(EvmDataWriter::new().write(true).build()))
 	}
 
-	fn allowance(handle: &mut impl PrecompileHandle) -> PrecompileResult {
-		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+	fn allowance(asset_id: AssetId, handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost().saturating_mul(2))?;
 
 		// Parse input
 		let mut input = handle.read_input()?;
 		input.expect_arguments(2)?;
 
-		let _owner: H160 = input.read::<Address>()?.into();
+		let owner: H160 = input.read::<Address>()?.into();
 		let spender: H160 = input.read::<Address>()?.into();
 
-		let allowance =
-			if <pallet_evm_accounts::Pallet<Runtime> as InspectEvmAccounts<Runtime::AccountId>>::is_approved_contract(
-				spender,
-			) {
-				u128::MAX
-			} else {
-				0
-			};
+		let allowance = Self::allowance_of(asset_id, owner, spender);
 
 		let encoded = Output::encode_uint::<u128>(allowance);
 		Ok(succeed(encoded))
 	}
 
-	fn transfer_from(asset_id: AssetId, handle: &mut impl PrecompileHandle) -> PrecompileResult {
+	fn approve(asset_id: AssetId, handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+		handle.record_log_costs_manual(3, 32)?;
+
+		// Parse input
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+
+		let spender: H160 = input.read::<Address>()?.into();
+		let amount = Self::saturated_amount(input.read::<U256>()?);
+
+		let owner = handle.context().caller;
+
+		log::debug!(target: "evm", "multicurrency: approve owner: {:?}, spender: {:?}, amount: {:?}", owner, spender, amount);
+
+		Self::do_approve(asset_id, handle, owner, spender, amount)?;
+
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn increase_allowance(asset_id: AssetId, handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+		handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+		handle.record_log_costs_manual(3, 32)?;
+
+		// Parse input
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+
+		let spender: H160 = input.read::<Address>()?.into();
+		let added_value = input.read::<U256>()?;
+
+		let owner = handle.context().caller;
+
+		let allowance = U256::from(AllowancesStorage::get((asset_id, owner, spender))).saturating_add(added_value);
+
+		Self::do_approve(asset_id, handle, owner, spender, Self::saturated_amount(allowance))?;
+
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn decrease_allowance(asset_id: AssetId, handle: &mut impl PrecompileHandle) -> PrecompileResult {
 		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+		handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+		handle.record_log_costs_manual(3, 32)?;
+
+		// Parse input
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+
+		let spender: H160 = input.read::<Address>()?.into();
+		let subtracted_value = input.read::<U256>()?;
+
+		let owner = handle.context().caller;
+
+		let allowance = U256::from(AllowancesStorage::get((asset_id, owner, spender)))
+			.checked_sub(subtracted_value)
+			.ok_or_else(|| revert("ERC20: decreased allowance below zero"))?;
+
+		Self::do_approve(asset_id, handle, owner, spender, Self::saturated_amount(allowance))?;
+
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn transfer_from(asset_id: AssetId, handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost().saturating_mul(2))?;
 
 		// Parse input
 		let mut input = handle.read_input()?;
 		input.expect_arguments(3)?;
 
-		let origin: H160 = handle.context().caller;
+		let spender: H160 = handle.context().caller;
 		let from: H160 = input.read::<Address>()?.into();
 		let to: H160 = input.read::<Address>()?.into();
 		let amount = input.read::<Balance>()?;
 
-		let from = ExtendedAddressMapping::into_account_id(from);
-		let to = ExtendedAddressMapping::into_account_id(to);
+		// Approved contracts are allowed to manage balances without an allowance
+		if !<pallet_evm_accounts::Pallet<Runtime> as InspectEvmAccounts<Runtime::AccountId>>::is_approved_contract(
+			spender,
+		) {
+			let allowance = AllowancesStorage::get((asset_id, from, spender));
+			// Maximum allowance is never decreased
+			if allowance != Balance::MAX {
+				let remaining = allowance
+					.checked_sub(amount)
+					.ok_or_else(|| revert("ERC20: insufficient allowance"))?;
+
+				handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+				AllowancesStorage::insert((asset_id, from, spender), remaining);
+			}
+		}
 
-		log::debug!(target: "evm", "multicurrency: transferFrom from: {:?}, to: {:?}, amount: {:?}", from, to, amount);
+		Self::do_transfer(asset_id, handle, from, to, amount)?;
 
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn permit(asset_id: AssetId, handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_cost(ECRECOVER_GAS_COST)?;
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost().saturating_mul(3))?;
+		handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost().saturating_mul(2))?;
+		handle.record_log_costs_manual(3, 32)?;
+
+		// Parse input
+		let mut input = handle.read_input()?;
+		input.expect_arguments(7)?;
+
+		let owner: H160 = input.read::<Address>()?.into();
+		let spender: H160 = input.read::<Address>()?.into();
+		let value = input.read::<U256>()?;
+		let deadline = input.read::<U256>()?;
+		let v = input.read::<u8>()?;
+		let r = input.read::<H256>()?;
+		let s = input.read::<H256>()?;
+
+		// Blockchain time is in ms while Ethereum use second timestamps.
+		let timestamp: u128 = <Runtime as pallet_evm::Config>::Timestamp::now().unique_saturated_into();
+		let timestamp: U256 = U256::from(timestamp / 1000);
+
+		if deadline < timestamp {
+			return Err(revert("ERC20Permit: expired deadline"));
+		}
+
+		if s > SECP256K1_HALF_ORDER {
+			return Err(revert("ERC20Permit: invalid signature 's' value"));
+		}
+
+		let nonce = NoncesStorage::get(asset_id, owner);
+
+		let digest = Self::permit_digest(asset_id, handle.code_address(), owner, spender, value, nonce, deadline);
+
+		let mut sig = [0u8; 65];
+		sig[0..32].copy_from_slice(r.as_bytes());
+		sig[32..64].copy_from_slice(s.as_bytes());
+		sig[64] = v;
+
+		let signer = sp_io::crypto::secp256k1_ecdsa_recover(&sig, &digest)
+			.map_err(|_| revert("ERC20Permit: invalid signature"))?;
+		let signer = H160::from(H256::from_slice(keccak_256(&signer).as_slice()));
+
+		if signer == H160::zero() || signer != owner {
+			return Err(revert("ERC20Permit: invalid signature"));
+		}
+
+		NoncesStorage::insert(asset_id, owner, nonce.saturating_add(U256::one()));
+
+		log::debug!(target: "evm", "multicurrency: permit owner: {:?}, spender: {:?}, value: {:?}", owner, spender, value);
+
+		Self::do_approve(asset_id, handle, owner, spender, Self::saturated_amount(value))?;
+
+		Ok(succeed(EvmDataWriter::new().build()))
+	}
+
+	fn nonces(asset_id: AssetId, handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		// Parse input
+		let mut input = handle.read_input()?;
+		input.expect_arguments(1)?;
+
+		let owner: H160 = input.read::<Address>()?.into();
+
+		let encoded = Output::encode_uint::<U256>(NoncesStorage::get(asset_id, owner));
+		Ok(succeed(encoded))
+	}
+
+	fn domain_separator(asset_id: AssetId, handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		// Parse input
+		let input = handle.read_input()?;
+		input.expect_arguments(0)?;
+
+		let domain_separator = Self::compute_domain_separator(asset_id, handle.code_address());
+
+		let encoded = Output::encode_fixed_bytes(&domain_separator);
+		Ok(succeed(encoded))
+	}
+
+	/// Allowance of `spender` for `owner`'s asset. Approved contracts have unlimited allowance.
+	pub fn allowance_of(asset_id: AssetId, owner: H160, spender: H160) -> Balance {
 		if <pallet_evm_accounts::Pallet<Runtime> as InspectEvmAccounts<Runtime::AccountId>>::is_approved_contract(
-			origin,
+			spender,
 		) {
-			<pallet_currencies::Pallet<Runtime> as MultiCurrency<Runtime::AccountId>>::transfer(
-				asset_id,
-				&(<sp_runtime::AccountId32 as Into<Runtime::AccountId>>::into(from)),
-				&(<sp_runtime::AccountId32 as Into<Runtime::AccountId>>::into(to)),
-				amount,
-			)
-			.map_err(|e| PrecompileFailure::Revert {
-				exit_status: ExitRevert::Reverted,
-				output: Into::<&str>::into(e).as_bytes().to_vec(),
-			})?;
-
-			Ok(succeed(EvmDataWriter::new().write(true).build()))
+			Balance::MAX
 		} else {
-			Err(revert("Not approved contract"))
+			AllowancesStorage::get((asset_id, owner, spender))
 		}
 	}
 
-	fn not_supported() -> PrecompileResult {
-		Err(PrecompileFailure::Error {
-			exit_status: pallet_evm::ExitError::Other("not supported".into()),
-		})
+	/// Allowances are stored as `Balance`, amounts exceeding it are treated as unlimited allowance.
+	fn saturated_amount(amount: U256) -> Balance {
+		Balance::try_from(amount).unwrap_or(Balance::MAX)
+	}
+
+	fn do_approve(
+		asset_id: AssetId,
+		handle: &mut impl PrecompileHandle,
+		owner: H160,
+		spender: H160,
+		amount: Balance,
+	) -> EvmResult {
+		if owner.is_zero() {
+			return Err(revert("ERC20: approve from the zero address"));
+		}
+		if spender.is_zero() {
+			return Err(revert("ERC20: approve to the zero address"));
+		}
+
+		AllowancesStorage::insert((asset_id, owner, spender), amount);
+
+		Self::log_event(handle, SELECTOR_LOG_APPROVAL, owner, spender, amount)
+	}
+
+	fn do_transfer(
+		asset_id: AssetId,
+		handle: &mut impl PrecompileHandle,
+		from: H160,
+		to: H160,
+		amount: Balance,
+	) -> EvmResult {
+		handle.record_log_costs_manual(3, 32)?;
+
+		let from_account = ExtendedAddressMapping::into_account_id(from);
+		let to_account = ExtendedAddressMapping::into_account_id(to);
+
+		log::debug!(target: "evm", "multicurrency: transfer from: {:?}, to: {:?}, amount: {:?}", from_account, to_account, amount);
+
+		<pallet_currencies::Pallet<Runtime> as MultiCurrency<Runtime::AccountId>>::transfer(
+			asset_id,
+			&(<sp_runtime::AccountId32 as Into<Runtime::AccountId>>::into(from_account)),
+			&(<sp_runtime::AccountId32 as Into<Runtime::AccountId>>::into(to_account)),
+			amount,
+		)
+		.map_err(|e| PrecompileFailure::Revert {
+			exit_status: ExitRevert::Reverted,
+			output: Into::<&str>::into(e).as_bytes().to_vec(),
+		})?;
+
+		Self::log_event(handle, SELECTOR_LOG_TRANSFER, from, to, amount)
+	}
+
+	fn log_event(
+		handle: &mut impl PrecompileHandle,
+		selector: &[u8; 32],
+		topic1: H160,
+		topic2: H160,
+		amount: Balance,
+	) -> EvmResult {
+		let address = handle.code_address();
+		handle
+			.log(
+				address,
+				vec![H256::from(*selector), H256::from(topic1), H256::from(topic2)],
+				Output::encode_uint::<u128>(amount),
+			)
+			.map_err(|exit_status| PrecompileFailure::Error { exit_status })
+	}
+
+	fn compute_domain_separator(asset_id: AssetId, address: H160) -> [u8; 32] {
+		let name = <pallet_asset_registry::Pallet<Runtime>>::asset_name(asset_id.into()).unwrap_or_default();
+		let chain_id: u64 = <Runtime as pallet_evm::Config>::ChainId::get();
+
+		keccak_256(&ethabi::encode(&[
+			Token::FixedBytes(PERMIT_DOMAIN.to_vec()),
+			Token::FixedBytes(keccak_256(name.as_slice()).to_vec()),
+			Token::FixedBytes(keccak_256(b"1").to_vec()),
+			Token::Uint(chain_id.into()),
+			Token::Address(address),
+		]))
+	}
+
+	/// EIP712 digest of a permit which has to be signed by the `owner`.
+	pub fn permit_digest(
+		asset_id: AssetId,
+		address: H160,
+		owner: H160,
+		spender: H160,
+		value: U256,
+		nonce: U256,
+		deadline: U256,
+	) -> [u8; 32] {
+		let domain_separator = Self::compute_domain_separator(asset_id, address);
+
+		let permit_content = keccak_256(&ethabi::encode(&[
+			Token::FixedBytes(PERMIT_TYPEHASH.to_vec()),
+			Token::Address(owner),
+			Token::Address(spender),
+			Token::Uint(value),
+			Token::Uint(nonce),
+			Token::Uint(deadline),
+		]));
+
+		let mut pre_digest = Vec::with_capacity(2 + 32 + 32);
+		pre_digest.extend_from_slice(b"\x19\x01");
+		pre_digest.extend_from_slice(&domain_separator);
+		pre_digest.extend_from_slice(&permit_content);
+		keccak_256(&pre_digest)
 	}
 }
@@ -0,0 +1,185 @@
+//                    :                     $$\   $$\                 $$\                    $$$$$$$\  $$\   $$\
+//                  !YJJ^                   $$ |  $$ |                $$ |                   $$  __$$\ $$ |  $$ |
+//                7B5. ~B5^                 $$ |  $$ |$$\   $$\  $$$$$$$ | $$$$$$\  $$$$$$\  $$ |  $$ |\$$\ $$  |
+//             .?B@G    ~@@P~               $$$$$$$$ |$$ |  $$ |$$  __$$ |$$  __$$\ \____$$\ $$ |  $$ | \$$$$  /
+//           :?#@@@Y    .&@@@P!.            $$  __$$ |$$ |  $$ |$$ /  $$ |$$ |  \__|$$$$$$$ |$$ |  $$ | $$  $$<
+//         ^?J^7P&@@!  .5@@#Y~!J!.          $$ |  $$ |$$ |  $$ |$$ |  $$ |$$ |     $$  __$$ |$$ |  $$ |$$  /\$$\
+//       ^JJ!.   :!J5^ ?5?^    ^?Y7.        $$ |  $$ |\$$$$$$$ |\$$$$$$$ |$$ |     \$$$$$$$ |$$$$$$$  |$$ /  $$ |
+//     ~PP: 7#B5!.         :?P#G: 7G?.      \__|  \__| \____$$ | \_______|\__|      \_______|\_______/ \__|  \__|
+//  .!P@G    7@@@#Y^    .!P@@@#.   ~@&J:              $$\   $$ |
+//  !&@@J    :&@@@@P.   !&@@@@5     #@@P.             \$$$$$$  |
+//   :J##:   Y@@&P!      :JB@@&~   ?@G!                \______/
+//     .?P!.?GY7:   .. .    ^?PP^:JP~
+//       .7Y7.  .!YGP^ ?BP?^   ^JJ^         This file is part of https://github.com/galacticcouncil/HydraDX-node
+//         .!Y7Y#@@#:   ?@@@G?JJ^           Built with <3 for decentralisation.
+//            !G@@@Y    .&@@&J:
+//              ^5@#.   7@#?.               Copyright (C) 2021-2023  Intergalactic, Limited (GIB).
+//                :5P^.?G7.                 SPDX-License-Identifier: Apache-2.0
+//                  :?Y!                    Licensed under the Apache License, Version 2.0 (the "License");
+//                                          you may not use this file except in compliance with the License.
+//                                          http://www.apache.org/licenses/LICENSE-2.0
+
+//! Precompile for HDX staking.
+//!
+//! Calls are dispatched on behalf of the account of the caller, so EVM accounts can create and manage
+//! their staking position without using the dispatch precompile.
+
+use crate::evm::{
+	precompiles::{
+		handle::{EvmDataWriter, FunctionModifier, PrecompileHandleExt},
+		revert,
+		substrate::RuntimeHelper,
+		succeed, Address, Output,
+	},
+	ExtendedAddressMapping,
+};
+use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use pallet_evm::{AddressMapping, Precompile, PrecompileHandle, PrecompileResult};
+use primitive_types::U256;
+use primitives::Balance;
+use sp_runtime::{traits::Dispatchable, RuntimeDebug};
+use sp_std::marker::PhantomData;
+
+#[module_evm_utility_macro::generate_function_selector]
+#[derive(RuntimeDebug, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u32)]
+pub enum Function {
+	Stake = "stake(uint256)",
+	IncreaseStake = "increaseStake(uint256,uint256)",
+	Claim = "claim(uint256)",
+	Unstake = "unstake(uint256)",
+	GetPositionId = "getPositionId(address)",
+	GetPosition = "getPosition(uint256)",
+}
+
+pub struct StakingPrecompile<Runtime>(PhantomData<Runtime>);
+
+impl<Runtime> Precompile for StakingPrecompile<Runtime>
+where
+	Runtime: frame_system::Config + pallet_evm::Config + pallet_staking::Config<PositionItemId = u128>,
+	Runtime::RuntimeCall:
+		Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo + From<pallet_staking::Call<Runtime>>,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+	<Runtime as frame_system::Config>::AccountId: From<sp_runtime::AccountId32>,
+{
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		let selector = handle.read_selector()?;
+
+		handle.check_function_modifier(match selector {
+			Function::GetPositionId | Function::GetPosition => FunctionModifier::View,
+			_ => FunctionModifier::NonPayable,
+		})?;
+
+		match selector {
+			Function::Stake => Self::stake(handle),
+			Function::IncreaseStake => Self::increase_stake(handle),
+			Function::Claim => Self::claim(handle),
+			Function::Unstake => Self::unstake(handle),
+			Function::GetPositionId => Self::get_position_id(handle),
+			Function::GetPosition => Self::get_position(handle),
+		}
+	}
+}
+
+impl<Runtime> StakingPrecompile<Runtime>
+where
+	Runtime: frame_system::Config + pallet_evm::Config + pallet_staking::Config<PositionItemId = u128>,
+	Runtime::RuntimeCall:
+		Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo + From<pallet_staking::Call<Runtime>>,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+	<Runtime as frame_system::Config>::AccountId: From<sp_runtime::AccountId32>,
+{
+	fn stake(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(1)?;
+
+		let amount = input.read::<Balance>()?;
+
+		Self::dispatch(handle, pallet_staking::Call::<Runtime>::stake { amount })
+	}
+
+	fn increase_stake(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+
+		let position_id = input.read::<u128>()?;
+		let amount = input.read::<Balance>()?;
+
+		Self::dispatch(
+			handle,
+			pallet_staking::Call::<Runtime>::increase_stake { position_id, amount },
+		)
+	}
+
+	fn claim(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(1)?;
+
+		let position_id = input.read::<u128>()?;
+
+		Self::dispatch(handle, pallet_staking::Call::<Runtime>::claim { position_id })
+	}
+
+	fn unstake(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(1)?;
+
+		let position_id = input.read::<u128>()?;
+
+		Self::dispatch(handle, pallet_staking::Call::<Runtime>::unstake { position_id })
+	}
+
+	fn get_position_id(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		// Position NFT ownership
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost().saturating_mul(2))?;
+
+		let mut input = handle.read_input()?;
+		input.expect_arguments(1)?;
+
+		let owner = input.read::<Address>()?;
+		let who: Runtime::AccountId = ExtendedAddressMapping::into_account_id(owner.into()).into();
+
+		let position_id = pallet_staking::Pallet::<Runtime>::get_user_position_id(&who)
+			.map_err(|e| revert(Into::<&str>::into(e)))?
+			.ok_or_else(|| revert("position not found"))?;
+
+		Ok(succeed(Output::encode_uint::<u128>(position_id)))
+	}
+
+	/// Returns `(stake, totalLocked, points, claimableRewards, unclaimableRewards)` of the position.
+	fn get_position(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		// Position, staking state and balance of the pot
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost().saturating_mul(3))?;
+
+		let mut input = handle.read_input()?;
+		input.expect_arguments(1)?;
+
+		let position_id = input.read::<u128>()?;
+
+		let position =
+			pallet_staking::Pallet::<Runtime>::get_position(position_id).ok_or_else(|| revert("position not found"))?;
+		let total_locked = position.get_total_locked().map_err(|e| revert(Into::<&str>::into(e)))?;
+		let (points, claimable_rewards, unclaimable_rewards) =
+			pallet_staking::Pallet::<Runtime>::get_position_points_and_rewards(position_id)
+				.ok_or_else(|| revert("rewards calculation failed"))?;
+
+		Ok(succeed(
+			EvmDataWriter::new()
+				.write(U256::from(position.get_stake()))
+				.write(U256::from(total_locked))
+				.write(U256::from(points))
+				.write(U256::from(claimable_rewards))
+				.write(U256::from(unclaimable_rewards))
+				.build(),
+		))
+	}
+
+	fn dispatch(handle: &mut impl PrecompileHandle, call: pallet_staking::Call<Runtime>) -> PrecompileResult {
+		let who: Runtime::AccountId = ExtendedAddressMapping::into_account_id(handle.context().caller).into();
+
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(who).into(), call)?;
+
+		Ok(succeed([]))
+	}
+}
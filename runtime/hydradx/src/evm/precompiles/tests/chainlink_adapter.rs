@@ -0,0 +1,60 @@
+use crate::evm::precompiles::chainlink_adapter::{decode_oracle_address, encode_oracle_address, is_oracle_address};
+use crate::evm::precompiles::erc20_mapping::is_asset_address;
+use hex_literal::hex;
+use hydradx_traits::OraclePeriod;
+use primitive_types::H160;
+
+#[test]
+fn encode_oracle_address_should_work() {
+	assert_eq!(
+		encode_oracle_address(0, 5, OraclePeriod::LastBlock),
+		H160::from(hex!("0000000000000000000001000000000000000005"))
+	);
+	assert_eq!(
+		encode_oracle_address(5, 0, OraclePeriod::TenMinutes),
+		H160::from(hex!("0000000000000000000001020000000500000000"))
+	);
+	assert_eq!(
+		encode_oracle_address(u32::MAX, 1, OraclePeriod::Week),
+		H160::from(hex!("000000000000000000000105ffffffff00000001"))
+	);
+}
+
+#[test]
+fn decode_oracle_address_should_work() {
+	assert_eq!(
+		decode_oracle_address(H160::from(hex!("0000000000000000000001000000000000000005"))),
+		Some((0, 5, OraclePeriod::LastBlock))
+	);
+	assert_eq!(
+		decode_oracle_address(H160::from(hex!("0000000000000000000001030000000a00000002"))),
+		Some((10, 2, OraclePeriod::Hour))
+	);
+}
+
+#[test]
+fn decode_oracle_address_should_not_work_with_invalid_addresses() {
+	assert_eq!(
+		decode_oracle_address(H160::from(hex!("0000000000000000000001060000000000000005"))),
+		None
+	);
+	assert_eq!(
+		decode_oracle_address(H160::from(hex!("0000000000000000000002000000000000000005"))),
+		None
+	);
+	assert_eq!(
+		decode_oracle_address(H160::from(hex!("0000000000000000000000000000000100000005"))),
+		None
+	);
+}
+
+#[test]
+fn oracle_address_should_not_collide_with_asset_address() {
+	let address = encode_oracle_address(0, 1, OraclePeriod::Short);
+
+	assert!(is_oracle_address(address));
+	assert!(!is_asset_address(address));
+	assert!(!is_oracle_address(H160::from(hex!(
+		"0000000000000000000000000000000100000001"
+	))));
+}
@@ -1 +1,2 @@
+mod chainlink_adapter;
 mod erc20_mapping;
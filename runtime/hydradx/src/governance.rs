@@ -25,12 +25,14 @@ use frame_support::{
 	parameter_types,
 	sp_runtime::{Perbill, Percent, Permill},
 	traits::{
-		fungible::HoldConsideration, tokens::UnityAssetBalanceConversion, ConstU32, EitherOfDiverse,
-		LinearStoragePrice, LockIdentifier, PrivilegeCmp,
+		fungible::HoldConsideration, ConstU32, EitherOf, EitherOfDiverse, LinearStoragePrice, LockIdentifier,
+		PrivilegeCmp,
 	},
 	PalletId,
 };
 use frame_system::{EnsureRoot, EnsureSigned};
+use hydradx_adapters::{price::NativeBalanceConverter, price::OraclePriceProviderUsingRoute, OraclePriceProvider};
+use pallet_currencies::fungibles::FungibleCurrencies;
 use sp_runtime::traits::IdentityLookup;
 use sp_staking::currency_to_vote::U128CurrencyToVote;
 use sp_std::cmp::Ordering;
@@ -52,17 +54,18 @@ pub struct PayFromTreasuryAccount;
 impl frame_support::traits::tokens::Pay for PayFromTreasuryAccount {
 	type Balance = Balance;
 	type Beneficiary = AccountId;
-	type AssetKind = ();
+	type AssetKind = AssetId;
 	type Id = ();
 	type Error = sp_runtime::DispatchError;
 
 	#[cfg(not(feature = "runtime-benchmarks"))]
 	fn pay(
 		who: &Self::Beneficiary,
-		_asset_kind: Self::AssetKind,
+		asset_kind: Self::AssetKind,
 		amount: Self::Balance,
 	) -> Result<Self::Id, Self::Error> {
-		let _ = <Balances as frame_support::traits::fungible::Mutate<_>>::transfer(
+		let _ = <FungibleCurrencies<Runtime> as frame_support::traits::fungibles::Mutate<_>>::transfer(
+			asset_kind,
 			&TreasuryAccount::get(),
 			who,
 			amount,
@@ -74,12 +77,13 @@ impl frame_support::traits::tokens::Pay for PayFromTreasuryAccount {
 	#[cfg(feature = "runtime-benchmarks")]
 	fn pay(
 		who: &Self::Beneficiary,
-		_asset_kind: Self::AssetKind,
+		asset_kind: Self::AssetKind,
 		amount: Self::Balance,
 	) -> Result<Self::Id, Self::Error> {
 		// In case of benchmarks, we adjust the value by multiplying it by 1_000_000_000_000, otherwise it fails with BelowMinimum limit error, because
 		// treasury benchmarks uses only 100 as the amount.
-		let _ = <Balances as frame_support::traits::fungible::Mutate<_>>::transfer(
+		let _ = <FungibleCurrencies<Runtime> as frame_support::traits::fungibles::Mutate<_>>::transfer(
+			asset_kind,
 			&TreasuryAccount::get(),
 			who,
 			amount * 1_000_000_000_000,
@@ -93,8 +97,9 @@ impl frame_support::traits::tokens::Pay for PayFromTreasuryAccount {
 	}
 
 	#[cfg(feature = "runtime-benchmarks")]
-	fn ensure_successful(_: &Self::Beneficiary, _: Self::AssetKind, amount: Self::Balance) {
-		<Balances as frame_support::traits::fungible::Mutate<_>>::mint_into(
+	fn ensure_successful(_: &Self::Beneficiary, asset_kind: Self::AssetKind, amount: Self::Balance) {
+		<FungibleCurrencies<Runtime> as frame_support::traits::fungibles::Mutate<_>>::mint_into(
+			asset_kind,
 			&TreasuryAccount::get(),
 			amount * 1_000_000_000_000,
 		)
@@ -104,6 +109,59 @@ impl frame_support::traits::tokens::Pay for PayFromTreasuryAccount {
 	fn ensure_concluded(_: Self::Id) {}
 }
 
+parameter_types! {
+	pub const TreasurySpendOraclePeriod: OraclePeriod = OraclePeriod::TenMinutes;
+	pub const MaxTreasurySpend: Balance = Balance::MAX;
+	pub const AllCouncilTreasurySpend: Balance = 50_000_000 * UNITS;
+	pub const MajorityOfCouncilTreasurySpend: Balance = 10_000_000 * UNITS;
+	pub const MoreThanHalfCouncilTreasurySpend: Balance = 1_000_000 * UNITS;
+}
+
+/// Values treasury spends in native asset, using oracle price of the spent asset.
+pub type TreasuryBalanceConverter = NativeBalanceConverter<
+	OraclePriceProviderUsingRoute<Router, OraclePriceProvider<AssetId, EmaOracle, LRNA>, TreasurySpendOraclePeriod>,
+	NativeAssetId,
+>;
+
+/// Spend origins tiered by the native value of the spend. The first matching origin determines the limit.
+pub type TreasurySpendOrigin = EitherOf<
+	frame_system::EnsureRootWithSuccess<AccountId, MaxTreasurySpend>,
+	EitherOf<
+		frame_system::EnsureWithSuccess<
+			pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 1, 1>,
+			AccountId,
+			AllCouncilTreasurySpend,
+		>,
+		EitherOf<
+			frame_system::EnsureWithSuccess<
+				pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 2, 3>,
+				AccountId,
+				MajorityOfCouncilTreasurySpend,
+			>,
+			frame_system::EnsureWithSuccess<
+				pallet_collective::EnsureProportionMoreThan<AccountId, CouncilCollective, 1, 2>,
+				AccountId,
+				MoreThanHalfCouncilTreasurySpend,
+			>,
+		>,
+	>,
+>;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct TreasuryBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_treasury::ArgumentsFactory<AssetId, AccountId> for TreasuryBenchmarkHelper {
+	// Native asset is used, so the spend does not depend on oracle prices
+	fn create_asset_kind(_seed: u32) -> AssetId {
+		NativeAssetId::get()
+	}
+
+	fn create_beneficiary(seed: [u8; 32]) -> AccountId {
+		AccountId::from(seed)
+	}
+}
+
 impl pallet_treasury::Config for Runtime {
 	type Currency = Balances;
 	type ApproveOrigin = TreasuryApproveOrigin;
@@ -121,18 +179,18 @@ impl pallet_treasury::Config for Runtime {
 	type SpendFunds = ();
 	type MaxApprovals = MaxApprovals;
 	#[cfg(not(feature = "runtime-benchmarks"))]
-	type SpendOrigin = frame_support::traits::NeverEnsureOrigin<Balance>;
+	type SpendOrigin = TreasurySpendOrigin;
 	#[cfg(feature = "runtime-benchmarks")]
 	type SpendOrigin =
 		frame_system::EnsureWithSuccess<EnsureRoot<AccountId>, AccountId, crate::benches::BenchmarkMaxBalance>;
-	type AssetKind = (); // set to () to support only the native currency
+	type AssetKind = AssetId;
 	type Beneficiary = AccountId;
 	type BeneficiaryLookup = IdentityLookup<AccountId>;
 	type Paymaster = PayFromTreasuryAccount;
-	type BalanceConverter = UnityAssetBalanceConversion;
+	type BalanceConverter = TreasuryBalanceConverter;
 	type PayoutPeriod = TreasuryPayoutPeriod;
 	#[cfg(feature = "runtime-benchmarks")]
-	type BenchmarkHelper = (); // default impl is enough because we support only the native currency
+	type BenchmarkHelper = TreasuryBenchmarkHelper;
 }
 
 parameter_types! {
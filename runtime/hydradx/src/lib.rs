@@ -67,6 +67,7 @@ use sp_version::RuntimeVersion;
 // A few exports that help ease life for downstream crates.
 use frame_support::{construct_runtime, pallet_prelude::Hooks, weights::Weight};
 pub use hex_literal::hex;
+pub use hydra_dx_math::ema::EmaPrice;
 use orml_traits::MultiCurrency;
 /// Import HydraDX pallets
 pub use pallet_claims;
@@ -112,7 +113,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: create_runtime_str!("hydradx"),
 	impl_name: create_runtime_str!("hydradx"),
 	authoring_version: 1,
-	spec_version: 273,
+	spec_version: 298,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
 	transaction_version: 1,
@@ -200,6 +201,9 @@ construct_runtime!(
 
 		XYKLiquidityMining: pallet_xyk_liquidity_mining = 95,
 		XYKWarehouseLM: warehouse_liquidity_mining::<Instance2> = 96,
+		OmnipoolSubpools: pallet_omnipool_subpools = 97,
+		TreasuryConversion: pallet_treasury_conversion = 98,
+		ConcentratedLiquidity: pallet_concentrated_liquidity = 99,
 
 		RelayChainInfo: pallet_relaychain_info = 201,
 		//NOTE: DCA pallet should be declared before ParachainSystem pallet,
@@ -294,6 +298,7 @@ mod benches {
 		[pallet_transaction_pause, TransactionPause]
 		[pallet_ema_oracle, EmaOracle]
 		[pallet_circuit_breaker, CircuitBreaker]
+		[pallet_dynamic_fees, DynamicFees]
 		[pallet_bonds, Bonds]
 		[pallet_stableswap, Stableswap]
 		[pallet_claims, Claims]
@@ -303,6 +308,8 @@ mod benches {
 		[pallet_otc, OTC]
 		[pallet_otc_settlements, OtcSettlements]
 		[pallet_liquidation, Liquidation]
+		[pallet_treasury_conversion, TreasuryConversion]
+		[pallet_concentrated_liquidity, ConcentratedLiquidity]
 		[pallet_state_trie_migration, StateTrieMigration]
 		[frame_system, SystemBench::<Runtime>]
 		[pallet_balances, Balances]
@@ -429,11 +436,18 @@ impl fp_rpc::ConvertTransaction<sp_runtime::OpaqueExtrinsic> for TransactionConv
 use frame_support::{
 	genesis_builder_helper::{build_state, get_preset},
 	sp_runtime::{
-		traits::Convert, transaction_validity::TransactionSource, ApplyExtrinsicResult, ExtrinsicInclusionMode,
-		FixedPointNumber,
+		traits::Convert, transaction_validity::TransactionSource, ApplyExtrinsicResult, DispatchError,
+		ExtrinsicInclusionMode, FixedPointNumber, FixedU128,
 	},
+	traits::tokens::nonfungibles::InspectEnumerable,
 	weights::WeightToFee as _,
 };
+use hydradx_traits::router::{AssetPair as RouterAssetPair, RouteProvider, RouteQuote};
+use hydradx_traits::{AggregatedEntry, AggregatedOracle, OraclePeriod, PriceOracle, Source};
+use pallet_omnipool::types::{
+	AssetReserveState as OmnipoolAssetReserveState, Position as OmnipoolPosition, PositionValue, TvlCapInfo,
+};
+use pallet_xyk::types::{LiquidityPosition as XykLiquidityPosition, PoolFeeStats as XykPoolFeeStats};
 use polkadot_xcm::{IntoVersion, VersionedAssetId, VersionedAssets, VersionedLocation, VersionedXcm};
 use primitives::constants::chain::CORE_ASSET_ID;
 use sp_core::OpaqueMetadata;
@@ -933,6 +947,116 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_route_executor_rpc_runtime_api::RouterApi<Block, AssetId, Balance> for Runtime {
+		fn quote_sell(
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount_in: Balance,
+			route: Option<Vec<Trade<AssetId>>>,
+		) -> Result<RouteQuote<AssetId, Balance>, DispatchError> {
+			Router::quote_sell(asset_in, asset_out, amount_in, route.unwrap_or_default())
+		}
+
+		fn quote_buy(
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount_out: Balance,
+			route: Option<Vec<Trade<AssetId>>>,
+		) -> Result<RouteQuote<AssetId, Balance>, DispatchError> {
+			Router::quote_buy(asset_in, asset_out, amount_out, route.unwrap_or_default())
+		}
+
+		fn find_best_sell_route(
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount_in: Balance,
+		) -> Result<RouteQuote<AssetId, Balance>, DispatchError> {
+			let (route, _) = Router::find_best_sell_route(asset_in, asset_out, amount_in)?;
+			Router::quote_sell(asset_in, asset_out, amount_in, route)
+		}
+	}
+
+	impl pallet_omnipool_rpc_runtime_api::OmnipoolApi<Block, AssetId, AccountId, ItemId, Balance> for Runtime {
+		fn asset_state(asset_id: AssetId) -> Result<OmnipoolAssetReserveState<Balance>, DispatchError> {
+			Omnipool::load_asset_state(asset_id)
+		}
+
+		fn positions(who: AccountId) -> Vec<(ItemId, OmnipoolPosition<Balance, AssetId>)> {
+			Uniques::owned_in_collection(&OmnipoolCollectionId::get(), &who)
+				.filter_map(|position_id| Omnipool::positions(position_id).map(|position| (position_id, position)))
+				.collect()
+		}
+
+		fn position_value(position_id: ItemId) -> Result<PositionValue<Balance>, DispatchError> {
+			Omnipool::position_value(position_id)
+		}
+
+		fn lrna_spot_price(asset_id: AssetId) -> Result<FixedU128, DispatchError> {
+			Omnipool::lrna_spot_price(asset_id)
+		}
+
+		fn tvl_cap_info(
+			asset_id: AssetId,
+			stable_asset: AssetId,
+			tvl_cap: Balance,
+		) -> Result<TvlCapInfo<Balance>, DispatchError> {
+			Omnipool::tvl_cap_info(asset_id, stable_asset, tvl_cap)
+		}
+	}
+
+	impl pallet_xyk_rpc_runtime_api::XykApi<Block, AccountId> for Runtime {
+		fn pool_fee_stats(asset_a: AssetId, asset_b: AssetId) -> Result<XykPoolFeeStats, DispatchError> {
+			XYK::pool_fee_stats(asset_a, asset_b)
+		}
+
+		fn liquidity_position(
+			who: AccountId,
+			asset_a: AssetId,
+			asset_b: AssetId,
+		) -> Result<XykLiquidityPosition, DispatchError> {
+			XYK::liquidity_position(&who, asset_a, asset_b)
+		}
+
+		fn protocol_fee() -> Option<(u32, u32)> {
+			XYK::protocol_fee()
+		}
+	}
+
+	impl pallet_ema_oracle_rpc_runtime_api::EmaOracleApi<Block, AssetId, Balance, BlockNumber, EmaPrice> for Runtime {
+		fn get_entry(
+			source: Source,
+			asset_a: AssetId,
+			asset_b: AssetId,
+			period: OraclePeriod,
+		) -> Result<AggregatedEntry<Balance, BlockNumber, EmaPrice>, pallet_ema_oracle::OracleError> {
+			<EmaOracle as AggregatedOracle<AssetId, Balance, BlockNumber, EmaPrice>>::get_entry(
+				asset_a, asset_b, period, source,
+			)
+		}
+
+		fn get_entries(
+			source: Source,
+			asset_a: AssetId,
+			asset_b: AssetId,
+		) -> Vec<(OraclePeriod, AggregatedEntry<Balance, BlockNumber, EmaPrice>)> {
+			EmaOracle::get_entries(source, asset_a, asset_b)
+		}
+
+		fn whitelisted_oracles() -> Vec<(Source, (AssetId, AssetId))> {
+			EmaOracle::whitelisted_oracles()
+		}
+
+		fn route_price(
+			asset_in: AssetId,
+			asset_out: AssetId,
+			period: OraclePeriod,
+			route: Option<Vec<Trade<AssetId>>>,
+		) -> Option<EmaPrice> {
+			let route = route.unwrap_or_else(|| Router::get_route(RouterAssetPair::new(asset_in, asset_out)));
+			hydradx_adapters::OraclePriceProvider::<AssetId, EmaOracle, LRNA>::price(&route, period)
+		}
+	}
+
 	impl xcm_fee_payment_runtime_api::XcmPaymentApi<Block> for Runtime {
 		fn query_acceptable_payment_assets(xcm_version: polkadot_xcm::Version) -> Result<Vec<VersionedAssetId>, XcmPaymentApiError> {
 			if !matches!(xcm_version, 3 | 4) {
@@ -1015,6 +1139,7 @@ impl_runtime_apis! {
 			orml_list_benchmark!(list, extra, pallet_transaction_multi_payment, benchmarking::multi_payment);
 			orml_list_benchmark!(list, extra, pallet_duster, benchmarking::duster);
 			orml_list_benchmark!(list, extra, pallet_omnipool, benchmarking::omnipool);
+			orml_list_benchmark!(list, extra, pallet_omnipool_subpools, benchmarking::omnipool_subpools);
 			orml_list_benchmark!(list, extra, pallet_route_executor, benchmarking::route_executor);
 			orml_list_benchmark!(list, extra, pallet_dca, benchmarking::dca);
 			orml_list_benchmark!(list, extra, pallet_xyk, benchmarking::xyk);
@@ -1152,6 +1277,7 @@ impl_runtime_apis! {
 			orml_add_benchmark!(params, batches, pallet_transaction_multi_payment, benchmarking::multi_payment);
 			orml_add_benchmark!(params, batches, pallet_duster, benchmarking::duster);
 			orml_add_benchmark!(params, batches, pallet_omnipool, benchmarking::omnipool);
+			orml_add_benchmark!(params, batches, pallet_omnipool_subpools, benchmarking::omnipool_subpools);
 			orml_add_benchmark!(params, batches, pallet_route_executor, benchmarking::route_executor);
 			orml_add_benchmark!(params, batches, pallet_dca, benchmarking::dca);
 			orml_add_benchmark!(params, batches, pallet_xyk, benchmarking::xyk);
@@ -46,5 +46,9 @@ pub fn bind_pallet_account() -> Weight {
 impl OnRuntimeUpgrade for OnRuntimeUpgradeMigration {
 	fn on_runtime_upgrade() -> Weight {
 		bind_pallet_account()
+			.saturating_add(pallet_dca::migration::migrate_to_v1::<Runtime>())
+			.saturating_add(pallet_xyk::migration::migrate_to_v1::<Runtime>())
+			.saturating_add(pallet_lbp::migration::migrate_to_v1::<Runtime>())
+			.saturating_add(pallet_dynamic_fees::migration::migrate_to_v1::<Runtime>())
 	}
 }
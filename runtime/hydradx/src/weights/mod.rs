@@ -11,11 +11,13 @@ pub mod pallet_claims;
 pub mod pallet_collator_selection;
 pub mod pallet_collective_council;
 pub mod pallet_collective_technical_committee;
+pub mod pallet_concentrated_liquidity;
 pub mod pallet_currencies;
 pub mod pallet_dca;
 pub mod pallet_democracy;
 pub mod pallet_duster;
 pub mod pallet_dynamic_evm_fee;
+pub mod pallet_dynamic_fees;
 pub mod pallet_elections_phragmen;
 pub mod pallet_ema_oracle;
 pub mod pallet_evm_accounts;
@@ -26,6 +28,7 @@ pub mod pallet_message_queue;
 pub mod pallet_multisig;
 pub mod pallet_omnipool;
 pub mod pallet_omnipool_liquidity_mining;
+pub mod pallet_omnipool_subpools;
 pub mod pallet_otc;
 pub mod pallet_otc_settlements;
 pub mod pallet_preimage;
@@ -41,6 +44,7 @@ pub mod pallet_tips;
 pub mod pallet_transaction_multi_payment;
 pub mod pallet_transaction_pause;
 pub mod pallet_treasury;
+pub mod pallet_treasury_conversion;
 pub mod pallet_utility;
 pub mod pallet_xcm;
 pub mod pallet_xyk;
@@ -162,4 +162,36 @@ impl<T: frame_system::Config> pallet_circuit_breaker::WeightInfo for HydraWeight
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
-}
\ No newline at end of file
+	/// Storage: `CircuitBreaker::PoolPriceBarrier` (r:0 w:1)
+	/// Proof: `CircuitBreaker::PoolPriceBarrier` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn set_pool_price_barrier() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_214_000 picoseconds.
+		Weight::from_parts(9_532_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CircuitBreaker::AssetPriceBarrier` (r:0 w:1)
+	/// Proof: `CircuitBreaker::AssetPriceBarrier` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn set_asset_price_barrier() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 8_871_000 picoseconds.
+		Weight::from_parts(9_104_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CircuitBreaker::PoolPriceBarrier` (r:1 w:0)
+	/// Proof: `CircuitBreaker::PoolPriceBarrier` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `CircuitBreaker::AssetPriceBarrier` (r:2 w:0)
+	/// Proof: `CircuitBreaker::AssetPriceBarrier` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn ensure_price() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `261`
+		//  Estimated: `6488`
+		// Minimum execution time: 16_482_000 picoseconds.
+		Weight::from_parts(16_935_000, 6488)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+	}
+}
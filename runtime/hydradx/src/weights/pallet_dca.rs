@@ -257,4 +257,62 @@ impl<T: frame_system::Config> pallet_dca::WeightInfo for HydraWeight<T> {
             .saturating_add(T::DbWeight::get().reads(5_u64))
             .saturating_add(T::DbWeight::get().writes(7_u64))
     }
+    /// Storage: `DCA::Schedules` (r:1 w:0)
+    /// Proof: `DCA::Schedules` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+    /// Storage: `DCA::PausedSchedules` (r:1 w:1)
+    /// Proof: `DCA::PausedSchedules` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+    /// Storage: `DCA::ScheduleIdsPerBlock` (r:1 w:1)
+    /// Proof: `DCA::ScheduleIdsPerBlock` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+    fn pause() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `2530`
+        //  Estimated: `3675`
+        // Minimum execution time: 31_204_000 picoseconds.
+        Weight::from_parts(32_015_000, 3675)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// Storage: `DCA::Schedules` (r:1 w:0)
+    /// Proof: `DCA::Schedules` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+    /// Storage: `DCA::PausedSchedules` (r:1 w:1)
+    /// Proof: `DCA::PausedSchedules` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+    /// Storage: `DCA::ScheduleIdsPerBlock` (r:11 w:1)
+    /// Proof: `DCA::ScheduleIdsPerBlock` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+    fn resume() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `52702`
+        //  Estimated: `29326`
+        // Minimum execution time: 98_721_000 picoseconds.
+        Weight::from_parts(100_482_000, 29326)
+            .saturating_add(T::DbWeight::get().reads(13_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// Storage: `DCA::Schedules` (r:1 w:1)
+    /// Proof: `DCA::Schedules` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Reserves` (r:1 w:1)
+    /// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `DCA::RemainingAmounts` (r:1 w:1)
+    /// Proof: `DCA::RemainingAmounts` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    fn top_up_budget() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `2492`
+        //  Estimated: `4714`
+        // Minimum execution time: 47_310_000 picoseconds.
+        Weight::from_parts(48_392_000, 4714)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+    /// Storage: `DCA::Schedules` (r:1 w:1)
+    /// Proof: `DCA::Schedules` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+    fn update_schedule() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `1390`
+        //  Estimated: `3675`
+        // Minimum execution time: 22_108_000 picoseconds.
+        Weight::from_parts(22_740_000, 3675)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
 }
\ No newline at end of file
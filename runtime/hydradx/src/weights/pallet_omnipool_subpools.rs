@@ -0,0 +1,173 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+//! Autogenerated weights for `pallet_omnipool_subpools`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
+//! DATE: 2024-10-14, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `bench-bot`, CPU: `Intel(R) Core(TM) i7-7700K CPU @ 4.20GHz`
+//! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: `1024`
+
+// Executed Command:
+// ./target/release/hydradx
+// benchmark
+// pallet
+// --wasm-execution=compiled
+// --pallet
+// pallet-omnipool-subpools
+// --extrinsic
+// *
+// --heap-pages
+// 4096
+// --steps
+// 50
+// --repeat
+// 20
+// --template=scripts/pallet-weight-template.hbs
+// --output
+// omnipool_subpools.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weights for `pallet_omnipool_subpools`.
+pub struct WeightInfo<T>(PhantomData<T>);
+
+/// Weights for `pallet_omnipool_subpools` using the HydraDX node and recommended hardware.
+pub struct HydraWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_omnipool_subpools::WeightInfo for HydraWeight<T> {
+    /// Storage: `Omnipool::Assets` (r:3 w:3)
+    /// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+    /// Storage: `AssetRegistry::Assets` (r:3 w:0)
+    /// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+    /// Storage: `Stableswap::Pools` (r:1 w:1)
+    /// Proof: `Stableswap::Pools` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+    /// Storage: `Duster::AccountBlacklist` (r:1 w:1)
+    /// Proof: `Duster::AccountBlacklist` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+    /// Storage: `Tokens::Accounts` (r:5 w:5)
+    /// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+    /// Storage: `Tokens::TotalIssuance` (r:1 w:1)
+    /// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+    /// Storage: `OmnipoolSubpools::MigratedAssets` (r:2 w:2)
+    /// Proof: `OmnipoolSubpools::MigratedAssets` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+    /// Storage: `OmnipoolSubpools::Subpools` (r:2 w:1)
+    /// Proof: `OmnipoolSubpools::Subpools` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+    fn create_subpool() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `3802`
+        //  Estimated: `13405`
+        // Minimum execution time: 128_412_000 picoseconds.
+        Weight::from_parts(130_004_000, 13405)
+            .saturating_add(T::DbWeight::get().reads(18_u64))
+            .saturating_add(T::DbWeight::get().writes(14_u64))
+    }
+    /// Storage: `OmnipoolSubpools::Subpools` (r:2 w:0)
+    /// Proof: `OmnipoolSubpools::Subpools` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+    /// Storage: `Omnipool::Assets` (r:2 w:2)
+    /// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+    /// Storage: `AssetRegistry::Assets` (r:1 w:0)
+    /// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+    /// Storage: `Stableswap::Pools` (r:1 w:1)
+    /// Proof: `Stableswap::Pools` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+    /// Storage: `Tokens::TotalIssuance` (r:1 w:1)
+    /// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+    /// Storage: `Tokens::Accounts` (r:3 w:3)
+    /// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+    /// Storage: `OmnipoolSubpools::MigratedAssets` (r:1 w:1)
+    /// Proof: `OmnipoolSubpools::MigratedAssets` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+    fn migrate_asset_to_subpool() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `3415`
+        //  Estimated: `8799`
+        // Minimum execution time: 89_305_000 picoseconds.
+        Weight::from_parts(90_642_000, 8799)
+            .saturating_add(T::DbWeight::get().reads(11_u64))
+            .saturating_add(T::DbWeight::get().writes(8_u64))
+    }
+    /// Storage: `Omnipool::Positions` (r:1 w:1)
+    /// Proof: `Omnipool::Positions` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+    /// Storage: `OmnipoolSubpools::MigratedAssets` (r:1 w:0)
+    /// Proof: `OmnipoolSubpools::MigratedAssets` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+    fn convert_position() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `1652`
+        //  Estimated: `3565`
+        // Minimum execution time: 24_118_000 picoseconds.
+        Weight::from_parts(24_720_000, 3565)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `OmnipoolSubpools::MigratedAssets` (r:2 w:0)
+    /// Proof: `OmnipoolSubpools::MigratedAssets` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+    /// Storage: `Stableswap::Pools` (r:2 w:0)
+    /// Proof: `Stableswap::Pools` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+    /// Storage: `Stableswap::AssetTradability` (r:4 w:0)
+    /// Proof: `Stableswap::AssetTradability` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+    /// Storage: `Omnipool::Assets` (r:2 w:2)
+    /// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+    /// Storage: `Tokens::Accounts` (r:12 w:12)
+    /// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:2 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    /// The range of component `c` is `[1, 3]`.
+    fn sell(c: u32, ) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `1698 + c * (1414 ±0)`
+        //  Estimated: `3565 + c * (9174 ±0)`
+        // Minimum execution time: 352_930_000 picoseconds.
+        Weight::from_parts(8_925_000, 3565)
+            // Standard Error: 41_362
+            .saturating_add(Weight::from_parts(115_829_000, 0).saturating_mul(c.into()))
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().reads((7_u64).saturating_mul(c.into())))
+            .saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(c.into())))
+            .saturating_add(Weight::from_parts(0, 9174).saturating_mul(c.into()))
+    }
+    /// Storage: `OmnipoolSubpools::MigratedAssets` (r:2 w:0)
+    /// Proof: `OmnipoolSubpools::MigratedAssets` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+    /// Storage: `Stableswap::Pools` (r:2 w:0)
+    /// Proof: `Stableswap::Pools` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+    /// Storage: `Stableswap::AssetTradability` (r:4 w:0)
+    /// Proof: `Stableswap::AssetTradability` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+    /// Storage: `Omnipool::Assets` (r:2 w:2)
+    /// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+    /// Storage: `Tokens::Accounts` (r:12 w:12)
+    /// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:2 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    /// The range of component `c` is `[1, 3]`.
+    fn buy(c: u32, ) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `1698 + c * (1414 ±0)`
+        //  Estimated: `3565 + c * (9174 ±0)`
+        // Minimum execution time: 391_417_000 picoseconds.
+        Weight::from_parts(8_977_000, 3565)
+            // Standard Error: 41_362
+            .saturating_add(Weight::from_parts(128_750_000, 0).saturating_mul(c.into()))
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().reads((7_u64).saturating_mul(c.into())))
+            .saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(c.into())))
+            .saturating_add(Weight::from_parts(0, 9174).saturating_mul(c.into()))
+    }
+}
@@ -89,14 +89,22 @@ impl<T: frame_system::Config> pallet_xyk::WeightInfo for HydraWeight<T> {
 	/// Proof: `XYK::TotalLiquidity` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
 	/// Storage: `XYK::PoolAssets` (r:0 w:1)
 	/// Proof: `XYK::PoolAssets` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `XYK::PoolFees` (r:0 w:1)
+	/// Proof: `XYK::PoolFees` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `XYK::PoolOwners` (r:0 w:1)
+	/// Proof: `XYK::PoolOwners` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `XYK::ProtocolFee` (r:1 w:0)
+	/// Proof: `XYK::ProtocolFee` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `XYK::RootKLast` (r:0 w:1)
+	/// Proof: `XYK::RootKLast` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
 	fn create_pool() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `3863`
 		//  Estimated: `19071`
 		// Minimum execution time: 482_234_000 picoseconds.
 		Weight::from_parts(487_055_000, 19071)
-			.saturating_add(T::DbWeight::get().reads(34_u64))
-			.saturating_add(T::DbWeight::get().writes(20_u64))
+			.saturating_add(T::DbWeight::get().reads(35_u64))
+			.saturating_add(T::DbWeight::get().writes(23_u64))
 	}
 	/// Storage: `XYK::ShareToken` (r:1 w:0)
 	/// Proof: `XYK::ShareToken` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
@@ -128,14 +136,20 @@ impl<T: frame_system::Config> pallet_xyk::WeightInfo for HydraWeight<T> {
 	/// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
 	/// Storage: `EmaOracle::WhitelistedAssets` (r:1 w:0)
 	/// Proof: `EmaOracle::WhitelistedAssets` (`max_values`: Some(1), `max_size`: Some(641), added: 1136, mode: `MaxEncodedLen`)
+	/// Storage: `XYK::ProtocolFee` (r:1 w:0)
+	/// Proof: `XYK::ProtocolFee` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `XYK::RootKLast` (r:1 w:1)
+	/// Proof: `XYK::RootKLast` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `XYK::ProtocolFeeShares` (r:1 w:1)
+	/// Proof: `XYK::ProtocolFeeShares` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
 	fn add_liquidity() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `4499`
 		//  Estimated: `19071`
 		// Minimum execution time: 300_637_000 picoseconds.
 		Weight::from_parts(301_659_000, 19071)
-			.saturating_add(T::DbWeight::get().reads(28_u64))
-			.saturating_add(T::DbWeight::get().writes(13_u64))
+			.saturating_add(T::DbWeight::get().reads(32_u64))
+			.saturating_add(T::DbWeight::get().writes(17_u64))
 	}
 	/// Storage: `XYK::ShareToken` (r:1 w:1)
 	/// Proof: `XYK::ShareToken` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
@@ -169,14 +183,26 @@ impl<T: frame_system::Config> pallet_xyk::WeightInfo for HydraWeight<T> {
 	/// Proof: `EmaOracle::WhitelistedAssets` (`max_values`: Some(1), `max_size`: Some(641), added: 1136, mode: `MaxEncodedLen`)
 	/// Storage: `XYK::PoolAssets` (r:0 w:1)
 	/// Proof: `XYK::PoolAssets` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `XYK::PoolFees` (r:0 w:1)
+	/// Proof: `XYK::PoolFees` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `XYK::PoolOwners` (r:0 w:1)
+	/// Proof: `XYK::PoolOwners` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `XYK::ProtocolFee` (r:1 w:0)
+	/// Proof: `XYK::ProtocolFee` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `XYK::RootKLast` (r:1 w:1)
+	/// Proof: `XYK::RootKLast` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `XYK::ProtocolFeeShares` (r:1 w:1)
+	/// Proof: `XYK::ProtocolFeeShares` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `XYK::AccumulatedFees` (r:0 w:2)
+	/// Proof: `XYK::AccumulatedFees` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
 	fn remove_liquidity() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `4549`
 		//  Estimated: `19071`
 		// Minimum execution time: 437_485_000 picoseconds.
 		Weight::from_parts(441_006_000, 19071)
-			.saturating_add(T::DbWeight::get().reads(30_u64))
-			.saturating_add(T::DbWeight::get().writes(17_u64))
+			.saturating_add(T::DbWeight::get().reads(34_u64))
+			.saturating_add(T::DbWeight::get().writes(26_u64))
 	}
 	/// Storage: `XYK::ShareToken` (r:1 w:0)
 	/// Proof: `XYK::ShareToken` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
@@ -204,14 +230,18 @@ impl<T: frame_system::Config> pallet_xyk::WeightInfo for HydraWeight<T> {
 	/// Proof: `MultiTransactionPayment::AcceptedCurrencyPrice` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
 	/// Storage: `EmaOracle::WhitelistedAssets` (r:1 w:0)
 	/// Proof: `EmaOracle::WhitelistedAssets` (`max_values`: Some(1), `max_size`: Some(641), added: 1136, mode: `MaxEncodedLen`)
+	/// Storage: `XYK::PoolFees` (r:1 w:0)
+	/// Proof: `XYK::PoolFees` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `XYK::AccumulatedFees` (r:1 w:1)
+	/// Proof: `XYK::AccumulatedFees` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
 	fn sell() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `4398`
 		//  Estimated: `16488`
 		// Minimum execution time: 263_251_000 picoseconds.
 		Weight::from_parts(265_158_000, 16488)
-			.saturating_add(T::DbWeight::get().reads(25_u64))
-			.saturating_add(T::DbWeight::get().writes(10_u64))
+			.saturating_add(T::DbWeight::get().reads(27_u64))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
 	}
 	/// Storage: `XYK::ShareToken` (r:1 w:0)
 	/// Proof: `XYK::ShareToken` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
@@ -239,14 +269,18 @@ impl<T: frame_system::Config> pallet_xyk::WeightInfo for HydraWeight<T> {
 	/// Proof: `AssetRegistry::ExistentialDepositCounter` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	/// Storage: `EmaOracle::WhitelistedAssets` (r:1 w:0)
 	/// Proof: `EmaOracle::WhitelistedAssets` (`max_values`: Some(1), `max_size`: Some(641), added: 1136, mode: `MaxEncodedLen`)
+	/// Storage: `XYK::PoolFees` (r:1 w:0)
+	/// Proof: `XYK::PoolFees` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `XYK::AccumulatedFees` (r:1 w:1)
+	/// Proof: `XYK::AccumulatedFees` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
 	fn buy() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `4398`
 		//  Estimated: `16488`
 		// Minimum execution time: 261_604_000 picoseconds.
 		Weight::from_parts(262_778_000, 16488)
-			.saturating_add(T::DbWeight::get().reads(25_u64))
-			.saturating_add(T::DbWeight::get().writes(10_u64))
+			.saturating_add(T::DbWeight::get().reads(27_u64))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
 	}
 	/// Storage: `XYK::ShareToken` (r:1 w:0)
 	/// Proof: `XYK::ShareToken` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
@@ -276,6 +310,10 @@ impl<T: frame_system::Config> pallet_xyk::WeightInfo for HydraWeight<T> {
 	/// Proof: `EmaOracle::WhitelistedAssets` (`max_values`: Some(1), `max_size`: Some(641), added: 1136, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 2]`.
 	/// The range of component `e` is `[0, 1]`.
+	/// Storage: `XYK::PoolFees` (r:1 w:0)
+	/// Proof: `XYK::PoolFees` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `XYK::AccumulatedFees` (r:1 w:1)
+	/// Proof: `XYK::AccumulatedFees` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
 	fn router_execution_sell(c: u32, e: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1221 + e * (3177 ±0)`
@@ -287,8 +325,8 @@ impl<T: frame_system::Config> pallet_xyk::WeightInfo for HydraWeight<T> {
 			// Standard Error: 78_193
 			.saturating_add(Weight::from_parts(247_520_019, 0).saturating_mul(e.into()))
 			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().reads((22_u64).saturating_mul(e.into())))
-			.saturating_add(T::DbWeight::get().writes((10_u64).saturating_mul(e.into())))
+			.saturating_add(T::DbWeight::get().reads((24_u64).saturating_mul(e.into())))
+			.saturating_add(T::DbWeight::get().writes((11_u64).saturating_mul(e.into())))
 			.saturating_add(Weight::from_parts(0, 10332).saturating_mul(e.into()))
 	}
 	/// Storage: `XYK::ShareToken` (r:1 w:0)
@@ -319,6 +357,10 @@ impl<T: frame_system::Config> pallet_xyk::WeightInfo for HydraWeight<T> {
 	/// Proof: `EmaOracle::WhitelistedAssets` (`max_values`: Some(1), `max_size`: Some(641), added: 1136, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 3]`.
 	/// The range of component `e` is `[0, 1]`.
+	/// Storage: `XYK::PoolFees` (r:1 w:0)
+	/// Proof: `XYK::PoolFees` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `XYK::AccumulatedFees` (r:1 w:1)
+	/// Proof: `XYK::AccumulatedFees` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
 	fn router_execution_buy(c: u32, e: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1221 + e * (3177 ±0)`
@@ -330,18 +372,126 @@ impl<T: frame_system::Config> pallet_xyk::WeightInfo for HydraWeight<T> {
 			// Standard Error: 49_613
 			.saturating_add(Weight::from_parts(245_134_509, 0).saturating_mul(e.into()))
 			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().reads((22_u64).saturating_mul(e.into())))
-			.saturating_add(T::DbWeight::get().writes((10_u64).saturating_mul(e.into())))
+			.saturating_add(T::DbWeight::get().reads((24_u64).saturating_mul(e.into())))
+			.saturating_add(T::DbWeight::get().writes((11_u64).saturating_mul(e.into())))
 			.saturating_add(Weight::from_parts(0, 10332).saturating_mul(e.into()))
 	}
 	/// Storage: `Tokens::Accounts` (r:2 w:0)
 	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `XYK::PoolFees` (r:1 w:0)
+	/// Proof: `XYK::PoolFees` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
 	fn calculate_spot_price_with_fee() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1021`
 		//  Estimated: `6156`
 		// Minimum execution time: 19_349_000 picoseconds.
 		Weight::from_parts(19_578_000, 6156)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+	}
+	/// Storage: `XYK::FeeTiers` (r:1 w:0)
+	/// Proof: `XYK::FeeTiers` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::Assets` (r:3 w:1)
+	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::PoolData` (r:1 w:0)
+	/// Proof: `LBP::PoolData` (`max_values`: None, `max_size`: Some(163), added: 2638, mode: `MaxEncodedLen`)
+	/// Storage: `XYK::ShareToken` (r:1 w:1)
+	/// Proof: `XYK::ShareToken` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:7 w:7)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::AssetIds` (r:1 w:1)
+	/// Proof: `AssetRegistry::AssetIds` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::NextAssetId` (r:1 w:1)
+	/// Proof: `AssetRegistry::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Router::SkipEd` (r:1 w:0)
+	/// Proof: `Router::SkipEd` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Duster::AccountBlacklist` (r:1 w:1)
+	/// Proof: `Duster::AccountBlacklist` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::BannedAssets` (r:4 w:0)
+	/// Proof: `AssetRegistry::BannedAssets` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `MultiTransactionPayment::AccountCurrencyMap` (r:3 w:0)
+	/// Proof: `MultiTransactionPayment::AccountCurrencyMap` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `MultiTransactionPayment::AcceptedCurrencyPrice` (r:1 w:0)
+	/// Proof: `MultiTransactionPayment::AcceptedCurrencyPrice` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:3 w:3)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `MultiTransactionPayment::AcceptedCurrencies` (r:3 w:0)
+	/// Proof: `MultiTransactionPayment::AcceptedCurrencies` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::ExistentialDepositCounter` (r:1 w:1)
+	/// Proof: `AssetRegistry::ExistentialDepositCounter` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::TotalIssuance` (r:1 w:1)
+	/// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `XYK::TotalLiquidity` (r:0 w:1)
+	/// Proof: `XYK::TotalLiquidity` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `XYK::PoolAssets` (r:0 w:1)
+	/// Proof: `XYK::PoolAssets` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `XYK::PoolFees` (r:0 w:1)
+	/// Proof: `XYK::PoolFees` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `XYK::PoolOwners` (r:0 w:1)
+	/// Proof: `XYK::PoolOwners` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `XYK::ProtocolFee` (r:1 w:0)
+	/// Proof: `XYK::ProtocolFee` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `XYK::RootKLast` (r:0 w:1)
+	/// Proof: `XYK::RootKLast` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	fn create_pool_with_fee() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3863`
+		//  Estimated: `19071`
+		// Minimum execution time: 484_301_000 picoseconds.
+		Weight::from_parts(489_112_000, 19071)
+			.saturating_add(T::DbWeight::get().reads(36_u64))
+			.saturating_add(T::DbWeight::get().writes(23_u64))
+	}
+	/// Storage: `XYK::FeeTiers` (r:1 w:1)
+	/// Proof: `XYK::FeeTiers` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn add_fee_tier() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `3489`
+		// Minimum execution time: 11_422_000 picoseconds.
+		Weight::from_parts(11_763_000, 3489)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `XYK::FeeTiers` (r:1 w:1)
+	/// Proof: `XYK::FeeTiers` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn remove_fee_tier() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `125`
+		//  Estimated: `3489`
+		// Minimum execution time: 13_205_000 picoseconds.
+		Weight::from_parts(13_547_000, 3489)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `XYK::ShareToken` (r:1 w:0)
+	/// Proof: `XYK::ShareToken` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `XYK::PoolOwners` (r:1 w:0)
+	/// Proof: `XYK::PoolOwners` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `XYK::FeeTiers` (r:1 w:0)
+	/// Proof: `XYK::FeeTiers` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `XYK::PoolFees` (r:0 w:1)
+	/// Proof: `XYK::PoolFees` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn update_pool_fee() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `543`
+		//  Estimated: `3545`
+		// Minimum execution time: 20_878_000 picoseconds.
+		Weight::from_parts(21_312_000, 3545)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `XYK::ProtocolFee` (r:0 w:1)
+	/// Proof: `XYK::ProtocolFee` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	fn set_protocol_fee() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_118_000 picoseconds.
+		Weight::from_parts(9_384_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 }
\ No newline at end of file
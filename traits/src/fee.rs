@@ -9,7 +9,11 @@ pub trait InspectTransactionFeeCurrency<AssetId> {
 pub trait SwappablePaymentAssetTrader<AccountId, AssetId, Balance>: InspectTransactionFeeCurrency<AssetId> {
 	fn is_trade_supported(from: AssetId, into: AssetId) -> bool;
 
-	fn calculate_fee_amount(swap_amount: Balance) -> Result<Balance, DispatchError>;
+	fn calculate_fee_amount(
+		asset_in: AssetId,
+		asset_out: AssetId,
+		swap_amount: Balance,
+	) -> Result<Balance, DispatchError>;
 
 	fn calculate_in_given_out(
 		insuff_asset_id: AssetId,
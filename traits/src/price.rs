@@ -1,5 +1,31 @@
+use frame_support::sp_runtime::DispatchResult;
+use frame_support::weights::Weight;
+
 pub trait PriceProvider<AssetId> {
 	type Price;
 
 	fn get_price(asset_a: AssetId, asset_b: AssetId) -> Option<Self::Price>;
 }
+
+/// Guards liquidity operations of AMM pools against price manipulation by comparing the spot
+/// price of a pool with an oracle price.
+pub trait PriceBarrier<AccountId, AssetId, Price> {
+	/// Ensure that the spot price of `asset_a` denominated in `asset_b` does not deviate from the
+	/// oracle price by more than allowed.
+	///
+	/// The spot price is expected in the format of the oracle price, i.e. as the ratio of the
+	/// reserve of `asset_a` to the reserve of `asset_b` in a constant product pool.
+	fn ensure_price(who: &AccountId, asset_a: AssetId, asset_b: AssetId, spot_price: Price) -> DispatchResult;
+
+	fn ensure_price_weight() -> Weight;
+}
+
+impl<AccountId, AssetId, Price> PriceBarrier<AccountId, AssetId, Price> for () {
+	fn ensure_price(_who: &AccountId, _asset_a: AssetId, _asset_b: AssetId, _spot_price: Price) -> DispatchResult {
+		Ok(())
+	}
+
+	fn ensure_price_weight() -> Weight {
+		Weight::zero()
+	}
+}
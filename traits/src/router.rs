@@ -3,7 +3,9 @@ use frame_support::dispatch::DispatchResultWithPostInfo;
 use frame_support::sp_runtime::{DispatchError, DispatchResult};
 use frame_support::weights::Weight;
 use scale_info::TypeInfo;
-use sp_arithmetic::FixedU128;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_arithmetic::{FixedU128, Permill};
 use sp_std::vec;
 use sp_std::vec::Vec;
 
@@ -60,12 +62,14 @@ pub trait RouteProvider<AssetId> {
 	}
 }
 
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Clone, Copy, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
 pub enum PoolType<AssetId> {
 	XYK,
 	LBP,
 	Stableswap(AssetId),
 	Omnipool,
+	Concentrated(u32),
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -75,6 +79,7 @@ pub enum ExecutorError<E> {
 }
 
 ///A single trade for buy/sell, describing the asset pair and the pool type in which the trade is executed
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Debug, Eq, PartialEq, Copy, Clone, TypeInfo, MaxEncodedLen)]
 pub struct Trade<AssetId> {
 	pub pool: PoolType<AssetId>,
@@ -82,12 +87,31 @@ pub struct Trade<AssetId> {
 	pub asset_out: AssetId,
 }
 
-#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq, TypeInfo)]
 pub struct AmountInAndOut<Balance> {
 	pub amount_in: Balance,
 	pub amount_out: Balance,
 }
 
+/// Result of a simulated sell or buy through the router
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq, TypeInfo)]
+pub struct RouteQuote<AssetId, Balance> {
+	/// The route used for the simulation - either the provided, the on-chain or the default one
+	pub route: Vec<Trade<AssetId>>,
+	/// Amounts in and out for each trade of the route, in the order of the trades
+	pub amounts: Vec<AmountInAndOut<Balance>>,
+	/// Total amount of asset in spent by the route
+	pub amount_in: Balance,
+	/// Total amount of asset out received from the route
+	pub amount_out: Balance,
+	/// Spot price of the route including fees, if available
+	pub spot_price: Option<FixedU128>,
+	/// Difference between the amount calculated with the spot price and the simulated amount
+	pub price_impact: Option<Permill>,
+}
+
 pub fn inverse_route<AssetId>(trades: Vec<Trade<AssetId>>) -> Vec<Trade<AssetId>> {
 	trades
 		.into_iter()
@@ -310,6 +334,48 @@ impl<E: PartialEq, Origin: Clone, AccountId, AssetId: Copy, Balance: Copy>
 	}
 }
 
+/// A pool which can be used in a route, together with all its tradeable assets
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TradeablePool<AssetId> {
+	pub pool: PoolType<AssetId>,
+	pub assets: Vec<AssetId>,
+}
+
+/// Provides the pools used by the router for on-chain route discovery.
+/// All AMMs which should be considered in the route discovery are required to implement this trait.
+pub trait PoolsProvider<AssetId> {
+	/// Returns at most `limit` tradeable pools. Pools which are not tradeable don't count towards the limit.
+	fn get_pools(limit: u32) -> Vec<TradeablePool<AssetId>>;
+
+	/// Weight of `get_pools` called with `limit`.
+	fn get_pools_weight(limit: u32) -> Weight;
+}
+
+impl<AssetId> PoolsProvider<AssetId> for () {
+	fn get_pools(_limit: u32) -> Vec<TradeablePool<AssetId>> {
+		Vec::new()
+	}
+
+	fn get_pools_weight(_limit: u32) -> Weight {
+		Weight::zero()
+	}
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(1, 5)]
+impl<AssetId> PoolsProvider<AssetId> for Tuple {
+	fn get_pools(limit: u32) -> Vec<TradeablePool<AssetId>> {
+		let mut pools = Vec::new();
+		for_tuples!( #( pools.extend(Tuple::get_pools(limit)); )* );
+		pools
+	}
+
+	fn get_pools_weight(limit: u32) -> Weight {
+		let mut weight = Weight::zero();
+		for_tuples!( #( weight = weight.saturating_add(Tuple::get_pools_weight(limit)); )* );
+		weight
+	}
+}
+
 /// Provides weight info for the router. Calculates the weight of a route based on the AMMs.
 pub trait AmmTradeWeights<Trade> {
 	fn sell_weight(route: &[Trade]) -> Weight;
@@ -321,6 +387,8 @@ pub trait AmmTradeWeights<Trade> {
 	fn force_insert_route_weight() -> Weight;
 	fn get_route_weight() -> Weight;
 	fn calculate_spot_price_with_fee_weight(route: &[Trade]) -> Weight;
+	fn find_best_route_weight() -> Weight;
+	fn sell_with_best_route_weight() -> Weight;
 }
 
 impl<Trade> AmmTradeWeights<Trade> for () {
@@ -351,6 +419,12 @@ impl<Trade> AmmTradeWeights<Trade> for () {
 	fn calculate_spot_price_with_fee_weight(_route: &[Trade]) -> Weight {
 		Weight::zero()
 	}
+	fn find_best_route_weight() -> Weight {
+		Weight::zero()
+	}
+	fn sell_with_best_route_weight() -> Weight {
+		Weight::zero()
+	}
 }
 
 pub trait RefundEdCalculator<Balance> {